@@ -10,9 +10,10 @@ use common::metadata::Metadata;
 use common::organizations::OrganizationEntityType;
 use common::socials::Socials;
 use std::{cmp, fmt};
+use utoipa::ToSchema;
 
 /// It represents a model railways manufacturer.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Brand {
     /// the brand unique identifier (an url encoded string)
     pub brand_id: BrandId,
@@ -7,9 +7,10 @@ use std::fmt::Formatter;
 use std::ops;
 use std::str;
 use std::str::FromStr;
+use utoipa::ToSchema;
 
 /// It represents the unique identifier for a brand.
-#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Hash, Serialize, Deserialize, Type)]
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Hash, Serialize, Deserialize, Type, ToSchema)]
 #[sqlx(transparent)]
 pub struct BrandId(Slug);
 
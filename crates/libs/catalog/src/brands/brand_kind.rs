@@ -3,9 +3,10 @@
 use sqlx::Type;
 use strum_macros;
 use strum_macros::{Display, EnumString};
+use utoipa::ToSchema;
 
 /// The different kinds for railway models brands
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, Display, Type, Default)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, Display, Type, Default, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "brand_kind", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -7,10 +7,11 @@ use common::contacts::ContactInformation;
 use common::localized_text::LocalizedText;
 use common::organizations::OrganizationEntityType;
 use common::socials::Socials;
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// A request to create/update model railways brands
-#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize, Validate, ToSchema)]
 pub struct BrandRequest {
     /// the name
     #[validate(length(min = 3, max = 50))]
@@ -4,7 +4,7 @@ use crate::brands::brand_id::BrandId;
 use chrono::{DateTime, Utc};
 
 /// It represents a response for brands creation
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BrandCreated {
     /// the brand id for the new brand
     pub brand_id: BrandId,
@@ -13,10 +13,19 @@ pub struct BrandCreated {
 }
 
 /// It represents a response for brand updates
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BrandUpdated {
     /// the brand id for the updated brand
     pub brand_id: BrandId,
     /// the brand update timestamp
     pub last_modified_at: DateTime<Utc>,
 }
+
+/// It represents a response for brand soft deletion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrandDeleted {
+    /// the brand id for the deleted brand
+    pub brand_id: BrandId,
+    /// the brand deletion timestamp
+    pub deleted_at: DateTime<Utc>,
+}
@@ -0,0 +1,195 @@
+//! the brand soft deletion and restore commands
+
+use crate::brands::brand_id::BrandId;
+use crate::brands::brand_response::{BrandDeleted, BrandUpdated};
+use crate::brands::commands::repositories::DeleteBrandRepository;
+use crate::events::domain_event::DomainEvent;
+use crate::events::repositories::OutboxRepository;
+use chrono::Utc;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+
+pub type Result<R> = result::Result<R, BrandDeletionError>;
+
+/// Soft deletes the brand, the brand is not removed from the database and it can be restored later
+pub async fn delete_brand<'db, U, Repo, O, DB>(
+    brand_id: &BrandId,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<BrandDeleted>
+where
+    U: UnitOfWork<'db>,
+    Repo: DeleteBrandRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let deleted_at = Utc::now();
+    if !repo.soft_delete(brand_id, &deleted_at, &mut unit_of_work).await? {
+        return match repo.is_deleted(brand_id, &mut unit_of_work).await? {
+            None => Err(BrandDeletionError::BrandNotFound(brand_id.clone())),
+            Some(_) => Err(BrandDeletionError::BrandAlreadyDeleted(brand_id.clone())),
+        };
+    }
+
+    let deleted = BrandDeleted {
+        brand_id: brand_id.clone(),
+        deleted_at,
+    };
+    outbox
+        .append(&DomainEvent::BrandDeleted(deleted.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(deleted)
+}
+
+/// Restores a soft deleted brand
+pub async fn restore_brand<'db, U, Repo, O, DB>(
+    brand_id: &BrandId,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<BrandUpdated>
+where
+    U: UnitOfWork<'db>,
+    Repo: DeleteBrandRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let last_modified_at = Utc::now();
+    if !repo.restore(brand_id, &last_modified_at, &mut unit_of_work).await? {
+        return match repo.is_deleted(brand_id, &mut unit_of_work).await? {
+            None => Err(BrandDeletionError::BrandNotFound(brand_id.clone())),
+            Some(_) => Err(BrandDeletionError::BrandNotDeleted(brand_id.clone())),
+        };
+    }
+
+    let restored = BrandUpdated {
+        brand_id: brand_id.clone(),
+        last_modified_at,
+    };
+    outbox
+        .append(&DomainEvent::BrandRestored(restored.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(restored)
+}
+
+#[derive(Debug, Error)]
+pub enum BrandDeletionError {
+    #[error("The brand was not found (id: {0})")]
+    BrandNotFound(BrandId),
+
+    #[error("The brand has been already deleted (id: {0})")]
+    BrandAlreadyDeleted(BrandId),
+
+    #[error("The brand is not deleted (id: {0})")]
+    BrandNotDeleted(BrandId),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod delete_brand_command {
+        use super::*;
+        use crate::brands::commands::repositories::in_memory::InMemoryBrandDeletionRepository;
+        use crate::events::repositories::in_memory::InMemoryOutboxRepository;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_delete_a_brand() {
+            let repo = InMemoryBrandDeletionRepository::with(BrandId::new("ACME"), None);
+            let outbox = InMemoryOutboxRepository::empty();
+
+            let result = delete_brand(&BrandId::new("ACME"), repo, outbox.clone(), NoOpDatabase).await;
+
+            let deleted = result.expect("result is an error");
+            assert_eq!(BrandId::new("ACME"), deleted.brand_id);
+            assert_eq!(vec![DomainEvent::BrandDeleted(deleted)], outbox.events());
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_deleting_a_brand_that_does_not_exist() {
+            let repo = InMemoryBrandDeletionRepository::empty();
+
+            let result = delete_brand(
+                &BrandId::new("ACME"),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(BrandDeletionError::BrandNotFound(id)) => assert_eq!(BrandId::new("ACME"), id),
+                _ => panic!("BrandNotFound is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_deleting_a_brand_twice() {
+            let repo = InMemoryBrandDeletionRepository::with(BrandId::new("ACME"), Some(Utc::now()));
+            let outbox = InMemoryOutboxRepository::empty();
+
+            let result = delete_brand(&BrandId::new("ACME"), repo, outbox.clone(), NoOpDatabase).await;
+
+            match result {
+                Err(BrandDeletionError::BrandAlreadyDeleted(id)) => assert_eq!(BrandId::new("ACME"), id),
+                _ => panic!("BrandAlreadyDeleted is expected (found: {:?})", result),
+            }
+            assert!(outbox.events().is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_restore_a_deleted_brand() {
+            let repo = InMemoryBrandDeletionRepository::with(BrandId::new("ACME"), Some(Utc::now()));
+
+            let result = restore_brand(
+                &BrandId::new("ACME"),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let restored = result.expect("result is an error");
+            assert_eq!(BrandId::new("ACME"), restored.brand_id);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_restoring_a_brand_which_is_not_deleted() {
+            let repo = InMemoryBrandDeletionRepository::with(BrandId::new("ACME"), None);
+
+            let result = restore_brand(
+                &BrandId::new("ACME"),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(BrandDeletionError::BrandNotDeleted(id)) => assert_eq!(BrandId::new("ACME"), id),
+                _ => panic!("BrandNotDeleted is expected (found: {:?})", result),
+            }
+        }
+    }
+}
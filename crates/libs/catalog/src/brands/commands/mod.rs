@@ -1,4 +1,5 @@
 //! the brand commands
 
+pub mod delete_brand;
 pub mod new_brand;
 pub mod repositories;
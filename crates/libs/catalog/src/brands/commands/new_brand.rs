@@ -6,6 +6,8 @@ use crate::brands::brand_request::BrandRequest;
 use crate::brands::brand_response::BrandCreated;
 use crate::brands::brand_status::BrandStatus;
 use crate::brands::commands::repositories::NewBrandRepository;
+use crate::events::domain_event::DomainEvent;
+use crate::events::repositories::OutboxRepository;
 use chrono::Utc;
 use common::address::Address;
 use common::contacts::{ContactInformation, MailAddress, PhoneNumber, WebsiteUrl};
@@ -21,10 +23,16 @@ use validator::{Validate, ValidationErrors};
 
 pub type Result<R> = result::Result<R, BrandCreationError>;
 
-pub async fn create_new_brand<'db, U, Repo, DB>(request: BrandRequest, repo: Repo, db: DB) -> Result<BrandCreated>
+pub async fn create_new_brand<'db, U, Repo, O, DB>(
+    request: BrandRequest,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<BrandCreated>
 where
     U: UnitOfWork<'db>,
     Repo: NewBrandRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
     DB: Database<'db, U>,
 {
     let brand_id = BrandId::new(&request.name);
@@ -38,12 +46,17 @@ where
     let command = NewBrandCommand::try_from(request)?;
     repo.insert(&command, &mut unit_of_work).await?;
 
-    unit_of_work.commit().await?;
-
-    Ok(BrandCreated {
+    let created = BrandCreated {
         brand_id,
         created_at: *command.metadata.created(),
-    })
+    };
+    outbox
+        .append(&DomainEvent::BrandCreated(created.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(created)
 }
 
 #[derive(Debug, Error)]
@@ -197,6 +210,7 @@ mod test {
     mod new_brand_command {
         use super::*;
         use crate::brands::commands::repositories::in_memory::InMemoryBrandRepository;
+        use crate::events::repositories::in_memory::InMemoryOutboxRepository;
         use chrono::TimeZone;
         use common::localized_text::LocalizedText;
         use common::unit_of_work::noop::NoOpDatabase;
@@ -206,14 +220,16 @@ mod test {
         #[tokio::test]
         async fn it_should_create_a_new_brand() {
             let repo = InMemoryBrandRepository::empty();
+            let outbox = InMemoryOutboxRepository::empty();
 
             let request = new_brand("ACME");
             let db = NoOpDatabase;
-            let result = create_new_brand(request, repo, db).await;
+            let result = create_new_brand(request, repo, outbox.clone(), db).await;
 
             let created = result.expect("result is an error");
 
             assert_eq!(BrandId::new("ACME"), created.brand_id);
+            assert_eq!(vec![DomainEvent::BrandCreated(created)], outbox.events());
         }
 
         #[tokio::test]
@@ -223,7 +239,7 @@ mod test {
 
             let request = new_brand("ACME");
             let db = NoOpDatabase;
-            let result = create_new_brand(request, repo, db).await;
+            let result = create_new_brand(request, repo, InMemoryOutboxRepository::empty(), db).await;
 
             match result {
                 Err(BrandCreationError::BrandAlreadyExists(id)) => assert_eq!(BrandId::new("ACME"), id),
@@ -3,6 +3,7 @@
 use crate::brands::brand_id::BrandId;
 use crate::brands::commands::new_brand::NewBrandCommand;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use common::unit_of_work::UnitOfWork;
 
 /// The persistence related functionality for the brand commands
@@ -15,12 +16,38 @@ pub trait NewBrandRepository<'db, U: UnitOfWork<'db>> {
     async fn insert(&self, new_brand: &NewBrandCommand, unit_of_work: &mut U) -> Result<(), anyhow::Error>;
 }
 
+/// The persistence related functionality for the brand soft deletion
+#[async_trait]
+pub trait DeleteBrandRepository<'db, U: UnitOfWork<'db>> {
+    /// Checks if the brand has been soft deleted, it returns `None` when the brand does not exist
+    async fn is_deleted(&self, brand_id: &BrandId, unit_of_work: &mut U) -> Result<Option<bool>, anyhow::Error>;
+
+    /// Marks the brand as deleted, it returns `false` when the brand does not exist or it is
+    /// already deleted
+    async fn soft_delete(
+        &self,
+        brand_id: &BrandId,
+        deleted_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error>;
+
+    /// Restores a soft deleted brand, it returns `false` when the brand does not exist or it is
+    /// not deleted
+    async fn restore(
+        &self,
+        brand_id: &BrandId,
+        last_modified_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error>;
+}
+
 #[cfg(test)]
 pub mod in_memory {
     use crate::brands::brand_id::BrandId;
     use crate::brands::commands::new_brand::NewBrandCommand;
-    use crate::brands::commands::repositories::NewBrandRepository;
+    use crate::brands::commands::repositories::{DeleteBrandRepository, NewBrandRepository};
     use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
     use common::in_memory::InMemoryRepository;
     use common::unit_of_work::noop::NoOpUnitOfWork;
 
@@ -56,4 +83,60 @@ pub mod in_memory {
             Ok(())
         }
     }
+
+    /// An in-memory brand repository, it keeps track of the brands deletion timestamps
+    pub struct InMemoryBrandDeletionRepository(InMemoryRepository<BrandId, Option<DateTime<Utc>>>);
+
+    impl InMemoryBrandDeletionRepository {
+        /// Creates an empty in memory brands repository
+        pub fn empty() -> Self {
+            InMemoryBrandDeletionRepository(InMemoryRepository::empty())
+        }
+
+        /// Creates a new in-memory brands repository with an initial element
+        pub fn with(brand_id: BrandId, deleted_at: Option<DateTime<Utc>>) -> Self {
+            InMemoryBrandDeletionRepository(InMemoryRepository::of(brand_id, deleted_at))
+        }
+    }
+
+    #[async_trait]
+    impl DeleteBrandRepository<'static, NoOpUnitOfWork> for InMemoryBrandDeletionRepository {
+        async fn is_deleted(
+            &self,
+            brand_id: &BrandId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Option<bool>, anyhow::Error> {
+            Ok(self.0.find_by_id(brand_id).map(|deleted_at| deleted_at.is_some()))
+        }
+
+        async fn soft_delete(
+            &self,
+            brand_id: &BrandId,
+            deleted_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            match self.0.find_by_id(brand_id) {
+                Some(None) => {
+                    self.0.add(brand_id.clone(), Some(*deleted_at));
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn restore(
+            &self,
+            brand_id: &BrandId,
+            _last_modified_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            match self.0.find_by_id(brand_id) {
+                Some(Some(_)) => {
+                    self.0.add(brand_id.clone(), None);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+    }
 }
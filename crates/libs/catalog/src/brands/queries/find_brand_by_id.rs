@@ -5,7 +5,12 @@ use common::queries::errors::{DatabaseError, QueryError};
 use common::unit_of_work::{Database, UnitOfWork};
 
 /// The query to find a modelling brand by its `brand_id`
-pub async fn find_brand_by_id<'db, U, Repo, DB>(brand_id: &BrandId, repo: Repo, db: DB) -> Result<Brand, QueryError>
+pub async fn find_brand_by_id<'db, U, Repo, DB>(
+    brand_id: &BrandId,
+    include_deleted: bool,
+    repo: Repo,
+    db: DB,
+) -> Result<Brand, QueryError>
 where
     U: UnitOfWork<'db>,
     Repo: FindBrandByIdRepository<'db, U>,
@@ -13,11 +18,11 @@ where
 {
     let mut unit_of_work = db.begin().await?;
 
-    let result = repo
-        .find_by_id(brand_id, &mut unit_of_work)
-        .await?
-        .map(Ok)
-        .unwrap_or_else(|| Err(QueryError::EmptyResultSet));
+    let result = match repo.find_by_id(brand_id, &mut unit_of_work).await? {
+        Some(brand) if brand.metadata.is_deleted() && !include_deleted => Err(QueryError::ResourceDeleted),
+        Some(brand) => Ok(brand),
+        None => Err(QueryError::EmptyResultSet),
+    };
 
     unit_of_work.commit().await?;
 
@@ -41,6 +46,7 @@ mod test {
 
     mod find_by_id_query {
         use super::*;
+        use chrono::Utc;
         use common::unit_of_work::noop::NoOpDatabase;
         use pretty_assertions::assert_eq;
 
@@ -48,7 +54,7 @@ mod test {
         async fn it_should_return_a_result_when_the_brand_is_found() {
             let repo = InMemoryFindBrandByIdRepository::with(brand_with_name("ACME"));
 
-            let result = find_brand_by_id(&BrandId::new("ACME"), repo, NoOpDatabase).await;
+            let result = find_brand_by_id(&BrandId::new("ACME"), false, repo, NoOpDatabase).await;
 
             assert!(result.is_ok());
             let result = result.unwrap();
@@ -60,18 +66,43 @@ mod test {
         async fn it_should_return_an_error_when_the_brand_is_not_found() {
             let repo = InMemoryFindBrandByIdRepository::new();
 
-            let result = find_brand_by_id(&BrandId::new("ACME"), repo, NoOpDatabase).await;
+            let result = find_brand_by_id(&BrandId::new("ACME"), false, repo, NoOpDatabase).await;
 
             assert!(result.is_err());
             let error = result.unwrap_err();
             assert_eq!("No results were found", error.to_string());
         }
 
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_brand_was_deleted() {
+            let mut brand = brand_with_name("ACME");
+            brand.metadata = brand.metadata.deleted_at(Utc::now());
+            let repo = InMemoryFindBrandByIdRepository::with(brand);
+
+            let result = find_brand_by_id(&BrandId::new("ACME"), false, repo, NoOpDatabase).await;
+
+            assert!(result.is_err());
+            let error = result.unwrap_err();
+            assert_eq!("The resource has been deleted", error.to_string());
+        }
+
+        #[tokio::test]
+        async fn it_should_return_deleted_brands_when_they_are_included() {
+            let mut brand = brand_with_name("ACME");
+            brand.metadata = brand.metadata.deleted_at(Utc::now());
+            let repo = InMemoryFindBrandByIdRepository::with(brand);
+
+            let result = find_brand_by_id(&BrandId::new("ACME"), true, repo, NoOpDatabase).await;
+
+            let result = result.expect("the brand was not found");
+            assert!(result.metadata.is_deleted());
+        }
+
         #[tokio::test]
         async fn it_should_return_an_error_when_the_query_fails() {
             let repo = FindBrandByIdRepositoryWithError;
 
-            let result = find_brand_by_id(&BrandId::new("ACME"), repo, NoOpDatabase).await;
+            let result = find_brand_by_id(&BrandId::new("ACME"), false, repo, NoOpDatabase).await;
 
             assert!(result.is_err());
             let error = result.unwrap_err();
@@ -0,0 +1,37 @@
+use crate::brands::brand::Brand;
+use crate::brands::brand_id::BrandId;
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+
+/// The query to find the modelling brands with the given ids, the soft deleted ones are included.
+///
+/// The modelling brands are returned in no particular order, the unknown ids are ignored.
+pub async fn find_brands_by_ids<'db, U, Repo, DB>(
+    brand_ids: &[BrandId],
+    repo: Repo,
+    db: DB,
+) -> Result<Vec<Brand>, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindBrandsByIdsRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    if brand_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut unit_of_work = db.begin().await?;
+
+    let result = repo.find_by_ids(brand_ids, &mut unit_of_work).await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(result)
+}
+
+/// The find modelling brands by ids repository
+#[async_trait]
+pub trait FindBrandsByIdsRepository<'db, U: UnitOfWork<'db>> {
+    async fn find_by_ids(&self, brand_ids: &[BrandId], unit_of_work: &mut U) -> Result<Vec<Brand>, DatabaseError>;
+}
@@ -2,3 +2,4 @@
 
 pub mod find_all_brands;
 pub mod find_brand_by_id;
+pub mod find_brands_by_ids;
@@ -0,0 +1,167 @@
+//! the catalog item availability lifecycle
+
+use crate::catalog_items::availability_status::AvailabilityStatus;
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use crate::catalog_items::delivery_date::DeliveryDate;
+use chrono::{DateTime, NaiveDate, Utc};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// It represents a change in the catalog item availability status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AvailabilityChange {
+    /// the new availability status
+    pub status: AvailabilityStatus,
+    /// the availability status before the change (missing for the first status)
+    pub previous_status: Option<AvailabilityStatus>,
+    /// an optional note for the change
+    pub note: Option<String>,
+    /// the change timestamp
+    pub changed_at: DateTime<Utc>,
+}
+
+/// It represents the current availability for a catalog item, together with its history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Availability {
+    /// the catalog item id
+    pub catalog_item_id: CatalogItemId,
+    /// the current availability status
+    pub status: Option<AvailabilityStatus>,
+    /// the delivery date
+    pub delivery_date: Option<DeliveryDate>,
+    /// whether the catalog item is still announced after its delivery date
+    pub overdue: bool,
+    /// the timestamp when the catalog item was flagged for editorial review
+    pub review_requested_at: Option<DateTime<Utc>>,
+    /// the availability status changes, the oldest first
+    pub history: Vec<AvailabilityChange>,
+}
+
+impl Availability {
+    /// Creates a new catalog item availability, without history
+    pub fn new(
+        catalog_item_id: CatalogItemId,
+        status: Option<AvailabilityStatus>,
+        delivery_date: Option<DeliveryDate>,
+        review_requested_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Availability {
+            catalog_item_id,
+            status,
+            delivery_date,
+            overdue: false,
+            review_requested_at,
+            history: Vec::new(),
+        }
+    }
+
+    /// Sets the availability status changes, the oldest first
+    pub fn with_history(mut self, history: Vec<AvailabilityChange>) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Computes the `overdue` flag as of `today`
+    pub fn as_of(mut self, today: NaiveDate) -> Self {
+        self.overdue = self
+            .status
+            .zip(self.delivery_date.as_ref())
+            .map(|(status, delivery_date)| is_overdue(status, delivery_date, today))
+            .unwrap_or(false);
+        self
+    }
+}
+
+/// A request to change the catalog item availability status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AvailabilityRequest {
+    /// the new availability status
+    pub status: AvailabilityStatus,
+    /// an optional note for the change
+    #[validate(length(max = 250))]
+    pub note: Option<String>,
+}
+
+/// Checks whether an announced catalog item was due for delivery before `today`
+pub fn is_overdue(status: AvailabilityStatus, delivery_date: &DeliveryDate, today: NaiveDate) -> bool {
+    status == AvailabilityStatus::Announced && delivery_date.last_day() < today
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod availability {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+        use std::str::FromStr;
+
+        fn today() -> NaiveDate {
+            NaiveDate::from_ymd_opt(2026, 10, 19).unwrap()
+        }
+
+        #[rstest]
+        #[case(AvailabilityStatus::Announced, "2026/09", true)]
+        #[case(AvailabilityStatus::Announced, "2026/Q3", true)]
+        #[case(AvailabilityStatus::Announced, "2026/10", false)]
+        #[case(AvailabilityStatus::Announced, "2026/Q4", false)]
+        #[case(AvailabilityStatus::Announced, "2026", false)]
+        #[case(AvailabilityStatus::Available, "2026/09", false)]
+        #[case(AvailabilityStatus::Discontinued, "2020", false)]
+        fn it_should_check_whether_catalog_items_are_overdue(
+            #[case] status: AvailabilityStatus,
+            #[case] delivery_date: &str,
+            #[case] expected: bool,
+        ) {
+            let delivery_date = DeliveryDate::from_str(delivery_date).unwrap();
+            assert_eq!(expected, is_overdue(status, &delivery_date, today()));
+        }
+
+        #[test]
+        fn it_should_create_the_availability() {
+            let change = AvailabilityChange {
+                status: AvailabilityStatus::Announced,
+                previous_status: None,
+                note: None,
+                changed_at: Utc::now(),
+            };
+            let availability = Availability::new(
+                CatalogItemId::from_str("acme-60142").unwrap(),
+                Some(AvailabilityStatus::Announced),
+                Some(DeliveryDate::by_quarter(2026, 2)),
+                None,
+            )
+            .with_history(vec![change.clone()]);
+
+            assert!(!availability.overdue);
+            assert_eq!(vec![change], availability.history);
+            assert!(availability.as_of(today()).overdue);
+        }
+
+        #[test]
+        fn it_should_never_be_overdue_without_availability_status() {
+            let availability = Availability::new(
+                CatalogItemId::from_str("acme-60142").unwrap(),
+                None,
+                Some(DeliveryDate::by_quarter(2026, 2)),
+                None,
+            )
+            .as_of(today());
+
+            assert!(!availability.overdue);
+        }
+
+        #[rstest]
+        #[case(None, true)]
+        #[case(Some("x".repeat(250)), true)]
+        #[case(Some("x".repeat(251)), false)]
+        fn it_should_validate_availability_requests(#[case] note: Option<String>, #[case] is_valid: bool) {
+            let request = AvailabilityRequest {
+                status: AvailabilityStatus::Available,
+                note,
+            };
+            assert_eq!(is_valid, request.validate().is_ok());
+        }
+    }
+}
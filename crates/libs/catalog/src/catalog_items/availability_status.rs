@@ -3,9 +3,10 @@
 use sqlx::Type;
 use strum_macros;
 use strum_macros::{Display, EnumString};
+use utoipa::ToSchema;
 
 /// The availability status for a catalog item
-#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "availability_status", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -21,6 +22,25 @@ pub enum AvailabilityStatus {
     Discontinued,
 }
 
+impl AvailabilityStatus {
+    /// Returns the statuses reachable from this one.
+    ///
+    /// An announced catalog item is either delivered or cancelled, and discontinued catalog items
+    /// can be available again with a new production run. No catalog item is announced twice.
+    pub fn transitions(&self) -> &'static [AvailabilityStatus] {
+        match self {
+            AvailabilityStatus::Announced => &[AvailabilityStatus::Available, AvailabilityStatus::Discontinued],
+            AvailabilityStatus::Available => &[AvailabilityStatus::Discontinued],
+            AvailabilityStatus::Discontinued => &[AvailabilityStatus::Available],
+        }
+    }
+
+    /// Checks whether a catalog item with this status can move to the `status`
+    pub fn can_transition_to(&self, status: AvailabilityStatus) -> bool {
+        self.transitions().contains(&status)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -50,5 +70,23 @@ mod tests {
         fn it_should_display_dcc_interfaces(#[case] input: AvailabilityStatus, #[case] expected: &str) {
             assert_eq!(expected, input.to_string());
         }
+
+        #[rstest]
+        #[case(AvailabilityStatus::Announced, AvailabilityStatus::Announced, false)]
+        #[case(AvailabilityStatus::Announced, AvailabilityStatus::Available, true)]
+        #[case(AvailabilityStatus::Announced, AvailabilityStatus::Discontinued, true)]
+        #[case(AvailabilityStatus::Available, AvailabilityStatus::Announced, false)]
+        #[case(AvailabilityStatus::Available, AvailabilityStatus::Available, false)]
+        #[case(AvailabilityStatus::Available, AvailabilityStatus::Discontinued, true)]
+        #[case(AvailabilityStatus::Discontinued, AvailabilityStatus::Announced, false)]
+        #[case(AvailabilityStatus::Discontinued, AvailabilityStatus::Available, true)]
+        #[case(AvailabilityStatus::Discontinued, AvailabilityStatus::Discontinued, false)]
+        fn it_should_check_the_status_transitions(
+            #[case] from: AvailabilityStatus,
+            #[case] to: AvailabilityStatus,
+            #[case] expected: bool,
+        ) {
+            assert_eq!(expected, from.can_transition_to(to));
+        }
     }
 }
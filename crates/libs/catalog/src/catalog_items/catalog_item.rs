@@ -16,11 +16,12 @@ use common::localized_text::LocalizedText;
 use common::metadata::Metadata;
 use std::fmt::Formatter;
 use std::{cmp, convert, fmt};
+use utoipa::ToSchema;
 
 /// A catalog item, it can contain one or more rolling stock.
 ///
 /// A catalog item is identified by its catalog item number.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CatalogItem {
     /// the unique identifier for this catalog item
     pub catalog_item_id: CatalogItemId,
@@ -185,7 +186,7 @@ impl CatalogItem {
 }
 
 /// The model railways manufacturer for a catalog item
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CatalogItemBrand {
     /// the brand unique identifier
     pub brand_id: BrandId,
@@ -229,7 +230,7 @@ impl convert::From<Brand> for CatalogItemBrand {
 }
 
 /// The modelling scale for a catalog item
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CatalogItemScale {
     /// the scale unique identifier
     pub scale_id: ScaleId,
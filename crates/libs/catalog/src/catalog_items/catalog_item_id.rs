@@ -9,9 +9,10 @@ use std::fmt;
 use std::fmt::Formatter;
 use std::ops::Deref;
 use std::str::FromStr;
+use utoipa::ToSchema;
 
 /// A unique identifier for a catalog item
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Hash, Type)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Hash, Type, ToSchema)]
 #[sqlx(transparent)]
 pub struct CatalogItemId(Slug);
 
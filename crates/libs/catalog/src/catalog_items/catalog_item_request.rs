@@ -4,14 +4,17 @@ use crate::catalog_items::availability_status::AvailabilityStatus;
 use crate::catalog_items::category::Category;
 use crate::catalog_items::delivery_date::DeliveryDate;
 use crate::catalog_items::epoch::Epoch;
+use crate::catalog_items::epoch_calendar::EpochCalendar;
 use crate::catalog_items::item_number::ItemNumber;
 use crate::catalog_items::power_method::PowerMethod;
 use crate::catalog_items::rolling_stock_request::RollingStockRequest;
+use chrono::NaiveDate;
 use common::localized_text::LocalizedText;
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// A request to create/update catalog items
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CatalogItemRequest {
     /// the brand
     #[validate(length(min = 3, max = 50))]
@@ -46,18 +49,52 @@ pub struct CatalogItemRequest {
     pub count: i32,
 }
 
+impl CatalogItemRequest {
+    /// Returns the warnings for the rolling stocks in this request, they don't prevent the request to be accepted
+    pub fn warnings(&self) -> Vec<String> {
+        self.rolling_stocks_warnings(RollingStockRequest::warnings)
+    }
+
+    /// Returns the date for the vehicle keeper markings in the road numbers: the last day of the epoch
+    /// (in the European calendar), or the delivery date for the current epoch
+    pub fn reference_date(&self) -> Option<NaiveDate> {
+        EpochCalendar::european()
+            .period_of(&self.epoch)
+            .and_then(|period| period.end_date())
+            .or_else(|| self.delivery_date.as_ref().map(DeliveryDate::first_day))
+    }
+
+    fn rolling_stocks_warnings<F>(&self, f: F) -> Vec<String>
+    where
+        F: Fn(&RollingStockRequest) -> Vec<String>,
+    {
+        self.rolling_stocks
+            .iter()
+            .enumerate()
+            .flat_map(|(index, rolling_stock)| {
+                f(rolling_stock)
+                    .into_iter()
+                    .map(move |warning| format!("rolling_stocks[{index}]: {warning}"))
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod test {
     mod catalog_item_request_validation {
         use crate::catalog_items::catalog_item_request::CatalogItemRequest;
         use crate::catalog_items::category::Category;
+        use crate::catalog_items::delivery_date::DeliveryDate;
         use crate::catalog_items::epoch::Epoch;
         use crate::catalog_items::item_number::{invalid_item_number, ItemNumber};
         use crate::catalog_items::power_method::PowerMethod;
         use crate::catalog_items::rolling_stock_request::data::{
             freight_car_request, locomotive_request, passenger_car_request,
         };
+        use crate::catalog_items::rolling_stock_request::RollingStockRequest;
         use crate::test_helpers::{random_str, unwrap_map};
+        use chrono::NaiveDate;
         use common::localized_text::LocalizedText;
         use pretty_assertions::assert_eq;
         use rstest::rstest;
@@ -203,6 +240,62 @@ mod test {
             }
         }
 
+        #[test]
+        fn it_should_return_the_rolling_stocks_warnings() {
+            let freight_car = match freight_car_request() {
+                RollingStockRequest::FreightCarRequest {
+                    railway,
+                    livery,
+                    length_over_buffers,
+                    technical_specifications,
+                    prototype,
+                    type_name,
+                    freight_car_type,
+                    ..
+                } => RollingStockRequest::FreightCarRequest {
+                    railway,
+                    livery,
+                    length_over_buffers,
+                    technical_specifications,
+                    prototype,
+                    type_name,
+                    road_number: Some(String::from("31 80 665 1 234-5")),
+                    freight_car_type,
+                },
+                _ => unreachable!(),
+            };
+            let request = CatalogItemRequest {
+                rolling_stocks: vec![locomotive_request(), freight_car],
+                ..catalog_item_request()
+            };
+
+            assert_eq!(
+                vec![String::from(
+                    "rolling_stocks[1]: the road number '31 80 665 1 234-5' has an invalid UIC self-check digit (expected: 0)"
+                )],
+                request.warnings()
+            );
+            assert!(catalog_item_request().warnings().is_empty());
+        }
+
+        #[rstest]
+        #[case(Epoch::IV, None, NaiveDate::from_ymd_opt(1990, 12, 31))]
+        #[case(Epoch::IV, Some(DeliveryDate::by_year(2024)), NaiveDate::from_ymd_opt(1990, 12, 31))]
+        #[case(Epoch::VI, Some(DeliveryDate::by_year(2024)), NaiveDate::from_ymd_opt(2024, 1, 1))]
+        #[case(Epoch::VI, None, None)]
+        fn it_should_return_the_reference_date(
+            #[case] epoch: Epoch,
+            #[case] delivery_date: Option<DeliveryDate>,
+            #[case] expected: Option<NaiveDate>,
+        ) {
+            let request = CatalogItemRequest {
+                epoch,
+                delivery_date,
+                ..catalog_item_request()
+            };
+            assert_eq!(expected, request.reference_date());
+        }
+
         fn catalog_item_request() -> CatalogItemRequest {
             CatalogItemRequest {
                 brand: "ACME".to_string(),
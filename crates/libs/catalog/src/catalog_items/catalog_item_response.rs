@@ -1,18 +1,38 @@
 //! the catalog item command responses
 
+use crate::catalog_items::availability_status::AvailabilityStatus;
 use crate::catalog_items::catalog_item_id::CatalogItemId;
 use chrono::{DateTime, Utc};
 
 /// A response for new catalog items creation
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CatalogItemCreated {
     pub catalog_item_id: CatalogItemId,
     pub created_at: DateTime<Utc>,
+    /// the request warnings, they are not part of the domain event
+    #[serde(skip)]
+    pub warnings: Vec<String>,
 }
 
 /// A response for catalog items update
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CatalogItemUpdated {
     pub catalog_item_id: CatalogItemId,
     pub last_modified_at: DateTime<Utc>,
 }
+
+/// A response for catalog items soft deletion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogItemDeleted {
+    pub catalog_item_id: CatalogItemId,
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// A response for catalog items availability status changes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogItemAvailabilityChanged {
+    pub catalog_item_id: CatalogItemId,
+    pub status: AvailabilityStatus,
+    pub previous_status: Option<AvailabilityStatus>,
+    pub changed_at: DateTime<Utc>,
+}
@@ -3,9 +3,10 @@
 use sqlx::Type;
 use strum_macros;
 use strum_macros::{Display, EnumString};
+use utoipa::ToSchema;
 
 /// The enumeration of the model categories.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "catalog_item_category", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -34,7 +35,7 @@ pub enum Category {
 }
 
 /// The enumeration of the rolling stock categories.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "rolling_stock_category", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -57,7 +58,7 @@ pub enum RollingStockCategory {
 }
 
 /// The different kind of freight cars
-#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "freight_car_type", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -85,7 +86,7 @@ pub enum FreightCarType {
 }
 
 /// The different kinds of locomotives
-#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "locomotive_type", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -102,7 +103,7 @@ pub enum LocomotiveType {
 }
 
 /// The types for passenger car rolling stocks
-#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "passenger_car_type", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -157,7 +158,7 @@ pub enum PassengerCarType {
 /// power car, motor car, driving car, and trailer car.
 ///
 /// Each car can have more than one function, such as a motor-driving car or power-driving car.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "electric_multiple_unit_type", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -199,7 +200,7 @@ pub enum ElectricMultipleUnitType {
 ///
 /// In its simplest form, a "railcar" may also be little more than a motorized railway handcar
 /// or draisine.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "railcar_type", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -0,0 +1,289 @@
+//! the catalog item availability commands
+
+use crate::catalog_items::availability::{is_overdue, AvailabilityChange, AvailabilityRequest};
+use crate::catalog_items::availability_status::AvailabilityStatus;
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use crate::catalog_items::catalog_item_response::CatalogItemAvailabilityChanged;
+use crate::catalog_items::commands::repositories::AvailabilityRepository;
+use crate::events::domain_event::DomainEvent;
+use crate::events::repositories::OutboxRepository;
+use chrono::{DateTime, Datelike, Utc};
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+use validator::{Validate, ValidationErrors};
+
+pub type Result<R> = result::Result<R, AvailabilityChangeError>;
+
+/// Changes the catalog item availability status, the change is appended to the catalog item history.
+///
+/// Only the transitions allowed by [`AvailabilityStatus::can_transition_to`] are accepted, while catalog
+/// items without an availability status can move to any status.
+pub async fn change_availability_status<'db, U, Repo, O, DB>(
+    catalog_item_id: &CatalogItemId,
+    request: AvailabilityRequest,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<CatalogItemAvailabilityChanged>
+where
+    U: UnitOfWork<'db>,
+    Repo: AvailabilityRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    request.validate().map_err(AvailabilityChangeError::InvalidRequest)?;
+
+    let mut unit_of_work = db.begin().await?;
+
+    let previous_status = repo
+        .find_status(catalog_item_id, &mut unit_of_work)
+        .await?
+        .ok_or_else(|| AvailabilityChangeError::CatalogItemNotFound(catalog_item_id.clone()))?;
+
+    if let Some(previous_status) = previous_status {
+        if !previous_status.can_transition_to(request.status) {
+            return Err(AvailabilityChangeError::InvalidTransition(
+                previous_status,
+                request.status,
+            ));
+        }
+    }
+
+    let change = AvailabilityChange {
+        status: request.status,
+        previous_status,
+        note: request.note,
+        changed_at: Utc::now(),
+    };
+    repo.change_status(catalog_item_id, &change, &mut unit_of_work).await?;
+
+    let changed = CatalogItemAvailabilityChanged {
+        catalog_item_id: catalog_item_id.clone(),
+        status: change.status,
+        previous_status: change.previous_status,
+        changed_at: change.changed_at,
+    };
+    outbox
+        .append(
+            &DomainEvent::CatalogItemAvailabilityChanged(changed.clone()),
+            &mut unit_of_work,
+        )
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(changed)
+}
+
+/// Flags for editorial review the announced catalog items whose delivery date has passed, it returns
+/// the flagged catalog items.
+///
+/// The catalog items already flagged are skipped, the flag is cleared when the availability status changes.
+pub async fn request_overdue_reviews<'db, U, Repo, DB>(
+    now: DateTime<Utc>,
+    repo: Repo,
+    db: DB,
+) -> Result<Vec<CatalogItemId>>
+where
+    U: UnitOfWork<'db>,
+    Repo: AvailabilityRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let today = now.date_naive();
+
+    let mut unit_of_work = db.begin().await?;
+
+    let overdue: Vec<CatalogItemId> = repo
+        .find_announced(today.year(), &mut unit_of_work)
+        .await?
+        .into_iter()
+        .filter(|(_, delivery_date)| is_overdue(AvailabilityStatus::Announced, delivery_date, today))
+        .map(|(catalog_item_id, _)| catalog_item_id)
+        .collect();
+
+    if !overdue.is_empty() {
+        repo.request_review(&overdue, &now, &mut unit_of_work).await?;
+    }
+
+    unit_of_work.commit().await?;
+
+    Ok(overdue)
+}
+
+#[derive(Debug, Error)]
+pub enum AvailabilityChangeError {
+    #[error("The availability request is not valid")]
+    InvalidRequest(ValidationErrors),
+
+    #[error("The catalog item was not found (id: {0})")]
+    CatalogItemNotFound(CatalogItemId),
+
+    #[error("The availability status cannot change from {0} to {1}")]
+    InvalidTransition(AvailabilityStatus, AvailabilityStatus),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalog_items::commands::repositories::in_memory::InMemoryAvailabilityRepository;
+    use crate::catalog_items::delivery_date::DeliveryDate;
+    use crate::events::repositories::in_memory::InMemoryOutboxRepository;
+    use common::unit_of_work::noop::NoOpDatabase;
+    use std::str::FromStr;
+
+    mod change_availability_status_command {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_change_the_availability_status() {
+            let repo = InMemoryAvailabilityRepository::with(
+                catalog_item_id(),
+                Some(AvailabilityStatus::Announced),
+                Some(DeliveryDate::by_year(2026)),
+            );
+            let outbox = InMemoryOutboxRepository::empty();
+            let request = AvailabilityRequest {
+                status: AvailabilityStatus::Available,
+                note: Some(String::from("delivered to the shops")),
+            };
+
+            let result =
+                change_availability_status(&catalog_item_id(), request, repo.clone(), outbox.clone(), NoOpDatabase)
+                    .await;
+
+            let changed = result.expect("result is an error");
+            assert_eq!(catalog_item_id(), changed.catalog_item_id);
+            assert_eq!(AvailabilityStatus::Available, changed.status);
+            assert_eq!(Some(AvailabilityStatus::Announced), changed.previous_status);
+
+            let availability = repo.find(&catalog_item_id()).unwrap();
+            assert_eq!(Some(AvailabilityStatus::Available), availability.status);
+            assert_eq!(1, availability.history.len());
+            assert_eq!(
+                Some(String::from("delivered to the shops")),
+                availability.history[0].note
+            );
+            assert_eq!(1, outbox.events().len());
+        }
+
+        #[tokio::test]
+        async fn it_should_set_the_first_availability_status() {
+            let repo = InMemoryAvailabilityRepository::with(catalog_item_id(), None, None);
+            let request = AvailabilityRequest {
+                status: AvailabilityStatus::Discontinued,
+                note: None,
+            };
+
+            let result = change_availability_status(
+                &catalog_item_id(),
+                request,
+                repo.clone(),
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let changed = result.expect("result is an error");
+            assert_eq!(AvailabilityStatus::Discontinued, changed.status);
+            assert_eq!(None, changed.previous_status);
+        }
+
+        #[tokio::test]
+        async fn it_should_reject_invalid_transitions() {
+            let repo =
+                InMemoryAvailabilityRepository::with(catalog_item_id(), Some(AvailabilityStatus::Available), None);
+            let request = AvailabilityRequest {
+                status: AvailabilityStatus::Announced,
+                note: None,
+            };
+
+            let result = change_availability_status(
+                &catalog_item_id(),
+                request,
+                repo.clone(),
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(AvailabilityChangeError::InvalidTransition(from, to)) => {
+                    assert_eq!(AvailabilityStatus::Available, from);
+                    assert_eq!(AvailabilityStatus::Announced, to);
+                }
+                _ => panic!("InvalidTransition is expected (found: {:?})", result),
+            }
+            assert!(repo.find(&catalog_item_id()).unwrap().history.is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_catalog_item_does_not_exist() {
+            let request = AvailabilityRequest {
+                status: AvailabilityStatus::Available,
+                note: None,
+            };
+
+            let result = change_availability_status(
+                &catalog_item_id(),
+                request,
+                InMemoryAvailabilityRepository::empty(),
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(AvailabilityChangeError::CatalogItemNotFound(id)) => assert_eq!(catalog_item_id(), id),
+                _ => panic!("CatalogItemNotFound is expected (found: {:?})", result),
+            }
+        }
+    }
+
+    mod request_overdue_reviews_command {
+        use super::*;
+        use chrono::TimeZone;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_flag_the_overdue_catalog_items() {
+            let now = Utc.with_ymd_and_hms(2026, 10, 19, 3, 0, 0).unwrap();
+            let overdue = CatalogItemId::from_str("acme-60142").unwrap();
+            let repo = InMemoryAvailabilityRepository::with(
+                overdue.clone(),
+                Some(AvailabilityStatus::Announced),
+                Some(DeliveryDate::by_quarter(2026, 3)),
+            )
+            .and(
+                CatalogItemId::from_str("acme-60143").unwrap(),
+                AvailabilityStatus::Announced,
+                DeliveryDate::by_quarter(2026, 4),
+            )
+            .and(
+                CatalogItemId::from_str("acme-60144").unwrap(),
+                AvailabilityStatus::Available,
+                DeliveryDate::by_year(2025),
+            );
+
+            let result = request_overdue_reviews(now, repo.clone(), NoOpDatabase).await;
+
+            assert_eq!(vec![overdue.clone()], result.expect("result is an error"));
+            assert_eq!(Some(now), repo.find(&overdue).unwrap().review_requested_at);
+
+            let result = request_overdue_reviews(now, repo.clone(), NoOpDatabase).await;
+            assert!(result.expect("result is an error").is_empty());
+        }
+    }
+
+    fn catalog_item_id() -> CatalogItemId {
+        CatalogItemId::from_str("acme-60142").unwrap()
+    }
+}
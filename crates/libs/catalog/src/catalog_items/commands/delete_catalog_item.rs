@@ -0,0 +1,217 @@
+//! the catalog item soft deletion and restore commands
+
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use crate::catalog_items::catalog_item_response::{CatalogItemDeleted, CatalogItemUpdated};
+use crate::catalog_items::commands::repositories::DeleteCatalogItemRepository;
+use crate::events::domain_event::DomainEvent;
+use crate::events::repositories::OutboxRepository;
+use chrono::Utc;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+
+pub type Result<R> = result::Result<R, CatalogItemDeletionError>;
+
+/// Soft deletes the catalog item (including its rolling stocks), the catalog item is not removed from the database
+/// and it can be restored later
+pub async fn delete_catalog_item<'db, U, Repo, O, DB>(
+    catalog_item_id: &CatalogItemId,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<CatalogItemDeleted>
+where
+    U: UnitOfWork<'db>,
+    Repo: DeleteCatalogItemRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let deleted_at = Utc::now();
+    if !repo
+        .soft_delete(catalog_item_id, &deleted_at, &mut unit_of_work)
+        .await?
+    {
+        return match repo.is_deleted(catalog_item_id, &mut unit_of_work).await? {
+            None => Err(CatalogItemDeletionError::CatalogItemNotFound(catalog_item_id.clone())),
+            Some(_) => Err(CatalogItemDeletionError::CatalogItemAlreadyDeleted(
+                catalog_item_id.clone(),
+            )),
+        };
+    }
+
+    let deleted = CatalogItemDeleted {
+        catalog_item_id: catalog_item_id.clone(),
+        deleted_at,
+    };
+    outbox
+        .append(&DomainEvent::CatalogItemDeleted(deleted.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(deleted)
+}
+
+/// Restores a soft deleted catalog item, together with the rolling stocks deleted with it
+pub async fn restore_catalog_item<'db, U, Repo, O, DB>(
+    catalog_item_id: &CatalogItemId,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<CatalogItemUpdated>
+where
+    U: UnitOfWork<'db>,
+    Repo: DeleteCatalogItemRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let last_modified_at = Utc::now();
+    if !repo
+        .restore(catalog_item_id, &last_modified_at, &mut unit_of_work)
+        .await?
+    {
+        return match repo.is_deleted(catalog_item_id, &mut unit_of_work).await? {
+            None => Err(CatalogItemDeletionError::CatalogItemNotFound(catalog_item_id.clone())),
+            Some(_) => Err(CatalogItemDeletionError::CatalogItemNotDeleted(catalog_item_id.clone())),
+        };
+    }
+
+    let restored = CatalogItemUpdated {
+        catalog_item_id: catalog_item_id.clone(),
+        last_modified_at,
+    };
+    outbox
+        .append(&DomainEvent::CatalogItemRestored(restored.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(restored)
+}
+
+#[derive(Debug, Error)]
+pub enum CatalogItemDeletionError {
+    #[error("The catalog item was not found (id: {0})")]
+    CatalogItemNotFound(CatalogItemId),
+
+    #[error("The catalog item has been already deleted (id: {0})")]
+    CatalogItemAlreadyDeleted(CatalogItemId),
+
+    #[error("The catalog item is not deleted (id: {0})")]
+    CatalogItemNotDeleted(CatalogItemId),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod delete_catalog_item_command {
+        use super::*;
+        use crate::catalog_items::commands::repositories::in_memory::InMemoryCatalogItemDeletionRepository;
+        use crate::events::repositories::in_memory::InMemoryOutboxRepository;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+        use std::str::FromStr;
+
+        #[tokio::test]
+        async fn it_should_delete_a_catalog_item() {
+            let repo = InMemoryCatalogItemDeletionRepository::with(catalog_item_id(), None);
+
+            let result = delete_catalog_item(
+                &catalog_item_id(),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let deleted = result.expect("result is an error");
+            assert_eq!(catalog_item_id(), deleted.catalog_item_id);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_deleting_a_catalog_item_that_does_not_exist() {
+            let repo = InMemoryCatalogItemDeletionRepository::empty();
+
+            let result = delete_catalog_item(
+                &catalog_item_id(),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(CatalogItemDeletionError::CatalogItemNotFound(id)) => assert_eq!(catalog_item_id(), id),
+                _ => panic!("CatalogItemNotFound is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_deleting_a_catalog_item_twice() {
+            let repo = InMemoryCatalogItemDeletionRepository::with(catalog_item_id(), Some(Utc::now()));
+
+            let result = delete_catalog_item(
+                &catalog_item_id(),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(CatalogItemDeletionError::CatalogItemAlreadyDeleted(id)) => assert_eq!(catalog_item_id(), id),
+                _ => panic!("CatalogItemAlreadyDeleted is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_restore_a_deleted_catalog_item() {
+            let repo = InMemoryCatalogItemDeletionRepository::with(catalog_item_id(), Some(Utc::now()));
+
+            let result = restore_catalog_item(
+                &catalog_item_id(),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let restored = result.expect("result is an error");
+            assert_eq!(catalog_item_id(), restored.catalog_item_id);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_restoring_a_catalog_item_which_is_not_deleted() {
+            let repo = InMemoryCatalogItemDeletionRepository::with(catalog_item_id(), None);
+
+            let result = restore_catalog_item(
+                &catalog_item_id(),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(CatalogItemDeletionError::CatalogItemNotDeleted(id)) => assert_eq!(catalog_item_id(), id),
+                _ => panic!("CatalogItemNotDeleted is expected (found: {:?})", result),
+            }
+        }
+
+        fn catalog_item_id() -> CatalogItemId {
+            CatalogItemId::from_str("acme-123456").unwrap()
+        }
+    }
+}
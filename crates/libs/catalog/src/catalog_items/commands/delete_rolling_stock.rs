@@ -0,0 +1,233 @@
+//! the rolling stock soft deletion and restore commands
+
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use crate::catalog_items::commands::repositories::DeleteRollingStockRepository;
+use crate::catalog_items::rolling_stock_id::RollingStockId;
+use crate::catalog_items::rolling_stock_response::{RollingStockDeleted, RollingStockUpdated};
+use crate::events::domain_event::DomainEvent;
+use crate::events::repositories::OutboxRepository;
+use chrono::Utc;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+
+pub type Result<R> = result::Result<R, RollingStockDeletionError>;
+
+/// Soft deletes the rolling stock, the rolling stock is not removed from the database and it can be restored later
+pub async fn delete_rolling_stock<'db, U, Repo, O, DB>(
+    catalog_item_id: &CatalogItemId,
+    rolling_stock_id: &RollingStockId,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<RollingStockDeleted>
+where
+    U: UnitOfWork<'db>,
+    Repo: DeleteRollingStockRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let deleted_at = Utc::now();
+    if !repo
+        .soft_delete(catalog_item_id, rolling_stock_id, &deleted_at, &mut unit_of_work)
+        .await?
+    {
+        return match repo
+            .is_deleted(catalog_item_id, rolling_stock_id, &mut unit_of_work)
+            .await?
+        {
+            None => Err(RollingStockDeletionError::RollingStockNotFound(*rolling_stock_id)),
+            Some(_) => Err(RollingStockDeletionError::RollingStockAlreadyDeleted(*rolling_stock_id)),
+        };
+    }
+
+    let deleted = RollingStockDeleted {
+        rolling_stock_id: *rolling_stock_id,
+        deleted_at,
+    };
+    outbox
+        .append(&DomainEvent::RollingStockDeleted(deleted.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(deleted)
+}
+
+/// Restores a soft deleted rolling stock
+pub async fn restore_rolling_stock<'db, U, Repo, O, DB>(
+    catalog_item_id: &CatalogItemId,
+    rolling_stock_id: &RollingStockId,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<RollingStockUpdated>
+where
+    U: UnitOfWork<'db>,
+    Repo: DeleteRollingStockRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let last_modified_at = Utc::now();
+    if !repo
+        .restore(catalog_item_id, rolling_stock_id, &last_modified_at, &mut unit_of_work)
+        .await?
+    {
+        return match repo
+            .is_deleted(catalog_item_id, rolling_stock_id, &mut unit_of_work)
+            .await?
+        {
+            None => Err(RollingStockDeletionError::RollingStockNotFound(*rolling_stock_id)),
+            Some(_) => Err(RollingStockDeletionError::RollingStockNotDeleted(*rolling_stock_id)),
+        };
+    }
+
+    let restored = RollingStockUpdated {
+        rolling_stock_id: *rolling_stock_id,
+        last_modified_at,
+    };
+    outbox
+        .append(&DomainEvent::RollingStockRestored(restored.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(restored)
+}
+
+#[derive(Debug, Error)]
+pub enum RollingStockDeletionError {
+    #[error("The rolling stock was not found (id: {0})")]
+    RollingStockNotFound(RollingStockId),
+
+    #[error("The rolling stock has been already deleted (id: {0})")]
+    RollingStockAlreadyDeleted(RollingStockId),
+
+    #[error("The rolling stock is not deleted (id: {0})")]
+    RollingStockNotDeleted(RollingStockId),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod delete_rolling_stock_command {
+        use super::*;
+        use crate::catalog_items::commands::repositories::in_memory::InMemoryRollingStockDeletionRepository;
+        use crate::events::repositories::in_memory::InMemoryOutboxRepository;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+        use std::str::FromStr;
+
+        #[tokio::test]
+        async fn it_should_delete_a_rolling_stock() {
+            let rolling_stock_id = RollingStockId::new();
+            let repo = InMemoryRollingStockDeletionRepository::with(rolling_stock_id, None);
+
+            let result = delete_rolling_stock(
+                &catalog_item_id(),
+                &rolling_stock_id,
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let deleted = result.expect("result is an error");
+            assert_eq!(rolling_stock_id, deleted.rolling_stock_id);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_deleting_a_rolling_stock_that_does_not_exist() {
+            let rolling_stock_id = RollingStockId::new();
+            let repo = InMemoryRollingStockDeletionRepository::empty();
+
+            let result = delete_rolling_stock(
+                &catalog_item_id(),
+                &rolling_stock_id,
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(RollingStockDeletionError::RollingStockNotFound(id)) => assert_eq!(rolling_stock_id, id),
+                _ => panic!("RollingStockNotFound is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_deleting_a_rolling_stock_twice() {
+            let rolling_stock_id = RollingStockId::new();
+            let repo = InMemoryRollingStockDeletionRepository::with(rolling_stock_id, Some(Utc::now()));
+
+            let result = delete_rolling_stock(
+                &catalog_item_id(),
+                &rolling_stock_id,
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(RollingStockDeletionError::RollingStockAlreadyDeleted(id)) => assert_eq!(rolling_stock_id, id),
+                _ => panic!("RollingStockAlreadyDeleted is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_restore_a_deleted_rolling_stock() {
+            let rolling_stock_id = RollingStockId::new();
+            let repo = InMemoryRollingStockDeletionRepository::with(rolling_stock_id, Some(Utc::now()));
+
+            let result = restore_rolling_stock(
+                &catalog_item_id(),
+                &rolling_stock_id,
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let restored = result.expect("result is an error");
+            assert_eq!(rolling_stock_id, restored.rolling_stock_id);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_restoring_a_rolling_stock_which_is_not_deleted() {
+            let rolling_stock_id = RollingStockId::new();
+            let repo = InMemoryRollingStockDeletionRepository::with(rolling_stock_id, None);
+
+            let result = restore_rolling_stock(
+                &catalog_item_id(),
+                &rolling_stock_id,
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(RollingStockDeletionError::RollingStockNotDeleted(id)) => assert_eq!(rolling_stock_id, id),
+                _ => panic!("RollingStockNotDeleted is expected (found: {:?})", result),
+            }
+        }
+
+        fn catalog_item_id() -> CatalogItemId {
+            CatalogItemId::from_str("acme-123456").unwrap()
+        }
+    }
+}
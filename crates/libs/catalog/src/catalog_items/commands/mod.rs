@@ -1,4 +1,7 @@
 //! the catalog item commands
 
+pub mod change_availability;
+pub mod delete_catalog_item;
+pub mod delete_rolling_stock;
 pub mod new_catalog_item;
 pub mod repositories;
@@ -19,7 +19,14 @@ use crate::catalog_items::service_level::ServiceLevel;
 use crate::catalog_items::technical_specifications::{
     BodyShellType, ChassisType, Coupling, CouplingSocket, FeatureFlag, Radius,
 };
+use crate::catalog_items::uic_vehicle_number::UicVehicleNumber;
+use crate::events::domain_event::DomainEvent;
+use crate::events::repositories::OutboxRepository;
+use crate::prototypes::prototype_id::PrototypeId;
+use crate::railways::queries::find_railway_by_id::FindRailwayByIdRepository;
+use crate::railways::queries::find_vehicle_keeper::FindVehicleKeeperRepository;
 use crate::railways::railway_id::RailwayId;
+use crate::railways::vehicle_keeper_marking::check_vehicle_keeper;
 use crate::scales::scale_id::ScaleId;
 use chrono::Utc;
 use common::length::Length;
@@ -33,18 +40,26 @@ use validator::{Validate, ValidationErrors};
 
 pub type Result<R> = result::Result<R, CatalogItemCreationError>;
 
-pub async fn create_new_catalog_item<'db, U, R, RR, DB>(
+/// Creates a new catalog item, the request warnings (ie, road numbers with an invalid UIC self-check digit or
+/// belonging to a different railway) are returned together with the created catalog item.
+pub async fn create_new_catalog_item<'db, U, R, RR, K, O, DB>(
     request: CatalogItemRequest,
     repo: R,
     rs_repo: RR,
+    railways_repo: K,
+    outbox: O,
     db: DB,
 ) -> Result<CatalogItemCreated>
 where
     U: UnitOfWork<'db>,
     R: NewCatalogItemRepository<'db, U>,
     RR: NewRollingStockRepository<'db, U>,
+    K: FindRailwayByIdRepository<'db, U> + FindVehicleKeeperRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
     DB: Database<'db, U>,
 {
+    validate_request(&request)?;
+
     let brand_id = BrandId::new(&request.brand);
     let scale_id = ScaleId::new(&request.scale);
     let catalog_item_id = CatalogItemId::of(&brand_id, &request.item_number);
@@ -63,6 +78,9 @@ where
         return Err(CatalogItemCreationError::ScaleNotFound(scale_id));
     }
 
+    let mut warnings = request.warnings();
+    warnings.extend(check_vehicle_keepers(&request, &railways_repo, &mut unit_of_work).await?);
+
     let command = NewCatalogItemCommand::try_from(request)?;
 
     repo.insert(&command, &mut unit_of_work).await?;
@@ -71,15 +89,75 @@ where
         if !rs_repo.railway_exists(&rs.railway_id, &mut unit_of_work).await? {
             return Err(CatalogItemCreationError::RailwayNotFound(rs.railway_id));
         }
+        if let Some(prototype_id) = &rs.payload.prototype_id {
+            if !rs_repo.prototype_exists(prototype_id, &mut unit_of_work).await? {
+                return Err(CatalogItemCreationError::PrototypeNotFound(prototype_id.clone()));
+            }
+        }
         rs_repo.insert(&rs, &mut unit_of_work).await?;
     }
 
-    unit_of_work.commit().await?;
-
-    Ok(CatalogItemCreated {
+    let created = CatalogItemCreated {
         catalog_item_id,
         created_at: *command.metadata.created(),
-    })
+        warnings: Vec::new(),
+    };
+    outbox
+        .append(&DomainEvent::CatalogItemCreated(created.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(CatalogItemCreated { warnings, ..created })
+}
+
+/// Checks the rolling stocks road numbers against their railways, it returns a warning for each
+/// road number belonging to a different railway.
+///
+/// The vehicle keeper markings are matched at the request reference date, the ones not in use at that date
+/// do not belong to any railway.
+async fn check_vehicle_keepers<'db, U, K>(
+    request: &CatalogItemRequest,
+    railways_repo: &K,
+    unit_of_work: &mut U,
+) -> result::Result<Vec<String>, DatabaseError>
+where
+    U: UnitOfWork<'db>,
+    K: FindRailwayByIdRepository<'db, U> + FindVehicleKeeperRepository<'db, U>,
+{
+    let date = request.reference_date();
+
+    let mut warnings = Vec::new();
+    for (index, rolling_stock) in request.rolling_stocks.iter().enumerate() {
+        let Some(road_number) = rolling_stock.road_number() else {
+            continue;
+        };
+        let Ok(uic_number) = road_number.parse::<UicVehicleNumber>() else {
+            continue;
+        };
+        let railway_id = RailwayId::new(rolling_stock.railway());
+        let Some(railway) = railways_repo.find_by_id(&railway_id, unit_of_work).await? else {
+            continue;
+        };
+
+        let keeper = match (
+            uic_number.country_code.parse::<u8>(),
+            &uic_number.vehicle_keeper_marking,
+        ) {
+            (Ok(country_code), Some(marking)) => {
+                railways_repo
+                    .find_vehicle_keeper(country_code, marking, date, unit_of_work)
+                    .await?
+            }
+            _ => None,
+        };
+
+        if let Some(warning) = check_vehicle_keeper(road_number, &railway, keeper.as_ref()) {
+            warnings.push(format!("rolling_stocks[{index}]: {warning}"));
+        }
+    }
+
+    Ok(warnings)
 }
 
 #[derive(Debug, Error)]
@@ -99,6 +177,9 @@ pub enum CatalogItemCreationError {
     #[error("Unable to create the catalog item due to brand not found (id: {0})")]
     BrandNotFound(BrandId),
 
+    #[error("Unable to create the catalog item due to prototype not found (id: {0})")]
+    PrototypeNotFound(PrototypeId),
+
     #[error("Unable to create the catalog item due to railway not found (id: {0})")]
     RailwayNotFound(RailwayId),
 
@@ -208,6 +289,7 @@ pub struct RollingStockPayload {
     pub livery: Option<String>,
     pub length_over_buffers_mm: Option<Length>,
     pub length_over_buffers_in: Option<Length>,
+    pub prototype_id: Option<PrototypeId>,
     pub type_name: Option<String>,
     pub road_number: Option<String>,
     pub series: Option<String>,
@@ -260,6 +342,8 @@ impl TryFrom<RollingStockRequest> for RollingStockPayload {
             digital_shunting,
         } = coupling.unwrap_or_default();
 
+        let prototype_id = request.prototype().map(|it| PrototypeId::new(it));
+
         let (millimeters, inches) = if let Some(length_over_buffers) = request.length_over_buffers() {
             (length_over_buffers.millimeters, length_over_buffers.inches)
         } else {
@@ -272,6 +356,7 @@ impl TryFrom<RollingStockRequest> for RollingStockPayload {
                 livery,
                 length_over_buffers: _,
                 technical_specifications: _,
+                prototype: _,
                 type_name,
                 road_number,
                 series,
@@ -285,6 +370,7 @@ impl TryFrom<RollingStockRequest> for RollingStockPayload {
                 livery,
                 length_over_buffers_mm: millimeters,
                 length_over_buffers_in: inches,
+                prototype_id,
                 type_name: Some(type_name),
                 road_number,
                 series,
@@ -310,6 +396,7 @@ impl TryFrom<RollingStockRequest> for RollingStockPayload {
                 livery,
                 length_over_buffers: _,
                 technical_specifications: _,
+                prototype: _,
                 type_name,
                 road_number,
                 series,
@@ -323,6 +410,7 @@ impl TryFrom<RollingStockRequest> for RollingStockPayload {
                 livery,
                 length_over_buffers_mm: millimeters,
                 length_over_buffers_in: inches,
+                prototype_id,
                 type_name: Some(type_name),
                 road_number,
                 series,
@@ -348,6 +436,7 @@ impl TryFrom<RollingStockRequest> for RollingStockPayload {
                 livery,
                 length_over_buffers: _,
                 technical_specifications: _,
+                prototype: _,
                 class_name,
                 road_number,
                 series,
@@ -361,6 +450,7 @@ impl TryFrom<RollingStockRequest> for RollingStockPayload {
                 livery,
                 length_over_buffers_mm: millimeters,
                 length_over_buffers_in: inches,
+                prototype_id,
                 type_name: Some(class_name),
                 road_number: Some(road_number),
                 series,
@@ -386,6 +476,7 @@ impl TryFrom<RollingStockRequest> for RollingStockPayload {
                 livery,
                 length_over_buffers: _,
                 technical_specifications: _,
+                prototype: _,
                 type_name,
                 road_number,
                 series,
@@ -396,6 +487,7 @@ impl TryFrom<RollingStockRequest> for RollingStockPayload {
                 livery,
                 length_over_buffers_mm: millimeters,
                 length_over_buffers_in: inches,
+                prototype_id,
                 type_name: Some(type_name),
                 road_number,
                 series,
@@ -418,6 +510,7 @@ impl TryFrom<RollingStockRequest> for RollingStockPayload {
                 livery,
                 length_over_buffers: _,
                 technical_specifications: _,
+                prototype: _,
                 type_name,
                 road_number,
                 freight_car_type,
@@ -426,6 +519,7 @@ impl TryFrom<RollingStockRequest> for RollingStockPayload {
                 livery,
                 length_over_buffers_mm: millimeters,
                 length_over_buffers_in: inches,
+                prototype_id,
                 type_name: Some(type_name),
                 road_number,
                 freight_car_type,
@@ -453,15 +547,31 @@ mod test {
     mod new_catalog_item_command {
         use crate::brands::brand_id::BrandId;
         use crate::catalog_items::catalog_item_id::CatalogItemId;
+        use crate::catalog_items::catalog_item_request::CatalogItemRequest;
+        use crate::catalog_items::catalog_item_response::CatalogItemCreated;
+        use crate::catalog_items::category::LocomotiveType;
+        use crate::catalog_items::commands::new_catalog_item::test::new_catalog_item_with_prototype;
         use crate::catalog_items::commands::new_catalog_item::test::{catalog_item, new_catalog_item};
-        use crate::catalog_items::commands::new_catalog_item::{create_new_catalog_item, CatalogItemCreationError};
+        use crate::catalog_items::commands::new_catalog_item::{
+            create_new_catalog_item, CatalogItemCreationError, Result,
+        };
         use crate::catalog_items::commands::repositories::in_memory::{
             InMemoryCatalogItemRepository, InMemoryRollingStockRepository,
         };
+        use crate::catalog_items::delivery_date::DeliveryDate;
+        use crate::catalog_items::epoch::Epoch;
         use crate::catalog_items::item_number::ItemNumber;
+        use crate::catalog_items::rolling_stock_request::RollingStockRequest;
+        use crate::events::repositories::in_memory::InMemoryOutboxRepository;
+        use crate::prototypes::prototype_id::PrototypeId;
+        use crate::railways::queries::find_vehicle_keeper::in_memory::InMemoryVehicleKeepersRepository;
         use crate::railways::railway_id::RailwayId;
+        use crate::railways::test_data::{die_bahn, fs};
+        use crate::railways::vehicle_keeper_marking::VehicleKeeperMarking;
         use crate::scales::scale_id::ScaleId;
+        use chrono::NaiveDate;
         use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
 
         #[tokio::test]
         async fn it_should_return_an_error_when_the_brand_is_not_found() {
@@ -470,7 +580,15 @@ mod test {
             let db = NoOpDatabase;
 
             let request = new_catalog_item();
-            let result = create_new_catalog_item(request, repo, rr_repo, db).await;
+            let result = create_new_catalog_item(
+                request,
+                repo,
+                rr_repo,
+                InMemoryVehicleKeepersRepository::empty(),
+                InMemoryOutboxRepository::empty(),
+                db,
+            )
+            .await;
 
             assert!(result.is_err());
 
@@ -487,7 +605,15 @@ mod test {
             let db = NoOpDatabase;
 
             let request = new_catalog_item();
-            let result = create_new_catalog_item(request, repo, rr_repo, db).await;
+            let result = create_new_catalog_item(
+                request,
+                repo,
+                rr_repo,
+                InMemoryVehicleKeepersRepository::empty(),
+                InMemoryOutboxRepository::empty(),
+                db,
+            )
+            .await;
 
             assert!(result.is_err());
 
@@ -505,7 +631,15 @@ mod test {
             let db = NoOpDatabase;
 
             let request = new_catalog_item();
-            let result = create_new_catalog_item(request, repo, rr_repo, db).await;
+            let result = create_new_catalog_item(
+                request,
+                repo,
+                rr_repo,
+                InMemoryVehicleKeepersRepository::empty(),
+                InMemoryOutboxRepository::empty(),
+                db,
+            )
+            .await;
 
             assert!(result.is_err());
 
@@ -526,7 +660,15 @@ mod test {
             let db = NoOpDatabase;
 
             let request = new_catalog_item();
-            let result = create_new_catalog_item(request, repo, rr_repo, db).await;
+            let result = create_new_catalog_item(
+                request,
+                repo,
+                rr_repo,
+                InMemoryVehicleKeepersRepository::empty(),
+                InMemoryOutboxRepository::empty(),
+                db,
+            )
+            .await;
 
             assert!(result.is_err());
             match result {
@@ -537,6 +679,57 @@ mod test {
             }
         }
 
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_prototype_is_not_found() {
+            let repo = InMemoryCatalogItemRepository::empty()
+                .with_brand(BrandId::new("ACME"))
+                .with_scale(ScaleId::new("H0"));
+            let rr_repo = InMemoryRollingStockRepository::empty().with_railway(RailwayId::new("FS"));
+            let db = NoOpDatabase;
+
+            let request = new_catalog_item_with_prototype("fs-e-656");
+            let result = create_new_catalog_item(
+                request,
+                repo,
+                rr_repo,
+                InMemoryVehicleKeepersRepository::empty(),
+                InMemoryOutboxRepository::empty(),
+                db,
+            )
+            .await;
+
+            match result {
+                Err(CatalogItemCreationError::PrototypeNotFound(prototype_id)) => {
+                    assert_eq!("fs-e-656", prototype_id.to_string())
+                }
+                _ => panic!("CatalogItemCreationError::PrototypeNotFound is expected (found: {result:?})"),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_create_a_new_catalog_item_modelling_a_prototype() {
+            let repo = InMemoryCatalogItemRepository::empty()
+                .with_brand(BrandId::new("ACME"))
+                .with_scale(ScaleId::new("H0"));
+            let rr_repo = InMemoryRollingStockRepository::empty()
+                .with_railway(RailwayId::new("FS"))
+                .with_prototype(PrototypeId::new("fs-e-656"));
+            let db = NoOpDatabase;
+
+            let request = new_catalog_item_with_prototype("fs-e-656");
+            let result = create_new_catalog_item(
+                request,
+                repo,
+                rr_repo,
+                InMemoryVehicleKeepersRepository::empty(),
+                InMemoryOutboxRepository::empty(),
+                db,
+            )
+            .await;
+
+            assert!(result.is_ok());
+        }
+
         #[tokio::test]
         async fn it_should_create_a_new_catalog_item() {
             let repo = InMemoryCatalogItemRepository::empty()
@@ -546,7 +739,15 @@ mod test {
             let db = NoOpDatabase;
 
             let request = new_catalog_item();
-            let result = create_new_catalog_item(request, repo, rr_repo, db).await;
+            let result = create_new_catalog_item(
+                request,
+                repo,
+                rr_repo,
+                InMemoryVehicleKeepersRepository::empty(),
+                InMemoryOutboxRepository::empty(),
+                db,
+            )
+            .await;
 
             assert!(result.is_ok());
             assert_eq!(
@@ -554,6 +755,116 @@ mod test {
                 result.unwrap().catalog_item_id
             );
         }
+
+        #[tokio::test]
+        async fn it_should_return_the_warnings_for_the_road_numbers_belonging_to_other_railways() {
+            let mut request = new_catalog_item();
+            request.epoch = Epoch::VI;
+            request.delivery_date = None;
+            request.rolling_stocks = vec![
+                locomotive("DB", "91 80 6103 113-7 D-DB"),
+                locomotive("FS", "91 80 6103 113-7 D-DB"),
+                locomotive("FS", "E.444 001"),
+            ];
+
+            let result = create_catalog_item_with_vehicle_keepers(request).await;
+
+            assert_eq!(
+                vec![String::from(
+                    "rolling_stocks[1]: the road number '91 80 6103 113-7 D-DB' belongs to the railway 'db' (expected: fs)"
+                )],
+                result.expect("result is an error").warnings
+            );
+        }
+
+        #[tokio::test]
+        async fn it_should_not_match_the_vehicle_keeper_markings_expired_at_the_reference_date() {
+            let mut active = new_catalog_item();
+            active.delivery_date = None;
+            active.rolling_stocks = vec![locomotive("DB", "91 83 2444 001-8 I-FS")];
+
+            let mut expired = active.clone();
+            expired.epoch = Epoch::VI;
+            expired.delivery_date = Some(DeliveryDate::by_year(2024));
+
+            let result = create_catalog_item_with_vehicle_keepers(active).await;
+            assert_eq!(
+                vec![String::from(
+                    "rolling_stocks[0]: the road number '91 83 2444 001-8 I-FS' belongs to the railway 'fs' (expected: db)"
+                )],
+                result.expect("result is an error").warnings
+            );
+
+            let result = create_catalog_item_with_vehicle_keepers(expired).await;
+            assert_eq!(
+                vec![String::from(
+                    "rolling_stocks[0]: the road number '91 83 2444 001-8 I-FS' has the UIC country code 83 (expected: 80)"
+                )],
+                result.expect("result is an error").warnings
+            );
+        }
+
+        #[tokio::test]
+        async fn it_should_not_return_warnings_for_invalid_requests() {
+            let mut request = new_catalog_item();
+            request.rolling_stocks = vec![locomotive("FS", "91 80 6103 113-7 D-DB")];
+            request.count = 0;
+
+            let result = create_catalog_item_with_vehicle_keepers(request).await;
+
+            match result {
+                Err(CatalogItemCreationError::InvalidRequest(_)) => {}
+                _ => panic!("CatalogItemCreationError::InvalidRequest is expected (found: {result:?})"),
+            }
+        }
+
+        async fn create_catalog_item_with_vehicle_keepers(request: CatalogItemRequest) -> Result<CatalogItemCreated> {
+            let repo = InMemoryCatalogItemRepository::empty()
+                .with_brand(BrandId::new("ACME"))
+                .with_scale(ScaleId::new("H0"));
+            let rr_repo = InMemoryRollingStockRepository::empty()
+                .with_railway(RailwayId::new("DB"))
+                .with_railway(RailwayId::new("FS"));
+            let railways_repo = InMemoryVehicleKeepersRepository::with(vec![
+                die_bahn().with_vehicle_keepers(80, vec![VehicleKeeperMarking::new("DB", None, None)]),
+                fs().with_vehicle_keepers(
+                    83,
+                    vec![VehicleKeeperMarking::new(
+                        "FS",
+                        None,
+                        NaiveDate::from_ymd_opt(2004, 12, 31),
+                    )],
+                ),
+            ]);
+
+            create_new_catalog_item(
+                request,
+                repo,
+                rr_repo,
+                railways_repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await
+        }
+
+        fn locomotive(railway: &str, road_number: &str) -> RollingStockRequest {
+            RollingStockRequest::LocomotiveRequest {
+                railway: String::from(railway),
+                livery: None,
+                length_over_buffers: None,
+                technical_specifications: None,
+                prototype: None,
+                class_name: String::from("E.444"),
+                road_number: String::from(road_number),
+                series: None,
+                depot: None,
+                locomotive_type: LocomotiveType::ElectricLocomotive,
+                dcc_interface: None,
+                control: None,
+                is_dummy: false,
+            }
+        }
     }
 
     fn new_catalog_item() -> CatalogItemRequest {
@@ -573,12 +884,24 @@ mod test {
         }
     }
 
+    fn new_catalog_item_with_prototype(prototype: &str) -> CatalogItemRequest {
+        CatalogItemRequest {
+            rolling_stocks: vec![locomotive_request_with_prototype(Some(prototype))],
+            ..new_catalog_item()
+        }
+    }
+
     fn locomotive_request() -> RollingStockRequest {
+        locomotive_request_with_prototype(None)
+    }
+
+    fn locomotive_request_with_prototype(prototype: Option<&str>) -> RollingStockRequest {
         RollingStockRequest::LocomotiveRequest {
             railway: "FS".to_string(),
             livery: None,
             length_over_buffers: None,
             technical_specifications: None,
+            prototype: prototype.map(str::to_string),
             class_name: "E656".to_string(),
             road_number: "E656 077".to_string(),
             series: None,
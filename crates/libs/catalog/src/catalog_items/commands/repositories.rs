@@ -1,9 +1,15 @@
 use crate::brands::brand_id::BrandId;
+use crate::catalog_items::availability::AvailabilityChange;
+use crate::catalog_items::availability_status::AvailabilityStatus;
 use crate::catalog_items::catalog_item_id::CatalogItemId;
 use crate::catalog_items::commands::new_catalog_item::{NewCatalogItemCommand, NewRollingStockCommand};
+use crate::catalog_items::delivery_date::{DeliveryDate, Year};
+use crate::catalog_items::rolling_stock_id::RollingStockId;
+use crate::prototypes::prototype_id::PrototypeId;
 use crate::railways::railway_id::RailwayId;
 use crate::scales::scale_id::ScaleId;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use common::unit_of_work::UnitOfWork;
 
 /// The persistence related functionality for the catalog item commands
@@ -30,21 +36,133 @@ pub trait NewRollingStockRepository<'db, U: UnitOfWork<'db>> {
 
     /// Checks if the railway exists
     async fn railway_exists(&self, railway_id: &RailwayId, unit_of_work: &mut U) -> Result<bool, anyhow::Error>;
+
+    /// Checks if the prototype exists
+    async fn prototype_exists(&self, prototype_id: &PrototypeId, unit_of_work: &mut U) -> Result<bool, anyhow::Error>;
+}
+
+/// The persistence related functionality for the catalog item soft deletion
+#[async_trait]
+pub trait DeleteCatalogItemRepository<'db, U: UnitOfWork<'db>> {
+    /// Checks if the catalog item has been soft deleted, it returns `None` when the catalog item does not exist
+    async fn is_deleted(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut U,
+    ) -> Result<Option<bool>, anyhow::Error>;
+
+    /// Marks the catalog item, and its rolling stocks, as deleted, it returns `false` when the catalog item
+    /// does not exist or it is already deleted
+    async fn soft_delete(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        deleted_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error>;
+
+    /// Restores a soft deleted catalog item, and the rolling stocks deleted together with it, it returns `false`
+    /// when the catalog item does not exist or it is not deleted
+    async fn restore(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        last_modified_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error>;
+}
+
+/// The persistence related functionality for the rolling stock soft deletion
+#[async_trait]
+pub trait DeleteRollingStockRepository<'db, U: UnitOfWork<'db>> {
+    /// Checks if the rolling stock has been soft deleted, it returns `None` when the rolling stock
+    /// does not exist for the given catalog item
+    async fn is_deleted(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        rolling_stock_id: &RollingStockId,
+        unit_of_work: &mut U,
+    ) -> Result<Option<bool>, anyhow::Error>;
+
+    /// Marks the rolling stock as deleted, it returns `false` when the rolling stock does not exist for the
+    /// given catalog item or it is already deleted
+    async fn soft_delete(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        rolling_stock_id: &RollingStockId,
+        deleted_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error>;
+
+    /// Restores a soft deleted rolling stock, it returns `false` when the rolling stock does not exist for the
+    /// given catalog item or it is not deleted
+    async fn restore(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        rolling_stock_id: &RollingStockId,
+        last_modified_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error>;
+}
+
+/// The persistence related functionality for the catalog item availability commands
+#[async_trait]
+pub trait AvailabilityRepository<'db, U: UnitOfWork<'db>> {
+    /// Returns the catalog item availability status, it returns `None` when the catalog item
+    /// does not exist (or it has been soft deleted)
+    async fn find_status(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut U,
+    ) -> Result<Option<Option<AvailabilityStatus>>, anyhow::Error>;
+
+    /// Changes the availability status, and appends the change to the catalog item history.
+    ///
+    /// Any pending editorial review for the catalog item is cleared.
+    async fn change_status(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        change: &AvailabilityChange,
+        unit_of_work: &mut U,
+    ) -> Result<(), anyhow::Error>;
+
+    /// Returns the announced catalog items, not yet flagged for review, with a delivery date
+    /// up to the `until_year`
+    async fn find_announced(
+        &self,
+        until_year: Year,
+        unit_of_work: &mut U,
+    ) -> Result<Vec<(CatalogItemId, DeliveryDate)>, anyhow::Error>;
+
+    /// Flags the catalog items for editorial review
+    async fn request_review(
+        &self,
+        catalog_item_ids: &[CatalogItemId],
+        requested_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<(), anyhow::Error>;
 }
 
 #[cfg(test)]
 pub mod in_memory {
     use crate::brands::brand_id::BrandId;
+    use crate::catalog_items::availability::AvailabilityChange;
+    use crate::catalog_items::availability_status::AvailabilityStatus;
     use crate::catalog_items::catalog_item_id::CatalogItemId;
     use crate::catalog_items::commands::new_catalog_item::{NewCatalogItemCommand, NewRollingStockCommand};
-    use crate::catalog_items::commands::repositories::{NewCatalogItemRepository, NewRollingStockRepository};
+    use crate::catalog_items::commands::repositories::{
+        AvailabilityRepository, DeleteCatalogItemRepository, DeleteRollingStockRepository, NewCatalogItemRepository,
+        NewRollingStockRepository,
+    };
+    use crate::catalog_items::delivery_date::{DeliveryDate, Year};
     use crate::catalog_items::rolling_stock_id::RollingStockId;
+    use crate::prototypes::prototype_id::PrototypeId;
     use crate::railways::railway_id::RailwayId;
     use crate::scales::scale_id::ScaleId;
     use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
     use common::in_memory::InMemoryRepository;
     use common::unit_of_work::noop::NoOpUnitOfWork;
     use std::str::FromStr;
+    use std::sync::Arc;
 
     /// An in-memory catalog item repository
     pub struct InMemoryCatalogItemRepository {
@@ -129,6 +247,7 @@ pub mod in_memory {
     pub struct InMemoryRollingStockRepository {
         rolling_stocks: InMemoryRepository<RollingStockId, NewRollingStockCommand>,
         railways: Vec<RailwayId>,
+        prototypes: Vec<PrototypeId>,
     }
 
     impl InMemoryRollingStockRepository {
@@ -137,6 +256,7 @@ pub mod in_memory {
             InMemoryRollingStockRepository {
                 rolling_stocks: InMemoryRepository::empty(),
                 railways: Vec::new(),
+                prototypes: Vec::new(),
             }
         }
 
@@ -145,6 +265,12 @@ pub mod in_memory {
             self.railways.push(railway_id);
             self
         }
+
+        /// Adds the given prototype id to the current repository
+        pub fn with_prototype(mut self, prototype_id: PrototypeId) -> Self {
+            self.prototypes.push(prototype_id);
+            self
+        }
     }
 
     #[async_trait]
@@ -167,5 +293,257 @@ pub mod in_memory {
             let result = self.railways.contains(railway_id);
             Ok(result)
         }
+
+        async fn prototype_exists(
+            &self,
+            prototype_id: &PrototypeId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            let result = self.prototypes.contains(prototype_id);
+            Ok(result)
+        }
+    }
+
+    /// An in-memory catalog item repository, it keeps track of the catalog items deletion timestamps
+    pub struct InMemoryCatalogItemDeletionRepository(InMemoryRepository<CatalogItemId, Option<DateTime<Utc>>>);
+
+    impl InMemoryCatalogItemDeletionRepository {
+        /// Creates an empty in memory catalog items repository
+        pub fn empty() -> Self {
+            InMemoryCatalogItemDeletionRepository(InMemoryRepository::empty())
+        }
+
+        /// Creates a new in-memory catalog items repository with an initial element
+        pub fn with(catalog_item_id: CatalogItemId, deleted_at: Option<DateTime<Utc>>) -> Self {
+            InMemoryCatalogItemDeletionRepository(InMemoryRepository::of(catalog_item_id, deleted_at))
+        }
+    }
+
+    #[async_trait]
+    impl DeleteCatalogItemRepository<'static, NoOpUnitOfWork> for InMemoryCatalogItemDeletionRepository {
+        async fn is_deleted(
+            &self,
+            catalog_item_id: &CatalogItemId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Option<bool>, anyhow::Error> {
+            Ok(self
+                .0
+                .find_by_id(catalog_item_id)
+                .map(|deleted_at| deleted_at.is_some()))
+        }
+
+        async fn soft_delete(
+            &self,
+            catalog_item_id: &CatalogItemId,
+            deleted_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            match self.0.find_by_id(catalog_item_id) {
+                Some(None) => {
+                    self.0.add(catalog_item_id.clone(), Some(*deleted_at));
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn restore(
+            &self,
+            catalog_item_id: &CatalogItemId,
+            _last_modified_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            match self.0.find_by_id(catalog_item_id) {
+                Some(Some(_)) => {
+                    self.0.add(catalog_item_id.clone(), None);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+    }
+
+    /// An in-memory rolling stock repository, it keeps track of the rolling stocks deletion timestamps
+    pub struct InMemoryRollingStockDeletionRepository(InMemoryRepository<RollingStockId, Option<DateTime<Utc>>>);
+
+    impl InMemoryRollingStockDeletionRepository {
+        /// Creates an empty in memory rolling stocks repository
+        pub fn empty() -> Self {
+            InMemoryRollingStockDeletionRepository(InMemoryRepository::empty())
+        }
+
+        /// Creates a new in-memory rolling stocks repository with an initial element
+        pub fn with(rolling_stock_id: RollingStockId, deleted_at: Option<DateTime<Utc>>) -> Self {
+            InMemoryRollingStockDeletionRepository(InMemoryRepository::of(rolling_stock_id, deleted_at))
+        }
+    }
+
+    #[async_trait]
+    impl DeleteRollingStockRepository<'static, NoOpUnitOfWork> for InMemoryRollingStockDeletionRepository {
+        async fn is_deleted(
+            &self,
+            _catalog_item_id: &CatalogItemId,
+            rolling_stock_id: &RollingStockId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Option<bool>, anyhow::Error> {
+            Ok(self
+                .0
+                .find_by_id(rolling_stock_id)
+                .map(|deleted_at| deleted_at.is_some()))
+        }
+
+        async fn soft_delete(
+            &self,
+            _catalog_item_id: &CatalogItemId,
+            rolling_stock_id: &RollingStockId,
+            deleted_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            match self.0.find_by_id(rolling_stock_id) {
+                Some(None) => {
+                    self.0.add(*rolling_stock_id, Some(*deleted_at));
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn restore(
+            &self,
+            _catalog_item_id: &CatalogItemId,
+            rolling_stock_id: &RollingStockId,
+            _last_modified_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            match self.0.find_by_id(rolling_stock_id) {
+                Some(Some(_)) => {
+                    self.0.add(*rolling_stock_id, None);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+    }
+
+    /// The availability for an in-memory catalog item
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InMemoryAvailability {
+        pub status: Option<AvailabilityStatus>,
+        pub delivery_date: Option<DeliveryDate>,
+        pub review_requested_at: Option<DateTime<Utc>>,
+        pub history: Vec<AvailabilityChange>,
+    }
+
+    /// An in-memory catalog item repository, it keeps track of the catalog items availability (shared
+    /// between the clones of the same repository)
+    #[derive(Clone)]
+    pub struct InMemoryAvailabilityRepository(Arc<InMemoryRepository<CatalogItemId, InMemoryAvailability>>);
+
+    impl InMemoryAvailabilityRepository {
+        /// Creates an empty in memory catalog items repository
+        pub fn empty() -> Self {
+            InMemoryAvailabilityRepository(Arc::new(InMemoryRepository::empty()))
+        }
+
+        /// Creates a new in-memory catalog items repository with an initial element
+        pub fn with(
+            catalog_item_id: CatalogItemId,
+            status: Option<AvailabilityStatus>,
+            delivery_date: Option<DeliveryDate>,
+        ) -> Self {
+            let availability = InMemoryAvailability {
+                status,
+                delivery_date,
+                review_requested_at: None,
+                history: Vec::new(),
+            };
+            InMemoryAvailabilityRepository(Arc::new(InMemoryRepository::of(catalog_item_id, availability)))
+        }
+
+        /// Adds a new catalog item to the current repository
+        pub fn and(
+            self,
+            catalog_item_id: CatalogItemId,
+            status: AvailabilityStatus,
+            delivery_date: DeliveryDate,
+        ) -> Self {
+            let availability = InMemoryAvailability {
+                status: Some(status),
+                delivery_date: Some(delivery_date),
+                review_requested_at: None,
+                history: Vec::new(),
+            };
+            self.0.add(catalog_item_id, availability);
+            self
+        }
+
+        /// Returns the availability for the catalog item
+        pub fn find(&self, catalog_item_id: &CatalogItemId) -> Option<InMemoryAvailability> {
+            self.0.find_by_id(catalog_item_id)
+        }
+    }
+
+    #[async_trait]
+    impl AvailabilityRepository<'static, NoOpUnitOfWork> for InMemoryAvailabilityRepository {
+        async fn find_status(
+            &self,
+            catalog_item_id: &CatalogItemId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Option<Option<AvailabilityStatus>>, anyhow::Error> {
+            Ok(self.0.find_by_id(catalog_item_id).map(|it| it.status))
+        }
+
+        async fn change_status(
+            &self,
+            catalog_item_id: &CatalogItemId,
+            change: &AvailabilityChange,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<(), anyhow::Error> {
+            if let Some(mut availability) = self.0.find_by_id(catalog_item_id) {
+                availability.status = Some(change.status);
+                availability.review_requested_at = None;
+                availability.history.push(change.clone());
+                self.0.add(catalog_item_id.clone(), availability);
+            }
+            Ok(())
+        }
+
+        async fn find_announced(
+            &self,
+            until_year: Year,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Vec<(CatalogItemId, DeliveryDate)>, anyhow::Error> {
+            let mut result: Vec<(CatalogItemId, DeliveryDate)> = self
+                .0
+                .find_all()
+                .into_iter()
+                .filter_map(|(id, it)| {
+                    let delivery_date = it.delivery_date?;
+                    let announced = it.status == Some(AvailabilityStatus::Announced);
+                    if announced && it.review_requested_at.is_none() && delivery_date.year() <= until_year {
+                        Some((id, delivery_date))
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            result.sort_by(|a, b| a.0.value().cmp(b.0.value()));
+            Ok(result)
+        }
+
+        async fn request_review(
+            &self,
+            catalog_item_ids: &[CatalogItemId],
+            requested_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<(), anyhow::Error> {
+            for catalog_item_id in catalog_item_ids {
+                if let Some(mut availability) = self.0.find_by_id(catalog_item_id) {
+                    availability.review_requested_at = Some(*requested_at);
+                    self.0.add(catalog_item_id.clone(), availability);
+                }
+            }
+            Ok(())
+        }
     }
 }
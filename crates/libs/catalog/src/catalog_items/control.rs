@@ -3,9 +3,10 @@
 use sqlx::Type;
 use strum_macros;
 use strum_macros::{Display, EnumString};
+use utoipa::ToSchema;
 
 /// The control method for this railway model.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "control", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -34,7 +35,7 @@ pub enum Control {
 /// In many cases a blanking plug must be removed before installing the decoder. If a locomotive
 /// is not DCC-Ready it will lack an interface and must use a Hardwired Decoder or a drop-in
 /// replacement DCC control board (if available) for that specific model.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type, ToSchema)]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "dcc_interface")]
 pub enum DccInterface {
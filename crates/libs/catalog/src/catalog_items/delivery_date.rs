@@ -1,12 +1,16 @@
 //! the catalog item delivery date
 
+use chrono::{Datelike, Days, Months, NaiveDate};
 use serde::de::{Unexpected, Visitor};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::Formatter;
 use std::str;
 use std::str::FromStr;
 use thiserror::Error;
+use utoipa::openapi::{ObjectBuilder, RefOr, Schema, SchemaType};
+use utoipa::ToSchema;
 
 /// The delivery date quarter number
 pub type Quarter = u8;
@@ -39,7 +43,12 @@ impl DeliveryDate {
 
     /// Creates a new delivery date with the month/year information
     pub fn by_month(year: Year, month: Month) -> Self {
-        DeliveryDate::ByQuarter(year, month)
+        DeliveryDate::ByYearMonth(year, month)
+    }
+
+    /// Creates the delivery date for the month including the `date`
+    pub fn month_of(date: NaiveDate) -> Self {
+        DeliveryDate::ByYearMonth(date.year(), date.month() as Month)
     }
 
     /// Returns the year component from this delivery date
@@ -69,6 +78,32 @@ impl DeliveryDate {
         }
     }
 
+    /// Returns the first day of the period for this delivery date
+    pub fn first_day(&self) -> NaiveDate {
+        let month = match self {
+            DeliveryDate::ByYear(_) => 1,
+            DeliveryDate::ByQuarter(_, q) => ((*q).clamp(1, 4) - 1) * 3 + 1,
+            DeliveryDate::ByYearMonth(_, m) => (*m).clamp(1, 12),
+        };
+        NaiveDate::from_ymd_opt(self.year(), month as u32, 1).expect("the delivery date is not valid")
+    }
+
+    /// Returns the last day of the period for this delivery date
+    pub fn last_day(&self) -> NaiveDate {
+        let months = match self {
+            DeliveryDate::ByYear(_) => 12,
+            DeliveryDate::ByQuarter(_, _) => 3,
+            DeliveryDate::ByYearMonth(_, _) => 1,
+        };
+        self.first_day() + Months::new(months) - Days::new(1)
+    }
+
+    /// Checks whether the periods for this and the `other` delivery date have at least one day in common
+    /// (ie, _"2022/Q4"_ overlaps _"2022/11"_ and _"2022"_)
+    pub fn overlaps(&self, other: &DeliveryDate) -> bool {
+        self.first_day() <= other.last_day() && other.first_day() <= self.last_day()
+    }
+
     fn parse_year(s: &str) -> Result<Year, DeliveryDateParseError> {
         let year = s
             .parse::<Year>()
@@ -154,6 +189,20 @@ impl fmt::Display for DeliveryDate {
     }
 }
 
+/// The delivery dates are ordered by the beginning of their periods, the shorter periods first
+/// (ie, _"2022/10"_, _"2022/Q4"_ and then _"2023"_)
+impl Ord for DeliveryDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.first_day(), self.last_day()).cmp(&(other.first_day(), other.last_day()))
+    }
+}
+
+impl PartialOrd for DeliveryDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Serialize for DeliveryDate {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -163,6 +212,19 @@ impl Serialize for DeliveryDate {
     }
 }
 
+impl<'s> ToSchema<'s> for DeliveryDate {
+    fn schema() -> (&'s str, RefOr<Schema>) {
+        let schema = ObjectBuilder::new()
+            .schema_type(SchemaType::String)
+            .description(Some(
+                "the delivery date, either the year, the year and quarter or the year and month",
+            ))
+            .example(Some(serde_json::json!("2022/Q1")))
+            .build();
+        ("DeliveryDate", schema.into())
+    }
+}
+
 struct DeliveryDateVisitor;
 
 impl<'de> Visitor<'de> for DeliveryDateVisitor {
@@ -275,6 +337,63 @@ mod tests {
             assert_eq!(expected, result);
         }
 
+        #[test]
+        fn it_should_create_delivery_dates() {
+            assert_eq!(DeliveryDate::ByYear(2022), DeliveryDate::by_year(2022));
+            assert_eq!(DeliveryDate::ByQuarter(2022, 3), DeliveryDate::by_quarter(2022, 3));
+            assert_eq!(DeliveryDate::ByYearMonth(2022, 11), DeliveryDate::by_month(2022, 11));
+            assert_eq!(
+                DeliveryDate::ByYearMonth(2022, 11),
+                DeliveryDate::month_of(NaiveDate::from_ymd_opt(2022, 11, 18).unwrap())
+            );
+        }
+
+        #[rstest]
+        #[case("2022", "2022-01-01", "2022-12-31")]
+        #[case("2022/Q1", "2022-01-01", "2022-03-31")]
+        #[case("2022/Q4", "2022-10-01", "2022-12-31")]
+        #[case("2024/02", "2024-02-01", "2024-02-29")]
+        #[case("2022/12", "2022-12-01", "2022-12-31")]
+        fn it_should_return_the_delivery_date_periods(
+            #[case] input: &str,
+            #[case] first_day: NaiveDate,
+            #[case] last_day: NaiveDate,
+        ) {
+            let dd = input.parse::<DeliveryDate>().unwrap();
+            assert_eq!(first_day, dd.first_day());
+            assert_eq!(last_day, dd.last_day());
+        }
+
+        #[rstest]
+        #[case("2022/Q4", "2022/10", true)]
+        #[case("2022/Q4", "2022/12", true)]
+        #[case("2022/Q4", "2022/09", false)]
+        #[case("2022/Q4", "2022", true)]
+        #[case("2022/Q4", "2023/Q1", false)]
+        #[case("2022", "2023", false)]
+        fn it_should_check_whether_delivery_dates_overlap(
+            #[case] input: &str,
+            #[case] other: &str,
+            #[case] expected: bool,
+        ) {
+            let dd = input.parse::<DeliveryDate>().unwrap();
+            let other = other.parse::<DeliveryDate>().unwrap();
+            assert_eq!(expected, dd.overlaps(&other));
+            assert_eq!(expected, other.overlaps(&dd));
+        }
+
+        #[test]
+        fn it_should_sort_delivery_dates() {
+            let mut values: Vec<DeliveryDate> = ["2023", "2022/Q4", "2022", "2022/11", "2022/10", "2022/Q1"]
+                .iter()
+                .map(|it| it.parse::<DeliveryDate>().unwrap())
+                .collect();
+            values.sort();
+
+            let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+            assert_eq!(vec!["2022/Q1", "2022", "2022/10", "2022/Q4", "2022/11", "2023"], values);
+        }
+
         #[rstest]
         #[case("2020/Q11", DeliveryDateParseError::InvalidQuarterValue)]
         #[case("2020/Q0", DeliveryDateParseError::InvalidQuarterValue)]
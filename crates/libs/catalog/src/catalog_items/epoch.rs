@@ -8,6 +8,8 @@ use std::fmt::Formatter;
 use std::str;
 use std::str::FromStr;
 use thiserror::Error;
+use utoipa::openapi::{ObjectBuilder, RefOr, Schema, SchemaType};
+use utoipa::ToSchema;
 
 /// It represents a model railway epoch
 ///
@@ -29,6 +31,11 @@ use thiserror::Error;
 ///
 /// Typically Epochs include dates to give an idea of the time period being referenced, but
 /// these will differ country to country.
+///
+/// # Epoch ranges
+/// Every epoch covers a range on the epochs timeline: the main epochs include their sub-periods
+/// (`IIIa` is within `III`) and the mixed epochs (ie, `"III/IV/V"`) cover everything from the
+/// earliest to the latest of their values.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 #[allow(non_snake_case)]
 pub enum Epoch {
@@ -59,7 +66,8 @@ pub enum Epoch {
     /// _2007 – Present_: has been a time of building upon all of the progress that has been made
     /// over the last century.
     VI,
-    Multiple(Box<Epoch>, Box<Epoch>),
+    /// two or more epochs, in chronological order
+    Multiple(Vec<Epoch>),
 }
 
 impl str::FromStr for Epoch {
@@ -71,11 +79,16 @@ impl str::FromStr for Epoch {
         }
 
         if s.contains('/') {
-            let tokens: Vec<&str> = s.split_terminator('/').sorted().dedup().collect();
-            if tokens.len() == 2 {
-                let first = Epoch::parse_str(tokens[0])?;
-                let second = Epoch::parse_str(tokens[1])?;
-                Ok(Epoch::Multiple(Box::new(first), Box::new(second)))
+            let epochs: Vec<Epoch> = s
+                .split_terminator('/')
+                .map(Epoch::parse_str)
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .sorted()
+                .dedup()
+                .collect();
+            if epochs.len() >= 2 {
+                Ok(Epoch::Multiple(epochs))
             } else {
                 Err(EpochParseError::InvalidNumberOfValues)
             }
@@ -95,7 +108,104 @@ pub enum EpochParseError {
     InvalidValue,
 }
 
+/// It represents the range an epoch covers on the epochs timeline.
+///
+/// The timeline has a slot for every sub-period and, for the main epochs split into sub-periods, one more
+/// slot for the years not covered by the sub-periods (ie, the German `IIc`): the main epoch range includes all of them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct EpochRange {
+    start: i16,
+    end: i16,
+}
+
+impl EpochRange {
+    /// Creates a new epoch range, from the `start` to the `end` slot (both included)
+    pub fn new(start: i16, end: i16) -> Self {
+        EpochRange {
+            start: start.min(end),
+            end: start.max(end),
+        }
+    }
+
+    /// The first slot (included) for this range
+    pub fn start(&self) -> i16 {
+        self.start
+    }
+
+    /// The last slot (included) for this range
+    pub fn end(&self) -> i16 {
+        self.end
+    }
+
+    /// Checks whether the `other` range is entirely within this range
+    pub fn contains(&self, other: &EpochRange) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Checks whether this range and the `other` range have at least one slot in common
+    pub fn overlaps(&self, other: &EpochRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
 impl Epoch {
+    /// Returns the range this epoch covers on the epochs timeline
+    pub fn range(&self) -> EpochRange {
+        match self {
+            Epoch::I => EpochRange::new(0, 0),
+            Epoch::II => EpochRange::new(1, 3),
+            Epoch::IIa => EpochRange::new(1, 1),
+            Epoch::IIb => EpochRange::new(2, 2),
+            Epoch::III => EpochRange::new(4, 7),
+            Epoch::IIIa => EpochRange::new(4, 4),
+            Epoch::IIIb => EpochRange::new(5, 5),
+            Epoch::IIIc => EpochRange::new(6, 6),
+            Epoch::IV => EpochRange::new(8, 10),
+            Epoch::IVa => EpochRange::new(8, 8),
+            Epoch::IVb => EpochRange::new(9, 9),
+            Epoch::V => EpochRange::new(11, 14),
+            Epoch::Va => EpochRange::new(11, 11),
+            Epoch::Vb => EpochRange::new(12, 12),
+            Epoch::Vm => EpochRange::new(13, 13),
+            Epoch::VI => EpochRange::new(15, 15),
+            Epoch::Multiple(epochs) => {
+                let ranges = epochs.iter().map(Epoch::range);
+                let start = ranges.clone().map(|it| it.start).min().unwrap_or_default();
+                let end = ranges.map(|it| it.end).max().unwrap_or_default();
+                EpochRange::new(start, end)
+            }
+        }
+    }
+
+    /// Returns the main epoch for a sub-period (ie, `III` for `IIIa`), or `None` for main and mixed epochs
+    pub fn main_epoch(&self) -> Option<Epoch> {
+        match self {
+            Epoch::IIa | Epoch::IIb => Some(Epoch::II),
+            Epoch::IIIa | Epoch::IIIb | Epoch::IIIc => Some(Epoch::III),
+            Epoch::IVa | Epoch::IVb => Some(Epoch::IV),
+            Epoch::Va | Epoch::Vb | Epoch::Vm => Some(Epoch::V),
+            _ => None,
+        }
+    }
+
+    /// Returns the single epochs for this epoch, in chronological order
+    pub fn epochs(&self) -> Vec<&Epoch> {
+        match self {
+            Epoch::Multiple(epochs) => epochs.iter().collect(),
+            _ => vec![self],
+        }
+    }
+
+    /// Checks whether the `other` epoch is entirely within this epoch (ie, `IIIa` is within `III`)
+    pub fn contains(&self, other: &Epoch) -> bool {
+        self.range().contains(&other.range())
+    }
+
+    /// Checks whether this epoch and the `other` epoch overlap (ie, `IV/V` overlaps `Vb`)
+    pub fn overlaps(&self, other: &Epoch) -> bool {
+        self.range().overlaps(&other.range())
+    }
+
     // Helper method to parse just the simple value
     fn parse_str(value: &str) -> Result<Self, EpochParseError> {
         match value {
@@ -123,7 +233,7 @@ impl Epoch {
 impl fmt::Display for Epoch {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            Epoch::Multiple(ep1, ep2) => write!(f, "{}/{}", &ep1, &ep2),
+            Epoch::Multiple(epochs) => write!(f, "{}", epochs.iter().join("/")),
             _ => write!(f, "{self:?}"),
         }
     }
@@ -138,6 +248,19 @@ impl Serialize for Epoch {
     }
 }
 
+impl<'s> ToSchema<'s> for Epoch {
+    fn schema() -> (&'s str, RefOr<Schema>) {
+        let schema = ObjectBuilder::new()
+            .schema_type(SchemaType::String)
+            .description(Some(
+                "the model railway epoch, the mixed epochs are separated by a slash (ie, \"IV/V\" or \"III/IV/V\")",
+            ))
+            .example(Some(serde_json::json!("IV")))
+            .build();
+        ("Epoch", schema.into())
+    }
+}
+
 struct EpochVisitor;
 
 impl<'de> Visitor<'de> for EpochVisitor {
@@ -188,7 +311,26 @@ mod tests {
         fn it_should_convert_string_slices_to_mixed_epochs() {
             let epoch = "I/II".parse::<Epoch>();
             assert!(epoch.is_ok());
-            assert_eq!(epoch.unwrap(), Epoch::Multiple(Box::new(Epoch::I), Box::new(Epoch::II)));
+            assert_eq!(epoch.unwrap(), Epoch::Multiple(vec![Epoch::I, Epoch::II]));
+        }
+
+        #[test]
+        fn it_should_convert_string_slices_with_more_than_two_values_to_mixed_epochs() {
+            let epoch = "V/III/IV".parse::<Epoch>();
+            assert!(epoch.is_ok());
+            assert_eq!(epoch.unwrap(), Epoch::Multiple(vec![Epoch::III, Epoch::IV, Epoch::V]));
+        }
+
+        #[test]
+        fn it_should_sort_the_mixed_epochs_values_in_chronological_order() {
+            let epoch = "III/IIb".parse::<Epoch>().unwrap();
+            assert_eq!("IIb/III", epoch.to_string());
+        }
+
+        #[test]
+        fn it_should_fail_to_convert_mixed_epochs_with_one_value() {
+            let epoch = "IV/IV".parse::<Epoch>();
+            assert!(epoch.is_err());
         }
 
         #[test]
@@ -203,7 +345,7 @@ mod tests {
         #[test]
         #[allow(non_snake_case)]
         fn it_should_display_epoch_values() {
-            let epoch_I_II = Epoch::Multiple(Box::new(Epoch::I), Box::new(Epoch::II));
+            let epoch_I_II = Epoch::Multiple(vec![Epoch::I, Epoch::II]);
             let epoch_IVa = Epoch::IVa;
 
             assert_eq!("I/II", epoch_I_II.to_string());
@@ -227,7 +369,8 @@ mod tests {
         #[case(Epoch::Vb, r#""Vb""#)]
         #[case(Epoch::Vm, r#""Vm""#)]
         #[case(Epoch::VI, r#""VI""#)]
-        #[case(Epoch::Multiple(Box::new(Epoch::IV), Box::new(Epoch::V)), r#""IV/V""#)]
+        #[case(Epoch::Multiple(vec![Epoch::IV, Epoch::V]), r#""IV/V""#)]
+        #[case(Epoch::Multiple(vec![Epoch::III, Epoch::IV, Epoch::V]), r#""III/IV/V""#)]
         fn it_should_serialize_epochs(#[case] input: Epoch, #[case] expected: &str) {
             let result = serde_json::to_string(&input).unwrap();
             assert_eq!(expected, result);
@@ -259,6 +402,67 @@ mod tests {
             assert_eq!(test_struct, result.unwrap());
         }
 
+        #[rstest]
+        #[case("III", "IIIa", true)]
+        #[case("III", "IIIc", true)]
+        #[case("IIIa", "III", false)]
+        #[case("III/IV/V", "IVb", true)]
+        #[case("III/IV/V", "IV/V", true)]
+        #[case("IIa/IIb", "II", false)]
+        #[case("II/III", "IIa/IIIc", true)]
+        #[case("IV", "Va", false)]
+        fn it_should_check_whether_an_epoch_contains_another(
+            #[case] epoch: &str,
+            #[case] other: &str,
+            #[case] expected: bool,
+        ) {
+            let epoch = epoch.parse::<Epoch>().unwrap();
+            let other = other.parse::<Epoch>().unwrap();
+            assert_eq!(expected, epoch.contains(&other));
+        }
+
+        #[rstest]
+        #[case("IV/V", "Vb", true)]
+        #[case("Vb", "IV/V", true)]
+        #[case("IV", "IVa", true)]
+        #[case("IVa", "IVb", false)]
+        #[case("II/IV", "III", true)]
+        #[case("IV", "V", false)]
+        #[case("I", "VI", false)]
+        fn it_should_check_whether_two_epochs_overlap(
+            #[case] epoch: &str,
+            #[case] other: &str,
+            #[case] expected: bool,
+        ) {
+            let epoch = epoch.parse::<Epoch>().unwrap();
+            let other = other.parse::<Epoch>().unwrap();
+            assert_eq!(expected, epoch.overlaps(&other));
+        }
+
+        #[test]
+        fn it_should_return_the_range_for_mixed_epochs() {
+            let epoch = "IIIb/IV".parse::<Epoch>().unwrap();
+            assert_eq!(
+                EpochRange::new(Epoch::IIIb.range().start(), Epoch::IV.range().end()),
+                epoch.range()
+            );
+        }
+
+        #[rstest]
+        #[case(Epoch::IIIa, Some(Epoch::III))]
+        #[case(Epoch::Vm, Some(Epoch::V))]
+        #[case(Epoch::IV, None)]
+        fn it_should_return_the_main_epoch(#[case] epoch: Epoch, #[case] expected: Option<Epoch>) {
+            assert_eq!(expected, epoch.main_epoch());
+        }
+
+        #[test]
+        fn it_should_return_the_single_epochs() {
+            let epoch = "III/IV".parse::<Epoch>().unwrap();
+            assert_eq!(vec![&Epoch::III, &Epoch::IV], epoch.epochs());
+            assert_eq!(vec![&Epoch::VI], Epoch::VI.epochs());
+        }
+
         #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
         pub struct TestStruct {
             pub input: Epoch,
@@ -0,0 +1,413 @@
+//! the country specific epoch calendars
+
+use crate::catalog_items::epoch::{Epoch, EpochRange};
+use chrono::{Datelike, NaiveDate};
+use isocountry::CountryCode;
+use lazy_static::lazy_static;
+use std::cmp::Reverse;
+use utoipa::ToSchema;
+
+/// It represents the years an epoch covers in a given country.
+///
+/// The years are the ones from the NEM 806 tables, where the year that ends a period is also the
+/// first year of the next one: the end year is therefore exclusive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct EpochPeriod {
+    /// the epoch
+    pub epoch: Epoch,
+    /// the first year for the epoch
+    pub start_year: i32,
+    /// the year the epoch ended (exclusive), it is empty for the current epoch
+    pub end_year: Option<i32>,
+}
+
+impl EpochPeriod {
+    /// Creates a new epoch period
+    pub fn new(epoch: Epoch, start_year: i32, end_year: Option<i32>) -> Self {
+        EpochPeriod {
+            epoch,
+            start_year,
+            end_year,
+        }
+    }
+
+    /// Checks whether the `year` falls within this epoch period
+    pub fn contains(&self, year: i32) -> bool {
+        self.start_year <= year && self.end_year.map(|end_year| year < end_year).unwrap_or(true)
+    }
+
+    /// The first day for this epoch period
+    pub fn start_date(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.start_year, 1, 1).expect("the start year is a valid year")
+    }
+
+    /// The last day for this epoch period, if the epoch is over
+    pub fn end_date(&self) -> Option<NaiveDate> {
+        self.end_year
+            .and_then(|end_year| NaiveDate::from_ymd_opt(end_year - 1, 12, 31))
+    }
+}
+
+/// It represents the epoch calendar for a country.
+///
+/// # Description
+///
+/// The European epoch system only gives a label to a time bracket, the actual years for every epoch
+/// (and the sub-periods the epoch is split into) are defined country by country in the NEM 806 tables.
+///
+/// The sub-periods not included in [`Epoch`] (ie, `IIc` or `Vc`) are only covered by their main epoch.
+/// The countries without a table use the generic European calendar.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct EpochCalendar {
+    /// the country for the calendar, it is empty for the generic European calendar
+    #[schema(value_type = Option<String>)]
+    pub country: Option<CountryCode>,
+    /// the epoch periods, ordered by start year
+    pub periods: Vec<EpochPeriod>,
+}
+
+impl EpochCalendar {
+    /// Returns the generic European epoch calendar
+    pub fn european() -> &'static EpochCalendar {
+        &EUROPEAN_CALENDAR
+    }
+
+    /// Returns the epoch calendar for the `country`, or the generic European one when the country has none
+    pub fn for_country(country: CountryCode) -> &'static EpochCalendar {
+        COUNTRY_CALENDARS
+            .iter()
+            .find(|calendar| calendar.country == Some(country))
+            .unwrap_or(&EUROPEAN_CALENDAR)
+    }
+
+    /// Returns the country specific epoch calendars
+    pub fn country_calendars() -> &'static [EpochCalendar] {
+        &COUNTRY_CALENDARS
+    }
+
+    /// Returns the years covered by `epoch` in this calendar.
+    ///
+    /// For mixed epochs the period goes from the beginning of the earliest to the end of the latest epoch,
+    /// while `None` is returned when the calendar has no period for (one of) the epochs.
+    pub fn period_of(&self, epoch: &Epoch) -> Option<EpochPeriod> {
+        match epoch {
+            Epoch::Multiple(epochs) => {
+                let periods = epochs
+                    .iter()
+                    .map(|epoch| self.period_of(epoch))
+                    .collect::<Option<Vec<_>>>()?;
+                let start_year = periods.iter().map(|period| period.start_year).min()?;
+                let end_year = periods
+                    .iter()
+                    .map(|period| period.end_year)
+                    .collect::<Option<Vec<_>>>()
+                    .and_then(|end_years| end_years.into_iter().max());
+                Some(EpochPeriod::new(epoch.clone(), start_year, end_year))
+            }
+            _ => self.periods.iter().find(|period| &period.epoch == epoch).cloned(),
+        }
+    }
+
+    /// Returns the most specific epoch (a sub-period, when the calendar has one) for the `year`
+    pub fn epoch_at(&self, year: i32) -> Option<&Epoch> {
+        self.periods
+            .iter()
+            .filter(|period| period.contains(year))
+            .min_by_key(|period| (Reverse(period.start_year), period.end_year.unwrap_or(i32::MAX)))
+            .map(|period| &period.epoch)
+    }
+
+    /// Returns the most specific epoch for the `date`
+    pub fn epoch_on(&self, date: NaiveDate) -> Option<&Epoch> {
+        self.epoch_at(date.year())
+    }
+
+    /// Returns the range on the epochs timeline for the `year`.
+    ///
+    /// When the year falls within a main epoch, but outside all the sub-periods this calendar has for it,
+    /// the range only includes the main epoch slot for the sub-periods not in [`Epoch`].
+    pub fn range_at(&self, year: i32) -> Option<EpochRange> {
+        let epoch = self.epoch_at(year)?;
+        let range = epoch.range();
+
+        let has_sub_periods = self
+            .periods
+            .iter()
+            .any(|period| period.epoch.main_epoch().as_ref() == Some(epoch));
+        if has_sub_periods {
+            Some(EpochRange::new(range.end(), range.end()))
+        } else {
+            Some(range)
+        }
+    }
+}
+
+lazy_static! {
+    static ref EUROPEAN_CALENDAR: EpochCalendar = EpochCalendar {
+        country: None,
+        periods: periods(&[
+            (Epoch::I, 1848, Some(1921)),
+            (Epoch::II, 1921, Some(1949)),
+            (Epoch::III, 1949, Some(1971)),
+            (Epoch::IV, 1971, Some(1991)),
+            (Epoch::V, 1991, Some(2007)),
+            (Epoch::VI, 2007, None),
+        ]),
+    };
+    static ref COUNTRY_CALENDARS: Vec<EpochCalendar> = vec![
+        EpochCalendar {
+            country: Some(CountryCode::AUT),
+            periods: periods(&[
+                (Epoch::I, 1837, Some(1920)),
+                (Epoch::II, 1920, Some(1945)),
+                (Epoch::IIa, 1920, Some(1938)),
+                (Epoch::IIb, 1938, Some(1945)),
+                (Epoch::III, 1945, Some(1970)),
+                (Epoch::IIIa, 1945, Some(1956)),
+                (Epoch::IIIb, 1956, Some(1970)),
+                (Epoch::IV, 1970, Some(1990)),
+                (Epoch::IVa, 1970, Some(1980)),
+                (Epoch::IVb, 1980, Some(1990)),
+                (Epoch::V, 1990, Some(2005)),
+                (Epoch::VI, 2005, None),
+            ]),
+        },
+        EpochCalendar {
+            country: Some(CountryCode::CHE),
+            periods: periods(&[
+                (Epoch::I, 1847, Some(1920)),
+                (Epoch::II, 1920, Some(1945)),
+                (Epoch::III, 1945, Some(1970)),
+                (Epoch::IIIa, 1945, Some(1956)),
+                (Epoch::IIIb, 1956, Some(1970)),
+                (Epoch::IV, 1970, Some(1990)),
+                (Epoch::IVa, 1970, Some(1982)),
+                (Epoch::IVb, 1982, Some(1990)),
+                (Epoch::V, 1990, Some(2004)),
+                (Epoch::VI, 2004, None),
+            ]),
+        },
+        EpochCalendar {
+            country: Some(CountryCode::DEU),
+            periods: periods(&[
+                (Epoch::I, 1835, Some(1920)),
+                (Epoch::II, 1920, Some(1949)),
+                (Epoch::IIa, 1920, Some(1925)),
+                (Epoch::IIb, 1925, Some(1937)),
+                (Epoch::III, 1949, Some(1970)),
+                (Epoch::IIIa, 1949, Some(1956)),
+                (Epoch::IIIb, 1956, Some(1970)),
+                (Epoch::IV, 1970, Some(1990)),
+                (Epoch::IVa, 1970, Some(1980)),
+                (Epoch::IVb, 1980, Some(1990)),
+                (Epoch::V, 1990, Some(2006)),
+                (Epoch::Va, 1990, Some(1994)),
+                (Epoch::Vb, 1994, Some(2000)),
+                (Epoch::VI, 2006, None),
+            ]),
+        },
+        EpochCalendar {
+            country: Some(CountryCode::FRA),
+            periods: periods(&[
+                (Epoch::I, 1832, Some(1920)),
+                (Epoch::II, 1920, Some(1938)),
+                (Epoch::IIa, 1920, Some(1925)),
+                (Epoch::IIb, 1925, Some(1938)),
+                (Epoch::III, 1938, Some(1970)),
+                (Epoch::IIIa, 1938, Some(1950)),
+                (Epoch::IIIb, 1950, Some(1958)),
+                (Epoch::IIIc, 1958, Some(1970)),
+                (Epoch::IV, 1970, Some(1990)),
+                (Epoch::IVa, 1970, Some(1980)),
+                (Epoch::IVb, 1980, Some(1990)),
+                (Epoch::V, 1990, Some(2005)),
+                (Epoch::Va, 1990, Some(1998)),
+                (Epoch::Vb, 1998, Some(2005)),
+                (Epoch::VI, 2005, None),
+            ]),
+        },
+        EpochCalendar {
+            country: Some(CountryCode::ITA),
+            periods: periods(&[
+                (Epoch::I, 1839, Some(1905)),
+                (Epoch::II, 1905, Some(1945)),
+                (Epoch::IIa, 1905, Some(1925)),
+                (Epoch::IIb, 1925, Some(1945)),
+                (Epoch::III, 1945, Some(1970)),
+                (Epoch::IIIa, 1945, Some(1956)),
+                (Epoch::IIIb, 1956, Some(1963)),
+                (Epoch::IIIc, 1963, Some(1970)),
+                (Epoch::IV, 1970, Some(1990)),
+                (Epoch::IVa, 1970, Some(1980)),
+                (Epoch::IVb, 1980, Some(1990)),
+                (Epoch::V, 1990, Some(2007)),
+                (Epoch::Va, 1990, Some(1995)),
+                (Epoch::Vb, 1995, Some(2007)),
+                (Epoch::VI, 2007, None),
+            ]),
+        },
+    ];
+}
+
+fn periods(values: &[(Epoch, i32, Option<i32>)]) -> Vec<EpochPeriod> {
+    values
+        .iter()
+        .map(|(epoch, start_year, end_year)| EpochPeriod::new(epoch.clone(), *start_year, *end_year))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod epoch_periods {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        #[rstest]
+        #[case(1969, false)]
+        #[case(1970, true)]
+        #[case(1989, true)]
+        #[case(1990, false)]
+        fn it_should_check_whether_a_year_is_within_the_period(#[case] year: i32, #[case] expected: bool) {
+            let period = EpochPeriod::new(Epoch::IV, 1970, Some(1990));
+            assert_eq!(expected, period.contains(year));
+        }
+
+        #[test]
+        fn it_should_include_every_year_after_the_start_for_the_current_epoch() {
+            let period = EpochPeriod::new(Epoch::VI, 2007, None);
+            assert!(period.contains(2100));
+            assert!(!period.contains(2006));
+        }
+
+        #[test]
+        fn it_should_return_the_epoch_period_dates() {
+            let period = EpochPeriod::new(Epoch::IV, 1970, Some(1990));
+            assert_eq!(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(), period.start_date());
+            assert_eq!(NaiveDate::from_ymd_opt(1989, 12, 31), period.end_date());
+            assert_eq!(None, EpochPeriod::new(Epoch::VI, 2007, None).end_date());
+        }
+    }
+
+    mod epoch_calendars {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        #[test]
+        fn it_should_return_the_country_epoch_calendar() {
+            let calendar = EpochCalendar::for_country(CountryCode::ITA);
+            assert_eq!(Some(CountryCode::ITA), calendar.country);
+        }
+
+        #[test]
+        fn it_should_fallback_to_the_european_calendar() {
+            let calendar = EpochCalendar::for_country(CountryCode::USA);
+            assert_eq!(None, calendar.country);
+            assert_eq!(EpochCalendar::european(), calendar);
+        }
+
+        #[test]
+        fn it_should_order_the_periods_by_start_year() {
+            for calendar in EpochCalendar::country_calendars()
+                .iter()
+                .chain(std::iter::once(EpochCalendar::european()))
+            {
+                let start_years = calendar.periods.iter().map(|it| it.start_year).collect::<Vec<_>>();
+                let mut expected = start_years.clone();
+                expected.sort();
+                assert_eq!(expected, start_years);
+            }
+        }
+
+        #[rstest]
+        #[case(CountryCode::ITA, Epoch::III, 1945, Some(1970))]
+        #[case(CountryCode::DEU, Epoch::III, 1949, Some(1970))]
+        #[case(CountryCode::ITA, Epoch::IIIc, 1963, Some(1970))]
+        #[case(CountryCode::FRA, Epoch::II, 1920, Some(1938))]
+        #[case(CountryCode::ITA, Epoch::VI, 2007, None)]
+        fn it_should_return_the_epoch_periods(
+            #[case] country: CountryCode,
+            #[case] epoch: Epoch,
+            #[case] start_year: i32,
+            #[case] end_year: Option<i32>,
+        ) {
+            let calendar = EpochCalendar::for_country(country);
+            let period = calendar.period_of(&epoch);
+            assert_eq!(Some(EpochPeriod::new(epoch, start_year, end_year)), period);
+        }
+
+        #[test]
+        fn it_should_return_the_period_for_mixed_epochs() {
+            let calendar = EpochCalendar::for_country(CountryCode::ITA);
+            let epoch = "IV/V".parse::<Epoch>().unwrap();
+
+            let period = calendar.period_of(&epoch);
+
+            assert_eq!(Some(EpochPeriod::new(epoch, 1970, Some(2007))), period);
+        }
+
+        #[test]
+        fn it_should_return_no_period_for_epochs_not_in_the_calendar() {
+            let calendar = EpochCalendar::for_country(CountryCode::DEU);
+            assert_eq!(None, calendar.period_of(&Epoch::Vm));
+            assert_eq!(None, calendar.period_of(&"IV/Vm".parse::<Epoch>().unwrap()));
+        }
+
+        #[rstest]
+        #[case(CountryCode::ITA, 1965, Some(Epoch::IIIc))]
+        #[case(CountryCode::DEU, 1965, Some(Epoch::IIIb))]
+        #[case(CountryCode::DEU, 1940, Some(Epoch::II))]
+        #[case(CountryCode::DEU, 1920, Some(Epoch::IIa))]
+        #[case(CountryCode::CHE, 2024, Some(Epoch::VI))]
+        #[case(CountryCode::USA, 1965, Some(Epoch::III))]
+        #[case(CountryCode::ITA, 1800, None)]
+        fn it_should_return_the_epoch_for_a_year(
+            #[case] country: CountryCode,
+            #[case] year: i32,
+            #[case] expected: Option<Epoch>,
+        ) {
+            let calendar = EpochCalendar::for_country(country);
+            assert_eq!(expected.as_ref(), calendar.epoch_at(year));
+        }
+
+        #[test]
+        fn it_should_return_the_epoch_for_a_date() {
+            let calendar = EpochCalendar::for_country(CountryCode::ITA);
+            let date = NaiveDate::from_ymd_opt(1990, 6, 1).unwrap();
+            assert_eq!(Some(&Epoch::Va), calendar.epoch_on(date));
+        }
+
+        #[rstest]
+        #[case(CountryCode::ITA, 1965, "IIIc", true)]
+        #[case(CountryCode::ITA, 1965, "III", true)]
+        #[case(CountryCode::ITA, 1965, "IIIb", false)]
+        #[case(CountryCode::ITA, 1965, "III/IV/V", true)]
+        #[case(CountryCode::ITA, 1965, "IV", false)]
+        #[case(CountryCode::DEU, 1940, "II", true)]
+        #[case(CountryCode::DEU, 1940, "IIb", false)]
+        #[case(CountryCode::CHE, 1930, "IIa", true)]
+        #[case(CountryCode::USA, 1965, "III", true)]
+        fn it_should_return_the_epochs_range_for_a_year(
+            #[case] country: CountryCode,
+            #[case] year: i32,
+            #[case] epoch: &str,
+            #[case] expected: bool,
+        ) {
+            let calendar = EpochCalendar::for_country(country);
+            let epoch = epoch.parse::<Epoch>().unwrap();
+
+            let range = calendar.range_at(year).expect("the year has an epoch");
+
+            assert_eq!(expected, epoch.range().overlaps(&range));
+        }
+
+        #[test]
+        fn it_should_return_no_range_for_years_before_the_first_epoch() {
+            let calendar = EpochCalendar::for_country(CountryCode::ITA);
+            assert_eq!(None, calendar.range_at(1800));
+        }
+    }
+}
@@ -7,10 +7,11 @@ use std::fmt::Formatter;
 use std::str::FromStr;
 use std::{convert, fmt, ops, str};
 use thiserror::Error;
+use utoipa::ToSchema;
 use validator::{ValidateLength, ValidationError};
 
 /// It represents a catalog item number.
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize, Type)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize, Type, ToSchema)]
 #[sqlx(transparent)]
 pub struct ItemNumber(String);
 
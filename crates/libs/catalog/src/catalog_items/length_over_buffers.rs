@@ -6,17 +6,20 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use utoipa::ToSchema;
 use validator::{Validate, ValidationErrors};
 
 /// The rail vehicle measurement method expressed as the length over buffers
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
 #[serde(default)]
 pub struct LengthOverBuffers {
     /// the overall length in inches
     #[serde(with = "common::length::serde::inches_option")]
+    #[schema(value_type = Option<f64>)]
     pub inches: Option<Length>,
     /// the overall length in millimeters
     #[serde(with = "common::length::serde::millimeters_option")]
+    #[schema(value_type = Option<f64>)]
     pub millimeters: Option<Length>,
 }
 
@@ -1,5 +1,6 @@
 //! the module includes everything related to catalog items
 
+pub mod availability;
 pub mod availability_status;
 pub mod catalog_item;
 pub mod catalog_item_id;
@@ -10,16 +11,21 @@ pub mod commands;
 pub mod control;
 pub mod delivery_date;
 pub mod epoch;
+pub mod epoch_calendar;
 pub mod item_number;
 pub mod length_over_buffers;
 pub mod power_method;
 pub mod queries;
+pub mod release_calendar;
 pub mod rolling_stock;
 pub mod rolling_stock_id;
 pub mod rolling_stock_request;
 pub mod rolling_stock_response;
 pub mod service_level;
 pub mod technical_specifications;
+pub mod uic_coach_designation;
+pub mod uic_vehicle_number;
+pub mod uic_wagon_code;
 
 #[cfg(test)]
 pub mod test_data;
@@ -0,0 +1,56 @@
+use crate::catalog_items::availability::{Availability, AvailabilityChange};
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+
+/// The query to find the catalog item availability, together with the availability status history.
+///
+/// The `overdue` flag is computed as of `today`, it returns `None` when the catalog item does not exist
+/// (or it has been soft deleted).
+pub async fn find_availability<'db, U, Repo, DB>(
+    catalog_item_id: &CatalogItemId,
+    today: NaiveDate,
+    repo: Repo,
+    db: DB,
+) -> Result<Option<Availability>, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindAvailabilityRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let availability = repo.find_availability(catalog_item_id, &mut unit_of_work).await?;
+    let result = match availability {
+        Some(availability) => {
+            let history = repo
+                .find_availability_history(catalog_item_id, &mut unit_of_work)
+                .await?;
+            Some(availability.with_history(history).as_of(today))
+        }
+        None => None,
+    };
+
+    unit_of_work.commit().await?;
+
+    Ok(result)
+}
+
+#[async_trait]
+pub trait FindAvailabilityRepository<'db, U: UnitOfWork<'db>> {
+    /// Returns the current catalog item availability, without history
+    async fn find_availability(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut U,
+    ) -> Result<Option<Availability>, DatabaseError>;
+
+    /// Returns the availability status changes for the catalog item, the oldest first
+    async fn find_availability_history(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut U,
+    ) -> Result<Vec<AvailabilityChange>, DatabaseError>;
+}
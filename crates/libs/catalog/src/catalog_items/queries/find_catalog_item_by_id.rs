@@ -7,6 +7,7 @@ use common::unit_of_work::{Database, UnitOfWork};
 
 pub async fn find_catalog_item_by_id<'db, U, Repo, DB>(
     catalog_item_id: &CatalogItemId,
+    include_deleted: bool,
     repo: Repo,
     db: DB,
 ) -> Result<CatalogItem, QueryError>
@@ -19,13 +20,17 @@ where
 
     let catalog_item = repo.find_by_id(catalog_item_id, &mut unit_of_work).await?;
 
-    let result = if let Some(mut catalog_item) = catalog_item {
-        catalog_item.rolling_stocks = repo
-            .find_rolling_stocks_by_id(catalog_item_id, &mut unit_of_work)
-            .await?;
-        Ok(catalog_item)
-    } else {
-        Err(QueryError::EmptyResultSet)
+    let result = match catalog_item {
+        Some(catalog_item) if catalog_item.metadata.is_deleted() && !include_deleted => {
+            Err(QueryError::ResourceDeleted)
+        }
+        Some(mut catalog_item) => {
+            catalog_item.rolling_stocks = repo
+                .find_rolling_stocks_by_id(catalog_item_id, include_deleted, &mut unit_of_work)
+                .await?;
+            Ok(catalog_item)
+        }
+        None => Err(QueryError::EmptyResultSet),
     };
 
     unit_of_work.commit().await?;
@@ -44,9 +49,12 @@ pub trait FindCatalogItemByIdRepository<'db, U: UnitOfWork<'db>> {
 
 #[async_trait]
 pub trait FindRollingStocksByCatalogItemIdRepository<'db, U: UnitOfWork<'db>> {
+    /// Returns the rolling stocks for the catalog item, the soft deleted ones are included only
+    /// when `include_deleted` is `true`
     async fn find_rolling_stocks_by_id(
         &self,
         catalog_item_id: &CatalogItemId,
+        include_deleted: bool,
         unit_of_work: &mut U,
     ) -> Result<Vec<RollingStock>, DatabaseError>;
 }
@@ -0,0 +1,185 @@
+use crate::brands::brand_id::BrandId;
+use crate::catalog_items::availability_status::AvailabilityStatus;
+use crate::catalog_items::catalog_item::CatalogItem;
+use crate::catalog_items::epoch::Epoch;
+use crate::railways::railway_id::RailwayId;
+use crate::scales::scale_id::ScaleId;
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+
+/// The default number of catalog items returned by the query
+pub const DEFAULT_LIMIT: i64 = 50;
+/// The max number of catalog items returned by the query
+pub const MAX_LIMIT: i64 = 200;
+
+/// The criteria for the most recently created catalog items
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatestCatalogItemsCriteria {
+    /// only the catalog items for this brand
+    pub brand_id: Option<BrandId>,
+    /// only the catalog items in this scale
+    pub scale_id: Option<ScaleId>,
+    /// only the catalog items with this availability status
+    pub availability_status: Option<AvailabilityStatus>,
+    /// only the catalog items whose epoch overlaps this epoch
+    pub epoch: Option<Epoch>,
+    /// only the catalog items with rolling stocks for this railway
+    pub railway_id: Option<RailwayId>,
+    /// the railway predecessors and successors, whose catalog items are included as well
+    pub related_railway_ids: Vec<RailwayId>,
+    /// only the catalog items whose epoch includes this year, in the rolling stocks railway country calendar
+    pub year: Option<i32>,
+    /// the max number of catalog items
+    pub limit: i64,
+}
+
+impl LatestCatalogItemsCriteria {
+    /// Creates new criteria, the `limit` is capped to [`MAX_LIMIT`]
+    pub fn new(
+        brand_id: Option<BrandId>,
+        scale_id: Option<ScaleId>,
+        availability_status: Option<AvailabilityStatus>,
+        limit: Option<i64>,
+    ) -> Self {
+        LatestCatalogItemsCriteria {
+            brand_id,
+            scale_id,
+            availability_status,
+            epoch: None,
+            railway_id: None,
+            related_railway_ids: Vec::new(),
+            year: None,
+            limit: limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT),
+        }
+    }
+
+    /// Only the catalog items whose epoch overlaps the `epoch` (ie, `IV/V` for `Vb`)
+    pub fn with_epoch(mut self, epoch: Option<Epoch>) -> Self {
+        self.epoch = epoch;
+        self
+    }
+
+    /// Only the catalog items with rolling stocks for the `railway_id`
+    pub fn with_railway(mut self, railway_id: Option<RailwayId>) -> Self {
+        self.railway_id = railway_id;
+        self
+    }
+
+    /// Includes the catalog items for the railway predecessors and successors (ie, `FS` for `Trenitalia`),
+    /// it has no effect without a railway
+    pub fn with_related_railways(mut self, related_railway_ids: Vec<RailwayId>) -> Self {
+        self.related_railway_ids = related_railway_ids;
+        self
+    }
+
+    /// Only the catalog items valid in the `year`, the epochs are resolved with the
+    /// [`EpochCalendar`](crate::catalog_items::epoch_calendar::EpochCalendar) for the rolling stocks railway country
+    pub fn valid_in(mut self, year: Option<i32>) -> Self {
+        self.year = year;
+        self
+    }
+}
+
+impl Default for LatestCatalogItemsCriteria {
+    fn default() -> Self {
+        LatestCatalogItemsCriteria::new(None, None, None, None)
+    }
+}
+
+/// The query to find the most recently created catalog items, the newest first.
+///
+/// The soft deleted catalog items are never included, and the rolling stocks are not loaded.
+pub async fn find_latest_catalog_items<'db, U, Repo, DB>(
+    criteria: &LatestCatalogItemsCriteria,
+    repo: Repo,
+    db: DB,
+) -> Result<Vec<CatalogItem>, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindLatestCatalogItemsRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let result = repo.find_latest(criteria, &mut unit_of_work).await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(result)
+}
+
+#[async_trait]
+pub trait FindLatestCatalogItemsRepository<'db, U: UnitOfWork<'db>> {
+    /// Returns the catalog items matching the criteria, ordered by creation time (the newest first)
+    async fn find_latest(
+        &self,
+        criteria: &LatestCatalogItemsCriteria,
+        unit_of_work: &mut U,
+    ) -> Result<Vec<CatalogItem>, DatabaseError>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod latest_catalog_items_criteria {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_create_new_criteria() {
+            let criteria = LatestCatalogItemsCriteria::new(
+                Some(BrandId::new("ACME")),
+                Some(ScaleId::new("H0")),
+                Some(AvailabilityStatus::Announced),
+                Some(10),
+            );
+
+            assert_eq!(Some(BrandId::new("ACME")), criteria.brand_id);
+            assert_eq!(Some(ScaleId::new("H0")), criteria.scale_id);
+            assert_eq!(Some(AvailabilityStatus::Announced), criteria.availability_status);
+            assert_eq!(None, criteria.epoch);
+            assert_eq!(None, criteria.railway_id);
+            assert!(criteria.related_railway_ids.is_empty());
+            assert_eq!(None, criteria.year);
+            assert_eq!(10, criteria.limit);
+        }
+
+        #[test]
+        fn it_should_create_criteria_for_a_railway_in_a_year() {
+            let criteria = LatestCatalogItemsCriteria::default()
+                .with_railway(Some(RailwayId::new("FS")))
+                .valid_in(Some(1965));
+
+            assert_eq!(Some(RailwayId::new("FS")), criteria.railway_id);
+            assert_eq!(Some(1965), criteria.year);
+        }
+
+        #[test]
+        fn it_should_create_criteria_for_a_railway_and_its_related_railways() {
+            let criteria = LatestCatalogItemsCriteria::default()
+                .with_railway(Some(RailwayId::new("trenitalia")))
+                .with_related_railways(vec![RailwayId::new("fs")]);
+
+            assert_eq!(Some(RailwayId::new("trenitalia")), criteria.railway_id);
+            assert_eq!(vec![RailwayId::new("fs")], criteria.related_railway_ids);
+        }
+
+        #[test]
+        fn it_should_create_criteria_for_an_epoch() {
+            let criteria = LatestCatalogItemsCriteria::default().with_epoch(Some(Epoch::IV));
+            assert_eq!(Some(Epoch::IV), criteria.epoch);
+        }
+
+        #[test]
+        fn it_should_limit_the_number_of_catalog_items() {
+            assert_eq!(DEFAULT_LIMIT, LatestCatalogItemsCriteria::default().limit);
+            assert_eq!(
+                MAX_LIMIT,
+                LatestCatalogItemsCriteria::new(None, None, None, Some(10_000)).limit
+            );
+            assert_eq!(1, LatestCatalogItemsCriteria::new(None, None, None, Some(-1)).limit);
+        }
+    }
+}
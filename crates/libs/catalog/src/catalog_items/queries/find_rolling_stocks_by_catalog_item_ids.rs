@@ -0,0 +1,51 @@
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use crate::catalog_items::rolling_stock::RollingStock;
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+use std::collections::HashMap;
+
+/// The query to find the rolling stocks for the given catalog items, the soft deleted rolling stocks
+/// are not included.
+///
+/// The result includes an entry for each catalog item id, with an empty list when the catalog item is unknown.
+pub async fn find_rolling_stocks_by_catalog_item_ids<'db, U, Repo, DB>(
+    catalog_item_ids: &[CatalogItemId],
+    repo: Repo,
+    db: DB,
+) -> Result<HashMap<CatalogItemId, Vec<RollingStock>>, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindRollingStocksByCatalogItemIdsRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut result: HashMap<CatalogItemId, Vec<RollingStock>> =
+        catalog_item_ids.iter().map(|id| (id.clone(), Vec::new())).collect();
+    if catalog_item_ids.is_empty() {
+        return Ok(result);
+    }
+
+    let mut unit_of_work = db.begin().await?;
+
+    let rolling_stocks = repo
+        .find_rolling_stocks_by_ids(catalog_item_ids, &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    for (catalog_item_id, rolling_stock) in rolling_stocks {
+        result.entry(catalog_item_id).or_default().push(rolling_stock);
+    }
+
+    Ok(result)
+}
+
+#[async_trait]
+pub trait FindRollingStocksByCatalogItemIdsRepository<'db, U: UnitOfWork<'db>> {
+    /// Returns the (not deleted) rolling stocks for the catalog items, together with their catalog item id
+    async fn find_rolling_stocks_by_ids(
+        &self,
+        catalog_item_ids: &[CatalogItemId],
+        unit_of_work: &mut U,
+    ) -> Result<Vec<(CatalogItemId, RollingStock)>, DatabaseError>;
+}
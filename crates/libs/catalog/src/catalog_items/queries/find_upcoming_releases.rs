@@ -0,0 +1,176 @@
+use crate::brands::brand_id::BrandId;
+use crate::catalog_items::catalog_item::CatalogItem;
+use crate::catalog_items::delivery_date::{DeliveryDate, Year};
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+use thiserror::Error;
+
+/// The criteria for the upcoming releases
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleasesCriteria {
+    /// the first delivery period
+    pub from: DeliveryDate,
+    /// the last delivery period
+    pub to: DeliveryDate,
+    /// only the catalog items for these brands (all brands when empty)
+    pub brand_ids: Vec<BrandId>,
+}
+
+impl ReleasesCriteria {
+    /// Creates new criteria, the `from` delivery date cannot be after the `to` one and the
+    /// criteria cannot cover more than `max_years` years
+    pub fn new(
+        from: DeliveryDate,
+        to: DeliveryDate,
+        brand_ids: Vec<BrandId>,
+        max_years: i32,
+    ) -> Result<Self, ReleasesCriteriaError> {
+        if from.first_day() > to.last_day() {
+            return Err(ReleasesCriteriaError::InvalidPeriod);
+        }
+
+        if to.year() - from.year() >= max_years {
+            return Err(ReleasesCriteriaError::PeriodTooLong(max_years));
+        }
+
+        Ok(ReleasesCriteria { from, to, brand_ids })
+    }
+
+    /// the first year covered by the criteria
+    pub fn from_year(&self) -> Year {
+        self.from.year()
+    }
+
+    /// the last year covered by the criteria
+    pub fn to_year(&self) -> Year {
+        self.to.year()
+    }
+
+    /// Checks whether the `delivery_date` overlaps the period for these criteria
+    pub fn includes(&self, delivery_date: &DeliveryDate) -> bool {
+        delivery_date.first_day() <= self.to.last_day() && delivery_date.last_day() >= self.from.first_day()
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ReleasesCriteriaError {
+    #[error("The releases period start cannot be after its end")]
+    InvalidPeriod,
+    #[error("The releases period cannot be longer than {0} years")]
+    PeriodTooLong(i32),
+}
+
+/// The query to find the announced catalog items to be delivered in the criteria period.
+///
+/// The catalog items are sorted by delivery date, and the rolling stocks are not loaded.
+pub async fn find_upcoming_releases<'db, U, Repo, DB>(
+    criteria: &ReleasesCriteria,
+    repo: Repo,
+    db: DB,
+) -> Result<Vec<CatalogItem>, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindUpcomingReleasesRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let result = repo
+        .find_announced(
+            criteria.from_year(),
+            criteria.to_year(),
+            &criteria.brand_ids,
+            &mut unit_of_work,
+        )
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    let mut catalog_items: Vec<CatalogItem> = result
+        .into_iter()
+        .filter(|it| {
+            it.delivery_date
+                .as_ref()
+                .map(|dd| criteria.includes(dd))
+                .unwrap_or(false)
+        })
+        .collect();
+    catalog_items.sort_by(|a, b| {
+        (&a.delivery_date, a.catalog_item_id.value()).cmp(&(&b.delivery_date, b.catalog_item_id.value()))
+    });
+
+    Ok(catalog_items)
+}
+
+#[async_trait]
+pub trait FindUpcomingReleasesRepository<'db, U: UnitOfWork<'db>> {
+    /// Returns the announced catalog items with a delivery date between the two years (both included)
+    async fn find_announced(
+        &self,
+        from_year: Year,
+        to_year: Year,
+        brand_ids: &[BrandId],
+        unit_of_work: &mut U,
+    ) -> Result<Vec<CatalogItem>, DatabaseError>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod releases_criteria {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        const MAX_YEARS: i32 = 5;
+
+        #[test]
+        fn it_should_create_new_criteria() {
+            let criteria = ReleasesCriteria::new(
+                DeliveryDate::by_quarter(2026, 3),
+                DeliveryDate::by_year(2027),
+                vec![BrandId::new("ACME")],
+                MAX_YEARS,
+            )
+            .expect("the criteria are valid");
+
+            assert_eq!(2026, criteria.from_year());
+            assert_eq!(2027, criteria.to_year());
+            assert_eq!(vec![BrandId::new("ACME")], criteria.brand_ids);
+        }
+
+        #[rstest]
+        #[case("2027", "2026", ReleasesCriteriaError::InvalidPeriod)]
+        #[case("2026/11", "2026/10", ReleasesCriteriaError::InvalidPeriod)]
+        #[case("2026", "2031", ReleasesCriteriaError::PeriodTooLong(MAX_YEARS))]
+        fn it_should_reject_invalid_periods(
+            #[case] from: &str,
+            #[case] to: &str,
+            #[case] expected: ReleasesCriteriaError,
+        ) {
+            let result = ReleasesCriteria::new(from.parse().unwrap(), to.parse().unwrap(), Vec::new(), MAX_YEARS);
+            assert_eq!(Err(expected), result);
+        }
+
+        #[rstest]
+        #[case("2026/Q4", true)]
+        #[case("2026/Q2", false)]
+        #[case("2026/07", true)]
+        #[case("2026", true)]
+        #[case("2027/12", true)]
+        #[case("2028/01", false)]
+        fn it_should_check_whether_a_delivery_date_is_included(#[case] delivery_date: &str, #[case] expected: bool) {
+            let criteria = ReleasesCriteria::new(
+                DeliveryDate::by_quarter(2026, 3),
+                DeliveryDate::by_year(2027),
+                Vec::new(),
+                MAX_YEARS,
+            )
+            .expect("the criteria are valid");
+
+            assert_eq!(expected, criteria.includes(&delivery_date.parse().unwrap()));
+        }
+    }
+}
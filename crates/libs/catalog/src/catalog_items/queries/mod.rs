@@ -1,2 +1,6 @@
 //! the catalog items queries
+pub mod find_availability;
 pub mod find_catalog_item_by_id;
+pub mod find_latest_catalog_items;
+pub mod find_rolling_stocks_by_catalog_item_ids;
+pub mod find_upcoming_releases;
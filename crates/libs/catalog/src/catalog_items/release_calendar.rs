@@ -0,0 +1,269 @@
+//! the upcoming releases calendar
+
+use crate::catalog_items::catalog_item::CatalogItem;
+use crate::catalog_items::delivery_date::{DeliveryDate, Month};
+use chrono::{Datelike, Days, NaiveDate};
+use strum_macros::{Display, EnumString};
+use utoipa::ToSchema;
+
+/// The size for the release calendar periods
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, ToSchema)]
+#[strum(serialize_all = "lowercase")]
+#[strum(ascii_case_insensitive)]
+#[serde(rename_all = "lowercase")]
+pub enum Granularity {
+    /// one period for every month
+    #[default]
+    Month,
+    /// one period for every quarter
+    Quarter,
+    /// one period for every year
+    Year,
+}
+
+impl Granularity {
+    /// Returns the period, with this granularity, including the `date`
+    pub fn period_of(&self, date: NaiveDate) -> DeliveryDate {
+        match self {
+            Granularity::Month => DeliveryDate::month_of(date),
+            Granularity::Quarter => DeliveryDate::by_quarter(date.year(), (date.month0() / 3 + 1) as Month),
+            Granularity::Year => DeliveryDate::by_year(date.year()),
+        }
+    }
+
+    /// Returns the periods, with this granularity, from the `from` to the `to` delivery date (both included)
+    pub fn periods(&self, from: &DeliveryDate, to: &DeliveryDate) -> Vec<DeliveryDate> {
+        let mut periods = Vec::new();
+
+        let mut date = from.first_day();
+        while date <= to.last_day() {
+            let period = self.period_of(date);
+            date = period.last_day() + Days::new(1);
+            periods.push(period);
+        }
+
+        periods
+    }
+}
+
+/// It represents a period in the releases calendar, with the catalog items to be delivered
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct ReleaseBucket {
+    /// the calendar period
+    pub period: DeliveryDate,
+    /// the first day for the period
+    pub first_day: NaiveDate,
+    /// the last day for the period
+    pub last_day: NaiveDate,
+    /// the catalog items whose delivery date overlaps the period
+    pub catalog_items: Vec<CatalogItem>,
+}
+
+/// It represents the calendar for the announced catalog items, grouped by their delivery dates.
+///
+/// The catalog items are included in every period their delivery date overlaps: with a monthly
+/// calendar, an item to be delivered in _"2026/Q4"_ is included in October, November and December.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct ReleaseCalendar {
+    /// the first period for the calendar
+    pub from: DeliveryDate,
+    /// the last period for the calendar
+    pub to: DeliveryDate,
+    /// the calendar periods size
+    pub granularity: Granularity,
+    /// the calendar periods, in chronological order
+    pub buckets: Vec<ReleaseBucket>,
+}
+
+impl ReleaseCalendar {
+    /// Creates a new releases calendar, the catalog items without a delivery date are ignored
+    pub fn new(
+        from: DeliveryDate,
+        to: DeliveryDate,
+        granularity: Granularity,
+        mut catalog_items: Vec<CatalogItem>,
+    ) -> Self {
+        catalog_items.sort_by(|a, b| {
+            (&a.delivery_date, a.catalog_item_id.value()).cmp(&(&b.delivery_date, b.catalog_item_id.value()))
+        });
+
+        let buckets = granularity
+            .periods(&from, &to)
+            .into_iter()
+            .map(|period| {
+                let catalog_items = catalog_items
+                    .iter()
+                    .filter(|it| {
+                        it.delivery_date
+                            .as_ref()
+                            .map(|delivery_date| delivery_date.overlaps(&period))
+                            .unwrap_or(false)
+                    })
+                    .cloned()
+                    .collect();
+                ReleaseBucket {
+                    first_day: period.first_day(),
+                    last_day: period.last_day(),
+                    period,
+                    catalog_items,
+                }
+            })
+            .collect();
+
+        ReleaseCalendar {
+            from,
+            to,
+            granularity,
+            buckets,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod granularities {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+        use std::str::FromStr;
+
+        #[rstest]
+        #[case("month", Ok(Granularity::Month))]
+        #[case("QUARTER", Ok(Granularity::Quarter))]
+        #[case("year", Ok(Granularity::Year))]
+        #[case("week", Err(strum::ParseError::VariantNotFound))]
+        fn it_should_parse_granularities(
+            #[case] input: &str,
+            #[case] expected: Result<Granularity, strum::ParseError>,
+        ) {
+            assert_eq!(expected, Granularity::from_str(input));
+        }
+
+        #[test]
+        fn it_should_display_granularities() {
+            assert_eq!("quarter", Granularity::Quarter.to_string());
+        }
+
+        #[rstest]
+        #[case(Granularity::Month, "2026/11")]
+        #[case(Granularity::Quarter, "2026/Q4")]
+        #[case(Granularity::Year, "2026")]
+        fn it_should_return_the_period_for_a_date(#[case] granularity: Granularity, #[case] expected: &str) {
+            let date = NaiveDate::from_ymd_opt(2026, 11, 18).unwrap();
+            assert_eq!(expected, granularity.period_of(date).to_string());
+        }
+
+        #[rstest]
+        #[case(Granularity::Month, "2026/Q3", "2026/10", vec!["2026/07", "2026/08", "2026/09", "2026/10"])]
+        #[case(Granularity::Quarter, "2026/Q3", "2027", vec!["2026/Q3", "2026/Q4", "2027/Q1", "2027/Q2", "2027/Q3", "2027/Q4"])]
+        #[case(Granularity::Quarter, "2026/11", "2027/01", vec!["2026/Q4", "2027/Q1"])]
+        #[case(Granularity::Year, "2026/Q3", "2027", vec!["2026", "2027"])]
+        fn it_should_return_the_periods(
+            #[case] granularity: Granularity,
+            #[case] from: &str,
+            #[case] to: &str,
+            #[case] expected: Vec<&str>,
+        ) {
+            let from = from.parse::<DeliveryDate>().unwrap();
+            let to = to.parse::<DeliveryDate>().unwrap();
+
+            let periods: Vec<String> = granularity
+                .periods(&from, &to)
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+
+            assert_eq!(expected, periods);
+        }
+    }
+
+    mod release_calendars {
+        use super::*;
+        use crate::catalog_items::test_data::{Piko_52848, ACME_60142};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_group_the_catalog_items_by_period() {
+            let mut by_quarter = ACME_60142();
+            by_quarter.delivery_date = Some(DeliveryDate::by_quarter(2026, 4));
+            let mut by_month = Piko_52848();
+            by_month.delivery_date = Some(DeliveryDate::by_month(2026, 11));
+
+            let calendar = ReleaseCalendar::new(
+                DeliveryDate::by_month(2026, 9),
+                DeliveryDate::by_month(2026, 12),
+                Granularity::Month,
+                vec![by_quarter.clone(), by_month.clone()],
+            );
+
+            let buckets: Vec<(String, Vec<String>)> = calendar
+                .buckets
+                .iter()
+                .map(|bucket| {
+                    (
+                        bucket.period.to_string(),
+                        bucket
+                            .catalog_items
+                            .iter()
+                            .map(|it| it.catalog_item_id.to_string())
+                            .collect(),
+                    )
+                })
+                .collect();
+
+            let acme = by_quarter.catalog_item_id.to_string();
+            let piko = by_month.catalog_item_id.to_string();
+            assert_eq!(
+                vec![
+                    (String::from("2026/09"), vec![]),
+                    (String::from("2026/10"), vec![acme.clone()]),
+                    (String::from("2026/11"), vec![acme.clone(), piko]),
+                    (String::from("2026/12"), vec![acme]),
+                ],
+                buckets
+            );
+        }
+
+        #[test]
+        fn it_should_include_the_catalog_items_in_the_larger_periods() {
+            let mut by_month = ACME_60142();
+            by_month.delivery_date = Some(DeliveryDate::by_month(2026, 11));
+
+            let calendar = ReleaseCalendar::new(
+                DeliveryDate::by_year(2026),
+                DeliveryDate::by_year(2027),
+                Granularity::Quarter,
+                vec![by_month],
+            );
+
+            let sizes: Vec<usize> = calendar.buckets.iter().map(|it| it.catalog_items.len()).collect();
+            assert_eq!(vec![0, 0, 0, 1, 0, 0, 0, 0], sizes);
+            assert_eq!(
+                NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+                calendar.buckets[3].first_day
+            );
+            assert_eq!(
+                NaiveDate::from_ymd_opt(2026, 12, 31).unwrap(),
+                calendar.buckets[3].last_day
+            );
+        }
+
+        #[test]
+        fn it_should_ignore_the_catalog_items_without_delivery_date() {
+            let mut catalog_item = ACME_60142();
+            catalog_item.delivery_date = None;
+
+            let calendar = ReleaseCalendar::new(
+                DeliveryDate::by_year(2026),
+                DeliveryDate::by_year(2026),
+                Granularity::Year,
+                vec![catalog_item],
+            );
+
+            assert_eq!(1, calendar.buckets.len());
+            assert!(calendar.buckets[0].catalog_items.is_empty());
+        }
+    }
+}
@@ -8,11 +8,14 @@ use crate::catalog_items::length_over_buffers::LengthOverBuffers;
 use crate::catalog_items::rolling_stock_id::RollingStockId;
 use crate::catalog_items::service_level::ServiceLevel;
 use crate::catalog_items::technical_specifications::TechnicalSpecifications;
+use crate::catalog_items::uic_vehicle_number::UicVehicleNumber;
+use crate::prototypes::prototype_id::PrototypeId;
 use crate::railways::railway_id::RailwayId;
 use std::fmt;
 use std::fmt::{Display, Formatter};
+use utoipa::ToSchema;
 
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(tag = "category")]
 pub enum RollingStock {
     /// an electric multiple unit rolling stock
@@ -27,6 +30,9 @@ pub enum RollingStock {
         length_over_buffer: Option<LengthOverBuffers>,
         /// the technical specifications
         technical_specifications: Option<TechnicalSpecifications>,
+        /// the prototype modelled by this rolling stock
+        #[serde(default)]
+        prototype_id: Option<PrototypeId>,
         /// the electric multiple unit type name
         type_name: String,
         /// the identification marking for this electric multiple unit
@@ -56,10 +62,16 @@ pub enum RollingStock {
         length_over_buffer: Option<LengthOverBuffers>,
         /// the technical specifications
         technical_specifications: Option<TechnicalSpecifications>,
+        /// the prototype modelled by this rolling stock
+        #[serde(default)]
+        prototype_id: Option<PrototypeId>,
         /// the freight car type name
         type_name: String,
         /// the identification marking for this freight car
         road_number: Option<String>,
+        /// the road number decoded as UIC vehicle number
+        #[serde(default)]
+        uic_vehicle_number: Option<UicVehicleNumber>,
         /// the freight car type
         freight_car_type: Option<FreightCarType>,
     },
@@ -75,6 +87,9 @@ pub enum RollingStock {
         length_over_buffer: Option<LengthOverBuffers>,
         /// the technical specification
         technical_specifications: Option<TechnicalSpecifications>,
+        /// the prototype modelled by this rolling stock
+        #[serde(default)]
+        prototype_id: Option<PrototypeId>,
         /// the class of locomotives. The class is a group of locomotives built to a common design,
         /// typically for a single railroad or railway
         class_name: String,
@@ -105,10 +120,16 @@ pub enum RollingStock {
         length_over_buffer: Option<LengthOverBuffers>,
         /// the technical specifications
         technical_specifications: Option<TechnicalSpecifications>,
+        /// the prototype modelled by this rolling stock
+        #[serde(default)]
+        prototype_id: Option<PrototypeId>,
         /// the passenger car type name
         type_name: String,
         /// the identification marking for this passenger car
         road_number: Option<String>,
+        /// the road number decoded as UIC vehicle number
+        #[serde(default)]
+        uic_vehicle_number: Option<UicVehicleNumber>,
         /// the prototype series information
         series: Option<String>,
         /// the passenger car type
@@ -129,6 +150,9 @@ pub enum RollingStock {
         length_over_buffer: Option<LengthOverBuffers>,
         /// the technical specifications
         technical_specifications: Option<TechnicalSpecifications>,
+        /// the prototype modelled by this rolling stock
+        #[serde(default)]
+        prototype_id: Option<PrototypeId>,
         /// the railcar type name
         type_name: String,
         /// the identification marking for this railcar
@@ -171,6 +195,7 @@ impl RollingStock {
             livery: livery.map(str::to_string),
             length_over_buffer,
             technical_specifications,
+            prototype_id: None,
             type_name: String::from(type_name),
             road_number: road_number.map(str::to_string),
             series: series.map(str::to_string),
@@ -199,8 +224,10 @@ impl RollingStock {
             livery: livery.map(str::to_string),
             length_over_buffer,
             technical_specifications,
+            prototype_id: None,
             type_name: String::from(type_name),
             road_number: road_number.map(str::to_string),
+            uic_vehicle_number: road_number.and_then(|it| it.parse().ok()),
             freight_car_type,
         }
     }
@@ -227,6 +254,7 @@ impl RollingStock {
             livery: livery.map(str::to_string),
             length_over_buffer,
             technical_specifications,
+            prototype_id: None,
             class_name: String::from(class_name),
             road_number: String::from(road_number),
             series: series.map(str::to_string),
@@ -257,8 +285,10 @@ impl RollingStock {
             livery: livery.map(str::to_string),
             length_over_buffer,
             technical_specifications,
+            prototype_id: None,
             type_name: String::from(type_name),
             road_number: road_number.map(str::to_string),
+            uic_vehicle_number: road_number.and_then(|it| it.parse().ok()),
             series: series.map(str::to_string),
             passenger_car_type,
             service_level,
@@ -287,6 +317,7 @@ impl RollingStock {
             livery: livery.map(str::to_string),
             length_over_buffer,
             technical_specifications,
+            prototype_id: None,
             type_name: String::from(type_name),
             road_number: road_number.map(str::to_string),
             series: series.map(str::to_string),
@@ -298,6 +329,18 @@ impl RollingStock {
         }
     }
 
+    /// Sets the prototype modelled by this rolling stock
+    pub fn with_prototype(mut self, prototype: Option<PrototypeId>) -> Self {
+        match &mut self {
+            RollingStock::ElectricMultipleUnit { prototype_id, .. }
+            | RollingStock::Locomotive { prototype_id, .. }
+            | RollingStock::FreightCar { prototype_id, .. }
+            | RollingStock::PassengerCar { prototype_id, .. }
+            | RollingStock::Railcar { prototype_id, .. } => *prototype_id = prototype,
+        }
+        self
+    }
+
     /// The category for this rolling stock
     pub fn category(&self) -> RollingStockCategory {
         match self {
@@ -342,6 +385,17 @@ impl RollingStock {
         }
     }
 
+    /// The prototype modelled by this rolling stock, if any
+    pub fn prototype_id(&self) -> Option<&PrototypeId> {
+        match self {
+            RollingStock::ElectricMultipleUnit { prototype_id, .. } => prototype_id.as_ref(),
+            RollingStock::Locomotive { prototype_id, .. } => prototype_id.as_ref(),
+            RollingStock::FreightCar { prototype_id, .. } => prototype_id.as_ref(),
+            RollingStock::PassengerCar { prototype_id, .. } => prototype_id.as_ref(),
+            RollingStock::Railcar { prototype_id, .. } => prototype_id.as_ref(),
+        }
+    }
+
     /// The railway company for this rolling stock
     pub fn railway(&self) -> &RollingStockRailway {
         match self {
@@ -364,6 +418,15 @@ impl RollingStock {
         }
     }
 
+    /// The road number decoded as UIC vehicle number, only for freight and passenger cars
+    pub fn uic_vehicle_number(&self) -> Option<&UicVehicleNumber> {
+        match self {
+            RollingStock::FreightCar { uic_vehicle_number, .. } => uic_vehicle_number.as_ref(),
+            RollingStock::PassengerCar { uic_vehicle_number, .. } => uic_vehicle_number.as_ref(),
+            _ => None,
+        }
+    }
+
     /// The technical specification for this rolling stock
     pub fn technical_specifications(&self) -> Option<&TechnicalSpecifications> {
         match self {
@@ -442,7 +505,7 @@ impl RollingStock {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RollingStockRailway {
     /// the railway unique identifier
     pub railway_id: RailwayId,
@@ -587,6 +650,10 @@ mod test {
             assert_eq!(Some(&length), passenger_car.length_over_buffer());
             assert_eq!(&fs, passenger_car.railway());
             assert_eq!(Some("61 83 19-90 105-3 A"), passenger_car.road_number());
+            assert_eq!(
+                Some("618319901053"),
+                passenger_car.uic_vehicle_number().map(|it| it.number.as_str())
+            );
             assert_eq!(None, passenger_car.dcc_interface());
             assert_eq!(None, passenger_car.control());
             assert_eq!(Some(&tech_specs), passenger_car.technical_specifications());
@@ -652,6 +719,10 @@ mod test {
             assert_eq!(Some(&length), freight_car.length_over_buffer());
             assert_eq!(&fs, freight_car.railway());
             assert_eq!(Some("31 83 665 0 150-6"), freight_car.road_number());
+            assert_eq!(
+                Some("318366501506"),
+                freight_car.uic_vehicle_number().map(|it| it.number.as_str())
+            );
             assert_eq!(None, freight_car.dcc_interface());
             assert_eq!(None, freight_car.control());
             assert_eq!(Some(&tech_specs), freight_car.technical_specifications());
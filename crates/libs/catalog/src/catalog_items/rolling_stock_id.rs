@@ -4,10 +4,11 @@ use sqlx::Type;
 use std::fmt;
 use std::fmt::Formatter;
 use std::str;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// A unique identifier for a rolling stock
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Serialize, Deserialize, Type)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Serialize, Deserialize, Type, ToSchema)]
 #[sqlx(transparent)]
 pub struct RollingStockId(Uuid);
 
@@ -11,11 +11,14 @@ use crate::catalog_items::rolling_stock_request::validators::{
 };
 use crate::catalog_items::service_level::ServiceLevel;
 use crate::catalog_items::technical_specifications::TechnicalSpecifications;
+use crate::catalog_items::uic_vehicle_number::check_road_number;
+use crate::catalog_items::uic_wagon_code::check_freight_car_type;
 use common::validation::Validator;
+use utoipa::ToSchema;
 use validator::{Validate, ValidationErrors};
 
 /// It represents a request to create / modify a rolling stock
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
 #[serde(tag = "category")]
 pub enum RollingStockRequest {
     /// an electric multiple unit rolling stock
@@ -29,6 +32,9 @@ pub enum RollingStockRequest {
         length_over_buffers: Option<LengthOverBuffers>,
         /// the technical specifications
         technical_specifications: Option<TechnicalSpecifications>,
+        /// the prototype modelled by this rolling stock (its unique identifier)
+        #[serde(default)]
+        prototype: Option<String>,
         /// the electric multiple unit type name
         type_name: String,
         /// the identification marking for this electric multiple unit
@@ -57,6 +63,9 @@ pub enum RollingStockRequest {
         length_over_buffers: Option<LengthOverBuffers>,
         /// the technical specifications
         technical_specifications: Option<TechnicalSpecifications>,
+        /// the prototype modelled by this rolling stock (its unique identifier)
+        #[serde(default)]
+        prototype: Option<String>,
         /// the freight car type name
         type_name: String,
         /// the identification marking for this freight car
@@ -75,6 +84,9 @@ pub enum RollingStockRequest {
         length_over_buffers: Option<LengthOverBuffers>,
         /// the technical specifications
         technical_specifications: Option<TechnicalSpecifications>,
+        /// the prototype modelled by this rolling stock (its unique identifier)
+        #[serde(default)]
+        prototype: Option<String>,
         /// the class of locomotives. The class is a group of locomotives built to a common design,
         /// typically for a single railroad or railway
         class_name: String,
@@ -104,6 +116,9 @@ pub enum RollingStockRequest {
         length_over_buffers: Option<LengthOverBuffers>,
         /// the technical specifications
         technical_specifications: Option<TechnicalSpecifications>,
+        /// the prototype modelled by this rolling stock (its unique identifier)
+        #[serde(default)]
+        prototype: Option<String>,
         /// the passenger car type name
         type_name: String,
         /// the identification marking for this passenger car
@@ -127,6 +142,9 @@ pub enum RollingStockRequest {
         length_over_buffers: Option<LengthOverBuffers>,
         /// the technical specifications
         technical_specifications: Option<TechnicalSpecifications>,
+        /// the prototype modelled by this rolling stock (its unique identifier)
+        #[serde(default)]
+        prototype: Option<String>,
         /// the railcar type name
         type_name: String,
         /// the identification marking for this railcar
@@ -180,6 +198,28 @@ impl RollingStockRequest {
         }
     }
 
+    /// Returns the road number for this rolling stock request
+    pub fn road_number(&self) -> Option<&String> {
+        match self {
+            RollingStockRequest::ElectricMultipleUnitRequest { road_number, .. } => road_number.as_ref(),
+            RollingStockRequest::FreightCarRequest { road_number, .. } => road_number.as_ref(),
+            RollingStockRequest::LocomotiveRequest { road_number, .. } => Some(road_number),
+            RollingStockRequest::PassengerCarRequest { road_number, .. } => road_number.as_ref(),
+            RollingStockRequest::RailcarRequest { road_number, .. } => road_number.as_ref(),
+        }
+    }
+
+    /// Returns the prototype modelled by this rolling stock request
+    pub fn prototype(&self) -> Option<&String> {
+        match self {
+            RollingStockRequest::ElectricMultipleUnitRequest { prototype, .. } => prototype.as_ref(),
+            RollingStockRequest::FreightCarRequest { prototype, .. } => prototype.as_ref(),
+            RollingStockRequest::LocomotiveRequest { prototype, .. } => prototype.as_ref(),
+            RollingStockRequest::PassengerCarRequest { prototype, .. } => prototype.as_ref(),
+            RollingStockRequest::RailcarRequest { prototype, .. } => prototype.as_ref(),
+        }
+    }
+
     /// Returns the rolling stock length over buffers
     pub fn length_over_buffers(&self) -> Option<&LengthOverBuffers> {
         match self {
@@ -201,6 +241,31 @@ impl RollingStockRequest {
         }
     }
 
+    /// Returns the warnings for this rolling stock request, they don't prevent the request to be accepted.
+    ///
+    /// The freight and passenger cars road numbers are checked as UIC vehicle numbers, while the freight car
+    /// types are checked against the UIC classification codes in the type names.
+    pub fn warnings(&self) -> Vec<String> {
+        match self {
+            RollingStockRequest::FreightCarRequest {
+                type_name,
+                road_number,
+                freight_car_type,
+                ..
+            } => road_number
+                .as_deref()
+                .and_then(check_road_number)
+                .into_iter()
+                .chain(check_freight_car_type(type_name, *freight_car_type))
+                .collect(),
+            RollingStockRequest::PassengerCarRequest {
+                road_number: Some(road_number),
+                ..
+            } => check_road_number(road_number).into_iter().collect(),
+            _ => Vec::new(),
+        }
+    }
+
     /// Returns the technical specifications for this rolling stock request
     pub fn technical_specifications(&self) -> Option<&TechnicalSpecifications> {
         match self {
@@ -234,6 +299,7 @@ impl Validate for RollingStockRequest {
 
         validator.validate_length("railway", Some(2), Some(50), self.railway());
         validator.validate_length_optional("livery", None, Some(50), self.livery());
+        validator.validate_length_optional("prototype", Some(3), Some(160), self.prototype());
 
         match self {
             RollingStockRequest::LocomotiveRequest { .. } => validate_locomotive(&mut validator, self),
@@ -258,7 +324,10 @@ impl Validate for RollingStockRequest {
 
 mod validators {
     use crate::catalog_items::rolling_stock_request::RollingStockRequest;
+    use crate::catalog_items::uic_coach_designation::decode_passenger_car_type;
     use common::validation::Validator;
+    use std::borrow::Cow;
+    use validator::ValidationError;
 
     pub fn validate_freight_car(validator: &mut Validator, input: &RollingStockRequest) {
         if let RollingStockRequest::FreightCarRequest {
@@ -275,12 +344,52 @@ mod validators {
             type_name,
             road_number,
             series,
+            passenger_car_type,
+            service_level,
             ..
         } = input
         {
             validator.validate_length("type_name", Some(3), Some(25), type_name);
             validator.validate_length_optional("road_number", None, Some(50), road_number.as_ref());
             validator.validate_length_optional("series", None, Some(50), series.as_ref());
+
+            if (3..=25).contains(&type_name.chars().count()) {
+                if let Some(designation) = decode_passenger_car_type(type_name) {
+                    validate_uic_designation(
+                        validator,
+                        "passenger_car_type",
+                        type_name,
+                        passenger_car_type.as_ref(),
+                        Some(&designation.passenger_car_type),
+                    );
+                    validate_uic_designation(
+                        validator,
+                        "service_level",
+                        type_name,
+                        service_level.as_ref(),
+                        designation.service_level.as_ref(),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Checks the value is matching the one decoded from the UIC coach designation (when both are present)
+    fn validate_uic_designation<T: ToString + PartialEq>(
+        validator: &mut Validator,
+        field: &'static str,
+        type_name: &String,
+        value: Option<&T>,
+        expected: Option<&T>,
+    ) {
+        if let (Some(value), Some(expected)) = (value, expected) {
+            if value != expected {
+                let mut error = ValidationError::new("uic_designation");
+                error.add_param(Cow::from("value"), &value.to_string());
+                error.add_param(Cow::from("expected"), &expected.to_string());
+                error.add_param(Cow::from("type_name"), type_name);
+                validator.add_error(field, error);
+            }
         }
     }
 
@@ -387,6 +496,71 @@ mod test {
             assert_eq!(expected.as_ref(), request.technical_specifications());
         }
 
+        #[rstest]
+        #[case(Some("31 83 665 0 150-6"), 0)]
+        #[case(Some("31 80 665 1 234-5"), 1)]
+        #[case(Some("Gbhs 1234"), 0)]
+        #[case(None, 0)]
+        fn it_should_return_the_warnings_for_the_freight_car_road_numbers(
+            #[case] input: Option<&str>,
+            #[case] expected: usize,
+        ) {
+            let request = RollingStockRequest::FreightCarRequest {
+                railway: String::from("FS"),
+                livery: None,
+                length_over_buffers: None,
+                technical_specifications: None,
+                prototype: None,
+                type_name: String::from("Gbhs"),
+                road_number: input.map(str::to_string),
+                freight_car_type: Some(FreightCarType::CoveredFreightCars),
+            };
+            assert_eq!(expected, request.warnings().len());
+        }
+
+        #[rstest]
+        #[case("Gbhs", Some(FreightCarType::CoveredFreightCars), 0)]
+        #[case("Gbhs", Some(FreightCarType::TankCars), 1)]
+        #[case("Gbhs", None, 1)]
+        #[case("Carro chiuso", None, 0)]
+        fn it_should_return_the_warnings_for_the_freight_car_types(
+            #[case] type_name: &str,
+            #[case] freight_car_type: Option<FreightCarType>,
+            #[case] expected: usize,
+        ) {
+            let request = RollingStockRequest::FreightCarRequest {
+                railway: String::from("FS"),
+                livery: None,
+                length_over_buffers: None,
+                technical_specifications: None,
+                prototype: None,
+                type_name: String::from(type_name),
+                road_number: None,
+                freight_car_type,
+            };
+            assert_eq!(expected, request.warnings().len());
+        }
+
+        #[test]
+        fn it_should_not_return_warnings_for_the_locomotive_road_numbers() {
+            let request = RollingStockRequest::LocomotiveRequest {
+                railway: String::from("FS"),
+                livery: None,
+                length_over_buffers: None,
+                technical_specifications: None,
+                prototype: None,
+                class_name: String::from("E.444"),
+                road_number: String::from("91 83 2444 001-0"),
+                series: None,
+                depot: None,
+                locomotive_type: LocomotiveType::ElectricLocomotive,
+                dcc_interface: None,
+                control: None,
+                is_dummy: false,
+            };
+            assert!(request.warnings().is_empty());
+        }
+
         fn technical_specifications() -> Option<TechnicalSpecifications> {
             Some(TechnicalSpecifications::default())
         }
@@ -525,6 +699,7 @@ mod test {
                 livery: livery.map(String::to_string),
                 length_over_buffers: None,
                 technical_specifications: None,
+                prototype: None,
                 type_name,
                 road_number: road_number.map(String::to_string),
                 series: series.map(String::to_string),
@@ -635,6 +810,7 @@ mod test {
                 livery: livery.map(String::to_string),
                 length_over_buffers: None,
                 technical_specifications: None,
+                prototype: None,
                 type_name,
                 road_number: road_number.map(String::to_string),
                 freight_car_type: Some(FreightCarType::CoveredFreightCars),
@@ -780,6 +956,7 @@ mod test {
                 livery: livery.map(String::to_string),
                 length_over_buffers: None,
                 technical_specifications: None,
+                prototype: None,
                 class_name,
                 road_number,
                 series: series.map(String::to_string),
@@ -893,6 +1070,42 @@ mod test {
             assert_eq!(errors["livery"][0].params["max"], 50);
         }
 
+        #[rstest]
+        #[case("Apmz")]
+        #[case("Apmz 293")]
+        #[case("Corbellini")]
+        fn it_should_accept_passenger_cars_matching_the_uic_designation(#[case] input: String) {
+            let request = new_request_with(None, Some(&input), None, None, None);
+            let result = request.validate();
+            assert!(result.is_ok());
+        }
+
+        #[rstest]
+        #[case("Bpmz", "service_level", "FIRST_CLASS", "SECOND_CLASS")]
+        #[case("WRmz", "passenger_car_type", "OPEN_COACH", "DINING_CAR")]
+        #[case("WRmf", "passenger_car_type", "OPEN_COACH", "DINING_CAR")]
+        #[case("Acm", "passenger_car_type", "OPEN_COACH", "COMPARTMENT_COACH")]
+        #[case("Avmz", "passenger_car_type", "OPEN_COACH", "COMPARTMENT_COACH")]
+        fn it_should_validate_passenger_cars_against_the_uic_designation(
+            #[case] input: String,
+            #[case] field: &str,
+            #[case] value: &str,
+            #[case] expected: &str,
+        ) {
+            let request = new_request_with(None, Some(&input), None, None, None);
+
+            let result = request.validate();
+            let err = result.unwrap_err();
+            let errors = err.field_errors();
+            assert_eq!(1, errors.len());
+            assert!(errors.contains_key(field));
+            assert_eq!(errors[field].len(), 1);
+            assert_eq!(errors[field][0].code, "uic_designation");
+            assert_eq!(errors[field][0].params["value"], value);
+            assert_eq!(errors[field][0].params["expected"], expected);
+            assert_eq!(errors[field][0].params["type_name"], input);
+        }
+
         fn new_request_with(
             railway: Option<&String>,
             type_name: Option<&String>,
@@ -908,6 +1121,7 @@ mod test {
                 livery: livery.map(String::to_string),
                 length_over_buffers: None,
                 technical_specifications: None,
+                prototype: None,
                 type_name,
                 road_number: road_number.map(String::to_string),
                 series: series.map(String::to_string),
@@ -1050,6 +1264,7 @@ mod test {
                 livery: livery.map(String::to_string),
                 length_over_buffers: None,
                 technical_specifications: None,
+                prototype: None,
                 type_name,
                 road_number: road_number.map(String::to_string),
                 series: series.map(String::to_string),
@@ -1076,6 +1291,7 @@ pub mod data {
             livery: Some(String::from("castano/isabella")),
             length_over_buffers: None,
             technical_specifications: technical_specifications(),
+            prototype: None,
             type_name: "111".to_string(),
             road_number: None,
             series: None,
@@ -1094,6 +1310,7 @@ pub mod data {
             livery: Some(String::from("castano/isabella")),
             length_over_buffers: None,
             technical_specifications: technical_specifications(),
+            prototype: None,
             type_name: "111".to_string(),
             road_number: None,
             freight_car_type: None,
@@ -1107,6 +1324,7 @@ pub mod data {
             livery: Some(String::from("castano/isabella")),
             length_over_buffers: None,
             technical_specifications: technical_specifications(),
+            prototype: None,
             class_name: "111".to_string(),
             road_number: "999".to_string(),
             series: None,
@@ -1125,6 +1343,7 @@ pub mod data {
             livery: Some(String::from("castano/isabella")),
             length_over_buffers: None,
             technical_specifications: technical_specifications(),
+            prototype: None,
             type_name: "111".to_string(),
             road_number: None,
             series: None,
@@ -1140,6 +1359,7 @@ pub mod data {
             livery: Some(String::from("castano/isabella")),
             length_over_buffers: None,
             technical_specifications: technical_specifications(),
+            prototype: None,
             type_name: "111".to_string(),
             road_number: None,
             series: None,
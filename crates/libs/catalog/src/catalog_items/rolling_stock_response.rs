@@ -4,15 +4,22 @@ use crate::catalog_items::rolling_stock_id::RollingStockId;
 use chrono::{DateTime, Utc};
 
 /// A response for new rolling stocks creation
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RollingStockCreated {
     pub rolling_stock_id: RollingStockId,
     pub created_at: DateTime<Utc>,
 }
 
 /// A response for rolling stock updates
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RollingStockUpdated {
     pub rolling_stock_id: RollingStockId,
     pub last_modified_at: DateTime<Utc>,
 }
+
+/// A response for rolling stocks soft deletion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollingStockDeleted {
+    pub rolling_stock_id: RollingStockId,
+    pub deleted_at: DateTime<Utc>,
+}
@@ -4,11 +4,12 @@ use sqlx::Type;
 use std::str;
 use strum_macros;
 use strum_macros::{Display, EnumString};
+use utoipa::ToSchema;
 
 /// It represents the service level for a passenger cars, like first or second class.
 /// Values of service level can also include multiple service levels, like mixed first
 /// and second class.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, Type, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "service_level", rename_all = "SCREAMING_SNAKE_CASE")]
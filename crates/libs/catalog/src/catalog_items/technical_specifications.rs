@@ -9,11 +9,12 @@ use std::fmt::Formatter;
 use strum_macros;
 use strum_macros::{Display, EnumString};
 use thiserror::Error;
+use utoipa::ToSchema;
 use validator::Validate;
 use validator::ValidationError;
 
 /// It represents the coupling configuration for a rolling stock.
-#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub struct Coupling {
     /// the rolling stock coupling socket
     pub socket: Option<CouplingSocket>,
@@ -70,7 +71,7 @@ impl Coupling {
 }
 
 /// The NEM coupling socket standards
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, Display, Type, Default)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, Display, Type, Default, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "socket_type")]
@@ -125,7 +126,7 @@ pub enum CouplingSocket {
 }
 
 /// The technical specification data for a rolling stock model
-#[derive(Debug, Eq, PartialEq, Clone, Default, Serialize, Deserialize, Validate)]
+#[derive(Debug, Eq, PartialEq, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
 pub struct TechnicalSpecifications {
     /// the minimum drivable radius
     #[validate(custom(function = "validate_radius"))]
@@ -265,7 +266,7 @@ impl TechnicalSpecificationsBuilder {
 }
 
 /// A flag to indicate the presence/absence of a given technical specification feature
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize, EnumString, Display, Type, Default)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize, EnumString, Display, Type, Default, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "feature_flag", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -280,7 +281,7 @@ pub enum FeatureFlag {
     NotApplicable,
 }
 
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize, EnumString, Display, Type)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize, EnumString, Display, Type, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "body_shell_type", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -290,7 +291,7 @@ pub enum BodyShellType {
     MetalDieCast,
 }
 
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize, EnumString, Display, Type)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize, EnumString, Display, Type, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "chassis_type", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -301,8 +302,9 @@ pub enum ChassisType {
 }
 
 /// The minimum drivable radius
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize, Type)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize, Type, ToSchema)]
 #[sqlx(transparent, no_pg_array)]
+#[schema(value_type = f64)]
 pub struct Radius(#[serde(with = "common::length::serde::millimeters")] Length);
 
 impl Radius {
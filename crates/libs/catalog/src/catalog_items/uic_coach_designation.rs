@@ -0,0 +1,272 @@
+//! the UIC passenger coach type designations
+
+use crate::catalog_items::category::PassengerCarType;
+use crate::catalog_items::service_level::ServiceLevel;
+use std::fmt;
+use std::str;
+use thiserror::Error;
+use utoipa::ToSchema;
+
+/// The index letters in use for the passenger coaches, other letters (like `o` and `u`) are only
+/// found in plain words (ie, `Centoporte`)
+const INDEX_LETTERS: &str = "abcdefghiklmnprstvwxyz";
+
+/// The max number of index letters
+const MAX_INDEX_LETTERS: usize = 8;
+
+/// It represents a UIC passenger coach type designation (ie, `ABvmz`).
+///
+/// The designation is made of:
+/// * the type letters (uppercase), with the travel classes (`A`, `B` and `C`) or the special
+///   coaches (`WR` for dining cars, `WL` for sleeping cars, `D` for baggage cars and double deckers);
+/// * the index letters (lowercase), with the coach layout and equipment.
+///
+/// The meaning of the index letters is not fully standardised across the railways, only the most
+/// common ones are decoded.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UicCoachDesignation {
+    /// the type letters
+    pub type_letters: String,
+    /// the index letters
+    pub index_letters: String,
+    /// the service level, for the coaches with travel classes
+    pub service_level: Option<ServiceLevel>,
+    /// the passenger car type
+    pub passenger_car_type: PassengerCarType,
+    /// whether the coach is air conditioned (`z`)
+    pub is_air_conditioned: bool,
+    /// whether the coach has couchettes (`c`)
+    pub is_couchette: bool,
+    /// whether the coach has a driving cab (`f`)
+    pub has_driving_cab: bool,
+    /// whether the coach is longer than 24 metres (`m`)
+    pub is_long: bool,
+    /// whether the coach is wheelchair accessible (`b`)
+    pub is_wheelchair_accessible: bool,
+}
+
+impl str::FromStr for UicCoachDesignation {
+    type Err = UicCoachDesignationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(UicCoachDesignationError::EmptyValue);
+        }
+
+        let type_letters_end = s.find(|c: char| !c.is_ascii_uppercase()).unwrap_or(s.len());
+        let (type_letters, index_letters) = s.split_at(type_letters_end);
+        if type_letters.is_empty()
+            || index_letters.len() > MAX_INDEX_LETTERS
+            || !index_letters.chars().all(|c| INDEX_LETTERS.contains(c))
+        {
+            return Err(UicCoachDesignationError::InvalidDesignation(s.to_string()));
+        }
+
+        let has_index = |letter: char| index_letters.contains(letter);
+        let (service_level, special_type) = decode_type_letters(type_letters)
+            .ok_or_else(|| UicCoachDesignationError::InvalidDesignation(s.to_string()))?;
+
+        // the special coaches type letters win over the index letters, ie `WRmf` is a dining car with a
+        // driving cab, and the couchettes (`c`) are compartment coaches
+        let passenger_car_type = match special_type {
+            Some(passenger_car_type) => passenger_car_type,
+            None if has_index('f') => PassengerCarType::DrivingTrailer,
+            None if has_index('p') => PassengerCarType::OpenCoach,
+            None => PassengerCarType::CompartmentCoach,
+        };
+
+        Ok(UicCoachDesignation {
+            type_letters: type_letters.to_string(),
+            index_letters: index_letters.to_string(),
+            service_level,
+            passenger_car_type,
+            is_air_conditioned: has_index('z'),
+            is_couchette: has_index('c'),
+            has_driving_cab: has_index('f'),
+            is_long: has_index('m'),
+            is_wheelchair_accessible: has_index('b'),
+        })
+    }
+}
+
+impl fmt::Display for UicCoachDesignation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.type_letters, self.index_letters)
+    }
+}
+
+/// Decodes the type letters as the service level and, for the special coaches, the passenger car type
+fn decode_type_letters(type_letters: &str) -> Option<(Option<ServiceLevel>, Option<PassengerCarType>)> {
+    if let Some(classes) = type_letters.strip_prefix("WR") {
+        return Some((service_level_optional(classes)?, Some(PassengerCarType::DiningCar)));
+    }
+    if let Some(classes) = type_letters.strip_prefix("WL") {
+        return Some((service_level_optional(classes)?, Some(PassengerCarType::SleepingCar)));
+    }
+    if type_letters == "D" {
+        return Some((None, Some(PassengerCarType::BaggageCar)));
+    }
+    if let Some(classes) = type_letters.strip_prefix('D') {
+        return Some((Some(service_level(classes)?), Some(PassengerCarType::DoubleDecker)));
+    }
+
+    let classes_end = type_letters
+        .find(|c: char| !matches!(c, 'A' | 'B' | 'C'))
+        .unwrap_or(type_letters.len());
+    let (classes, special) = type_letters.split_at(classes_end);
+    let special_type = match special {
+        "" => None,
+        "D" => Some(PassengerCarType::CombineCar),
+        "R" => Some(PassengerCarType::DiningCar),
+        _ => return None,
+    };
+    Some((Some(service_level(classes)?), special_type))
+}
+
+fn service_level(classes: &str) -> Option<ServiceLevel> {
+    match classes {
+        "A" => Some(ServiceLevel::FirstClass),
+        "B" => Some(ServiceLevel::SecondClass),
+        "C" => Some(ServiceLevel::ThirdClass),
+        "AB" => Some(ServiceLevel::FirstAndSecondClass),
+        "ABC" => Some(ServiceLevel::FirstSecondAndThirdClass),
+        "BC" => Some(ServiceLevel::SecondAndThirdClass),
+        _ => None,
+    }
+}
+
+fn service_level_optional(classes: &str) -> Option<Option<ServiceLevel>> {
+    if classes.is_empty() {
+        Some(None)
+    } else {
+        service_level(classes).map(Some)
+    }
+}
+
+/// Returns the passenger car type and the service level for the passenger car type name, when it is
+/// a UIC coach designation (optionally followed by other words, ie `Bpmz 293`)
+pub fn decode_passenger_car_type(type_name: &str) -> Option<UicCoachDesignation> {
+    type_name
+        .split_whitespace()
+        .next()
+        .and_then(|designation| designation.parse::<UicCoachDesignation>().ok())
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum UicCoachDesignationError {
+    #[error("UIC coach designation cannot be blank")]
+    EmptyValue,
+    #[error("UIC coach designation is not valid (found: {0})")]
+    InvalidDesignation(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod uic_coach_designations {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+        use std::str::FromStr;
+
+        #[test]
+        fn it_should_parse_uic_coach_designations() {
+            let result = UicCoachDesignation::from_str("ABvmz");
+
+            let designation = result.expect("invalid UIC coach designation");
+            assert_eq!("AB", designation.type_letters);
+            assert_eq!("vmz", designation.index_letters);
+            assert_eq!(Some(ServiceLevel::FirstAndSecondClass), designation.service_level);
+            assert_eq!(PassengerCarType::CompartmentCoach, designation.passenger_car_type);
+            assert!(designation.is_air_conditioned);
+            assert!(designation.is_long);
+            assert!(!designation.is_wheelchair_accessible);
+            assert!(!designation.is_couchette);
+            assert!(!designation.has_driving_cab);
+            assert_eq!("ABvmz", designation.to_string());
+        }
+
+        #[rstest]
+        #[case("Bcm", true, false)]
+        #[case("WRmf", false, true)]
+        #[case("Bdf", false, true)]
+        #[case("Bpmz", false, false)]
+        fn it_should_decode_the_couchettes_and_the_driving_cabs(
+            #[case] input: &str,
+            #[case] is_couchette: bool,
+            #[case] has_driving_cab: bool,
+        ) {
+            let designation = UicCoachDesignation::from_str(input).expect("invalid UIC coach designation");
+            assert_eq!(is_couchette, designation.is_couchette);
+            assert_eq!(has_driving_cab, designation.has_driving_cab);
+        }
+
+        #[rstest]
+        #[case("Am", Some(ServiceLevel::FirstClass), PassengerCarType::CompartmentCoach)]
+        #[case("Bpmz", Some(ServiceLevel::SecondClass), PassengerCarType::OpenCoach)]
+        #[case("Bcm", Some(ServiceLevel::SecondClass), PassengerCarType::CompartmentCoach)]
+        #[case("Bdf", Some(ServiceLevel::SecondClass), PassengerCarType::DrivingTrailer)]
+        #[case(
+            "ABC",
+            Some(ServiceLevel::FirstSecondAndThirdClass),
+            PassengerCarType::CompartmentCoach
+        )]
+        #[case("BC", Some(ServiceLevel::SecondAndThirdClass), PassengerCarType::CompartmentCoach)]
+        #[case("BD", Some(ServiceLevel::SecondClass), PassengerCarType::CombineCar)]
+        #[case("ARmz", Some(ServiceLevel::FirstClass), PassengerCarType::DiningCar)]
+        #[case("WRmz", None, PassengerCarType::DiningCar)]
+        #[case("WRmf", None, PassengerCarType::DiningCar)]
+        #[case("WLABm", Some(ServiceLevel::FirstAndSecondClass), PassengerCarType::SleepingCar)]
+        #[case("WLABmz", Some(ServiceLevel::FirstAndSecondClass), PassengerCarType::SleepingCar)]
+        #[case("DABpza", Some(ServiceLevel::FirstAndSecondClass), PassengerCarType::DoubleDecker)]
+        #[case("Dm", None, PassengerCarType::BaggageCar)]
+        fn it_should_decode_the_service_level_and_the_passenger_car_type(
+            #[case] input: &str,
+            #[case] service_level: Option<ServiceLevel>,
+            #[case] passenger_car_type: PassengerCarType,
+        ) {
+            let designation = UicCoachDesignation::from_str(input).expect("invalid UIC coach designation");
+            assert_eq!(service_level, designation.service_level);
+            assert_eq!(passenger_car_type, designation.passenger_car_type);
+        }
+
+        #[rstest]
+        #[case("", UicCoachDesignationError::EmptyValue)]
+        #[case("  ", UicCoachDesignationError::EmptyValue)]
+        #[case("bpmz", UicCoachDesignationError::InvalidDesignation(String::from("bpmz")))]
+        #[case("AX", UicCoachDesignationError::InvalidDesignation(String::from("AX")))]
+        #[case("BA", UicCoachDesignationError::InvalidDesignation(String::from("BA")))]
+        #[case("Bpmz 293", UicCoachDesignationError::InvalidDesignation(String::from("Bpmz 293")))]
+        #[case("Bpm3", UicCoachDesignationError::InvalidDesignation(String::from("Bpm3")))]
+        #[case(
+            "Centoporte",
+            UicCoachDesignationError::InvalidDesignation(String::from("Centoporte"))
+        )]
+        #[case(
+            "Bpmbdzfhit",
+            UicCoachDesignationError::InvalidDesignation(String::from("Bpmbdzfhit"))
+        )]
+        fn it_should_reject_invalid_uic_coach_designations(
+            #[case] input: &str,
+            #[case] expected: UicCoachDesignationError,
+        ) {
+            assert_eq!(Err(expected), UicCoachDesignation::from_str(input));
+        }
+    }
+
+    mod passenger_car_type_decoding {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_decode_the_passenger_car_type_names() {
+            let designation = decode_passenger_car_type("Bpmz 293").expect("invalid UIC coach designation");
+            assert_eq!(Some(ServiceLevel::SecondClass), designation.service_level);
+            assert_eq!(PassengerCarType::OpenCoach, designation.passenger_car_type);
+
+            assert_eq!(None, decode_passenger_car_type("Carrozza tipo 1959"));
+        }
+    }
+}
@@ -0,0 +1,275 @@
+//! the UIC vehicle numbers
+
+use std::fmt;
+use std::str;
+use strum_macros::Display;
+use thiserror::Error;
+use utoipa::ToSchema;
+
+/// The number of digits in a UIC vehicle number, including the self-check digit
+const DIGITS: usize = 12;
+
+/// It represents a 12 digits UIC vehicle number (ie, `31 83 665 0 150-6`).
+///
+/// The number is made of:
+/// * the interchangeability code (digits 1-2), its first digit is the kind of vehicle;
+/// * the owner country code (digits 3-4);
+/// * the type code (digits 5-8), with the vehicle technical characteristics;
+/// * the serial number (digits 9-11);
+/// * the self-check digit (digit 12), computed with the Luhn algorithm.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UicVehicleNumber {
+    /// the 12 digits, without separators
+    pub number: String,
+    /// the interchangeability code
+    pub interchangeability_code: String,
+    /// the owner country code
+    pub country_code: String,
+    /// the type code
+    pub type_code: String,
+    /// the serial number
+    pub serial_number: String,
+    /// the self-check digit
+    pub check_digit: u8,
+    /// the kind of vehicle, from the first digit
+    pub vehicle_kind: UicVehicleKind,
+    /// whether the self-check digit matches the other digits
+    pub is_valid: bool,
+    /// the vehicle keeper marking (VKM), ie `TI` from `I-TI`
+    #[serde(default)]
+    pub vehicle_keeper_marking: Option<String>,
+}
+
+impl UicVehicleNumber {
+    /// The self-check digit for this vehicle number, computed from the first 11 digits
+    pub fn expected_check_digit(&self) -> u8 {
+        check_digit(&self.number.as_bytes()[..DIGITS - 1])
+    }
+}
+
+impl str::FromStr for UicVehicleNumber {
+    type Err = UicVehicleNumberError;
+
+    /// Parses a UIC vehicle number, the digits can be separated by spaces or hyphens and followed
+    /// by a letters suffix (ie, `61 83 19-90 105-3 A`) or by the vehicle keeper marking, with the
+    /// keeper country (ie, `91 83 2444 001-8 I-TI`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(UicVehicleNumberError::EmptyValue);
+        }
+
+        let digits_end = s
+            .find(|c: char| !(c.is_ascii_digit() || c == ' ' || c == '-'))
+            .unwrap_or(s.len());
+        let (digits, suffix) = s.split_at(digits_end);
+        let vehicle_keeper_marking = vehicle_keeper_marking(suffix.trim());
+        if vehicle_keeper_marking.is_none() && !suffix.chars().all(|c| c.is_alphabetic() || c.is_whitespace()) {
+            return Err(UicVehicleNumberError::InvalidCharacters);
+        }
+
+        let number: String = digits.chars().filter(char::is_ascii_digit).collect();
+        if number.len() != DIGITS {
+            return Err(UicVehicleNumberError::InvalidLength(number.len()));
+        }
+
+        let bytes = number.as_bytes();
+        let check_digit_value = bytes[DIGITS - 1] - b'0';
+        let is_valid = check_digit(&bytes[..DIGITS - 1]) == check_digit_value;
+        Ok(UicVehicleNumber {
+            interchangeability_code: number[0..2].to_string(),
+            country_code: number[2..4].to_string(),
+            type_code: number[4..8].to_string(),
+            serial_number: number[8..11].to_string(),
+            check_digit: check_digit_value,
+            vehicle_kind: UicVehicleKind::from_digit(bytes[0] - b'0'),
+            is_valid,
+            number,
+            vehicle_keeper_marking,
+        })
+    }
+}
+
+/// Extracts the vehicle keeper marking from the suffix, made of the keeper country letters and the
+/// keeper code separated by a hyphen (ie, `CH-SBB`)
+fn vehicle_keeper_marking(suffix: &str) -> Option<String> {
+    let (country, keeper) = suffix.split_once('-')?;
+    let is_country = (1..=3).contains(&country.len()) && country.chars().all(|c| c.is_ascii_uppercase());
+    let is_keeper = (1..=5).contains(&keeper.len()) && keeper.chars().all(|c| c.is_ascii_alphanumeric());
+    if is_country && is_keeper {
+        Some(keeper.to_uppercase())
+    } else {
+        None
+    }
+}
+
+impl fmt::Display for UicVehicleNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}-{}",
+            self.interchangeability_code, self.country_code, self.type_code, self.serial_number, self.check_digit
+        )
+    }
+}
+
+/// The Luhn self-check digit: the digits are multiplied alternately by 2 and 1 (starting from the first one),
+/// the digits of the products are added together, the check digit is the amount needed to reach the next ten
+fn check_digit(digits: &[u8]) -> u8 {
+    let sum: u32 = digits
+        .iter()
+        .enumerate()
+        .map(|(index, digit)| {
+            let value = u32::from(digit - b'0') * if index % 2 == 0 { 2 } else { 1 };
+            value / 10 + value % 10
+        })
+        .sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// The kind of vehicle encoded in the UIC vehicle number first digit
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Display, ToSchema)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum UicVehicleKind {
+    /// Freight cars (first digit `0` to `4` or `8`).
+    #[serde(rename = "FREIGHT_CAR")]
+    FreightCar,
+
+    /// Passenger cars (first digit `5` to `7`).
+    #[serde(rename = "PASSENGER_CAR")]
+    PassengerCar,
+
+    /// Tractive units, like locomotives and multiple units (first digit `9`).
+    #[serde(rename = "TRACTIVE_UNIT")]
+    TractiveUnit,
+}
+
+impl UicVehicleKind {
+    fn from_digit(digit: u8) -> Self {
+        match digit {
+            5..=7 => UicVehicleKind::PassengerCar,
+            9 => UicVehicleKind::TractiveUnit,
+            _ => UicVehicleKind::FreightCar,
+        }
+    }
+}
+
+/// Checks the road number for a freight or a passenger car, it returns a warning when the road number
+/// is a UIC vehicle number with a wrong self-check digit.
+///
+/// Road numbers in other formats (ie, national numbering schemes) are not checked.
+pub fn check_road_number(road_number: &str) -> Option<String> {
+    match road_number.parse::<UicVehicleNumber>() {
+        Ok(uic_number) if !uic_number.is_valid => Some(format!(
+            "the road number '{road_number}' has an invalid UIC self-check digit (expected: {})",
+            uic_number.expected_check_digit()
+        )),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum UicVehicleNumberError {
+    #[error("UIC vehicle number cannot be blank")]
+    EmptyValue,
+    #[error("UIC vehicle number can only contain digits, spaces and hyphens (followed by a letters suffix)")]
+    InvalidCharacters,
+    #[error("UIC vehicle number must have 12 digits (found: {0})")]
+    InvalidLength(usize),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod uic_vehicle_numbers {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+        use std::str::FromStr;
+
+        #[test]
+        fn it_should_parse_uic_vehicle_numbers() {
+            let result = UicVehicleNumber::from_str("31 83 665 0 150-6");
+
+            let uic_number = result.expect("invalid UIC vehicle number");
+            assert_eq!("318366501506", uic_number.number);
+            assert_eq!("31", uic_number.interchangeability_code);
+            assert_eq!("83", uic_number.country_code);
+            assert_eq!("6650", uic_number.type_code);
+            assert_eq!("150", uic_number.serial_number);
+            assert_eq!(6, uic_number.check_digit);
+            assert_eq!(UicVehicleKind::FreightCar, uic_number.vehicle_kind);
+            assert!(uic_number.is_valid);
+            assert_eq!(None, uic_number.vehicle_keeper_marking);
+            assert_eq!("31 83 6650 150-6", uic_number.to_string());
+        }
+
+        #[rstest]
+        #[case("91 83 2444 001-8 I-TI", Some("TI"))]
+        #[case("91 85 4460 001-1 CH-SBB", Some("SBB"))]
+        #[case("61 80 19-90 105-3 D-DB", Some("DB"))]
+        #[case("61 83 19-90 105-3 A", None)]
+        fn it_should_parse_the_vehicle_keeper_markings(#[case] input: &str, #[case] expected: Option<&str>) {
+            let uic_number = UicVehicleNumber::from_str(input).expect("invalid UIC vehicle number");
+            assert_eq!(expected.map(str::to_string), uic_number.vehicle_keeper_marking);
+        }
+
+        #[rstest]
+        #[case("31 83 665 0 150-6", UicVehicleKind::FreightCar, true)]
+        #[case("318366501506", UicVehicleKind::FreightCar, true)]
+        #[case("31-83-665-0-150-6", UicVehicleKind::FreightCar, true)]
+        #[case("61 83 19-90 105-3 A", UicVehicleKind::PassengerCar, true)]
+        #[case("91 83 2444 001-8", UicVehicleKind::TractiveUnit, true)]
+        #[case("31 80 665 1 234-5", UicVehicleKind::FreightCar, false)]
+        fn it_should_check_the_self_check_digit(
+            #[case] input: &str,
+            #[case] vehicle_kind: UicVehicleKind,
+            #[case] is_valid: bool,
+        ) {
+            let uic_number = UicVehicleNumber::from_str(input).expect("invalid UIC vehicle number");
+            assert_eq!(vehicle_kind, uic_number.vehicle_kind);
+            assert_eq!(is_valid, uic_number.is_valid);
+        }
+
+        #[rstest]
+        #[case("", UicVehicleNumberError::EmptyValue)]
+        #[case("   ", UicVehicleNumberError::EmptyValue)]
+        #[case("E.656 077", UicVehicleNumberError::InvalidCharacters)]
+        #[case("31 83 665 0 150", UicVehicleNumberError::InvalidLength(11))]
+        #[case("31 83 665 0 150-6 7", UicVehicleNumberError::InvalidLength(13))]
+        #[case("31 83 665 0 150-6 A1", UicVehicleNumberError::InvalidCharacters)]
+        #[case("31 83 665 0 150-6 I-", UicVehicleNumberError::InvalidCharacters)]
+        #[case("31 83 665 0 150-6 i-ti", UicVehicleNumberError::InvalidCharacters)]
+        fn it_should_reject_invalid_uic_vehicle_numbers(#[case] input: &str, #[case] expected: UicVehicleNumberError) {
+            assert_eq!(Err(expected), UicVehicleNumber::from_str(input));
+        }
+
+        #[test]
+        fn it_should_compute_the_expected_check_digit() {
+            let uic_number = UicVehicleNumber::from_str("31 80 665 1 234-5").unwrap();
+            assert_eq!(0, uic_number.expected_check_digit());
+        }
+    }
+
+    mod road_number_checks {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_return_a_warning_for_invalid_check_digits() {
+            assert_eq!(
+                Some(String::from(
+                    "the road number '31 80 665 1 234-5' has an invalid UIC self-check digit (expected: 0)"
+                )),
+                check_road_number("31 80 665 1 234-5")
+            );
+        }
+
+        #[test]
+        fn it_should_not_return_warnings_for_valid_or_national_road_numbers() {
+            assert_eq!(None, check_road_number("31 83 665 0 150-6"));
+            assert_eq!(None, check_road_number("Gbhs 1234"));
+        }
+    }
+}
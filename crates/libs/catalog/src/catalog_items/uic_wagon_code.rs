@@ -0,0 +1,312 @@
+//! the UIC freight wagon classification letters
+
+use crate::catalog_items::category::FreightCarType;
+use std::fmt;
+use std::str;
+use strum_macros::Display;
+use thiserror::Error;
+use utoipa::ToSchema;
+
+/// It represents a UIC freight wagon classification code (ie, `Habbins`).
+///
+/// The code is made of:
+/// * the category letter (uppercase), the main kind of wagon;
+/// * the index letters (lowercase), with the wagon technical characteristics.
+///
+/// The meaning of the index letters depends on the category, only the most common ones
+/// (axles count, load limits and maximum speed) are decoded.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UicWagonCode {
+    /// the category letter
+    pub category: char,
+    /// the index letters
+    pub index_letters: String,
+    /// the freight car type for the category
+    pub freight_car_type: FreightCarType,
+    /// the number of axles
+    pub axles: UicAxles,
+    /// the load limit
+    pub load_limit: UicLoadLimit,
+    /// the maximum speed (in km/h) when it is above the standard one
+    pub max_speed: Option<u16>,
+}
+
+impl UicWagonCode {
+    /// Checks whether this is a bogie flat wagon category, where the index letters
+    /// are counting the extra axles
+    fn is_bogie_flat_wagon(category: char) -> bool {
+        matches!(category, 'R' | 'S')
+    }
+}
+
+impl str::FromStr for UicWagonCode {
+    type Err = UicWagonCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let mut chars = s.chars();
+        let category = chars.next().ok_or(UicWagonCodeError::EmptyValue)?;
+        let index_letters = chars.as_str();
+
+        if !index_letters.chars().all(|c| c.is_ascii_lowercase()) {
+            return Err(UicWagonCodeError::InvalidIndexLetters(index_letters.to_string()));
+        }
+
+        let count = |letter: char| index_letters.chars().filter(|c| *c == letter).count();
+        let freight_car_type = freight_car_type(category, index_letters)?;
+
+        let axles = if UicWagonCode::is_bogie_flat_wagon(category) {
+            match count('a') {
+                0 => UicAxles::Four,
+                1 => UicAxles::Six,
+                _ => UicAxles::MoreThanSix,
+            }
+        } else {
+            match count('a') {
+                0 => UicAxles::Two,
+                1 => UicAxles::Four,
+                2 => UicAxles::Six,
+                _ => UicAxles::MoreThanSix,
+            }
+        };
+
+        let load_limit = match (count('k'), count('n')) {
+            (1, _) => UicLoadLimit::Low,
+            (k, _) if k > 1 => UicLoadLimit::Medium,
+            (_, n) if n > 0 => UicLoadLimit::High,
+            _ => UicLoadLimit::Standard,
+        };
+
+        let max_speed = match count('s') {
+            0 => None,
+            1 => Some(100),
+            _ => Some(120),
+        };
+
+        Ok(UicWagonCode {
+            category,
+            index_letters: index_letters.to_string(),
+            freight_car_type,
+            axles,
+            load_limit,
+            max_speed,
+        })
+    }
+}
+
+impl fmt::Display for UicWagonCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.category, self.index_letters)
+    }
+}
+
+/// Maps the category letter (and, for a few categories, the index letters) to the freight car type
+fn freight_car_type(category: char, index_letters: &str) -> Result<FreightCarType, UicWagonCodeError> {
+    let has_index = |letter: char| index_letters.contains(letter);
+    let freight_car_type = match category {
+        'E' => FreightCarType::Gondola,
+        'F' => FreightCarType::HopperWagon,
+        'G' => FreightCarType::CoveredFreightCars,
+        'H' if has_index('i') => FreightCarType::SlidingWallBoxcars,
+        'H' => FreightCarType::CoveredFreightCars,
+        'I' => FreightCarType::RefrigeratorCars,
+        'K' | 'O' | 'R' => FreightCarType::StakeWagons,
+        'L' | 'S' if has_index('g') => FreightCarType::ContainerCars,
+        'L' => FreightCarType::AutoTransportCars,
+        'S' if has_index('i') => FreightCarType::TelescopeHoodWagons,
+        'S' => FreightCarType::SpecialTransport,
+        'T' => FreightCarType::SwingRoofWagon,
+        'U' => FreightCarType::SiloContainerCars,
+        'Z' => FreightCarType::TankCars,
+        _ => return Err(UicWagonCodeError::InvalidCategory(category)),
+    };
+    Ok(freight_car_type)
+}
+
+/// The number of axles for a freight wagon
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Display, ToSchema)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UicAxles {
+    Two,
+    Four,
+    Six,
+    MoreThanSix,
+}
+
+/// The load limit for a freight wagon, compared to the standard one for its category
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Display, ToSchema)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UicLoadLimit {
+    /// the `k` index letter
+    Low,
+    /// the `kk` index letter
+    Medium,
+    /// no index letters for the load limit
+    Standard,
+    /// the `n` index letter
+    High,
+}
+
+/// Returns the freight car type for the freight car type name, when it is a UIC classification code
+/// (optionally followed by other words, ie `Gbs 254`)
+pub fn suggest_freight_car_type(type_name: &str) -> Option<FreightCarType> {
+    type_name
+        .split_whitespace()
+        .next()
+        .and_then(|code| code.parse::<UicWagonCode>().ok())
+        .map(|it| it.freight_car_type)
+}
+
+/// Checks the freight car type against the one for the type name UIC classification code, it returns a warning
+/// when the two are not matching or the freight car type is missing.
+pub fn check_freight_car_type(type_name: &str, freight_car_type: Option<FreightCarType>) -> Option<String> {
+    let suggested = suggest_freight_car_type(type_name)?;
+    match freight_car_type {
+        Some(freight_car_type) if freight_car_type != suggested => Some(format!(
+            "the freight car type '{freight_car_type}' does not match the UIC code '{type_name}' (expected: {suggested})"
+        )),
+        None => Some(format!(
+            "the freight car type is missing, the UIC code '{type_name}' suggests: {suggested}"
+        )),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum UicWagonCodeError {
+    #[error("UIC wagon code cannot be blank")]
+    EmptyValue,
+    #[error("UIC wagon code has an invalid category letter (found: {0})")]
+    InvalidCategory(char),
+    #[error("UIC wagon code index letters must be lowercase letters (found: {0})")]
+    InvalidIndexLetters(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod uic_wagon_codes {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+        use std::str::FromStr;
+
+        #[test]
+        fn it_should_parse_uic_wagon_codes() {
+            let result = UicWagonCode::from_str("Habbins");
+
+            let code = result.expect("invalid UIC wagon code");
+            assert_eq!('H', code.category);
+            assert_eq!("abbins", code.index_letters);
+            assert_eq!(FreightCarType::SlidingWallBoxcars, code.freight_car_type);
+            assert_eq!(UicAxles::Four, code.axles);
+            assert_eq!(UicLoadLimit::High, code.load_limit);
+            assert_eq!(Some(100), code.max_speed);
+            assert_eq!("Habbins", code.to_string());
+        }
+
+        #[rstest]
+        #[case("Eaos", FreightCarType::Gondola)]
+        #[case("Fals", FreightCarType::HopperWagon)]
+        #[case("Gbs", FreightCarType::CoveredFreightCars)]
+        #[case("Hbis", FreightCarType::SlidingWallBoxcars)]
+        #[case("Hcs", FreightCarType::CoveredFreightCars)]
+        #[case("Ibbhs", FreightCarType::RefrigeratorCars)]
+        #[case("Ks", FreightCarType::StakeWagons)]
+        #[case("Laaers", FreightCarType::AutoTransportCars)]
+        #[case("Lgs", FreightCarType::ContainerCars)]
+        #[case("Res", FreightCarType::StakeWagons)]
+        #[case("Sgnss", FreightCarType::ContainerCars)]
+        #[case("Shimmns", FreightCarType::TelescopeHoodWagons)]
+        #[case("Samms", FreightCarType::SpecialTransport)]
+        #[case("Tadns", FreightCarType::SwingRoofWagon)]
+        #[case("Uacs", FreightCarType::SiloContainerCars)]
+        #[case("Zacns", FreightCarType::TankCars)]
+        fn it_should_map_the_category_letters(#[case] input: &str, #[case] expected: FreightCarType) {
+            let code = UicWagonCode::from_str(input).expect("invalid UIC wagon code");
+            assert_eq!(expected, code.freight_car_type);
+        }
+
+        #[rstest]
+        #[case("Gbs", UicAxles::Two)]
+        #[case("Eaos", UicAxles::Four)]
+        #[case("Faals", UicAxles::Six)]
+        #[case("Res", UicAxles::Four)]
+        #[case("Samms", UicAxles::Six)]
+        #[case("Saadkms", UicAxles::MoreThanSix)]
+        fn it_should_decode_the_axles_count(#[case] input: &str, #[case] expected: UicAxles) {
+            let code = UicWagonCode::from_str(input).expect("invalid UIC wagon code");
+            assert_eq!(expected, code.axles);
+        }
+
+        #[rstest]
+        #[case("Eaos", UicLoadLimit::Standard, Some(100))]
+        #[case("Gbk", UicLoadLimit::Low, None)]
+        #[case("Gbkkss", UicLoadLimit::Medium, Some(120))]
+        #[case("Zacns", UicLoadLimit::High, Some(100))]
+        fn it_should_decode_the_load_limit_and_max_speed(
+            #[case] input: &str,
+            #[case] load_limit: UicLoadLimit,
+            #[case] max_speed: Option<u16>,
+        ) {
+            let code = UicWagonCode::from_str(input).expect("invalid UIC wagon code");
+            assert_eq!(load_limit, code.load_limit);
+            assert_eq!(max_speed, code.max_speed);
+        }
+
+        #[rstest]
+        #[case("", UicWagonCodeError::EmptyValue)]
+        #[case("   ", UicWagonCodeError::EmptyValue)]
+        #[case("Xabs", UicWagonCodeError::InvalidCategory('X'))]
+        #[case("eaos", UicWagonCodeError::InvalidCategory('e'))]
+        #[case("Gbs 254", UicWagonCodeError::InvalidIndexLetters(String::from("bs 254")))]
+        #[case("GBS", UicWagonCodeError::InvalidIndexLetters(String::from("BS")))]
+        fn it_should_reject_invalid_uic_wagon_codes(#[case] input: &str, #[case] expected: UicWagonCodeError) {
+            assert_eq!(Err(expected), UicWagonCode::from_str(input));
+        }
+    }
+
+    mod freight_car_type_checks {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_suggest_the_freight_car_type() {
+            assert_eq!(Some(FreightCarType::Gondola), suggest_freight_car_type("Eaos"));
+            assert_eq!(
+                Some(FreightCarType::CoveredFreightCars),
+                suggest_freight_car_type("Gbs 254")
+            );
+            assert_eq!(None, suggest_freight_car_type("Carro tramoggia"));
+        }
+
+        #[test]
+        fn it_should_return_a_warning_when_the_freight_car_type_does_not_match() {
+            assert_eq!(
+                Some(String::from(
+                    "the freight car type 'TANK_CARS' does not match the UIC code 'Eaos' (expected: GONDOLA)"
+                )),
+                check_freight_car_type("Eaos", Some(FreightCarType::TankCars))
+            );
+        }
+
+        #[test]
+        fn it_should_return_a_warning_when_the_freight_car_type_is_missing() {
+            assert_eq!(
+                Some(String::from(
+                    "the freight car type is missing, the UIC code 'Zacns' suggests: TANK_CARS"
+                )),
+                check_freight_car_type("Zacns", None)
+            );
+        }
+
+        #[test]
+        fn it_should_not_return_warnings_for_matching_or_unknown_codes() {
+            assert_eq!(None, check_freight_car_type("Eaos", Some(FreightCarType::Gondola)));
+            assert_eq!(None, check_freight_car_type("Carro tramoggia", None));
+        }
+    }
+}
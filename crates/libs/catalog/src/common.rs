@@ -3,13 +3,14 @@
 use sqlx::Type;
 use strum_macros;
 use strum_macros::{Display, EnumString};
+use utoipa::ToSchema;
 
 /// In rail transport, track gauge is the distance between the two rails of a railway track.
 /// All vehicles on a rail network must have wheel sets that are compatible with the track gauge.
 ///
 /// Since many different track gauges exist worldwide, gauge differences often present a barrier to wider operation on
 /// railway networks.
-#[derive(Debug, Eq, PartialEq, Clone, Copy, Serialize, Deserialize, EnumString, Display, Type, Default)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Serialize, Deserialize, EnumString, Display, Type, Default, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "track_gauge", rename_all = "SCREAMING_SNAKE_CASE")]
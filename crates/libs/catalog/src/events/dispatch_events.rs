@@ -0,0 +1,267 @@
+//! the command to dispatch the pending domain events from the outbox
+
+use crate::events::event_sink::EventSink;
+use crate::events::repositories::{ClaimedEvent, PendingEventsRepository};
+use crate::events::retry_policy::RetryPolicy;
+use chrono::{Duration, Utc};
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+
+pub type Result<R> = result::Result<R, EventsDispatchError>;
+
+/// Publishes a batch of pending events from the outbox to the event sink.
+///
+/// The events are claimed in a short transaction, published outside of it, and then the outcome
+/// of each event is recorded: a claimed event is not dispatched by other dispatchers until the
+/// claim expires. Events which the sink fails to publish are dispatched again later, according to the
+/// retry policy, until they run out of attempts and they are dead lettered.
+pub async fn dispatch_pending_events<'db, U, Repo, S, DB>(
+    batch_size: i64,
+    retry_policy: &RetryPolicy,
+    claim_timeout: Duration,
+    sink: &S,
+    repo: Repo,
+    db: DB,
+) -> Result<EventsDispatched>
+where
+    U: UnitOfWork<'db>,
+    Repo: PendingEventsRepository<'db, U>,
+    S: EventSink + ?Sized,
+    DB: Database<'db, U> + Clone,
+{
+    let now = Utc::now();
+    let mut unit_of_work = db.clone().begin().await?;
+    let claimed = repo
+        .claim_pending(batch_size, &now, &(now + claim_timeout), &mut unit_of_work)
+        .await?;
+    unit_of_work.commit().await?;
+
+    let mut result = EventsDispatched::default();
+    for ClaimedEvent { envelope, attempts } in claimed {
+        let outcome = sink.publish(&envelope).await;
+
+        let attempted_at = Utc::now();
+        let mut unit_of_work = db.clone().begin().await?;
+        match outcome {
+            Ok(_) => {
+                repo.mark_dispatched(&envelope.event_id, &attempted_at, &mut unit_of_work)
+                    .await?;
+                result.dispatched += 1;
+            }
+            Err(why) => {
+                let next_attempt_at = retry_policy.next_attempt_at(attempts + 1, attempted_at);
+                repo.mark_failed(
+                    &envelope.event_id,
+                    &format!("{why:#}"),
+                    &attempted_at,
+                    next_attempt_at.as_ref(),
+                    &mut unit_of_work,
+                )
+                .await?;
+                if next_attempt_at.is_some() {
+                    result.failed += 1;
+                } else {
+                    result.dead_lettered += 1;
+                }
+            }
+        }
+        unit_of_work.commit().await?;
+    }
+
+    Ok(result)
+}
+
+/// The outcome for a batch of dispatched events
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EventsDispatched {
+    /// the number of events published to the sink
+    pub dispatched: usize,
+    /// the number of events the sink failed to publish, they will be dispatched again
+    pub failed: usize,
+    /// the number of events the sink failed to publish, which ran out of attempts
+    pub dead_lettered: usize,
+}
+
+#[derive(Debug, Error)]
+pub enum EventsDispatchError {
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod dispatch_pending_events_command {
+        use super::*;
+        use crate::brands::brand_id::BrandId;
+        use crate::brands::brand_response::{BrandCreated, BrandDeleted};
+        use crate::events::domain_event::DomainEvent;
+        use crate::events::event_sink::in_memory::InMemoryEventSink;
+        use crate::events::repositories::in_memory::InMemoryPendingEventsRepository;
+        use chrono::TimeZone;
+        use common::unit_of_work::noop::{NoOpDatabase, NoOpUnitOfWork};
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_dispatch_the_pending_events() {
+            let repo = InMemoryPendingEventsRepository::with(vec![brand_created(), brand_deleted()]);
+            let sink = InMemoryEventSink::new();
+
+            let result =
+                dispatch_pending_events(10, &retry_policy(), claim_timeout(), &sink, repo.clone(), NoOpDatabase).await;
+
+            let dispatched = result.expect("result is an error");
+            assert_eq!(
+                EventsDispatched {
+                    dispatched: 2,
+                    failed: 0,
+                    dead_lettered: 0,
+                },
+                dispatched
+            );
+
+            let published: Vec<DomainEvent> = sink.events().into_iter().map(|it| it.event).collect();
+            assert_eq!(vec![brand_created(), brand_deleted()], published);
+            assert!(repo.entries().iter().all(|it| it.dispatched_at.is_some()));
+        }
+
+        #[tokio::test]
+        async fn it_should_dispatch_at_most_batch_size_events() {
+            let repo = InMemoryPendingEventsRepository::with(vec![brand_created(), brand_deleted()]);
+            let sink = InMemoryEventSink::new();
+
+            let result =
+                dispatch_pending_events(1, &retry_policy(), claim_timeout(), &sink, repo.clone(), NoOpDatabase).await;
+
+            let dispatched = result.expect("result is an error");
+            assert_eq!(1, dispatched.dispatched);
+            assert_eq!(1, sink.events().len());
+            assert_eq!(1, repo.entries().iter().filter(|it| it.dispatched_at.is_none()).count());
+        }
+
+        #[tokio::test]
+        async fn it_should_keep_the_events_pending_when_the_sink_fails() {
+            let repo = InMemoryPendingEventsRepository::with(vec![brand_created()]);
+            let sink = InMemoryEventSink::failing();
+
+            let result =
+                dispatch_pending_events(10, &retry_policy(), claim_timeout(), &sink, repo.clone(), NoOpDatabase).await;
+
+            let dispatched = result.expect("result is an error");
+            assert_eq!(
+                EventsDispatched {
+                    dispatched: 0,
+                    failed: 1,
+                    dead_lettered: 0,
+                },
+                dispatched
+            );
+
+            let entries = repo.entries();
+            assert_eq!(None, entries[0].dispatched_at);
+            assert_eq!(1, entries[0].attempts);
+            assert_eq!(Some(String::from("unable to publish the event")), entries[0].last_error);
+            assert_eq!(None, entries[0].dead_lettered_at);
+            assert!(entries[0].next_attempt_at > Utc::now() + Duration::seconds(5));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_block_the_newer_events_while_a_failed_event_waits_for_its_backoff() {
+            let repo = InMemoryPendingEventsRepository::with(vec![brand_created(), brand_deleted()]);
+
+            let failing_sink = InMemoryEventSink::failing();
+            let result = dispatch_pending_events(
+                1,
+                &retry_policy(),
+                claim_timeout(),
+                &failing_sink,
+                repo.clone(),
+                NoOpDatabase,
+            )
+            .await;
+            assert_eq!(1, result.expect("result is an error").failed);
+
+            let sink = InMemoryEventSink::new();
+            let result =
+                dispatch_pending_events(1, &retry_policy(), claim_timeout(), &sink, repo.clone(), NoOpDatabase).await;
+            assert_eq!(1, result.expect("result is an error").dispatched);
+
+            let published: Vec<DomainEvent> = sink.events().into_iter().map(|it| it.event).collect();
+            assert_eq!(vec![brand_deleted()], published);
+        }
+
+        #[tokio::test]
+        async fn it_should_dead_letter_the_events_when_they_run_out_of_attempts() {
+            let repo = InMemoryPendingEventsRepository::with_attempts(vec![brand_created()], 2);
+            let sink = InMemoryEventSink::failing();
+
+            let result =
+                dispatch_pending_events(10, &retry_policy(), claim_timeout(), &sink, repo.clone(), NoOpDatabase).await;
+
+            let dispatched = result.expect("result is an error");
+            assert_eq!(
+                EventsDispatched {
+                    dispatched: 0,
+                    failed: 0,
+                    dead_lettered: 1,
+                },
+                dispatched
+            );
+
+            let entries = repo.entries();
+            assert_eq!(3, entries[0].attempts);
+            assert!(entries[0].dead_lettered_at.is_some());
+
+            let sink = InMemoryEventSink::new();
+            let result =
+                dispatch_pending_events(10, &retry_policy(), claim_timeout(), &sink, repo.clone(), NoOpDatabase).await;
+            assert_eq!(EventsDispatched::default(), result.expect("result is an error"));
+        }
+
+        #[tokio::test]
+        async fn it_should_skip_the_events_claimed_by_another_dispatcher() {
+            let repo = InMemoryPendingEventsRepository::with(vec![brand_created()]);
+            let now = Utc::now();
+            let claimed = repo
+                .claim_pending(10, &now, &(now + claim_timeout()), &mut NoOpUnitOfWork::default())
+                .await
+                .expect("unable to claim the events");
+            assert_eq!(1, claimed.len());
+
+            let sink = InMemoryEventSink::new();
+            let result =
+                dispatch_pending_events(10, &retry_policy(), claim_timeout(), &sink, repo.clone(), NoOpDatabase).await;
+
+            assert_eq!(EventsDispatched::default(), result.expect("result is an error"));
+            assert!(sink.events().is_empty());
+        }
+
+        fn retry_policy() -> RetryPolicy {
+            RetryPolicy::new(3, Duration::seconds(10), Duration::minutes(5))
+        }
+
+        fn claim_timeout() -> Duration {
+            Duration::minutes(1)
+        }
+
+        fn brand_created() -> DomainEvent {
+            DomainEvent::BrandCreated(BrandCreated {
+                brand_id: BrandId::new("ACME"),
+                created_at: Utc.with_ymd_and_hms(1988, 11, 25, 0, 0, 0).unwrap(),
+            })
+        }
+
+        fn brand_deleted() -> DomainEvent {
+            DomainEvent::BrandDeleted(BrandDeleted {
+                brand_id: BrandId::new("ACME"),
+                deleted_at: Utc.with_ymd_and_hms(1988, 11, 26, 0, 0, 0).unwrap(),
+            })
+        }
+    }
+}
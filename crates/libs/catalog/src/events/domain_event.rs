@@ -0,0 +1,272 @@
+//! the catalog domain events
+
+use crate::brands::brand_response::{BrandCreated, BrandDeleted, BrandUpdated};
+use crate::catalog_items::catalog_item_response::{
+    CatalogItemAvailabilityChanged, CatalogItemCreated, CatalogItemDeleted, CatalogItemUpdated,
+};
+use crate::catalog_items::rolling_stock_response::{RollingStockDeleted, RollingStockUpdated};
+use crate::prototypes::prototype_response::PrototypeCreated;
+use crate::railways::railway_response::{RailwayCreated, RailwayDeleted, RailwayUpdated};
+use crate::scales::scale_response::{ScaleCreated, ScaleDeleted, ScaleUpdated};
+use chrono::{DateTime, Utc};
+use strum::VariantNames as _;
+use strum_macros::{Display, EnumString, IntoStaticStr, VariantNames};
+use uuid::Uuid;
+
+/// It represents a change to the catalog, which downstream systems might want to react to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, IntoStaticStr, VariantNames)]
+#[serde(tag = "event_type", content = "payload")]
+pub enum DomainEvent {
+    BrandCreated(BrandCreated),
+    BrandDeleted(BrandDeleted),
+    BrandRestored(BrandUpdated),
+    CatalogItemAvailabilityChanged(CatalogItemAvailabilityChanged),
+    CatalogItemCreated(CatalogItemCreated),
+    CatalogItemDeleted(CatalogItemDeleted),
+    CatalogItemRestored(CatalogItemUpdated),
+    PrototypeCreated(PrototypeCreated),
+    RailwayCreated(RailwayCreated),
+    RailwayDeleted(RailwayDeleted),
+    RailwayRelationshipAdded(RailwayUpdated),
+    RailwayRestored(RailwayUpdated),
+    RollingStockDeleted(RollingStockDeleted),
+    RollingStockRestored(RollingStockUpdated),
+    ScaleCreated(ScaleCreated),
+    ScaleDeleted(ScaleDeleted),
+    ScaleRestored(ScaleUpdated),
+}
+
+impl DomainEvent {
+    /// Checks whether `event_type` is a valid domain event type name
+    pub fn is_event_type(event_type: &str) -> bool {
+        DomainEvent::VARIANTS.contains(&event_type)
+    }
+
+    /// The event type name (ie `BrandCreated`)
+    pub fn event_type(&self) -> &'static str {
+        self.into()
+    }
+
+    /// The type of the entity changed by this event
+    pub fn aggregate_type(&self) -> AggregateType {
+        match self {
+            DomainEvent::BrandCreated(_) | DomainEvent::BrandDeleted(_) | DomainEvent::BrandRestored(_) => {
+                AggregateType::Brand
+            }
+            DomainEvent::CatalogItemAvailabilityChanged(_)
+            | DomainEvent::CatalogItemCreated(_)
+            | DomainEvent::CatalogItemDeleted(_)
+            | DomainEvent::CatalogItemRestored(_) => AggregateType::CatalogItem,
+            DomainEvent::PrototypeCreated(_) => AggregateType::Prototype,
+            DomainEvent::RailwayCreated(_)
+            | DomainEvent::RailwayDeleted(_)
+            | DomainEvent::RailwayRelationshipAdded(_)
+            | DomainEvent::RailwayRestored(_) => AggregateType::Railway,
+            DomainEvent::RollingStockDeleted(_) | DomainEvent::RollingStockRestored(_) => AggregateType::RollingStock,
+            DomainEvent::ScaleCreated(_) | DomainEvent::ScaleDeleted(_) | DomainEvent::ScaleRestored(_) => {
+                AggregateType::Scale
+            }
+        }
+    }
+
+    /// The id of the entity changed by this event
+    pub fn aggregate_id(&self) -> String {
+        match self {
+            DomainEvent::BrandCreated(e) => e.brand_id.to_string(),
+            DomainEvent::BrandDeleted(e) => e.brand_id.to_string(),
+            DomainEvent::BrandRestored(e) => e.brand_id.to_string(),
+            DomainEvent::CatalogItemAvailabilityChanged(e) => e.catalog_item_id.to_string(),
+            DomainEvent::CatalogItemCreated(e) => e.catalog_item_id.to_string(),
+            DomainEvent::CatalogItemDeleted(e) => e.catalog_item_id.to_string(),
+            DomainEvent::CatalogItemRestored(e) => e.catalog_item_id.to_string(),
+            DomainEvent::PrototypeCreated(e) => e.prototype_id.to_string(),
+            DomainEvent::RailwayCreated(e) => e.railway_id.to_string(),
+            DomainEvent::RailwayDeleted(e) => e.railway_id.to_string(),
+            DomainEvent::RailwayRelationshipAdded(e) => e.railway_id.to_string(),
+            DomainEvent::RailwayRestored(e) => e.railway_id.to_string(),
+            DomainEvent::RollingStockDeleted(e) => e.rolling_stock_id.to_string(),
+            DomainEvent::RollingStockRestored(e) => e.rolling_stock_id.to_string(),
+            DomainEvent::ScaleCreated(e) => e.scale_id.to_string(),
+            DomainEvent::ScaleDeleted(e) => e.scale_id.to_string(),
+            DomainEvent::ScaleRestored(e) => e.scale_id.to_string(),
+        }
+    }
+
+    /// The kind of change for the entity, restoring a soft deleted entity is reported as an update
+    pub fn change_kind(&self) -> ChangeKind {
+        match self {
+            DomainEvent::BrandCreated(_)
+            | DomainEvent::CatalogItemCreated(_)
+            | DomainEvent::PrototypeCreated(_)
+            | DomainEvent::RailwayCreated(_)
+            | DomainEvent::ScaleCreated(_) => ChangeKind::Created,
+            DomainEvent::BrandRestored(_)
+            | DomainEvent::CatalogItemAvailabilityChanged(_)
+            | DomainEvent::CatalogItemRestored(_)
+            | DomainEvent::RailwayRelationshipAdded(_)
+            | DomainEvent::RailwayRestored(_)
+            | DomainEvent::RollingStockRestored(_)
+            | DomainEvent::ScaleRestored(_) => ChangeKind::Updated,
+            DomainEvent::BrandDeleted(_)
+            | DomainEvent::CatalogItemDeleted(_)
+            | DomainEvent::RailwayDeleted(_)
+            | DomainEvent::RollingStockDeleted(_)
+            | DomainEvent::ScaleDeleted(_) => ChangeKind::Deleted,
+        }
+    }
+
+    /// The timestamp for the change
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        match self {
+            DomainEvent::BrandCreated(e) => e.created_at,
+            DomainEvent::BrandDeleted(e) => e.deleted_at,
+            DomainEvent::BrandRestored(e) => e.last_modified_at,
+            DomainEvent::CatalogItemAvailabilityChanged(e) => e.changed_at,
+            DomainEvent::CatalogItemCreated(e) => e.created_at,
+            DomainEvent::CatalogItemDeleted(e) => e.deleted_at,
+            DomainEvent::CatalogItemRestored(e) => e.last_modified_at,
+            DomainEvent::PrototypeCreated(e) => e.created_at,
+            DomainEvent::RailwayCreated(e) => e.created_at,
+            DomainEvent::RailwayDeleted(e) => e.deleted_at,
+            DomainEvent::RailwayRelationshipAdded(e) => e.last_modified_at,
+            DomainEvent::RailwayRestored(e) => e.last_modified_at,
+            DomainEvent::RollingStockDeleted(e) => e.deleted_at,
+            DomainEvent::RollingStockRestored(e) => e.last_modified_at,
+            DomainEvent::ScaleCreated(e) => e.created_at,
+            DomainEvent::ScaleDeleted(e) => e.deleted_at,
+            DomainEvent::ScaleRestored(e) => e.last_modified_at,
+        }
+    }
+}
+
+/// The catalog entity types which produce domain events
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[strum(ascii_case_insensitive)]
+pub enum AggregateType {
+    Brand,
+    CatalogItem,
+    Prototype,
+    Railway,
+    RollingStock,
+    Scale,
+}
+
+/// The kind of change described by a domain event
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+/// A domain event as stored in the outbox, together with its unique identifier
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventEnvelope {
+    /// the unique event identifier
+    pub event_id: Uuid,
+    /// the event
+    #[serde(flatten)]
+    pub event: DomainEvent,
+    /// the timestamp for the change
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl EventEnvelope {
+    /// Wraps the domain event in a new envelope, with a random event id
+    pub fn new(event: DomainEvent) -> Self {
+        let occurred_at = event.occurred_at();
+        EventEnvelope {
+            event_id: Uuid::new_v4(),
+            event,
+            occurred_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod domain_events {
+        use super::*;
+        use crate::brands::brand_id::BrandId;
+        use crate::catalog_items::rolling_stock_id::RollingStockId;
+        use chrono::TimeZone;
+        use pretty_assertions::assert_eq;
+        use serde_json::json;
+        use std::str::FromStr;
+
+        #[test]
+        fn it_should_describe_the_domain_events() {
+            let created_at = Utc.with_ymd_and_hms(1988, 11, 25, 0, 0, 0).unwrap();
+            let event = DomainEvent::BrandCreated(BrandCreated {
+                brand_id: BrandId::new("ACME"),
+                created_at,
+            });
+
+            assert_eq!("BrandCreated", event.event_type());
+            assert_eq!(AggregateType::Brand, event.aggregate_type());
+            assert_eq!("acme", event.aggregate_id());
+            assert_eq!(ChangeKind::Created, event.change_kind());
+            assert_eq!(created_at, event.occurred_at());
+        }
+
+        #[test]
+        fn it_should_serialize_domain_events_as_json() {
+            let created_at = Utc.with_ymd_and_hms(1988, 11, 25, 0, 0, 0).unwrap();
+            let event = DomainEvent::BrandCreated(BrandCreated {
+                brand_id: BrandId::new("ACME"),
+                created_at,
+            });
+
+            let value = serde_json::to_value(&event).unwrap();
+
+            let expected = json!({
+                "event_type": "BrandCreated",
+                "payload": {
+                    "brand_id": "acme",
+                    "created_at": "1988-11-25T00:00:00Z"
+                }
+            });
+            assert_eq!(expected, value);
+
+            let deserialized: DomainEvent = serde_json::from_value(value).unwrap();
+            assert_eq!(event, deserialized);
+        }
+
+        #[test]
+        fn it_should_wrap_domain_events_in_envelopes() {
+            let deleted_at = Utc.with_ymd_and_hms(1988, 11, 25, 0, 0, 0).unwrap();
+            let rolling_stock_id = RollingStockId::from_str("9b9e2b3d-7b3a-4f2b-8d2c-6f1f1e7c8a10").unwrap();
+            let event = DomainEvent::RollingStockDeleted(RollingStockDeleted {
+                rolling_stock_id,
+                deleted_at,
+            });
+
+            let envelope = EventEnvelope::new(event.clone());
+
+            assert_eq!(event, envelope.event);
+            assert_eq!(deleted_at, envelope.occurred_at);
+            assert_eq!(AggregateType::RollingStock, envelope.event.aggregate_type());
+            assert_eq!(rolling_stock_id.to_string(), envelope.event.aggregate_id());
+            assert_eq!(ChangeKind::Deleted, envelope.event.change_kind());
+        }
+
+        #[test]
+        fn it_should_check_the_event_type_names() {
+            assert!(DomainEvent::is_event_type("CatalogItemCreated"));
+            assert!(!DomainEvent::is_event_type("catalog_item_created"));
+            assert!(!DomainEvent::is_event_type("NotAnEvent"));
+        }
+
+        #[test]
+        fn it_should_parse_aggregate_types() {
+            assert_eq!(Ok(AggregateType::CatalogItem), AggregateType::from_str("catalog_item"));
+            assert_eq!("rolling_stock", AggregateType::RollingStock.to_string());
+        }
+    }
+}
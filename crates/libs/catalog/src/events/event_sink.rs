@@ -0,0 +1,71 @@
+//! the domain events sinks
+
+use crate::events::domain_event::EventEnvelope;
+use async_trait::async_trait;
+
+/// A destination for the domain events dispatched from the outbox.
+///
+/// Events are delivered at least once, sinks should be prepared to receive the same event (with the
+/// same event id) more than once.
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    /// The sink name, used for logging
+    fn name(&self) -> &str;
+
+    /// Publishes the domain event
+    async fn publish(&self, envelope: &EventEnvelope) -> Result<(), anyhow::Error>;
+}
+
+#[cfg(test)]
+pub mod in_memory {
+    use crate::events::domain_event::EventEnvelope;
+    use crate::events::event_sink::EventSink;
+    use anyhow::anyhow;
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+
+    /// An in-memory event sink, which records the published events
+    #[derive(Clone, Default)]
+    pub struct InMemoryEventSink {
+        events: Arc<Mutex<Vec<EventEnvelope>>>,
+        failing: bool,
+    }
+
+    impl InMemoryEventSink {
+        /// Creates a new sink
+        pub fn new() -> Self {
+            InMemoryEventSink::default()
+        }
+
+        /// Creates a new sink which fails to publish any event
+        pub fn failing() -> Self {
+            InMemoryEventSink {
+                failing: true,
+                ..InMemoryEventSink::default()
+            }
+        }
+
+        /// Returns the events published so far
+        pub fn events(&self) -> Vec<EventEnvelope> {
+            let events = self.events.lock().expect("unable to acquire the events lock");
+            events.clone()
+        }
+    }
+
+    #[async_trait]
+    impl EventSink for InMemoryEventSink {
+        fn name(&self) -> &str {
+            "in-memory"
+        }
+
+        async fn publish(&self, envelope: &EventEnvelope) -> Result<(), anyhow::Error> {
+            if self.failing {
+                return Err(anyhow!("unable to publish the event"));
+            }
+
+            let mut events = self.events.lock().expect("unable to acquire the events lock");
+            events.push(envelope.clone());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! the commands to follow the domain events stored in the outbox, on every server instance
+
+use crate::events::event_sink::EventSink;
+use crate::events::repositories::{EventFeedRepository, FeedEvent, FeedPosition};
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+
+pub type Result<R> = result::Result<R, EventsFollowError>;
+
+/// Returns the position where a new follower starts, it receives only the events stored after it
+pub async fn feed_start_position<'db, U, Repo, DB>(repo: Repo, db: DB) -> Result<Option<FeedPosition>>
+where
+    U: UnitOfWork<'db>,
+    Repo: EventFeedRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+    let position = repo.last_position(&mut unit_of_work).await?;
+    unit_of_work.commit().await?;
+
+    Ok(position)
+}
+
+/// Publishes the next batch of events after `position` from the outbox to the event sink.
+///
+/// Unlike the outbox dispatch, the events are not claimed: every server instance follows the outbox
+/// with its own position, and publishes all the events to its local sinks (ie the event stream clients
+/// connected to the instance). The events are published once, even when the sink fails to publish them.
+pub async fn follow_events<'db, U, Repo, S, DB>(
+    position: Option<FeedPosition>,
+    batch_size: i64,
+    sink: &S,
+    repo: Repo,
+    db: DB,
+) -> Result<EventsFollowed>
+where
+    U: UnitOfWork<'db>,
+    Repo: EventFeedRepository<'db, U>,
+    S: EventSink + ?Sized,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+    let events = repo
+        .find_after(position.as_ref(), batch_size, &mut unit_of_work)
+        .await?;
+    unit_of_work.commit().await?;
+
+    let mut result = EventsFollowed {
+        position,
+        ..EventsFollowed::default()
+    };
+    for FeedEvent { position, envelope } in events {
+        match sink.publish(&envelope).await {
+            Ok(_) => result.published += 1,
+            Err(_) => result.failed += 1,
+        }
+        result.position = Some(position);
+    }
+
+    Ok(result)
+}
+
+/// The outcome for a batch of followed events
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EventsFollowed {
+    /// the position of the last followed event
+    pub position: Option<FeedPosition>,
+    /// the number of events published to the sink
+    pub published: usize,
+    /// the number of events the sink failed to publish, they are not published again
+    pub failed: usize,
+}
+
+impl EventsFollowed {
+    /// The number of events read from the outbox
+    pub fn len(&self) -> usize {
+        self.published + self.failed
+    }
+
+    /// Returns `true` when there were no new events in the outbox
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum EventsFollowError {
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod follow_events_command {
+        use super::*;
+        use crate::brands::brand_id::BrandId;
+        use crate::brands::brand_response::BrandCreated;
+        use crate::events::dispatch_events::dispatch_pending_events;
+        use crate::events::domain_event::{DomainEvent, EventEnvelope};
+        use crate::events::event_sink::in_memory::InMemoryEventSink;
+        use crate::events::repositories::in_memory::InMemoryPendingEventsRepository;
+        use crate::events::retry_policy::RetryPolicy;
+        use chrono::{Duration, Utc};
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_publish_the_events_after_the_position() {
+            let repo = InMemoryPendingEventsRepository::with(vec![brand_created("acme"), brand_created("roco")]);
+            let sink = InMemoryEventSink::new();
+
+            let result = follow_events(None, 10, &sink, repo.clone(), NoOpDatabase).await;
+
+            let followed = result.expect("result is an error");
+            assert_eq!(2, followed.published);
+            assert_eq!(
+                brand_ids(repo.entries().iter().map(|it| &it.envelope)),
+                brand_ids(&sink.events())
+            );
+
+            let result = follow_events(followed.position, 10, &sink, repo.clone(), NoOpDatabase).await;
+
+            let followed_again = result.expect("result is an error");
+            assert!(followed_again.is_empty());
+            assert_eq!(followed.position, followed_again.position);
+            assert_eq!(2, sink.events().len());
+        }
+
+        #[tokio::test]
+        async fn it_should_publish_at_most_batch_size_events() {
+            let repo = InMemoryPendingEventsRepository::with(vec![brand_created("acme"), brand_created("roco")]);
+            let sink = InMemoryEventSink::new();
+
+            let result = follow_events(None, 1, &sink, repo.clone(), NoOpDatabase).await;
+            let followed = result.expect("result is an error");
+            assert_eq!(1, followed.published);
+
+            let result = follow_events(followed.position, 1, &sink, repo.clone(), NoOpDatabase).await;
+            assert_eq!(1, result.expect("result is an error").published);
+
+            assert_eq!(vec!["acme", "roco"], brand_ids(&sink.events()));
+        }
+
+        #[tokio::test]
+        async fn it_should_move_past_the_events_the_sink_fails_to_publish() {
+            let repo = InMemoryPendingEventsRepository::with(vec![brand_created("acme")]);
+            let sink = InMemoryEventSink::failing();
+
+            let result = follow_events(None, 10, &sink, repo.clone(), NoOpDatabase).await;
+
+            let followed = result.expect("result is an error");
+            assert_eq!(1, followed.failed);
+            assert!(followed.position.is_some());
+        }
+
+        #[tokio::test]
+        async fn it_should_start_following_after_the_last_event() {
+            let repo = InMemoryPendingEventsRepository::with(vec![brand_created("acme")]);
+            let sink = InMemoryEventSink::new();
+
+            let position = feed_start_position(repo.clone(), NoOpDatabase)
+                .await
+                .expect("result is an error");
+            let result = follow_events(position, 10, &sink, repo.clone(), NoOpDatabase).await;
+
+            assert!(result.expect("result is an error").is_empty());
+            assert!(sink.events().is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_publish_every_event_to_every_instance_while_the_dispatchers_split_them() {
+            let repo = InMemoryPendingEventsRepository::with(vec![
+                brand_created("acme"),
+                brand_created("roco"),
+                brand_created("rivarossi"),
+            ]);
+            let retry_policy = RetryPolicy::new(3, Duration::seconds(10), Duration::minutes(5));
+
+            // two server instances, each one with its own dispatcher and event stream subscribers
+            let instances = [
+                (InMemoryEventSink::new(), InMemoryEventSink::new()),
+                (InMemoryEventSink::new(), InMemoryEventSink::new()),
+            ];
+
+            for (dispatcher_sink, _) in instances.iter() {
+                let result = dispatch_pending_events(
+                    2,
+                    &retry_policy,
+                    Duration::minutes(1),
+                    dispatcher_sink,
+                    repo.clone(),
+                    NoOpDatabase,
+                )
+                .await;
+                assert!(result.expect("result is an error").dispatched > 0);
+            }
+            for (_, subscribers_sink) in instances.iter() {
+                let result = follow_events(None, 10, subscribers_sink, repo.clone(), NoOpDatabase).await;
+                assert_eq!(3, result.expect("result is an error").published);
+            }
+
+            let (first, second) = (&instances[0], &instances[1]);
+            assert_eq!(vec!["acme", "roco"], brand_ids(&first.0.events()));
+            assert_eq!(vec!["rivarossi"], brand_ids(&second.0.events()));
+            assert_eq!(vec!["acme", "roco", "rivarossi"], brand_ids(&first.1.events()));
+            assert_eq!(vec!["acme", "roco", "rivarossi"], brand_ids(&second.1.events()));
+        }
+
+        fn brand_created(name: &str) -> DomainEvent {
+            DomainEvent::BrandCreated(BrandCreated {
+                brand_id: BrandId::new(name),
+                created_at: Utc::now(),
+            })
+        }
+
+        fn brand_ids<'a>(envelopes: impl IntoIterator<Item = &'a EventEnvelope>) -> Vec<String> {
+            envelopes.into_iter().map(|it| it.event.aggregate_id()).collect()
+        }
+    }
+}
@@ -0,0 +1,8 @@
+//! the catalog domain events, stored in the transactional outbox and later dispatched to the event sinks
+
+pub mod dispatch_events;
+pub mod domain_event;
+pub mod event_sink;
+pub mod follow_events;
+pub mod repositories;
+pub mod retry_policy;
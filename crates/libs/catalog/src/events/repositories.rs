@@ -0,0 +1,286 @@
+//! the domain events repositories
+
+use crate::events::domain_event::{DomainEvent, EventEnvelope};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use common::unit_of_work::UnitOfWork;
+use uuid::Uuid;
+
+/// The persistence related functionality for the domain events outbox.
+///
+/// Events are appended as part of the same unit of work that changes the catalog, this way
+/// they are stored only when the change is committed.
+#[async_trait]
+pub trait OutboxRepository<'db, U: UnitOfWork<'db>> {
+    /// Appends a new domain event to the outbox
+    async fn append(&self, event: &DomainEvent, unit_of_work: &mut U) -> Result<(), anyhow::Error>;
+}
+
+/// The persistence related functionality to dispatch the domain events stored in the outbox
+#[async_trait]
+pub trait PendingEventsRepository<'db, U: UnitOfWork<'db>> {
+    /// Claims the oldest events due for dispatch (not dispatched, nor dead lettered, and past their
+    /// backoff), they are not due again until `claimed_until` unless their outcome is recorded before
+    async fn claim_pending(
+        &self,
+        limit: i64,
+        now: &DateTime<Utc>,
+        claimed_until: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<Vec<ClaimedEvent>, anyhow::Error>;
+
+    /// Marks the event as dispatched
+    async fn mark_dispatched(
+        &self,
+        event_id: &Uuid,
+        dispatched_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<(), anyhow::Error>;
+
+    /// Records a failed dispatch attempt, the event is dispatched again at `next_attempt_at`. Without
+    /// a next attempt the event is dead lettered, and it will not be dispatched anymore
+    async fn mark_failed(
+        &self,
+        event_id: &Uuid,
+        error: &str,
+        attempted_at: &DateTime<Utc>,
+        next_attempt_at: Option<&DateTime<Utc>>,
+        unit_of_work: &mut U,
+    ) -> Result<(), anyhow::Error>;
+}
+
+/// A pending event claimed for dispatch
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClaimedEvent {
+    /// the domain event
+    pub envelope: EventEnvelope,
+    /// the number of the previous dispatch attempts
+    pub attempts: i32,
+}
+
+/// The persistence related functionality to follow the domain events stored in the outbox.
+///
+/// Every server instance follows the outbox on its own, regardless of which instance dispatched the
+/// events: the events are read in the order their transactions are completed, and only after
+/// every transaction which started before them is completed, this way the feed never skips an event.
+#[async_trait]
+pub trait EventFeedRepository<'db, U: UnitOfWork<'db>> {
+    /// Returns the position of the last event in the feed, if any
+    async fn last_position(&self, unit_of_work: &mut U) -> Result<Option<FeedPosition>, anyhow::Error>;
+
+    /// Returns the events in the feed after the given position (from the beginning, without a position)
+    async fn find_after(
+        &self,
+        position: Option<&FeedPosition>,
+        limit: i64,
+        unit_of_work: &mut U,
+    ) -> Result<Vec<FeedEvent>, anyhow::Error>;
+}
+
+/// The position of an event in the outbox feed
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FeedPosition {
+    /// the id of the transaction which stored the event
+    pub transaction_id: i64,
+    /// the event sequence number
+    pub sequence: i64,
+}
+
+/// A domain event read from the outbox feed
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedEvent {
+    /// the event position in the feed
+    pub position: FeedPosition,
+    /// the domain event
+    pub envelope: EventEnvelope,
+}
+
+#[cfg(test)]
+pub mod in_memory {
+    use crate::events::domain_event::{DomainEvent, EventEnvelope};
+    use crate::events::repositories::{
+        ClaimedEvent, EventFeedRepository, FeedEvent, FeedPosition, OutboxRepository, PendingEventsRepository,
+    };
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use common::unit_of_work::noop::NoOpUnitOfWork;
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    /// An in-memory outbox, the appended events are shared between the clones of the same outbox
+    #[derive(Clone, Default)]
+    pub struct InMemoryOutboxRepository(Arc<Mutex<Vec<DomainEvent>>>);
+
+    impl InMemoryOutboxRepository {
+        /// Creates an empty in-memory outbox
+        pub fn empty() -> Self {
+            InMemoryOutboxRepository::default()
+        }
+
+        /// Returns the events appended so far
+        pub fn events(&self) -> Vec<DomainEvent> {
+            let events = self.0.lock().expect("unable to acquire the events lock");
+            events.clone()
+        }
+    }
+
+    #[async_trait]
+    impl OutboxRepository<'static, NoOpUnitOfWork> for InMemoryOutboxRepository {
+        async fn append(&self, event: &DomainEvent, _unit_of_work: &mut NoOpUnitOfWork) -> Result<(), anyhow::Error> {
+            let mut events = self.0.lock().expect("unable to acquire the events lock");
+            events.push(event.clone());
+            Ok(())
+        }
+    }
+
+    /// An in-memory outbox entry
+    #[derive(Debug, Clone)]
+    pub struct PendingEvent {
+        pub envelope: EventEnvelope,
+        pub dispatched_at: Option<DateTime<Utc>>,
+        pub attempts: i32,
+        pub last_error: Option<String>,
+        pub last_attempt_at: Option<DateTime<Utc>>,
+        pub next_attempt_at: DateTime<Utc>,
+        pub dead_lettered_at: Option<DateTime<Utc>>,
+    }
+
+    /// An in-memory outbox with events waiting to be dispatched
+    #[derive(Clone, Default)]
+    pub struct InMemoryPendingEventsRepository(Arc<Mutex<Vec<PendingEvent>>>);
+
+    impl InMemoryPendingEventsRepository {
+        /// Creates a new outbox with the given pending events
+        pub fn with(events: Vec<DomainEvent>) -> Self {
+            InMemoryPendingEventsRepository::with_attempts(events, 0)
+        }
+
+        /// Creates a new outbox with the given pending events, which already failed `attempts` times
+        pub fn with_attempts(events: Vec<DomainEvent>, attempts: i32) -> Self {
+            let events = events
+                .into_iter()
+                .map(|event| PendingEvent {
+                    envelope: EventEnvelope::new(event),
+                    dispatched_at: None,
+                    attempts,
+                    last_error: None,
+                    last_attempt_at: None,
+                    next_attempt_at: DateTime::<Utc>::MIN_UTC,
+                    dead_lettered_at: None,
+                })
+                .collect();
+            InMemoryPendingEventsRepository(Arc::new(Mutex::new(events)))
+        }
+
+        /// Returns the outbox entries
+        pub fn entries(&self) -> Vec<PendingEvent> {
+            let events = self.0.lock().expect("unable to acquire the events lock");
+            events.clone()
+        }
+
+        fn update<F: FnOnce(&mut PendingEvent)>(&self, event_id: &Uuid, f: F) {
+            let mut events = self.0.lock().expect("unable to acquire the events lock");
+            if let Some(entry) = events.iter_mut().find(|it| it.envelope.event_id == *event_id) {
+                f(entry)
+            }
+        }
+    }
+
+    #[async_trait]
+    impl PendingEventsRepository<'static, NoOpUnitOfWork> for InMemoryPendingEventsRepository {
+        async fn claim_pending(
+            &self,
+            limit: i64,
+            now: &DateTime<Utc>,
+            claimed_until: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Vec<ClaimedEvent>, anyhow::Error> {
+            let mut events = self.0.lock().expect("unable to acquire the events lock");
+            Ok(events
+                .iter_mut()
+                .filter(|it| it.dispatched_at.is_none() && it.dead_lettered_at.is_none() && it.next_attempt_at <= *now)
+                .take(limit as usize)
+                .map(|it| {
+                    it.next_attempt_at = *claimed_until;
+                    ClaimedEvent {
+                        envelope: it.envelope.clone(),
+                        attempts: it.attempts,
+                    }
+                })
+                .collect())
+        }
+
+        async fn mark_dispatched(
+            &self,
+            event_id: &Uuid,
+            dispatched_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<(), anyhow::Error> {
+            self.update(event_id, |entry| {
+                entry.attempts += 1;
+                entry.dispatched_at = Some(*dispatched_at);
+                entry.last_attempt_at = Some(*dispatched_at);
+                entry.last_error = None;
+            });
+            Ok(())
+        }
+
+        async fn mark_failed(
+            &self,
+            event_id: &Uuid,
+            error: &str,
+            attempted_at: &DateTime<Utc>,
+            next_attempt_at: Option<&DateTime<Utc>>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<(), anyhow::Error> {
+            self.update(event_id, |entry| {
+                entry.attempts += 1;
+                entry.last_error = Some(error.to_owned());
+                entry.last_attempt_at = Some(*attempted_at);
+                match next_attempt_at {
+                    Some(next_attempt_at) => entry.next_attempt_at = *next_attempt_at,
+                    None => entry.dead_lettered_at = Some(*attempted_at),
+                }
+            });
+            Ok(())
+        }
+    }
+
+    /// The in-memory outbox feed, the events position is their index in the outbox
+    #[async_trait]
+    impl EventFeedRepository<'static, NoOpUnitOfWork> for InMemoryPendingEventsRepository {
+        async fn last_position(
+            &self,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Option<FeedPosition>, anyhow::Error> {
+            let events = self.0.lock().expect("unable to acquire the events lock");
+            Ok(events.len().checked_sub(1).map(feed_position))
+        }
+
+        async fn find_after(
+            &self,
+            position: Option<&FeedPosition>,
+            limit: i64,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Vec<FeedEvent>, anyhow::Error> {
+            let events = self.0.lock().expect("unable to acquire the events lock");
+            Ok(events
+                .iter()
+                .enumerate()
+                .map(|(index, it)| FeedEvent {
+                    position: feed_position(index),
+                    envelope: it.envelope.clone(),
+                })
+                .filter(|it| position.map_or(true, |position| it.position > *position))
+                .take(limit as usize)
+                .collect())
+        }
+    }
+
+    fn feed_position(index: usize) -> FeedPosition {
+        FeedPosition {
+            transaction_id: 1,
+            sequence: index as i64 + 1,
+        }
+    }
+}
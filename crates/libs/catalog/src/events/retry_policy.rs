@@ -0,0 +1,85 @@
+//! the retry policy for the failed outbox events dispatches and webhook deliveries
+
+use chrono::{DateTime, Duration, Utc};
+
+/// It decides when a failed event dispatch (or webhook delivery) is attempted again, using an exponential backoff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// the max number of attempts for each dispatch (or delivery)
+    pub max_attempts: i32,
+    /// the delay before the first retry
+    pub initial_backoff: Duration,
+    /// the max delay between two attempts
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a new retry policy
+    pub fn new(max_attempts: i32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        RetryPolicy {
+            max_attempts,
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    /// Returns the timestamp for the next attempt after `attempts` failed attempts, or `None` when
+    /// the dispatch (or delivery) should not be attempted anymore
+    pub fn next_attempt_at(&self, attempts: i32, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if attempts >= self.max_attempts {
+            return None;
+        }
+
+        let exponent = attempts.saturating_sub(1).clamp(0, 30) as u32;
+        let backoff = self
+            .initial_backoff
+            .checked_mul(2_i32.saturating_pow(exponent))
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+        Some(now + backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new(8, Duration::seconds(10), Duration::hours(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod retry_policies {
+        use super::*;
+        use chrono::TimeZone;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        #[rstest]
+        #[case(1, Some(10))]
+        #[case(2, Some(20))]
+        #[case(3, Some(40))]
+        #[case(4, Some(60))]
+        #[case(5, None)]
+        fn it_should_compute_the_next_attempt_with_exponential_backoff(
+            #[case] attempts: i32,
+            #[case] expected: Option<i64>,
+        ) {
+            let policy = RetryPolicy::new(5, Duration::seconds(10), Duration::seconds(60));
+            let now = Utc.with_ymd_and_hms(2026, 10, 18, 9, 0, 0).unwrap();
+
+            let next_attempt_at = policy.next_attempt_at(attempts, now);
+            assert_eq!(expected.map(|secs| now + Duration::seconds(secs)), next_attempt_at);
+        }
+
+        #[test]
+        fn it_should_cap_the_backoff_for_large_attempt_numbers() {
+            let policy = RetryPolicy::new(i32::MAX, Duration::seconds(10), Duration::hours(1));
+            let now = Utc.with_ymd_and_hms(2026, 10, 18, 9, 0, 0).unwrap();
+
+            let next_attempt_at = policy.next_attempt_at(1000, now);
+            assert_eq!(Some(now + Duration::hours(1)), next_attempt_at);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! the catalog item image view models
+
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use crate::images::image_id::ImageId;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// It represents an image in the catalog item gallery
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CatalogItemImage {
+    /// the image unique identifier
+    pub image_id: ImageId,
+    /// the catalog item this image belongs to
+    pub catalog_item_id: CatalogItemId,
+    /// the image media type
+    pub content_type: String,
+    /// the image size in bytes
+    pub size_bytes: i64,
+    /// the image position in the gallery (starting from 1)
+    pub position: i32,
+    /// whether this is the catalog item primary image
+    pub primary: bool,
+    /// the image creation timestamp
+    pub created_at: DateTime<Utc>,
+}
+
+impl CatalogItemImage {
+    /// Returns the key for the image content in the storage
+    pub fn storage_key(&self) -> String {
+        storage_key(&self.catalog_item_id, &self.image_id)
+    }
+}
+
+/// Returns the storage key for a catalog item image
+pub fn storage_key(catalog_item_id: &CatalogItemId, image_id: &ImageId) -> String {
+    format!("catalog-items/{catalog_item_id}/images/{image_id}")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod catalog_item_images {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use std::str::FromStr;
+        use uuid::Uuid;
+
+        #[test]
+        fn it_should_return_the_image_storage_key() {
+            let image_id = ImageId::from(Uuid::from_str("9d7e8a2c-52f6-4a4e-9f2e-0a6b1f1f6f3b").unwrap());
+            let catalog_item_id = CatalogItemId::from_str("acme-60142").unwrap();
+
+            assert_eq!(
+                "catalog-items/acme-60142/images/9d7e8a2c-52f6-4a4e-9f2e-0a6b1f1f6f3b",
+                storage_key(&catalog_item_id, &image_id)
+            );
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! the catalog item image removal command
+
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use crate::images::catalog_item_image::CatalogItemImage;
+use crate::images::commands::repositories::CatalogItemImagesRepository;
+use crate::images::image_id::ImageId;
+use crate::images::image_storage::ImageStorage;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+
+pub type Result<R> = result::Result<R, ImageDeletionError>;
+
+/// Removes the image from the catalog item gallery, and its content from the storage.
+///
+/// The remaining images are shifted to fill the gap, when the primary image is removed the
+/// first remaining image becomes the new primary one.
+pub async fn delete_image<'db, U, Repo, Storage, DB>(
+    catalog_item_id: &CatalogItemId,
+    image_id: &ImageId,
+    repo: Repo,
+    storage: &Storage,
+    db: DB,
+) -> Result<()>
+where
+    U: UnitOfWork<'db>,
+    Repo: CatalogItemImagesRepository<'db, U>,
+    Storage: ImageStorage + ?Sized,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let images = repo.find_images(catalog_item_id, &mut unit_of_work).await?;
+    let (deleted, remaining): (Vec<CatalogItemImage>, Vec<CatalogItemImage>) =
+        images.into_iter().partition(|it| it.image_id == *image_id);
+    let deleted = deleted
+        .into_iter()
+        .next()
+        .ok_or(ImageDeletionError::ImageNotFound(*image_id))?;
+
+    repo.delete(image_id, &mut unit_of_work).await?;
+
+    for (image, position) in remaining.iter().zip(1..) {
+        if image.position != position {
+            repo.update_position(&image.image_id, position, &mut unit_of_work)
+                .await?;
+        }
+    }
+
+    if deleted.primary {
+        if let Some(first) = remaining.first() {
+            repo.set_primary(catalog_item_id, Some(&first.image_id), &mut unit_of_work)
+                .await?;
+        }
+    }
+
+    storage
+        .delete(&deleted.storage_key())
+        .await
+        .map_err(ImageDeletionError::StorageError)?;
+
+    unit_of_work.commit().await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum ImageDeletionError {
+    #[error("The image was not found (id: {0})")]
+    ImageNotFound(ImageId),
+
+    #[error("Unable to remove the image content")]
+    StorageError(#[source] anyhow::Error),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod delete_image_command {
+        use super::*;
+        use crate::images::commands::repositories::in_memory::InMemoryImagesRepository;
+        use crate::images::image_storage::in_memory::InMemoryImageStorage;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+        use std::str::FromStr;
+
+        #[tokio::test]
+        async fn it_should_delete_an_image() {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+            let first = repo.add(&catalog_item_id, false);
+            let second = repo.add(&catalog_item_id, true);
+            let third = repo.add(&catalog_item_id, false);
+            let storage = InMemoryImageStorage::empty();
+            let key = repo.images(&catalog_item_id)[1].storage_key();
+            storage.put(&key, b"content", "image/png").await.unwrap();
+
+            let result = delete_image(&catalog_item_id, &second, repo.clone(), &storage, NoOpDatabase).await;
+
+            assert!(result.is_ok());
+            assert!(!storage.contains(&key));
+            let images: Vec<_> = repo
+                .images(&catalog_item_id)
+                .into_iter()
+                .map(|it| (it.image_id, it.position, it.primary))
+                .collect();
+            assert_eq!(vec![(first, 1, true), (third, 2, false)], images);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_image_does_not_exist() {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+            let image_id = ImageId::new();
+
+            let result = delete_image(
+                &catalog_item_id,
+                &image_id,
+                repo,
+                &InMemoryImageStorage::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(ImageDeletionError::ImageNotFound(id)) => assert_eq!(image_id, id),
+                _ => panic!("ImageNotFound is expected (found: {:?})", result),
+            }
+        }
+
+        fn catalog_item_id() -> CatalogItemId {
+            CatalogItemId::from_str("acme-60142").unwrap()
+        }
+    }
+}
@@ -0,0 +1,6 @@
+//! the catalog item image commands
+
+pub mod delete_image;
+pub mod repositories;
+pub mod update_image;
+pub mod upload_image;
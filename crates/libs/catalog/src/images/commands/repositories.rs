@@ -0,0 +1,229 @@
+//! the catalog item image command repositories
+
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use crate::images::catalog_item_image::CatalogItemImage;
+use crate::images::image_id::ImageId;
+use async_trait::async_trait;
+use common::unit_of_work::UnitOfWork;
+
+/// The persistence related functionality for the catalog item images
+#[async_trait]
+pub trait CatalogItemImagesRepository<'db, U: UnitOfWork<'db>> {
+    /// Locks the catalog item until the end of the unit of work, in order to serialize the changes to
+    /// its gallery. It returns `false` when the catalog item does not exist (or it was deleted)
+    async fn lock_catalog_item(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error>;
+
+    /// Returns the catalog item images, sorted by their position
+    async fn find_images(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut U,
+    ) -> Result<Vec<CatalogItemImage>, anyhow::Error>;
+
+    /// Inserts a new image
+    async fn insert(&self, image: &CatalogItemImage, unit_of_work: &mut U) -> Result<(), anyhow::Error>;
+
+    /// Changes the image position in the gallery
+    async fn update_position(
+        &self,
+        image_id: &ImageId,
+        position: i32,
+        unit_of_work: &mut U,
+    ) -> Result<(), anyhow::Error>;
+
+    /// Makes the input image the primary one, all the other catalog item images are no longer primary.
+    /// When `image_id` is `None` the catalog item is left without a primary image
+    async fn set_primary(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        image_id: Option<&ImageId>,
+        unit_of_work: &mut U,
+    ) -> Result<(), anyhow::Error>;
+
+    /// Deletes the image
+    async fn delete(&self, image_id: &ImageId, unit_of_work: &mut U) -> Result<(), anyhow::Error>;
+}
+
+#[cfg(test)]
+pub mod in_memory {
+    use crate::catalog_items::catalog_item_id::CatalogItemId;
+    use crate::images::catalog_item_image::CatalogItemImage;
+    use crate::images::commands::repositories::CatalogItemImagesRepository;
+    use crate::images::image_id::ImageId;
+    use crate::images::queries::find_catalog_item_images::FindCatalogItemImagesRepository;
+    use crate::images::queries::find_image_by_id::FindImageByIdRepository;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use common::queries::errors::DatabaseError;
+    use common::unit_of_work::noop::NoOpUnitOfWork;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct State {
+        catalog_items: Vec<CatalogItemId>,
+        images: Vec<CatalogItemImage>,
+    }
+
+    /// An in-memory images repository, the state is shared between the clones of the same repository
+    #[derive(Clone, Default)]
+    pub struct InMemoryImagesRepository(Arc<Mutex<State>>);
+
+    impl InMemoryImagesRepository {
+        /// Creates an empty in-memory images repository
+        pub fn empty() -> Self {
+            InMemoryImagesRepository::default()
+        }
+
+        /// Creates a new in-memory images repository with a catalog item without images
+        pub fn with(catalog_item_id: &CatalogItemId) -> Self {
+            let repo = InMemoryImagesRepository::empty();
+            {
+                let mut state = repo.0.lock().expect("unable to acquire the lock");
+                state.catalog_items.push(catalog_item_id.clone());
+            }
+            repo
+        }
+
+        /// Adds a new image to the catalog item gallery, in the last position
+        pub fn add(&self, catalog_item_id: &CatalogItemId, primary: bool) -> ImageId {
+            let mut state = self.0.lock().expect("unable to acquire the lock");
+            let position = state
+                .images
+                .iter()
+                .filter(|it| it.catalog_item_id == *catalog_item_id)
+                .count() as i32
+                + 1;
+            let image = CatalogItemImage {
+                image_id: ImageId::new(),
+                catalog_item_id: catalog_item_id.clone(),
+                content_type: String::from("image/png"),
+                size_bytes: 42,
+                position,
+                primary,
+                created_at: Utc::now(),
+            };
+            let image_id = image.image_id;
+            state.images.push(image);
+            image_id
+        }
+
+        /// Returns the catalog item images, sorted by their position
+        pub fn images(&self, catalog_item_id: &CatalogItemId) -> Vec<CatalogItemImage> {
+            let state = self.0.lock().expect("unable to acquire the lock");
+            let mut images: Vec<CatalogItemImage> = state
+                .images
+                .iter()
+                .filter(|it| it.catalog_item_id == *catalog_item_id)
+                .cloned()
+                .collect();
+            images.sort_by_key(|it| it.position);
+            images
+        }
+
+        fn contains(&self, catalog_item_id: &CatalogItemId) -> bool {
+            let state = self.0.lock().expect("unable to acquire the lock");
+            state.catalog_items.contains(catalog_item_id)
+        }
+    }
+
+    #[async_trait]
+    impl CatalogItemImagesRepository<'static, NoOpUnitOfWork> for InMemoryImagesRepository {
+        async fn lock_catalog_item(
+            &self,
+            catalog_item_id: &CatalogItemId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            Ok(self.contains(catalog_item_id))
+        }
+
+        async fn find_images(
+            &self,
+            catalog_item_id: &CatalogItemId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Vec<CatalogItemImage>, anyhow::Error> {
+            Ok(self.images(catalog_item_id))
+        }
+
+        async fn insert(
+            &self,
+            image: &CatalogItemImage,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<(), anyhow::Error> {
+            let mut state = self.0.lock().expect("unable to acquire the lock");
+            state.images.push(image.clone());
+            Ok(())
+        }
+
+        async fn update_position(
+            &self,
+            image_id: &ImageId,
+            position: i32,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<(), anyhow::Error> {
+            let mut state = self.0.lock().expect("unable to acquire the lock");
+            if let Some(image) = state.images.iter_mut().find(|it| it.image_id == *image_id) {
+                image.position = position;
+            }
+            Ok(())
+        }
+
+        async fn set_primary(
+            &self,
+            catalog_item_id: &CatalogItemId,
+            image_id: Option<&ImageId>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<(), anyhow::Error> {
+            let mut state = self.0.lock().expect("unable to acquire the lock");
+            state
+                .images
+                .iter_mut()
+                .filter(|it| it.catalog_item_id == *catalog_item_id)
+                .for_each(|it| it.primary = Some(&it.image_id) == image_id);
+            Ok(())
+        }
+
+        async fn delete(&self, image_id: &ImageId, _unit_of_work: &mut NoOpUnitOfWork) -> Result<(), anyhow::Error> {
+            let mut state = self.0.lock().expect("unable to acquire the lock");
+            state.images.retain(|it| it.image_id != *image_id);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl FindCatalogItemImagesRepository<'static, NoOpUnitOfWork> for InMemoryImagesRepository {
+        async fn catalog_item_exists(
+            &self,
+            catalog_item_id: &CatalogItemId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, DatabaseError> {
+            Ok(self.contains(catalog_item_id))
+        }
+
+        async fn find_images(
+            &self,
+            catalog_item_id: &CatalogItemId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Vec<CatalogItemImage>, DatabaseError> {
+            Ok(self.images(catalog_item_id))
+        }
+    }
+
+    #[async_trait]
+    impl FindImageByIdRepository<'static, NoOpUnitOfWork> for InMemoryImagesRepository {
+        async fn find_image(
+            &self,
+            catalog_item_id: &CatalogItemId,
+            image_id: &ImageId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Option<CatalogItemImage>, DatabaseError> {
+            Ok(self
+                .images(catalog_item_id)
+                .into_iter()
+                .find(|it| it.image_id == *image_id))
+        }
+    }
+}
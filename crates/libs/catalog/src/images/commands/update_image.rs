@@ -0,0 +1,213 @@
+//! the catalog item image update command
+
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use crate::images::catalog_item_image::CatalogItemImage;
+use crate::images::commands::repositories::CatalogItemImagesRepository;
+use crate::images::image_id::ImageId;
+use crate::images::image_request::ImageRequest;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+use validator::{Validate, ValidationErrors};
+
+pub type Result<R> = result::Result<R, ImageUpdateError>;
+
+/// Changes the image position in the gallery and/or its primary flag.
+///
+/// Moving an image shifts the other images, a position after the last one moves the image
+/// to the end of the gallery.
+pub async fn update_image<'db, U, Repo, DB>(
+    catalog_item_id: &CatalogItemId,
+    image_id: &ImageId,
+    request: ImageRequest,
+    repo: Repo,
+    db: DB,
+) -> Result<CatalogItemImage>
+where
+    U: UnitOfWork<'db>,
+    Repo: CatalogItemImagesRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    request.validate().map_err(ImageUpdateError::InvalidRequest)?;
+
+    let mut unit_of_work = db.begin().await?;
+
+    let mut images = repo.find_images(catalog_item_id, &mut unit_of_work).await?;
+    if !images.iter().any(|it| it.image_id == *image_id) {
+        return Err(ImageUpdateError::ImageNotFound(*image_id));
+    }
+
+    if let Some(position) = request.position {
+        for (id, new_position) in move_image(&images, image_id, position) {
+            repo.update_position(&id, new_position, &mut unit_of_work).await?;
+            if let Some(image) = images.iter_mut().find(|it| it.image_id == id) {
+                image.position = new_position;
+            }
+        }
+    }
+
+    if let Some(primary) = request.primary {
+        let is_primary = images.iter().any(|it| it.image_id == *image_id && it.primary);
+        if primary != is_primary {
+            let primary_id = if primary { Some(image_id) } else { None };
+            repo.set_primary(catalog_item_id, primary_id, &mut unit_of_work).await?;
+            images
+                .iter_mut()
+                .for_each(|it| it.primary = Some(&it.image_id) == primary_id);
+        }
+    }
+
+    unit_of_work.commit().await?;
+
+    let image = images
+        .into_iter()
+        .find(|it| it.image_id == *image_id)
+        .expect("the image is in the gallery");
+    Ok(image)
+}
+
+/// Moves the image to the new position, it returns only the images whose position has changed
+fn move_image(images: &[CatalogItemImage], image_id: &ImageId, position: i32) -> Vec<(ImageId, i32)> {
+    let mut ids: Vec<ImageId> = images.iter().map(|it| it.image_id).collect();
+    let current = ids.iter().position(|it| it == image_id);
+    if let Some(current) = current {
+        let moved = ids.remove(current);
+        let index = (position.max(1) as usize - 1).min(ids.len());
+        ids.insert(index, moved);
+    }
+
+    ids.into_iter()
+        .zip(1..)
+        .filter(|(id, position)| images.iter().any(|it| it.image_id == *id && it.position != *position))
+        .collect()
+}
+
+#[derive(Debug, Error)]
+pub enum ImageUpdateError {
+    #[error("The image request is not valid")]
+    InvalidRequest(ValidationErrors),
+
+    #[error("The image was not found (id: {0})")]
+    ImageNotFound(ImageId),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod update_image_command {
+        use super::*;
+        use crate::images::commands::repositories::in_memory::InMemoryImagesRepository;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+        use std::str::FromStr;
+
+        #[rstest]
+        #[case(0, 1, vec![0, 1, 2])]
+        #[case(0, 3, vec![1, 2, 0])]
+        #[case(2, 1, vec![2, 0, 1])]
+        #[case(1, 99, vec![0, 2, 1])]
+        fn it_should_move_the_images(#[case] image: usize, #[case] position: i32, #[case] expected: Vec<usize>) {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+            let ids: Vec<ImageId> = (0..3).map(|_| repo.add(&catalog_item_id, false)).collect();
+            let images = repo.images(&catalog_item_id);
+
+            let mut result: Vec<(ImageId, i32)> = images.iter().map(|it| (it.image_id, it.position)).collect();
+            for (id, new_position) in move_image(&images, &ids[image], position) {
+                if let Some(entry) = result.iter_mut().find(|(it, _)| *it == id) {
+                    entry.1 = new_position;
+                }
+            }
+            result.sort_by_key(|(_, position)| *position);
+
+            let expected: Vec<ImageId> = expected.into_iter().map(|it| ids[it]).collect();
+            let result: Vec<ImageId> = result.into_iter().map(|(id, _)| id).collect();
+            assert_eq!(expected, result);
+        }
+
+        #[tokio::test]
+        async fn it_should_update_the_image_position() {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+            let first = repo.add(&catalog_item_id, true);
+            let second = repo.add(&catalog_item_id, false);
+
+            let request = ImageRequest {
+                position: Some(1),
+                primary: None,
+            };
+            let image = update_image(&catalog_item_id, &second, request, repo.clone(), NoOpDatabase)
+                .await
+                .expect("the image was not updated");
+
+            assert_eq!(1, image.position);
+            let image_ids: Vec<_> = repo.images(&catalog_item_id).iter().map(|it| it.image_id).collect();
+            assert_eq!(vec![second, first], image_ids);
+        }
+
+        #[rstest]
+        #[case(true, vec![false, true])]
+        #[case(false, vec![true, false])]
+        #[tokio::test]
+        async fn it_should_update_the_primary_flag(#[case] primary: bool, #[case] expected: Vec<bool>) {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+            repo.add(&catalog_item_id, true);
+            let second = repo.add(&catalog_item_id, false);
+
+            let request = ImageRequest {
+                position: None,
+                primary: Some(primary),
+            };
+            let image = update_image(&catalog_item_id, &second, request, repo.clone(), NoOpDatabase)
+                .await
+                .expect("the image was not updated");
+
+            assert_eq!(primary, image.primary);
+            let flags: Vec<_> = repo.images(&catalog_item_id).iter().map(|it| it.primary).collect();
+            assert_eq!(expected, flags);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_image_does_not_exist() {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+            let image_id = ImageId::new();
+
+            let result = update_image(&catalog_item_id, &image_id, ImageRequest::default(), repo, NoOpDatabase).await;
+
+            match result {
+                Err(ImageUpdateError::ImageNotFound(id)) => assert_eq!(image_id, id),
+                _ => panic!("ImageNotFound is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_request_is_not_valid() {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+            let image_id = repo.add(&catalog_item_id, true);
+
+            let request = ImageRequest {
+                position: Some(0),
+                primary: None,
+            };
+            let result = update_image(&catalog_item_id, &image_id, request, repo, NoOpDatabase).await;
+
+            assert!(matches!(result, Err(ImageUpdateError::InvalidRequest(_))));
+        }
+
+        fn catalog_item_id() -> CatalogItemId {
+            CatalogItemId::from_str("acme-60142").unwrap()
+        }
+    }
+}
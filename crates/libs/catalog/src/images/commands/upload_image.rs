@@ -0,0 +1,385 @@
+//! the catalog item image upload command
+
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use crate::images::catalog_item_image::CatalogItemImage;
+use crate::images::commands::repositories::CatalogItemImagesRepository;
+use crate::images::image_format::ImageFormat;
+use crate::images::image_id::ImageId;
+use crate::images::image_storage::ImageStorage;
+use chrono::Utc;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+
+pub type Result<R> = result::Result<R, ImageUploadError>;
+
+/// It represents an image uploaded by a client
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ImageUpload {
+    /// the image content
+    pub content: Vec<u8>,
+    /// the content type declared by the client (if any)
+    pub content_type: Option<String>,
+    /// whether the new image must become the catalog item primary image
+    pub primary: bool,
+}
+
+/// The limits enforced on the catalog item images
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageLimits {
+    /// the max image size in bytes
+    pub max_size_bytes: usize,
+    /// the max number of images for a catalog item
+    pub max_images: usize,
+}
+
+/// Adds a new image to the catalog item gallery, in the last position.
+///
+/// The first image of a catalog item is always the primary one. The image content is stored
+/// before the unit of work is committed, a storage failure leaves the gallery unchanged and
+/// the stored content is removed when the unit of work fails to commit.
+pub async fn upload_image<'db, U, Repo, Storage, DB>(
+    catalog_item_id: &CatalogItemId,
+    upload: ImageUpload,
+    limits: &ImageLimits,
+    repo: Repo,
+    storage: &Storage,
+    db: DB,
+) -> Result<CatalogItemImage>
+where
+    U: UnitOfWork<'db>,
+    Repo: CatalogItemImagesRepository<'db, U>,
+    Storage: ImageStorage + ?Sized,
+    DB: Database<'db, U>,
+{
+    let format = validate_upload(&upload, limits)?;
+
+    let mut unit_of_work = db.begin().await?;
+
+    if !repo.lock_catalog_item(catalog_item_id, &mut unit_of_work).await? {
+        return Err(ImageUploadError::CatalogItemNotFound(catalog_item_id.clone()));
+    }
+
+    let images = repo.find_images(catalog_item_id, &mut unit_of_work).await?;
+    if images.len() >= limits.max_images {
+        return Err(ImageUploadError::TooManyImages(limits.max_images));
+    }
+
+    let primary = upload.primary || images.iter().all(|it| !it.primary);
+    let image = CatalogItemImage {
+        image_id: ImageId::new(),
+        catalog_item_id: catalog_item_id.clone(),
+        content_type: format.content_type().to_owned(),
+        size_bytes: upload.content.len() as i64,
+        position: images.iter().map(|it| it.position).max().unwrap_or_default() + 1,
+        primary,
+        created_at: Utc::now(),
+    };
+
+    repo.insert(
+        &CatalogItemImage {
+            primary: false,
+            ..image.clone()
+        },
+        &mut unit_of_work,
+    )
+    .await?;
+    if primary {
+        repo.set_primary(catalog_item_id, Some(&image.image_id), &mut unit_of_work)
+            .await?;
+    }
+
+    let storage_key = image.storage_key();
+    storage
+        .put(&storage_key, &upload.content, format.content_type())
+        .await
+        .map_err(ImageUploadError::StorageError)?;
+
+    if let Err(why) = unit_of_work.commit().await {
+        // the commit error is the one reported, a failed removal only leaves an unreferenced content behind
+        let _ = storage.delete(&storage_key).await;
+        return Err(why.into());
+    }
+
+    Ok(image)
+}
+
+fn validate_upload(upload: &ImageUpload, limits: &ImageLimits) -> Result<ImageFormat> {
+    if upload.content.is_empty() {
+        return Err(ImageUploadError::EmptyImage);
+    }
+
+    if upload.content.len() > limits.max_size_bytes {
+        return Err(ImageUploadError::ImageTooLarge(limits.max_size_bytes));
+    }
+
+    let format = ImageFormat::sniff(&upload.content)
+        .ok_or_else(|| ImageUploadError::UnsupportedMediaType(String::from("the image format is not supported")))?;
+
+    // a generic (or missing) content type is fine, the sniffed format is used in its place
+    let declared = upload
+        .content_type
+        .as_deref()
+        .filter(|it| !it.starts_with("application/octet-stream"));
+    if let Some(declared) = declared {
+        if ImageFormat::from_content_type(declared) != Some(format) {
+            return Err(ImageUploadError::UnsupportedMediaType(format!(
+                "the image content ({format}) does not match the declared content type ({declared})"
+            )));
+        }
+    }
+
+    Ok(format)
+}
+
+#[derive(Debug, Error)]
+pub enum ImageUploadError {
+    #[error("The image is empty")]
+    EmptyImage,
+
+    #[error("The image is too large (max size: {0} bytes)")]
+    ImageTooLarge(usize),
+
+    #[error("The image media type is not supported: {0}")]
+    UnsupportedMediaType(String),
+
+    #[error("The catalog item was not found (id: {0})")]
+    CatalogItemNotFound(CatalogItemId),
+
+    #[error("The catalog item has too many images (max: {0})")]
+    TooManyImages(usize),
+
+    #[error("Unable to store the image content")]
+    StorageError(#[source] anyhow::Error),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod upload_image_command {
+        use super::*;
+        use crate::images::commands::repositories::in_memory::InMemoryImagesRepository;
+        use crate::images::image_storage::in_memory::InMemoryImageStorage;
+        use common::unit_of_work::noop::{FailingCommitDatabase, NoOpDatabase};
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+        use std::str::FromStr;
+
+        const PNG: &[u8] = b"\x89PNG\x0D\x0A\x1A\x0A\x00\x00\x00\x0DIHDR";
+        const LIMITS: ImageLimits = ImageLimits {
+            max_size_bytes: 64,
+            max_images: 3,
+        };
+
+        #[tokio::test]
+        async fn it_should_upload_the_first_image_as_primary() {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+            let storage = InMemoryImageStorage::empty();
+
+            let image = upload_image(
+                &catalog_item_id,
+                new_upload(Some("image/png"), false),
+                &LIMITS,
+                repo.clone(),
+                &storage,
+                NoOpDatabase,
+            )
+            .await
+            .expect("the image was not uploaded");
+
+            assert_eq!("image/png", image.content_type);
+            assert_eq!(PNG.len() as i64, image.size_bytes);
+            assert_eq!(1, image.position);
+            assert!(image.primary);
+            assert_eq!(vec![image.clone()], repo.images(&catalog_item_id));
+            assert!(storage.contains(&image.storage_key()));
+        }
+
+        #[tokio::test]
+        async fn it_should_append_the_new_images_to_the_gallery() {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+            repo.add(&catalog_item_id, true);
+
+            let image = upload_image(
+                &catalog_item_id,
+                new_upload(None, false),
+                &LIMITS,
+                repo.clone(),
+                &InMemoryImageStorage::empty(),
+                NoOpDatabase,
+            )
+            .await
+            .expect("the image was not uploaded");
+
+            assert_eq!(2, image.position);
+            assert!(!image.primary);
+        }
+
+        #[tokio::test]
+        async fn it_should_replace_the_primary_image() {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+            let previous = repo.add(&catalog_item_id, true);
+
+            let image = upload_image(
+                &catalog_item_id,
+                new_upload(Some("application/octet-stream"), true),
+                &LIMITS,
+                repo.clone(),
+                &InMemoryImageStorage::empty(),
+                NoOpDatabase,
+            )
+            .await
+            .expect("the image was not uploaded");
+
+            let primary: Vec<_> = repo
+                .images(&catalog_item_id)
+                .into_iter()
+                .filter(|it| it.primary)
+                .map(|it| it.image_id)
+                .collect();
+            assert_eq!(vec![image.image_id], primary);
+            assert_ne!(previous, image.image_id);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_catalog_item_does_not_exist() {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::empty();
+
+            let result = upload_image(
+                &catalog_item_id,
+                new_upload(None, false),
+                &LIMITS,
+                repo,
+                &InMemoryImageStorage::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(ImageUploadError::CatalogItemNotFound(id)) => assert_eq!(catalog_item_id, id),
+                _ => panic!("CatalogItemNotFound is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_gallery_is_full() {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+            for _ in 0..LIMITS.max_images {
+                repo.add(&catalog_item_id, false);
+            }
+
+            let result = upload_image(
+                &catalog_item_id,
+                new_upload(None, false),
+                &LIMITS,
+                repo,
+                &InMemoryImageStorage::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            assert!(matches!(result, Err(ImageUploadError::TooManyImages(3))));
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_storage_fails() {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+
+            let result = upload_image(
+                &catalog_item_id,
+                new_upload(None, false),
+                &LIMITS,
+                repo,
+                &InMemoryImageStorage::failing(),
+                NoOpDatabase,
+            )
+            .await;
+
+            assert!(matches!(result, Err(ImageUploadError::StorageError(_))));
+        }
+
+        #[tokio::test]
+        async fn it_should_remove_the_stored_image_when_the_commit_fails() {
+            let catalog_item_id = catalog_item_id();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+            let storage = InMemoryImageStorage::empty();
+
+            let result = upload_image(
+                &catalog_item_id,
+                new_upload(None, false),
+                &LIMITS,
+                repo,
+                &storage,
+                FailingCommitDatabase,
+            )
+            .await;
+
+            assert!(matches!(result, Err(ImageUploadError::UnexpectedError(_))));
+            assert!(storage.is_empty());
+        }
+
+        #[rstest]
+        #[case(b"", None, "The image is empty")]
+        #[case(&[0xFF; 65], None, "The image is too large (max size: 64 bytes)")]
+        #[case(
+            b"%PDF-1.7",
+            None,
+            "The image media type is not supported: the image format is not supported"
+        )]
+        #[case(
+            b"\xFF\xD8\xFF\xE0\x00\x10JFIF",
+            Some("image/png"),
+            "The image media type is not supported: the image content (image/jpeg) does not match the declared content type (image/png)"
+        )]
+        fn it_should_validate_the_uploads(
+            #[case] content: &[u8],
+            #[case] content_type: Option<&str>,
+            #[case] expected: &str,
+        ) {
+            let upload = ImageUpload {
+                content: content.to_vec(),
+                content_type: content_type.map(String::from),
+                primary: false,
+            };
+
+            let result = validate_upload(&upload, &LIMITS);
+
+            assert_eq!(expected, result.unwrap_err().to_string());
+        }
+
+        #[rstest]
+        #[case(None)]
+        #[case(Some("image/png"))]
+        #[case(Some("application/octet-stream"))]
+        fn it_should_accept_the_valid_uploads(#[case] content_type: Option<&str>) {
+            let result = validate_upload(&new_upload(content_type, false), &LIMITS);
+            assert_eq!(ImageFormat::Png, result.unwrap());
+        }
+
+        fn catalog_item_id() -> CatalogItemId {
+            CatalogItemId::from_str("acme-60142").unwrap()
+        }
+
+        fn new_upload(content_type: Option<&str>, primary: bool) -> ImageUpload {
+            ImageUpload {
+                content: PNG.to_vec(),
+                content_type: content_type.map(String::from),
+                primary,
+            }
+        }
+    }
+}
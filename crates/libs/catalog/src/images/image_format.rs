@@ -0,0 +1,106 @@
+//! the image formats
+
+use std::fmt;
+use std::fmt::Formatter;
+
+/// The image formats accepted for the catalog item images.
+///
+/// The format is always recognized from the image content (its "magic number"), the content type
+/// declared by the clients is only checked for consistency.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ImageFormat {
+    Gif,
+    Jpeg,
+    Png,
+    Webp,
+}
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+const JPEG_SIGNATURE: [u8; 3] = [0xFF, 0xD8, 0xFF];
+
+impl ImageFormat {
+    /// Recognizes the image format from its content, it returns `None` for unsupported formats
+    pub fn sniff(content: &[u8]) -> Option<ImageFormat> {
+        if content.starts_with(&JPEG_SIGNATURE) {
+            Some(ImageFormat::Jpeg)
+        } else if content.starts_with(&PNG_SIGNATURE) {
+            Some(ImageFormat::Png)
+        } else if content.starts_with(b"GIF87a") || content.starts_with(b"GIF89a") {
+            Some(ImageFormat::Gif)
+        } else if content.len() >= 12 && content.starts_with(b"RIFF") && &content[8..12] == b"WEBP" {
+            Some(ImageFormat::Webp)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the image format for the media type, if supported
+    pub fn from_content_type(content_type: &str) -> Option<ImageFormat> {
+        let media_type = content_type.split(';').next().unwrap_or_default().trim();
+        match media_type.to_ascii_lowercase().as_str() {
+            "image/gif" => Some(ImageFormat::Gif),
+            "image/jpeg" | "image/jpg" => Some(ImageFormat::Jpeg),
+            "image/png" => Some(ImageFormat::Png),
+            "image/webp" => Some(ImageFormat::Webp),
+            _ => None,
+        }
+    }
+
+    /// Returns the media type for this image format
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ImageFormat::Gif => "image/gif",
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Png => "image/png",
+            ImageFormat::Webp => "image/webp",
+        }
+    }
+}
+
+impl fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.content_type())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod image_formats {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        #[rstest]
+        #[case(b"GIF89a\x01\x00", Some(ImageFormat::Gif))]
+        #[case(b"GIF87a\x01\x00", Some(ImageFormat::Gif))]
+        #[case(b"\xFF\xD8\xFF\xE0\x00\x10JFIF", Some(ImageFormat::Jpeg))]
+        #[case(b"\x89PNG\x0D\x0A\x1A\x0A\x00\x00", Some(ImageFormat::Png))]
+        #[case(b"RIFF\x24\x00\x00\x00WEBPVP8 ", Some(ImageFormat::Webp))]
+        #[case(b"RIFF\x24\x00\x00\x00WAVEfmt ", None)]
+        #[case(b"%PDF-1.7", None)]
+        #[case(b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>", None)]
+        #[case(b"", None)]
+        fn it_should_sniff_the_image_formats(#[case] content: &[u8], #[case] expected: Option<ImageFormat>) {
+            assert_eq!(expected, ImageFormat::sniff(content));
+        }
+
+        #[rstest]
+        #[case("image/gif", Some(ImageFormat::Gif))]
+        #[case("image/jpeg", Some(ImageFormat::Jpeg))]
+        #[case("image/jpg", Some(ImageFormat::Jpeg))]
+        #[case("IMAGE/PNG", Some(ImageFormat::Png))]
+        #[case("image/webp; charset=binary", Some(ImageFormat::Webp))]
+        #[case("image/svg+xml", None)]
+        #[case("application/octet-stream", None)]
+        fn it_should_parse_the_content_types(#[case] content_type: &str, #[case] expected: Option<ImageFormat>) {
+            assert_eq!(expected, ImageFormat::from_content_type(content_type));
+        }
+
+        #[test]
+        fn it_should_display_image_formats() {
+            assert_eq!("image/png", ImageFormat::Png.to_string());
+        }
+    }
+}
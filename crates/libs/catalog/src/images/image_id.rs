@@ -0,0 +1,89 @@
+//! the image identifier
+
+use sqlx::Type;
+use std::fmt;
+use std::fmt::Formatter;
+use std::str;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A unique identifier for an image
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+pub struct ImageId(Uuid);
+
+impl ImageId {
+    /// Create a new random image id
+    pub fn new() -> Self {
+        ImageId::default()
+    }
+
+    /// the image unique identifier value
+    pub fn value(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for ImageId {
+    fn default() -> Self {
+        let id = Uuid::new_v4();
+        ImageId(id)
+    }
+}
+
+impl fmt::Display for ImageId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+type Error = &'static str;
+
+impl str::FromStr for ImageId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = Uuid::try_parse(s).map_err(|_| "invalid image id")?;
+        Ok(ImageId(id))
+    }
+}
+
+impl From<Uuid> for ImageId {
+    fn from(id: Uuid) -> Self {
+        ImageId(id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod image_ids {
+        use std::str::FromStr;
+
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_create_new_image_id_from_str() {
+            let id = "3302b9a7-252c-4b41-8de2-eb71efb1888e".parse::<ImageId>().unwrap();
+            assert_eq!(
+                ImageId(Uuid::from_str("3302b9a7-252c-4b41-8de2-eb71efb1888e").unwrap()),
+                id
+            );
+        }
+
+        #[test]
+        fn it_should_create_new_image_id_from_uuid() {
+            let uuid = Uuid::new_v4();
+            let image_id: ImageId = uuid.into();
+            assert_eq!(uuid, image_id.value());
+        }
+
+        #[test]
+        fn it_should_fail_to_parse_invalid_values_as_images() {
+            let result = "invalid value".parse::<ImageId>();
+            assert!(result.is_err());
+        }
+    }
+}
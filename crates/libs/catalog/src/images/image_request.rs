@@ -0,0 +1,39 @@
+//! the catalog item image command requests
+
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// A request to change the position or the primary flag of a catalog item image
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ImageRequest {
+    /// the new image position in the gallery (starting from 1)
+    #[validate(range(min = 1))]
+    pub position: Option<i32>,
+    /// whether the image is the catalog item primary image
+    pub primary: Option<bool>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod image_request_validation {
+        use super::*;
+        use rstest::rstest;
+
+        #[rstest]
+        #[case(None, None, true)]
+        #[case(Some(1), Some(true), true)]
+        #[case(Some(42), None, true)]
+        #[case(Some(0), None, false)]
+        #[case(Some(-1), Some(false), false)]
+        fn it_should_validate_image_requests(
+            #[case] position: Option<i32>,
+            #[case] primary: Option<bool>,
+            #[case] is_valid: bool,
+        ) {
+            let request = ImageRequest { position, primary };
+            assert_eq!(is_valid, request.validate().is_ok());
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! the storage for the catalog item images content
+
+use async_trait::async_trait;
+
+/// A binary storage for the catalog item images content.
+///
+/// The image metadata (ordering, primary flag) are stored in the database, the storage only
+/// deals with the image bytes.
+#[async_trait]
+pub trait ImageStorage: Send + Sync {
+    /// Stores the content under the given key, replacing the previous content (if any)
+    async fn put(&self, key: &str, content: &[u8], content_type: &str) -> Result<(), anyhow::Error>;
+
+    /// Returns the content stored under the given key, `None` when the key does not exist
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, anyhow::Error>;
+
+    /// Removes the content stored under the given key, removing a missing key is not an error
+    async fn delete(&self, key: &str) -> Result<(), anyhow::Error>;
+}
+
+#[cfg(test)]
+pub mod in_memory {
+    use crate::images::image_storage::ImageStorage;
+    use anyhow::anyhow;
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    /// An in-memory image storage, the state is shared between the clones of the same storage
+    #[derive(Clone, Default)]
+    pub struct InMemoryImageStorage {
+        content: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+        failing: Arc<AtomicBool>,
+    }
+
+    impl InMemoryImageStorage {
+        /// Creates an empty in-memory image storage
+        pub fn empty() -> Self {
+            InMemoryImageStorage::default()
+        }
+
+        /// Creates an in-memory image storage which fails every operation
+        pub fn failing() -> Self {
+            let storage = InMemoryImageStorage::default();
+            storage.failing.store(true, Ordering::SeqCst);
+            storage
+        }
+
+        /// Checks whether some content is stored under the given key
+        pub fn contains(&self, key: &str) -> bool {
+            let content = self.content.lock().expect("unable to acquire the lock");
+            content.contains_key(key)
+        }
+
+        /// Checks whether no content is stored
+        pub fn is_empty(&self) -> bool {
+            let content = self.content.lock().expect("unable to acquire the lock");
+            content.is_empty()
+        }
+
+        fn check(&self) -> Result<(), anyhow::Error> {
+            if self.failing.load(Ordering::SeqCst) {
+                Err(anyhow!("the image storage is not available"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ImageStorage for InMemoryImageStorage {
+        async fn put(&self, key: &str, content: &[u8], _content_type: &str) -> Result<(), anyhow::Error> {
+            self.check()?;
+            let mut state = self.content.lock().expect("unable to acquire the lock");
+            state.insert(key.to_owned(), content.to_vec());
+            Ok(())
+        }
+
+        async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, anyhow::Error> {
+            self.check()?;
+            let state = self.content.lock().expect("unable to acquire the lock");
+            Ok(state.get(key).cloned())
+        }
+
+        async fn delete(&self, key: &str) -> Result<(), anyhow::Error> {
+            self.check()?;
+            let mut state = self.content.lock().expect("unable to acquire the lock");
+            state.remove(key);
+            Ok(())
+        }
+    }
+}
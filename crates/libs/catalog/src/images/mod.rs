@@ -0,0 +1,9 @@
+//! the module includes everything related to the catalog item images
+
+pub mod catalog_item_image;
+pub mod commands;
+pub mod image_format;
+pub mod image_id;
+pub mod image_request;
+pub mod image_storage;
+pub mod queries;
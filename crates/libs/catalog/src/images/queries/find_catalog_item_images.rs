@@ -0,0 +1,85 @@
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use crate::images::catalog_item_image::CatalogItemImage;
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+
+/// The query to find the catalog item images, sorted by their position
+pub async fn find_catalog_item_images<'db, U, Repo, DB>(
+    catalog_item_id: &CatalogItemId,
+    repo: Repo,
+    db: DB,
+) -> Result<Vec<CatalogItemImage>, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindCatalogItemImagesRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    if !repo.catalog_item_exists(catalog_item_id, &mut unit_of_work).await? {
+        return Err(QueryError::EmptyResultSet);
+    }
+
+    let result = repo.find_images(catalog_item_id, &mut unit_of_work).await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(result)
+}
+
+/// The find catalog item images repository
+#[async_trait]
+pub trait FindCatalogItemImagesRepository<'db, U: UnitOfWork<'db>> {
+    /// Checks if a catalog item with the input id exists (and it was not deleted)
+    async fn catalog_item_exists(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut U,
+    ) -> Result<bool, DatabaseError>;
+
+    /// Returns the catalog item images, sorted by their position
+    async fn find_images(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut U,
+    ) -> Result<Vec<CatalogItemImage>, DatabaseError>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod find_catalog_item_images_query {
+        use super::*;
+        use crate::images::commands::repositories::in_memory::InMemoryImagesRepository;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+        use std::str::FromStr;
+
+        #[tokio::test]
+        async fn it_should_find_the_catalog_item_images() {
+            let catalog_item_id = CatalogItemId::from_str("acme-60142").unwrap();
+            let repo = InMemoryImagesRepository::with(&catalog_item_id);
+            let first = repo.add(&catalog_item_id, true);
+            let second = repo.add(&catalog_item_id, false);
+
+            let result = find_catalog_item_images(&catalog_item_id, repo, NoOpDatabase)
+                .await
+                .expect("the images were not found");
+
+            let image_ids: Vec<_> = result.iter().map(|it| it.image_id).collect();
+            assert_eq!(vec![first, second], image_ids);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_catalog_item_does_not_exist() {
+            let catalog_item_id = CatalogItemId::from_str("acme-60142").unwrap();
+            let repo = InMemoryImagesRepository::empty();
+
+            let result = find_catalog_item_images(&catalog_item_id, repo, NoOpDatabase).await;
+
+            assert!(matches!(result, Err(QueryError::EmptyResultSet)));
+        }
+    }
+}
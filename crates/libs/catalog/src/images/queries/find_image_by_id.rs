@@ -0,0 +1,38 @@
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use crate::images::catalog_item_image::CatalogItemImage;
+use crate::images::image_id::ImageId;
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+
+/// The query to find a catalog item image by its `image_id`
+pub async fn find_image_by_id<'db, U, Repo, DB>(
+    catalog_item_id: &CatalogItemId,
+    image_id: &ImageId,
+    repo: Repo,
+    db: DB,
+) -> Result<CatalogItemImage, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindImageByIdRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let result = repo.find_image(catalog_item_id, image_id, &mut unit_of_work).await?;
+
+    unit_of_work.commit().await?;
+
+    result.ok_or(QueryError::EmptyResultSet)
+}
+
+/// The find catalog item image by id repository
+#[async_trait]
+pub trait FindImageByIdRepository<'db, U: UnitOfWork<'db>> {
+    async fn find_image(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        image_id: &ImageId,
+        unit_of_work: &mut U,
+    ) -> Result<Option<CatalogItemImage>, DatabaseError>;
+}
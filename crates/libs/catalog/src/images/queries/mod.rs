@@ -0,0 +1,4 @@
+//! the catalog item image queries
+
+pub mod find_catalog_item_images;
+pub mod find_image_by_id;
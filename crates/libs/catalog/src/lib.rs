@@ -4,15 +4,23 @@
 //! The list of supported types includes:
 //!
 //! - brands
+//! - catalog item images
 //! - catalog items and rolling stocks
+//! - domain events
+//! - prototypes
 //! - railway companies
 //! - modeling scales
+//! - webhook subscriptions
 
 pub mod brands;
 pub mod catalog_items;
 pub mod common;
+pub mod events;
+pub mod images;
+pub mod prototypes;
 pub mod railways;
 pub mod scales;
+pub mod webhooks;
 
 #[cfg(test)]
 mod test_helpers;
@@ -0,0 +1,4 @@
+//! the prototype commands
+
+pub mod new_prototype;
+pub mod repositories;
@@ -0,0 +1,186 @@
+use crate::events::domain_event::DomainEvent;
+use crate::events::repositories::OutboxRepository;
+use crate::prototypes::commands::repositories::NewPrototypeRepository;
+use crate::prototypes::prototype_id::PrototypeId;
+use crate::prototypes::prototype_request::PrototypeRequest;
+use crate::prototypes::prototype_response::PrototypeCreated;
+use crate::railways::railway_id::RailwayId;
+use chrono::Utc;
+use common::metadata::Metadata;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+use validator::{Validate, ValidationErrors};
+
+pub type Result<R> = result::Result<R, PrototypeCreationError>;
+
+pub async fn create_new_prototype<'db, U, Repo, O, DB>(
+    request: PrototypeRequest,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<PrototypeCreated>
+where
+    U: UnitOfWork<'db>,
+    Repo: NewPrototypeRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let command = NewPrototypeCommand::try_from(request)?;
+    let prototype_id = command.prototype_id.clone();
+
+    let mut unit_of_work = db.begin().await?;
+
+    if repo.exists(&prototype_id, &mut unit_of_work).await? {
+        return Err(PrototypeCreationError::PrototypeAlreadyExists(prototype_id));
+    }
+
+    if !repo.railway_exists(&command.railway_id, &mut unit_of_work).await? {
+        return Err(PrototypeCreationError::RailwayNotFound(command.railway_id));
+    }
+
+    repo.insert(&command, &mut unit_of_work).await?;
+
+    let created = PrototypeCreated {
+        prototype_id,
+        created_at: *command.metadata.created(),
+    };
+    outbox
+        .append(&DomainEvent::PrototypeCreated(created.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(created)
+}
+
+#[derive(Debug, Error)]
+pub enum PrototypeCreationError {
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+
+    #[error("The prototype request is not valid")]
+    InvalidRequest(ValidationErrors),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error("The prototype already exists (id: {0})")]
+    PrototypeAlreadyExists(PrototypeId),
+
+    #[error("Unable to create the prototype due to railway not found (id: {0})")]
+    RailwayNotFound(RailwayId),
+}
+
+/// It represents the command to create a new prototype
+#[derive(Debug, Clone)]
+pub struct NewPrototypeCommand {
+    pub prototype_id: PrototypeId,
+    pub railway_id: RailwayId,
+    pub payload: PrototypeRequest,
+    pub metadata: Metadata,
+}
+
+impl TryFrom<PrototypeRequest> for NewPrototypeCommand {
+    type Error = PrototypeCreationError;
+
+    fn try_from(value: PrototypeRequest) -> result::Result<Self, Self::Error> {
+        value.validate().map_err(PrototypeCreationError::InvalidRequest)?;
+
+        let railway_id = RailwayId::new(&value.railway);
+        let prototype_id = PrototypeId::of(&railway_id, &value.class_name, value.series.as_deref());
+        Ok(NewPrototypeCommand {
+            prototype_id,
+            railway_id,
+            payload: value,
+            metadata: Metadata::created_at(Utc::now()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod new_prototype_command {
+        use super::*;
+        use crate::events::repositories::in_memory::InMemoryOutboxRepository;
+        use crate::prototypes::commands::repositories::in_memory::InMemoryPrototypeRepository;
+        use crate::prototypes::power_type::PowerType;
+        use crate::prototypes::years_built::YearsBuilt;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_create_a_new_prototype() {
+            let repo = InMemoryPrototypeRepository::empty().with_railway(RailwayId::new("FS"));
+            let outbox = InMemoryOutboxRepository::empty();
+
+            let result = create_new_prototype(new_prototype(), repo, outbox.clone(), NoOpDatabase).await;
+
+            let created = result.expect("result is an error");
+            assert_eq!(PrototypeId::new("fs-e-626"), created.prototype_id);
+            assert_eq!(1, outbox.events().len());
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_prototype_already_exists() {
+            let command = NewPrototypeCommand::try_from(new_prototype()).unwrap();
+            let repo = InMemoryPrototypeRepository::with(command).with_railway(RailwayId::new("FS"));
+
+            let result =
+                create_new_prototype(new_prototype(), repo, InMemoryOutboxRepository::empty(), NoOpDatabase).await;
+
+            match result {
+                Err(PrototypeCreationError::PrototypeAlreadyExists(id)) => {
+                    assert_eq!(PrototypeId::new("fs-e-626"), id)
+                }
+                _ => panic!("PrototypeAlreadyExists is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_railway_is_not_found() {
+            let repo = InMemoryPrototypeRepository::empty();
+
+            let result =
+                create_new_prototype(new_prototype(), repo, InMemoryOutboxRepository::empty(), NoOpDatabase).await;
+
+            match result {
+                Err(PrototypeCreationError::RailwayNotFound(id)) => assert_eq!(RailwayId::new("FS"), id),
+                _ => panic!("RailwayNotFound is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_request_is_not_valid() {
+            let request = PrototypeRequest {
+                class_name: String::new(),
+                ..new_prototype()
+            };
+
+            let result = create_new_prototype(
+                request,
+                InMemoryPrototypeRepository::empty(),
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            assert!(matches!(result, Err(PrototypeCreationError::InvalidRequest(_))));
+        }
+
+        fn new_prototype() -> PrototypeRequest {
+            PrototypeRequest {
+                railway: String::from("FS"),
+                class_name: String::from("E.626"),
+                series: None,
+                builder: Some(String::from("TIBB")),
+                years_built: YearsBuilt::new(1927, Some(1939)).ok(),
+                power_type: Some(PowerType::Electric),
+                length_over_buffers: None,
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::prototypes::commands::new_prototype::NewPrototypeCommand;
+use crate::prototypes::prototype_id::PrototypeId;
+use crate::railways::railway_id::RailwayId;
+use async_trait::async_trait;
+use common::unit_of_work::UnitOfWork;
+
+/// The persistence related functionality for the prototype commands
+#[async_trait]
+pub trait NewPrototypeRepository<'db, U: UnitOfWork<'db>> {
+    /// Checks if a prototype with the input id already exists
+    async fn exists(&self, prototype_id: &PrototypeId, unit_of_work: &mut U) -> Result<bool, anyhow::Error>;
+
+    /// Inserts a new prototype
+    async fn insert(&self, new_prototype: &NewPrototypeCommand, unit_of_work: &mut U) -> Result<(), anyhow::Error>;
+
+    /// Checks if the railway exists
+    async fn railway_exists(&self, railway_id: &RailwayId, unit_of_work: &mut U) -> Result<bool, anyhow::Error>;
+}
+
+#[cfg(test)]
+pub mod in_memory {
+    use crate::prototypes::commands::new_prototype::NewPrototypeCommand;
+    use crate::prototypes::commands::repositories::NewPrototypeRepository;
+    use crate::prototypes::prototype_id::PrototypeId;
+    use crate::railways::railway_id::RailwayId;
+    use async_trait::async_trait;
+    use common::in_memory::InMemoryRepository;
+    use common::unit_of_work::noop::NoOpUnitOfWork;
+
+    /// An in-memory prototype repository
+    pub struct InMemoryPrototypeRepository {
+        prototypes: InMemoryRepository<PrototypeId, NewPrototypeCommand>,
+        railways: Vec<RailwayId>,
+    }
+
+    impl InMemoryPrototypeRepository {
+        /// Creates an empty in memory prototypes repository
+        pub fn empty() -> Self {
+            InMemoryPrototypeRepository {
+                prototypes: InMemoryRepository::empty(),
+                railways: Vec::new(),
+            }
+        }
+
+        /// Creates a new in-memory prototypes repository with an initial element
+        pub fn with(command: NewPrototypeCommand) -> Self {
+            InMemoryPrototypeRepository {
+                prototypes: InMemoryRepository::of(command.prototype_id.clone(), command),
+                railways: Vec::new(),
+            }
+        }
+
+        /// Adds the railway to the repository
+        pub fn with_railway(mut self, railway_id: RailwayId) -> Self {
+            self.railways.push(railway_id);
+            self
+        }
+    }
+
+    #[async_trait]
+    impl NewPrototypeRepository<'static, NoOpUnitOfWork> for InMemoryPrototypeRepository {
+        async fn exists(
+            &self,
+            prototype_id: &PrototypeId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            Ok(self.prototypes.contains(prototype_id))
+        }
+
+        async fn insert(
+            &self,
+            new_prototype: &NewPrototypeCommand,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<(), anyhow::Error> {
+            self.prototypes
+                .add(new_prototype.prototype_id.clone(), new_prototype.clone());
+            Ok(())
+        }
+
+        async fn railway_exists(
+            &self,
+            railway_id: &RailwayId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            Ok(self.railways.contains(railway_id))
+        }
+    }
+}
@@ -0,0 +1,13 @@
+//! the module includes everything related to the real world vehicles (prototypes) modelled by the rolling stocks
+
+pub mod commands;
+pub mod power_type;
+pub mod prototype;
+pub mod prototype_id;
+pub mod prototype_request;
+pub mod prototype_response;
+pub mod queries;
+pub mod years_built;
+
+#[cfg(test)]
+pub mod test_data;
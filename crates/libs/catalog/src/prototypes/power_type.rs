@@ -0,0 +1,68 @@
+//! the prototype power type
+
+use sqlx::Type;
+use strum_macros;
+use strum_macros::{Display, EnumString};
+use utoipa::ToSchema;
+
+/// The way the real world vehicle is powered.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, Display, Type, ToSchema)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[strum(ascii_case_insensitive)]
+#[sqlx(type_name = "power_type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PowerType {
+    /// Powered by batteries.
+    #[serde(rename = "BATTERY")]
+    Battery,
+
+    /// Powered by a diesel engine.
+    #[serde(rename = "DIESEL")]
+    Diesel,
+
+    /// Powered by electricity from the overhead line (or a third rail).
+    #[serde(rename = "ELECTRIC")]
+    Electric,
+
+    /// More than one power source (ie, electro-diesel locomotives).
+    #[serde(rename = "HYBRID")]
+    Hybrid,
+
+    /// Powered by a steam engine.
+    #[serde(rename = "STEAM")]
+    Steam,
+
+    /// Vehicles without their own power source (ie, passenger and freight cars).
+    #[serde(rename = "UNPOWERED")]
+    Unpowered,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod power_types {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+        use strum::ParseError;
+
+        #[rstest]
+        #[case("BATTERY", Ok(PowerType::Battery))]
+        #[case("DIESEL", Ok(PowerType::Diesel))]
+        #[case("electric", Ok(PowerType::Electric))]
+        #[case("HYBRID", Ok(PowerType::Hybrid))]
+        #[case("STEAM", Ok(PowerType::Steam))]
+        #[case("UNPOWERED", Ok(PowerType::Unpowered))]
+        #[case("invalid", Err(ParseError::VariantNotFound))]
+        fn it_should_parse_string_as_power_types(#[case] input: &str, #[case] expected: Result<PowerType, ParseError>) {
+            assert_eq!(expected, input.parse::<PowerType>());
+        }
+
+        #[rstest]
+        #[case(PowerType::Electric, "ELECTRIC")]
+        #[case(PowerType::Unpowered, "UNPOWERED")]
+        fn it_should_display_power_types(#[case] input: PowerType, #[case] expected: &str) {
+            assert_eq!(expected, input.to_string());
+        }
+    }
+}
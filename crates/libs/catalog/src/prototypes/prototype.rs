@@ -0,0 +1,125 @@
+//! the prototype view models
+
+use crate::prototypes::power_type::PowerType;
+use crate::prototypes::prototype_id::PrototypeId;
+use crate::prototypes::years_built::YearsBuilt;
+use crate::railways::railway_id::RailwayId;
+use common::length::Length;
+use common::metadata::Metadata;
+use std::fmt;
+use utoipa::ToSchema;
+
+/// A prototype is the real world vehicle (or class of vehicles) modelled by the rolling stocks.
+///
+/// The same prototype (ie, the FS E.626 or the DB BR 103) is produced by many brands, in many scales:
+/// the prototype is shared by all these rolling stocks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Prototype {
+    /// the unique identifier for the prototype
+    pub prototype_id: PrototypeId,
+    /// the railway operating the prototype
+    pub railway: PrototypeRailway,
+    /// the class name. The class is a group of vehicles built to a common design
+    pub class_name: String,
+    /// the series within the class
+    pub series: Option<String>,
+    /// the manufacturer
+    pub builder: Option<String>,
+    /// the years of production
+    pub years_built: Option<YearsBuilt>,
+    /// the way the prototype is powered
+    pub power_type: Option<PowerType>,
+    /// the real world overall length (in meters)
+    #[serde(default, with = "common::length::serde::meters_option")]
+    #[schema(value_type = Option<f64>)]
+    pub length_over_buffers: Option<Length>,
+    /// the metadata
+    pub metadata: Metadata,
+}
+
+impl Prototype {
+    /// The unique identifier for this prototype
+    pub fn prototype_id(&self) -> &PrototypeId {
+        &self.prototype_id
+    }
+
+    /// The railway operating this prototype
+    pub fn railway(&self) -> &PrototypeRailway {
+        &self.railway
+    }
+
+    /// The prototype class name
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// The (optional) prototype series
+    pub fn series(&self) -> Option<&str> {
+        self.series.as_deref()
+    }
+
+    /// The prototype metadata
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+}
+
+impl fmt::Display for Prototype {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.series {
+            Some(series) => write!(f, "{} {} ({})", self.railway, self.class_name, series),
+            None => write!(f, "{} {}", self.railway, self.class_name),
+        }
+    }
+}
+
+/// It represents the railway operating the prototype
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PrototypeRailway {
+    /// the railway unique identifier
+    pub railway_id: RailwayId,
+    /// the railway display name
+    pub display: String,
+}
+
+impl PrototypeRailway {
+    /// Creates a new railway with the display text.
+    pub fn new(railway_id: RailwayId, display: &str) -> Self {
+        PrototypeRailway {
+            railway_id,
+            display: display.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PrototypeRailway {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", &self.display)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod prototypes {
+        use super::*;
+        use crate::prototypes::test_data::{db_br_103, fs_e626};
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_display_prototypes() {
+            assert_eq!("FS E.626", fs_e626().to_string());
+            assert_eq!("DB BR 103 (103.1)", db_br_103().to_string());
+        }
+
+        #[test]
+        fn it_should_return_the_prototype_values() {
+            let prototype = db_br_103();
+            assert_eq!(&PrototypeId::new("db-br-103-103-1"), prototype.prototype_id());
+            assert_eq!(&RailwayId::new("DB"), &prototype.railway().railway_id);
+            assert_eq!("BR 103", prototype.class_name());
+            assert_eq!(Some("103.1"), prototype.series());
+        }
+    }
+}
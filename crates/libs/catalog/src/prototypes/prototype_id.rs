@@ -0,0 +1,91 @@
+//! the prototype identifier
+
+use crate::railways::railway_id::RailwayId;
+use common::slug::{Slug, SlugParserError};
+use sqlx::Type;
+use std::fmt;
+use std::ops;
+use std::str;
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// It represents a unique prototype id
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+pub struct PrototypeId(Slug);
+
+impl PrototypeId {
+    /// Creates a new unique identifier for a prototype
+    ///
+    /// # Panics
+    /// Panics if `id` is not a valid value (ie, blank string)
+    pub fn new(id: &str) -> Self {
+        PrototypeId::from_str(id).expect("invalid prototype id")
+    }
+
+    /// Creates the prototype id from the railway, the class name and the (optional) series
+    /// (ie, `fs-e-626` or `db-br-103-103-1`)
+    pub fn of(railway_id: &RailwayId, class_name: &str, series: Option<&str>) -> Self {
+        let id = match series {
+            Some(series) => format!("{railway_id} {class_name} {series}"),
+            None => format!("{railway_id} {class_name}"),
+        };
+        PrototypeId::new(&id)
+    }
+}
+
+impl str::FromStr for PrototypeId {
+    type Err = SlugParserError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Slug::from_str(s).map(PrototypeId)
+    }
+}
+
+impl fmt::Display for PrototypeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ops::Deref for PrototypeId {
+    type Target = Slug;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod prototype_ids {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_return_an_error_when_the_prototype_id_is_empty() {
+            let result = PrototypeId::from_str("");
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn it_should_create_new_prototype_ids() {
+            let prototype_id = PrototypeId::new("FS E626");
+            assert_eq!("fs-e626", prototype_id.to_string());
+        }
+
+        #[test]
+        fn it_should_create_prototype_ids_from_the_railway_and_class_name() {
+            let prototype_id = PrototypeId::of(&RailwayId::new("FS"), "E.626", None);
+            assert_eq!("fs-e-626", prototype_id.to_string());
+        }
+
+        #[test]
+        fn it_should_create_prototype_ids_including_the_series() {
+            let prototype_id = PrototypeId::of(&RailwayId::new("DB"), "BR 103", Some("103.1"));
+            assert_eq!("db-br-103-103-1", prototype_id.to_string());
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! the prototype command requests
+
+use crate::prototypes::power_type::PowerType;
+use crate::prototypes::years_built::YearsBuilt;
+use common::length::{validate_length_range, Length};
+use rust_decimal_macros::dec;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+/// A request to create prototypes
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct PrototypeRequest {
+    /// the railway name for this prototype
+    #[validate(length(min = 2, max = 50))]
+    pub railway: String,
+    /// the class name
+    #[validate(length(min = 1, max = 50))]
+    pub class_name: String,
+    /// the series within the class
+    #[validate(length(min = 1, max = 50))]
+    pub series: Option<String>,
+    /// the manufacturer
+    #[validate(length(max = 100))]
+    pub builder: Option<String>,
+    /// the years of production
+    #[validate(custom(function = "crate::prototypes::years_built::validate_years_built"))]
+    pub years_built: Option<YearsBuilt>,
+    /// the way the prototype is powered
+    pub power_type: Option<PowerType>,
+    /// the real world overall length (in meters)
+    #[serde(default, with = "common::length::serde::meters_option")]
+    #[schema(value_type = Option<f64>)]
+    #[validate(custom(function = "validate_prototype_length"))]
+    pub length_over_buffers: Option<Length>,
+}
+
+fn validate_prototype_length(input: &Length) -> Result<(), ValidationError> {
+    validate_length_range(input, Some(dec!(1.0)), Some(dec!(500.0)))
+}
+
+#[cfg(test)]
+mod test {
+    mod prototype_request_validation {
+        use crate::prototypes::prototype_request::PrototypeRequest;
+        use crate::prototypes::years_built::YearsBuilt;
+        use crate::test_helpers::random_str;
+        use common::length::Length;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+        use rust_decimal::Decimal;
+        use rust_decimal_macros::dec;
+        use validator::Validate;
+
+        #[test]
+        fn it_should_validate_valid_prototype_requests() {
+            let request = PrototypeRequest {
+                railway: String::from("FS"),
+                class_name: String::from("E.626"),
+                years_built: Some(YearsBuilt {
+                    from: 1927,
+                    until: None,
+                }),
+                ..PrototypeRequest::default()
+            };
+
+            assert!(request.validate().is_ok());
+        }
+
+        #[rstest]
+        #[case(random_str(0))]
+        #[case(random_str(51))]
+        fn it_should_validate_the_class_name(#[case] input: String) {
+            let request = PrototypeRequest {
+                railway: String::from("FS"),
+                class_name: input,
+                ..PrototypeRequest::default()
+            };
+
+            let err = request.validate().unwrap_err();
+            let errors = err.field_errors();
+            assert!(errors.contains_key("class_name"));
+            assert_eq!(errors["class_name"][0].code, "length");
+        }
+
+        #[test]
+        fn it_should_validate_the_years_built() {
+            let request = PrototypeRequest {
+                railway: String::from("FS"),
+                class_name: String::from("E.626"),
+                years_built: Some(YearsBuilt {
+                    from: 1939,
+                    until: Some(1927),
+                }),
+                ..PrototypeRequest::default()
+            };
+
+            let err = request.validate().unwrap_err();
+            let errors = err.field_errors();
+            assert!(errors.contains_key("years_built"));
+            assert_eq!(errors["years_built"][0].code, "years_built");
+        }
+
+        #[rstest]
+        #[case(dec!(0.5))]
+        #[case(dec!(501.0))]
+        fn it_should_validate_the_length_over_buffers(#[case] meters: Decimal) {
+            let request = PrototypeRequest {
+                railway: String::from("FS"),
+                class_name: String::from("E.626"),
+                length_over_buffers: Some(Length::Meters(meters)),
+                ..PrototypeRequest::default()
+            };
+
+            let err = request.validate().unwrap_err();
+            assert!(err.field_errors().contains_key("length_over_buffers"));
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! the prototype command responses
+
+use crate::prototypes::prototype_id::PrototypeId;
+use chrono::{DateTime, Utc};
+
+/// A response for new prototypes creation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrototypeCreated {
+    pub prototype_id: PrototypeId,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,35 @@
+use crate::prototypes::prototype::Prototype;
+use crate::railways::railway_id::RailwayId;
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+
+/// The query to find all the (not deleted) prototypes, optionally only the ones for the given railway
+pub async fn find_all_prototypes<'db, U, Repo, DB>(
+    railway_id: Option<&RailwayId>,
+    repo: Repo,
+    db: DB,
+) -> Result<Vec<Prototype>, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindAllPrototypesRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let result = repo.find_all(railway_id, &mut unit_of_work).await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(result)
+}
+
+#[async_trait]
+pub trait FindAllPrototypesRepository<'db, U: UnitOfWork<'db>> {
+    /// Returns all the (not deleted) prototypes, only the ones for the railway when it is provided
+    async fn find_all(
+        &self,
+        railway_id: Option<&RailwayId>,
+        unit_of_work: &mut U,
+    ) -> Result<Vec<Prototype>, DatabaseError>;
+}
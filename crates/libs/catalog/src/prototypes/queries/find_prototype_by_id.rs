@@ -0,0 +1,102 @@
+use crate::prototypes::prototype::Prototype;
+use crate::prototypes::prototype_id::PrototypeId;
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+
+/// The query to find a prototype with the given `prototype_id`
+pub async fn find_prototype_by_id<'db, U, Repo, DB>(
+    prototype_id: &PrototypeId,
+    repo: Repo,
+    db: DB,
+) -> Result<Prototype, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindPrototypeByIdRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let result = match repo.find_by_id(prototype_id, &mut unit_of_work).await? {
+        Some(prototype) if prototype.metadata.is_deleted() => Err(QueryError::ResourceDeleted),
+        Some(prototype) => Ok(prototype),
+        None => Err(QueryError::EmptyResultSet),
+    };
+
+    unit_of_work.commit().await?;
+
+    result
+}
+
+#[async_trait]
+pub trait FindPrototypeByIdRepository<'db, U: UnitOfWork<'db>> {
+    async fn find_by_id(
+        &self,
+        prototype_id: &PrototypeId,
+        unit_of_work: &mut U,
+    ) -> Result<Option<Prototype>, DatabaseError>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prototypes::test_data::fs_e626;
+    use common::in_memory::InMemoryRepository;
+    use common::unit_of_work::noop::NoOpUnitOfWork;
+
+    mod find_by_id_query {
+        use super::*;
+        use chrono::Utc;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_return_a_result_when_the_prototype_is_found() {
+            let repo = InMemoryFindPrototypeByIdRepository::with(fs_e626());
+
+            let result = find_prototype_by_id(&PrototypeId::new("fs-e-626"), repo, NoOpDatabase).await;
+
+            let result = result.expect("the prototype was not found");
+            assert_eq!(fs_e626(), result);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_prototype_is_not_found() {
+            let repo = InMemoryFindPrototypeByIdRepository(InMemoryRepository::empty());
+
+            let result = find_prototype_by_id(&PrototypeId::new("fs-e-626"), repo, NoOpDatabase).await;
+
+            assert!(matches!(result, Err(QueryError::EmptyResultSet)));
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_prototype_was_deleted() {
+            let mut prototype = fs_e626();
+            prototype.metadata = prototype.metadata.deleted_at(Utc::now());
+            let repo = InMemoryFindPrototypeByIdRepository::with(prototype);
+
+            let result = find_prototype_by_id(&PrototypeId::new("fs-e-626"), repo, NoOpDatabase).await;
+
+            assert!(matches!(result, Err(QueryError::ResourceDeleted)));
+        }
+    }
+
+    struct InMemoryFindPrototypeByIdRepository(InMemoryRepository<PrototypeId, Prototype>);
+
+    impl InMemoryFindPrototypeByIdRepository {
+        fn with(prototype: Prototype) -> Self {
+            InMemoryFindPrototypeByIdRepository(InMemoryRepository::of(prototype.prototype_id.clone(), prototype))
+        }
+    }
+
+    #[async_trait]
+    impl FindPrototypeByIdRepository<'static, NoOpUnitOfWork> for InMemoryFindPrototypeByIdRepository {
+        async fn find_by_id(
+            &self,
+            prototype_id: &PrototypeId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Option<Prototype>, DatabaseError> {
+            Ok(self.0.find_by_id(prototype_id))
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use crate::catalog_items::catalog_item::CatalogItem;
+use crate::catalog_items::catalog_item_id::CatalogItemId;
+use crate::catalog_items::queries::find_rolling_stocks_by_catalog_item_ids::FindRollingStocksByCatalogItemIdsRepository;
+use crate::prototypes::prototype_id::PrototypeId;
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+use std::collections::HashMap;
+
+/// The query to find all the catalog items modelling the prototype, across brands and scales.
+///
+/// A catalog item is included when at least one of its rolling stocks references the prototype; the
+/// catalog items include all their rolling stocks.
+pub async fn find_prototype_models<'db, U, Repo, RsRepo, DB>(
+    prototype_id: &PrototypeId,
+    repo: Repo,
+    rolling_stocks_repo: RsRepo,
+    db: DB,
+) -> Result<Vec<CatalogItem>, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindPrototypeModelsRepository<'db, U>,
+    RsRepo: FindRollingStocksByCatalogItemIdsRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    if !repo.prototype_exists(prototype_id, &mut unit_of_work).await? {
+        return Err(QueryError::EmptyResultSet);
+    }
+
+    let mut catalog_items = repo.find_models(prototype_id, &mut unit_of_work).await?;
+
+    let catalog_item_ids: Vec<CatalogItemId> = catalog_items.iter().map(|it| it.catalog_item_id.clone()).collect();
+    let rolling_stocks = if catalog_item_ids.is_empty() {
+        Vec::new()
+    } else {
+        rolling_stocks_repo
+            .find_rolling_stocks_by_ids(&catalog_item_ids, &mut unit_of_work)
+            .await?
+    };
+
+    unit_of_work.commit().await?;
+
+    let mut rolling_stocks_by_id: HashMap<CatalogItemId, Vec<_>> = HashMap::new();
+    for (catalog_item_id, rolling_stock) in rolling_stocks {
+        rolling_stocks_by_id
+            .entry(catalog_item_id)
+            .or_default()
+            .push(rolling_stock);
+    }
+
+    for catalog_item in catalog_items.iter_mut() {
+        catalog_item.rolling_stocks = rolling_stocks_by_id
+            .remove(&catalog_item.catalog_item_id)
+            .unwrap_or_default();
+    }
+
+    Ok(catalog_items)
+}
+
+#[async_trait]
+pub trait FindPrototypeModelsRepository<'db, U: UnitOfWork<'db>> {
+    /// Checks if the (not deleted) prototype exists
+    async fn prototype_exists(&self, prototype_id: &PrototypeId, unit_of_work: &mut U) -> Result<bool, DatabaseError>;
+
+    /// Returns the (not deleted) catalog items modelling the prototype, sorted by brand and item number.
+    /// The rolling stocks are not loaded.
+    async fn find_models(
+        &self,
+        prototype_id: &PrototypeId,
+        unit_of_work: &mut U,
+    ) -> Result<Vec<CatalogItem>, DatabaseError>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalog_items::rolling_stock::RollingStock;
+    use crate::catalog_items::test_data::{Piko_52848, ACME_60142};
+    use common::unit_of_work::noop::NoOpUnitOfWork;
+
+    mod find_prototype_models_query {
+        use super::*;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_return_the_catalog_items_with_their_rolling_stocks() {
+            let acme = ACME_60142();
+            let piko = Piko_52848();
+            let rolling_stocks = vec![
+                (acme.catalog_item_id.clone(), acme.rolling_stocks[0].clone()),
+                (piko.catalog_item_id.clone(), piko.rolling_stocks[0].clone()),
+            ];
+            let repo = InMemoryPrototypeModelsRepository {
+                exists: true,
+                models: vec![
+                    without_rolling_stocks(acme.clone()),
+                    without_rolling_stocks(piko.clone()),
+                ],
+            };
+
+            let result = find_prototype_models(
+                &PrototypeId::new("fs-e-645"),
+                repo,
+                InMemoryRollingStocksRepository(rolling_stocks),
+                NoOpDatabase,
+            )
+            .await;
+
+            let result = result.expect("the query failed");
+            assert_eq!(2, result.len());
+            assert_eq!(acme.rolling_stocks, result[0].rolling_stocks);
+            assert_eq!(piko.rolling_stocks, result[1].rolling_stocks);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_empty_list_when_the_prototype_has_no_models() {
+            let repo = InMemoryPrototypeModelsRepository {
+                exists: true,
+                models: Vec::new(),
+            };
+
+            let result = find_prototype_models(
+                &PrototypeId::new("fs-e-645"),
+                repo,
+                InMemoryRollingStocksRepository(Vec::new()),
+                NoOpDatabase,
+            )
+            .await;
+
+            assert_eq!(Vec::<CatalogItem>::new(), result.expect("the query failed"));
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_prototype_is_not_found() {
+            let repo = InMemoryPrototypeModelsRepository {
+                exists: false,
+                models: Vec::new(),
+            };
+
+            let result = find_prototype_models(
+                &PrototypeId::new("fs-e-645"),
+                repo,
+                InMemoryRollingStocksRepository(Vec::new()),
+                NoOpDatabase,
+            )
+            .await;
+
+            assert!(matches!(result, Err(QueryError::EmptyResultSet)));
+        }
+
+        fn without_rolling_stocks(catalog_item: CatalogItem) -> CatalogItem {
+            CatalogItem {
+                rolling_stocks: Vec::new(),
+                ..catalog_item
+            }
+        }
+    }
+
+    struct InMemoryPrototypeModelsRepository {
+        exists: bool,
+        models: Vec<CatalogItem>,
+    }
+
+    #[async_trait]
+    impl FindPrototypeModelsRepository<'static, NoOpUnitOfWork> for InMemoryPrototypeModelsRepository {
+        async fn prototype_exists(
+            &self,
+            _prototype_id: &PrototypeId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, DatabaseError> {
+            Ok(self.exists)
+        }
+
+        async fn find_models(
+            &self,
+            _prototype_id: &PrototypeId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Vec<CatalogItem>, DatabaseError> {
+            Ok(self.models.clone())
+        }
+    }
+
+    struct InMemoryRollingStocksRepository(Vec<(CatalogItemId, RollingStock)>);
+
+    #[async_trait]
+    impl FindRollingStocksByCatalogItemIdsRepository<'static, NoOpUnitOfWork> for InMemoryRollingStocksRepository {
+        async fn find_rolling_stocks_by_ids(
+            &self,
+            _catalog_item_ids: &[CatalogItemId],
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Vec<(CatalogItemId, RollingStock)>, DatabaseError> {
+            Ok(self.0.clone())
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! the prototype queries
+
+pub mod find_all_prototypes;
+pub mod find_prototype_by_id;
+pub mod find_prototype_models;
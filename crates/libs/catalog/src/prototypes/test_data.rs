@@ -0,0 +1,41 @@
+use crate::prototypes::power_type::PowerType;
+use crate::prototypes::prototype::{Prototype, PrototypeRailway};
+use crate::prototypes::prototype_id::PrototypeId;
+use crate::prototypes::years_built::YearsBuilt;
+use crate::railways::railway_id::RailwayId;
+use chrono::{TimeZone, Utc};
+use common::length::Length;
+use common::metadata::Metadata;
+use rust_decimal_macros::dec;
+
+pub fn fs_e626() -> Prototype {
+    Prototype {
+        prototype_id: PrototypeId::new("fs-e-626"),
+        railway: PrototypeRailway::new(RailwayId::new("FS"), "FS"),
+        class_name: String::from("E.626"),
+        series: None,
+        builder: Some(String::from("TIBB")),
+        years_built: YearsBuilt::new(1927, Some(1939)).ok(),
+        power_type: Some(PowerType::Electric),
+        length_over_buffers: Some(Length::Meters(dec!(14.95))),
+        metadata: metadata(),
+    }
+}
+
+pub fn db_br_103() -> Prototype {
+    Prototype {
+        prototype_id: PrototypeId::new("db-br-103-103-1"),
+        railway: PrototypeRailway::new(RailwayId::new("DB"), "DB"),
+        class_name: String::from("BR 103"),
+        series: Some(String::from("103.1")),
+        builder: Some(String::from("Henschel")),
+        years_built: YearsBuilt::new(1970, Some(1974)).ok(),
+        power_type: Some(PowerType::Electric),
+        length_over_buffers: Some(Length::Meters(dec!(19.5))),
+        metadata: metadata(),
+    }
+}
+
+fn metadata() -> Metadata {
+    Metadata::created_at(Utc.with_ymd_and_hms(2026, 10, 19, 9, 30, 0).unwrap())
+}
@@ -0,0 +1,105 @@
+//! the prototype production years
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use thiserror::Error;
+use utoipa::ToSchema;
+use validator::ValidationError;
+
+/// The first year of manufacture for the oldest prototype vehicles
+const MIN_YEAR: i32 = 1800;
+
+/// It represents the years when the prototype vehicles were built
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct YearsBuilt {
+    /// the year when the first vehicle was built
+    pub from: i32,
+    /// the year when the last vehicle was built, missing when the production is still running
+    pub until: Option<i32>,
+}
+
+pub fn validate_years_built(input: &YearsBuilt) -> Result<(), ValidationError> {
+    if let Err(why) = validate_inputs(input.from, input.until) {
+        let error = ValidationError {
+            code: Cow::from("years_built"),
+            message: Some(Cow::from(why.to_string())),
+            params: HashMap::new(),
+        };
+        Err(error)
+    } else {
+        Ok(())
+    }
+}
+
+impl YearsBuilt {
+    /// Creates new production years
+    pub fn new(from: i32, until: Option<i32>) -> Result<Self, YearsBuiltError> {
+        validate_inputs(from, until)?;
+        Ok(YearsBuilt { from, until })
+    }
+
+    /// Checks whether vehicles were built in the `year`
+    pub fn includes(&self, year: i32) -> bool {
+        self.from <= year && self.until.map(|until| year <= until).unwrap_or(true)
+    }
+}
+
+fn validate_inputs(from: i32, until: Option<i32>) -> Result<(), YearsBuiltError> {
+    if from < MIN_YEAR {
+        return Err(YearsBuiltError::InvalidYear(from));
+    }
+
+    match until {
+        Some(until) if until < from => Err(YearsBuiltError::UntilBeforeFrom),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Error)]
+pub enum YearsBuiltError {
+    #[error("the year is not valid (year: {0})")]
+    InvalidYear(i32),
+    #[error("the last year of production must not happen before the first one")]
+    UntilBeforeFrom,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod years_built {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        #[rstest]
+        #[case(1927, Some(1939), Ok(YearsBuilt { from: 1927, until: Some(1939) }))]
+        #[case(1970, None, Ok(YearsBuilt { from: 1970, until: None }))]
+        #[case(1970, Some(1970), Ok(YearsBuilt { from: 1970, until: Some(1970) }))]
+        #[case(1939, Some(1927), Err(YearsBuiltError::UntilBeforeFrom))]
+        #[case(1066, None, Err(YearsBuiltError::InvalidYear(1066)))]
+        fn it_should_validate_the_inputs(
+            #[case] from: i32,
+            #[case] until: Option<i32>,
+            #[case] expected: Result<YearsBuilt, YearsBuiltError>,
+        ) {
+            assert_eq!(expected, YearsBuilt::new(from, until));
+        }
+
+        #[rstest]
+        #[case(1926, false)]
+        #[case(1927, true)]
+        #[case(1939, true)]
+        #[case(1940, false)]
+        fn it_should_check_whether_the_year_is_included(#[case] year: i32, #[case] expected: bool) {
+            let years_built = YearsBuilt::new(1927, Some(1939)).unwrap();
+            assert_eq!(expected, years_built.includes(year));
+        }
+
+        #[test]
+        fn it_should_include_every_following_year_while_the_production_is_running() {
+            let years_built = YearsBuilt::new(1970, None).unwrap();
+            assert!(years_built.includes(2026));
+        }
+    }
+}
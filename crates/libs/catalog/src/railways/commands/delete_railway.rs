@@ -0,0 +1,203 @@
+//! the railway soft deletion and restore commands
+
+use crate::events::domain_event::DomainEvent;
+use crate::events::repositories::OutboxRepository;
+use crate::railways::commands::repositories::DeleteRailwayRepository;
+use crate::railways::railway_id::RailwayId;
+use crate::railways::railway_response::{RailwayDeleted, RailwayUpdated};
+use chrono::Utc;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+
+pub type Result<R> = result::Result<R, RailwayDeletionError>;
+
+/// Soft deletes the railway, the railway is not removed from the database and it can be restored later
+pub async fn delete_railway<'db, U, Repo, O, DB>(
+    railway_id: &RailwayId,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<RailwayDeleted>
+where
+    U: UnitOfWork<'db>,
+    Repo: DeleteRailwayRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let deleted_at = Utc::now();
+    if !repo.soft_delete(railway_id, &deleted_at, &mut unit_of_work).await? {
+        return match repo.is_deleted(railway_id, &mut unit_of_work).await? {
+            None => Err(RailwayDeletionError::RailwayNotFound(railway_id.clone())),
+            Some(_) => Err(RailwayDeletionError::RailwayAlreadyDeleted(railway_id.clone())),
+        };
+    }
+
+    let deleted = RailwayDeleted {
+        railway_id: railway_id.clone(),
+        deleted_at,
+    };
+    outbox
+        .append(&DomainEvent::RailwayDeleted(deleted.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(deleted)
+}
+
+/// Restores a soft deleted railway
+pub async fn restore_railway<'db, U, Repo, O, DB>(
+    railway_id: &RailwayId,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<RailwayUpdated>
+where
+    U: UnitOfWork<'db>,
+    Repo: DeleteRailwayRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let last_modified_at = Utc::now();
+    if !repo.restore(railway_id, &last_modified_at, &mut unit_of_work).await? {
+        return match repo.is_deleted(railway_id, &mut unit_of_work).await? {
+            None => Err(RailwayDeletionError::RailwayNotFound(railway_id.clone())),
+            Some(_) => Err(RailwayDeletionError::RailwayNotDeleted(railway_id.clone())),
+        };
+    }
+
+    let restored = RailwayUpdated {
+        railway_id: railway_id.clone(),
+        last_modified_at,
+    };
+    outbox
+        .append(&DomainEvent::RailwayRestored(restored.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(restored)
+}
+
+#[derive(Debug, Error)]
+pub enum RailwayDeletionError {
+    #[error("The railway was not found (id: {0})")]
+    RailwayNotFound(RailwayId),
+
+    #[error("The railway has been already deleted (id: {0})")]
+    RailwayAlreadyDeleted(RailwayId),
+
+    #[error("The railway is not deleted (id: {0})")]
+    RailwayNotDeleted(RailwayId),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod delete_railway_command {
+        use super::*;
+        use crate::events::repositories::in_memory::InMemoryOutboxRepository;
+        use crate::railways::commands::repositories::in_memory::InMemoryRailwayDeletionRepository;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_delete_a_railway() {
+            let repo = InMemoryRailwayDeletionRepository::with(RailwayId::new("FS"), None);
+
+            let result = delete_railway(
+                &RailwayId::new("FS"),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let deleted = result.expect("result is an error");
+            assert_eq!(RailwayId::new("FS"), deleted.railway_id);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_deleting_a_railway_that_does_not_exist() {
+            let repo = InMemoryRailwayDeletionRepository::empty();
+
+            let result = delete_railway(
+                &RailwayId::new("FS"),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(RailwayDeletionError::RailwayNotFound(id)) => assert_eq!(RailwayId::new("FS"), id),
+                _ => panic!("RailwayNotFound is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_deleting_a_railway_twice() {
+            let repo = InMemoryRailwayDeletionRepository::with(RailwayId::new("FS"), Some(Utc::now()));
+
+            let result = delete_railway(
+                &RailwayId::new("FS"),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(RailwayDeletionError::RailwayAlreadyDeleted(id)) => assert_eq!(RailwayId::new("FS"), id),
+                _ => panic!("RailwayAlreadyDeleted is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_restore_a_deleted_railway() {
+            let repo = InMemoryRailwayDeletionRepository::with(RailwayId::new("FS"), Some(Utc::now()));
+
+            let result = restore_railway(
+                &RailwayId::new("FS"),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let restored = result.expect("result is an error");
+            assert_eq!(RailwayId::new("FS"), restored.railway_id);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_restoring_a_railway_which_is_not_deleted() {
+            let repo = InMemoryRailwayDeletionRepository::with(RailwayId::new("FS"), None);
+
+            let result = restore_railway(
+                &RailwayId::new("FS"),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(RailwayDeletionError::RailwayNotDeleted(id)) => assert_eq!(RailwayId::new("FS"), id),
+                _ => panic!("RailwayNotDeleted is expected (found: {:?})", result),
+            }
+        }
+    }
+}
@@ -1,4 +1,6 @@
 //! the railway commands
 
+pub mod delete_railway;
+pub mod new_railway_relationship;
 pub mod new_railways;
 pub mod repositories;
@@ -0,0 +1,285 @@
+//! the railway corporate history commands
+
+use crate::events::domain_event::DomainEvent;
+use crate::events::repositories::OutboxRepository;
+use crate::railways::commands::repositories::NewRailwayRelationshipRepository;
+use crate::railways::railway_id::RailwayId;
+use crate::railways::railway_relationship::{RailwayLineage, RailwayRelationship, RailwayRelationshipRequest};
+use crate::railways::railway_response::RailwayUpdated;
+use chrono::Utc;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use std::str::FromStr;
+use thiserror::Error;
+use validator::{Validate, ValidationErrors};
+
+pub type Result<R> = result::Result<R, RailwayRelationshipCreationError>;
+
+/// Links the railway to another railway company.
+///
+/// The relationships which would make a railway a predecessor of itself are rejected.
+pub async fn create_new_railway_relationship<'db, U, Repo, O, DB>(
+    railway_id: &RailwayId,
+    request: RailwayRelationshipRequest,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<RailwayUpdated>
+where
+    U: UnitOfWork<'db>,
+    Repo: NewRailwayRelationshipRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let relationship = to_relationship(railway_id, request)?;
+
+    let mut unit_of_work = db.begin().await?;
+
+    for railway_id in [&relationship.railway_id, &relationship.related_railway_id] {
+        if !repo.railway_exists(railway_id, &mut unit_of_work).await? {
+            return Err(RailwayRelationshipCreationError::RailwayNotFound(railway_id.clone()));
+        }
+    }
+
+    let relationships = repo.find_relationships(&mut unit_of_work).await?;
+    if relationships.iter().any(|it| is_same_link(it, &relationship)) {
+        return Err(RailwayRelationshipCreationError::RelationshipAlreadyExists(
+            relationship.railway_id,
+            relationship.related_railway_id,
+        ));
+    }
+
+    let lineage = RailwayLineage::of(relationship.predecessor(), &relationships);
+    if lineage.has_predecessor(relationship.successor()) {
+        return Err(RailwayRelationshipCreationError::CircularRelationship(
+            relationship.railway_id,
+            relationship.related_railway_id,
+        ));
+    }
+
+    let created_at = Utc::now();
+    repo.insert(&relationship, &created_at, &mut unit_of_work).await?;
+
+    let updated = RailwayUpdated {
+        railway_id: relationship.railway_id,
+        last_modified_at: created_at,
+    };
+    outbox
+        .append(
+            &DomainEvent::RailwayRelationshipAdded(updated.clone()),
+            &mut unit_of_work,
+        )
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(updated)
+}
+
+fn to_relationship(railway_id: &RailwayId, request: RailwayRelationshipRequest) -> Result<RailwayRelationship> {
+    request
+        .validate()
+        .map_err(RailwayRelationshipCreationError::InvalidRequest)?;
+
+    let related_railway_id = RailwayId::from_str(&request.related_railway)
+        .map_err(|why| RailwayRelationshipCreationError::UnexpectedError(why.into()))?;
+    if railway_id == &related_railway_id {
+        return Err(RailwayRelationshipCreationError::CircularRelationship(
+            railway_id.clone(),
+            related_railway_id,
+        ));
+    }
+
+    Ok(RailwayRelationship::new(
+        railway_id.clone(),
+        related_railway_id,
+        request.kind,
+        request.date,
+    ))
+}
+
+fn is_same_link(lhs: &RailwayRelationship, rhs: &RailwayRelationship) -> bool {
+    lhs.predecessor() == rhs.predecessor() && lhs.successor() == rhs.successor()
+}
+
+#[derive(Debug, Error)]
+pub enum RailwayRelationshipCreationError {
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+
+    #[error("The railway relationship request is not valid")]
+    InvalidRequest(ValidationErrors),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error("The railway was not found (id: {0})")]
+    RailwayNotFound(RailwayId),
+
+    #[error("The railway relationship already exists (from: {0}, to: {1})")]
+    RelationshipAlreadyExists(RailwayId, RailwayId),
+
+    #[error("The railway relationship would make a railway its own predecessor (from: {0}, to: {1})")]
+    CircularRelationship(RailwayId, RailwayId),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod new_railway_relationship_command {
+        use super::*;
+        use crate::events::repositories::in_memory::InMemoryOutboxRepository;
+        use crate::railways::commands::repositories::in_memory::InMemoryRailwayRelationshipRepository;
+        use crate::railways::railway_relationship::RelationshipKind;
+        use chrono::NaiveDate;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_create_a_new_railway_relationship() {
+            let repo = InMemoryRailwayRelationshipRepository::with_railways(&["fs", "trenitalia"]);
+            let outbox = InMemoryOutboxRepository::empty();
+
+            let result = create_new_railway_relationship(
+                &RailwayId::new("trenitalia"),
+                new_request("fs", RelationshipKind::SplitFrom),
+                repo.clone(),
+                outbox.clone(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let updated = result.expect("result is an error");
+            assert_eq!(RailwayId::new("trenitalia"), updated.railway_id);
+            assert_eq!(
+                vec![RailwayRelationship::new(
+                    RailwayId::new("trenitalia"),
+                    RailwayId::new("fs"),
+                    RelationshipKind::SplitFrom,
+                    NaiveDate::from_ymd_opt(2000, 6, 1)
+                )],
+                repo.relationships()
+            );
+            assert_eq!(1, outbox.events().len());
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_related_railway_is_not_found() {
+            let repo = InMemoryRailwayRelationshipRepository::with_railways(&["trenitalia"]);
+
+            let result = create_new_railway_relationship(
+                &RailwayId::new("trenitalia"),
+                new_request("fs", RelationshipKind::SplitFrom),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(RailwayRelationshipCreationError::RailwayNotFound(id)) => assert_eq!(RailwayId::new("fs"), id),
+                _ => panic!("RailwayNotFound is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_relationship_already_exists() {
+            let repo = InMemoryRailwayRelationshipRepository::with_railways(&["fs", "trenitalia"]).with_relationship(
+                RailwayRelationship::new(
+                    RailwayId::new("fs"),
+                    RailwayId::new("trenitalia"),
+                    RelationshipKind::RenamedTo,
+                    None,
+                ),
+            );
+
+            let result = create_new_railway_relationship(
+                &RailwayId::new("trenitalia"),
+                new_request("fs", RelationshipKind::SplitFrom),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            assert!(matches!(
+                result,
+                Err(RailwayRelationshipCreationError::RelationshipAlreadyExists(_, _))
+            ));
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_relationship_is_circular() {
+            let repo = InMemoryRailwayRelationshipRepository::with_railways(&["fs", "trenitalia"]).with_relationship(
+                RailwayRelationship::new(
+                    RailwayId::new("trenitalia"),
+                    RailwayId::new("fs"),
+                    RelationshipKind::SplitFrom,
+                    None,
+                ),
+            );
+
+            let result = create_new_railway_relationship(
+                &RailwayId::new("trenitalia"),
+                new_request("fs", RelationshipKind::MergedInto),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            assert!(matches!(
+                result,
+                Err(RailwayRelationshipCreationError::CircularRelationship(_, _))
+            ));
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_railway_is_related_to_itself() {
+            let repo = InMemoryRailwayRelationshipRepository::with_railways(&["fs"]);
+
+            let result = create_new_railway_relationship(
+                &RailwayId::new("fs"),
+                new_request("fs", RelationshipKind::RenamedTo),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            assert!(matches!(
+                result,
+                Err(RailwayRelationshipCreationError::CircularRelationship(_, _))
+            ));
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_request_is_not_valid() {
+            let repo = InMemoryRailwayRelationshipRepository::with_railways(&["fs"]);
+
+            let result = create_new_railway_relationship(
+                &RailwayId::new("fs"),
+                new_request("", RelationshipKind::RenamedTo),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            assert!(matches!(
+                result,
+                Err(RailwayRelationshipCreationError::InvalidRequest(_))
+            ));
+        }
+
+        fn new_request(related_railway: &str, kind: RelationshipKind) -> RailwayRelationshipRequest {
+            RailwayRelationshipRequest {
+                related_railway: related_railway.to_string(),
+                kind,
+                date: NaiveDate::from_ymd_opt(2000, 6, 1),
+            }
+        }
+    }
+}
@@ -1,9 +1,12 @@
 use crate::common::TrackGauge;
+use crate::events::domain_event::DomainEvent;
+use crate::events::repositories::OutboxRepository;
 use crate::railways::commands::repositories::NewRailwayRepository;
 use crate::railways::period_of_activity::{PeriodOfActivity, RailwayStatus};
 use crate::railways::railway_id::RailwayId;
 use crate::railways::railway_request::RailwayRequest;
 use crate::railways::railway_response::RailwayCreated;
+use crate::railways::vehicle_keeper_marking::VehicleKeeperMarking;
 use chrono::{NaiveDate, Utc};
 use common::contacts::{ContactInformation, MailAddress, PhoneNumber, WebsiteUrl};
 use common::localized_text::LocalizedText;
@@ -19,10 +22,16 @@ use validator::{Validate, ValidationErrors};
 
 pub type Result<R> = result::Result<R, RailwayCreationError>;
 
-pub async fn create_new_railway<'db, U, Repo, DB>(request: RailwayRequest, repo: Repo, db: DB) -> Result<RailwayCreated>
+pub async fn create_new_railway<'db, U, Repo, O, DB>(
+    request: RailwayRequest,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<RailwayCreated>
 where
     U: UnitOfWork<'db>,
     Repo: NewRailwayRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
     DB: Database<'db, U>,
 {
     let railway_id = RailwayId::new(&request.name);
@@ -36,12 +45,17 @@ where
     let command = NewRailwayCommand::try_from(request)?;
     repo.insert(&command, &mut unit_of_work).await?;
 
-    unit_of_work.commit().await?;
-
-    Ok(RailwayCreated {
+    let created = RailwayCreated {
         railway_id,
         created_at: *command.metadata.created(),
-    })
+    };
+    outbox
+        .append(&DomainEvent::RailwayCreated(created.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(created)
 }
 
 #[derive(Debug, Error)]
@@ -95,6 +109,8 @@ pub struct RailwayCommandPayload {
     pub organization_entity_type: Option<OrganizationEntityType>,
     pub description: LocalizedText,
     pub country: String,
+    pub uic_country_code: Option<i16>,
+    pub vehicle_keeper_markings: Vec<VehicleKeeperMarking>,
     pub operating_since: Option<NaiveDate>,
     pub operating_until: Option<NaiveDate>,
     pub status: Option<RailwayStatus>,
@@ -157,6 +173,8 @@ impl TryFrom<RailwayRequest> for RailwayCommandPayload {
                 .expect("country code is required for railway requests")
                 .alpha2()
                 .to_string(),
+            uic_country_code: request.uic_country_code.map(i16::from),
+            vehicle_keeper_markings: request.vehicle_keeper_markings,
             operating_since,
             operating_until,
             status: Some(status),
@@ -183,6 +201,7 @@ mod test {
 
     mod new_railway_command {
         use super::*;
+        use crate::events::repositories::in_memory::InMemoryOutboxRepository;
         use crate::railways::commands::repositories::in_memory::InMemoryRailwayRepository;
         use chrono::TimeZone;
         use common::unit_of_work::noop::NoOpDatabase;
@@ -195,7 +214,7 @@ mod test {
 
             let request = new_railway("FS");
             let db = NoOpDatabase;
-            let result = create_new_railway(request, repo, db).await;
+            let result = create_new_railway(request, repo, InMemoryOutboxRepository::empty(), db).await;
 
             let created = result.expect("result is an error");
 
@@ -208,7 +227,7 @@ mod test {
 
             let request = new_railway("FS");
             let db = NoOpDatabase;
-            let result = create_new_railway(request, repo, db).await;
+            let result = create_new_railway(request, repo, InMemoryOutboxRepository::empty(), db).await;
 
             match result {
                 Err(RailwayCreationError::RailwayAlreadyExists(id)) => assert_eq!(RailwayId::new("FS"), id),
@@ -1,6 +1,8 @@
 use crate::railways::commands::new_railways::NewRailwayCommand;
 use crate::railways::railway_id::RailwayId;
+use crate::railways::railway_relationship::RailwayRelationship;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use common::unit_of_work::UnitOfWork;
 
 /// The persistence related functionality for the railway commands
@@ -13,14 +15,62 @@ pub trait NewRailwayRepository<'db, U: UnitOfWork<'db>> {
     async fn insert(&self, new_railway: &NewRailwayCommand, unit_of_work: &mut U) -> Result<(), anyhow::Error>;
 }
 
+/// The persistence related functionality for the railway soft deletion
+#[async_trait]
+pub trait DeleteRailwayRepository<'db, U: UnitOfWork<'db>> {
+    /// Checks if the railway has been soft deleted, it returns `None` when the railway does not exist
+    async fn is_deleted(&self, railway_id: &RailwayId, unit_of_work: &mut U) -> Result<Option<bool>, anyhow::Error>;
+
+    /// Marks the railway as deleted, it returns `false` when the railway does not exist or it is
+    /// already deleted
+    async fn soft_delete(
+        &self,
+        railway_id: &RailwayId,
+        deleted_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error>;
+
+    /// Restores a soft deleted railway, it returns `false` when the railway does not exist or it is
+    /// not deleted
+    async fn restore(
+        &self,
+        railway_id: &RailwayId,
+        last_modified_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error>;
+}
+
+/// The persistence related functionality for the railway corporate history
+#[async_trait]
+pub trait NewRailwayRelationshipRepository<'db, U: UnitOfWork<'db>> {
+    /// Checks if the (not deleted) railway exists
+    async fn railway_exists(&self, railway_id: &RailwayId, unit_of_work: &mut U) -> Result<bool, anyhow::Error>;
+
+    /// Returns all the railway relationships
+    async fn find_relationships(&self, unit_of_work: &mut U) -> Result<Vec<RailwayRelationship>, anyhow::Error>;
+
+    /// Inserts a new railway relationship
+    async fn insert(
+        &self,
+        relationship: &RailwayRelationship,
+        created_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<(), anyhow::Error>;
+}
+
 #[cfg(test)]
 pub mod in_memory {
     use crate::railways::commands::new_railways::NewRailwayCommand;
-    use crate::railways::commands::repositories::NewRailwayRepository;
+    use crate::railways::commands::repositories::{
+        DeleteRailwayRepository, NewRailwayRelationshipRepository, NewRailwayRepository,
+    };
     use crate::railways::railway_id::RailwayId;
+    use crate::railways::railway_relationship::RailwayRelationship;
     use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
     use common::in_memory::InMemoryRepository;
     use common::unit_of_work::noop::NoOpUnitOfWork;
+    use std::sync::{Arc, Mutex};
 
     /// An in-memory railway repository
     pub struct InMemoryRailwayRepository(InMemoryRepository<RailwayId, NewRailwayCommand>);
@@ -58,4 +108,126 @@ pub mod in_memory {
             Ok(())
         }
     }
+
+    /// An in-memory railway repository, it keeps track of the railways deletion timestamps
+    pub struct InMemoryRailwayDeletionRepository(InMemoryRepository<RailwayId, Option<DateTime<Utc>>>);
+
+    impl InMemoryRailwayDeletionRepository {
+        /// Creates an empty in memory railways repository
+        pub fn empty() -> Self {
+            InMemoryRailwayDeletionRepository(InMemoryRepository::empty())
+        }
+
+        /// Creates a new in-memory railways repository with an initial element
+        pub fn with(railway_id: RailwayId, deleted_at: Option<DateTime<Utc>>) -> Self {
+            InMemoryRailwayDeletionRepository(InMemoryRepository::of(railway_id, deleted_at))
+        }
+    }
+
+    #[async_trait]
+    impl DeleteRailwayRepository<'static, NoOpUnitOfWork> for InMemoryRailwayDeletionRepository {
+        async fn is_deleted(
+            &self,
+            railway_id: &RailwayId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Option<bool>, anyhow::Error> {
+            Ok(self.0.find_by_id(railway_id).map(|deleted_at| deleted_at.is_some()))
+        }
+
+        async fn soft_delete(
+            &self,
+            railway_id: &RailwayId,
+            deleted_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            match self.0.find_by_id(railway_id) {
+                Some(None) => {
+                    self.0.add(railway_id.clone(), Some(*deleted_at));
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn restore(
+            &self,
+            railway_id: &RailwayId,
+            _last_modified_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            match self.0.find_by_id(railway_id) {
+                Some(Some(_)) => {
+                    self.0.add(railway_id.clone(), None);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+    }
+
+    /// An in-memory railway relationships repository, the relationships are shared between the clones
+    #[derive(Clone, Default)]
+    pub struct InMemoryRailwayRelationshipRepository {
+        railways: Vec<RailwayId>,
+        relationships: Arc<Mutex<Vec<RailwayRelationship>>>,
+    }
+
+    impl InMemoryRailwayRelationshipRepository {
+        /// Creates a new in-memory repository with the given railways
+        pub fn with_railways(railway_ids: &[&str]) -> Self {
+            InMemoryRailwayRelationshipRepository {
+                railways: railway_ids.iter().map(|it| RailwayId::new(it)).collect(),
+                ..InMemoryRailwayRelationshipRepository::default()
+            }
+        }
+
+        /// Adds an existing railway relationship
+        pub fn with_relationship(self, relationship: RailwayRelationship) -> Self {
+            self.relationships
+                .lock()
+                .expect("unable to acquire the relationships lock")
+                .push(relationship);
+            self
+        }
+
+        /// Returns the railway relationships
+        pub fn relationships(&self) -> Vec<RailwayRelationship> {
+            let relationships = self
+                .relationships
+                .lock()
+                .expect("unable to acquire the relationships lock");
+            relationships.clone()
+        }
+    }
+
+    #[async_trait]
+    impl NewRailwayRelationshipRepository<'static, NoOpUnitOfWork> for InMemoryRailwayRelationshipRepository {
+        async fn railway_exists(
+            &self,
+            railway_id: &RailwayId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            Ok(self.railways.contains(railway_id))
+        }
+
+        async fn find_relationships(
+            &self,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Vec<RailwayRelationship>, anyhow::Error> {
+            Ok(self.relationships())
+        }
+
+        async fn insert(
+            &self,
+            relationship: &RailwayRelationship,
+            _created_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<(), anyhow::Error> {
+            self.relationships
+                .lock()
+                .expect("unable to acquire the relationships lock")
+                .push(relationship.clone());
+            Ok(())
+        }
+    }
 }
@@ -7,8 +7,10 @@ pub mod railway;
 pub mod railway_gauge;
 pub mod railway_id;
 pub mod railway_length;
+pub mod railway_relationship;
 pub mod railway_request;
 pub mod railway_response;
+pub mod vehicle_keeper_marking;
 
 #[cfg(test)]
 pub mod test_data;
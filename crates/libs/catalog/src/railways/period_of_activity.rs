@@ -7,10 +7,11 @@ use std::collections::HashMap;
 use strum_macros;
 use strum_macros::{Display, EnumString};
 use thiserror::Error;
+use utoipa::ToSchema;
 use validator::ValidationError;
 
 /// It represents the period of activity for a railway company
-#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Default, ToSchema)]
 pub struct PeriodOfActivity {
     /// the date when the railway started its operation
     pub operating_since: Option<NaiveDate>,
@@ -103,7 +104,7 @@ pub enum PeriodOfActivityError {
     UntilDateForActiveRailway,
 }
 
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize, EnumString, Display, Type, Default)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize, EnumString, Display, Type, Default, ToSchema)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[strum(ascii_case_insensitive)]
 #[sqlx(type_name = "railway_status", rename_all = "SCREAMING_SNAKE_CASE")]
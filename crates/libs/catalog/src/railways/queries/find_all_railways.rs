@@ -3,7 +3,11 @@ use async_trait::async_trait;
 use common::queries::errors::{DatabaseError, QueryError};
 use common::unit_of_work::{Database, UnitOfWork};
 
-pub async fn find_all_railways<'db, U, Repo, DB>(repo: Repo, db: DB) -> Result<Vec<Railway>, QueryError>
+pub async fn find_all_railways<'db, U, Repo, DB>(
+    include_deleted: bool,
+    repo: Repo,
+    db: DB,
+) -> Result<Vec<Railway>, QueryError>
 where
     U: UnitOfWork<'db>,
     Repo: FindAllRailwaysRepository<'db, U>,
@@ -11,7 +15,7 @@ where
 {
     let mut unit_of_work = db.begin().await?;
 
-    let result = repo.find_all(&mut unit_of_work).await?;
+    let result = repo.find_all(include_deleted, &mut unit_of_work).await?;
 
     unit_of_work.commit().await?;
 
@@ -20,5 +24,6 @@ where
 
 #[async_trait]
 pub trait FindAllRailwaysRepository<'db, U: UnitOfWork<'db>> {
-    async fn find_all(&self, unit_of_work: &mut U) -> Result<Vec<Railway>, DatabaseError>;
+    /// Returns all the railways, the soft deleted ones are included only when `include_deleted` is `true`
+    async fn find_all(&self, include_deleted: bool, unit_of_work: &mut U) -> Result<Vec<Railway>, DatabaseError>;
 }
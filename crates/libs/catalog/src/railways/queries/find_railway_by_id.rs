@@ -8,6 +8,7 @@ use common::unit_of_work::{Database, UnitOfWork};
 /// The query to find a railway company with the given `railway_id`
 pub async fn find_railway_by_id<'db, U, Repo, DB>(
     railway_id: &RailwayId,
+    include_deleted: bool,
     repo: Repo,
     db: DB,
 ) -> Result<Railway, QueryError>
@@ -18,11 +19,11 @@ where
 {
     let mut unit_of_work = db.begin().await?;
 
-    let result = repo
-        .find_by_id(railway_id, &mut unit_of_work)
-        .await?
-        .map(Ok)
-        .unwrap_or_else(|| Err(QueryError::EmptyResultSet));
+    let result = match repo.find_by_id(railway_id, &mut unit_of_work).await? {
+        Some(railway) if railway.metadata.is_deleted() && !include_deleted => Err(QueryError::ResourceDeleted),
+        Some(railway) => Ok(railway),
+        None => Err(QueryError::EmptyResultSet),
+    };
 
     unit_of_work.commit().await?;
 
@@ -47,6 +48,7 @@ mod test {
 
     mod find_by_id_query {
         use super::*;
+        use chrono::Utc;
         use common::unit_of_work::noop::NoOpDatabase;
         use pretty_assertions::assert_eq;
 
@@ -54,7 +56,7 @@ mod test {
         async fn it_should_return_a_result_when_the_railway_is_found() {
             let repo = InMemoryFindRailwayByIdRepository::with(railway_with_name("FS"));
 
-            let result = find_railway_by_id(&RailwayId::new("FS"), repo, NoOpDatabase).await;
+            let result = find_railway_by_id(&RailwayId::new("FS"), false, repo, NoOpDatabase).await;
 
             assert!(result.is_ok());
             let result = result.unwrap();
@@ -66,18 +68,43 @@ mod test {
         async fn it_should_return_an_error_when_the_railway_is_not_found() {
             let repo = InMemoryFindRailwayByIdRepository::new();
 
-            let result = find_railway_by_id(&RailwayId::new("ACME"), repo, NoOpDatabase).await;
+            let result = find_railway_by_id(&RailwayId::new("ACME"), false, repo, NoOpDatabase).await;
 
             assert!(result.is_err());
             let error = result.unwrap_err();
             assert_eq!("No results were found", error.to_string());
         }
 
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_railway_was_deleted() {
+            let mut railway = railway_with_name("FS");
+            railway.metadata = railway.metadata.deleted_at(Utc::now());
+            let repo = InMemoryFindRailwayByIdRepository::with(railway);
+
+            let result = find_railway_by_id(&RailwayId::new("FS"), false, repo, NoOpDatabase).await;
+
+            assert!(result.is_err());
+            let error = result.unwrap_err();
+            assert_eq!("The resource has been deleted", error.to_string());
+        }
+
+        #[tokio::test]
+        async fn it_should_return_deleted_railways_when_they_are_included() {
+            let mut railway = railway_with_name("FS");
+            railway.metadata = railway.metadata.deleted_at(Utc::now());
+            let repo = InMemoryFindRailwayByIdRepository::with(railway);
+
+            let result = find_railway_by_id(&RailwayId::new("FS"), true, repo, NoOpDatabase).await;
+
+            let result = result.expect("the railway was not found");
+            assert!(result.metadata.is_deleted());
+        }
+
         #[tokio::test]
         async fn it_should_return_an_error_when_the_query_fails() {
             let repo = FindRailwayByIdRepositoryWithError;
 
-            let result = find_railway_by_id(&RailwayId::new("ACME"), repo, NoOpDatabase).await;
+            let result = find_railway_by_id(&RailwayId::new("ACME"), false, repo, NoOpDatabase).await;
 
             assert!(result.is_err());
             let error = result.unwrap_err();
@@ -120,6 +147,8 @@ mod test {
             organization_entity_type: None,
             description: Default::default(),
             country: CountryCode::ITA,
+            uic_country_code: None,
+            vehicle_keeper_markings: vec![],
             period_of_activity: None,
             gauge: None,
             headquarters: vec![],
@@ -0,0 +1,106 @@
+use crate::railways::railway_id::RailwayId;
+use crate::railways::railway_relationship::{RailwayLineage, RailwayRelationship};
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+
+/// The query to find the corporate history (predecessors and successors) for the railway company
+pub async fn find_railway_lineage<'db, U, Repo, DB>(
+    railway_id: &RailwayId,
+    repo: Repo,
+    db: DB,
+) -> Result<RailwayLineage, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindRailwayLineageRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    if !repo.railway_exists(railway_id, &mut unit_of_work).await? {
+        return Err(QueryError::EmptyResultSet);
+    }
+
+    let relationships = repo.find_relationships(&mut unit_of_work).await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(RailwayLineage::of(railway_id, &relationships))
+}
+
+#[async_trait]
+pub trait FindRailwayLineageRepository<'db, U: UnitOfWork<'db>> {
+    /// Checks if the (not deleted) railway exists
+    async fn railway_exists(&self, railway_id: &RailwayId, unit_of_work: &mut U) -> Result<bool, DatabaseError>;
+
+    /// Returns all the railway relationships, sorted by date
+    async fn find_relationships(&self, unit_of_work: &mut U) -> Result<Vec<RailwayRelationship>, DatabaseError>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::railways::railway_relationship::RelationshipKind;
+    use common::unit_of_work::noop::NoOpUnitOfWork;
+
+    mod find_railway_lineage_query {
+        use super::*;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_return_the_railway_lineage() {
+            let relationship = RailwayRelationship::new(
+                RailwayId::new("fs"),
+                RailwayId::new("trenitalia"),
+                RelationshipKind::RenamedTo,
+                None,
+            );
+            let repo = InMemoryRailwayLineageRepository {
+                exists: true,
+                relationships: vec![relationship.clone()],
+            };
+
+            let result = find_railway_lineage(&RailwayId::new("trenitalia"), repo, NoOpDatabase).await;
+
+            let lineage = result.expect("the query failed");
+            assert_eq!(vec![relationship], lineage.predecessors);
+            assert_eq!(Vec::<RailwayRelationship>::new(), lineage.successors);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_railway_is_not_found() {
+            let repo = InMemoryRailwayLineageRepository {
+                exists: false,
+                relationships: Vec::new(),
+            };
+
+            let result = find_railway_lineage(&RailwayId::new("fs"), repo, NoOpDatabase).await;
+
+            assert!(matches!(result, Err(QueryError::EmptyResultSet)));
+        }
+    }
+
+    struct InMemoryRailwayLineageRepository {
+        exists: bool,
+        relationships: Vec<RailwayRelationship>,
+    }
+
+    #[async_trait]
+    impl FindRailwayLineageRepository<'static, NoOpUnitOfWork> for InMemoryRailwayLineageRepository {
+        async fn railway_exists(
+            &self,
+            _railway_id: &RailwayId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, DatabaseError> {
+            Ok(self.exists)
+        }
+
+        async fn find_relationships(
+            &self,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Vec<RailwayRelationship>, DatabaseError> {
+            Ok(self.relationships.clone())
+        }
+    }
+}
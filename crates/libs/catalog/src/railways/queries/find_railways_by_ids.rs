@@ -0,0 +1,38 @@
+use crate::railways::railway::Railway;
+use crate::railways::railway_id::RailwayId;
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+
+/// The query to find the railways with the given ids, the soft deleted ones are included.
+///
+/// The railways are returned in no particular order, the unknown ids are ignored.
+pub async fn find_railways_by_ids<'db, U, Repo, DB>(
+    railway_ids: &[RailwayId],
+    repo: Repo,
+    db: DB,
+) -> Result<Vec<Railway>, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindRailwaysByIdsRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    if railway_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut unit_of_work = db.begin().await?;
+
+    let result = repo.find_by_ids(railway_ids, &mut unit_of_work).await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(result)
+}
+
+/// The find railways by ids repository
+#[async_trait]
+pub trait FindRailwaysByIdsRepository<'db, U: UnitOfWork<'db>> {
+    async fn find_by_ids(&self, railway_ids: &[RailwayId], unit_of_work: &mut U)
+        -> Result<Vec<Railway>, DatabaseError>;
+}
@@ -0,0 +1,76 @@
+use crate::railways::railway_id::RailwayId;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::UnitOfWork;
+
+#[async_trait]
+pub trait FindVehicleKeeperRepository<'db, U: UnitOfWork<'db>> {
+    /// Returns the railway registered as keeper for the UIC country code and the vehicle keeper marking,
+    /// the marking must be in use at the given date (if any)
+    async fn find_vehicle_keeper(
+        &self,
+        uic_country_code: u8,
+        vehicle_keeper_marking: &str,
+        date: Option<NaiveDate>,
+        unit_of_work: &mut U,
+    ) -> Result<Option<RailwayId>, DatabaseError>;
+}
+
+#[cfg(test)]
+pub mod in_memory {
+    use crate::railways::queries::find_railway_by_id::FindRailwayByIdRepository;
+    use crate::railways::queries::find_vehicle_keeper::FindVehicleKeeperRepository;
+    use crate::railways::railway::Railway;
+    use crate::railways::railway_id::RailwayId;
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use common::queries::errors::DatabaseError;
+    use common::unit_of_work::noop::NoOpUnitOfWork;
+
+    /// An in-memory railways repository, to find the vehicle keepers
+    pub struct InMemoryVehicleKeepersRepository(Vec<Railway>);
+
+    impl InMemoryVehicleKeepersRepository {
+        /// Creates an empty in memory railways repository
+        pub fn empty() -> Self {
+            InMemoryVehicleKeepersRepository(Vec::new())
+        }
+
+        /// Creates a new in-memory railways repository with the given railways
+        pub fn with(railways: Vec<Railway>) -> Self {
+            InMemoryVehicleKeepersRepository(railways)
+        }
+    }
+
+    #[async_trait]
+    impl FindRailwayByIdRepository<'static, NoOpUnitOfWork> for InMemoryVehicleKeepersRepository {
+        async fn find_by_id(
+            &self,
+            railway_id: &RailwayId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Option<Railway>, DatabaseError> {
+            Ok(self.0.iter().find(|it| &it.railway_id == railway_id).cloned())
+        }
+    }
+
+    #[async_trait]
+    impl FindVehicleKeeperRepository<'static, NoOpUnitOfWork> for InMemoryVehicleKeepersRepository {
+        async fn find_vehicle_keeper(
+            &self,
+            uic_country_code: u8,
+            vehicle_keeper_marking: &str,
+            date: Option<NaiveDate>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Option<RailwayId>, DatabaseError> {
+            Ok(self
+                .0
+                .iter()
+                .find(|it| {
+                    it.uic_country_code == Some(uic_country_code)
+                        && it.has_vehicle_keeper_marking_at(vehicle_keeper_marking, date)
+                })
+                .map(|it| it.railway_id.clone()))
+        }
+    }
+}
@@ -2,3 +2,6 @@
 
 pub mod find_all_railways;
 pub mod find_railway_by_id;
+pub mod find_railway_lineage;
+pub mod find_railways_by_ids;
+pub mod find_vehicle_keeper;
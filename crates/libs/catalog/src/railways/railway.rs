@@ -1,9 +1,12 @@
 //! the railway view models
 
+use crate::catalog_items::uic_vehicle_number::UicVehicleNumber;
 use crate::railways::period_of_activity::PeriodOfActivity;
 use crate::railways::railway_gauge::RailwayGauge;
 use crate::railways::railway_id::RailwayId;
 use crate::railways::railway_length::RailwayLength;
+use crate::railways::vehicle_keeper_marking::VehicleKeeperMarking;
+use chrono::NaiveDate;
 use common::contacts::ContactInformation;
 use common::localized_text::LocalizedText;
 use common::metadata::Metadata;
@@ -12,6 +15,7 @@ use common::socials::Socials;
 use isocountry::CountryCode;
 use std::fmt::Formatter;
 use std::{cmp, fmt};
+use utoipa::ToSchema;
 
 /// A railway company is a company within the rail industry.
 ///
@@ -20,7 +24,7 @@ use std::{cmp, fmt};
 /// by infrastructure operators and trains are run by different companies.
 ///
 /// Railway companies can be private or public.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Railway {
     /// the unique railway identifier (an URL encoded string)
     pub railway_id: RailwayId,
@@ -35,7 +39,14 @@ pub struct Railway {
     /// the railway description
     pub description: LocalizedText,
     /// the registration country
+    #[schema(value_type = String)]
     pub country: CountryCode,
+    /// the UIC country code (ie, `83` for Italy)
+    #[serde(default)]
+    pub uic_country_code: Option<u8>,
+    /// the registered vehicle keeper markings
+    #[serde(default)]
+    pub vehicle_keeper_markings: Vec<VehicleKeeperMarking>,
     /// the period of activity
     pub period_of_activity: Option<PeriodOfActivity>,
     /// the track gauge
@@ -80,6 +91,8 @@ impl Railway {
             organization_entity_type,
             description: description.map(LocalizedText::with_italian).unwrap_or_default(),
             country,
+            uic_country_code: None,
+            vehicle_keeper_markings: Vec::new(),
             period_of_activity,
             gauge,
             headquarters,
@@ -141,6 +154,52 @@ impl Railway {
         self.country
     }
 
+    /// The UIC country code
+    pub fn uic_country_code(&self) -> Option<u8> {
+        self.uic_country_code
+    }
+
+    /// The registered vehicle keeper markings
+    pub fn vehicle_keeper_markings(&self) -> &Vec<VehicleKeeperMarking> {
+        &self.vehicle_keeper_markings
+    }
+
+    /// Sets the UIC country code and the vehicle keeper markings for this railway company
+    pub fn with_vehicle_keepers(mut self, uic_country_code: u8, markings: Vec<VehicleKeeperMarking>) -> Self {
+        self.uic_country_code = Some(uic_country_code);
+        self.vehicle_keeper_markings = markings;
+        self
+    }
+
+    /// Checks whether this railway company is the keeper for the vehicle with the UIC vehicle number,
+    /// matching the UIC country code and the vehicle keeper marking (valid at the given date, if any)
+    pub fn is_vehicle_keeper_of(&self, uic_number: &UicVehicleNumber, date: Option<NaiveDate>) -> bool {
+        let Some(keeper) = uic_number.vehicle_keeper_marking.as_deref() else {
+            return false;
+        };
+
+        self.has_uic_country_code(&uic_number.country_code) && self.has_vehicle_keeper_marking_at(keeper, date)
+    }
+
+    /// Checks whether the vehicle keeper marking is registered for this railway company
+    pub fn has_vehicle_keeper_marking(&self, code: &str) -> bool {
+        self.has_vehicle_keeper_marking_at(code, None)
+    }
+
+    /// Checks whether the vehicle keeper marking is registered for this railway company, and it was in use
+    /// at the given date (if any)
+    pub fn has_vehicle_keeper_marking_at(&self, code: &str, date: Option<NaiveDate>) -> bool {
+        self.vehicle_keeper_markings
+            .iter()
+            .any(|it| it.code == code && date.map_or(true, |date| it.is_valid_at(date)))
+    }
+
+    /// Checks whether the UIC country code (as in the vehicle numbers) belongs to this railway company
+    pub fn has_uic_country_code(&self, country_code: &str) -> bool {
+        self.uic_country_code
+            .is_some_and(|it| country_code.parse::<u8>().is_ok_and(|code| code == it))
+    }
+
     /// The railway headquarter
     pub fn headquarters(&self) -> &Vec<String> {
         &self.headquarters
@@ -189,6 +248,7 @@ mod test {
         use chrono::Utc;
         use common::socials::SocialsBuilder;
         use pretty_assertions::{assert_eq, assert_ne};
+        use rstest::rstest;
         use rust_decimal_macros::dec;
 
         #[test]
@@ -240,6 +300,46 @@ mod test {
             assert_eq!(Some(&contact_info), railway.contact_info());
             assert_eq!(Some(&socials), railway.socials());
             assert_eq!(&metadata, railway.metadata());
+            assert_eq!(None, railway.uic_country_code());
+            assert!(railway.vehicle_keeper_markings().is_empty());
+        }
+
+        #[test]
+        fn it_should_set_the_vehicle_keepers() {
+            let markings = vec![VehicleKeeperMarking::new("FS", None, None)];
+
+            let railway = fs().with_vehicle_keepers(83, markings.clone());
+
+            assert_eq!(Some(83), railway.uic_country_code());
+            assert_eq!(&markings, railway.vehicle_keeper_markings());
+            assert!(railway.has_uic_country_code("83"));
+            assert!(!railway.has_uic_country_code("80"));
+            assert!(railway.has_vehicle_keeper_marking("FS"));
+            assert!(!railway.has_vehicle_keeper_marking("TI"));
+        }
+
+        #[rstest]
+        #[case("91 83 2444 001-8 I-FS", None, true)]
+        #[case("91 83 2444 001-8 I-FS", NaiveDate::from_ymd_opt(2010, 1, 1), false)]
+        #[case("91 83 2444 001-8 I-TI", None, false)]
+        #[case("91 80 2444 001-8 D-FS", None, false)]
+        #[case("91 83 2444 001-8", None, false)]
+        fn it_should_check_the_vehicle_keeper(
+            #[case] road_number: &str,
+            #[case] date: Option<NaiveDate>,
+            #[case] expected: bool,
+        ) {
+            let railway = fs().with_vehicle_keepers(
+                83,
+                vec![VehicleKeeperMarking::new(
+                    "FS",
+                    None,
+                    NaiveDate::from_ymd_opt(2004, 12, 31),
+                )],
+            );
+            let uic_number = road_number.parse::<UicVehicleNumber>().unwrap();
+
+            assert_eq!(expected, railway.is_vehicle_keeper_of(&uic_number, date));
         }
 
         #[test]
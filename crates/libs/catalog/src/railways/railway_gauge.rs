@@ -5,12 +5,14 @@ use common::length::{validate_length_range, Length};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use validator::{Validate, ValidationErrors};
 
-#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RailwayGauge {
     /// the distance between the rails in meters
     #[serde(with = "common::length::serde::meters")]
+    #[schema(value_type = f64)]
     pub meters: Length,
     /// the track gauge
     pub track_gauge: TrackGauge,
@@ -5,9 +5,10 @@ use sqlx::Type;
 use std::str;
 use std::str::FromStr;
 use std::{fmt, ops};
+use utoipa::ToSchema;
 
 /// It represents a unique railway id
-#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Serialize, Deserialize, Type, Hash)]
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Serialize, Deserialize, Type, Hash, ToSchema)]
 #[sqlx(transparent)]
 pub struct RailwayId(Slug);
 
@@ -7,16 +7,19 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 use thiserror::Error;
+use utoipa::ToSchema;
 use validator::{Validate, ValidationErrors};
 
 /// The overall length of tracks (in km and miles) operated by a railway company
-#[derive(Debug, Eq, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Serialize, Deserialize, ToSchema)]
 pub struct RailwayLength {
     /// the total railway network in kilometers
     #[serde(with = "common::length::serde::kilometers")]
+    #[schema(value_type = f64)]
     pub kilometers: Length,
     /// the total railway network in miles
     #[serde(with = "common::length::serde::miles")]
+    #[schema(value_type = f64)]
     pub miles: Length,
 }
 
@@ -0,0 +1,306 @@
+//! the railway corporate history
+
+use crate::railways::railway_id::RailwayId;
+use chrono::NaiveDate;
+use sqlx::Type;
+use std::collections::{HashSet, VecDeque};
+use strum_macros;
+use strum_macros::{Display, EnumString};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// The kind of change linking two railway companies
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, EnumString, Display, Type, ToSchema)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[strum(ascii_case_insensitive)]
+#[sqlx(type_name = "railway_relationship_kind", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RelationshipKind {
+    /// The railway company continued its operation under a new name.
+    #[serde(rename = "RENAMED_TO")]
+    RenamedTo,
+
+    /// The railway company was merged into another company.
+    #[serde(rename = "MERGED_INTO")]
+    MergedInto,
+
+    /// The railway company was created splitting an existing company.
+    #[serde(rename = "SPLIT_FROM")]
+    SplitFrom,
+}
+
+/// It represents a directed link between two railway companies (ie, `FS` renamed to `Trenitalia`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RailwayRelationship {
+    /// the railway
+    pub railway_id: RailwayId,
+    /// the related railway
+    pub related_railway_id: RailwayId,
+    /// the relationship kind
+    pub kind: RelationshipKind,
+    /// the date when the change took place
+    pub date: Option<NaiveDate>,
+}
+
+impl RailwayRelationship {
+    /// Creates a new railway relationship
+    pub fn new(
+        railway_id: RailwayId,
+        related_railway_id: RailwayId,
+        kind: RelationshipKind,
+        date: Option<NaiveDate>,
+    ) -> Self {
+        RailwayRelationship {
+            railway_id,
+            related_railway_id,
+            kind,
+            date,
+        }
+    }
+
+    /// The railway company which came first
+    pub fn predecessor(&self) -> &RailwayId {
+        match self.kind {
+            RelationshipKind::RenamedTo | RelationshipKind::MergedInto => &self.railway_id,
+            RelationshipKind::SplitFrom => &self.related_railway_id,
+        }
+    }
+
+    /// The railway company which came after
+    pub fn successor(&self) -> &RailwayId {
+        match self.kind {
+            RelationshipKind::RenamedTo | RelationshipKind::MergedInto => &self.related_railway_id,
+            RelationshipKind::SplitFrom => &self.railway_id,
+        }
+    }
+}
+
+/// A request to link the railway to another railway company
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RailwayRelationshipRequest {
+    /// the related railway id
+    #[validate(length(min = 2, max = 50))]
+    pub related_railway: String,
+    /// the relationship kind
+    pub kind: RelationshipKind,
+    /// the date when the change took place
+    pub date: Option<NaiveDate>,
+}
+
+/// It represents the corporate history for a railway company
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RailwayLineage {
+    /// the railway
+    pub railway_id: RailwayId,
+    /// the relationships with the railway predecessors, the closest first
+    pub predecessors: Vec<RailwayRelationship>,
+    /// the relationships with the railway successors, the closest first
+    pub successors: Vec<RailwayRelationship>,
+}
+
+impl RailwayLineage {
+    /// Computes the railway lineage, following the `relationships` backward (for the predecessors)
+    /// and forward (for the successors) from the railway
+    pub fn of(railway_id: &RailwayId, relationships: &[RailwayRelationship]) -> Self {
+        let predecessors = traverse(railway_id, relationships, |it| (it.successor(), it.predecessor()));
+        let successors = traverse(railway_id, relationships, |it| (it.predecessor(), it.successor()));
+        RailwayLineage {
+            railway_id: railway_id.clone(),
+            predecessors,
+            successors,
+        }
+    }
+
+    /// Checks whether `railway_id` is a predecessor of this railway
+    pub fn has_predecessor(&self, railway_id: &RailwayId) -> bool {
+        self.predecessors.iter().any(|it| it.predecessor() == railway_id)
+    }
+
+    /// The railway ids in this lineage, including the railway itself
+    pub fn railway_ids(&self) -> Vec<RailwayId> {
+        let mut railway_ids: Vec<RailwayId> = self
+            .predecessors
+            .iter()
+            .map(|it| it.predecessor().clone())
+            .chain(self.successors.iter().map(|it| it.successor().clone()))
+            .chain(std::iter::once(self.railway_id.clone()))
+            .collect();
+        railway_ids.sort();
+        railway_ids.dedup();
+        railway_ids
+    }
+}
+
+fn traverse<F>(railway_id: &RailwayId, relationships: &[RailwayRelationship], direction: F) -> Vec<RailwayRelationship>
+where
+    F: Fn(&RailwayRelationship) -> (&RailwayId, &RailwayId),
+{
+    let mut visited = HashSet::from([railway_id]);
+    let mut queue = VecDeque::from([railway_id]);
+    let mut result = Vec::new();
+
+    while let Some(current) = queue.pop_front() {
+        for relationship in relationships {
+            let (from, to) = direction(relationship);
+            if from == current && visited.insert(to) {
+                queue.push_back(to);
+                result.push(relationship.clone());
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod relationship_kinds {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+        use std::str::FromStr;
+
+        #[rstest]
+        #[case("RENAMED_TO", Ok(RelationshipKind::RenamedTo))]
+        #[case("MERGED_INTO", Ok(RelationshipKind::MergedInto))]
+        #[case("split_from", Ok(RelationshipKind::SplitFrom))]
+        #[case("invalid", Err(strum::ParseError::VariantNotFound))]
+        fn it_should_parse_relationship_kinds(
+            #[case] input: &str,
+            #[case] expected: Result<RelationshipKind, strum::ParseError>,
+        ) {
+            assert_eq!(expected, RelationshipKind::from_str(input));
+        }
+
+        #[rstest]
+        #[case(RelationshipKind::RenamedTo, "fs", "trenitalia")]
+        #[case(RelationshipKind::MergedInto, "fs", "trenitalia")]
+        #[case(RelationshipKind::SplitFrom, "trenitalia", "fs")]
+        fn it_should_find_the_predecessor_and_the_successor(
+            #[case] kind: RelationshipKind,
+            #[case] predecessor: &str,
+            #[case] successor: &str,
+        ) {
+            let relationship = RailwayRelationship::new(RailwayId::new("fs"), RailwayId::new("trenitalia"), kind, None);
+            assert_eq!(&RailwayId::new(predecessor), relationship.predecessor());
+            assert_eq!(&RailwayId::new(successor), relationship.successor());
+        }
+    }
+
+    mod railway_relationship_requests {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        #[rstest]
+        #[case("trenitalia", true)]
+        #[case("x", false)]
+        #[case(&"a".repeat(51), false)]
+        fn it_should_validate_railway_relationship_requests(#[case] related_railway: &str, #[case] is_valid: bool) {
+            let request = RailwayRelationshipRequest {
+                related_railway: related_railway.to_string(),
+                kind: RelationshipKind::RenamedTo,
+                date: None,
+            };
+            assert_eq!(is_valid, request.validate().is_ok());
+        }
+    }
+
+    mod railway_lineages {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_find_the_railway_predecessors_and_successors() {
+            let relationships = relationships();
+
+            let lineage = RailwayLineage::of(&RailwayId::new("fs"), &relationships);
+
+            assert_eq!(RailwayId::new("fs"), lineage.railway_id);
+            assert_eq!(vec![relationships[0].clone()], lineage.predecessors);
+            assert_eq!(
+                vec![relationships[1].clone(), relationships[2].clone()],
+                lineage.successors
+            );
+        }
+
+        #[test]
+        fn it_should_follow_the_relationships_transitively() {
+            let relationships = relationships();
+
+            let lineage = RailwayLineage::of(&RailwayId::new("trenitalia"), &relationships);
+
+            assert_eq!(
+                vec![relationships[1].clone(), relationships[0].clone()],
+                lineage.predecessors
+            );
+            assert_eq!(Vec::<RailwayRelationship>::new(), lineage.successors);
+            assert!(lineage.has_predecessor(&RailwayId::new("sfai")));
+            assert!(!lineage.has_predecessor(&RailwayId::new("rfi")));
+            assert_eq!(
+                vec![
+                    RailwayId::new("fs"),
+                    RailwayId::new("sfai"),
+                    RailwayId::new("trenitalia")
+                ],
+                lineage.railway_ids()
+            );
+        }
+
+        #[test]
+        fn it_should_return_an_empty_lineage_for_railways_without_relationships() {
+            let lineage = RailwayLineage::of(&RailwayId::new("db"), &relationships());
+
+            assert!(lineage.predecessors.is_empty());
+            assert!(lineage.successors.is_empty());
+            assert_eq!(vec![RailwayId::new("db")], lineage.railway_ids());
+        }
+
+        #[test]
+        fn it_should_stop_when_the_relationships_include_a_cycle() {
+            let relationships = vec![
+                RailwayRelationship::new(
+                    RailwayId::new("a"),
+                    RailwayId::new("b"),
+                    RelationshipKind::RenamedTo,
+                    None,
+                ),
+                RailwayRelationship::new(
+                    RailwayId::new("b"),
+                    RailwayId::new("a"),
+                    RelationshipKind::RenamedTo,
+                    None,
+                ),
+            ];
+
+            let lineage = RailwayLineage::of(&RailwayId::new("a"), &relationships);
+
+            assert_eq!(vec![relationships[1].clone()], lineage.predecessors);
+            assert_eq!(vec![relationships[0].clone()], lineage.successors);
+        }
+
+        fn relationships() -> Vec<RailwayRelationship> {
+            vec![
+                RailwayRelationship::new(
+                    RailwayId::new("sfai"),
+                    RailwayId::new("fs"),
+                    RelationshipKind::MergedInto,
+                    NaiveDate::from_ymd_opt(1905, 7, 1),
+                ),
+                RailwayRelationship::new(
+                    RailwayId::new("trenitalia"),
+                    RailwayId::new("fs"),
+                    RelationshipKind::SplitFrom,
+                    NaiveDate::from_ymd_opt(2000, 6, 1),
+                ),
+                RailwayRelationship::new(
+                    RailwayId::new("rfi"),
+                    RailwayId::new("fs"),
+                    RelationshipKind::SplitFrom,
+                    NaiveDate::from_ymd_opt(2001, 7, 1),
+                ),
+            ]
+        }
+    }
+}
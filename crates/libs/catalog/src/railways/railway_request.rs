@@ -3,15 +3,17 @@
 use crate::railways::period_of_activity::PeriodOfActivity;
 use crate::railways::railway_gauge::RailwayGauge;
 use crate::railways::railway_length::RailwayLength;
+use crate::railways::vehicle_keeper_marking::VehicleKeeperMarking;
 use common::contacts::ContactInformation;
 use common::localized_text::LocalizedText;
 use common::organizations::OrganizationEntityType;
 use common::socials::Socials;
 use isocountry::CountryCode;
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// A request to create/update railways
-#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Validate, ToSchema)]
 pub struct RailwayRequest {
     /// the railway name
     #[validate(length(min = 2, max = 50))]
@@ -29,7 +31,15 @@ pub struct RailwayRequest {
     pub description: LocalizedText,
     /// the registration country
     #[validate(required)]
+    #[schema(value_type = Option<String>)]
     pub country: Option<CountryCode>,
+    /// the UIC country code (ie, `83` for Italy)
+    #[validate(range(min = 10, max = 99))]
+    pub uic_country_code: Option<u8>,
+    /// the registered vehicle keeper markings
+    #[validate(custom(function = "crate::railways::vehicle_keeper_marking::validate_vehicle_keeper_markings"))]
+    #[serde(default)]
+    pub vehicle_keeper_markings: Vec<VehicleKeeperMarking>,
     /// the period of activity
     #[validate(custom(function = "crate::railways::period_of_activity::validate_period_of_activity"))]
     pub period_of_activity: Option<PeriodOfActivity>,
@@ -55,6 +65,7 @@ pub struct RailwayRequest {
 mod test {
     mod railway_request_validation {
         use crate::railways::railway_request::RailwayRequest;
+        use crate::railways::vehicle_keeper_marking::VehicleKeeperMarking;
         use crate::test_helpers::random_str;
         use pretty_assertions::assert_eq;
         use rstest::rstest;
@@ -131,5 +142,39 @@ mod test {
             assert_eq!(errors["abbreviation"][0].params["min"], 2);
             assert_eq!(errors["abbreviation"][0].params["max"], 10);
         }
+
+        #[rstest]
+        #[case(9)]
+        #[case(100)]
+        fn it_should_validate_the_uic_country_code(#[case] input: u8) {
+            let request = RailwayRequest {
+                name: "ABC".to_string(),
+                uic_country_code: Some(input),
+                ..RailwayRequest::default()
+            };
+
+            let result = request.validate();
+            let err = result.unwrap_err();
+            let errors = err.field_errors();
+            assert!(errors.contains_key("uic_country_code"));
+            assert_eq!(errors["uic_country_code"].len(), 1);
+            assert_eq!(errors["uic_country_code"][0].code, "range");
+        }
+
+        #[test]
+        fn it_should_validate_the_vehicle_keeper_markings() {
+            let request = RailwayRequest {
+                name: "ABC".to_string(),
+                vehicle_keeper_markings: vec![VehicleKeeperMarking::new("TOOLONG", None, None)],
+                ..RailwayRequest::default()
+            };
+
+            let result = request.validate();
+            let err = result.unwrap_err();
+            let errors = err.field_errors();
+            assert!(errors.contains_key("vehicle_keeper_markings"));
+            assert_eq!(errors["vehicle_keeper_markings"].len(), 1);
+            assert_eq!(errors["vehicle_keeper_markings"][0].code, "vehicle_keeper_marking");
+        }
     }
 }
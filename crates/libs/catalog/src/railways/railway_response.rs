@@ -4,15 +4,22 @@ use crate::railways::railway_id::RailwayId;
 use chrono::{DateTime, Utc};
 
 /// A response for new railways creation
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RailwayCreated {
     pub railway_id: RailwayId,
     pub created_at: DateTime<Utc>,
 }
 
 /// A response for railways update
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RailwayUpdated {
     pub railway_id: RailwayId,
     pub last_modified_at: DateTime<Utc>,
 }
+
+/// A response for railways soft deletion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RailwayDeleted {
+    pub railway_id: RailwayId,
+    pub deleted_at: DateTime<Utc>,
+}
@@ -0,0 +1,202 @@
+//! the railway vehicle keeper markings
+
+use crate::catalog_items::uic_vehicle_number::UicVehicleNumber;
+use crate::railways::railway::Railway;
+use crate::railways::railway_id::RailwayId;
+use chrono::NaiveDate;
+use std::borrow::Cow;
+use utoipa::ToSchema;
+use validator::ValidationError;
+
+/// It represents a registered Vehicle Keeper Marking (VKM), the code painted next to the vehicle number
+/// to identify the vehicle keeper (ie, `FS`, `DB`, `SBB` or `TI`).
+///
+/// The same railway can register different codes over the years.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct VehicleKeeperMarking {
+    /// the vehicle keeper marking code
+    pub code: String,
+    /// the date when the code was registered
+    pub valid_from: Option<NaiveDate>,
+    /// the date when the code was withdrawn
+    pub valid_until: Option<NaiveDate>,
+}
+
+impl VehicleKeeperMarking {
+    /// Creates a new vehicle keeper marking
+    pub fn new(code: &str, valid_from: Option<NaiveDate>, valid_until: Option<NaiveDate>) -> Self {
+        VehicleKeeperMarking {
+            code: code.to_uppercase(),
+            valid_from,
+            valid_until,
+        }
+    }
+
+    /// Checks whether the vehicle keeper marking was in use at the given date
+    pub fn is_valid_at(&self, date: NaiveDate) -> bool {
+        self.valid_from.map_or(true, |from| from <= date) && self.valid_until.map_or(true, |until| date <= until)
+    }
+}
+
+/// Validates the vehicle keeper markings: the codes are 1 to 5 uppercase letters (or digits) and
+/// the validity periods cannot end before their start
+pub fn validate_vehicle_keeper_markings(input: &Vec<VehicleKeeperMarking>) -> Result<(), ValidationError> {
+    for marking in input {
+        let is_valid_code = (1..=5).contains(&marking.code.len())
+            && marking
+                .code
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+        if !is_valid_code {
+            let mut error = ValidationError::new("vehicle_keeper_marking");
+            error.message = Some(Cow::from("the code must be 1 to 5 uppercase letters or digits"));
+            return Err(error);
+        }
+
+        if let (Some(from), Some(until)) = (marking.valid_from, marking.valid_until) {
+            if until < from {
+                let mut error = ValidationError::new("vehicle_keeper_marking");
+                error.message = Some(Cow::from("the validity period cannot end before its start"));
+                return Err(error);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the road number against the railway claimed by a rolling stock, it returns a warning when the
+/// road number is a UIC vehicle number belonging to a different railway.
+///
+/// The `keeper` is the railway registered for the road number UIC country code and vehicle keeper marking
+/// (if any), otherwise the road number is checked against the claimed railway country code and markings.
+/// The check is skipped for road numbers in other formats.
+pub fn check_vehicle_keeper(road_number: &str, railway: &Railway, keeper: Option<&RailwayId>) -> Option<String> {
+    let uic_number = road_number.parse::<UicVehicleNumber>().ok()?;
+    let railway_id = &railway.railway_id;
+
+    if let Some(keeper) = keeper {
+        return (keeper != railway_id).then(|| {
+            format!("the road number '{road_number}' belongs to the railway '{keeper}' (expected: {railway_id})")
+        });
+    }
+
+    match (railway.uic_country_code, &uic_number.vehicle_keeper_marking) {
+        (Some(country_code), _) if !railway.has_uic_country_code(&uic_number.country_code) => Some(format!(
+            "the road number '{road_number}' has the UIC country code {} (expected: {country_code})",
+            uic_number.country_code
+        )),
+        (_, Some(keeper))
+            if !railway.vehicle_keeper_markings.is_empty() && !railway.has_vehicle_keeper_marking(keeper) =>
+        {
+            Some(format!(
+                "the road number '{road_number}' has the vehicle keeper marking '{keeper}', not registered for the railway '{railway_id}'"
+            ))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod vehicle_keeper_markings {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        #[test]
+        fn it_should_create_new_vehicle_keeper_markings() {
+            let marking = VehicleKeeperMarking::new("ti", NaiveDate::from_ymd_opt(2000, 6, 1), None);
+            assert_eq!("TI", marking.code);
+            assert_eq!(NaiveDate::from_ymd_opt(2000, 6, 1), marking.valid_from);
+            assert_eq!(None, marking.valid_until);
+        }
+
+        #[rstest]
+        #[case(None, None, true)]
+        #[case(NaiveDate::from_ymd_opt(2000, 6, 1), None, true)]
+        #[case(NaiveDate::from_ymd_opt(2010, 6, 1), None, false)]
+        #[case(None, NaiveDate::from_ymd_opt(2004, 12, 31), true)]
+        #[case(None, NaiveDate::from_ymd_opt(2004, 12, 30), false)]
+        fn it_should_check_the_validity_period(
+            #[case] valid_from: Option<NaiveDate>,
+            #[case] valid_until: Option<NaiveDate>,
+            #[case] expected: bool,
+        ) {
+            let marking = VehicleKeeperMarking::new("FS", valid_from, valid_until);
+            let date = NaiveDate::from_ymd_opt(2004, 12, 31).unwrap();
+            assert_eq!(expected, marking.is_valid_at(date));
+        }
+
+        #[rstest]
+        #[case(VehicleKeeperMarking::new("SBB", None, None), true)]
+        #[case(VehicleKeeperMarking::new("", None, None), false)]
+        #[case(VehicleKeeperMarking::new("ABCDEF", None, None), false)]
+        #[case(VehicleKeeperMarking::new("F S", None, None), false)]
+        #[case(
+            VehicleKeeperMarking::new("FS", NaiveDate::from_ymd_opt(2000, 1, 1), NaiveDate::from_ymd_opt(2001, 1, 1)),
+            true
+        )]
+        #[case(
+            VehicleKeeperMarking::new("FS", NaiveDate::from_ymd_opt(2001, 1, 1), NaiveDate::from_ymd_opt(2000, 1, 1)),
+            false
+        )]
+        fn it_should_validate_vehicle_keeper_markings(#[case] input: VehicleKeeperMarking, #[case] expected: bool) {
+            let result = validate_vehicle_keeper_markings(&vec![input]);
+            assert_eq!(expected, result.is_ok());
+        }
+    }
+
+    mod vehicle_keeper_checks {
+        use super::*;
+        use crate::railways::test_data::{die_bahn, fs};
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        #[rstest]
+        #[case(
+            "91 80 6103 113-7 D-DB",
+            fs_railway(),
+            Some("db"),
+            Some("the road number '91 80 6103 113-7 D-DB' belongs to the railway 'db' (expected: fs)")
+        )]
+        #[case("91 80 6103 113-7 D-DB", die_bahn(), Some("db"), None)]
+        #[case(
+            "91 80 6103 113-7",
+            fs_railway(),
+            None,
+            Some("the road number '91 80 6103 113-7' has the UIC country code 80 (expected: 83)")
+        )]
+        #[case("91 83 2444 001-8 I-TI", fs_railway(), None, Some("the road number '91 83 2444 001-8 I-TI' has the vehicle keeper marking 'TI', not registered for the railway 'fs'"))]
+        #[case("91 83 2444 001-8 I-FS", fs_railway(), None, None)]
+        #[case("91 83 2444 001-8", fs_railway(), None, None)]
+        #[case("E.444 001", fs_railway(), Some("db"), None)]
+        #[case("91 80 6103 113-7", die_bahn().with_vehicle_keepers(80, Vec::new()), None, None)]
+        #[case("91 83 2444 001-8 I-FS", die_bahn(), None, None)]
+        fn it_should_check_the_vehicle_keeper(
+            #[case] road_number: &str,
+            #[case] railway: Railway,
+            #[case] keeper: Option<&str>,
+            #[case] expected: Option<&str>,
+        ) {
+            let keeper = keeper.map(RailwayId::new);
+
+            let result = check_vehicle_keeper(road_number, &railway, keeper.as_ref());
+
+            assert_eq!(expected.map(str::to_string), result);
+        }
+
+        fn fs_railway() -> Railway {
+            fs().with_vehicle_keepers(
+                83,
+                vec![VehicleKeeperMarking::new(
+                    "FS",
+                    None,
+                    NaiveDate::from_ymd_opt(2004, 12, 31),
+                )],
+            )
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! the scale soft deletion and restore commands
+
+use crate::events::domain_event::DomainEvent;
+use crate::events::repositories::OutboxRepository;
+use crate::scales::commands::repositories::DeleteScaleRepository;
+use crate::scales::scale_id::ScaleId;
+use crate::scales::scale_response::{ScaleDeleted, ScaleUpdated};
+use chrono::Utc;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+
+pub type Result<R> = result::Result<R, ScaleDeletionError>;
+
+/// Soft deletes the scale, the scale is not removed from the database and it can be restored later
+pub async fn delete_scale<'db, U, Repo, O, DB>(
+    scale_id: &ScaleId,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<ScaleDeleted>
+where
+    U: UnitOfWork<'db>,
+    Repo: DeleteScaleRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let deleted_at = Utc::now();
+    if !repo.soft_delete(scale_id, &deleted_at, &mut unit_of_work).await? {
+        return match repo.is_deleted(scale_id, &mut unit_of_work).await? {
+            None => Err(ScaleDeletionError::ScaleNotFound(scale_id.clone())),
+            Some(_) => Err(ScaleDeletionError::ScaleAlreadyDeleted(scale_id.clone())),
+        };
+    }
+
+    let deleted = ScaleDeleted {
+        scale_id: scale_id.clone(),
+        deleted_at,
+    };
+    outbox
+        .append(&DomainEvent::ScaleDeleted(deleted.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(deleted)
+}
+
+/// Restores a soft deleted scale
+pub async fn restore_scale<'db, U, Repo, O, DB>(
+    scale_id: &ScaleId,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<ScaleUpdated>
+where
+    U: UnitOfWork<'db>,
+    Repo: DeleteScaleRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let last_modified_at = Utc::now();
+    if !repo.restore(scale_id, &last_modified_at, &mut unit_of_work).await? {
+        return match repo.is_deleted(scale_id, &mut unit_of_work).await? {
+            None => Err(ScaleDeletionError::ScaleNotFound(scale_id.clone())),
+            Some(_) => Err(ScaleDeletionError::ScaleNotDeleted(scale_id.clone())),
+        };
+    }
+
+    let restored = ScaleUpdated {
+        scale_id: scale_id.clone(),
+        last_modified_at,
+    };
+    outbox
+        .append(&DomainEvent::ScaleRestored(restored.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(restored)
+}
+
+#[derive(Debug, Error)]
+pub enum ScaleDeletionError {
+    #[error("The scale was not found (id: {0})")]
+    ScaleNotFound(ScaleId),
+
+    #[error("The scale has been already deleted (id: {0})")]
+    ScaleAlreadyDeleted(ScaleId),
+
+    #[error("The scale is not deleted (id: {0})")]
+    ScaleNotDeleted(ScaleId),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod delete_scale_command {
+        use super::*;
+        use crate::events::repositories::in_memory::InMemoryOutboxRepository;
+        use crate::scales::commands::repositories::in_memory::InMemoryScaleDeletionRepository;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_delete_a_scale() {
+            let repo = InMemoryScaleDeletionRepository::with(ScaleId::new("H0"), None);
+
+            let result = delete_scale(
+                &ScaleId::new("H0"),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let deleted = result.expect("result is an error");
+            assert_eq!(ScaleId::new("H0"), deleted.scale_id);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_deleting_a_scale_that_does_not_exist() {
+            let repo = InMemoryScaleDeletionRepository::empty();
+
+            let result = delete_scale(
+                &ScaleId::new("H0"),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(ScaleDeletionError::ScaleNotFound(id)) => assert_eq!(ScaleId::new("H0"), id),
+                _ => panic!("ScaleNotFound is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_deleting_a_scale_twice() {
+            let repo = InMemoryScaleDeletionRepository::with(ScaleId::new("H0"), Some(Utc::now()));
+
+            let result = delete_scale(
+                &ScaleId::new("H0"),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(ScaleDeletionError::ScaleAlreadyDeleted(id)) => assert_eq!(ScaleId::new("H0"), id),
+                _ => panic!("ScaleAlreadyDeleted is expected (found: {:?})", result),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_restore_a_deleted_scale() {
+            let repo = InMemoryScaleDeletionRepository::with(ScaleId::new("H0"), Some(Utc::now()));
+
+            let result = restore_scale(
+                &ScaleId::new("H0"),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let restored = result.expect("result is an error");
+            assert_eq!(ScaleId::new("H0"), restored.scale_id);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_restoring_a_scale_which_is_not_deleted() {
+            let repo = InMemoryScaleDeletionRepository::with(ScaleId::new("H0"), None);
+
+            let result = restore_scale(
+                &ScaleId::new("H0"),
+                repo,
+                InMemoryOutboxRepository::empty(),
+                NoOpDatabase,
+            )
+            .await;
+
+            match result {
+                Err(ScaleDeletionError::ScaleNotDeleted(id)) => assert_eq!(ScaleId::new("H0"), id),
+                _ => panic!("ScaleNotDeleted is expected (found: {:?})", result),
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 //! the scale commands
 
+pub mod delete_scale;
 pub mod new_scales;
 pub mod repositories;
@@ -1,4 +1,6 @@
 use crate::common::TrackGauge;
+use crate::events::domain_event::DomainEvent;
+use crate::events::repositories::OutboxRepository;
 use crate::scales::commands::repositories::NewScaleRepository;
 use crate::scales::scale_id::ScaleId;
 use crate::scales::scale_request::ScaleRequest;
@@ -16,10 +18,16 @@ use validator::{Validate, ValidationErrors};
 
 pub type Result<R> = result::Result<R, ScaleCreationError>;
 
-pub async fn create_new_scale<'db, U, Repo, DB>(request: ScaleRequest, repo: Repo, db: DB) -> Result<ScaleCreated>
+pub async fn create_new_scale<'db, U, Repo, O, DB>(
+    request: ScaleRequest,
+    repo: Repo,
+    outbox: O,
+    db: DB,
+) -> Result<ScaleCreated>
 where
     U: UnitOfWork<'db>,
     Repo: NewScaleRepository<'db, U>,
+    O: OutboxRepository<'db, U>,
     DB: Database<'db, U>,
 {
     let scale_id = ScaleId::new(&request.name);
@@ -33,12 +41,17 @@ where
     let command = NewScaleCommand::try_from(request)?;
     repo.insert(&command, &mut unit_of_work).await?;
 
-    unit_of_work.commit().await?;
-
-    Ok(ScaleCreated {
+    let created = ScaleCreated {
         scale_id,
         created_at: *command.metadata.created(),
-    })
+    };
+    outbox
+        .append(&DomainEvent::ScaleCreated(created.clone()), &mut unit_of_work)
+        .await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(created)
 }
 
 #[derive(Debug, Error)]
@@ -125,6 +138,7 @@ mod test {
 
     mod new_scale_command {
         use super::*;
+        use crate::events::repositories::in_memory::InMemoryOutboxRepository;
         use crate::scales::commands::repositories::in_memory::InMemoryScaleRepository;
         use crate::scales::ratio::Ratio;
         use crate::scales::scale_gauge::Gauge;
@@ -142,7 +156,7 @@ mod test {
             let request = new_scale("H0", ratio);
 
             let db = NoOpDatabase;
-            let result = create_new_scale(request, repo, db).await;
+            let result = create_new_scale(request, repo, InMemoryOutboxRepository::empty(), db).await;
 
             let created = result.expect("result is an error");
 
@@ -157,7 +171,7 @@ mod test {
             let request = new_scale("H0", ratio);
 
             let db = NoOpDatabase;
-            let result = create_new_scale(request, repo, db).await;
+            let result = create_new_scale(request, repo, InMemoryOutboxRepository::empty(), db).await;
 
             match result {
                 Err(ScaleCreationError::ScaleAlreadyExists(id)) => assert_eq!(ScaleId::new("H0"), id),
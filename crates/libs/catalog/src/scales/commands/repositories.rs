@@ -1,6 +1,7 @@
 use crate::scales::commands::new_scales::NewScaleCommand;
 use crate::scales::scale_id::ScaleId;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use common::unit_of_work::UnitOfWork;
 
 /// The persistence related functionality for the scale commands
@@ -13,12 +14,38 @@ pub trait NewScaleRepository<'db, U: UnitOfWork<'db>> {
     async fn insert(&self, new_scale: &NewScaleCommand, unit_of_work: &mut U) -> Result<(), anyhow::Error>;
 }
 
+/// The persistence related functionality for the scale soft deletion
+#[async_trait]
+pub trait DeleteScaleRepository<'db, U: UnitOfWork<'db>> {
+    /// Checks if the scale has been soft deleted, it returns `None` when the scale does not exist
+    async fn is_deleted(&self, scale_id: &ScaleId, unit_of_work: &mut U) -> Result<Option<bool>, anyhow::Error>;
+
+    /// Marks the scale as deleted, it returns `false` when the scale does not exist or it is
+    /// already deleted
+    async fn soft_delete(
+        &self,
+        scale_id: &ScaleId,
+        deleted_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error>;
+
+    /// Restores a soft deleted scale, it returns `false` when the scale does not exist or it is
+    /// not deleted
+    async fn restore(
+        &self,
+        scale_id: &ScaleId,
+        last_modified_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error>;
+}
+
 #[cfg(test)]
 pub mod in_memory {
     use crate::scales::commands::new_scales::NewScaleCommand;
-    use crate::scales::commands::repositories::NewScaleRepository;
+    use crate::scales::commands::repositories::{DeleteScaleRepository, NewScaleRepository};
     use crate::scales::scale_id::ScaleId;
     use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
     use common::in_memory::InMemoryRepository;
     use common::unit_of_work::noop::NoOpUnitOfWork;
 
@@ -54,4 +81,60 @@ pub mod in_memory {
             Ok(())
         }
     }
+
+    /// An in-memory scale repository, it keeps track of the scales deletion timestamps
+    pub struct InMemoryScaleDeletionRepository(InMemoryRepository<ScaleId, Option<DateTime<Utc>>>);
+
+    impl InMemoryScaleDeletionRepository {
+        /// Creates an empty in memory scales repository
+        pub fn empty() -> Self {
+            InMemoryScaleDeletionRepository(InMemoryRepository::empty())
+        }
+
+        /// Creates a new in-memory scales repository with an initial element
+        pub fn with(scale_id: ScaleId, deleted_at: Option<DateTime<Utc>>) -> Self {
+            InMemoryScaleDeletionRepository(InMemoryRepository::of(scale_id, deleted_at))
+        }
+    }
+
+    #[async_trait]
+    impl DeleteScaleRepository<'static, NoOpUnitOfWork> for InMemoryScaleDeletionRepository {
+        async fn is_deleted(
+            &self,
+            scale_id: &ScaleId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Option<bool>, anyhow::Error> {
+            Ok(self.0.find_by_id(scale_id).map(|deleted_at| deleted_at.is_some()))
+        }
+
+        async fn soft_delete(
+            &self,
+            scale_id: &ScaleId,
+            deleted_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            match self.0.find_by_id(scale_id) {
+                Some(None) => {
+                    self.0.add(scale_id.clone(), Some(*deleted_at));
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn restore(
+            &self,
+            scale_id: &ScaleId,
+            _last_modified_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            match self.0.find_by_id(scale_id) {
+                Some(Some(_)) => {
+                    self.0.add(scale_id.clone(), None);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+    }
 }
@@ -5,7 +5,12 @@ use common::queries::errors::{DatabaseError, QueryError};
 use common::unit_of_work::{Database, UnitOfWork};
 
 /// The query to find a modelling scale with the given `scale_id`
-pub async fn find_scale_by_id<'db, U, Repo, DB>(scale_id: &ScaleId, repo: Repo, db: DB) -> Result<Scale, QueryError>
+pub async fn find_scale_by_id<'db, U, Repo, DB>(
+    scale_id: &ScaleId,
+    include_deleted: bool,
+    repo: Repo,
+    db: DB,
+) -> Result<Scale, QueryError>
 where
     U: UnitOfWork<'db>,
     Repo: FindScaleByIdRepository<'db, U>,
@@ -13,11 +18,11 @@ where
 {
     let mut unit_of_work = db.begin().await?;
 
-    let result = repo
-        .find_by_id(scale_id, &mut unit_of_work)
-        .await?
-        .map(Ok)
-        .unwrap_or_else(|| Err(QueryError::EmptyResultSet));
+    let result = match repo.find_by_id(scale_id, &mut unit_of_work).await? {
+        Some(scale) if scale.metadata.is_deleted() && !include_deleted => Err(QueryError::ResourceDeleted),
+        Some(scale) => Ok(scale),
+        None => Err(QueryError::EmptyResultSet),
+    };
 
     unit_of_work.commit().await?;
 
@@ -44,6 +49,7 @@ mod test {
 
     mod find_by_id_query {
         use super::*;
+        use chrono::Utc;
         use common::unit_of_work::noop::NoOpDatabase;
         use pretty_assertions::assert_eq;
 
@@ -51,7 +57,7 @@ mod test {
         async fn it_should_return_a_result_when_the_scale_is_found() {
             let repo = InMemoryFindScaleByIdRepository::with(scale_with_name("H0"));
 
-            let result = find_scale_by_id(&ScaleId::new("H0"), repo, NoOpDatabase).await;
+            let result = find_scale_by_id(&ScaleId::new("H0"), false, repo, NoOpDatabase).await;
 
             assert!(result.is_ok());
             let result = result.unwrap();
@@ -63,18 +69,43 @@ mod test {
         async fn it_should_return_an_error_when_the_scale_is_not_found() {
             let repo = InMemoryFindScaleByIdRepository::new();
 
-            let result = find_scale_by_id(&ScaleId::new("H0"), repo, NoOpDatabase).await;
+            let result = find_scale_by_id(&ScaleId::new("H0"), false, repo, NoOpDatabase).await;
 
             assert!(result.is_err());
             let error = result.unwrap_err();
             assert_eq!("No results were found", error.to_string());
         }
 
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_scale_was_deleted() {
+            let mut scale = scale_with_name("H0");
+            scale.metadata = scale.metadata.deleted_at(Utc::now());
+            let repo = InMemoryFindScaleByIdRepository::with(scale);
+
+            let result = find_scale_by_id(&ScaleId::new("H0"), false, repo, NoOpDatabase).await;
+
+            assert!(result.is_err());
+            let error = result.unwrap_err();
+            assert_eq!("The resource has been deleted", error.to_string());
+        }
+
+        #[tokio::test]
+        async fn it_should_return_deleted_scales_when_they_are_included() {
+            let mut scale = scale_with_name("H0");
+            scale.metadata = scale.metadata.deleted_at(Utc::now());
+            let repo = InMemoryFindScaleByIdRepository::with(scale);
+
+            let result = find_scale_by_id(&ScaleId::new("H0"), true, repo, NoOpDatabase).await;
+
+            let result = result.expect("the scale was not found");
+            assert!(result.metadata.is_deleted());
+        }
+
         #[tokio::test]
         async fn it_should_return_an_error_when_the_query_fails() {
             let repo = FindScaleByIdRepositoryWithError;
 
-            let result = find_scale_by_id(&ScaleId::new("H0"), repo, NoOpDatabase).await;
+            let result = find_scale_by_id(&ScaleId::new("H0"), false, repo, NoOpDatabase).await;
 
             assert!(result.is_err());
             let error = result.unwrap_err();
@@ -0,0 +1,37 @@
+use crate::scales::scale::Scale;
+use crate::scales::scale_id::ScaleId;
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+
+/// The query to find the modelling scales with the given ids, the soft deleted ones are included.
+///
+/// The modelling scales are returned in no particular order, the unknown ids are ignored.
+pub async fn find_scales_by_ids<'db, U, Repo, DB>(
+    scale_ids: &[ScaleId],
+    repo: Repo,
+    db: DB,
+) -> Result<Vec<Scale>, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindScalesByIdsRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    if scale_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut unit_of_work = db.begin().await?;
+
+    let result = repo.find_by_ids(scale_ids, &mut unit_of_work).await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(result)
+}
+
+/// The find modelling scales by ids repository
+#[async_trait]
+pub trait FindScalesByIdsRepository<'db, U: UnitOfWork<'db>> {
+    async fn find_by_ids(&self, scale_ids: &[ScaleId], unit_of_work: &mut U) -> Result<Vec<Scale>, DatabaseError>;
+}
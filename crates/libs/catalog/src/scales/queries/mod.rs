@@ -2,3 +2,4 @@
 
 pub mod find_all_scales;
 pub mod find_scale_by_id;
+pub mod find_scales_by_ids;
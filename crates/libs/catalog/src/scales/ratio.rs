@@ -9,12 +9,14 @@ use std::cmp::Ordering;
 use std::fmt::Formatter;
 use std::{cmp, convert, fmt, ops};
 use thiserror::Error;
+use utoipa::ToSchema;
 use validator::ValidationError;
 
 /// It represents the {@code Ratio} between a model railway size
 /// and the size of an actual train.
-#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Type)]
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Type, ToSchema)]
 #[sqlx(transparent)]
+#[schema(value_type = f64)]
 pub struct Ratio(Decimal);
 
 pub fn validate_ratio(input: &Ratio) -> Result<(), ValidationError> {
@@ -9,6 +9,7 @@ use common::metadata::Metadata;
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::{cmp, fmt};
+use utoipa::ToSchema;
 
 /// Rail transport modelling uses a variety of scales (ratio between the real world and the model)
 /// to ensure scale models look correct when placed next to each other.
@@ -20,7 +21,7 @@ use std::{cmp, fmt};
 /// (e.g. HO, OO, N, O, G, TT and Z.) The majority of commercial model railway equipment manufacturers
 /// base their offerings on Normen Europäischer Modellbahnen (NEM) or
 /// National Model Railroad Association (NMRA) standards in most popular scales.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Scale {
     /// the unique identifier for a scale
     pub scale_id: ScaleId,
@@ -10,16 +10,19 @@ use serde::{Deserialize, Serialize};
 use std::cmp;
 use std::cmp::Ordering;
 use thiserror::Error;
+use utoipa::ToSchema;
 use validator::{Validate, ValidationErrors};
 
 /// It represents the track gauge information for a modelling scale
-#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Gauge {
     /// the distance between the rails in millimeters
     #[serde(with = "common::length::serde::millimeters")]
+    #[schema(value_type = f64)]
     pub millimeters: Length,
     /// the distance between the rails in inches
     #[serde(with = "common::length::serde::inches")]
+    #[schema(value_type = f64)]
     pub inches: Length,
     /// the track gauge
     pub track_gauge: TrackGauge,
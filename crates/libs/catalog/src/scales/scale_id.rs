@@ -6,9 +6,10 @@ use std::fmt;
 use std::ops;
 use std::str;
 use std::str::FromStr;
+use utoipa::ToSchema;
 
 /// It represents a unique scale id
-#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Hash, Serialize, Deserialize, Type)]
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Hash, Serialize, Deserialize, Type, ToSchema)]
 #[sqlx(transparent)]
 pub struct ScaleId(Slug);
 
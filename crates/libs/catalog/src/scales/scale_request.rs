@@ -4,10 +4,11 @@ use crate::scales::ratio::Ratio;
 use crate::scales::scale_gauge::Gauge;
 use crate::scales::standard::Standard;
 use common::localized_text::LocalizedText;
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// A request to create/update rail transport modelling scales
-#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize, Validate)]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize, Validate, ToSchema)]
 pub struct ScaleRequest {
     /// the scale name
     #[validate(length(min = 1, max = 50))]
@@ -4,15 +4,22 @@ use crate::scales::scale_id::ScaleId;
 use chrono::{DateTime, Utc};
 
 /// A response for new scales creation
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ScaleCreated {
     pub scale_id: ScaleId,
     pub created_at: DateTime<Utc>,
 }
 
 /// A response for scale updates
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ScaleUpdated {
     pub scale_id: ScaleId,
     pub last_modified_at: DateTime<Utc>,
 }
+
+/// A response for scales soft deletion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScaleDeleted {
+    pub scale_id: ScaleId,
+    pub deleted_at: DateTime<Utc>,
+}
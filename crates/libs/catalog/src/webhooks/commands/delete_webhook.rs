@@ -0,0 +1,78 @@
+//! the webhook removal command
+
+use crate::webhooks::commands::repositories::DeleteWebhookRepository;
+use crate::webhooks::webhook_id::WebhookId;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+
+pub type Result<R> = result::Result<R, WebhookDeletionError>;
+
+/// Removes the webhook subscription, together with its delivery log
+pub async fn delete_webhook<'db, U, Repo, DB>(webhook_id: &WebhookId, repo: Repo, db: DB) -> Result<()>
+where
+    U: UnitOfWork<'db>,
+    Repo: DeleteWebhookRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    if !repo.exists(webhook_id, &mut unit_of_work).await? {
+        return Err(WebhookDeletionError::WebhookNotFound(*webhook_id));
+    }
+
+    repo.delete(webhook_id, &mut unit_of_work).await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum WebhookDeletionError {
+    #[error("The webhook was not found (id: {0})")]
+    WebhookNotFound(WebhookId),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod delete_webhook_command {
+        use super::*;
+        use crate::webhooks::commands::repositories::in_memory::InMemoryWebhookRepository;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_delete_a_webhook() {
+            let webhook_id = WebhookId::new();
+            let repo = InMemoryWebhookRepository::with(webhook_id, vec!["BrandCreated"]);
+
+            let result = delete_webhook(&webhook_id, repo.clone(), NoOpDatabase).await;
+
+            assert!(result.is_ok());
+            assert!(repo.find(&webhook_id).is_none());
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_deleting_a_webhook_that_does_not_exist() {
+            let webhook_id = WebhookId::new();
+            let repo = InMemoryWebhookRepository::empty();
+
+            let result = delete_webhook(&webhook_id, repo, NoOpDatabase).await;
+
+            match result {
+                Err(WebhookDeletionError::WebhookNotFound(id)) => assert_eq!(webhook_id, id),
+                _ => panic!("WebhookNotFound is expected (found: {:?})", result),
+            }
+        }
+    }
+}
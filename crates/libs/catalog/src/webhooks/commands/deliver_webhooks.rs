@@ -0,0 +1,245 @@
+//! the command to deliver the pending webhook requests
+
+use crate::events::retry_policy::RetryPolicy;
+use crate::webhooks::commands::repositories::DeliverWebhooksRepository;
+use crate::webhooks::webhook_delivery::{DeliveryAttempt, DeliveryStatus};
+use crate::webhooks::webhook_response::WebhookDeliveriesAttempted;
+use crate::webhooks::webhook_sender::WebhookSender;
+use chrono::{DateTime, Duration, Utc};
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+use uuid::Uuid;
+
+pub type Result<R> = result::Result<R, WebhookDeliveryError>;
+
+/// Sends a batch of the webhook deliveries due for their next attempt.
+///
+/// The deliveries are claimed in a short transaction, the requests are sent outside of it, and then the
+/// outcome of each attempt is recorded: a claimed delivery is not sent by other workers until the claim expires.
+/// Failed deliveries are attempted again later, according to the retry policy, until they run out of attempts.
+pub async fn deliver_pending_webhooks<'db, U, Repo, S, DB>(
+    batch_size: i64,
+    retry_policy: &RetryPolicy,
+    claim_timeout: Duration,
+    sender: &S,
+    repo: Repo,
+    db: DB,
+) -> Result<WebhookDeliveriesAttempted>
+where
+    U: UnitOfWork<'db>,
+    Repo: DeliverWebhooksRepository<'db, U>,
+    S: WebhookSender + ?Sized,
+    DB: Database<'db, U> + Clone,
+{
+    let now = Utc::now();
+    let mut unit_of_work = db.clone().begin().await?;
+    let due = repo
+        .claim_due(batch_size, &now, &(now + claim_timeout), &mut unit_of_work)
+        .await?;
+    unit_of_work.commit().await?;
+
+    let mut result = WebhookDeliveriesAttempted::default();
+    for delivery in due {
+        let outcome = sender.send(&delivery).await;
+
+        let attempted_at = Utc::now();
+        let attempts = delivery.attempts + 1;
+        let mut update = DeliveryUpdate {
+            delivery_id: delivery.delivery_id,
+            status: DeliveryStatus::Delivered,
+            attempts,
+            last_response_status: outcome.response_status(),
+            last_error: outcome.error(),
+            next_attempt_at: None,
+            delivered_at: None,
+            attempted_at,
+        };
+
+        if let DeliveryAttempt::Delivered { .. } = outcome {
+            update.delivered_at = Some(attempted_at);
+            result.delivered += 1;
+        } else if let Some(next_attempt_at) = retry_policy.next_attempt_at(attempts, attempted_at) {
+            update.status = DeliveryStatus::Pending;
+            update.next_attempt_at = Some(next_attempt_at);
+            result.retried += 1;
+        } else {
+            update.status = DeliveryStatus::Failed;
+            result.failed += 1;
+        }
+
+        let mut unit_of_work = db.clone().begin().await?;
+        repo.record_attempt(&update, &mut unit_of_work).await?;
+        unit_of_work.commit().await?;
+    }
+
+    Ok(result)
+}
+
+/// The changes to a webhook delivery after an attempt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryUpdate {
+    pub delivery_id: Uuid,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub last_response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub attempted_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Error)]
+pub enum WebhookDeliveryError {
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod deliver_pending_webhooks_command {
+        use super::*;
+        use crate::webhooks::commands::repositories::in_memory::InMemoryWebhookRepository;
+        use crate::webhooks::commands::repositories::DeliverWebhooksRepository;
+        use crate::webhooks::webhook_id::WebhookId;
+        use crate::webhooks::webhook_sender::in_memory::InMemoryWebhookSender;
+        use common::unit_of_work::noop::{NoOpDatabase, NoOpUnitOfWork};
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_deliver_the_pending_webhooks() {
+            let webhook_id = WebhookId::new();
+            let repo = InMemoryWebhookRepository::with(webhook_id, vec!["BrandCreated"]);
+            let delivery_id = repo.add_delivery(webhook_id, 0);
+            let sender = InMemoryWebhookSender::returning(DeliveryAttempt::Delivered { response_status: 204 });
+
+            let result = deliver_pending_webhooks(
+                10,
+                &retry_policy(),
+                claim_timeout(),
+                &sender,
+                repo.clone(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let attempted = result.expect("result is an error");
+            assert_eq!(1, attempted.delivered);
+
+            let sent = sender.sent();
+            assert_eq!(1, sent.len());
+            assert_eq!("https://www.example.com/hooks", sent[0].target_url);
+
+            let delivery = repo.delivery(&delivery_id).unwrap();
+            assert_eq!(DeliveryStatus::Delivered, delivery.status);
+            assert_eq!(1, delivery.attempts);
+            assert_eq!(Some(204), delivery.last_response_status);
+            assert!(delivery.delivered_at.is_some());
+            assert_eq!(None, delivery.next_attempt_at);
+        }
+
+        #[tokio::test]
+        async fn it_should_schedule_a_new_attempt_when_the_delivery_fails() {
+            let webhook_id = WebhookId::new();
+            let repo = InMemoryWebhookRepository::with(webhook_id, vec!["BrandCreated"]);
+            let delivery_id = repo.add_delivery(webhook_id, 1);
+            let sender = InMemoryWebhookSender::returning(DeliveryAttempt::Rejected { response_status: 503 });
+
+            let result = deliver_pending_webhooks(
+                10,
+                &retry_policy(),
+                claim_timeout(),
+                &sender,
+                repo.clone(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let attempted = result.expect("result is an error");
+            assert_eq!(1, attempted.retried);
+
+            let delivery = repo.delivery(&delivery_id).unwrap();
+            assert_eq!(DeliveryStatus::Pending, delivery.status);
+            assert_eq!(2, delivery.attempts);
+            assert_eq!(Some(503), delivery.last_response_status);
+            let next_attempt_at = delivery.next_attempt_at.expect("the next attempt is missing");
+            assert!(next_attempt_at > Utc::now() + Duration::seconds(15));
+        }
+
+        #[tokio::test]
+        async fn it_should_give_up_when_the_delivery_runs_out_of_attempts() {
+            let webhook_id = WebhookId::new();
+            let repo = InMemoryWebhookRepository::with(webhook_id, vec!["BrandCreated"]);
+            let delivery_id = repo.add_delivery(webhook_id, 2);
+            let sender = InMemoryWebhookSender::returning(DeliveryAttempt::Unreachable {
+                error: String::from("connection refused"),
+            });
+
+            let result = deliver_pending_webhooks(
+                10,
+                &retry_policy(),
+                claim_timeout(),
+                &sender,
+                repo.clone(),
+                NoOpDatabase,
+            )
+            .await;
+
+            let attempted = result.expect("result is an error");
+            assert_eq!(1, attempted.failed);
+
+            let delivery = repo.delivery(&delivery_id).unwrap();
+            assert_eq!(DeliveryStatus::Failed, delivery.status);
+            assert_eq!(3, delivery.attempts);
+            assert_eq!(Some(String::from("connection refused")), delivery.last_error);
+            assert_eq!(None, delivery.next_attempt_at);
+        }
+
+        #[tokio::test]
+        async fn it_should_skip_the_deliveries_claimed_by_another_worker() {
+            let webhook_id = WebhookId::new();
+            let repo = InMemoryWebhookRepository::with(webhook_id, vec!["BrandCreated"]);
+            let delivery_id = repo.add_delivery(webhook_id, 0);
+            let now = Utc::now();
+            let claimed = repo
+                .claim_due(10, &now, &(now + claim_timeout()), &mut NoOpUnitOfWork::default())
+                .await
+                .expect("unable to claim the deliveries");
+            assert_eq!(1, claimed.len());
+
+            let sender = InMemoryWebhookSender::returning(DeliveryAttempt::Delivered { response_status: 204 });
+            let result = deliver_pending_webhooks(
+                10,
+                &retry_policy(),
+                claim_timeout(),
+                &sender,
+                repo.clone(),
+                NoOpDatabase,
+            )
+            .await;
+
+            assert_eq!(
+                WebhookDeliveriesAttempted::default(),
+                result.expect("result is an error")
+            );
+            assert!(sender.sent().is_empty());
+            let delivery = repo.delivery(&delivery_id).unwrap();
+            assert_eq!(DeliveryStatus::Pending, delivery.status);
+            assert_eq!(0, delivery.attempts);
+        }
+
+        fn retry_policy() -> RetryPolicy {
+            RetryPolicy::new(3, Duration::seconds(10), Duration::minutes(5))
+        }
+
+        fn claim_timeout() -> Duration {
+            Duration::minutes(5)
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! the webhook commands
+
+pub mod delete_webhook;
+pub mod deliver_webhooks;
+pub mod new_webhook;
+pub mod repositories;
+pub mod schedule_deliveries;
@@ -0,0 +1,120 @@
+//! the new webhook subscription command
+
+use crate::webhooks::commands::repositories::NewWebhookRepository;
+use crate::webhooks::webhook_id::WebhookId;
+use crate::webhooks::webhook_request::WebhookRequest;
+use crate::webhooks::webhook_response::WebhookCreated;
+use chrono::Utc;
+use common::metadata::Metadata;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+use validator::{Validate, ValidationErrors};
+
+pub type Result<R> = result::Result<R, WebhookCreationError>;
+
+pub async fn create_new_webhook<'db, U, Repo, DB>(request: WebhookRequest, repo: Repo, db: DB) -> Result<WebhookCreated>
+where
+    U: UnitOfWork<'db>,
+    Repo: NewWebhookRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let command = NewWebhookCommand::try_from(request)?;
+
+    let mut unit_of_work = db.begin().await?;
+
+    repo.insert(&command, &mut unit_of_work).await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(WebhookCreated {
+        webhook_id: command.webhook_id,
+        created_at: *command.metadata.created(),
+    })
+}
+
+#[derive(Debug, Error)]
+pub enum WebhookCreationError {
+    #[error("The webhook request is not valid")]
+    InvalidRequest(ValidationErrors),
+
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+/// It represents the command to subscribe a new webhook
+#[derive(Debug, Clone)]
+pub struct NewWebhookCommand {
+    pub webhook_id: WebhookId,
+    pub payload: WebhookRequest,
+    pub metadata: Metadata,
+}
+
+impl TryFrom<WebhookRequest> for NewWebhookCommand {
+    type Error = WebhookCreationError;
+
+    fn try_from(mut value: WebhookRequest) -> result::Result<Self, Self::Error> {
+        value.validate().map_err(WebhookCreationError::InvalidRequest)?;
+        value.event_types.sort();
+        value.event_types.dedup();
+
+        Ok(NewWebhookCommand {
+            webhook_id: WebhookId::new(),
+            payload: value,
+            metadata: Metadata::created_at(Utc::now()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod create_new_webhook_command {
+        use super::*;
+        use crate::webhooks::commands::repositories::in_memory::InMemoryWebhookRepository;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_create_a_new_webhook() {
+            let repo = InMemoryWebhookRepository::empty();
+
+            let request = new_request(vec!["CatalogItemCreated", "BrandCreated", "BrandCreated"]);
+            let result = create_new_webhook(request, repo.clone(), NoOpDatabase).await;
+
+            let created = result.expect("result is an error");
+
+            let command = repo.find(&created.webhook_id).expect("the webhook was not stored");
+            assert_eq!(
+                vec![String::from("BrandCreated"), String::from("CatalogItemCreated")],
+                command.payload.event_types
+            );
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_request_is_not_valid() {
+            let repo = InMemoryWebhookRepository::empty();
+
+            let request = new_request(vec!["NotAnEvent"]);
+            let result = create_new_webhook(request, repo, NoOpDatabase).await;
+
+            match result {
+                Err(WebhookCreationError::InvalidRequest(_)) => {}
+                _ => panic!("InvalidRequest is expected (found: {:?})", result),
+            }
+        }
+
+        fn new_request(event_types: Vec<&str>) -> WebhookRequest {
+            WebhookRequest {
+                target_url: String::from("https://www.example.com/hooks"),
+                event_types: event_types.into_iter().map(String::from).collect(),
+                secret: String::from("a-very-long-secret"),
+            }
+        }
+    }
+}
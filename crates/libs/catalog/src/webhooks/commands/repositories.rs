@@ -0,0 +1,298 @@
+//! the webhook command repositories
+
+use crate::events::domain_event::EventEnvelope;
+use crate::webhooks::commands::deliver_webhooks::DeliveryUpdate;
+use crate::webhooks::commands::new_webhook::NewWebhookCommand;
+use crate::webhooks::webhook_delivery::PendingDelivery;
+use crate::webhooks::webhook_id::WebhookId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use common::unit_of_work::UnitOfWork;
+
+/// The persistence related functionality for the new webhooks
+#[async_trait]
+pub trait NewWebhookRepository<'db, U: UnitOfWork<'db>> {
+    /// Inserts a new webhook
+    async fn insert(&self, new_webhook: &NewWebhookCommand, unit_of_work: &mut U) -> Result<(), anyhow::Error>;
+}
+
+/// The persistence related functionality for the webhook removal
+#[async_trait]
+pub trait DeleteWebhookRepository<'db, U: UnitOfWork<'db>> {
+    /// Checks if a webhook with the input id exists
+    async fn exists(&self, webhook_id: &WebhookId, unit_of_work: &mut U) -> Result<bool, anyhow::Error>;
+
+    /// Deletes the webhook, and its deliveries
+    async fn delete(&self, webhook_id: &WebhookId, unit_of_work: &mut U) -> Result<(), anyhow::Error>;
+}
+
+/// The persistence related functionality to schedule the webhook deliveries
+#[async_trait]
+pub trait ScheduleWebhookDeliveriesRepository<'db, U: UnitOfWork<'db>> {
+    /// Returns the webhooks subscribed to the event type
+    async fn find_subscribers(&self, event_type: &str, unit_of_work: &mut U) -> Result<Vec<WebhookId>, anyhow::Error>;
+
+    /// Inserts a new pending delivery, it returns `false` when the event was already scheduled for the webhook
+    async fn insert_delivery(
+        &self,
+        webhook_id: &WebhookId,
+        envelope: &EventEnvelope,
+        payload: &str,
+        next_attempt_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error>;
+}
+
+/// The persistence related functionality to send the webhook deliveries
+#[async_trait]
+pub trait DeliverWebhooksRepository<'db, U: UnitOfWork<'db>> {
+    /// Claims the pending deliveries due before `now`, they are not due again until `claimed_until`
+    /// unless their attempt is recorded before
+    async fn claim_due(
+        &self,
+        limit: i64,
+        now: &DateTime<Utc>,
+        claimed_until: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<Vec<PendingDelivery>, anyhow::Error>;
+
+    /// Records the outcome of a delivery attempt
+    async fn record_attempt(&self, update: &DeliveryUpdate, unit_of_work: &mut U) -> Result<(), anyhow::Error>;
+}
+
+#[cfg(test)]
+pub mod in_memory {
+    use crate::events::domain_event::EventEnvelope;
+    use crate::webhooks::commands::deliver_webhooks::DeliveryUpdate;
+    use crate::webhooks::commands::new_webhook::NewWebhookCommand;
+    use crate::webhooks::commands::repositories::{
+        DeleteWebhookRepository, DeliverWebhooksRepository, NewWebhookRepository, ScheduleWebhookDeliveriesRepository,
+    };
+    use crate::webhooks::webhook_delivery::{DeliveryStatus, PendingDelivery, WebhookDelivery};
+    use crate::webhooks::webhook_id::WebhookId;
+    use crate::webhooks::webhook_request::WebhookRequest;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use common::metadata::Metadata;
+    use common::unit_of_work::noop::NoOpUnitOfWork;
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    #[derive(Default)]
+    struct State {
+        webhooks: Vec<NewWebhookCommand>,
+        deliveries: Vec<(WebhookDelivery, String)>,
+    }
+
+    /// An in-memory webhooks repository, the state is shared between the clones of the same repository
+    #[derive(Clone, Default)]
+    pub struct InMemoryWebhookRepository(Arc<Mutex<State>>);
+
+    impl InMemoryWebhookRepository {
+        /// Creates an empty in-memory webhooks repository
+        pub fn empty() -> Self {
+            InMemoryWebhookRepository::default()
+        }
+
+        /// Creates a new in-memory webhooks repository with an initial webhook
+        pub fn with(webhook_id: WebhookId, event_types: Vec<&str>) -> Self {
+            let repo = InMemoryWebhookRepository::empty();
+            repo.add(webhook_id, event_types);
+            repo
+        }
+
+        /// Adds a new webhook
+        pub fn add(&self, webhook_id: WebhookId, event_types: Vec<&str>) {
+            let command = NewWebhookCommand {
+                webhook_id,
+                payload: WebhookRequest {
+                    target_url: String::from("https://www.example.com/hooks"),
+                    event_types: event_types.into_iter().map(String::from).collect(),
+                    secret: String::from("a-very-long-secret"),
+                },
+                metadata: Metadata::created_at(Utc::now()),
+            };
+            let mut state = self.0.lock().expect("unable to acquire the lock");
+            state.webhooks.push(command);
+        }
+
+        /// Adds a new pending delivery, due now
+        pub fn add_delivery(&self, webhook_id: WebhookId, attempts: i32) -> Uuid {
+            let delivery = WebhookDelivery {
+                delivery_id: Uuid::new_v4(),
+                webhook_id,
+                event_id: Uuid::new_v4(),
+                event_type: String::from("BrandCreated"),
+                status: DeliveryStatus::Pending,
+                attempts,
+                last_response_status: None,
+                last_error: None,
+                next_attempt_at: Some(Utc::now()),
+                delivered_at: None,
+                created_at: Utc::now(),
+            };
+            let delivery_id = delivery.delivery_id;
+            let mut state = self.0.lock().expect("unable to acquire the lock");
+            state.deliveries.push((delivery, String::from("{}")));
+            delivery_id
+        }
+
+        /// Returns the webhook with the input id (if any)
+        pub fn find(&self, webhook_id: &WebhookId) -> Option<NewWebhookCommand> {
+            let state = self.0.lock().expect("unable to acquire the lock");
+            state.webhooks.iter().find(|it| it.webhook_id == *webhook_id).cloned()
+        }
+
+        /// Returns all the deliveries
+        pub fn deliveries(&self) -> Vec<WebhookDelivery> {
+            let state = self.0.lock().expect("unable to acquire the lock");
+            state.deliveries.iter().map(|(it, _)| it.clone()).collect()
+        }
+
+        /// Returns the delivery with the input id (if any)
+        pub fn delivery(&self, delivery_id: &Uuid) -> Option<WebhookDelivery> {
+            self.deliveries().into_iter().find(|it| it.delivery_id == *delivery_id)
+        }
+    }
+
+    #[async_trait]
+    impl NewWebhookRepository<'static, NoOpUnitOfWork> for InMemoryWebhookRepository {
+        async fn insert(
+            &self,
+            new_webhook: &NewWebhookCommand,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<(), anyhow::Error> {
+            let mut state = self.0.lock().expect("unable to acquire the lock");
+            state.webhooks.push(new_webhook.clone());
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl DeleteWebhookRepository<'static, NoOpUnitOfWork> for InMemoryWebhookRepository {
+        async fn exists(
+            &self,
+            webhook_id: &WebhookId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            Ok(self.find(webhook_id).is_some())
+        }
+
+        async fn delete(
+            &self,
+            webhook_id: &WebhookId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<(), anyhow::Error> {
+            let mut state = self.0.lock().expect("unable to acquire the lock");
+            state.webhooks.retain(|it| it.webhook_id != *webhook_id);
+            state.deliveries.retain(|(it, _)| it.webhook_id != *webhook_id);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ScheduleWebhookDeliveriesRepository<'static, NoOpUnitOfWork> for InMemoryWebhookRepository {
+        async fn find_subscribers(
+            &self,
+            event_type: &str,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Vec<WebhookId>, anyhow::Error> {
+            let state = self.0.lock().expect("unable to acquire the lock");
+            Ok(state
+                .webhooks
+                .iter()
+                .filter(|it| it.payload.event_types.iter().any(|t| t == event_type))
+                .map(|it| it.webhook_id)
+                .collect())
+        }
+
+        async fn insert_delivery(
+            &self,
+            webhook_id: &WebhookId,
+            envelope: &EventEnvelope,
+            payload: &str,
+            next_attempt_at: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<bool, anyhow::Error> {
+            let mut state = self.0.lock().expect("unable to acquire the lock");
+            let exists = state
+                .deliveries
+                .iter()
+                .any(|(it, _)| it.webhook_id == *webhook_id && it.event_id == envelope.event_id);
+            if exists {
+                return Ok(false);
+            }
+
+            let delivery = WebhookDelivery {
+                delivery_id: Uuid::new_v4(),
+                webhook_id: *webhook_id,
+                event_id: envelope.event_id,
+                event_type: envelope.event.event_type().to_owned(),
+                status: DeliveryStatus::Pending,
+                attempts: 0,
+                last_response_status: None,
+                last_error: None,
+                next_attempt_at: Some(*next_attempt_at),
+                delivered_at: None,
+                created_at: Utc::now(),
+            };
+            state.deliveries.push((delivery, payload.to_owned()));
+            Ok(true)
+        }
+    }
+
+    #[async_trait]
+    impl DeliverWebhooksRepository<'static, NoOpUnitOfWork> for InMemoryWebhookRepository {
+        async fn claim_due(
+            &self,
+            limit: i64,
+            now: &DateTime<Utc>,
+            claimed_until: &DateTime<Utc>,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Vec<PendingDelivery>, anyhow::Error> {
+            let mut state = self.0.lock().expect("unable to acquire the lock");
+            let State { webhooks, deliveries } = &mut *state;
+            let due = deliveries
+                .iter_mut()
+                .filter(|(it, _)| it.status == DeliveryStatus::Pending && it.next_attempt_at.is_some_and(|t| t <= *now))
+                .take(limit as usize)
+                .filter_map(|(it, payload)| {
+                    let webhook = webhooks.iter().find(|w| w.webhook_id == it.webhook_id)?;
+                    it.next_attempt_at = Some(*claimed_until);
+                    Some(PendingDelivery {
+                        delivery_id: it.delivery_id,
+                        webhook_id: it.webhook_id,
+                        event_id: it.event_id,
+                        event_type: it.event_type.clone(),
+                        payload: payload.clone(),
+                        attempts: it.attempts,
+                        target_url: webhook.payload.target_url.clone(),
+                        secret: webhook.payload.secret.clone(),
+                    })
+                })
+                .collect();
+            Ok(due)
+        }
+
+        async fn record_attempt(
+            &self,
+            update: &DeliveryUpdate,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<(), anyhow::Error> {
+            let mut state = self.0.lock().expect("unable to acquire the lock");
+            if let Some((delivery, _)) = state
+                .deliveries
+                .iter_mut()
+                .find(|(it, _)| it.delivery_id == update.delivery_id)
+            {
+                delivery.status = update.status;
+                delivery.attempts = update.attempts;
+                delivery.last_response_status = update.last_response_status;
+                delivery.last_error = update.last_error.clone();
+                delivery.next_attempt_at = update.next_attempt_at;
+                delivery.delivered_at = update.delivered_at;
+            }
+            Ok(())
+        }
+    }
+}
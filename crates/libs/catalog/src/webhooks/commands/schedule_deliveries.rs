@@ -0,0 +1,114 @@
+//! the command to schedule the webhook deliveries for a domain event
+
+use crate::events::domain_event::EventEnvelope;
+use crate::webhooks::commands::repositories::ScheduleWebhookDeliveriesRepository;
+use crate::webhooks::webhook_response::WebhookDeliveriesScheduled;
+use anyhow::Context;
+use chrono::Utc;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::{Database, UnitOfWork};
+use std::result;
+use thiserror::Error;
+
+pub type Result<R> = result::Result<R, WebhookSchedulingError>;
+
+/// Schedules a new delivery of the domain event for each webhook subscribed to its type.
+///
+/// Scheduling the same event more than once does not produce duplicated deliveries.
+pub async fn schedule_webhook_deliveries<'db, U, Repo, DB>(
+    envelope: &EventEnvelope,
+    repo: Repo,
+    db: DB,
+) -> Result<WebhookDeliveriesScheduled>
+where
+    U: UnitOfWork<'db>,
+    Repo: ScheduleWebhookDeliveriesRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let event_type = envelope.event.event_type();
+    let payload = serde_json::to_string(envelope).context("Unable to serialize the domain event.")?;
+
+    let mut unit_of_work = db.begin().await?;
+
+    let subscribers = repo.find_subscribers(event_type, &mut unit_of_work).await?;
+
+    let now = Utc::now();
+    let mut result = WebhookDeliveriesScheduled::default();
+    for webhook_id in subscribers {
+        if repo
+            .insert_delivery(&webhook_id, envelope, &payload, &now, &mut unit_of_work)
+            .await?
+        {
+            result.scheduled += 1;
+        }
+    }
+
+    unit_of_work.commit().await?;
+
+    Ok(result)
+}
+
+#[derive(Debug, Error)]
+pub enum WebhookSchedulingError {
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod schedule_webhook_deliveries_command {
+        use super::*;
+        use crate::brands::brand_id::BrandId;
+        use crate::brands::brand_response::BrandCreated;
+        use crate::events::domain_event::DomainEvent;
+        use crate::webhooks::commands::repositories::in_memory::InMemoryWebhookRepository;
+        use crate::webhooks::webhook_delivery::DeliveryStatus;
+        use crate::webhooks::webhook_id::WebhookId;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_schedule_deliveries_for_the_subscribed_webhooks() {
+            let subscribed = WebhookId::new();
+            let repo = InMemoryWebhookRepository::with(subscribed, vec!["BrandCreated"]);
+            repo.add(WebhookId::new(), vec!["BrandDeleted"]);
+
+            let envelope = brand_created();
+            let result = schedule_webhook_deliveries(&envelope, repo.clone(), NoOpDatabase).await;
+
+            let scheduled = result.expect("result is an error");
+            assert_eq!(1, scheduled.scheduled);
+
+            let deliveries = repo.deliveries();
+            assert_eq!(1, deliveries.len());
+            assert_eq!(subscribed, deliveries[0].webhook_id);
+            assert_eq!(envelope.event_id, deliveries[0].event_id);
+            assert_eq!(DeliveryStatus::Pending, deliveries[0].status);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_schedule_the_same_event_twice() {
+            let repo = InMemoryWebhookRepository::with(WebhookId::new(), vec!["BrandCreated"]);
+
+            let envelope = brand_created();
+            let _ = schedule_webhook_deliveries(&envelope, repo.clone(), NoOpDatabase).await;
+            let result = schedule_webhook_deliveries(&envelope, repo.clone(), NoOpDatabase).await;
+
+            let scheduled = result.expect("result is an error");
+            assert_eq!(0, scheduled.scheduled);
+            assert_eq!(1, repo.deliveries().len());
+        }
+
+        fn brand_created() -> EventEnvelope {
+            EventEnvelope::new(DomainEvent::BrandCreated(BrandCreated {
+                brand_id: BrandId::new("ACME"),
+                created_at: Utc::now(),
+            }))
+        }
+    }
+}
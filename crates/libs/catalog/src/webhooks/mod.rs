@@ -0,0 +1,11 @@
+//! the module includes everything related to the webhook subscriptions for the catalog domain events
+
+pub mod commands;
+pub mod queries;
+pub mod target_url;
+pub mod webhook;
+pub mod webhook_delivery;
+pub mod webhook_id;
+pub mod webhook_request;
+pub mod webhook_response;
+pub mod webhook_sender;
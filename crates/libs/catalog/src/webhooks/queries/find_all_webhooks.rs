@@ -0,0 +1,25 @@
+use crate::webhooks::webhook::Webhook;
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+
+pub async fn find_all_webhooks<'db, U, Repo, DB>(repo: Repo, db: DB) -> Result<Vec<Webhook>, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindAllWebhooksRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let result = repo.find_all(&mut unit_of_work).await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(result)
+}
+
+#[async_trait]
+pub trait FindAllWebhooksRepository<'db, U: UnitOfWork<'db>> {
+    /// Returns all the webhooks
+    async fn find_all(&self, unit_of_work: &mut U) -> Result<Vec<Webhook>, DatabaseError>;
+}
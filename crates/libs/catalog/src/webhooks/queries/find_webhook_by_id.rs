@@ -0,0 +1,31 @@
+use crate::webhooks::webhook::Webhook;
+use crate::webhooks::webhook_id::WebhookId;
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+
+/// The query to find a webhook by its `webhook_id`
+pub async fn find_webhook_by_id<'db, U, Repo, DB>(
+    webhook_id: &WebhookId,
+    repo: Repo,
+    db: DB,
+) -> Result<Webhook, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindWebhookByIdRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    let result = repo.find_by_id(webhook_id, &mut unit_of_work).await?;
+
+    unit_of_work.commit().await?;
+
+    result.ok_or(QueryError::EmptyResultSet)
+}
+
+/// The find webhook by id repository
+#[async_trait]
+pub trait FindWebhookByIdRepository<'db, U: UnitOfWork<'db>> {
+    async fn find_by_id(&self, webhook_id: &WebhookId, unit_of_work: &mut U) -> Result<Option<Webhook>, DatabaseError>;
+}
@@ -0,0 +1,128 @@
+use crate::webhooks::queries::find_webhook_by_id::FindWebhookByIdRepository;
+use crate::webhooks::webhook_delivery::WebhookDelivery;
+use crate::webhooks::webhook_id::WebhookId;
+use async_trait::async_trait;
+use common::queries::errors::{DatabaseError, QueryError};
+use common::unit_of_work::{Database, UnitOfWork};
+
+/// The query to find the delivery log for a webhook, the most recent deliveries first
+pub async fn find_webhook_deliveries<'db, U, Repo, DB>(
+    webhook_id: &WebhookId,
+    repo: Repo,
+    db: DB,
+) -> Result<Vec<WebhookDelivery>, QueryError>
+where
+    U: UnitOfWork<'db>,
+    Repo: FindWebhookByIdRepository<'db, U> + FindWebhookDeliveriesRepository<'db, U>,
+    DB: Database<'db, U>,
+{
+    let mut unit_of_work = db.begin().await?;
+
+    if repo.find_by_id(webhook_id, &mut unit_of_work).await?.is_none() {
+        return Err(QueryError::EmptyResultSet);
+    }
+
+    let result = repo.find_deliveries(webhook_id, &mut unit_of_work).await?;
+
+    unit_of_work.commit().await?;
+
+    Ok(result)
+}
+
+/// The find webhook deliveries repository
+#[async_trait]
+pub trait FindWebhookDeliveriesRepository<'db, U: UnitOfWork<'db>> {
+    async fn find_deliveries(
+        &self,
+        webhook_id: &WebhookId,
+        unit_of_work: &mut U,
+    ) -> Result<Vec<WebhookDelivery>, DatabaseError>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::webhooks::webhook::Webhook;
+    use async_trait::async_trait;
+    use common::unit_of_work::noop::NoOpUnitOfWork;
+
+    mod find_webhook_deliveries_query {
+        use super::*;
+        use crate::webhooks::webhook_delivery::DeliveryStatus;
+        use chrono::Utc;
+        use common::metadata::Metadata;
+        use common::unit_of_work::noop::NoOpDatabase;
+        use pretty_assertions::assert_eq;
+        use uuid::Uuid;
+
+        #[tokio::test]
+        async fn it_should_return_the_webhook_deliveries() {
+            let webhook_id = WebhookId::new();
+            let delivery = WebhookDelivery {
+                delivery_id: Uuid::new_v4(),
+                webhook_id,
+                event_id: Uuid::new_v4(),
+                event_type: String::from("BrandCreated"),
+                status: DeliveryStatus::Delivered,
+                attempts: 1,
+                last_response_status: Some(200),
+                last_error: None,
+                next_attempt_at: None,
+                delivered_at: Some(Utc::now()),
+                created_at: Utc::now(),
+            };
+            let webhook = Webhook {
+                webhook_id,
+                target_url: String::from("https://www.example.com/hooks"),
+                event_types: vec![String::from("BrandCreated")],
+                metadata: Metadata::created_at(Utc::now()),
+            };
+            let repo = InMemoryDeliveriesRepository(Some(webhook), vec![delivery.clone()]);
+
+            let result = find_webhook_deliveries(&webhook_id, repo, NoOpDatabase).await;
+
+            let deliveries = result.expect("result is an error");
+            assert_eq!(vec![delivery], deliveries);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_an_error_when_the_webhook_is_not_found() {
+            let repo = InMemoryDeliveriesRepository(None, Vec::new());
+
+            let result = find_webhook_deliveries(&WebhookId::new(), repo, NoOpDatabase).await;
+
+            assert!(result.is_err());
+            let error = result.unwrap_err();
+            assert_eq!("No results were found", error.to_string());
+        }
+    }
+
+    struct InMemoryDeliveriesRepository(Option<Webhook>, Vec<WebhookDelivery>);
+
+    #[async_trait]
+    impl FindWebhookByIdRepository<'static, NoOpUnitOfWork> for InMemoryDeliveriesRepository {
+        async fn find_by_id(
+            &self,
+            webhook_id: &WebhookId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Option<Webhook>, DatabaseError> {
+            Ok(self.0.clone().filter(|it| it.webhook_id == *webhook_id))
+        }
+    }
+
+    #[async_trait]
+    impl FindWebhookDeliveriesRepository<'static, NoOpUnitOfWork> for InMemoryDeliveriesRepository {
+        async fn find_deliveries(
+            &self,
+            webhook_id: &WebhookId,
+            _unit_of_work: &mut NoOpUnitOfWork,
+        ) -> Result<Vec<WebhookDelivery>, DatabaseError> {
+            Ok(self
+                .1
+                .iter()
+                .filter(|it| it.webhook_id == *webhook_id)
+                .cloned()
+                .collect())
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! the webhook queries
+
+pub mod find_all_webhooks;
+pub mod find_webhook_by_id;
+pub mod find_webhook_deliveries;
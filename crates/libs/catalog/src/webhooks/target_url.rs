@@ -0,0 +1,137 @@
+//! the checks for the webhook target urls, the webhook requests must not reach the internal network
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use url::{Host, Url};
+use validator::ValidationError;
+
+/// Checks the webhook target url: it must use `https`, and its host must not be a loopback,
+/// link-local or private address (or the `localhost` name)
+pub fn validate_target_url(target_url: &str) -> Result<(), ValidationError> {
+    // the malformed urls are reported by the url validation
+    let Ok(url) = Url::parse(target_url) else {
+        return Ok(());
+    };
+
+    if url.scheme() != "https" {
+        return Err(ValidationError::new("https_required"));
+    }
+
+    let is_public = match url.host() {
+        Some(Host::Domain(domain)) => !is_local_domain(domain),
+        Some(Host::Ipv4(ip)) => is_public_address(&IpAddr::V4(ip)),
+        Some(Host::Ipv6(ip)) => is_public_address(&IpAddr::V6(ip)),
+        None => false,
+    };
+
+    if is_public {
+        Ok(())
+    } else {
+        Err(ValidationError::new("private_address"))
+    }
+}
+
+/// Checks whether the address is reachable on the public internet, the loopback, link-local,
+/// private and reserved addresses are not
+pub fn is_public_address(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ipv4(&ip),
+            None => is_public_ipv6(ip),
+        },
+    }
+}
+
+fn is_local_domain(domain: &str) -> bool {
+    let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+    domain == "localhost" || domain.ends_with(".localhost")
+}
+
+fn is_public_ipv4(ip: &Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    let is_shared = a == 100 && (64..128).contains(&b);
+    let is_protocol_assignment = a == 192 && b == 0 && c == 0;
+    let is_benchmarking = a == 198 && (18..20).contains(&b);
+    let is_reserved = a == 0 || a >= 240;
+
+    !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        || is_shared
+        || is_protocol_assignment
+        || is_benchmarking
+        || is_reserved)
+}
+
+fn is_public_ipv6(ip: &Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    let is_unique_local = (first & 0xfe00) == 0xfc00;
+    let is_link_local = (first & 0xffc0) == 0xfe80;
+    let is_documentation = first == 0x2001 && ip.segments()[1] == 0x0db8;
+
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || is_unique_local
+        || is_link_local
+        || is_documentation)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod target_urls {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        #[rstest]
+        #[case("https://www.example.com/hooks", None)]
+        #[case("https://93.184.216.34/hooks", None)]
+        #[case("https://[2606:2800:220:1:248:1893:25c8:1946]/hooks", None)]
+        #[case("http://www.example.com/hooks", Some("https_required"))]
+        #[case("ftp://www.example.com/hooks", Some("https_required"))]
+        #[case("https://localhost/hooks", Some("private_address"))]
+        #[case("https://LOCALHOST./hooks", Some("private_address"))]
+        #[case("https://api.localhost/hooks", Some("private_address"))]
+        #[case("https://127.0.0.1/hooks", Some("private_address"))]
+        #[case("https://10.0.0.1/hooks", Some("private_address"))]
+        #[case("https://172.16.0.1/hooks", Some("private_address"))]
+        #[case("https://192.168.1.1/hooks", Some("private_address"))]
+        #[case("https://169.254.169.254/latest/meta-data", Some("private_address"))]
+        #[case("https://0.0.0.0/hooks", Some("private_address"))]
+        #[case("https://[::1]/hooks", Some("private_address"))]
+        #[case("https://[fd00::1]/hooks", Some("private_address"))]
+        #[case("https://[fe80::1]/hooks", Some("private_address"))]
+        #[case("https://[::ffff:127.0.0.1]/hooks", Some("private_address"))]
+        fn it_should_validate_the_target_urls(#[case] target_url: &str, #[case] expected: Option<&str>) {
+            let result = validate_target_url(target_url);
+            assert_eq!(expected, result.err().map(|it| it.code.to_string()).as_deref());
+        }
+
+        #[rstest]
+        #[case("8.8.8.8", true)]
+        #[case("2001:4860:4860::8888", true)]
+        #[case("127.0.0.53", false)]
+        #[case("10.1.2.3", false)]
+        #[case("100.64.0.1", false)]
+        #[case("169.254.0.1", false)]
+        #[case("192.0.0.8", false)]
+        #[case("198.18.0.1", false)]
+        #[case("224.0.0.1", false)]
+        #[case("255.255.255.255", false)]
+        #[case("::", false)]
+        #[case("fc00::1", false)]
+        #[case("2001:db8::1", false)]
+        #[case("::ffff:10.0.0.1", false)]
+        fn it_should_check_for_public_addresses(#[case] ip: &str, #[case] expected: bool) {
+            let ip: IpAddr = ip.parse().unwrap();
+            assert_eq!(expected, is_public_address(&ip));
+        }
+    }
+}
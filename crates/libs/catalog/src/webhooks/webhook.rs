@@ -0,0 +1,51 @@
+//! the webhook view models
+
+use crate::webhooks::webhook_id::WebhookId;
+use common::metadata::Metadata;
+use utoipa::ToSchema;
+
+/// It represents a subscription to the catalog domain events.
+///
+/// The events are delivered as signed JSON `POST` requests to the target url, the shared secret
+/// used to sign the requests is never returned.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Webhook {
+    /// the webhook unique identifier
+    pub webhook_id: WebhookId,
+    /// the url which receives the events
+    pub target_url: String,
+    /// the domain event types delivered to this webhook
+    pub event_types: Vec<String>,
+    /// the webhook metadata
+    pub metadata: Metadata,
+}
+
+impl Webhook {
+    /// Checks whether the events with the given type are delivered to this webhook
+    pub fn is_subscribed_to(&self, event_type: &str) -> bool {
+        self.event_types.iter().any(|it| it == event_type)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod webhooks {
+        use super::*;
+        use chrono::Utc;
+
+        #[test]
+        fn it_should_check_the_webhook_event_types() {
+            let webhook = Webhook {
+                webhook_id: WebhookId::new(),
+                target_url: String::from("https://www.example.com/hooks"),
+                event_types: vec![String::from("BrandCreated"), String::from("CatalogItemCreated")],
+                metadata: Metadata::created_at(Utc::now()),
+            };
+
+            assert!(webhook.is_subscribed_to("BrandCreated"));
+            assert!(!webhook.is_subscribed_to("BrandDeleted"));
+        }
+    }
+}
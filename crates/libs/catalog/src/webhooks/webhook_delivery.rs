@@ -0,0 +1,157 @@
+//! the webhook deliveries
+
+use crate::webhooks::webhook_id::WebhookId;
+use chrono::{DateTime, Utc};
+use sqlx::Type;
+use strum_macros::{Display, EnumString};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// The status for a webhook delivery
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize, EnumString, Display, Type, ToSchema)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[strum(ascii_case_insensitive)]
+#[sqlx(type_name = "webhook_delivery_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DeliveryStatus {
+    /// the delivery is waiting for its next attempt
+    Pending,
+
+    /// the event was delivered
+    Delivered,
+
+    /// the delivery failed, and it will not be attempted again
+    Failed,
+}
+
+/// It represents the delivery of a domain event to a webhook, as recorded in the delivery log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDelivery {
+    /// the delivery unique identifier
+    pub delivery_id: Uuid,
+    /// the webhook which receives the event
+    pub webhook_id: WebhookId,
+    /// the domain event id
+    pub event_id: Uuid,
+    /// the domain event type
+    pub event_type: String,
+    /// the delivery status
+    pub status: DeliveryStatus,
+    /// the number of attempts so far
+    pub attempts: i32,
+    /// the HTTP status code returned by the webhook for the last attempt
+    pub last_response_status: Option<i32>,
+    /// the error for the last failed attempt
+    pub last_error: Option<String>,
+    /// the timestamp for the next attempt
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    /// the delivery timestamp
+    pub delivered_at: Option<DateTime<Utc>>,
+    /// the delivery creation timestamp
+    pub created_at: DateTime<Utc>,
+}
+
+/// A delivery due for its next attempt, together with the webhook settings to send it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingDelivery {
+    /// the delivery unique identifier
+    pub delivery_id: Uuid,
+    /// the webhook which receives the event
+    pub webhook_id: WebhookId,
+    /// the domain event id
+    pub event_id: Uuid,
+    /// the domain event type
+    pub event_type: String,
+    /// the request body
+    pub payload: String,
+    /// the number of attempts so far
+    pub attempts: i32,
+    /// the url which receives the event
+    pub target_url: String,
+    /// the shared secret used to sign the request
+    pub secret: String,
+}
+
+/// The outcome of a single delivery attempt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryAttempt {
+    /// the webhook answered with a successful (2xx) status code
+    Delivered { response_status: u16 },
+    /// the webhook answered with an error status code
+    Rejected { response_status: u16 },
+    /// it was not possible to send the request (ie connection refused or timeout)
+    Unreachable { error: String },
+}
+
+impl DeliveryAttempt {
+    /// The HTTP status code returned by the webhook (if any)
+    pub fn response_status(&self) -> Option<i32> {
+        match self {
+            DeliveryAttempt::Delivered { response_status } | DeliveryAttempt::Rejected { response_status } => {
+                Some(*response_status as i32)
+            }
+            DeliveryAttempt::Unreachable { .. } => None,
+        }
+    }
+
+    /// A description for the failed attempts
+    pub fn error(&self) -> Option<String> {
+        match self {
+            DeliveryAttempt::Delivered { .. } => None,
+            DeliveryAttempt::Rejected { response_status } => {
+                Some(format!("the webhook answered with status code {response_status}"))
+            }
+            DeliveryAttempt::Unreachable { error } => Some(error.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod delivery_statuses {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+        use strum::ParseError;
+
+        #[rstest]
+        #[case("PENDING", Ok(DeliveryStatus::Pending))]
+        #[case("DELIVERED", Ok(DeliveryStatus::Delivered))]
+        #[case("FAILED", Ok(DeliveryStatus::Failed))]
+        #[case("invalid", Err(ParseError::VariantNotFound))]
+        fn it_should_parse_delivery_statuses(
+            #[case] input: &str,
+            #[case] expected: Result<DeliveryStatus, ParseError>,
+        ) {
+            let status = input.parse::<DeliveryStatus>();
+            assert_eq!(expected, status);
+        }
+    }
+
+    mod delivery_attempts {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_describe_the_delivery_attempts() {
+            let delivered = DeliveryAttempt::Delivered { response_status: 204 };
+            assert_eq!(Some(204), delivered.response_status());
+            assert_eq!(None, delivered.error());
+
+            let rejected = DeliveryAttempt::Rejected { response_status: 500 };
+            assert_eq!(Some(500), rejected.response_status());
+            assert_eq!(
+                Some(String::from("the webhook answered with status code 500")),
+                rejected.error()
+            );
+
+            let unreachable = DeliveryAttempt::Unreachable {
+                error: String::from("connection refused"),
+            };
+            assert_eq!(None, unreachable.response_status());
+            assert_eq!(Some(String::from("connection refused")), unreachable.error());
+        }
+    }
+}
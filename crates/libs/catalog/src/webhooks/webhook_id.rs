@@ -0,0 +1,89 @@
+//! the webhook identifier
+
+use sqlx::Type;
+use std::fmt;
+use std::fmt::Formatter;
+use std::str;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A unique identifier for a webhook
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(transparent)]
+pub struct WebhookId(Uuid);
+
+impl WebhookId {
+    /// Create a new random webhook id
+    pub fn new() -> Self {
+        WebhookId::default()
+    }
+
+    /// the webhook unique identifier value
+    pub fn value(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for WebhookId {
+    fn default() -> Self {
+        let id = Uuid::new_v4();
+        WebhookId(id)
+    }
+}
+
+impl fmt::Display for WebhookId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+type Error = &'static str;
+
+impl str::FromStr for WebhookId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = Uuid::try_parse(s).map_err(|_| "invalid webhook id")?;
+        Ok(WebhookId(id))
+    }
+}
+
+impl From<Uuid> for WebhookId {
+    fn from(id: Uuid) -> Self {
+        WebhookId(id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod webhook_ids {
+        use std::str::FromStr;
+
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_create_new_webhook_id_from_str() {
+            let id = "3302b9a7-252c-4b41-8de2-eb71efb1888e".parse::<WebhookId>().unwrap();
+            assert_eq!(
+                WebhookId(Uuid::from_str("3302b9a7-252c-4b41-8de2-eb71efb1888e").unwrap()),
+                id
+            );
+        }
+
+        #[test]
+        fn it_should_create_new_webhook_id_from_uuid() {
+            let uuid = Uuid::new_v4();
+            let webhook_id: WebhookId = uuid.into();
+            assert_eq!(uuid, webhook_id.value());
+        }
+
+        #[test]
+        fn it_should_fail_to_parse_invalid_values_as_webhooks() {
+            let result = "invalid value".parse::<WebhookId>();
+            assert!(result.is_err());
+        }
+    }
+}
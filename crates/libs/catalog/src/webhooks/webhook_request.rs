@@ -0,0 +1,137 @@
+//! the webhook command requests
+
+use crate::events::domain_event::DomainEvent;
+use crate::webhooks::target_url::validate_target_url;
+use std::fmt;
+use std::fmt::Formatter;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+/// A request to subscribe a new webhook
+#[derive(Clone, PartialEq, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct WebhookRequest {
+    /// the url which receives the events, it must be a public `https` url
+    #[validate(url, length(max = 500), custom(function = "validate_target_url"))]
+    pub target_url: String,
+    /// the domain event types to deliver
+    #[validate(length(min = 1), custom(function = "validate_event_types"))]
+    pub event_types: Vec<String>,
+    /// the shared secret used to sign the requests
+    #[validate(length(min = 16, max = 100))]
+    pub secret: String,
+}
+
+impl fmt::Debug for WebhookRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookRequest")
+            .field("target_url", &self.target_url)
+            .field("event_types", &self.event_types)
+            .field("secret", &"[REDACTED]")
+            .finish()
+    }
+}
+
+fn validate_event_types(event_types: &[String]) -> Result<(), ValidationError> {
+    if event_types.iter().all(|it| DomainEvent::is_event_type(it)) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("unknown event type"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod webhook_request_validation {
+        use super::*;
+        use crate::test_helpers::random_str;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        #[test]
+        fn it_should_validate_webhook_requests() {
+            let request = new_request(
+                "https://www.example.com/hooks",
+                vec!["BrandCreated"],
+                "a-very-long-secret",
+            );
+            assert!(request.validate().is_ok());
+        }
+
+        #[rstest]
+        #[case("")]
+        #[case("not an url")]
+        fn it_should_validate_the_target_url(#[case] input: &str) {
+            let request = new_request(input, vec!["BrandCreated"], "a-very-long-secret");
+
+            let result = request.validate();
+            let err = result.unwrap_err();
+            let errors = err.field_errors();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors["target_url"][0].code, "url");
+        }
+
+        #[rstest]
+        #[case("http://www.example.com/hooks", "https_required")]
+        #[case("https://localhost:8080/hooks", "private_address")]
+        #[case("https://127.0.0.1/hooks", "private_address")]
+        #[case("https://192.168.0.10/hooks", "private_address")]
+        #[case("https://169.254.169.254/latest/meta-data", "private_address")]
+        fn it_should_reject_the_target_urls_to_the_internal_network(#[case] input: &str, #[case] expected: &str) {
+            let request = new_request(input, vec!["BrandCreated"], "a-very-long-secret");
+
+            let result = request.validate();
+            let err = result.unwrap_err();
+            let errors = err.field_errors();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors["target_url"][0].code, expected);
+        }
+
+        #[rstest]
+        #[case(vec![], "length")]
+        #[case(vec!["BrandCreated", "NotAnEvent"], "unknown event type")]
+        fn it_should_validate_the_event_types(#[case] input: Vec<&str>, #[case] expected: &str) {
+            let request = new_request("https://www.example.com/hooks", input, "a-very-long-secret");
+
+            let result = request.validate();
+            let err = result.unwrap_err();
+            let errors = err.field_errors();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors["event_types"][0].code, expected);
+        }
+
+        #[rstest]
+        #[case(random_str(15))]
+        #[case(random_str(101))]
+        fn it_should_validate_the_secret(#[case] input: String) {
+            let request = new_request("https://www.example.com/hooks", vec!["BrandCreated"], &input);
+
+            let result = request.validate();
+            let err = result.unwrap_err();
+            let errors = err.field_errors();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors["secret"][0].code, "length");
+        }
+
+        #[test]
+        fn it_should_not_include_the_secret_in_the_debug_output() {
+            let request = new_request(
+                "https://www.example.com/hooks",
+                vec!["BrandCreated"],
+                "a-very-long-secret",
+            );
+
+            let output = format!("{request:?}");
+            assert!(!output.contains("a-very-long-secret"));
+        }
+
+        fn new_request(target_url: &str, event_types: Vec<&str>, secret: &str) -> WebhookRequest {
+            WebhookRequest {
+                target_url: String::from(target_url),
+                event_types: event_types.into_iter().map(String::from).collect(),
+                secret: String::from(secret),
+            }
+        }
+    }
+}
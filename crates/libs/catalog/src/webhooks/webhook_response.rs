@@ -0,0 +1,31 @@
+//! the webhook command responses
+
+use crate::webhooks::webhook_id::WebhookId;
+use chrono::{DateTime, Utc};
+
+/// It represents a response for webhooks creation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookCreated {
+    /// the webhook id for the new webhook
+    pub webhook_id: WebhookId,
+    /// the webhook creation timestamp
+    pub created_at: DateTime<Utc>,
+}
+
+/// It represents a response for the webhook deliveries scheduling
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WebhookDeliveriesScheduled {
+    /// the number of new deliveries
+    pub scheduled: usize,
+}
+
+/// It represents a response for a batch of webhook delivery attempts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WebhookDeliveriesAttempted {
+    /// the number of deliveries completed successfully
+    pub delivered: usize,
+    /// the number of failed deliveries which will be attempted again
+    pub retried: usize,
+    /// the number of deliveries which failed for good
+    pub failed: usize,
+}
@@ -0,0 +1,51 @@
+//! the webhook requests sender
+
+use crate::webhooks::webhook_delivery::{DeliveryAttempt, PendingDelivery};
+use async_trait::async_trait;
+
+/// It sends the webhook requests for the pending deliveries
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// Sends the request for the delivery, the failures are reported in the attempt outcome
+    async fn send(&self, delivery: &PendingDelivery) -> DeliveryAttempt;
+}
+
+#[cfg(test)]
+pub mod in_memory {
+    use crate::webhooks::webhook_delivery::{DeliveryAttempt, PendingDelivery};
+    use crate::webhooks::webhook_sender::WebhookSender;
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+
+    /// A webhook sender which always returns the same outcome, and records the sent deliveries
+    #[derive(Clone)]
+    pub struct InMemoryWebhookSender {
+        outcome: DeliveryAttempt,
+        sent: Arc<Mutex<Vec<PendingDelivery>>>,
+    }
+
+    impl InMemoryWebhookSender {
+        /// Creates a new sender returning `outcome` for every delivery
+        pub fn returning(outcome: DeliveryAttempt) -> Self {
+            InMemoryWebhookSender {
+                outcome,
+                sent: Arc::new(Mutex::new(Vec::new())),
+            }
+        }
+
+        /// Returns the deliveries sent so far
+        pub fn sent(&self) -> Vec<PendingDelivery> {
+            let sent = self.sent.lock().expect("unable to acquire the deliveries lock");
+            sent.clone()
+        }
+    }
+
+    #[async_trait]
+    impl WebhookSender for InMemoryWebhookSender {
+        async fn send(&self, delivery: &PendingDelivery) -> DeliveryAttempt {
+            let mut sent = self.sent.lock().expect("unable to acquire the deliveries lock");
+            sent.push(delivery.clone());
+            self.outcome.clone()
+        }
+    }
+}
@@ -2,10 +2,11 @@
 
 use isocountry::CountryCode;
 use thiserror::Error;
+use utoipa::ToSchema;
 use validator::Validate;
 
 /// It represents a physical street address
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
 pub struct Address {
     /// the street address
     #[validate(length(min = 5, max = 255))]
@@ -23,6 +24,7 @@ pub struct Address {
     #[validate(length(min = 3, max = 10))]
     pub postal_code: String,
     /// the ISO country code (ISO 3166-1 alpha-3)
+    #[schema(value_type = String)]
     pub country: CountryCode,
 }
 
@@ -7,10 +7,11 @@ use std::fmt::Formatter;
 use std::str;
 use std::str::FromStr;
 use thiserror::Error;
+use utoipa::ToSchema;
 use validator::{ValidateEmail, ValidateLength, ValidationError};
 
 /// It represents a mail address
-#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Type)]
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Type, ToSchema)]
 #[sqlx(transparent)]
 pub struct MailAddress(String);
 
@@ -9,6 +9,7 @@ use crate::contacts::phones::PhoneParsingError;
 use crate::contacts::website_urls::WebsiteUrlParsingError;
 use std::str::FromStr;
 use thiserror::Error;
+use utoipa::ToSchema;
 use validator::Validate;
 
 pub use emails::MailAddress;
@@ -16,7 +17,7 @@ pub use phones::PhoneNumber;
 pub use website_urls::WebsiteUrl;
 
 /// The contact information provides the means to communicate with an organization.
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Default, Validate)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Default, Validate, ToSchema)]
 pub struct ContactInformation {
     /// the email address
     #[validate(
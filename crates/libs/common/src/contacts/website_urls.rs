@@ -8,10 +8,11 @@ use std::str;
 use std::str::FromStr;
 use thiserror::Error;
 use url::Url;
+use utoipa::ToSchema;
 use validator::{ValidateLength, ValidateUrl, ValidationError};
 
 /// It represents a website url
-#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Type)]
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Type, ToSchema)]
 #[sqlx(transparent)]
 pub struct WebsiteUrl(String);
 
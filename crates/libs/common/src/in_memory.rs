@@ -59,6 +59,20 @@ impl<Id: Debug + Eq + Hash, T: Debug + Clone> InMemoryRepository<Id, T> {
         let result = items.borrow().get(id).cloned();
         result
     }
+
+    /// Returns all the elements in the in-memory repository, in no particular order
+    pub fn find_all(&self) -> Vec<(Id, T)>
+    where
+        Id: Clone,
+    {
+        let items = self.storage.lock().expect("unable to acquire the items lock");
+        let result = items
+            .borrow()
+            .iter()
+            .map(|(id, item)| (id.clone(), item.clone()))
+            .collect();
+        result
+    }
 }
 
 #[cfg(test)]
@@ -90,7 +104,18 @@ mod test {
             assert_eq!(true, repository.contains(&Id(42)));
         }
 
-        #[derive(Debug, Hash, PartialEq, Eq)]
+        #[test]
+        fn it_should_find_all_the_elements() {
+            let repository = InMemoryRepository::of(Id(42), "answer");
+            repository.add(Id(1), "one");
+
+            let mut items = repository.find_all();
+            items.sort_by_key(|(id, _)| id.0);
+
+            assert_eq!(vec![(Id(1), "one"), (Id(42), "answer")], items);
+        }
+
+        #[derive(Debug, Clone, Hash, PartialEq, Eq)]
         struct Id(u32);
     }
 }
@@ -3,10 +3,11 @@
 use crate::validation::Validator;
 use strum_macros;
 use strum_macros::{Display, EnumString};
+use utoipa::ToSchema;
 use validator::{Validate, ValidationErrors};
 
 /// It represents a multi-language text.
-#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Eq, ToSchema)]
 pub struct LocalizedText {
     /// the text in German
     pub de: Option<String>,
@@ -95,6 +96,21 @@ impl LocalizedText {
     pub fn german(&self) -> Option<&String> {
         self.de.as_ref()
     }
+
+    /// Returns the label in the given language, if exists
+    pub fn label(&self, language: Language) -> Option<&String> {
+        match language {
+            Language::English => self.english(),
+            Language::French => self.french(),
+            Language::German => self.german(),
+            Language::Italian => self.italian(),
+        }
+    }
+
+    /// Returns the label in the given language, falling back to the English one when missing
+    pub fn label_or_english(&self, language: Language) -> Option<&String> {
+        self.label(language).or_else(|| self.english())
+    }
 }
 
 impl Validate for LocalizedText {
@@ -155,7 +171,7 @@ impl LocalizedTextBuilder {
 }
 
 /// The languages supported by the application
-#[derive(Debug, Eq, PartialEq, Hash, Display, EnumString, Serialize, Deserialize, Clone, Copy)]
+#[derive(Debug, Eq, PartialEq, Hash, Display, EnumString, Serialize, Deserialize, Clone, Copy, ToSchema)]
 pub enum Language {
     /// the French language
     #[strum(serialize = "fr")]
@@ -262,6 +278,25 @@ mod test {
             assert_eq!(Some(&String::from("Guten Morgen")), localized_text.german());
             assert_eq!(Some(&String::from("Bonjour")), localized_text.french());
         }
+
+        #[test]
+        fn it_should_return_the_label_for_a_language() {
+            let localized_text = LocalizedTextBuilder::default()
+                .english_text("Good Morning")
+                .italian_text("Buongiorno")
+                .build();
+
+            assert_eq!(
+                Some(&String::from("Buongiorno")),
+                localized_text.label(Language::Italian)
+            );
+            assert_eq!(None, localized_text.label(Language::German));
+            assert_eq!(
+                Some(&String::from("Good Morning")),
+                localized_text.label_or_english(Language::German)
+            );
+            assert_eq!(None, LocalizedText::default().label_or_english(Language::German));
+        }
     }
 
     mod localize_texts_validation {
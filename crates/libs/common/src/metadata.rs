@@ -1,13 +1,16 @@
 //! the module includes everything related to resource metadata
 
 use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
 
 /// The metadata information for the current resource
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
 pub struct Metadata {
     version: u8,
     created: DateTime<Utc>,
     last_modified: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    deleted: Option<DateTime<Utc>>,
 }
 
 impl Metadata {
@@ -17,6 +20,7 @@ impl Metadata {
             version,
             created,
             last_modified,
+            deleted: None,
         }
     }
 
@@ -26,6 +30,7 @@ impl Metadata {
             version: 1u8,
             created,
             last_modified: None,
+            deleted: None,
         }
     }
 
@@ -35,6 +40,15 @@ impl Metadata {
             version: self.version + 1,
             created: self.created,
             last_modified: Some(last_modified),
+            deleted: self.deleted,
+        }
+    }
+
+    /// Marks this metadata as (soft) deleted
+    pub fn deleted_at(self, deleted: DateTime<Utc>) -> Self {
+        Metadata {
+            deleted: Some(deleted),
+            ..self
         }
     }
 
@@ -52,6 +66,16 @@ impl Metadata {
     pub fn last_modified(&self) -> Option<&DateTime<Utc>> {
         self.last_modified.as_ref()
     }
+
+    /// The resource (soft) deletion timestamp
+    pub fn deleted(&self) -> Option<&DateTime<Utc>> {
+        self.deleted.as_ref()
+    }
+
+    /// Returns `true` if the resource has been (soft) deleted
+    pub fn is_deleted(&self) -> bool {
+        self.deleted.is_some()
+    }
 }
 
 impl Default for Metadata {
@@ -86,5 +110,25 @@ mod tests {
             assert_eq!(&now, metadata.created());
             assert_eq!(Some(&now), metadata.last_modified());
         }
+
+        #[test]
+        fn it_should_mark_metadata_as_deleted() {
+            let now: DateTime<Utc> = Utc::now();
+            let metadata = Metadata::created_at(now);
+            assert!(!metadata.is_deleted());
+            assert_eq!(None, metadata.deleted());
+
+            let metadata = metadata.deleted_at(now);
+            assert!(metadata.is_deleted());
+            assert_eq!(Some(&now), metadata.deleted());
+            assert_eq!(1, metadata.version());
+        }
+
+        #[test]
+        fn it_should_keep_the_deletion_timestamp_after_updates() {
+            let now: DateTime<Utc> = Utc::now();
+            let metadata = Metadata::created_at(now).deleted_at(now).updated_at(now);
+            assert_eq!(Some(&now), metadata.deleted());
+        }
     }
 }
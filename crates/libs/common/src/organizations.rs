@@ -4,9 +4,10 @@ use serde_derive::{Deserialize, Serialize};
 use sqlx::Type;
 use strum_macros;
 use strum_macros::{Display, EnumString};
+use utoipa::ToSchema;
 
 /// The many types of business entities defined in the legal systems of various countries
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, EnumString, Display, Type)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, EnumString, Display, Type, ToSchema)]
 #[sqlx(type_name = "organization_entity_type", rename_all = "SCREAMING_SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
@@ -18,6 +18,9 @@ pub enum QueryError {
     #[error("No results were found")]
     EmptyResultSet,
 
+    #[error("The resource has been deleted")]
+    ResourceDeleted,
+
     #[error(transparent)]
     UnexpectedError(#[from] anyhow::Error),
 
@@ -3,3 +3,4 @@
 pub mod converters;
 pub mod errors;
 pub mod pagination;
+pub mod soft_delete;
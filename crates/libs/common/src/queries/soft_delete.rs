@@ -0,0 +1,46 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// The query parameters to include the soft deleted resources in the result set.
+///
+/// Resources are never hard deleted; their deletion timestamp is recorded instead. By default,
+/// queries exclude the deleted resources unless `include_deleted` is set.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SoftDeleteFilter {
+    /// when `true`, the soft deleted resources are included in the result set (admin only)
+    #[serde(default)]
+    pub include_deleted: bool,
+}
+
+impl SoftDeleteFilter {
+    /// Creates a filter which includes the soft deleted resources
+    pub fn include_deleted() -> Self {
+        SoftDeleteFilter { include_deleted: true }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod soft_delete_filters {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_exclude_deleted_resources_by_default() {
+            let filter = SoftDeleteFilter::default();
+            assert!(!filter.include_deleted);
+        }
+
+        #[test]
+        fn it_should_deserialize_soft_delete_filters_from_query_strings() {
+            let filter: SoftDeleteFilter = serde_urlencoded::from_str("include_deleted=true").unwrap();
+            assert_eq!(SoftDeleteFilter::include_deleted(), filter);
+
+            let filter: SoftDeleteFilter = serde_urlencoded::from_str("").unwrap();
+            assert_eq!(SoftDeleteFilter::default(), filter);
+        }
+    }
+}
@@ -7,9 +7,10 @@ use std::str;
 use std::str::FromStr;
 use std::{convert, fmt};
 use thiserror::Error;
+use utoipa::ToSchema;
 
 /// A SEO friendly string
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Clone, Serialize, Deserialize, Type, Hash)]
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Clone, Serialize, Deserialize, Type, Hash, ToSchema)]
 #[sqlx(transparent)]
 pub struct Slug(String);
 
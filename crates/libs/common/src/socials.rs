@@ -7,6 +7,7 @@ use std::fmt;
 use std::fmt::Formatter;
 use std::str;
 use thiserror::Error;
+use utoipa::ToSchema;
 use validator::{Validate, ValidationErrors};
 
 /// The social profiles for an organization
@@ -26,7 +27,7 @@ use validator::{Validate, ValidationErrors};
 ///   .build()
 ///   .unwrap();
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema)]
 pub struct Socials {
     /// the facebook handler
     pub facebook: Option<Handler>,
@@ -155,7 +156,7 @@ pub enum SocialsBuilderError {
 /// A social network handler.
 ///
 /// the value must be URL encoded.
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Type)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Type, ToSchema)]
 #[sqlx(transparent)]
 pub struct Handler(String);
 
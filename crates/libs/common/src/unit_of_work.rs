@@ -2,11 +2,18 @@
 
 use async_trait::async_trait;
 
+/// An action to run once the unit of work is committed
+pub type AfterCommit = Box<dyn FnOnce() + Send + Sync>;
+
 /// It represents a database unit of work, typically to wrap a transaction
 #[async_trait]
 pub trait UnitOfWork<'transaction> {
     /// Commit the unit of work
     async fn commit(self) -> Result<(), anyhow::Error>;
+
+    /// Registers an action to run after the unit of work is committed, the action is discarded
+    /// when the unit of work is not committed
+    fn after_commit(&mut self, action: AfterCommit);
 }
 
 /// It represents a database context, usually its main purpose is to
@@ -19,35 +26,64 @@ pub trait Database<'db, U: UnitOfWork<'db>> {
 
 /// A no-op unit of work, useful for testing
 pub mod noop {
-    use crate::unit_of_work::{Database, UnitOfWork};
+    use crate::unit_of_work::{AfterCommit, Database, UnitOfWork};
+    use anyhow::anyhow;
     use async_trait::async_trait;
 
+    #[derive(Clone)]
     pub struct NoOpDatabase;
-    pub struct NoOpUnitOfWork;
+    #[derive(Default)]
+    pub struct NoOpUnitOfWork {
+        failing_commit: bool,
+    }
+
+    /// A no-op database whose units of work always fail to commit
+    #[derive(Clone)]
+    pub struct FailingCommitDatabase;
 
     #[async_trait]
     impl UnitOfWork<'static> for NoOpUnitOfWork {
         async fn commit(self) -> Result<(), anyhow::Error> {
-            Ok(())
+            if self.failing_commit {
+                Err(anyhow!("unable to commit the unit of work"))
+            } else {
+                Ok(())
+            }
+        }
+
+        /// The no-op unit of work has nothing to commit, the action runs immediately (unless
+        /// the commit is going to fail)
+        fn after_commit(&mut self, action: AfterCommit) {
+            if !self.failing_commit {
+                action()
+            }
         }
     }
 
     #[async_trait]
     impl Database<'static, NoOpUnitOfWork> for NoOpDatabase {
         async fn begin(self) -> Result<NoOpUnitOfWork, anyhow::Error> {
-            Ok(NoOpUnitOfWork)
+            Ok(NoOpUnitOfWork::default())
+        }
+    }
+
+    #[async_trait]
+    impl Database<'static, NoOpUnitOfWork> for FailingCommitDatabase {
+        async fn begin(self) -> Result<NoOpUnitOfWork, anyhow::Error> {
+            Ok(NoOpUnitOfWork { failing_commit: true })
         }
     }
 }
 
 /// A unit of work implementation for Postgres
 pub mod postgres {
-    use crate::unit_of_work::{Database, UnitOfWork};
+    use crate::unit_of_work::{AfterCommit, Database, UnitOfWork};
     use anyhow::Context;
     use async_trait::async_trait;
     use sqlx::{PgPool, Postgres, Transaction};
 
     /// A postgres concrete implementation for a database connection wrapper
+    #[derive(Clone)]
     pub struct PgDatabase<'db> {
         pg_pool: &'db PgPool,
     }
@@ -63,13 +99,24 @@ pub mod postgres {
     impl<'db> Database<'db, PgUnitOfWork<'db>> for PgDatabase<'db> {
         async fn begin(self) -> Result<PgUnitOfWork<'db>, anyhow::Error> {
             let transaction = self.pg_pool.begin().await.context("Could not begin the transaction")?;
-            Ok(PgUnitOfWork { transaction })
+            Ok(PgUnitOfWork::new(transaction))
         }
     }
 
     /// A postgres concrete implementation for a database unit of work
     pub struct PgUnitOfWork<'transaction> {
         pub transaction: Transaction<'transaction, Postgres>,
+        after_commit: Vec<AfterCommit>,
+    }
+
+    impl<'transaction> PgUnitOfWork<'transaction> {
+        /// Creates a new unit of work for the transaction
+        pub fn new(transaction: Transaction<'transaction, Postgres>) -> Self {
+            PgUnitOfWork {
+                transaction,
+                after_commit: Vec::new(),
+            }
+        }
     }
 
     #[async_trait]
@@ -79,7 +126,14 @@ pub mod postgres {
                 .commit()
                 .await
                 .context("Could not commit the transaction")?;
+            for action in self.after_commit {
+                action();
+            }
             Ok(())
         }
+
+        fn after_commit(&mut self, action: AfterCommit) {
+            self.after_commit.push(action);
+        }
     }
 }
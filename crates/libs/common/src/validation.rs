@@ -49,6 +49,11 @@ impl Validator {
         }
     }
 
+    /// Adds a validation error for the field
+    pub fn add_error(&mut self, field: &'static str, error: ValidationError) {
+        self.0.add(field, error);
+    }
+
     pub fn validate_length(&mut self, field: &'static str, min: Option<u64>, max: Option<u64>, input: &String) {
         if !input.validate_length(min, max, None) {
             let mut error = ValidationError::new("length");
@@ -92,6 +97,19 @@ mod test {
             assert!(!validator.has_errors());
         }
 
+        #[test]
+        fn it_should_add_validation_errors() {
+            let mut validator = Validator::new();
+
+            validator.add_error("field_name", ValidationError::new("custom"));
+
+            assert!(validator.has_errors());
+            let errors = validator.errors();
+            let errors = errors.field_errors();
+            assert_eq!(errors["field_name"].len(), 1);
+            assert_eq!(errors["field_name"][0].code, "custom");
+        }
+
         #[test]
         fn it_should_validate_the_string_length() {
             let mut validator = Validator::new();
@@ -5,6 +5,8 @@ use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 use sqlx::postgres::{PgConnectOptions, PgPoolOptions, PgSslMode};
 use sqlx::PgPool;
+use std::path::PathBuf;
+use std::time::Duration;
 
 /// It represents the settings for the service
 #[derive(Serialize, Deserialize, Debug)]
@@ -15,6 +17,33 @@ pub struct Settings {
     pub server: ServerSettings,
     /// the logging and tracing settings
     pub logging: LoggingSettings,
+    /// the domain events outbox settings
+    #[serde(default)]
+    pub outbox: OutboxSettings,
+    /// the webhook deliveries settings
+    #[serde(default)]
+    pub webhooks: WebhooksSettings,
+    /// the catalog changes stream settings
+    #[serde(default)]
+    pub event_stream: EventStreamSettings,
+    /// the management endpoints settings
+    #[serde(default)]
+    pub management: ManagementSettings,
+    /// the reference data (brands, railways and scales) cache settings
+    #[serde(default)]
+    pub cache: CacheSettings,
+    /// the catalog item images settings
+    #[serde(default)]
+    pub images: ImagesSettings,
+    /// the overdue announced catalog items review settings
+    #[serde(default)]
+    pub availability_review: AvailabilityReviewSettings,
+    /// the admin access settings
+    #[serde(default)]
+    pub admin: AdminSettings,
+    /// the release calendar settings
+    #[serde(default)]
+    pub calendar: CalendarSettings,
 }
 
 impl Settings {
@@ -38,7 +67,14 @@ impl Settings {
     pub fn load_from_path(config_file: &str) -> Result<Settings, config::ConfigError> {
         let s = Config::builder()
             .add_source(File::with_name(config_file).required(false))
-            .add_source(Environment::default().separator("__").ignore_empty(true))
+            .add_source(
+                Environment::default()
+                    .separator("__")
+                    .ignore_empty(true)
+                    .try_parsing(true)
+                    .list_separator(",")
+                    .with_list_parse_key("server.cors_allowed_origins"),
+            )
             .build()?;
         s.try_deserialize()
     }
@@ -71,12 +107,397 @@ pub struct LoggingSettings {
 }
 
 /// It contains the server configuration
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
 pub struct ServerSettings {
     /// the server host name
     pub host: String,
     /// the server port number
     pub port: u16,
+    /// the timeout (in milliseconds) to produce a response, the streamed response bodies are not affected
+    pub request_timeout_ms: u64,
+    /// the max size (in bytes) for the request bodies
+    pub max_body_size: usize,
+    /// the max number of requests handled concurrently, the others wait for their turn
+    pub concurrency_limit: usize,
+    /// the origins allowed for cross-origin requests (`*` for any origin), when empty CORS is disabled
+    pub cors_allowed_origins: Vec<String>,
+    /// the max time (in milliseconds) to drain the open connections on shutdown
+    pub shutdown_timeout_ms: u64,
+    /// the TLS settings, when missing the server is using plain HTTP
+    pub tls: Option<TlsSettings>,
+    /// the public base url (ie, `https://trenako.com`) for the absolute urls in the responses, when missing
+    /// it is taken from the request `Host` and `X-Forwarded-Proto` headers
+    pub public_url: Option<String>,
+}
+
+impl ServerSettings {
+    /// Returns the timeout to produce a response
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+
+    /// Returns the max time to drain the open connections on shutdown
+    pub fn shutdown_timeout(&self) -> Duration {
+        Duration::from_millis(self.shutdown_timeout_ms)
+    }
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        ServerSettings {
+            host: String::from("127.0.0.1"),
+            port: 5000,
+            request_timeout_ms: 30_000,
+            max_body_size: 2 * 1024 * 1024,
+            concurrency_limit: 1024,
+            cors_allowed_origins: Vec::new(),
+            shutdown_timeout_ms: 30_000,
+            tls: None,
+            public_url: None,
+        }
+    }
+}
+
+/// It contains the TLS settings, to serve HTTPS requests
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TlsSettings {
+    /// the path for the PEM encoded certificate chain
+    pub cert_path: PathBuf,
+    /// the path for the PEM encoded private key
+    pub key_path: PathBuf,
+    /// the port for a plain HTTP listener redirecting to HTTPS, when missing there is no redirect
+    #[serde(default)]
+    pub http_redirect_port: Option<u16>,
+    /// the interval (in milliseconds) between two checks for changes to the certificate files
+    #[serde(default = "TlsSettings::default_reload_interval_ms")]
+    pub reload_interval_ms: u64,
+}
+
+impl TlsSettings {
+    /// Creates new TLS settings, using the default reload interval and no HTTP redirect
+    pub fn new(cert_path: impl Into<PathBuf>, key_path: impl Into<PathBuf>) -> Self {
+        TlsSettings {
+            cert_path: cert_path.into(),
+            key_path: key_path.into(),
+            http_redirect_port: None,
+            reload_interval_ms: Self::default_reload_interval_ms(),
+        }
+    }
+
+    /// Returns the interval between two checks for changes to the certificate files
+    pub fn reload_interval(&self) -> Duration {
+        Duration::from_millis(self.reload_interval_ms)
+    }
+
+    fn default_reload_interval_ms() -> u64 {
+        60_000
+    }
+}
+
+/// It contains the domain events outbox settings
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct OutboxSettings {
+    /// when `false` the pending events are not dispatched
+    pub enabled: bool,
+    /// the interval (in milliseconds) between two checks for pending events
+    pub poll_interval_ms: u64,
+    /// the max number of events dispatched for each check
+    pub batch_size: i64,
+    /// the max number of dispatch attempts for each event, then the event is dead lettered
+    pub max_attempts: i32,
+    /// the delay (in milliseconds) before the first retry, it doubles for each following attempt
+    pub initial_backoff_ms: u64,
+    /// the max delay (in milliseconds) between two attempts
+    pub max_backoff_ms: u64,
+    /// the time (in milliseconds) a dispatcher owns the claimed events, before other dispatchers can claim them again
+    pub claim_timeout_ms: u64,
+}
+
+impl OutboxSettings {
+    /// Returns the interval between two checks for pending events
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+
+    /// Returns the time a dispatcher owns the claimed events
+    pub fn claim_timeout(&self) -> Duration {
+        Duration::from_millis(self.claim_timeout_ms)
+    }
+}
+
+impl Default for OutboxSettings {
+    fn default() -> Self {
+        OutboxSettings {
+            enabled: true,
+            poll_interval_ms: 1000,
+            batch_size: 100,
+            max_attempts: 10,
+            initial_backoff_ms: 1000,
+            max_backoff_ms: 300_000,
+            claim_timeout_ms: 60_000,
+        }
+    }
+}
+
+/// It contains the webhook deliveries settings
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct WebhooksSettings {
+    /// when `false` the pending deliveries are not sent
+    pub enabled: bool,
+    /// the interval (in milliseconds) between two checks for due deliveries
+    pub poll_interval_ms: u64,
+    /// the max number of deliveries sent for each check
+    pub batch_size: i64,
+    /// the max number of attempts for each delivery
+    pub max_attempts: i32,
+    /// the delay (in milliseconds) before the first retry, it doubles for each following attempt
+    pub initial_backoff_ms: u64,
+    /// the max delay (in milliseconds) between two attempts
+    pub max_backoff_ms: u64,
+    /// the timeout (in milliseconds) for the webhook requests
+    pub request_timeout_ms: u64,
+    /// the time (in milliseconds) a worker owns the claimed deliveries, before other workers can claim them again
+    pub claim_timeout_ms: u64,
+    /// when `true` the deliveries can reach the internal network (only for local development and tests),
+    /// the new webhooks still require a public `https` url
+    pub allow_private_targets: bool,
+}
+
+impl WebhooksSettings {
+    /// Returns the interval between two checks for due deliveries
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+
+    /// Returns the timeout for the webhook requests
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+}
+
+impl Default for WebhooksSettings {
+    fn default() -> Self {
+        WebhooksSettings {
+            enabled: true,
+            poll_interval_ms: 1000,
+            batch_size: 50,
+            max_attempts: 8,
+            initial_backoff_ms: 10_000,
+            max_backoff_ms: 3_600_000,
+            request_timeout_ms: 5000,
+            claim_timeout_ms: 300_000,
+            allow_private_targets: false,
+        }
+    }
+}
+
+/// It contains the settings for the job that flags the overdue announced catalog items for editorial review
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct AvailabilityReviewSettings {
+    /// when `false` the overdue catalog items are not flagged
+    pub enabled: bool,
+    /// the interval (in milliseconds) between two checks for overdue catalog items
+    pub poll_interval_ms: u64,
+}
+
+impl AvailabilityReviewSettings {
+    /// Returns the interval between two checks for overdue catalog items
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+}
+
+impl Default for AvailabilityReviewSettings {
+    fn default() -> Self {
+        AvailabilityReviewSettings {
+            enabled: true,
+            poll_interval_ms: 3_600_000,
+        }
+    }
+}
+
+/// It contains the release calendar settings
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct CalendarSettings {
+    /// the max number of years covered by a release calendar request
+    pub max_releases_years: i32,
+}
+
+impl Default for CalendarSettings {
+    fn default() -> Self {
+        CalendarSettings { max_releases_years: 5 }
+    }
+}
+
+/// It contains the catalog changes stream (server-sent events) settings
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct EventStreamSettings {
+    /// the max number of recent events kept for the clients resuming the stream
+    pub replay_buffer_size: usize,
+    /// the interval (in milliseconds) between two keep-alive messages
+    pub keep_alive_interval_ms: u64,
+    /// the interval (in milliseconds) between two reads of the new events from the outbox, every
+    /// server instance follows the outbox on its own (even when the outbox dispatcher is disabled)
+    pub poll_interval_ms: u64,
+    /// the max number of events read from the outbox each time
+    pub batch_size: i64,
+}
+
+impl EventStreamSettings {
+    /// Returns the interval between two keep-alive messages
+    pub fn keep_alive_interval(&self) -> Duration {
+        Duration::from_millis(self.keep_alive_interval_ms)
+    }
+
+    /// Returns the interval between two reads of the new events from the outbox
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+}
+
+impl Default for EventStreamSettings {
+    fn default() -> Self {
+        EventStreamSettings {
+            replay_buffer_size: 1000,
+            keep_alive_interval_ms: 15_000,
+            poll_interval_ms: 500,
+            batch_size: 100,
+        }
+    }
+}
+
+/// It contains the management endpoints settings
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct ManagementSettings {
+    /// the metrics endpoint settings
+    pub metrics: MetricsSettings,
+}
+
+/// It contains the admin access settings
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct AdminSettings {
+    /// the API key required (as bearer token) by the admin operations, when missing
+    /// the admin operations are not available
+    #[serde(skip_serializing)]
+    pub api_key: Option<SecretString>,
+}
+
+/// It contains the metrics (Prometheus text format) endpoint settings
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct MetricsSettings {
+    /// when `false` the metrics endpoint is not exposed
+    pub enabled: bool,
+    /// the metrics endpoint path
+    pub path: String,
+}
+
+impl Default for MetricsSettings {
+    fn default() -> Self {
+        MetricsSettings {
+            enabled: true,
+            path: String::from("/metrics"),
+        }
+    }
+}
+
+/// It contains the reference data (brands, railways and scales) cache settings
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct CacheSettings {
+    /// when `false` the reference data is always read from the database
+    pub enabled: bool,
+    /// the time (in milliseconds) a cached value is used before reading it again from the database
+    pub ttl_ms: u64,
+    /// the max number of cached values, for each entity type
+    pub max_entries: usize,
+}
+
+impl CacheSettings {
+    /// Returns the time a cached value is used before reading it again from the database
+    pub fn ttl(&self) -> Duration {
+        Duration::from_millis(self.ttl_ms)
+    }
+}
+
+impl Default for CacheSettings {
+    fn default() -> Self {
+        CacheSettings {
+            enabled: true,
+            ttl_ms: 60_000,
+            max_entries: 1_000,
+        }
+    }
+}
+
+/// It contains the catalog item images settings
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct ImagesSettings {
+    /// the max size (in bytes) for an uploaded image
+    pub max_size_bytes: usize,
+    /// the max number of images for each catalog item
+    pub max_images: usize,
+    /// the storage for the images content
+    pub storage: ImageStorageSettings,
+}
+
+impl Default for ImagesSettings {
+    fn default() -> Self {
+        ImagesSettings {
+            max_size_bytes: 5 * 1024 * 1024,
+            max_images: 20,
+            storage: ImageStorageSettings::default(),
+        }
+    }
+}
+
+/// It contains the storage settings for the images content
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ImageStorageSettings {
+    /// the images are stored in a local directory
+    Filesystem {
+        /// the root directory for the images
+        root: PathBuf,
+    },
+    /// the images are stored in a S3 compatible object storage
+    S3(S3Settings),
+}
+
+impl Default for ImageStorageSettings {
+    fn default() -> Self {
+        ImageStorageSettings::Filesystem {
+            root: PathBuf::from("data/images"),
+        }
+    }
+}
+
+/// It contains the settings for a S3 compatible object storage (AWS S3, MinIO...)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct S3Settings {
+    /// the storage endpoint (ie `https://s3.eu-west-1.amazonaws.com`)
+    pub endpoint: String,
+    /// the storage region
+    pub region: String,
+    /// the bucket name
+    pub bucket: String,
+    /// the access key id
+    pub access_key_id: String,
+    /// the secret access key
+    #[serde(skip_serializing)]
+    pub secret_access_key: SecretString,
+    /// when `true` the bucket is part of the path, rather than the host name (MinIO requires it)
+    #[serde(default)]
+    pub path_style: bool,
 }
 
 /// It contains the database connection settings
@@ -99,6 +520,71 @@ pub struct DatabaseSettings {
     pub max_connections: u32,
     /// the SSL mode for the connection
     pub require_ssl: bool,
+    /// when the server is applying the database migrations
+    #[serde(default)]
+    pub run_migrations: RunMigrations,
+    /// the read replica connection settings, when missing every query goes to the primary
+    #[serde(default)]
+    pub replica: Option<ReplicaSettings>,
+}
+
+/// It contains the read replica connection settings.
+/// The replica shares the credentials, the database name and the pool sizes with the primary.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReplicaSettings {
+    /// the host name
+    pub host: String,
+    /// the port number
+    pub port: u16,
+    /// the interval (in milliseconds) between two replica health checks
+    #[serde(default = "ReplicaSettings::default_health_check_interval_ms")]
+    pub health_check_interval_ms: u64,
+    /// the time (in milliseconds) the queries from a client are using the primary after the client wrote something
+    #[serde(default = "ReplicaSettings::default_pin_after_write_ms")]
+    pub pin_after_write_ms: u64,
+}
+
+impl ReplicaSettings {
+    /// Creates new replica settings, using the default health check interval
+    pub fn new(host: &str, port: u16) -> Self {
+        ReplicaSettings {
+            host: host.to_owned(),
+            port,
+            health_check_interval_ms: Self::default_health_check_interval_ms(),
+            pin_after_write_ms: Self::default_pin_after_write_ms(),
+        }
+    }
+
+    /// Returns the interval between two replica health checks
+    pub fn health_check_interval(&self) -> Duration {
+        Duration::from_millis(self.health_check_interval_ms)
+    }
+
+    /// Returns the time the queries from a client are using the primary after the client wrote something
+    pub fn pin_after_write(&self) -> Duration {
+        Duration::from_millis(self.pin_after_write_ms)
+    }
+
+    fn default_health_check_interval_ms() -> u64 {
+        5_000
+    }
+
+    fn default_pin_after_write_ms() -> u64 {
+        5_000
+    }
+}
+
+/// It represents when the server is applying the database migrations
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RunMigrations {
+    /// the migrations are applied outside the server (ie `cargo make db-migrate`)
+    #[default]
+    Never,
+    /// the pending migrations are applied when the server starts
+    OnStartup,
+    /// the server refuses to start when the database schema is behind
+    CheckOnly,
 }
 
 impl DatabaseSettings {
@@ -113,16 +599,37 @@ impl DatabaseSettings {
             min_connections: 5,
             max_connections: 10,
             require_ssl: false,
+            run_migrations: RunMigrations::Never,
+            replica: None,
         }
     }
 
     /// Creates a new postgres connection pool using the database connection settings.
     pub fn get_connection_pool(&self) -> PgPool {
+        self.pool_options().connect_lazy_with(self.pg_connection_options())
+    }
+
+    /// Creates a new postgres connection pool for the read replica, if any.
+    pub fn get_replica_connection_pool(&self) -> Option<PgPool> {
+        self.replica_connection_options()
+            .map(|options| self.pool_options().connect_lazy_with(options))
+    }
+
+    /// Returns the postgres connection options for the read replica, if any.
+    pub fn replica_connection_options(&self) -> Option<PgConnectOptions> {
+        self.replica.as_ref().map(|replica| {
+            self.pg_connection_options()
+                .application_name("trenako-replica")
+                .host(&replica.host)
+                .port(replica.port)
+        })
+    }
+
+    fn pool_options(&self) -> PgPoolOptions {
         PgPoolOptions::new()
             .min_connections(self.min_connections)
             .max_connections(self.max_connections)
-            .acquire_timeout(std::time::Duration::from_secs(2))
-            .connect_lazy_with(self.pg_connection_options())
+            .acquire_timeout(Duration::from_secs(2))
     }
 
     /// Returns the postgres connection options
@@ -159,11 +666,21 @@ mod tests {
                 server: ServerSettings {
                     host: String::from("127.0.0.1"),
                     port: 8080,
+                    ..ServerSettings::default()
                 },
                 logging: LoggingSettings {
                     level: LoggingLevel::Debug,
                     format: LoggingFormat::Full,
                 },
+                outbox: OutboxSettings::default(),
+                webhooks: WebhooksSettings::default(),
+                event_stream: EventStreamSettings::default(),
+                management: ManagementSettings::default(),
+                cache: CacheSettings::default(),
+                images: ImagesSettings::default(),
+                availability_review: AvailabilityReviewSettings::default(),
+                admin: AdminSettings::default(),
+                calendar: CalendarSettings::default(),
             };
 
             assert_eq!("127.0.0.1:8080", settings.address());
@@ -176,15 +693,168 @@ mod tests {
                 server: ServerSettings {
                     host: String::from("127.0.0.1"),
                     port: 8080,
+                    ..ServerSettings::default()
                 },
                 logging: LoggingSettings {
                     level: LoggingLevel::Debug,
                     format: LoggingFormat::Full,
                 },
+                outbox: OutboxSettings::default(),
+                webhooks: WebhooksSettings::default(),
+                event_stream: EventStreamSettings::default(),
+                management: ManagementSettings::default(),
+                cache: CacheSettings::default(),
+                images: ImagesSettings::default(),
+                availability_review: AvailabilityReviewSettings::default(),
+                admin: AdminSettings::default(),
+                calendar: CalendarSettings::default(),
             };
 
             let pg_connection_options = settings.pg_connection_options();
             assert_eq!(Some("database-name"), pg_connection_options.get_database());
         }
+
+        #[test]
+        fn it_should_return_the_outbox_poll_interval() {
+            let outbox = OutboxSettings {
+                poll_interval_ms: 250,
+                ..OutboxSettings::default()
+            };
+
+            assert_eq!(Duration::from_millis(250), outbox.poll_interval());
+        }
+
+        #[test]
+        fn it_should_return_the_outbox_claim_timeout() {
+            let outbox = OutboxSettings {
+                claim_timeout_ms: 30_000,
+                ..OutboxSettings::default()
+            };
+
+            assert_eq!(Duration::from_secs(30), outbox.claim_timeout());
+        }
+
+        #[test]
+        fn it_should_return_the_availability_review_poll_interval() {
+            let availability_review = AvailabilityReviewSettings {
+                poll_interval_ms: 60_000,
+                ..AvailabilityReviewSettings::default()
+            };
+
+            assert_eq!(Duration::from_secs(60), availability_review.poll_interval());
+        }
+
+        #[test]
+        fn it_should_load_the_settings_from_environment_variables() {
+            let variables = [
+                ("DATABASE__USERNAME", "postgres"),
+                ("DATABASE__PASSWORD", "12345"),
+                ("DATABASE__HOST", "database-host"),
+                ("DATABASE__PORT", "5432"),
+                ("DATABASE__NAME", "database-name"),
+                ("DATABASE__MIN_CONNECTIONS", "1"),
+                ("DATABASE__MAX_CONNECTIONS", "10"),
+                ("DATABASE__REQUIRE_SSL", "false"),
+                ("DATABASE__RUN_MIGRATIONS", "check_only"),
+                ("LOGGING__FORMAT", "json"),
+                ("LOGGING__LEVEL", "info"),
+                (
+                    "SERVER__CORS_ALLOWED_ORIGINS",
+                    "http://localhost:3000,https://trenako.com",
+                ),
+                ("SERVER__PUBLIC_URL", "https://trenako.com"),
+            ];
+            for (key, value) in variables {
+                std::env::set_var(key, value);
+            }
+
+            let settings = Settings::load_from_path("config/not-existing").expect("invalid settings");
+
+            for (key, _) in variables {
+                std::env::remove_var(key);
+            }
+
+            assert_eq!("12345", settings.database.password.expose_secret());
+            assert_eq!(RunMigrations::CheckOnly, settings.database.run_migrations);
+            assert_eq!(
+                vec![
+                    String::from("http://localhost:3000"),
+                    String::from("https://trenako.com")
+                ],
+                settings.server.cors_allowed_origins
+            );
+            assert_eq!(Some(String::from("https://trenako.com")), settings.server.public_url);
+            assert_eq!(ServerSettings::default().port, settings.server.port);
+            assert_eq!(None, settings.server.tls);
+        }
+
+        #[test]
+        fn it_should_return_the_server_timeouts() {
+            let server = ServerSettings {
+                request_timeout_ms: 500,
+                shutdown_timeout_ms: 1500,
+                ..ServerSettings::default()
+            };
+
+            assert_eq!(Duration::from_millis(500), server.request_timeout());
+            assert_eq!(Duration::from_millis(1500), server.shutdown_timeout());
+        }
+
+        #[test]
+        fn it_should_deserialize_the_tls_settings_with_defaults() {
+            let yaml = "cert_path: certs/cert.pem\nkey_path: certs/key.pem";
+            let tls: TlsSettings = Config::builder()
+                .add_source(File::from_str(yaml, config::FileFormat::Yaml))
+                .build()
+                .and_then(|config| config.try_deserialize())
+                .unwrap();
+
+            assert_eq!(TlsSettings::new("certs/cert.pem", "certs/key.pem"), tls);
+            assert_eq!(Duration::from_secs(60), tls.reload_interval());
+        }
+
+        #[test]
+        fn it_should_build_the_replica_connection_options() {
+            let mut database = DatabaseSettings::new("postgres", "pa$$word", "database-host", 5432, "database-name");
+            assert!(database.replica_connection_options().is_none());
+
+            database.replica = Some(ReplicaSettings::new("replica-host", 5433));
+            let options = database.replica_connection_options().expect("missing replica options");
+
+            assert_eq!("replica-host", options.get_host());
+            assert_eq!(5433, options.get_port());
+            assert_eq!(Some("database-name"), options.get_database());
+            assert_eq!("postgres", options.get_username());
+            assert_eq!(
+                Duration::from_secs(5),
+                ReplicaSettings::new("replica-host", 5433).health_check_interval()
+            );
+            assert_eq!(
+                Duration::from_secs(5),
+                ReplicaSettings::new("replica-host", 5433).pin_after_write()
+            );
+        }
+
+        #[test]
+        fn it_should_deserialize_the_image_storage_settings() {
+            let yaml = "max_images: 5\nstorage:\n  type: s3\n  endpoint: http://localhost:9000\n  region: us-east-1\n  bucket: images\n  access_key_id: minio\n  secret_access_key: minio123\n  path_style: true";
+            let images: ImagesSettings = Config::builder()
+                .add_source(File::from_str(yaml, config::FileFormat::Yaml))
+                .build()
+                .and_then(|config| config.try_deserialize())
+                .unwrap();
+
+            assert_eq!(5, images.max_images);
+            assert_eq!(ImagesSettings::default().max_size_bytes, images.max_size_bytes);
+            match images.storage {
+                ImageStorageSettings::S3(s3) => {
+                    assert_eq!("http://localhost:9000", s3.endpoint);
+                    assert_eq!("images", s3.bucket);
+                    assert_eq!("minio123", s3.secret_access_key.expose_secret());
+                    assert!(s3.path_style);
+                }
+                storage => panic!("S3 storage is expected (found: {:?})", storage),
+            }
+        }
     }
 }
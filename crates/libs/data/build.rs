@@ -0,0 +1,4 @@
+// the database migrations are embedded at compile time, any change must trigger a new build
+fn main() {
+    println!("cargo:rerun-if-changed=../../../migrations");
+}
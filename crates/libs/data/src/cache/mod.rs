@@ -0,0 +1,10 @@
+//! the in-process cache for the reference data (brands, railways and scales), which changes rarely
+//! but is read by almost every catalog request.
+//!
+//! The cached repositories are wrapping the postgres ones, the write commands are invalidating the cache
+//! for the changed entity type once they are committed. The other server instances are invalidating their
+//! caches when they follow the domain events from the outbox, and the entries expire after the time to live anyway.
+
+pub mod reference_data;
+pub mod repositories;
+pub mod ttl_cache;
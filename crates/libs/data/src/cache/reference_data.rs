@@ -0,0 +1,172 @@
+use crate::cache::ttl_cache::{CacheStats, TtlCache};
+use async_trait::async_trait;
+use catalog::brands::brand::Brand;
+use catalog::brands::brand_id::BrandId;
+use catalog::events::domain_event::{AggregateType, EventEnvelope};
+use catalog::events::event_sink::EventSink;
+use catalog::railways::railway::Railway;
+use catalog::railways::railway_id::RailwayId;
+use catalog::scales::scale::Scale;
+use catalog::scales::scale_id::ScaleId;
+use std::fmt;
+use std::hash::Hash;
+use std::time::Duration;
+
+/// The cached values for one entity type
+pub struct EntityCache<K, V> {
+    /// the entities by id
+    pub by_id: TtlCache<K, V>,
+    /// the entities list, with (`true`) or without (`false`) the soft deleted ones
+    pub all: TtlCache<bool, Vec<V>>,
+    /// the ids for the entities known to exist (and not deleted)
+    pub existing: TtlCache<K, ()>,
+}
+
+impl<K, V> EntityCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    /// Creates a new empty entity cache
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        EntityCache {
+            by_id: TtlCache::new(ttl, max_entries),
+            all: TtlCache::new(ttl, 2),
+            existing: TtlCache::new(ttl, max_entries),
+        }
+    }
+
+    /// Returns the cached entities with the given ids, together with the ids not found in the cache
+    pub fn find_by_ids(&self, ids: &[K]) -> (Vec<V>, Vec<K>) {
+        let mut found = Vec::with_capacity(ids.len());
+        let mut missing = Vec::new();
+        for id in ids {
+            match self.by_id.get(id) {
+                Some(value) => found.push(value),
+                None => missing.push(id.clone()),
+            }
+        }
+        (found, missing)
+    }
+
+    /// Stores the entities by their id
+    pub fn insert_all(&self, values: &[V], id: impl Fn(&V) -> K) {
+        for value in values {
+            self.by_id.insert(id(value), value.clone());
+        }
+    }
+
+    /// Removes all the cached values, after a change to any entity of this type
+    pub fn invalidate(&self) {
+        self.by_id.invalidate_all();
+        self.all.invalidate_all();
+        self.existing.invalidate_all();
+    }
+
+    /// Returns the statistics for all the cached values
+    pub fn stats(&self) -> CacheStats {
+        self.by_id.stats().merge(self.all.stats()).merge(self.existing.stats())
+    }
+}
+
+impl<K, V> Clone for EntityCache<K, V> {
+    fn clone(&self) -> Self {
+        EntityCache {
+            by_id: self.by_id.clone(),
+            all: self.all.clone(),
+            existing: self.existing.clone(),
+        }
+    }
+}
+
+/// The reference data cache, one instance is shared by all the cached repositories
+#[derive(Clone)]
+pub struct ReferenceDataCache {
+    pub brands: EntityCache<BrandId, Brand>,
+    pub railways: EntityCache<RailwayId, Railway>,
+    pub scales: EntityCache<ScaleId, Scale>,
+}
+
+impl ReferenceDataCache {
+    /// Creates a new empty cache, each entity type is storing up to `max_entries` values
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        ReferenceDataCache {
+            brands: EntityCache::new(ttl, max_entries),
+            railways: EntityCache::new(ttl, max_entries),
+            scales: EntityCache::new(ttl, max_entries),
+        }
+    }
+
+    /// Creates a cache which is not storing anything, every lookup is a miss
+    pub fn disabled() -> Self {
+        ReferenceDataCache::new(Duration::ZERO, 0)
+    }
+
+    /// Removes the cached values for the entity type, if it is a reference data type
+    pub fn invalidate(&self, aggregate_type: AggregateType) {
+        match aggregate_type {
+            AggregateType::Brand => self.brands.invalidate(),
+            AggregateType::Railway => self.railways.invalidate(),
+            AggregateType::Scale => self.scales.invalidate(),
+            AggregateType::CatalogItem | AggregateType::Prototype | AggregateType::RollingStock => {}
+        }
+    }
+
+    /// Returns the statistics, by entity type
+    pub fn stats(&self) -> [(&'static str, CacheStats); 3] {
+        [
+            ("brands", self.brands.stats()),
+            ("railways", self.railways.stats()),
+            ("scales", self.scales.stats()),
+        ]
+    }
+}
+
+impl fmt::Debug for ReferenceDataCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReferenceDataCache")
+            .field("brands", &self.brands.stats())
+            .field("railways", &self.railways.stats())
+            .field("scales", &self.scales.stats())
+            .finish()
+    }
+}
+
+/// Every server instance invalidates its cache when it follows the domain events from the outbox, this is
+/// how the changes made by the other instances reach the cache (and the previous values stored by a query
+/// running concurrently with the write are removed).
+#[async_trait]
+impl EventSink for ReferenceDataCache {
+    fn name(&self) -> &str {
+        "reference-data-cache"
+    }
+
+    async fn publish(&self, envelope: &EventEnvelope) -> Result<(), anyhow::Error> {
+        self.invalidate(envelope.event.aggregate_type());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use catalog::brands::brand_response::BrandDeleted;
+    use catalog::events::domain_event::DomainEvent;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn it_should_invalidate_the_changed_entity_type_when_the_events_are_followed() {
+        let cache = ReferenceDataCache::new(Duration::from_secs(60), 100);
+        cache.brands.existing.insert(BrandId::new("ACME"), ());
+        cache.scales.existing.insert(ScaleId::new("H0"), ());
+
+        let event = DomainEvent::BrandDeleted(BrandDeleted {
+            brand_id: BrandId::new("ACME"),
+            deleted_at: Utc::now(),
+        });
+        cache.publish(&EventEnvelope::new(event)).await.unwrap();
+
+        assert_eq!(0, cache.brands.stats().entries);
+        assert_eq!(1, cache.scales.stats().entries);
+    }
+}
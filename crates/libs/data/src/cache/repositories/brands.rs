@@ -0,0 +1,310 @@
+use crate::cache::repositories::CachedRepository;
+use async_trait::async_trait;
+use catalog::brands::brand::Brand;
+use catalog::brands::brand_id::BrandId;
+use catalog::brands::commands::new_brand::NewBrandCommand;
+use catalog::brands::commands::repositories::{DeleteBrandRepository, NewBrandRepository};
+use catalog::brands::queries::find_all_brands::FindAllBrandsRepository;
+use catalog::brands::queries::find_brand_by_id::FindBrandByIdRepository;
+use catalog::brands::queries::find_brands_by_ids::FindBrandsByIdsRepository;
+use chrono::{DateTime, Utc};
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::UnitOfWork;
+
+#[async_trait]
+impl<'db, U, R> FindAllBrandsRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: FindAllBrandsRepository<'db, U> + Send + Sync,
+{
+    async fn find_all(&self, include_deleted: bool, unit_of_work: &mut U) -> Result<Vec<Brand>, DatabaseError> {
+        if let Some(brands) = self.cache.brands.all.get(&include_deleted) {
+            return Ok(brands);
+        }
+
+        let brands = self.inner.find_all(include_deleted, unit_of_work).await?;
+        self.cache.brands.all.insert(include_deleted, brands.clone());
+        Ok(brands)
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> FindBrandByIdRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: FindBrandByIdRepository<'db, U> + Send + Sync,
+{
+    async fn find_by_id(&self, brand_id: &BrandId, unit_of_work: &mut U) -> Result<Option<Brand>, DatabaseError> {
+        if let Some(brand) = self.cache.brands.by_id.get(brand_id) {
+            return Ok(Some(brand));
+        }
+
+        let brand = self.inner.find_by_id(brand_id, unit_of_work).await?;
+        if let Some(brand) = &brand {
+            self.cache.brands.by_id.insert(brand_id.clone(), brand.clone());
+        }
+        Ok(brand)
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> FindBrandsByIdsRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: FindBrandsByIdsRepository<'db, U> + Send + Sync,
+{
+    async fn find_by_ids(&self, brand_ids: &[BrandId], unit_of_work: &mut U) -> Result<Vec<Brand>, DatabaseError> {
+        let (mut brands, missing) = self.cache.brands.find_by_ids(brand_ids);
+        if !missing.is_empty() {
+            let loaded = self.inner.find_by_ids(&missing, unit_of_work).await?;
+            self.cache.brands.insert_all(&loaded, |brand| brand.brand_id.clone());
+            brands.extend(loaded);
+        }
+        Ok(brands)
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> NewBrandRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: NewBrandRepository<'db, U> + Send + Sync,
+{
+    async fn exists(&self, brand_id: &BrandId, unit_of_work: &mut U) -> Result<bool, anyhow::Error> {
+        self.inner.exists(brand_id, unit_of_work).await
+    }
+
+    async fn insert(&self, new_brand: &NewBrandCommand, unit_of_work: &mut U) -> Result<(), anyhow::Error> {
+        self.inner.insert(new_brand, unit_of_work).await?;
+        self.invalidate_after_commit(|cache| cache.brands.invalidate(), unit_of_work);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> DeleteBrandRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: DeleteBrandRepository<'db, U> + Send + Sync,
+{
+    async fn is_deleted(&self, brand_id: &BrandId, unit_of_work: &mut U) -> Result<Option<bool>, anyhow::Error> {
+        self.inner.is_deleted(brand_id, unit_of_work).await
+    }
+
+    async fn soft_delete(
+        &self,
+        brand_id: &BrandId,
+        deleted_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error> {
+        let deleted = self.inner.soft_delete(brand_id, deleted_at, unit_of_work).await?;
+        if deleted {
+            self.invalidate_after_commit(|cache| cache.brands.invalidate(), unit_of_work);
+        }
+        Ok(deleted)
+    }
+
+    async fn restore(
+        &self,
+        brand_id: &BrandId,
+        last_modified_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error> {
+        let restored = self.inner.restore(brand_id, last_modified_at, unit_of_work).await?;
+        if restored {
+            self.invalidate_after_commit(|cache| cache.brands.invalidate(), unit_of_work);
+        }
+        Ok(restored)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cache::reference_data::ReferenceDataCache;
+    use common::unit_of_work::noop::NoOpUnitOfWork;
+    use common::unit_of_work::AfterCommit;
+    use pretty_assertions::assert_eq;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// A brands repository which counts the lookups
+    #[derive(Default)]
+    struct CountingBrandsRepository {
+        lookups: AtomicUsize,
+    }
+
+    impl CountingBrandsRepository {
+        fn lookups(&self) -> usize {
+            self.lookups.load(Ordering::Relaxed)
+        }
+    }
+
+    #[async_trait]
+    impl<'db, U: UnitOfWork<'db> + Send> FindBrandByIdRepository<'db, U> for &CountingBrandsRepository {
+        async fn find_by_id(&self, brand_id: &BrandId, _unit_of_work: &mut U) -> Result<Option<Brand>, DatabaseError> {
+            self.lookups.fetch_add(1, Ordering::Relaxed);
+            Ok(Some(new_brand(brand_id)))
+        }
+    }
+
+    #[async_trait]
+    impl<'db, U: UnitOfWork<'db> + Send> FindBrandsByIdsRepository<'db, U> for &CountingBrandsRepository {
+        async fn find_by_ids(&self, brand_ids: &[BrandId], _unit_of_work: &mut U) -> Result<Vec<Brand>, DatabaseError> {
+            self.lookups.fetch_add(brand_ids.len(), Ordering::Relaxed);
+            Ok(brand_ids.iter().map(new_brand).collect())
+        }
+    }
+
+    #[async_trait]
+    impl<'db, U: UnitOfWork<'db> + Send> DeleteBrandRepository<'db, U> for &CountingBrandsRepository {
+        async fn is_deleted(&self, _brand_id: &BrandId, _unit_of_work: &mut U) -> Result<Option<bool>, anyhow::Error> {
+            Ok(Some(false))
+        }
+
+        async fn soft_delete(
+            &self,
+            _brand_id: &BrandId,
+            _deleted_at: &DateTime<Utc>,
+            _unit_of_work: &mut U,
+        ) -> Result<bool, anyhow::Error> {
+            Ok(true)
+        }
+
+        async fn restore(
+            &self,
+            _brand_id: &BrandId,
+            _last_modified_at: &DateTime<Utc>,
+            _unit_of_work: &mut U,
+        ) -> Result<bool, anyhow::Error> {
+            Ok(true)
+        }
+    }
+
+    /// A unit of work which runs the after commit actions only when it is committed
+    #[derive(Default)]
+    struct DeferredUnitOfWork(Vec<AfterCommit>);
+
+    #[async_trait]
+    impl UnitOfWork<'static> for DeferredUnitOfWork {
+        async fn commit(self) -> Result<(), anyhow::Error> {
+            self.0.into_iter().for_each(|action| action());
+            Ok(())
+        }
+
+        fn after_commit(&mut self, action: AfterCommit) {
+            self.0.push(action);
+        }
+    }
+
+    fn new_brand(brand_id: &BrandId) -> Brand {
+        Brand {
+            brand_id: brand_id.clone(),
+            name: brand_id.to_string(),
+            registered_company_name: None,
+            organization_entity_type: None,
+            group_name: None,
+            description: Default::default(),
+            address: None,
+            contact_info: None,
+            kind: Default::default(),
+            status: None,
+            socials: None,
+            metadata: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_serve_the_brands_from_the_cache() {
+        let inner = CountingBrandsRepository::default();
+        let cache = ReferenceDataCache::new(Duration::from_secs(60), 100);
+        let repo = CachedRepository::new(&inner, &cache);
+        let brand_id = BrandId::new("ACME");
+
+        let first = repo
+            .find_by_id(&brand_id, &mut NoOpUnitOfWork::default())
+            .await
+            .unwrap();
+        let second = repo
+            .find_by_id(&brand_id, &mut NoOpUnitOfWork::default())
+            .await
+            .unwrap();
+
+        assert_eq!(first.map(|it| it.brand_id), second.map(|it| it.brand_id));
+        assert_eq!(1, inner.lookups());
+        assert_eq!(1, cache.brands.stats().hits);
+        assert_eq!(1, cache.brands.stats().misses);
+    }
+
+    #[tokio::test]
+    async fn it_should_load_only_the_brands_missing_from_the_cache() {
+        let inner = CountingBrandsRepository::default();
+        let cache = ReferenceDataCache::new(Duration::from_secs(60), 100);
+        let repo = CachedRepository::new(&inner, &cache);
+
+        let _ = FindBrandByIdRepository::find_by_id(&repo, &BrandId::new("ACME"), &mut NoOpUnitOfWork::default()).await;
+        let brands = repo
+            .find_by_ids(
+                &[BrandId::new("ACME"), BrandId::new("Roco")],
+                &mut NoOpUnitOfWork::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(2, brands.len());
+        assert_eq!(2, inner.lookups());
+    }
+
+    #[tokio::test]
+    async fn it_should_invalidate_the_cache_when_a_brand_is_deleted() {
+        let inner = CountingBrandsRepository::default();
+        let cache = ReferenceDataCache::new(Duration::from_secs(60), 100);
+        let repo = CachedRepository::new(&inner, &cache);
+        let brand_id = BrandId::new("ACME");
+
+        let _ = repo.find_by_id(&brand_id, &mut NoOpUnitOfWork::default()).await;
+        repo.soft_delete(&brand_id, &Utc::now(), &mut NoOpUnitOfWork::default())
+            .await
+            .unwrap();
+        let _ = repo.find_by_id(&brand_id, &mut NoOpUnitOfWork::default()).await;
+
+        assert_eq!(2, inner.lookups());
+    }
+
+    #[tokio::test]
+    async fn it_should_invalidate_the_cache_only_after_the_commit() {
+        let inner = CountingBrandsRepository::default();
+        let cache = ReferenceDataCache::new(Duration::from_secs(60), 100);
+        let repo = CachedRepository::new(&inner, &cache);
+        let brand_id = BrandId::new("ACME");
+
+        let _ = repo.find_by_id(&brand_id, &mut NoOpUnitOfWork::default()).await;
+
+        let mut unit_of_work = DeferredUnitOfWork::default();
+        repo.soft_delete(&brand_id, &Utc::now(), &mut unit_of_work)
+            .await
+            .unwrap();
+        assert_eq!(1, cache.brands.stats().entries);
+
+        unit_of_work.commit().await.unwrap();
+        assert_eq!(0, cache.brands.stats().entries);
+    }
+
+    #[tokio::test]
+    async fn it_should_not_invalidate_the_cache_when_the_write_is_not_committed() {
+        let inner = CountingBrandsRepository::default();
+        let cache = ReferenceDataCache::new(Duration::from_secs(60), 100);
+        let repo = CachedRepository::new(&inner, &cache);
+        let brand_id = BrandId::new("ACME");
+
+        let _ = repo.find_by_id(&brand_id, &mut NoOpUnitOfWork::default()).await;
+
+        let mut unit_of_work = DeferredUnitOfWork::default();
+        repo.soft_delete(&brand_id, &Utc::now(), &mut unit_of_work)
+            .await
+            .unwrap();
+        drop(unit_of_work);
+
+        assert_eq!(1, cache.brands.stats().entries);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::cache::repositories::CachedRepository;
+use async_trait::async_trait;
+use catalog::brands::brand_id::BrandId;
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::catalog_items::commands::new_catalog_item::{NewCatalogItemCommand, NewRollingStockCommand};
+use catalog::catalog_items::commands::repositories::{NewCatalogItemRepository, NewRollingStockRepository};
+use catalog::prototypes::prototype_id::PrototypeId;
+use catalog::railways::railway_id::RailwayId;
+use catalog::scales::scale_id::ScaleId;
+use common::unit_of_work::UnitOfWork;
+
+/// Only the existing reference data is cached, a missing one could be created at any moment
+#[async_trait]
+impl<'db, U, R> NewCatalogItemRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: NewCatalogItemRepository<'db, U> + Send + Sync,
+{
+    async fn exists(&self, catalog_item_id: &CatalogItemId, unit_of_work: &mut U) -> Result<bool, anyhow::Error> {
+        self.inner.exists(catalog_item_id, unit_of_work).await
+    }
+
+    async fn insert(&self, new_item: &NewCatalogItemCommand, unit_of_work: &mut U) -> Result<(), anyhow::Error> {
+        self.inner.insert(new_item, unit_of_work).await
+    }
+
+    async fn brand_exists(&self, brand_id: &BrandId, unit_of_work: &mut U) -> Result<bool, anyhow::Error> {
+        if self.cache.brands.existing.get(brand_id).is_some() {
+            return Ok(true);
+        }
+
+        let exists = self.inner.brand_exists(brand_id, unit_of_work).await?;
+        if exists {
+            self.cache.brands.existing.insert(brand_id.clone(), ());
+        }
+        Ok(exists)
+    }
+
+    async fn scale_exists(&self, scale_id: &ScaleId, unit_of_work: &mut U) -> Result<bool, anyhow::Error> {
+        if self.cache.scales.existing.get(scale_id).is_some() {
+            return Ok(true);
+        }
+
+        let exists = self.inner.scale_exists(scale_id, unit_of_work).await?;
+        if exists {
+            self.cache.scales.existing.insert(scale_id.clone(), ());
+        }
+        Ok(exists)
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> NewRollingStockRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: NewRollingStockRepository<'db, U> + Send + Sync,
+{
+    async fn insert(&self, new_item: &NewRollingStockCommand, unit_of_work: &mut U) -> Result<(), anyhow::Error> {
+        self.inner.insert(new_item, unit_of_work).await
+    }
+
+    async fn railway_exists(&self, railway_id: &RailwayId, unit_of_work: &mut U) -> Result<bool, anyhow::Error> {
+        if self.cache.railways.existing.get(railway_id).is_some() {
+            return Ok(true);
+        }
+
+        let exists = self.inner.railway_exists(railway_id, unit_of_work).await?;
+        if exists {
+            self.cache.railways.existing.insert(railway_id.clone(), ());
+        }
+        Ok(exists)
+    }
+    async fn prototype_exists(&self, prototype_id: &PrototypeId, unit_of_work: &mut U) -> Result<bool, anyhow::Error> {
+        self.inner.prototype_exists(prototype_id, unit_of_work).await
+    }
+}
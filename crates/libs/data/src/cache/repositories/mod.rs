@@ -0,0 +1,38 @@
+//! the cached repositories, they are wrapping another repository implementation
+
+mod brands;
+mod catalog_items;
+mod railways;
+mod scales;
+
+use crate::cache::reference_data::ReferenceDataCache;
+use common::unit_of_work::UnitOfWork;
+
+/// A repository which uses the reference data cache, delegating to the `inner` repository
+/// for the cache misses and the write operations.
+#[derive(Clone)]
+pub struct CachedRepository<R> {
+    inner: R,
+    cache: ReferenceDataCache,
+}
+
+impl<R> CachedRepository<R> {
+    /// Wraps the repository with the reference data cache
+    pub fn new(inner: R, cache: &ReferenceDataCache) -> Self {
+        CachedRepository {
+            inner,
+            cache: cache.clone(),
+        }
+    }
+
+    /// Invalidates the cache once the write is committed, a query running before the commit would
+    /// store the previous values again
+    fn invalidate_after_commit<'db, U, F>(&self, invalidate: F, unit_of_work: &mut U)
+    where
+        U: UnitOfWork<'db>,
+        F: FnOnce(&ReferenceDataCache) + Send + Sync + 'static,
+    {
+        let cache = self.cache.clone();
+        unit_of_work.after_commit(Box::new(move || invalidate(&cache)));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::cache::repositories::CachedRepository;
+use async_trait::async_trait;
+use catalog::railways::commands::new_railways::NewRailwayCommand;
+use catalog::railways::commands::repositories::{DeleteRailwayRepository, NewRailwayRepository};
+use catalog::railways::queries::find_all_railways::FindAllRailwaysRepository;
+use catalog::railways::queries::find_railway_by_id::FindRailwayByIdRepository;
+use catalog::railways::queries::find_railways_by_ids::FindRailwaysByIdsRepository;
+use catalog::railways::queries::find_vehicle_keeper::FindVehicleKeeperRepository;
+use catalog::railways::railway::Railway;
+use catalog::railways::railway_id::RailwayId;
+use chrono::{DateTime, NaiveDate, Utc};
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::UnitOfWork;
+
+#[async_trait]
+impl<'db, U, R> FindAllRailwaysRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: FindAllRailwaysRepository<'db, U> + Send + Sync,
+{
+    async fn find_all(&self, include_deleted: bool, unit_of_work: &mut U) -> Result<Vec<Railway>, DatabaseError> {
+        if let Some(railways) = self.cache.railways.all.get(&include_deleted) {
+            return Ok(railways);
+        }
+
+        let railways = self.inner.find_all(include_deleted, unit_of_work).await?;
+        self.cache.railways.all.insert(include_deleted, railways.clone());
+        Ok(railways)
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> FindRailwayByIdRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: FindRailwayByIdRepository<'db, U> + Send + Sync,
+{
+    async fn find_by_id(&self, railway_id: &RailwayId, unit_of_work: &mut U) -> Result<Option<Railway>, DatabaseError> {
+        if let Some(railway) = self.cache.railways.by_id.get(railway_id) {
+            return Ok(Some(railway));
+        }
+
+        let railway = self.inner.find_by_id(railway_id, unit_of_work).await?;
+        if let Some(railway) = &railway {
+            self.cache.railways.by_id.insert(railway_id.clone(), railway.clone());
+        }
+        Ok(railway)
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> FindRailwaysByIdsRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: FindRailwaysByIdsRepository<'db, U> + Send + Sync,
+{
+    async fn find_by_ids(
+        &self,
+        railway_ids: &[RailwayId],
+        unit_of_work: &mut U,
+    ) -> Result<Vec<Railway>, DatabaseError> {
+        let (mut railways, missing) = self.cache.railways.find_by_ids(railway_ids);
+        if !missing.is_empty() {
+            let loaded = self.inner.find_by_ids(&missing, unit_of_work).await?;
+            self.cache
+                .railways
+                .insert_all(&loaded, |railway| railway.railway_id.clone());
+            railways.extend(loaded);
+        }
+        Ok(railways)
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> FindVehicleKeeperRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: FindVehicleKeeperRepository<'db, U> + Send + Sync,
+{
+    async fn find_vehicle_keeper(
+        &self,
+        uic_country_code: u8,
+        vehicle_keeper_marking: &str,
+        date: Option<NaiveDate>,
+        unit_of_work: &mut U,
+    ) -> Result<Option<RailwayId>, DatabaseError> {
+        self.inner
+            .find_vehicle_keeper(uic_country_code, vehicle_keeper_marking, date, unit_of_work)
+            .await
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> NewRailwayRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: NewRailwayRepository<'db, U> + Send + Sync,
+{
+    async fn exists(&self, railway_id: &RailwayId, unit_of_work: &mut U) -> Result<bool, anyhow::Error> {
+        self.inner.exists(railway_id, unit_of_work).await
+    }
+
+    async fn insert(&self, new_railway: &NewRailwayCommand, unit_of_work: &mut U) -> Result<(), anyhow::Error> {
+        self.inner.insert(new_railway, unit_of_work).await?;
+        self.invalidate_after_commit(|cache| cache.railways.invalidate(), unit_of_work);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> DeleteRailwayRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: DeleteRailwayRepository<'db, U> + Send + Sync,
+{
+    async fn is_deleted(&self, railway_id: &RailwayId, unit_of_work: &mut U) -> Result<Option<bool>, anyhow::Error> {
+        self.inner.is_deleted(railway_id, unit_of_work).await
+    }
+
+    async fn soft_delete(
+        &self,
+        railway_id: &RailwayId,
+        deleted_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error> {
+        let deleted = self.inner.soft_delete(railway_id, deleted_at, unit_of_work).await?;
+        if deleted {
+            self.invalidate_after_commit(|cache| cache.railways.invalidate(), unit_of_work);
+        }
+        Ok(deleted)
+    }
+
+    async fn restore(
+        &self,
+        railway_id: &RailwayId,
+        last_modified_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error> {
+        let restored = self.inner.restore(railway_id, last_modified_at, unit_of_work).await?;
+        if restored {
+            self.invalidate_after_commit(|cache| cache.railways.invalidate(), unit_of_work);
+        }
+        Ok(restored)
+    }
+}
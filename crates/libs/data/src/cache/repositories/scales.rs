@@ -0,0 +1,119 @@
+use crate::cache::repositories::CachedRepository;
+use async_trait::async_trait;
+use catalog::scales::commands::new_scales::NewScaleCommand;
+use catalog::scales::commands::repositories::{DeleteScaleRepository, NewScaleRepository};
+use catalog::scales::queries::find_all_scales::FindAllScalesRepository;
+use catalog::scales::queries::find_scale_by_id::FindScaleByIdRepository;
+use catalog::scales::queries::find_scales_by_ids::FindScalesByIdsRepository;
+use catalog::scales::scale::Scale;
+use catalog::scales::scale_id::ScaleId;
+use chrono::{DateTime, Utc};
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::UnitOfWork;
+
+#[async_trait]
+impl<'db, U, R> FindAllScalesRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: FindAllScalesRepository<'db, U> + Send + Sync,
+{
+    async fn find_all(&self, include_deleted: bool, unit_of_work: &mut U) -> Result<Vec<Scale>, DatabaseError> {
+        if let Some(scales) = self.cache.scales.all.get(&include_deleted) {
+            return Ok(scales);
+        }
+
+        let scales = self.inner.find_all(include_deleted, unit_of_work).await?;
+        self.cache.scales.all.insert(include_deleted, scales.clone());
+        Ok(scales)
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> FindScaleByIdRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: FindScaleByIdRepository<'db, U> + Send + Sync,
+{
+    async fn find_by_id(&self, scale_id: &ScaleId, unit_of_work: &mut U) -> Result<Option<Scale>, DatabaseError> {
+        if let Some(scale) = self.cache.scales.by_id.get(scale_id) {
+            return Ok(Some(scale));
+        }
+
+        let scale = self.inner.find_by_id(scale_id, unit_of_work).await?;
+        if let Some(scale) = &scale {
+            self.cache.scales.by_id.insert(scale_id.clone(), scale.clone());
+        }
+        Ok(scale)
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> FindScalesByIdsRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: FindScalesByIdsRepository<'db, U> + Send + Sync,
+{
+    async fn find_by_ids(&self, scale_ids: &[ScaleId], unit_of_work: &mut U) -> Result<Vec<Scale>, DatabaseError> {
+        let (mut scales, missing) = self.cache.scales.find_by_ids(scale_ids);
+        if !missing.is_empty() {
+            let loaded = self.inner.find_by_ids(&missing, unit_of_work).await?;
+            self.cache.scales.insert_all(&loaded, |scale| scale.scale_id.clone());
+            scales.extend(loaded);
+        }
+        Ok(scales)
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> NewScaleRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: NewScaleRepository<'db, U> + Send + Sync,
+{
+    async fn exists(&self, scale_id: &ScaleId, unit_of_work: &mut U) -> Result<bool, anyhow::Error> {
+        self.inner.exists(scale_id, unit_of_work).await
+    }
+
+    async fn insert(&self, new_scale: &NewScaleCommand, unit_of_work: &mut U) -> Result<(), anyhow::Error> {
+        self.inner.insert(new_scale, unit_of_work).await?;
+        self.invalidate_after_commit(|cache| cache.scales.invalidate(), unit_of_work);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<'db, U, R> DeleteScaleRepository<'db, U> for CachedRepository<R>
+where
+    U: UnitOfWork<'db> + Send,
+    R: DeleteScaleRepository<'db, U> + Send + Sync,
+{
+    async fn is_deleted(&self, scale_id: &ScaleId, unit_of_work: &mut U) -> Result<Option<bool>, anyhow::Error> {
+        self.inner.is_deleted(scale_id, unit_of_work).await
+    }
+
+    async fn soft_delete(
+        &self,
+        scale_id: &ScaleId,
+        deleted_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error> {
+        let deleted = self.inner.soft_delete(scale_id, deleted_at, unit_of_work).await?;
+        if deleted {
+            self.invalidate_after_commit(|cache| cache.scales.invalidate(), unit_of_work);
+        }
+        Ok(deleted)
+    }
+
+    async fn restore(
+        &self,
+        scale_id: &ScaleId,
+        last_modified_at: &DateTime<Utc>,
+        unit_of_work: &mut U,
+    ) -> Result<bool, anyhow::Error> {
+        let restored = self.inner.restore(scale_id, last_modified_at, unit_of_work).await?;
+        if restored {
+            self.invalidate_after_commit(|cache| cache.scales.invalidate(), unit_of_work);
+        }
+        Ok(restored)
+    }
+}
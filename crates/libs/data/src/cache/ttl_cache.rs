@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// An in-process cache, the entries expire after the time to live.
+///
+/// When the cache is full the expired entries are removed first, then the oldest one.
+/// A cache with no room for entries (`max_entries` is `0`) is not storing anything.
+pub struct TtlCache<K, V> {
+    inner: Arc<TtlCacheInner<K, V>>,
+}
+
+struct TtlCacheInner<K, V> {
+    entries: Mutex<HashMap<K, Entry<V>>>,
+    ttl: Duration,
+    max_entries: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+struct Entry<V> {
+    value: V,
+    inserted_at: Instant,
+}
+
+/// The cache statistics
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// the number of lookups served by the cache
+    pub hits: u64,
+    /// the number of lookups for missing (or expired) entries
+    pub misses: u64,
+    /// the number of entries in the cache
+    pub entries: usize,
+}
+
+impl CacheStats {
+    /// Sums two cache statistics
+    pub fn merge(self, other: CacheStats) -> CacheStats {
+        CacheStats {
+            hits: self.hits + other.hits,
+            misses: self.misses + other.misses,
+            entries: self.entries + other.entries,
+        }
+    }
+}
+
+impl<K, V> TtlCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    /// Creates a new empty cache
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        TtlCache {
+            inner: Arc::new(TtlCacheInner {
+                entries: Mutex::new(HashMap::new()),
+                ttl,
+                max_entries,
+                hits: AtomicU64::new(0),
+                misses: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Returns the value for the key, if present and not expired
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut entries = self.inner.entries.lock().expect("unable to acquire the cache lock");
+        let result = match entries.get(key) {
+            Some(entry) if entry.inserted_at.elapsed() < self.inner.ttl => Some(entry.value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        };
+
+        let counter = if result.is_some() {
+            &self.inner.hits
+        } else {
+            &self.inner.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        result
+    }
+
+    /// Stores the value for the key, replacing the previous one
+    pub fn insert(&self, key: K, value: V) {
+        if self.inner.max_entries == 0 {
+            return;
+        }
+
+        let mut entries = self.inner.entries.lock().expect("unable to acquire the cache lock");
+        if !entries.contains_key(&key) && entries.len() >= self.inner.max_entries {
+            let ttl = self.inner.ttl;
+            entries.retain(|_, entry| entry.inserted_at.elapsed() < ttl);
+        }
+        if !entries.contains_key(&key) && entries.len() >= self.inner.max_entries {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.inserted_at)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+
+        entries.insert(
+            key,
+            Entry {
+                value,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Removes all the entries
+    pub fn invalidate_all(&self) {
+        let mut entries = self.inner.entries.lock().expect("unable to acquire the cache lock");
+        entries.clear();
+    }
+
+    /// Returns the cache statistics
+    pub fn stats(&self) -> CacheStats {
+        let entries = self.inner.entries.lock().expect("unable to acquire the cache lock");
+        CacheStats {
+            hits: self.inner.hits.load(Ordering::Relaxed),
+            misses: self.inner.misses.load(Ordering::Relaxed),
+            entries: entries.len(),
+        }
+    }
+}
+
+impl<K, V> Clone for TtlCache<K, V> {
+    fn clone(&self) -> Self {
+        TtlCache {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_count_hits_and_misses() {
+        let cache = TtlCache::new(Duration::from_secs(60), 10);
+        cache.insert("key", 42);
+
+        assert_eq!(Some(42), cache.get(&"key"));
+        assert_eq!(None, cache.get(&"other"));
+        assert_eq!(
+            CacheStats {
+                hits: 1,
+                misses: 1,
+                entries: 1
+            },
+            cache.stats()
+        );
+    }
+
+    #[test]
+    fn it_should_expire_the_entries_after_the_time_to_live() {
+        let cache = TtlCache::new(Duration::from_millis(10), 10);
+        cache.insert("key", 42);
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(None, cache.get(&"key"));
+        assert_eq!(0, cache.stats().entries);
+    }
+
+    #[test]
+    fn it_should_evict_the_oldest_entry_when_full() {
+        let cache = TtlCache::new(Duration::from_secs(60), 2);
+        cache.insert("first", 1);
+        std::thread::sleep(Duration::from_millis(1));
+        cache.insert("second", 2);
+        std::thread::sleep(Duration::from_millis(1));
+        cache.insert("third", 3);
+
+        assert_eq!(None, cache.get(&"first"));
+        assert_eq!(Some(2), cache.get(&"second"));
+        assert_eq!(Some(3), cache.get(&"third"));
+    }
+
+    #[test]
+    fn it_should_not_store_anything_without_room_for_entries() {
+        let cache = TtlCache::new(Duration::from_secs(60), 0);
+        cache.insert("key", 42);
+
+        assert_eq!(None, cache.get(&"key"));
+    }
+
+    #[test]
+    fn it_should_invalidate_all_the_entries() {
+        let cache = TtlCache::new(Duration::from_secs(60), 10);
+        cache.insert("key", 42);
+        cache.invalidate_all();
+
+        assert_eq!(None, cache.get(&"key"));
+    }
+}
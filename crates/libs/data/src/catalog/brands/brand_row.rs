@@ -36,6 +36,7 @@ pub struct BrandRow {
     pub version: i32,
     pub created_at: DateTime<Utc>,
     pub last_modified_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 #[cfg(test)]
@@ -73,6 +74,7 @@ pub mod test {
             version: 0,
             created_at,
             last_modified_at: None,
+            deleted_at: None,
         }
     }
 }
@@ -6,9 +6,11 @@ use catalog::brands::brand_id::BrandId;
 use catalog::brands::brand_kind::BrandKind;
 use catalog::brands::brand_status::BrandStatus;
 use catalog::brands::commands::new_brand::NewBrandCommand;
-use catalog::brands::commands::repositories::NewBrandRepository;
+use catalog::brands::commands::repositories::{DeleteBrandRepository, NewBrandRepository};
 use catalog::brands::queries::find_all_brands::FindAllBrandsRepository;
 use catalog::brands::queries::find_brand_by_id::FindBrandByIdRepository;
+use catalog::brands::queries::find_brands_by_ids::FindBrandsByIdsRepository;
+use chrono::{DateTime, Utc};
 use common::contacts::WebsiteUrl;
 use common::contacts::{MailAddress, PhoneNumber};
 use common::organizations::OrganizationEntityType;
@@ -100,7 +102,11 @@ impl<'db> NewBrandRepository<'db, PgUnitOfWork<'db>> for BrandsRepository {
 
 #[async_trait]
 impl<'db> FindAllBrandsRepository<'db, PgUnitOfWork<'db>> for BrandsRepository {
-    async fn find_all(&self, unit_of_work: &mut PgUnitOfWork) -> Result<Vec<Brand>, DatabaseError> {
+    async fn find_all(
+        &self,
+        include_deleted: bool,
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<Vec<Brand>, DatabaseError> {
         let results: Vec<BrandRow> = sqlx::query_as!(BrandRow,
                 r#"SELECT
                     brand_id as "brand_id!: BrandId", 
@@ -125,9 +131,12 @@ impl<'db> FindAllBrandsRepository<'db, PgUnitOfWork<'db>> for BrandsRepository {
                     socials_youtube as "socials_youtube?: Handler",
                     created_at,
                     last_modified_at,
+                    deleted_at,
                     version
                 FROM brands
-                ORDER BY name"#)
+                WHERE $1 OR deleted_at IS NULL
+                ORDER BY name"#,
+                include_deleted)
             .fetch_all(&mut *unit_of_work.transaction)
             .await
             .context("A database failure was encountered while trying to fetch the brands.")?;
@@ -172,6 +181,7 @@ impl<'db> FindBrandByIdRepository<'db, PgUnitOfWork<'db>> for BrandsRepository {
                     socials_youtube as "socials_youtube?: Handler",
                     created_at,
                     last_modified_at,
+                    deleted_at,
                     version
                 FROM brands WHERE brand_id = $1"#, 
                 brand_id)
@@ -182,3 +192,100 @@ impl<'db> FindBrandByIdRepository<'db, PgUnitOfWork<'db>> for BrandsRepository {
         result.to_output().map_err(DatabaseError::ConversionError)
     }
 }
+
+#[async_trait]
+impl<'db> FindBrandsByIdsRepository<'db, PgUnitOfWork<'db>> for BrandsRepository {
+    async fn find_by_ids(
+        &self,
+        brand_ids: &[BrandId],
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<Vec<Brand>, DatabaseError> {
+        let brand_ids_param: Vec<String> = brand_ids.iter().map(|id| id.to_string()).collect();
+        let result: Vec<BrandRow> = sqlx::query_as!(BrandRow,
+                r#"SELECT
+                    brand_id as "brand_id!: BrandId", 
+                    name, registered_company_name, 
+                    organization_entity_type as "organization_entity_type: OrganizationEntityType", 
+                    group_name, 
+                    description_de, 
+                    description_en,
+                    description_fr, 
+                    description_it,
+                    kind as "kind: BrandKind", 
+                    status as "status?: BrandStatus",
+                    contact_email as "contact_email?: MailAddress", 
+                    contact_website_url as "contact_website_url?: WebsiteUrl", 
+                    contact_phone as "contact_phone?: PhoneNumber",
+                    address_street_address, address_extended_address, address_city, address_region, address_postal_code, address_country,
+                    socials_facebook as "socials_facebook?: Handler", 
+                    socials_instagram as "socials_instagram?: Handler",     
+                    socials_linkedin as "socials_linkedin?: Handler",    
+                    socials_twitter as "socials_twitter?: Handler",    
+                    socials_youtube as "socials_youtube?: Handler",
+                    created_at,
+                    last_modified_at,
+                    deleted_at,
+                    version
+                FROM brands WHERE brand_id = ANY($1)"#, 
+                &brand_ids_param[..])
+            .fetch_all(&mut *unit_of_work.transaction)
+            .await
+            .context("A database failure was encountered while trying to fetch the brands.")?;
+
+        result.to_output().map_err(DatabaseError::ConversionError)
+    }
+}
+
+#[async_trait]
+impl<'db> DeleteBrandRepository<'db, PgUnitOfWork<'db>> for BrandsRepository {
+    async fn is_deleted(
+        &self,
+        brand_id: &BrandId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Option<bool>, anyhow::Error> {
+        let result = sqlx::query!("SELECT deleted_at FROM brands WHERE brand_id = $1", brand_id)
+            .fetch_optional(&mut *unit_of_work.transaction)
+            .await
+            .context("A database failure was encountered while trying to check for a brand deletion.")?;
+
+        Ok(result.map(|row| row.deleted_at.is_some()))
+    }
+
+    async fn soft_delete(
+        &self,
+        brand_id: &BrandId,
+        deleted_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            r#"UPDATE brands SET deleted_at = $2, last_modified_at = $2, version = version + 1
+            WHERE brand_id = $1 AND deleted_at IS NULL"#,
+            brand_id,
+            deleted_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to delete a brand.")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn restore(
+        &self,
+        brand_id: &BrandId,
+        last_modified_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            r#"UPDATE brands SET deleted_at = NULL, last_modified_at = $2, version = version + 1
+            WHERE brand_id = $1 AND deleted_at IS NOT NULL"#,
+            brand_id,
+            last_modified_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to restore a brand.")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,94 @@
+//! the catalog item availability rows definition
+
+use catalog::catalog_items::availability::{Availability, AvailabilityChange};
+use catalog::catalog_items::availability_status::AvailabilityStatus;
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::catalog_items::delivery_date::DeliveryDate;
+use chrono::{DateTime, Utc};
+use common::queries::converters::{ConversionErrors, ToOutputConverter};
+use std::str::FromStr;
+
+/// It represents the catalog item availability row definition
+#[derive(Debug)]
+pub struct AvailabilityRow {
+    pub catalog_item_id: CatalogItemId,
+    pub availability_status: Option<AvailabilityStatus>,
+    pub delivery_date: Option<String>,
+    pub review_requested_at: Option<DateTime<Utc>>,
+}
+
+/// It represents the catalog item availability history row definition
+#[derive(Debug)]
+pub struct AvailabilityChangeRow {
+    pub status: AvailabilityStatus,
+    pub previous_status: Option<AvailabilityStatus>,
+    pub note: Option<String>,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl ToOutputConverter<Availability> for AvailabilityRow {
+    fn to_output(self) -> Result<Availability, ConversionErrors> {
+        let delivery_date = self
+            .delivery_date
+            .as_deref()
+            .map(DeliveryDate::from_str)
+            .transpose()
+            .map_err(|_| ConversionErrors::new())?;
+
+        Ok(Availability::new(
+            self.catalog_item_id,
+            self.availability_status,
+            delivery_date,
+            self.review_requested_at,
+        ))
+    }
+}
+
+impl ToOutputConverter<AvailabilityChange> for AvailabilityChangeRow {
+    fn to_output(self) -> Result<AvailabilityChange, ConversionErrors> {
+        Ok(AvailabilityChange {
+            status: self.status,
+            previous_status: self.previous_status,
+            note: self.note,
+            changed_at: self.changed_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod availability_row_converters {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_convert_availability_rows() {
+            let row = AvailabilityRow {
+                catalog_item_id: CatalogItemId::from_str("acme-60142").unwrap(),
+                availability_status: Some(AvailabilityStatus::Announced),
+                delivery_date: Some(String::from("2026/Q4")),
+                review_requested_at: None,
+            };
+
+            let availability = row.to_output().expect("the availability");
+
+            assert_eq!(Some(AvailabilityStatus::Announced), availability.status);
+            assert_eq!(Some(DeliveryDate::by_quarter(2026, 4)), availability.delivery_date);
+            assert!(availability.history.is_empty());
+        }
+
+        #[test]
+        fn it_should_fail_to_convert_availability_rows_with_invalid_delivery_dates() {
+            let row = AvailabilityRow {
+                catalog_item_id: CatalogItemId::from_str("acme-60142").unwrap(),
+                availability_status: Some(AvailabilityStatus::Announced),
+                delivery_date: Some(String::from("not a date")),
+                review_requested_at: None,
+            };
+
+            assert!(row.to_output().is_err());
+        }
+    }
+}
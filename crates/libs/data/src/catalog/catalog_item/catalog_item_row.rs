@@ -34,6 +34,7 @@ pub struct CatalogItemRow {
     pub version: i32,
     pub created_at: DateTime<Utc>,
     pub last_modified_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 #[cfg(test)]
@@ -74,6 +75,7 @@ pub mod test {
             version: 0,
             created_at,
             last_modified_at: None,
+            deleted_at: None,
         }
     }
 }
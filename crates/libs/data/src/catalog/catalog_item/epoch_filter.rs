@@ -0,0 +1,83 @@
+//! the epoch filters for the catalog items queries
+
+use catalog::catalog_items::epoch::EpochRange;
+use catalog::catalog_items::epoch_calendar::EpochCalendar;
+
+/// It represents the epoch ranges valid in a given year.
+///
+/// The range depends on the railway country: there is one range for every country with its own epoch
+/// calendar, while the railways from any other country use the European calendar range.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct EpochFilter {
+    /// the countries (alpha2 codes) with a range for the year
+    pub countries: Vec<String>,
+    /// the first slot of the range, one for every value in `countries`
+    pub starts: Vec<i16>,
+    /// the last slot of the range, one for every value in `countries`
+    pub ends: Vec<i16>,
+    /// the countries with their own epoch calendar
+    pub calendar_countries: Vec<String>,
+    /// the range in the European calendar
+    pub european: Option<EpochRange>,
+}
+
+impl EpochFilter {
+    /// Creates the filter for the epochs valid in the `year`
+    pub fn in_year(year: i32) -> Self {
+        let mut filter = EpochFilter::default();
+
+        for calendar in EpochCalendar::country_calendars() {
+            let Some(country) = calendar.country else {
+                continue;
+            };
+
+            filter.calendar_countries.push(country.alpha2().to_owned());
+            if let Some(range) = calendar.range_at(year) {
+                filter.countries.push(country.alpha2().to_owned());
+                filter.starts.push(range.start());
+                filter.ends.push(range.end());
+            }
+        }
+
+        filter.european = EpochCalendar::european().range_at(year);
+        filter
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod epoch_filters {
+        use super::*;
+        use catalog::catalog_items::epoch::Epoch;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_create_the_filter_for_a_year() {
+            let filter = EpochFilter::in_year(1965);
+
+            assert_eq!(filter.countries.len(), filter.starts.len());
+            assert_eq!(filter.countries.len(), filter.ends.len());
+            assert!(filter.calendar_countries.contains(&String::from("IT")));
+            assert_eq!(Some(Epoch::III.range()), filter.european);
+
+            let position = filter
+                .countries
+                .iter()
+                .position(|country| country.as_str() == "IT")
+                .expect("the italian calendar has a range for the year");
+            assert_eq!(Epoch::IIIc.range().start(), filter.starts[position]);
+            assert_eq!(Epoch::IIIc.range().end(), filter.ends[position]);
+        }
+
+        #[test]
+        fn it_should_create_an_empty_filter_for_years_before_the_first_epoch() {
+            let filter = EpochFilter::in_year(1800);
+
+            assert!(filter.countries.is_empty());
+            assert_eq!(None, filter.european);
+            assert!(!filter.calendar_countries.is_empty());
+        }
+    }
+}
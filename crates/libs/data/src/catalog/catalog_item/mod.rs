@@ -1,6 +1,8 @@
 //! the persistence layer for catalog items
 
-mod catalog_item_row;
+mod availability_row;
+pub(crate) mod catalog_item_row;
+mod epoch_filter;
 pub mod repositories;
 mod rolling_stock_row;
 mod row_converters;
@@ -1,8 +1,11 @@
+use crate::catalog::catalog_item::availability_row::{AvailabilityChangeRow, AvailabilityRow};
 use crate::catalog::catalog_item::catalog_item_row::CatalogItemRow;
+use crate::catalog::catalog_item::epoch_filter::EpochFilter;
 use crate::catalog::catalog_item::rolling_stock_row::RollingStockRow;
 use anyhow::Context;
 use async_trait::async_trait;
 use catalog::brands::brand_id::BrandId;
+use catalog::catalog_items::availability::{Availability, AvailabilityChange};
 use catalog::catalog_items::availability_status::AvailabilityStatus;
 use catalog::catalog_items::catalog_item::CatalogItem;
 use catalog::catalog_items::catalog_item_id::CatalogItemId;
@@ -11,21 +14,35 @@ use catalog::catalog_items::category::{
     RollingStockCategory,
 };
 use catalog::catalog_items::commands::new_catalog_item::{NewCatalogItemCommand, NewRollingStockCommand};
-use catalog::catalog_items::commands::repositories::{NewCatalogItemRepository, NewRollingStockRepository};
+use catalog::catalog_items::commands::repositories::{
+    AvailabilityRepository, DeleteCatalogItemRepository, DeleteRollingStockRepository, NewCatalogItemRepository,
+    NewRollingStockRepository,
+};
 use catalog::catalog_items::control::{Control, DccInterface};
+use catalog::catalog_items::delivery_date::{DeliveryDate, Year};
+use catalog::catalog_items::epoch::Epoch;
 use catalog::catalog_items::power_method::PowerMethod;
+use catalog::catalog_items::queries::find_availability::FindAvailabilityRepository;
 use catalog::catalog_items::queries::find_catalog_item_by_id::{
     FindCatalogItemByIdRepository, FindRollingStocksByCatalogItemIdRepository,
 };
+use catalog::catalog_items::queries::find_latest_catalog_items::{
+    FindLatestCatalogItemsRepository, LatestCatalogItemsCriteria,
+};
+use catalog::catalog_items::queries::find_rolling_stocks_by_catalog_item_ids::FindRollingStocksByCatalogItemIdsRepository;
+use catalog::catalog_items::queries::find_upcoming_releases::FindUpcomingReleasesRepository;
 use catalog::catalog_items::rolling_stock::RollingStock;
 use catalog::catalog_items::rolling_stock_id::RollingStockId;
 use catalog::catalog_items::service_level::ServiceLevel;
 use catalog::catalog_items::technical_specifications::{BodyShellType, ChassisType, CouplingSocket, FeatureFlag};
+use catalog::prototypes::prototype_id::PrototypeId;
 use catalog::railways::railway_id::RailwayId;
 use catalog::scales::scale_id::ScaleId;
+use chrono::{DateTime, Utc};
 use common::queries::converters::ToOutputConverter;
 use common::queries::errors::DatabaseError;
 use common::unit_of_work::postgres::PgUnitOfWork;
+use std::str::FromStr;
 
 #[derive(Debug)]
 pub struct CatalogItemsRepository;
@@ -81,12 +98,15 @@ impl<'db> NewCatalogItemRepository<'db, PgUnitOfWork<'db>> for CatalogItemsRepos
                 availability_status,
                 count,
                 created_at,
-                version
+                version,
+                epoch_start,
+                epoch_end
             )
             VALUES (
                 $1, $2, $3, $4, $5, $6,
                 $7, $8, $9, $10, $11, $12, 
-                $13, $14, $15, $16, $17, $18
+                $13, $14, $15, $16, $17, $18,
+                $19, $20
             )"#,
             catalog_item_id as &CatalogItemId,
             brand_id as &BrandId,
@@ -105,12 +125,27 @@ impl<'db> NewCatalogItemRepository<'db, PgUnitOfWork<'db>> for CatalogItemsRepos
             request.availability_status as Option<AvailabilityStatus>,
             request.count,
             metadata.created(),
-            metadata.version() as i32
+            metadata.version() as i32,
+            request.epoch.range().start(),
+            request.epoch.range().end()
         )
         .execute(&mut *unit_of_work.transaction)
         .await
         .context("A database failure was encountered while trying to store a catalog item.")?;
 
+        if let Some(availability_status) = request.availability_status {
+            sqlx::query!(
+                r#"INSERT INTO catalog_item_availability_history (catalog_item_id, status, changed_at)
+                VALUES ($1, $2, $3)"#,
+                catalog_item_id as &CatalogItemId,
+                availability_status as AvailabilityStatus,
+                metadata.created()
+            )
+            .execute(&mut *unit_of_work.transaction)
+            .await
+            .context("A database failure was encountered while trying to store the availability history.")?;
+        }
+
         Ok(())
     }
 
@@ -119,10 +154,13 @@ impl<'db> NewCatalogItemRepository<'db, PgUnitOfWork<'db>> for CatalogItemsRepos
         brand_id: &BrandId,
         unit_of_work: &mut PgUnitOfWork<'db>,
     ) -> Result<bool, anyhow::Error> {
-        let result = sqlx::query!("SELECT brand_id FROM brands WHERE brand_id = $1 LIMIT 1", brand_id)
-            .fetch_optional(&mut *unit_of_work.transaction)
-            .await
-            .context("A database failure was encountered while trying to check for brand existence.")?;
+        let result = sqlx::query!(
+            "SELECT brand_id FROM brands WHERE brand_id = $1 AND deleted_at IS NULL LIMIT 1",
+            brand_id
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to check for brand existence.")?;
 
         Ok(result.is_some())
     }
@@ -132,10 +170,13 @@ impl<'db> NewCatalogItemRepository<'db, PgUnitOfWork<'db>> for CatalogItemsRepos
         scale_id: &ScaleId,
         unit_of_work: &mut PgUnitOfWork<'db>,
     ) -> Result<bool, anyhow::Error> {
-        let result = sqlx::query!("SELECT scale_id FROM scales WHERE scale_id = $1 LIMIT 1", scale_id)
-            .fetch_optional(&mut *unit_of_work.transaction)
-            .await
-            .context("A database failure was encountered while trying to check for scale existence.")?;
+        let result = sqlx::query!(
+            "SELECT scale_id FROM scales WHERE scale_id = $1 AND deleted_at IS NULL LIMIT 1",
+            scale_id
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to check for scale existence.")?;
 
         Ok(result.is_some())
     }
@@ -184,14 +225,16 @@ impl<'db> NewRollingStockRepository<'db, PgUnitOfWork<'db>> for RollingStocksRep
                         chassis,
                         interior_lights,
                         lights,
-                        sprung_buffers
+                        sprung_buffers,
+                        prototype_id
                     )
                     VALUES (
                         $1, $2, $3, $4, $5, $6,
                         $7, $8, $9, $10, $11, $12, 
                         $13, $14, $15, $16, $17, $18,
                         $19, $20, $21, $22, $23, $24, 
-                        $25, $26, $27, $28, $29, $30
+                        $25, $26, $27, $28, $29, $30,
+                        $31
                     )"#,
             rolling_stock_id as &RollingStockId,
             catalog_item_id as &CatalogItemId,
@@ -222,7 +265,8 @@ impl<'db> NewRollingStockRepository<'db, PgUnitOfWork<'db>> for RollingStocksRep
             request.chassis as Option<ChassisType>,
             request.interior_lights as Option<FeatureFlag>,
             request.lights as Option<FeatureFlag>,
-            request.sprung_buffers as Option<FeatureFlag>
+            request.sprung_buffers as Option<FeatureFlag>,
+            request.prototype_id.as_ref() as Option<&PrototypeId>
         )
         .execute(&mut *unit_of_work.transaction)
         .await
@@ -237,7 +281,7 @@ impl<'db> NewRollingStockRepository<'db, PgUnitOfWork<'db>> for RollingStocksRep
         unit_of_work: &mut PgUnitOfWork<'db>,
     ) -> Result<bool, anyhow::Error> {
         let result = sqlx::query!(
-            "SELECT railway_id FROM railways WHERE railway_id = $1 LIMIT 1",
+            "SELECT railway_id FROM railways WHERE railway_id = $1 AND deleted_at IS NULL LIMIT 1",
             railway_id
         )
         .fetch_optional(&mut *unit_of_work.transaction)
@@ -246,6 +290,22 @@ impl<'db> NewRollingStockRepository<'db, PgUnitOfWork<'db>> for RollingStocksRep
 
         Ok(result.is_some())
     }
+
+    async fn prototype_exists(
+        &self,
+        prototype_id: &PrototypeId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            "SELECT prototype_id FROM prototypes WHERE prototype_id = $1 AND deleted_at IS NULL LIMIT 1",
+            prototype_id as &PrototypeId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to check for a prototype existence.")?;
+
+        Ok(result.is_some())
+    }
 }
 
 #[async_trait]
@@ -280,6 +340,7 @@ impl<'db> FindCatalogItemByIdRepository<'db, PgUnitOfWork<'db>> for CatalogItems
                 c.count,
                 c.created_at,
                 c.last_modified_at,
+                c.deleted_at,
                 c.version
             FROM catalog_items AS c
             JOIN brands AS b
@@ -297,11 +358,210 @@ impl<'db> FindCatalogItemByIdRepository<'db, PgUnitOfWork<'db>> for CatalogItems
     }
 }
 
+#[async_trait]
+impl<'db> FindLatestCatalogItemsRepository<'db, PgUnitOfWork<'db>> for CatalogItemsRepository {
+    async fn find_latest(
+        &self,
+        criteria: &LatestCatalogItemsCriteria,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<CatalogItem>, DatabaseError> {
+        let epoch_range = criteria.epoch.as_ref().map(Epoch::range);
+        let epoch_filter = criteria.year.map(EpochFilter::in_year).unwrap_or_default();
+        let related_railway_ids: Vec<String> = criteria.related_railway_ids.iter().map(|it| it.to_string()).collect();
+        let results = sqlx::query_as!(
+            CatalogItemRow,
+            r#"SELECT
+                c.catalog_item_id as "catalog_item_id: CatalogItemId",
+                c.item_number,
+                c.brand_id as "brand_id: BrandId",
+                b.name as brand_display,
+                c.scale_id as "scale_id: ScaleId",
+                s.name as scale_display,
+                c.category as "category: Category",
+                c.power_method as "power_method: PowerMethod",
+                c.epoch,
+                c.description_de,
+                c.description_en,
+                c.description_fr,
+                c.description_it,
+                c.details_de,
+                c.details_en,
+                c.details_fr,
+                c.details_it,
+                c.delivery_date,
+                c.availability_status as "availability_status: AvailabilityStatus",
+                c.count,
+                c.created_at,
+                c.last_modified_at,
+                c.deleted_at,
+                c.version
+            FROM catalog_items AS c
+            JOIN brands AS b
+              ON c.brand_id = b.brand_id
+            JOIN scales AS s
+              ON s.scale_id = c.scale_id
+            WHERE c.deleted_at IS NULL
+              AND ($1::varchar IS NULL OR c.brand_id = $1)
+              AND ($2::varchar IS NULL OR c.scale_id = $2)
+              AND ($3::availability_status IS NULL OR c.availability_status = $3)
+              AND ($4::smallint IS NULL OR (c.epoch_start <= $5 AND $4 <= c.epoch_end))
+              AND (($6::varchar IS NULL AND $7::integer IS NULL) OR EXISTS (
+                SELECT 1
+                FROM rolling_stocks AS rs
+                JOIN railways AS r
+                  ON r.railway_id = rs.railway_id
+                WHERE rs.catalog_item_id = c.catalog_item_id
+                  AND rs.deleted_at IS NULL
+                  AND ($6::varchar IS NULL OR rs.railway_id = $6 OR rs.railway_id = ANY($15::varchar[]))
+                  AND ($7::integer IS NULL
+                    OR EXISTS (
+                      SELECT 1
+                      FROM UNNEST($8::varchar[], $9::smallint[], $10::smallint[]) AS e(country, epoch_start, epoch_end)
+                      WHERE e.country = r.country AND c.epoch_start <= e.epoch_end AND e.epoch_start <= c.epoch_end)
+                    OR (r.country <> ALL($11::varchar[]) AND c.epoch_start <= $13 AND $12 <= c.epoch_end))
+              ))
+            ORDER BY c.created_at DESC
+            LIMIT $14"#,
+            criteria.brand_id.as_ref() as Option<&BrandId>,
+            criteria.scale_id.as_ref() as Option<&ScaleId>,
+            criteria.availability_status as Option<AvailabilityStatus>,
+            epoch_range.map(|it| it.start()),
+            epoch_range.map(|it| it.end()),
+            criteria.railway_id.as_ref() as Option<&RailwayId>,
+            criteria.year,
+            &epoch_filter.countries,
+            &epoch_filter.starts,
+            &epoch_filter.ends,
+            &epoch_filter.calendar_countries,
+            epoch_filter.european.map(|it| it.start()),
+            epoch_filter.european.map(|it| it.end()),
+            criteria.limit,
+            &related_railway_ids
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to fetch the latest catalog items.")?;
+
+        results.to_output().map_err(DatabaseError::ConversionError)
+    }
+}
+
+#[async_trait]
+impl<'db> FindUpcomingReleasesRepository<'db, PgUnitOfWork<'db>> for CatalogItemsRepository {
+    async fn find_announced(
+        &self,
+        from_year: Year,
+        to_year: Year,
+        brand_ids: &[BrandId],
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<CatalogItem>, DatabaseError> {
+        let brand_ids: Vec<String> = brand_ids.iter().map(ToString::to_string).collect();
+        let results = sqlx::query_as!(
+            CatalogItemRow,
+            r#"SELECT
+                c.catalog_item_id as "catalog_item_id: CatalogItemId",
+                c.item_number,
+                c.brand_id as "brand_id: BrandId",
+                b.name as brand_display,
+                c.scale_id as "scale_id: ScaleId",
+                s.name as scale_display,
+                c.category as "category: Category",
+                c.power_method as "power_method: PowerMethod",
+                c.epoch,
+                c.description_de,
+                c.description_en,
+                c.description_fr,
+                c.description_it,
+                c.details_de,
+                c.details_en,
+                c.details_fr,
+                c.details_it,
+                c.delivery_date,
+                c.availability_status as "availability_status: AvailabilityStatus",
+                c.count,
+                c.created_at,
+                c.last_modified_at,
+                c.deleted_at,
+                c.version
+            FROM catalog_items AS c
+            JOIN brands AS b
+              ON c.brand_id = b.brand_id
+            JOIN scales AS s
+              ON s.scale_id = c.scale_id
+            WHERE c.deleted_at IS NULL
+              AND c.availability_status = 'ANNOUNCED'
+              AND c.delivery_date IS NOT NULL
+              AND split_part(c.delivery_date, '/', 1)::integer BETWEEN $1 AND $2
+              AND (cardinality($3::varchar[]) = 0 OR c.brand_id = ANY($3))
+            ORDER BY c.delivery_date, c.catalog_item_id"#,
+            from_year,
+            to_year,
+            &brand_ids
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to fetch the upcoming releases.")?;
+
+        results.to_output().map_err(DatabaseError::ConversionError)
+    }
+}
+
+#[async_trait]
+impl<'db> FindAvailabilityRepository<'db, PgUnitOfWork<'db>> for CatalogItemsRepository {
+    async fn find_availability(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Option<Availability>, DatabaseError> {
+        let result = sqlx::query_as!(
+            AvailabilityRow,
+            r#"SELECT
+                catalog_item_id as "catalog_item_id: CatalogItemId",
+                availability_status as "availability_status: AvailabilityStatus",
+                delivery_date,
+                review_requested_at
+            FROM catalog_items
+            WHERE catalog_item_id = $1 AND deleted_at IS NULL"#,
+            catalog_item_id as &CatalogItemId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to fetch the catalog item availability.")?;
+
+        result.to_output().map_err(DatabaseError::ConversionError)
+    }
+
+    async fn find_availability_history(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<AvailabilityChange>, DatabaseError> {
+        let results = sqlx::query_as!(
+            AvailabilityChangeRow,
+            r#"SELECT
+                status as "status: AvailabilityStatus",
+                previous_status as "previous_status: AvailabilityStatus",
+                note,
+                changed_at
+            FROM catalog_item_availability_history
+            WHERE catalog_item_id = $1
+            ORDER BY changed_at, history_id"#,
+            catalog_item_id as &CatalogItemId
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to fetch the catalog item availability history.")?;
+
+        results.to_output().map_err(DatabaseError::ConversionError)
+    }
+}
+
 #[async_trait]
 impl<'db> FindRollingStocksByCatalogItemIdRepository<'db, PgUnitOfWork<'db>> for CatalogItemsRepository {
     async fn find_rolling_stocks_by_id(
         &self,
         catalog_item_id: &CatalogItemId,
+        include_deleted: bool,
         unit_of_work: &mut PgUnitOfWork<'db>,
     ) -> Result<Vec<RollingStock>, DatabaseError> {
         let rolling_stocks = sqlx::query_as!(
@@ -315,6 +575,7 @@ impl<'db> FindRollingStocksByCatalogItemIdRepository<'db, PgUnitOfWork<'db>> for
                 rs.livery,
                 rs.length_over_buffers_mm,
                 rs.length_over_buffers_in,
+                rs.prototype_id as "prototype_id: PrototypeId",
                 rs.type_name,
                 rs.road_number,
                 rs.series,
@@ -341,8 +602,9 @@ impl<'db> FindRollingStocksByCatalogItemIdRepository<'db, PgUnitOfWork<'db>> for
             FROM rolling_stocks AS rs
             JOIN railways AS r
               ON r.railway_id = rs.railway_id
-            WHERE rs.catalog_item_id = $1"#,
-            catalog_item_id as &CatalogItemId
+            WHERE rs.catalog_item_id = $1 AND ($2 OR rs.deleted_at IS NULL)"#,
+            catalog_item_id as &CatalogItemId,
+            include_deleted
         )
         .fetch_all(&mut *unit_of_work.transaction)
         .await
@@ -351,3 +613,340 @@ impl<'db> FindRollingStocksByCatalogItemIdRepository<'db, PgUnitOfWork<'db>> for
         rolling_stocks.to_output().map_err(DatabaseError::ConversionError)
     }
 }
+
+#[async_trait]
+impl<'db> FindRollingStocksByCatalogItemIdsRepository<'db, PgUnitOfWork<'db>> for CatalogItemsRepository {
+    async fn find_rolling_stocks_by_ids(
+        &self,
+        catalog_item_ids: &[CatalogItemId],
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<(CatalogItemId, RollingStock)>, DatabaseError> {
+        let catalog_item_ids: Vec<String> = catalog_item_ids.iter().map(|id| id.to_string()).collect();
+
+        let rolling_stocks = sqlx::query_as!(
+            RollingStockRow,
+            r#"SELECT 
+                rs.rolling_stock_id as "rolling_stock_id: RollingStockId",
+                rs.catalog_item_id as "catalog_item_id: CatalogItemId",
+                rs.railway_id as "railway_id: RailwayId",
+                r.name as railway_label, 
+                rs.rolling_stock_category as "rolling_stock_category: RollingStockCategory",
+                rs.livery,
+                rs.length_over_buffers_mm,
+                rs.length_over_buffers_in,
+                rs.prototype_id as "prototype_id: PrototypeId",
+                rs.type_name,
+                rs.road_number,
+                rs.series,
+                rs.depot,
+                rs.dcc_interface as "dcc_interface: DccInterface",
+                rs.control as "control: Control",
+                rs.electric_multiple_unit_type as "electric_multiple_unit_type: ElectricMultipleUnitType",
+                rs.freight_car_type as "freight_car_type: FreightCarType",
+                rs.locomotive_type as "locomotive_type: LocomotiveType",
+                rs.passenger_car_type as "passenger_car_type: PassengerCarType",
+                rs.railcar_type as "railcar_type: RailcarType",
+                rs.service_level as "service_level: ServiceLevel",
+                rs.is_dummy,
+                rs.minimum_radius,
+                rs.coupling_socket as "coupling_socket: CouplingSocket",
+                rs.close_couplers as "close_couplers: FeatureFlag",
+                rs.digital_shunting_coupling as "digital_shunting_coupling: FeatureFlag",
+                rs.flywheel_fitted as "flywheel_fitted: FeatureFlag",
+                rs.body_shell as "body_shell: BodyShellType",
+                rs.chassis as "chassis: ChassisType",
+                rs.interior_lights as "interior_lights: FeatureFlag",
+                rs.lights as "lights: FeatureFlag",
+                rs.sprung_buffers as "sprung_buffers: FeatureFlag"
+            FROM rolling_stocks AS rs
+            JOIN railways AS r
+              ON r.railway_id = rs.railway_id
+            WHERE rs.catalog_item_id = ANY($1) AND rs.deleted_at IS NULL"#,
+            &catalog_item_ids[..]
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to fetch the rolling stock(s).")?;
+
+        let mut output = Vec::with_capacity(rolling_stocks.len());
+        for row in rolling_stocks.into_iter() {
+            let catalog_item_id = row.catalog_item_id.clone();
+            let rolling_stock = row.to_output().map_err(DatabaseError::ConversionError)?;
+            output.push((catalog_item_id, rolling_stock));
+        }
+
+        Ok(output)
+    }
+}
+
+#[async_trait]
+impl<'db> DeleteCatalogItemRepository<'db, PgUnitOfWork<'db>> for CatalogItemsRepository {
+    async fn is_deleted(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Option<bool>, anyhow::Error> {
+        let result = sqlx::query!(
+            "SELECT deleted_at FROM catalog_items WHERE catalog_item_id = $1",
+            catalog_item_id as &CatalogItemId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to check for a catalog item deletion.")?;
+
+        Ok(result.map(|row| row.deleted_at.is_some()))
+    }
+
+    async fn soft_delete(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        deleted_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            r#"UPDATE catalog_items SET deleted_at = $2, last_modified_at = $2, version = version + 1
+            WHERE catalog_item_id = $1 AND deleted_at IS NULL"#,
+            catalog_item_id as &CatalogItemId,
+            deleted_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to delete a catalog item.")?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query!(
+            "UPDATE rolling_stocks SET deleted_at = $2 WHERE catalog_item_id = $1 AND deleted_at IS NULL",
+            catalog_item_id as &CatalogItemId,
+            deleted_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to delete the rolling stocks.")?;
+
+        Ok(true)
+    }
+
+    async fn restore(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        last_modified_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let deleted_at = sqlx::query_scalar!(
+            r#"WITH deleted AS (
+                SELECT catalog_item_id, deleted_at FROM catalog_items
+                WHERE catalog_item_id = $1 AND deleted_at IS NOT NULL
+                FOR UPDATE
+            )
+            UPDATE catalog_items AS c
+            SET deleted_at = NULL, last_modified_at = $2, version = c.version + 1
+            FROM deleted
+            WHERE c.catalog_item_id = deleted.catalog_item_id
+            RETURNING deleted.deleted_at AS "deleted_at!""#,
+            catalog_item_id as &CatalogItemId,
+            last_modified_at
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to restore a catalog item.")?;
+
+        let Some(deleted_at) = deleted_at else {
+            return Ok(false);
+        };
+
+        sqlx::query!(
+            "UPDATE rolling_stocks SET deleted_at = NULL WHERE catalog_item_id = $1 AND deleted_at = $2",
+            catalog_item_id as &CatalogItemId,
+            deleted_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to restore the rolling stocks.")?;
+
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl<'db> DeleteRollingStockRepository<'db, PgUnitOfWork<'db>> for RollingStocksRepository {
+    async fn is_deleted(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        rolling_stock_id: &RollingStockId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Option<bool>, anyhow::Error> {
+        let result = sqlx::query!(
+            "SELECT deleted_at FROM rolling_stocks WHERE catalog_item_id = $1 AND rolling_stock_id = $2",
+            catalog_item_id as &CatalogItemId,
+            rolling_stock_id as &RollingStockId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to check for a rolling stock deletion.")?;
+
+        Ok(result.map(|row| row.deleted_at.is_some()))
+    }
+
+    async fn soft_delete(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        rolling_stock_id: &RollingStockId,
+        deleted_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            r#"WITH deleted AS (
+                UPDATE rolling_stocks SET deleted_at = $3
+                WHERE catalog_item_id = $1 AND rolling_stock_id = $2 AND deleted_at IS NULL
+                RETURNING catalog_item_id
+            )
+            UPDATE catalog_items AS c
+            SET last_modified_at = $3, version = c.version + 1
+            FROM deleted
+            WHERE c.catalog_item_id = deleted.catalog_item_id"#,
+            catalog_item_id as &CatalogItemId,
+            rolling_stock_id as &RollingStockId,
+            deleted_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to delete a rolling stock.")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn restore(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        rolling_stock_id: &RollingStockId,
+        last_modified_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            r#"WITH restored AS (
+                UPDATE rolling_stocks SET deleted_at = NULL
+                WHERE catalog_item_id = $1 AND rolling_stock_id = $2 AND deleted_at IS NOT NULL
+                RETURNING catalog_item_id
+            )
+            UPDATE catalog_items AS c
+            SET last_modified_at = $3, version = c.version + 1
+            FROM restored
+            WHERE c.catalog_item_id = restored.catalog_item_id"#,
+            catalog_item_id as &CatalogItemId,
+            rolling_stock_id as &RollingStockId,
+            last_modified_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to restore a rolling stock.")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[async_trait]
+impl<'db> AvailabilityRepository<'db, PgUnitOfWork<'db>> for CatalogItemsRepository {
+    async fn find_status(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Option<Option<AvailabilityStatus>>, anyhow::Error> {
+        let result = sqlx::query!(
+            r#"SELECT availability_status as "availability_status: AvailabilityStatus"
+            FROM catalog_items
+            WHERE catalog_item_id = $1 AND deleted_at IS NULL"#,
+            catalog_item_id as &CatalogItemId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to fetch the availability status.")?;
+
+        Ok(result.map(|row| row.availability_status))
+    }
+
+    async fn change_status(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        change: &AvailabilityChange,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query!(
+            r#"UPDATE catalog_items
+            SET availability_status = $2, review_requested_at = NULL, last_modified_at = $3
+            WHERE catalog_item_id = $1"#,
+            catalog_item_id as &CatalogItemId,
+            change.status as AvailabilityStatus,
+            change.changed_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to change the availability status.")?;
+
+        sqlx::query!(
+            r#"INSERT INTO catalog_item_availability_history (catalog_item_id, status, previous_status, note, changed_at)
+            VALUES ($1, $2, $3, $4, $5)"#,
+            catalog_item_id as &CatalogItemId,
+            change.status as AvailabilityStatus,
+            change.previous_status as Option<AvailabilityStatus>,
+            change.note,
+            change.changed_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to store the availability history.")?;
+
+        Ok(())
+    }
+
+    async fn find_announced(
+        &self,
+        until_year: Year,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<(CatalogItemId, DeliveryDate)>, anyhow::Error> {
+        let results = sqlx::query!(
+            r#"SELECT catalog_item_id as "catalog_item_id: CatalogItemId", delivery_date as "delivery_date!"
+            FROM catalog_items
+            WHERE deleted_at IS NULL
+              AND availability_status = 'ANNOUNCED'
+              AND review_requested_at IS NULL
+              AND delivery_date IS NOT NULL
+              AND split_part(delivery_date, '/', 1)::integer <= $1
+            ORDER BY delivery_date, catalog_item_id"#,
+            until_year
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to fetch the announced catalog items.")?;
+
+        results
+            .into_iter()
+            .map(|row| {
+                let delivery_date = DeliveryDate::from_str(&row.delivery_date)
+                    .with_context(|| format!("Invalid delivery date for the catalog item {}", row.catalog_item_id))?;
+                Ok((row.catalog_item_id, delivery_date))
+            })
+            .collect()
+    }
+
+    async fn request_review(
+        &self,
+        catalog_item_ids: &[CatalogItemId],
+        requested_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<(), anyhow::Error> {
+        let catalog_item_ids: Vec<String> = catalog_item_ids.iter().map(ToString::to_string).collect();
+        sqlx::query!(
+            "UPDATE catalog_items SET review_requested_at = $2 WHERE catalog_item_id = ANY($1)",
+            &catalog_item_ids,
+            requested_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to request the editorial reviews.")?;
+
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use catalog::catalog_items::control::{Control, DccInterface};
 use catalog::catalog_items::rolling_stock_id::RollingStockId;
 use catalog::catalog_items::service_level::ServiceLevel;
 use catalog::catalog_items::technical_specifications::{BodyShellType, ChassisType, CouplingSocket, FeatureFlag};
+use catalog::prototypes::prototype_id::PrototypeId;
 use catalog::railways::railway_id::RailwayId;
 use rust_decimal::Decimal;
 
@@ -22,6 +23,7 @@ pub struct RollingStockRow {
     pub livery: Option<String>,
     pub length_over_buffers_mm: Option<Decimal>,
     pub length_over_buffers_in: Option<Decimal>,
+    pub prototype_id: Option<PrototypeId>,
     pub type_name: String,
     pub road_number: Option<String>,
     pub series: Option<String>,
@@ -62,6 +64,7 @@ pub mod test {
             livery: None,
             length_over_buffers_mm: None,
             length_over_buffers_in: None,
+            prototype_id: None,
             type_name: String::from(type_name),
             road_number: None,
             series: None,
@@ -91,11 +91,11 @@ impl Converter<CatalogItemRow> for ItemNumber {
 
 impl Converter<CatalogItemRow> for Metadata {
     fn try_convert(value: &CatalogItemRow) -> Result<Self, ConversionErrors> {
-        Ok(Metadata::new(
-            value.version as u8,
-            value.created_at,
-            value.last_modified_at,
-        ))
+        let metadata = Metadata::new(value.version as u8, value.created_at, value.last_modified_at);
+        match value.deleted_at {
+            Some(deleted_at) => Ok(metadata.deleted_at(deleted_at)),
+            None => Ok(metadata),
+        }
     }
 }
 
@@ -116,6 +116,7 @@ impl ToOutputConverter<RollingStock> for RollingStockRow {
                 livery: row.livery,
                 length_over_buffer,
                 technical_specifications,
+                prototype_id: row.prototype_id,
                 class_name: row.type_name,
                 road_number: row.road_number.expect("missing road number"),
                 series: row.series,
@@ -134,7 +135,9 @@ impl ToOutputConverter<RollingStock> for RollingStockRow {
                 livery: row.livery,
                 length_over_buffer,
                 technical_specifications,
+                prototype_id: row.prototype_id,
                 type_name: row.type_name,
+                uic_vehicle_number: row.road_number.as_deref().and_then(|it| it.parse().ok()),
                 road_number: row.road_number,
                 series: row.series,
                 passenger_car_type: row.passenger_car_type,
@@ -149,7 +152,9 @@ impl ToOutputConverter<RollingStock> for RollingStockRow {
                 livery: row.livery,
                 length_over_buffer,
                 technical_specifications,
+                prototype_id: row.prototype_id,
                 type_name: row.type_name,
+                uic_vehicle_number: row.road_number.as_deref().and_then(|it| it.parse().ok()),
                 road_number: row.road_number,
                 freight_car_type: row.freight_car_type,
             }),
@@ -162,6 +167,7 @@ impl ToOutputConverter<RollingStock> for RollingStockRow {
                 livery: row.livery,
                 length_over_buffer,
                 technical_specifications,
+                prototype_id: row.prototype_id,
                 type_name: row.type_name,
                 road_number: row.road_number,
                 series: row.series,
@@ -182,6 +188,7 @@ impl ToOutputConverter<RollingStock> for RollingStockRow {
                 livery: row.livery,
                 length_over_buffer,
                 technical_specifications,
+                prototype_id: row.prototype_id,
                 type_name: row.type_name,
                 road_number: row.road_number,
                 series: row.series,
@@ -439,6 +446,7 @@ mod test {
                     livery,
                     length_over_buffer,
                     technical_specifications,
+                    prototype_id,
                     class_name,
                     road_number,
                     series,
@@ -449,6 +457,7 @@ mod test {
                     is_dummy,
                 } => {
                     assert_eq!(id, row.rolling_stock_id);
+                    assert_eq!(prototype_id, row.prototype_id);
                     assert_eq!(railway.railway_id, row.railway_id);
                     assert_eq!(livery, row.livery);
                     assert_eq!(depot, row.depot);
@@ -535,6 +544,7 @@ mod test {
                     livery,
                     length_over_buffer,
                     technical_specifications,
+                    prototype_id,
                     type_name,
                     road_number,
                     series,
@@ -545,6 +555,7 @@ mod test {
                     is_dummy,
                 } => {
                     assert_eq!(id, row.rolling_stock_id);
+                    assert_eq!(prototype_id, row.prototype_id);
                     assert_eq!(railway.railway_id, row.railway_id);
                     assert_eq!(livery, row.livery);
                     assert_eq!(depot, row.depot);
@@ -631,6 +642,7 @@ mod test {
                     livery,
                     length_over_buffer,
                     technical_specifications,
+                    prototype_id,
                     type_name,
                     road_number,
                     series,
@@ -641,6 +653,7 @@ mod test {
                     is_dummy,
                 } => {
                     assert_eq!(id, row.rolling_stock_id);
+                    assert_eq!(prototype_id, row.prototype_id);
                     assert_eq!(railway.railway_id, row.railway_id);
                     assert_eq!(livery, row.livery);
                     assert_eq!(depot, row.depot);
@@ -724,13 +737,16 @@ mod test {
                     livery,
                     length_over_buffer,
                     technical_specifications,
+                    prototype_id,
                     type_name,
                     road_number,
+                    uic_vehicle_number,
                     series,
                     passenger_car_type,
                     service_level,
                 } => {
                     assert_eq!(id, row.rolling_stock_id);
+                    assert_eq!(prototype_id, row.prototype_id);
                     assert_eq!(railway.railway_id, row.railway_id);
                     assert_eq!(livery, row.livery);
 
@@ -747,6 +763,7 @@ mod test {
 
                     assert_eq!(type_name, row.type_name);
                     assert_eq!(road_number, row.road_number);
+                    assert_eq!(None, uic_vehicle_number);
                     assert_eq!(series, row.series);
 
                     assert!(technical_specifications.is_some());
@@ -793,7 +810,7 @@ mod test {
                 length_over_buffers_mm: Some(dec!(16.5)),
                 length_over_buffers_in: Some(dec!(0.65)),
                 type_name: String::from("Group 1"),
-                road_number: Some(String::from("Number 42")),
+                road_number: Some(String::from("31 83 665 0 150-6")),
                 series: Some(String::from("prototype")),
                 minimum_radius: Some(dec!(360)),
                 close_couplers: Some(FeatureFlag::Yes),
@@ -811,11 +828,14 @@ mod test {
                     livery,
                     length_over_buffer,
                     technical_specifications,
+                    prototype_id,
                     type_name,
                     road_number,
+                    uic_vehicle_number,
                     freight_car_type,
                 } => {
                     assert_eq!(id, row.rolling_stock_id);
+                    assert_eq!(prototype_id, row.prototype_id);
                     assert_eq!(railway.railway_id, row.railway_id);
                     assert_eq!(livery, row.livery);
 
@@ -832,6 +852,10 @@ mod test {
 
                     assert_eq!(type_name, row.type_name);
                     assert_eq!(road_number, row.road_number);
+                    assert_eq!(
+                        Some("318366501506"),
+                        uic_vehicle_number.as_ref().map(|it| it.number.as_str())
+                    );
 
                     assert!(technical_specifications.is_some());
                     let technical_specifications = technical_specifications.unwrap();
@@ -1,4 +1,5 @@
 pub mod brands;
 pub mod catalog_item;
+pub mod prototypes;
 pub mod railways;
 pub mod scales;
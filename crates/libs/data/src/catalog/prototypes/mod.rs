@@ -0,0 +1,5 @@
+//! the persistence layer for prototypes
+
+mod prototype_row;
+pub mod repositories;
+mod row_converters;
@@ -0,0 +1,49 @@
+use catalog::prototypes::power_type::PowerType;
+use catalog::prototypes::prototype_id::PrototypeId;
+use catalog::railways::railway_id::RailwayId;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+#[derive(Debug, Clone)]
+pub struct PrototypeRow {
+    pub prototype_id: PrototypeId,
+    pub railway_id: RailwayId,
+    pub railway_label: String,
+    pub class_name: String,
+    pub series: Option<String>,
+    pub builder: Option<String>,
+    pub built_from: Option<i32>,
+    pub built_until: Option<i32>,
+    pub power_type: Option<PowerType>,
+    pub length_meters: Option<Decimal>,
+    pub version: i32,
+    pub created_at: DateTime<Utc>,
+    pub last_modified_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    #[allow(dead_code)]
+    pub fn new_prototype_row(railway: &str, class_name: &str, created_at: DateTime<Utc>) -> PrototypeRow {
+        let railway_id = RailwayId::new(railway);
+        PrototypeRow {
+            prototype_id: PrototypeId::of(&railway_id, class_name, None),
+            railway_id,
+            railway_label: String::from(railway),
+            class_name: String::from(class_name),
+            series: None,
+            builder: None,
+            built_from: None,
+            built_until: None,
+            power_type: None,
+            length_meters: None,
+            version: 1,
+            created_at,
+            last_modified_at: None,
+            deleted_at: None,
+        }
+    }
+}
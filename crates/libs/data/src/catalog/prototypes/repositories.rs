@@ -0,0 +1,262 @@
+use crate::catalog::catalog_item::catalog_item_row::CatalogItemRow;
+use crate::catalog::prototypes::prototype_row::PrototypeRow;
+use anyhow::Context;
+use async_trait::async_trait;
+use catalog::brands::brand_id::BrandId;
+use catalog::catalog_items::availability_status::AvailabilityStatus;
+use catalog::catalog_items::catalog_item::CatalogItem;
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::catalog_items::category::Category;
+use catalog::catalog_items::power_method::PowerMethod;
+use catalog::prototypes::commands::new_prototype::NewPrototypeCommand;
+use catalog::prototypes::commands::repositories::NewPrototypeRepository;
+use catalog::prototypes::power_type::PowerType;
+use catalog::prototypes::prototype::Prototype;
+use catalog::prototypes::prototype_id::PrototypeId;
+use catalog::prototypes::queries::find_all_prototypes::FindAllPrototypesRepository;
+use catalog::prototypes::queries::find_prototype_by_id::FindPrototypeByIdRepository;
+use catalog::prototypes::queries::find_prototype_models::FindPrototypeModelsRepository;
+use catalog::railways::railway_id::RailwayId;
+use catalog::scales::scale_id::ScaleId;
+use common::measure_units::MeasureUnit;
+use common::queries::converters::ToOutputConverter;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::postgres::PgUnitOfWork;
+
+#[derive(Debug)]
+pub struct PrototypesRepository;
+
+#[async_trait]
+impl<'db> NewPrototypeRepository<'db, PgUnitOfWork<'db>> for PrototypesRepository {
+    async fn exists(
+        &self,
+        prototype_id: &PrototypeId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            "SELECT prototype_id FROM prototypes WHERE prototype_id = $1 LIMIT 1",
+            prototype_id as &PrototypeId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to check for prototype existence.")?;
+
+        Ok(result.is_some())
+    }
+
+    async fn insert(
+        &self,
+        new_prototype: &NewPrototypeCommand,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<(), anyhow::Error> {
+        let prototype_id = &new_prototype.prototype_id;
+        let railway_id = &new_prototype.railway_id;
+        let request = &new_prototype.payload;
+        let metadata = &new_prototype.metadata;
+
+        sqlx::query!(
+            r#"INSERT INTO prototypes (
+                    prototype_id,
+                    railway_id,
+                    class_name,
+                    series,
+                    builder,
+                    built_from,
+                    built_until,
+                    power_type,
+                    length_meters,
+                    created_at,
+                    version
+                )
+                VALUES (
+                    $1, $2, $3, $4, $5, $6,
+                    $7, $8, $9, $10, $11
+                )"#,
+            prototype_id as &PrototypeId,
+            railway_id as &RailwayId,
+            request.class_name,
+            request.series,
+            request.builder,
+            request.years_built.map(|it| it.from),
+            request.years_built.and_then(|it| it.until),
+            request.power_type as Option<PowerType>,
+            request
+                .length_over_buffers
+                .map(|it| it.get_value_as(MeasureUnit::Meters)),
+            metadata.created(),
+            metadata.version() as i32
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to store a prototype.")?;
+
+        Ok(())
+    }
+
+    async fn railway_exists(
+        &self,
+        railway_id: &RailwayId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            "SELECT railway_id FROM railways WHERE railway_id = $1 AND deleted_at IS NULL LIMIT 1",
+            railway_id as &RailwayId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to check for a railway existence.")?;
+
+        Ok(result.is_some())
+    }
+}
+
+#[async_trait]
+impl<'db> FindAllPrototypesRepository<'db, PgUnitOfWork<'db>> for PrototypesRepository {
+    async fn find_all(
+        &self,
+        railway_id: Option<&RailwayId>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<Prototype>, DatabaseError> {
+        let results = sqlx::query_as!(
+            PrototypeRow,
+            r#"SELECT
+                p.prototype_id as "prototype_id: PrototypeId",
+                p.railway_id as "railway_id: RailwayId",
+                r.name as railway_label,
+                p.class_name,
+                p.series,
+                p.builder,
+                p.built_from,
+                p.built_until,
+                p.power_type as "power_type: PowerType",
+                p.length_meters,
+                p.created_at,
+                p.last_modified_at,
+                p.deleted_at,
+                p.version
+            FROM prototypes AS p
+            JOIN railways AS r
+              ON r.railway_id = p.railway_id
+            WHERE p.deleted_at IS NULL
+              AND ($1::varchar IS NULL OR p.railway_id = $1)
+            ORDER BY r.name, p.class_name, p.series NULLS FIRST"#,
+            railway_id.map(|it| it.to_string())
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to fetch prototypes.")?;
+
+        results.to_output().map_err(DatabaseError::ConversionError)
+    }
+}
+
+#[async_trait]
+impl<'db> FindPrototypeByIdRepository<'db, PgUnitOfWork<'db>> for PrototypesRepository {
+    async fn find_by_id(
+        &self,
+        prototype_id: &PrototypeId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Option<Prototype>, DatabaseError> {
+        let result = sqlx::query_as!(
+            PrototypeRow,
+            r#"SELECT
+                p.prototype_id as "prototype_id: PrototypeId",
+                p.railway_id as "railway_id: RailwayId",
+                r.name as railway_label,
+                p.class_name,
+                p.series,
+                p.builder,
+                p.built_from,
+                p.built_until,
+                p.power_type as "power_type: PowerType",
+                p.length_meters,
+                p.created_at,
+                p.last_modified_at,
+                p.deleted_at,
+                p.version
+            FROM prototypes AS p
+            JOIN railways AS r
+              ON r.railway_id = p.railway_id
+            WHERE p.prototype_id = $1"#,
+            prototype_id as &PrototypeId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to fetch a prototype.")?;
+
+        result.to_output().map_err(DatabaseError::ConversionError)
+    }
+}
+
+#[async_trait]
+impl<'db> FindPrototypeModelsRepository<'db, PgUnitOfWork<'db>> for PrototypesRepository {
+    async fn prototype_exists(
+        &self,
+        prototype_id: &PrototypeId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, DatabaseError> {
+        let result = sqlx::query!(
+            "SELECT prototype_id FROM prototypes WHERE prototype_id = $1 AND deleted_at IS NULL LIMIT 1",
+            prototype_id as &PrototypeId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to check for prototype existence.")?;
+
+        Ok(result.is_some())
+    }
+
+    async fn find_models(
+        &self,
+        prototype_id: &PrototypeId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<CatalogItem>, DatabaseError> {
+        let results = sqlx::query_as!(
+            CatalogItemRow,
+            r#"SELECT
+                c.catalog_item_id as "catalog_item_id: CatalogItemId",
+                c.item_number,
+                c.brand_id as "brand_id: BrandId",
+                b.name as brand_display,
+                c.scale_id as "scale_id: ScaleId",
+                s.name as scale_display,
+                c.category as "category: Category",
+                c.power_method as "power_method: PowerMethod",
+                c.epoch,
+                c.description_de,
+                c.description_en,
+                c.description_fr,
+                c.description_it,
+                c.details_de,
+                c.details_en,
+                c.details_fr,
+                c.details_it,
+                c.delivery_date,
+                c.availability_status as "availability_status: AvailabilityStatus",
+                c.count,
+                c.created_at,
+                c.last_modified_at,
+                c.deleted_at,
+                c.version
+            FROM catalog_items AS c
+            JOIN brands AS b
+              ON c.brand_id = b.brand_id
+            JOIN scales AS s
+              ON s.scale_id = c.scale_id
+            WHERE c.deleted_at IS NULL
+              AND EXISTS (
+                SELECT 1
+                FROM rolling_stocks AS rs
+                WHERE rs.catalog_item_id = c.catalog_item_id
+                  AND rs.prototype_id = $1
+                  AND rs.deleted_at IS NULL)
+            ORDER BY b.name, c.item_number"#,
+            prototype_id as &PrototypeId
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to fetch the prototype models.")?;
+
+        results.to_output().map_err(DatabaseError::ConversionError)
+    }
+}
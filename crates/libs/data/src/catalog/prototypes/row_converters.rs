@@ -0,0 +1,122 @@
+use crate::catalog::prototypes::prototype_row::PrototypeRow;
+use catalog::prototypes::prototype::{Prototype, PrototypeRailway};
+use catalog::prototypes::years_built::YearsBuilt;
+use common::length::Length;
+use common::measure_units::MeasureUnit;
+use common::metadata::Metadata;
+use common::queries::converters::{ConversionErrors, Converter, OptionConverter, ToOutputConverter};
+
+impl ToOutputConverter<Prototype> for PrototypeRow {
+    fn to_output(self) -> Result<Prototype, ConversionErrors> {
+        let row = self;
+
+        let years_built = YearsBuilt::try_convert(&row)?;
+        let length_over_buffers = Length::try_convert(&row)?;
+        let metadata = Metadata::try_convert(&row)?;
+
+        Ok(Prototype {
+            prototype_id: row.prototype_id,
+            railway: PrototypeRailway::new(row.railway_id, &row.railway_label),
+            class_name: row.class_name,
+            series: row.series,
+            builder: row.builder,
+            years_built,
+            power_type: row.power_type,
+            length_over_buffers,
+            metadata,
+        })
+    }
+}
+
+impl OptionConverter<PrototypeRow> for YearsBuilt {
+    fn try_convert(row: &PrototypeRow) -> Result<Option<Self>, ConversionErrors> {
+        match row.built_from {
+            Some(from) => YearsBuilt::new(from, row.built_until)
+                .map(Some)
+                .map_err(|_| ConversionErrors::new()),
+            None => Ok(None),
+        }
+    }
+}
+
+impl OptionConverter<PrototypeRow> for Length {
+    fn try_convert(row: &PrototypeRow) -> Result<Option<Self>, ConversionErrors> {
+        match row.length_meters {
+            Some(meters) => Length::try_new(meters, MeasureUnit::Meters)
+                .map(Some)
+                .map_err(|_| ConversionErrors::new()),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Converter<PrototypeRow> for Metadata {
+    fn try_convert(row: &PrototypeRow) -> Result<Self, ConversionErrors> {
+        let metadata = Metadata::new(row.version as u8, row.created_at, row.last_modified_at);
+        match row.deleted_at {
+            Some(deleted_at) => Ok(metadata.deleted_at(deleted_at)),
+            None => Ok(metadata),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalog::prototypes::prototype_row::test::new_prototype_row;
+    use catalog::prototypes::power_type::PowerType;
+    use catalog::prototypes::prototype_id::PrototypeId;
+    use chrono::Utc;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn it_should_convert_a_prototype_row() {
+        let now = Utc::now();
+        let row = PrototypeRow {
+            series: Some(String::from("103.1")),
+            builder: Some(String::from("Henschel")),
+            built_from: Some(1970),
+            built_until: Some(1974),
+            power_type: Some(PowerType::Electric),
+            length_meters: Some(dec!(19.5)),
+            ..new_prototype_row("DB", "BR 103", now)
+        };
+
+        let prototype = row.to_output().expect("the prototype conversion failed");
+
+        assert_eq!(PrototypeId::new("db-br-103"), prototype.prototype_id);
+        assert_eq!("DB", prototype.railway.display);
+        assert_eq!("BR 103", prototype.class_name);
+        assert_eq!(Some("103.1"), prototype.series());
+        assert_eq!(Some(String::from("Henschel")), prototype.builder);
+        assert_eq!(YearsBuilt::new(1970, Some(1974)).ok(), prototype.years_built);
+        assert_eq!(Some(PowerType::Electric), prototype.power_type);
+        assert_eq!(Some(Length::Meters(dec!(19.5))), prototype.length_over_buffers);
+        assert_eq!(&Metadata::created_at(now), prototype.metadata());
+    }
+
+    #[test]
+    fn it_should_convert_a_prototype_row_without_optional_values() {
+        let now = Utc::now();
+        let row = new_prototype_row("FS", "E.626", now);
+
+        let prototype = row.to_output().expect("the prototype conversion failed");
+
+        assert_eq!(None, prototype.years_built);
+        assert_eq!(None, prototype.length_over_buffers);
+    }
+
+    #[test]
+    fn it_should_fail_to_convert_invalid_years_built() {
+        let row = PrototypeRow {
+            built_from: Some(1980),
+            built_until: Some(1970),
+            ..new_prototype_row("FS", "E.626", Utc::now())
+        };
+
+        let result = row.to_output();
+
+        assert!(result.is_err());
+    }
+}
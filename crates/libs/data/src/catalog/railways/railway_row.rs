@@ -1,11 +1,13 @@
 use catalog::common::TrackGauge;
 use catalog::railways::period_of_activity::RailwayStatus;
 use catalog::railways::railway_id::RailwayId;
+use catalog::railways::vehicle_keeper_marking::VehicleKeeperMarking;
 use chrono::{DateTime, NaiveDate, Utc};
 use common::contacts::{MailAddress, PhoneNumber, WebsiteUrl};
 use common::organizations::OrganizationEntityType;
 use common::socials::Handler;
 use rust_decimal::Decimal;
+use sqlx::types::Json;
 
 #[derive(Debug)]
 pub struct RailwayRow {
@@ -19,6 +21,8 @@ pub struct RailwayRow {
     pub description_fr: Option<String>,
     pub description_it: Option<String>,
     pub country: String,
+    pub uic_country_code: Option<i16>,
+    pub vehicle_keeper_markings: Json<Vec<VehicleKeeperMarking>>,
     pub operating_since: Option<NaiveDate>,
     pub operating_until: Option<NaiveDate>,
     pub status: Option<RailwayStatus>,
@@ -38,6 +42,7 @@ pub struct RailwayRow {
     pub version: i32,
     pub created_at: DateTime<Utc>,
     pub last_modified_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 #[cfg(test)]
@@ -58,6 +63,8 @@ pub mod test {
             description_fr: None,
             description_it: None,
             country: country.alpha2().to_owned(),
+            uic_country_code: None,
+            vehicle_keeper_markings: Json(Vec::new()),
             operating_since: None,
             operating_until: None,
             status: None,
@@ -77,6 +84,7 @@ pub mod test {
             version: 1,
             created_at,
             last_modified_at: None,
+            deleted_at: None,
         }
     }
 }
@@ -3,12 +3,20 @@ use anyhow::Context;
 use async_trait::async_trait;
 use catalog::common::TrackGauge;
 use catalog::railways::commands::new_railways::NewRailwayCommand;
-use catalog::railways::commands::repositories::NewRailwayRepository;
+use catalog::railways::commands::repositories::{
+    DeleteRailwayRepository, NewRailwayRelationshipRepository, NewRailwayRepository,
+};
 use catalog::railways::period_of_activity::RailwayStatus;
 use catalog::railways::queries::find_all_railways::FindAllRailwaysRepository;
 use catalog::railways::queries::find_railway_by_id::FindRailwayByIdRepository;
+use catalog::railways::queries::find_railway_lineage::FindRailwayLineageRepository;
+use catalog::railways::queries::find_railways_by_ids::FindRailwaysByIdsRepository;
+use catalog::railways::queries::find_vehicle_keeper::FindVehicleKeeperRepository;
 use catalog::railways::railway::Railway;
 use catalog::railways::railway_id::RailwayId;
+use catalog::railways::railway_relationship::{RailwayRelationship, RelationshipKind};
+use catalog::railways::vehicle_keeper_marking::VehicleKeeperMarking;
+use chrono::{DateTime, NaiveDate, Utc};
 use common::contacts::WebsiteUrl;
 use common::contacts::{MailAddress, PhoneNumber};
 use common::organizations::OrganizationEntityType;
@@ -16,6 +24,7 @@ use common::queries::converters::ToOutputConverter;
 use common::queries::errors::DatabaseError;
 use common::socials::Handler;
 use common::unit_of_work::postgres::PgUnitOfWork;
+use sqlx::types::Json;
 
 #[derive(Debug)]
 pub struct RailwaysRepository;
@@ -55,6 +64,8 @@ impl<'db> NewRailwayRepository<'db, PgUnitOfWork<'db>> for RailwaysRepository {
                 description_fr, 
                 description_it,
                 country,
+                uic_country_code,
+                vehicle_keeper_markings,
                 operating_since,
                 operating_until,
                 status,
@@ -79,7 +90,7 @@ impl<'db> NewRailwayRepository<'db, PgUnitOfWork<'db>> for RailwaysRepository {
                 $7, $8, $9, $10, $11, $12, 
                 $13, $14, $15, $16, $17, $18,
                 $19, $20, $21, $22, $23, $24, 
-                $25, $26, $27, $28
+                $25, $26, $27, $28, $29, $30
             )"#,
             railway_id as &RailwayId,
             request.name,
@@ -91,6 +102,8 @@ impl<'db> NewRailwayRepository<'db, PgUnitOfWork<'db>> for RailwaysRepository {
             request.description.french(),
             request.description.italian(),
             request.country,
+            request.uic_country_code,
+            Json(&request.vehicle_keeper_markings) as _,
             request.operating_since,
             request.operating_until,
             request.status.as_ref() as Option<&RailwayStatus>,
@@ -120,7 +133,11 @@ impl<'db> NewRailwayRepository<'db, PgUnitOfWork<'db>> for RailwaysRepository {
 
 #[async_trait]
 impl<'db> FindAllRailwaysRepository<'db, PgUnitOfWork<'db>> for RailwaysRepository {
-    async fn find_all(&self, unit_of_work: &mut PgUnitOfWork<'db>) -> Result<Vec<Railway>, DatabaseError> {
+    async fn find_all(
+        &self,
+        include_deleted: bool,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<Railway>, DatabaseError> {
         let results = sqlx::query_as!(
             RailwayRow,
             r#"SELECT
@@ -134,6 +151,8 @@ impl<'db> FindAllRailwaysRepository<'db, PgUnitOfWork<'db>> for RailwaysReposito
                 description_fr, 
                 description_it,
                 country,
+                uic_country_code,
+                vehicle_keeper_markings as "vehicle_keeper_markings!: Json<Vec<VehicleKeeperMarking>>",
                 operating_since,
                 operating_until,
                 status as "status?: RailwayStatus",
@@ -152,9 +171,12 @@ impl<'db> FindAllRailwaysRepository<'db, PgUnitOfWork<'db>> for RailwaysReposito
                 socials_youtube as "socials_youtube?: Handler",
                 created_at,
                 last_modified_at,
+                deleted_at,
                 version
             FROM railways
+            WHERE $1 OR deleted_at IS NULL
             ORDER BY name"#,
+            include_deleted
         )
         .fetch_all(&mut *unit_of_work.transaction)
         .await
@@ -184,6 +206,8 @@ impl<'db> FindRailwayByIdRepository<'db, PgUnitOfWork<'db>> for RailwaysReposito
                 description_fr, 
                 description_it,
                 country,
+                uic_country_code,
+                vehicle_keeper_markings as "vehicle_keeper_markings!: Json<Vec<VehicleKeeperMarking>>",
                 operating_since,
                 operating_until,
                 status as "status?: RailwayStatus",
@@ -202,6 +226,7 @@ impl<'db> FindRailwayByIdRepository<'db, PgUnitOfWork<'db>> for RailwaysReposito
                 socials_youtube as "socials_youtube?: Handler",
                 created_at,
                 last_modified_at,
+                deleted_at,
                 version
             FROM railways 
             WHERE railway_id = $1"#,
@@ -214,3 +239,248 @@ impl<'db> FindRailwayByIdRepository<'db, PgUnitOfWork<'db>> for RailwaysReposito
         result.to_output().map_err(DatabaseError::ConversionError)
     }
 }
+
+#[async_trait]
+impl<'db> FindVehicleKeeperRepository<'db, PgUnitOfWork<'db>> for RailwaysRepository {
+    async fn find_vehicle_keeper(
+        &self,
+        uic_country_code: u8,
+        vehicle_keeper_marking: &str,
+        date: Option<NaiveDate>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Option<RailwayId>, DatabaseError> {
+        let result = sqlx::query_scalar!(
+            r#"SELECT railway_id as "railway_id: RailwayId"
+            FROM railways
+            WHERE uic_country_code = $1
+              AND deleted_at IS NULL
+              AND EXISTS (
+                SELECT 1
+                FROM jsonb_array_elements(vehicle_keeper_markings) AS marking
+                WHERE marking->>'code' = $2
+                  AND ($3::date IS NULL OR (marking->>'valid_from') IS NULL OR (marking->>'valid_from')::date <= $3)
+                  AND ($3::date IS NULL OR (marking->>'valid_until') IS NULL OR $3 <= (marking->>'valid_until')::date)
+              )
+            ORDER BY railway_id
+            LIMIT 1"#,
+            uic_country_code as i16,
+            vehicle_keeper_marking,
+            date
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to fetch the vehicle keeper.")?;
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl<'db> FindRailwaysByIdsRepository<'db, PgUnitOfWork<'db>> for RailwaysRepository {
+    async fn find_by_ids(
+        &self,
+        railway_ids: &[RailwayId],
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<Railway>, DatabaseError> {
+        let railway_ids_param: Vec<String> = railway_ids.iter().map(|id| id.to_string()).collect();
+        let result = sqlx::query_as!(
+            RailwayRow,
+            r#"SELECT
+                railway_id as "railway_id: RailwayId",
+                name,
+                abbreviation,
+                registered_company_name,
+                organization_entity_type as "organization_entity_type?: OrganizationEntityType",
+                description_de, 
+                description_en,
+                description_fr, 
+                description_it,
+                country,
+                uic_country_code,
+                vehicle_keeper_markings as "vehicle_keeper_markings!: Json<Vec<VehicleKeeperMarking>>",
+                operating_since,
+                operating_until,
+                status as "status?: RailwayStatus",
+                gauge_meters,
+                track_gauge as "track_gauge?: TrackGauge",
+                headquarters as "headquarters!: Vec<String>",
+                total_length_mi,
+                total_length_km,
+                contact_email as "contact_email?: MailAddress",
+                contact_website_url as "contact_website_url?: WebsiteUrl",
+                contact_phone as "contact_phone?: PhoneNumber",
+                socials_facebook as "socials_facebook?: Handler",
+                socials_instagram as "socials_instagram?: Handler",
+                socials_linkedin as "socials_linkedin?: Handler",
+                socials_twitter as "socials_twitter?: Handler",
+                socials_youtube as "socials_youtube?: Handler",
+                created_at,
+                last_modified_at,
+                deleted_at,
+                version
+            FROM railways 
+            WHERE railway_id = ANY($1)"#,
+            &railway_ids_param[..]
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to fetch the railways.")?;
+
+        result.to_output().map_err(DatabaseError::ConversionError)
+    }
+}
+
+#[async_trait]
+impl<'db> DeleteRailwayRepository<'db, PgUnitOfWork<'db>> for RailwaysRepository {
+    async fn is_deleted(
+        &self,
+        railway_id: &RailwayId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Option<bool>, anyhow::Error> {
+        let result = sqlx::query!("SELECT deleted_at FROM railways WHERE railway_id = $1", railway_id)
+            .fetch_optional(&mut *unit_of_work.transaction)
+            .await
+            .context("A database failure was encountered while trying to check for a railway deletion.")?;
+
+        Ok(result.map(|row| row.deleted_at.is_some()))
+    }
+
+    async fn soft_delete(
+        &self,
+        railway_id: &RailwayId,
+        deleted_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            r#"UPDATE railways SET deleted_at = $2, last_modified_at = $2, version = version + 1
+            WHERE railway_id = $1 AND deleted_at IS NULL"#,
+            railway_id,
+            deleted_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to delete a railway.")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn restore(
+        &self,
+        railway_id: &RailwayId,
+        last_modified_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            r#"UPDATE railways SET deleted_at = NULL, last_modified_at = $2, version = version + 1
+            WHERE railway_id = $1 AND deleted_at IS NOT NULL"#,
+            railway_id,
+            last_modified_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to restore a railway.")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[async_trait]
+impl<'db> NewRailwayRelationshipRepository<'db, PgUnitOfWork<'db>> for RailwaysRepository {
+    async fn railway_exists(
+        &self,
+        railway_id: &RailwayId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            "SELECT railway_id FROM railways WHERE railway_id = $1 AND deleted_at IS NULL LIMIT 1",
+            railway_id as &RailwayId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to check for a railway existence.")?;
+
+        Ok(result.is_some())
+    }
+
+    async fn find_relationships(
+        &self,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<RailwayRelationship>, anyhow::Error> {
+        let result = fetch_relationships(unit_of_work)
+            .await
+            .context("A database failure was encountered while trying to fetch the railway relationships.")?;
+        Ok(result)
+    }
+
+    async fn insert(
+        &self,
+        relationship: &RailwayRelationship,
+        created_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query!(
+            r#"INSERT INTO railway_relationships (
+                railway_id,
+                related_railway_id,
+                kind,
+                relationship_date,
+                created_at
+            )
+            VALUES ($1, $2, $3, $4, $5)"#,
+            &relationship.railway_id as &RailwayId,
+            &relationship.related_railway_id as &RailwayId,
+            relationship.kind as RelationshipKind,
+            relationship.date,
+            created_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to store a railway relationship.")?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<'db> FindRailwayLineageRepository<'db, PgUnitOfWork<'db>> for RailwaysRepository {
+    async fn railway_exists(
+        &self,
+        railway_id: &RailwayId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, DatabaseError> {
+        let result = sqlx::query!(
+            "SELECT railway_id FROM railways WHERE railway_id = $1 AND deleted_at IS NULL LIMIT 1",
+            railway_id as &RailwayId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to check for a railway existence.")?;
+
+        Ok(result.is_some())
+    }
+
+    async fn find_relationships(
+        &self,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<RailwayRelationship>, DatabaseError> {
+        let result = fetch_relationships(unit_of_work)
+            .await
+            .context("A database failure was encountered while trying to fetch the railway relationships.")?;
+        Ok(result)
+    }
+}
+
+async fn fetch_relationships(unit_of_work: &mut PgUnitOfWork<'_>) -> Result<Vec<RailwayRelationship>, sqlx::Error> {
+    sqlx::query_as!(
+        RailwayRelationship,
+        r#"SELECT
+            railway_id as "railway_id: RailwayId",
+            related_railway_id as "related_railway_id: RailwayId",
+            kind as "kind: RelationshipKind",
+            relationship_date as date
+        FROM railway_relationships
+        ORDER BY relationship_date NULLS LAST, railway_id, related_railway_id"#
+    )
+    .fetch_all(&mut *unit_of_work.transaction)
+    .await
+}
@@ -36,6 +36,8 @@ impl ToOutputConverter<Railway> for RailwayRow {
             organization_entity_type: row.organization_entity_type,
             description,
             country,
+            uic_country_code: row.uic_country_code.and_then(|it| u8::try_from(it).ok()),
+            vehicle_keeper_markings: row.vehicle_keeper_markings.0,
             period_of_activity,
             gauge,
             headquarters: row.headquarters,
@@ -137,11 +139,11 @@ impl OptionConverter<RailwayRow> for Socials {
 
 impl Converter<RailwayRow> for Metadata {
     fn try_convert(value: &RailwayRow) -> Result<Self, ConversionErrors> {
-        Ok(Metadata::new(
-            value.version as u8,
-            value.created_at,
-            value.last_modified_at,
-        ))
+        let metadata = Metadata::new(value.version as u8, value.created_at, value.last_modified_at);
+        match value.deleted_at {
+            Some(deleted_at) => Ok(metadata.deleted_at(deleted_at)),
+            None => Ok(metadata),
+        }
     }
 }
 
@@ -239,6 +241,25 @@ mod test {
             assert_eq!(None, metadata.last_modified());
             assert_eq!(42, metadata.version());
         }
+
+        #[test]
+        fn it_should_convert_metadata_for_deleted_rows() {
+            let now = Utc::now();
+            let row = RailwayRow {
+                created_at: now,
+                deleted_at: Some(now),
+                version: 42,
+                ..default_row()
+            };
+
+            let result = Metadata::try_convert(&row);
+
+            assert!(result.is_ok());
+
+            let metadata = result.unwrap();
+            assert!(metadata.is_deleted());
+            assert_eq!(Some(&now), metadata.deleted());
+        }
     }
 
     mod period_of_activity_converter {
@@ -492,4 +513,35 @@ mod test {
             assert_eq!(Some(Handler::new("twitter")), socials.twitter);
         }
     }
+
+    mod railway_converter {
+        use super::*;
+        use catalog::railways::vehicle_keeper_marking::VehicleKeeperMarking;
+        use chrono::NaiveDate;
+        use pretty_assertions::assert_eq;
+        use sqlx::types::Json;
+
+        #[test]
+        fn it_should_convert_the_vehicle_keepers() {
+            let marking = VehicleKeeperMarking::new("FS", None, NaiveDate::from_ymd_opt(2004, 12, 31));
+            let row = RailwayRow {
+                uic_country_code: Some(83),
+                vehicle_keeper_markings: Json(vec![marking.clone()]),
+                ..default_row()
+            };
+
+            let railway = row.to_output().expect("the railway conversion failed");
+
+            assert_eq!(Some(83), railway.uic_country_code);
+            assert_eq!(vec![marking], railway.vehicle_keeper_markings);
+        }
+
+        #[test]
+        fn it_should_convert_railways_without_vehicle_keepers() {
+            let railway = default_row().to_output().expect("the railway conversion failed");
+
+            assert_eq!(None, railway.uic_country_code);
+            assert!(railway.vehicle_keeper_markings.is_empty());
+        }
+    }
 }
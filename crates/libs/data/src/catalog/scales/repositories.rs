@@ -3,12 +3,14 @@ use anyhow::Context;
 use async_trait::async_trait;
 use catalog::common::TrackGauge;
 use catalog::scales::commands::new_scales::NewScaleCommand;
-use catalog::scales::commands::repositories::NewScaleRepository;
+use catalog::scales::commands::repositories::{DeleteScaleRepository, NewScaleRepository};
 use catalog::scales::queries::find_all_scales::FindAllScalesRepository;
 use catalog::scales::queries::find_scale_by_id::FindScaleByIdRepository;
+use catalog::scales::queries::find_scales_by_ids::FindScalesByIdsRepository;
 use catalog::scales::scale::Scale;
 use catalog::scales::scale_id::ScaleId;
 use catalog::scales::standard::Standard;
+use chrono::{DateTime, Utc};
 use common::queries::converters::ToOutputConverter;
 use common::queries::errors::DatabaseError;
 use common::unit_of_work::postgres::PgUnitOfWork;
@@ -77,7 +79,11 @@ impl<'db> NewScaleRepository<'db, PgUnitOfWork<'db>> for ScalesRepository {
 
 #[async_trait]
 impl<'db> FindAllScalesRepository<'db, PgUnitOfWork<'db>> for ScalesRepository {
-    async fn find_all(&self, unit_of_work: &mut PgUnitOfWork) -> Result<Vec<Scale>, DatabaseError> {
+    async fn find_all(
+        &self,
+        include_deleted: bool,
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<Vec<Scale>, DatabaseError> {
         let results = sqlx::query_as!(
             ScaleRow,
             r#"SELECT
@@ -94,9 +100,12 @@ impl<'db> FindAllScalesRepository<'db, PgUnitOfWork<'db>> for ScalesRepository {
                 standards as "standards!: Vec<Standard>",
                 created_at,
                 last_modified_at,
+                deleted_at,
                 version
             FROM scales
-            ORDER BY name"#
+            WHERE $1 OR deleted_at IS NULL
+            ORDER BY name"#,
+            include_deleted
         )
         .fetch_all(&mut *unit_of_work.transaction)
         .await
@@ -129,6 +138,7 @@ impl<'db> FindScaleByIdRepository<'db, PgUnitOfWork<'db>> for ScalesRepository {
                 standards as "standards!: Vec<Standard>",
                 created_at,
                 last_modified_at,
+                deleted_at,
                 version
             FROM scales
             WHERE scale_id = $1"#,
@@ -141,3 +151,95 @@ impl<'db> FindScaleByIdRepository<'db, PgUnitOfWork<'db>> for ScalesRepository {
         result.to_output().map_err(DatabaseError::ConversionError)
     }
 }
+
+#[async_trait]
+impl<'db> FindScalesByIdsRepository<'db, PgUnitOfWork<'db>> for ScalesRepository {
+    async fn find_by_ids(
+        &self,
+        scale_ids: &[ScaleId],
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<Scale>, DatabaseError> {
+        let scale_ids_param: Vec<String> = scale_ids.iter().map(|id| id.to_string()).collect();
+        let result = sqlx::query_as!(
+            ScaleRow,
+            r#"SELECT
+                scale_id as "scale_id: ScaleId",
+                name,
+                ratio,
+                gauge_millimeters,
+                gauge_inches,
+                track_gauge as "track_gauge: TrackGauge",
+                description_de, 
+                description_en,
+                description_fr, 
+                description_it,
+                standards as "standards!: Vec<Standard>",
+                created_at,
+                last_modified_at,
+                deleted_at,
+                version
+            FROM scales
+            WHERE scale_id = ANY($1)"#,
+            &scale_ids_param[..]
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to fetch the scales.")?;
+
+        result.to_output().map_err(DatabaseError::ConversionError)
+    }
+}
+
+#[async_trait]
+impl<'db> DeleteScaleRepository<'db, PgUnitOfWork<'db>> for ScalesRepository {
+    async fn is_deleted(
+        &self,
+        scale_id: &ScaleId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Option<bool>, anyhow::Error> {
+        let result = sqlx::query!("SELECT deleted_at FROM scales WHERE scale_id = $1", scale_id)
+            .fetch_optional(&mut *unit_of_work.transaction)
+            .await
+            .context("A database failure was encountered while trying to check for a scale deletion.")?;
+
+        Ok(result.map(|row| row.deleted_at.is_some()))
+    }
+
+    async fn soft_delete(
+        &self,
+        scale_id: &ScaleId,
+        deleted_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            r#"UPDATE scales SET deleted_at = $2, last_modified_at = $2, version = version + 1
+            WHERE scale_id = $1 AND deleted_at IS NULL"#,
+            scale_id,
+            deleted_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to delete a scale.")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn restore(
+        &self,
+        scale_id: &ScaleId,
+        last_modified_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            r#"UPDATE scales SET deleted_at = NULL, last_modified_at = $2, version = version + 1
+            WHERE scale_id = $1 AND deleted_at IS NOT NULL"#,
+            scale_id,
+            last_modified_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to restore a scale.")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
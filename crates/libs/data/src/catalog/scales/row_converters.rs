@@ -69,11 +69,11 @@ impl Converter<ScaleRow> for LocalizedText {
 
 impl Converter<ScaleRow> for Metadata {
     fn try_convert(value: &ScaleRow) -> Result<Self, ConversionErrors> {
-        Ok(Metadata::new(
-            value.version as u8,
-            value.created_at,
-            value.last_modified_at,
-        ))
+        let metadata = Metadata::new(value.version as u8, value.created_at, value.last_modified_at);
+        match value.deleted_at {
+            Some(deleted_at) => Ok(metadata.deleted_at(deleted_at)),
+            None => Ok(metadata),
+        }
     }
 }
 
@@ -235,5 +235,24 @@ mod test {
             assert_eq!(None, metadata.last_modified());
             assert_eq!(42, metadata.version());
         }
+
+        #[test]
+        fn it_should_convert_metadata_for_deleted_rows() {
+            let now = Utc::now();
+            let row = ScaleRow {
+                created_at: now,
+                deleted_at: Some(now),
+                version: 42,
+                ..default_row()
+            };
+
+            let result = Metadata::try_convert(&row);
+
+            assert!(result.is_ok());
+
+            let metadata = result.unwrap();
+            assert!(metadata.is_deleted());
+            assert_eq!(Some(&now), metadata.deleted());
+        }
     }
 }
@@ -20,6 +20,7 @@ pub struct ScaleRow {
     pub version: i32,
     pub created_at: DateTime<Utc>,
     pub last_modified_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 #[cfg(test)]
@@ -43,6 +44,7 @@ pub mod test {
             version: 1,
             created_at,
             last_modified_at: None,
+            deleted_at: None,
         }
     }
 }
@@ -0,0 +1,3 @@
+//! the persistence layer for the domain events outbox
+
+pub mod repositories;
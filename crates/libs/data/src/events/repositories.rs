@@ -0,0 +1,212 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use catalog::events::domain_event::{DomainEvent, EventEnvelope};
+use catalog::events::repositories::{
+    ClaimedEvent, EventFeedRepository, FeedEvent, FeedPosition, OutboxRepository, PendingEventsRepository,
+};
+use chrono::{DateTime, Utc};
+use common::unit_of_work::postgres::PgUnitOfWork;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct OutboxEventsRepository;
+
+#[async_trait]
+impl<'db> OutboxRepository<'db, PgUnitOfWork<'db>> for OutboxEventsRepository {
+    async fn append(&self, event: &DomainEvent, unit_of_work: &mut PgUnitOfWork) -> Result<(), anyhow::Error> {
+        let envelope = EventEnvelope::new(event.clone());
+        let payload = serde_json::to_value(&envelope.event).context("Unable to serialize the domain event.")?;
+
+        sqlx::query!(
+            r#"INSERT INTO outbox_events (
+                event_id,
+                aggregate_type,
+                aggregate_id,
+                event_type,
+                payload,
+                occurred_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)"#,
+            envelope.event_id,
+            envelope.event.aggregate_type().to_string(),
+            envelope.event.aggregate_id(),
+            envelope.event.event_type(),
+            payload,
+            envelope.occurred_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to store a domain event.")?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<'db> PendingEventsRepository<'db, PgUnitOfWork<'db>> for OutboxEventsRepository {
+    async fn claim_pending(
+        &self,
+        limit: i64,
+        now: &DateTime<Utc>,
+        claimed_until: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<Vec<ClaimedEvent>, anyhow::Error> {
+        let rows = sqlx::query!(
+            r#"WITH claimed AS (
+                UPDATE outbox_events
+                SET next_attempt_at = $3
+                WHERE event_id IN (
+                    SELECT event_id
+                    FROM outbox_events
+                    WHERE dispatched_at IS NULL AND dead_lettered_at IS NULL AND next_attempt_at <= $2
+                    ORDER BY created_at, occurred_at
+                    LIMIT $1
+                    FOR UPDATE SKIP LOCKED
+                )
+                RETURNING event_id, payload, occurred_at, created_at, attempts
+            )
+            SELECT event_id as "event_id!", payload as "payload!", occurred_at as "occurred_at!", attempts as "attempts!"
+            FROM claimed
+            ORDER BY created_at, occurred_at"#,
+            limit,
+            now,
+            claimed_until
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to claim the pending domain events.")?;
+
+        rows.into_iter()
+            .map(|row| {
+                let event: DomainEvent = serde_json::from_value(row.payload)
+                    .with_context(|| format!("Unable to deserialize the domain event (id: {})", row.event_id))?;
+                Ok(ClaimedEvent {
+                    envelope: EventEnvelope {
+                        event_id: row.event_id,
+                        event,
+                        occurred_at: row.occurred_at,
+                    },
+                    attempts: row.attempts,
+                })
+            })
+            .collect()
+    }
+
+    async fn mark_dispatched(
+        &self,
+        event_id: &Uuid,
+        dispatched_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query!(
+            r#"UPDATE outbox_events
+            SET dispatched_at = $2, last_attempt_at = $2, attempts = attempts + 1, last_error = NULL
+            WHERE event_id = $1"#,
+            event_id,
+            dispatched_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to mark a domain event as dispatched.")?;
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        event_id: &Uuid,
+        error: &str,
+        attempted_at: &DateTime<Utc>,
+        next_attempt_at: Option<&DateTime<Utc>>,
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query!(
+            r#"UPDATE outbox_events
+            SET attempts = attempts + 1,
+                last_error = $2,
+                last_attempt_at = $3::timestamptz,
+                next_attempt_at = COALESCE($4::timestamptz, next_attempt_at),
+                dead_lettered_at = CASE WHEN $4::timestamptz IS NULL THEN $3::timestamptz END
+            WHERE event_id = $1"#,
+            event_id,
+            error,
+            attempted_at,
+            next_attempt_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to record a domain event failure.")?;
+
+        Ok(())
+    }
+}
+
+/// The outbox feed, the events are following the order of the transactions which stored them.
+///
+/// Only the events stored by the transactions older than any transaction still running
+/// (`pg_snapshot_xmin`) are returned: a transaction which is not committed yet cannot store an event
+/// before the last position read from the feed.
+#[async_trait]
+impl<'db> EventFeedRepository<'db, PgUnitOfWork<'db>> for OutboxEventsRepository {
+    async fn last_position(&self, unit_of_work: &mut PgUnitOfWork) -> Result<Option<FeedPosition>, anyhow::Error> {
+        let row = sqlx::query!(
+            r#"SELECT transaction_id::text::bigint as "transaction_id!", sequence
+            FROM outbox_events
+            WHERE transaction_id < pg_snapshot_xmin(pg_current_snapshot())
+            ORDER BY transaction_id DESC, sequence DESC
+            LIMIT 1"#
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to read the outbox feed position.")?;
+
+        Ok(row.map(|row| FeedPosition {
+            transaction_id: row.transaction_id,
+            sequence: row.sequence,
+        }))
+    }
+
+    async fn find_after(
+        &self,
+        position: Option<&FeedPosition>,
+        limit: i64,
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<Vec<FeedEvent>, anyhow::Error> {
+        let position = position.copied().unwrap_or(FeedPosition {
+            transaction_id: 0,
+            sequence: 0,
+        });
+        let rows = sqlx::query!(
+            r#"SELECT transaction_id::text::bigint as "transaction_id!", sequence, event_id, payload, occurred_at
+            FROM outbox_events
+            WHERE (transaction_id, sequence) > ($1::text::xid8, $2)
+                AND transaction_id < pg_snapshot_xmin(pg_current_snapshot())
+            ORDER BY transaction_id, sequence
+            LIMIT $3"#,
+            position.transaction_id.to_string(),
+            position.sequence,
+            limit
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to read the outbox feed.")?;
+
+        rows.into_iter()
+            .map(|row| {
+                let event: DomainEvent = serde_json::from_value(row.payload)
+                    .with_context(|| format!("Unable to deserialize the domain event (id: {})", row.event_id))?;
+                Ok(FeedEvent {
+                    position: FeedPosition {
+                        transaction_id: row.transaction_id,
+                        sequence: row.sequence,
+                    },
+                    envelope: EventEnvelope {
+                        event_id: row.event_id,
+                        event,
+                        occurred_at: row.occurred_at,
+                    },
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,3 @@
+//! the persistence layer for the catalog item images
+
+pub mod repositories;
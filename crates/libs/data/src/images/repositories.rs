@@ -0,0 +1,224 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::images::catalog_item_image::CatalogItemImage;
+use catalog::images::commands::repositories::CatalogItemImagesRepository;
+use catalog::images::image_id::ImageId;
+use catalog::images::queries::find_catalog_item_images::FindCatalogItemImagesRepository;
+use catalog::images::queries::find_image_by_id::FindImageByIdRepository;
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::postgres::PgUnitOfWork;
+
+#[derive(Debug)]
+pub struct ImagesRepository;
+
+#[async_trait]
+impl<'db> CatalogItemImagesRepository<'db, PgUnitOfWork<'db>> for ImagesRepository {
+    async fn lock_catalog_item(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            "SELECT catalog_item_id FROM catalog_items WHERE catalog_item_id = $1 AND deleted_at IS NULL FOR UPDATE",
+            catalog_item_id as &CatalogItemId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to lock the catalog item.")?;
+
+        Ok(result.is_some())
+    }
+
+    async fn find_images(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<CatalogItemImage>, anyhow::Error> {
+        let results = find_images(catalog_item_id, unit_of_work)
+            .await
+            .context("A database failure was encountered while trying to fetch the catalog item images.")?;
+
+        Ok(results)
+    }
+
+    async fn insert(
+        &self,
+        image: &CatalogItemImage,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query!(
+            r#"INSERT INTO catalog_item_images (
+                image_id,
+                catalog_item_id,
+                content_type,
+                size_bytes,
+                position,
+                is_primary,
+                created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+            image.image_id as ImageId,
+            &image.catalog_item_id as &CatalogItemId,
+            image.content_type,
+            image.size_bytes,
+            image.position,
+            image.primary,
+            image.created_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to store a catalog item image.")?;
+
+        Ok(())
+    }
+
+    async fn update_position(
+        &self,
+        image_id: &ImageId,
+        position: i32,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query!(
+            "UPDATE catalog_item_images SET position = $2 WHERE image_id = $1",
+            image_id as &ImageId,
+            position
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to move a catalog item image.")?;
+
+        Ok(())
+    }
+
+    async fn set_primary(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        image_id: Option<&ImageId>,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<(), anyhow::Error> {
+        // the unique index on the primary images is checked row by row: the previous primary
+        // image must be cleared before the new one is set
+        sqlx::query!(
+            "UPDATE catalog_item_images SET is_primary = false WHERE catalog_item_id = $1 AND is_primary",
+            catalog_item_id as &CatalogItemId
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to clear the catalog item primary image.")?;
+
+        if let Some(image_id) = image_id {
+            sqlx::query!(
+                "UPDATE catalog_item_images SET is_primary = true WHERE catalog_item_id = $1 AND image_id = $2",
+                catalog_item_id as &CatalogItemId,
+                image_id as &ImageId
+            )
+            .execute(&mut *unit_of_work.transaction)
+            .await
+            .context("A database failure was encountered while trying to set the catalog item primary image.")?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, image_id: &ImageId, unit_of_work: &mut PgUnitOfWork<'db>) -> Result<(), anyhow::Error> {
+        sqlx::query!(
+            "DELETE FROM catalog_item_images WHERE image_id = $1",
+            image_id as &ImageId
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to delete a catalog item image.")?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<'db> FindCatalogItemImagesRepository<'db, PgUnitOfWork<'db>> for ImagesRepository {
+    async fn catalog_item_exists(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<bool, DatabaseError> {
+        let result = catalog_item_exists(catalog_item_id, unit_of_work).await?;
+        Ok(result)
+    }
+
+    async fn find_images(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Vec<CatalogItemImage>, DatabaseError> {
+        let results = find_images(catalog_item_id, unit_of_work).await?;
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl<'db> FindImageByIdRepository<'db, PgUnitOfWork<'db>> for ImagesRepository {
+    async fn find_image(
+        &self,
+        catalog_item_id: &CatalogItemId,
+        image_id: &ImageId,
+        unit_of_work: &mut PgUnitOfWork<'db>,
+    ) -> Result<Option<CatalogItemImage>, DatabaseError> {
+        let result = sqlx::query_as!(
+            CatalogItemImage,
+            r#"SELECT
+                i.image_id as "image_id: ImageId",
+                i.catalog_item_id as "catalog_item_id: CatalogItemId",
+                i.content_type,
+                i.size_bytes,
+                i.position,
+                i.is_primary as primary,
+                i.created_at
+            FROM catalog_item_images i
+            JOIN catalog_items c ON c.catalog_item_id = i.catalog_item_id
+            WHERE i.catalog_item_id = $1 AND i.image_id = $2 AND c.deleted_at IS NULL"#,
+            catalog_item_id as &CatalogItemId,
+            image_id as &ImageId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await?;
+
+        Ok(result)
+    }
+}
+
+async fn catalog_item_exists(
+    catalog_item_id: &CatalogItemId,
+    unit_of_work: &mut PgUnitOfWork<'_>,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        "SELECT catalog_item_id FROM catalog_items WHERE catalog_item_id = $1 AND deleted_at IS NULL LIMIT 1",
+        catalog_item_id as &CatalogItemId
+    )
+    .fetch_optional(&mut *unit_of_work.transaction)
+    .await?;
+
+    Ok(result.is_some())
+}
+
+async fn find_images(
+    catalog_item_id: &CatalogItemId,
+    unit_of_work: &mut PgUnitOfWork<'_>,
+) -> Result<Vec<CatalogItemImage>, sqlx::Error> {
+    sqlx::query_as!(
+        CatalogItemImage,
+        r#"SELECT
+            image_id as "image_id: ImageId",
+            catalog_item_id as "catalog_item_id: CatalogItemId",
+            content_type,
+            size_bytes,
+            position,
+            is_primary as primary,
+            created_at
+        FROM catalog_item_images
+        WHERE catalog_item_id = $1
+        ORDER BY position, created_at"#,
+        catalog_item_id as &CatalogItemId
+    )
+    .fetch_all(&mut *unit_of_work.transaction)
+    .await
+}
@@ -1 +1,6 @@
+pub mod cache;
 pub mod catalog;
+pub mod events;
+pub mod images;
+pub mod migrations;
+pub mod webhooks;
@@ -0,0 +1,108 @@
+//! the module includes the database migrations, embedded at compile time from the `migrations` folder
+
+use anyhow::Context;
+use sqlx::migrate::Migrator;
+use sqlx::{PgPool, Row};
+use std::collections::HashSet;
+
+/// The database migrations
+pub static MIGRATOR: Migrator = sqlx::migrate!("../../../migrations");
+
+/// the postgres error code for a missing table
+const UNDEFINED_TABLE: &str = "42P01";
+
+/// It represents the database migrations status, compared with the migrations embedded in the application
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationsStatus {
+    /// the versions of the migrations successfully applied
+    pub applied: Vec<i64>,
+    /// the versions of the migrations not yet applied (or failed)
+    pub pending: Vec<i64>,
+}
+
+impl MigrationsStatus {
+    /// Checks whether all the migrations have been applied
+    pub fn is_up_to_date(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    fn new(expected: impl Iterator<Item = i64>, applied: HashSet<i64>) -> Self {
+        let (mut applied_versions, mut pending): (Vec<i64>, Vec<i64>) =
+            expected.partition(|version| applied.contains(version));
+        applied_versions.sort_unstable();
+        pending.sort_unstable();
+        MigrationsStatus {
+            applied: applied_versions,
+            pending,
+        }
+    }
+}
+
+/// Returns the migrations status, reading the successfully applied migrations from the `_sqlx_migrations` table
+pub async fn migrations_status(pg_pool: &PgPool) -> Result<MigrationsStatus, anyhow::Error> {
+    let result = sqlx::query("SELECT version FROM _sqlx_migrations WHERE success")
+        .fetch_all(pg_pool)
+        .await;
+
+    let applied = match result {
+        Ok(rows) => rows
+            .iter()
+            .map(|row| row.try_get::<i64, _>("version"))
+            .collect::<Result<HashSet<i64>, _>>()
+            .context("A database failure was encountered while trying to read the applied migrations.")?,
+        Err(sqlx::Error::Database(why)) if why.code().as_deref() == Some(UNDEFINED_TABLE) => HashSet::new(),
+        Err(why) => {
+            return Err(why).context("A database failure was encountered while trying to read the applied migrations.")
+        }
+    };
+
+    let expected = MIGRATOR
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .map(|migration| migration.version);
+    Ok(MigrationsStatus::new(expected, applied))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod migrations_status {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_find_the_pending_migrations() {
+            let applied = HashSet::from([1, 2]);
+            let status = MigrationsStatus::new(vec![3, 1, 2, 4].into_iter(), applied);
+
+            assert_eq!(vec![1, 2], status.applied);
+            assert_eq!(vec![3, 4], status.pending);
+            assert!(!status.is_up_to_date());
+        }
+
+        #[test]
+        fn it_should_be_up_to_date_when_all_migrations_are_applied() {
+            let applied = HashSet::from([1, 2, 3]);
+            let status = MigrationsStatus::new(vec![1, 2].into_iter(), applied);
+
+            assert!(status.is_up_to_date());
+        }
+
+        #[test]
+        fn it_should_lock_the_database_while_applying_the_migrations() {
+            assert!(MIGRATOR.locking);
+        }
+
+        #[test]
+        fn it_should_embed_the_up_migrations() {
+            let versions: Vec<i64> = MIGRATOR
+                .iter()
+                .filter(|migration| !migration.migration_type.is_down_migration())
+                .map(|migration| migration.version)
+                .collect();
+
+            assert!(versions.contains(&20221220193301));
+        }
+    }
+}
@@ -0,0 +1,4 @@
+//! the persistence layer for the webhook subscriptions and their deliveries
+
+pub mod repositories;
+mod webhook_row;
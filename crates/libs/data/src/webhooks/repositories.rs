@@ -0,0 +1,298 @@
+use crate::webhooks::webhook_row::{WebhookDeliveryRow, WebhookRow};
+use anyhow::Context;
+use async_trait::async_trait;
+use catalog::events::domain_event::EventEnvelope;
+use catalog::webhooks::commands::deliver_webhooks::DeliveryUpdate;
+use catalog::webhooks::commands::new_webhook::NewWebhookCommand;
+use catalog::webhooks::commands::repositories::{
+    DeleteWebhookRepository, DeliverWebhooksRepository, NewWebhookRepository, ScheduleWebhookDeliveriesRepository,
+};
+use catalog::webhooks::queries::find_all_webhooks::FindAllWebhooksRepository;
+use catalog::webhooks::queries::find_webhook_by_id::FindWebhookByIdRepository;
+use catalog::webhooks::queries::find_webhook_deliveries::FindWebhookDeliveriesRepository;
+use catalog::webhooks::webhook::Webhook;
+use catalog::webhooks::webhook_delivery::{DeliveryStatus, PendingDelivery, WebhookDelivery};
+use catalog::webhooks::webhook_id::WebhookId;
+use chrono::{DateTime, Utc};
+use common::queries::errors::DatabaseError;
+use common::unit_of_work::postgres::PgUnitOfWork;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct WebhooksRepository;
+
+#[async_trait]
+impl<'db> NewWebhookRepository<'db, PgUnitOfWork<'db>> for WebhooksRepository {
+    async fn insert(
+        &self,
+        new_webhook: &NewWebhookCommand,
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<(), anyhow::Error> {
+        let request = &new_webhook.payload;
+        let metadata = &new_webhook.metadata;
+
+        sqlx::query!(
+            r#"INSERT INTO webhooks (
+                webhook_id,
+                target_url,
+                event_types,
+                secret,
+                created_at,
+                version
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)"#,
+            new_webhook.webhook_id as WebhookId,
+            request.target_url,
+            &request.event_types,
+            request.secret,
+            metadata.created(),
+            metadata.version() as i32
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to store a webhook.")?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<'db> DeleteWebhookRepository<'db, PgUnitOfWork<'db>> for WebhooksRepository {
+    async fn exists(&self, webhook_id: &WebhookId, unit_of_work: &mut PgUnitOfWork) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            "SELECT webhook_id FROM webhooks WHERE webhook_id = $1 LIMIT 1",
+            webhook_id as &WebhookId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to check for a webhook existence.")?;
+
+        Ok(result.is_some())
+    }
+
+    async fn delete(&self, webhook_id: &WebhookId, unit_of_work: &mut PgUnitOfWork) -> Result<(), anyhow::Error> {
+        sqlx::query!("DELETE FROM webhooks WHERE webhook_id = $1", webhook_id as &WebhookId)
+            .execute(&mut *unit_of_work.transaction)
+            .await
+            .context("A database failure was encountered while trying to delete a webhook.")?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<'db> ScheduleWebhookDeliveriesRepository<'db, PgUnitOfWork<'db>> for WebhooksRepository {
+    async fn find_subscribers(
+        &self,
+        event_type: &str,
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<Vec<WebhookId>, anyhow::Error> {
+        let results = sqlx::query!(
+            r#"SELECT webhook_id as "webhook_id: WebhookId" FROM webhooks WHERE $1 = ANY(event_types)"#,
+            event_type
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to find the webhook subscribers.")?;
+
+        Ok(results.into_iter().map(|row| row.webhook_id).collect())
+    }
+
+    async fn insert_delivery(
+        &self,
+        webhook_id: &WebhookId,
+        envelope: &EventEnvelope,
+        payload: &str,
+        next_attempt_at: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query!(
+            r#"INSERT INTO webhook_deliveries (
+                delivery_id,
+                webhook_id,
+                event_id,
+                event_type,
+                payload,
+                status,
+                next_attempt_at,
+                created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (webhook_id, event_id) DO NOTHING"#,
+            Uuid::new_v4(),
+            webhook_id as &WebhookId,
+            envelope.event_id,
+            envelope.event.event_type(),
+            payload,
+            DeliveryStatus::Pending as DeliveryStatus,
+            next_attempt_at,
+            Utc::now()
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to store a webhook delivery.")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[async_trait]
+impl<'db> DeliverWebhooksRepository<'db, PgUnitOfWork<'db>> for WebhooksRepository {
+    async fn claim_due(
+        &self,
+        limit: i64,
+        now: &DateTime<Utc>,
+        claimed_until: &DateTime<Utc>,
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<Vec<PendingDelivery>, anyhow::Error> {
+        let results = sqlx::query_as!(
+            PendingDelivery,
+            r#"WITH claimed AS (
+                UPDATE webhook_deliveries
+                SET next_attempt_at = $3
+                WHERE delivery_id IN (
+                    SELECT delivery_id
+                    FROM webhook_deliveries
+                    WHERE status = 'PENDING' AND next_attempt_at <= $2
+                    ORDER BY next_attempt_at
+                    LIMIT $1
+                    FOR UPDATE SKIP LOCKED
+                )
+                RETURNING delivery_id, webhook_id, event_id, event_type, payload, attempts, created_at
+            )
+            SELECT
+                c.delivery_id as "delivery_id!",
+                c.webhook_id as "webhook_id!: WebhookId",
+                c.event_id as "event_id!",
+                c.event_type as "event_type!",
+                c.payload as "payload!",
+                c.attempts as "attempts!",
+                w.target_url,
+                w.secret
+            FROM claimed c
+            JOIN webhooks w ON w.webhook_id = c.webhook_id
+            ORDER BY c.created_at"#,
+            limit,
+            now,
+            claimed_until
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to claim the due webhook deliveries.")?;
+
+        Ok(results)
+    }
+
+    async fn record_attempt(
+        &self,
+        update: &DeliveryUpdate,
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<(), anyhow::Error> {
+        sqlx::query!(
+            r#"UPDATE webhook_deliveries SET
+                status = $2,
+                attempts = $3,
+                last_response_status = $4,
+                last_error = $5,
+                next_attempt_at = $6,
+                delivered_at = $7,
+                last_attempt_at = $8
+            WHERE delivery_id = $1"#,
+            update.delivery_id,
+            update.status as DeliveryStatus,
+            update.attempts,
+            update.last_response_status,
+            update.last_error,
+            update.next_attempt_at,
+            update.delivered_at,
+            update.attempted_at
+        )
+        .execute(&mut *unit_of_work.transaction)
+        .await
+        .context("A database failure was encountered while trying to record a webhook delivery attempt.")?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<'db> FindAllWebhooksRepository<'db, PgUnitOfWork<'db>> for WebhooksRepository {
+    async fn find_all(&self, unit_of_work: &mut PgUnitOfWork) -> Result<Vec<Webhook>, DatabaseError> {
+        let results = sqlx::query_as!(
+            WebhookRow,
+            r#"SELECT
+                webhook_id as "webhook_id: WebhookId",
+                target_url,
+                event_types as "event_types!: Vec<String>",
+                created_at,
+                last_modified_at,
+                version
+            FROM webhooks
+            ORDER BY created_at"#
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await?;
+
+        Ok(results.into_iter().map(Webhook::from).collect())
+    }
+}
+
+#[async_trait]
+impl<'db> FindWebhookByIdRepository<'db, PgUnitOfWork<'db>> for WebhooksRepository {
+    async fn find_by_id(
+        &self,
+        webhook_id: &WebhookId,
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<Option<Webhook>, DatabaseError> {
+        let result = sqlx::query_as!(
+            WebhookRow,
+            r#"SELECT
+                webhook_id as "webhook_id: WebhookId",
+                target_url,
+                event_types as "event_types!: Vec<String>",
+                created_at,
+                last_modified_at,
+                version
+            FROM webhooks
+            WHERE webhook_id = $1"#,
+            webhook_id as &WebhookId
+        )
+        .fetch_optional(&mut *unit_of_work.transaction)
+        .await?;
+
+        Ok(result.map(Webhook::from))
+    }
+}
+
+#[async_trait]
+impl<'db> FindWebhookDeliveriesRepository<'db, PgUnitOfWork<'db>> for WebhooksRepository {
+    async fn find_deliveries(
+        &self,
+        webhook_id: &WebhookId,
+        unit_of_work: &mut PgUnitOfWork,
+    ) -> Result<Vec<WebhookDelivery>, DatabaseError> {
+        let results = sqlx::query_as!(
+            WebhookDeliveryRow,
+            r#"SELECT
+                delivery_id,
+                webhook_id as "webhook_id: WebhookId",
+                event_id,
+                event_type,
+                status as "status: DeliveryStatus",
+                attempts,
+                last_response_status,
+                last_error,
+                next_attempt_at,
+                delivered_at,
+                created_at
+            FROM webhook_deliveries
+            WHERE webhook_id = $1
+            ORDER BY created_at DESC"#,
+            webhook_id as &WebhookId
+        )
+        .fetch_all(&mut *unit_of_work.transaction)
+        .await?;
+
+        Ok(results.into_iter().map(WebhookDelivery::from).collect())
+    }
+}
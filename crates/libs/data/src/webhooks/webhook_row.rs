@@ -0,0 +1,92 @@
+use catalog::webhooks::webhook::Webhook;
+use catalog::webhooks::webhook_delivery::{DeliveryStatus, WebhookDelivery};
+use catalog::webhooks::webhook_id::WebhookId;
+use chrono::{DateTime, Utc};
+use common::metadata::Metadata;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct WebhookRow {
+    pub webhook_id: WebhookId,
+    pub target_url: String,
+    pub event_types: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_modified_at: Option<DateTime<Utc>>,
+    pub version: i32,
+}
+
+impl From<WebhookRow> for Webhook {
+    fn from(row: WebhookRow) -> Self {
+        Webhook {
+            webhook_id: row.webhook_id,
+            target_url: row.target_url,
+            event_types: row.event_types,
+            metadata: Metadata::new(row.version as u8, row.created_at, row.last_modified_at),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct WebhookDeliveryRow {
+    pub delivery_id: Uuid,
+    pub webhook_id: WebhookId,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub last_response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WebhookDeliveryRow> for WebhookDelivery {
+    fn from(row: WebhookDeliveryRow) -> Self {
+        WebhookDelivery {
+            delivery_id: row.delivery_id,
+            webhook_id: row.webhook_id,
+            event_id: row.event_id,
+            event_type: row.event_type,
+            status: row.status,
+            attempts: row.attempts,
+            last_response_status: row.last_response_status,
+            last_error: row.last_error,
+            next_attempt_at: row.next_attempt_at,
+            delivered_at: row.delivered_at,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod webhook_row_converters {
+        use super::*;
+        use chrono::TimeZone;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_convert_webhook_rows() {
+            let created_at = Utc.with_ymd_and_hms(2026, 10, 18, 9, 0, 0).unwrap();
+            let webhook_id = WebhookId::new();
+            let row = WebhookRow {
+                webhook_id,
+                target_url: String::from("https://www.example.com/hooks"),
+                event_types: vec![String::from("BrandCreated")],
+                created_at,
+                last_modified_at: None,
+                version: 1,
+            };
+
+            let webhook = Webhook::from(row);
+
+            assert_eq!(webhook_id, webhook.webhook_id);
+            assert_eq!("https://www.example.com/hooks", webhook.target_url);
+            assert_eq!(vec![String::from("BrandCreated")], webhook.event_types);
+            assert_eq!(Metadata::new(1, created_at, None), webhook.metadata);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::calendar::routes::CALENDAR_RELEASES_API;
+use crate::openapi::responses::{BadRequest, InternalServerError};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use axum::extract::{Query, State};
+use axum::Json;
+use catalog::brands::brand_id::BrandId;
+use catalog::catalog_items::delivery_date::DeliveryDate;
+use catalog::catalog_items::queries::find_upcoming_releases::{find_upcoming_releases, ReleasesCriteria};
+use catalog::catalog_items::release_calendar::{Granularity, ReleaseCalendar};
+use chrono::{NaiveDate, Utc};
+use data::catalog::catalog_item::repositories::CatalogItemsRepository;
+use serde::Deserialize;
+use std::str::FromStr;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+/// The upcoming releases query string
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReleasesQuery {
+    /// the first delivery period (ie, `2026`, `2026-Q3` or `2026-07`), the current month when missing
+    pub from: Option<String>,
+    /// the last delivery period (ie, `2027`, `2027-Q1` or `2027-03`), the end of next year when missing
+    pub to: Option<String>,
+    /// the calendar periods size (`month`, `quarter` or `year`)
+    pub granularity: Option<String>,
+    /// only the catalog items for these brands (comma separated)
+    pub brand: Option<String>,
+}
+
+/// Returns the announced catalog items, grouped by their delivery dates
+#[utoipa::path(
+    get,
+    path = "/api/calendar/releases",
+    tag = "calendar",
+    operation_id = "get-releases",
+    params(ReleasesQuery, ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    responses(
+        (status = 200, description = "the upcoming releases calendar", body = ReleaseCalendar),
+        (status = 400, response = BadRequest),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_releases", skip(app_state))]
+pub async fn handle(
+    Query(query): Query<ReleasesQuery>,
+    consistency: ReadConsistency,
+    State(app_state): State<AppState>,
+) -> Result<Json<ReleaseCalendar>, ProblemDetail> {
+    let request_id = Uuid::new_v4();
+    let bad_request = |detail: String| ProblemDetail::bad_request(request_id, &detail);
+
+    let criteria = to_criteria(
+        query.from.as_deref(),
+        query.to.as_deref(),
+        query.brand.as_deref(),
+        Utc::now().date_naive(),
+        app_state.calendar.max_releases_years,
+    )
+    .map_err(bad_request)?;
+    let granularity = query
+        .granularity
+        .as_deref()
+        .map(|value| Granularity::from_str(value).map_err(|_| format!("invalid granularity: {value}")))
+        .transpose()
+        .map_err(bad_request)?
+        .unwrap_or_default();
+
+    let database = app_state.get_read_database(consistency);
+    let repo = CatalogItemsRepository;
+
+    let catalog_items = find_upcoming_releases(&criteria, repo, database)
+        .await
+        .map_err(|why| why.to_problem_detail(request_id, Some(CALENDAR_RELEASES_API)))?;
+
+    Ok(Json(ReleaseCalendar::new(
+        criteria.from,
+        criteria.to,
+        granularity,
+        catalog_items,
+    )))
+}
+
+/// Converts the query string values to the releases criteria, the missing periods are relative to `today`
+/// and the criteria cannot cover more than `max_years` years
+pub(crate) fn to_criteria(
+    from: Option<&str>,
+    to: Option<&str>,
+    brand: Option<&str>,
+    today: NaiveDate,
+    max_years: i32,
+) -> Result<ReleasesCriteria, String> {
+    fn parse(value: &str, name: &str) -> Result<DeliveryDate, String> {
+        DeliveryDate::from_str(&value.trim().replace('-', "/")).map_err(|_| format!("invalid {name}: {value}"))
+    }
+
+    let from = match from {
+        Some(from) => parse(from, "from")?,
+        None => DeliveryDate::month_of(today),
+    };
+    let to = match to {
+        Some(to) => parse(to, "to")?,
+        None => DeliveryDate::by_year(from.year() + 1),
+    };
+    let brand_ids = brand
+        .map(|brands| {
+            brands
+                .split(',')
+                .map(str::trim)
+                .filter(|it| !it.is_empty())
+                .map(BrandId::new)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    ReleasesCriteria::new(from, to, brand_ids, max_years).map_err(|why| why.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod releases_criteria {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        const MAX_YEARS: i32 = 5;
+
+        fn today() -> NaiveDate {
+            NaiveDate::from_ymd_opt(2026, 10, 19).unwrap()
+        }
+
+        #[test]
+        fn it_should_convert_the_query_to_criteria() {
+            let criteria = to_criteria(Some("2026-Q3"), Some("2027"), Some("ACME, roco"), today(), MAX_YEARS)
+                .expect("the criteria are valid");
+
+            assert_eq!(DeliveryDate::by_quarter(2026, 3), criteria.from);
+            assert_eq!(DeliveryDate::by_year(2027), criteria.to);
+            assert_eq!(vec![BrandId::new("ACME"), BrandId::new("roco")], criteria.brand_ids);
+        }
+
+        #[test]
+        fn it_should_use_the_default_periods() {
+            let criteria = to_criteria(None, None, None, today(), MAX_YEARS).expect("the criteria are valid");
+
+            assert_eq!(DeliveryDate::by_month(2026, 10), criteria.from);
+            assert_eq!(DeliveryDate::by_year(2027), criteria.to);
+            assert!(criteria.brand_ids.is_empty());
+        }
+
+        #[test]
+        fn it_should_reject_invalid_periods() {
+            assert_eq!(
+                Err(String::from("invalid from: 2026-Q5")),
+                to_criteria(Some("2026-Q5"), None, None, today(), MAX_YEARS)
+            );
+            assert_eq!(
+                Err(String::from("The releases period start cannot be after its end")),
+                to_criteria(Some("2027"), Some("2026-12"), None, today(), MAX_YEARS)
+            );
+        }
+
+        #[test]
+        fn it_should_reject_periods_longer_than_the_max_years() {
+            assert!(to_criteria(Some("2026"), Some("2030-12"), None, today(), MAX_YEARS).is_ok());
+            assert_eq!(
+                Err(String::from("The releases period cannot be longer than 5 years")),
+                to_criteria(Some("2026"), Some("2031"), None, today(), MAX_YEARS)
+            );
+        }
+    }
+}
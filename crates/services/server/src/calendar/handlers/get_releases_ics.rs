@@ -0,0 +1,196 @@
+use crate::calendar::handlers::get_releases::to_criteria;
+use crate::calendar::ics::{Calendar, Event};
+use crate::calendar::routes::CALENDAR_RELEASES_ICS;
+use crate::catalog::catalog_items::routes::CATALOG_ITEMS_ROOT_API;
+use crate::openapi::responses::{BadRequest, InternalServerError};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::web::urls::base_url;
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use catalog::catalog_items::catalog_item::CatalogItem;
+use catalog::catalog_items::queries::find_upcoming_releases::{find_upcoming_releases, ReleasesCriteria};
+use chrono::{DateTime, Days, Utc};
+use common::localized_text::Language;
+use data::catalog::catalog_item::repositories::CatalogItemsRepository;
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+/// The upcoming releases iCalendar query string
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReleasesIcsQuery {
+    /// the first delivery period (ie, `2026`, `2026-Q3` or `2026-07`), the current month when missing
+    pub from: Option<String>,
+    /// the last delivery period (ie, `2027`, `2027-Q1` or `2027-03`), the end of next year when missing
+    pub to: Option<String>,
+    /// only the catalog items for these brands (comma separated)
+    pub brand: Option<String>,
+}
+
+/// The iCalendar export for the announced catalog items, with one all-day event for every delivery date
+#[utoipa::path(
+    get,
+    path = "/api/calendar/releases.ics",
+    tag = "calendar",
+    operation_id = "get-releases-ics",
+    params(ReleasesIcsQuery, ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    responses(
+        (status = 200, description = "the upcoming releases calendar", content_type = "text/calendar", body = String),
+        (status = 400, response = BadRequest),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_releases_ics", skip(app_state, headers))]
+pub async fn handle(
+    Query(query): Query<ReleasesIcsQuery>,
+    headers: HeaderMap,
+    consistency: ReadConsistency,
+    State(app_state): State<AppState>,
+) -> Result<Calendar, ProblemDetail> {
+    let request_id = Uuid::new_v4();
+
+    let now = Utc::now();
+    let criteria = to_criteria(
+        query.from.as_deref(),
+        query.to.as_deref(),
+        query.brand.as_deref(),
+        now.date_naive(),
+        app_state.calendar.max_releases_years,
+    )
+    .map_err(|detail| ProblemDetail::bad_request(request_id, &detail))?;
+
+    let database = app_state.get_read_database(consistency);
+    let repo = CatalogItemsRepository;
+
+    let catalog_items = find_upcoming_releases(&criteria, repo, database)
+        .await
+        .map_err(|why| why.to_problem_detail(request_id, Some(CALENDAR_RELEASES_ICS)))?;
+
+    Ok(to_calendar(
+        &criteria,
+        &base_url(app_state.public_url.as_deref(), &headers),
+        now,
+        catalog_items,
+    ))
+}
+
+fn to_calendar(
+    criteria: &ReleasesCriteria,
+    base_url: &str,
+    timestamp: DateTime<Utc>,
+    catalog_items: Vec<CatalogItem>,
+) -> Calendar {
+    let events = catalog_items
+        .into_iter()
+        .filter_map(|catalog_item| {
+            let delivery_date = catalog_item.delivery_date.as_ref()?;
+            Some(Event {
+                uid: format!("{}@trenako", catalog_item.id()),
+                timestamp,
+                start: delivery_date.first_day(),
+                end: delivery_date.last_day() + Days::new(1),
+                summary: format!("{} {}", catalog_item.brand().display(), catalog_item.item_number()),
+                description: catalog_item
+                    .description
+                    .label_or_english(Language::English)
+                    .or_else(|| catalog_item.description())
+                    .cloned(),
+                url: Some(format!("{base_url}{CATALOG_ITEMS_ROOT_API}/{}", catalog_item.id())),
+                categories: vec![catalog_item.scale().display().to_string(), delivery_date.to_string()],
+            })
+        })
+        .collect();
+
+    let name = if criteria.brand_ids.is_empty() {
+        String::from("Upcoming releases")
+    } else {
+        let brands: Vec<String> = criteria.brand_ids.iter().map(ToString::to_string).collect();
+        format!("Upcoming releases: {}", brands.join(", "))
+    };
+
+    Calendar {
+        product_id: String::from("-//trenako//releases//EN"),
+        name,
+        events,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod releases_calendar {
+        use super::*;
+        use catalog::brands::brand_id::BrandId;
+        use catalog::catalog_items::availability_status::AvailabilityStatus;
+        use catalog::catalog_items::catalog_item::{CatalogItemBrand, CatalogItemScale};
+        use catalog::catalog_items::catalog_item_id::CatalogItemId;
+        use catalog::catalog_items::category::Category;
+        use catalog::catalog_items::delivery_date::DeliveryDate;
+        use catalog::catalog_items::epoch::Epoch;
+        use catalog::catalog_items::item_number::ItemNumber;
+        use catalog::catalog_items::power_method::PowerMethod;
+        use catalog::scales::scale_id::ScaleId;
+        use chrono::{NaiveDate, TimeZone};
+        use common::metadata::Metadata;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_create_one_event_for_every_catalog_item() {
+            let timestamp = Utc.with_ymd_and_hms(2026, 10, 19, 10, 30, 0).unwrap();
+            let criteria = ReleasesCriteria::new(
+                DeliveryDate::by_quarter(2026, 3),
+                DeliveryDate::by_year(2027),
+                vec![BrandId::new("ACME")],
+                5,
+            )
+            .unwrap();
+
+            let calendar = to_calendar(&criteria, "http://localhost:5000", timestamp, vec![new_catalog_item()]);
+
+            assert_eq!("Upcoming releases: acme", calendar.name);
+            assert_eq!(1, calendar.events.len());
+
+            let event = &calendar.events[0];
+            assert_eq!("acme-60142@trenako", event.uid);
+            assert_eq!(timestamp, event.timestamp);
+            assert_eq!(NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(), event.start);
+            assert_eq!(NaiveDate::from_ymd_opt(2027, 1, 1).unwrap(), event.end);
+            assert_eq!("ACME 60142", event.summary);
+            assert_eq!(Some(String::from("Electric locomotive E.645")), event.description);
+            assert_eq!(
+                Some(String::from("http://localhost:5000/api/catalog-items/acme-60142")),
+                event.url
+            );
+            assert_eq!(
+                vec![String::from("H0 (1:87)"), String::from("2026/Q4")],
+                event.categories
+            );
+        }
+
+        fn new_catalog_item() -> CatalogItem {
+            let brand = CatalogItemBrand::new(BrandId::new("ACME"), "ACME");
+            let item_number = ItemNumber::new("60142");
+            CatalogItem::new(
+                CatalogItemId::of(&BrandId::new("ACME"), &item_number),
+                brand,
+                item_number,
+                Category::Locomotives,
+                CatalogItemScale::new(ScaleId::new("H0"), "H0 (1:87)"),
+                Some("Electric locomotive E.645"),
+                None,
+                Vec::new(),
+                PowerMethod::DC,
+                Epoch::IV,
+                Some(DeliveryDate::by_quarter(2026, 4)),
+                Some(AvailabilityStatus::Announced),
+                1,
+                Metadata::created_at(Utc.with_ymd_and_hms(2026, 10, 18, 10, 30, 0).unwrap()),
+            )
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod get_releases;
+pub(crate) mod get_releases_ics;
+
+pub use get_releases::handle as get_releases;
+pub use get_releases_ics::handle as get_releases_ics;
@@ -0,0 +1,209 @@
+//! a minimal iCalendar (RFC 5545) writer
+
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// The iCalendar media type
+pub const ICS_MEDIA_TYPE: &str = "text/calendar; charset=utf-8";
+
+/// The max length (in octets) for the content lines, the longer lines are folded
+const MAX_LINE_LENGTH: usize = 75;
+
+/// It represents an iCalendar object
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calendar {
+    /// the identifier for the product that created the calendar
+    pub product_id: String,
+    /// the calendar name
+    pub name: String,
+    /// the calendar events
+    pub events: Vec<Event>,
+}
+
+/// It represents an all-day iCalendar event
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    /// the globally unique event identifier
+    pub uid: String,
+    /// the timestamp when the event was created
+    pub timestamp: DateTime<Utc>,
+    /// the first day for the event
+    pub start: NaiveDate,
+    /// the day after the last one for the event (the end date is not inclusive)
+    pub end: NaiveDate,
+    /// the event summary
+    pub summary: String,
+    /// the event description
+    pub description: Option<String>,
+    /// the link to the resource described by the event
+    pub url: Option<String>,
+    /// the event categories
+    pub categories: Vec<String>,
+}
+
+impl Calendar {
+    /// Writes the calendar as an iCalendar document
+    pub fn to_ics(&self) -> String {
+        let mut ics = String::new();
+        write_line(&mut ics, "BEGIN:VCALENDAR");
+        write_line(&mut ics, "VERSION:2.0");
+        write_line(&mut ics, &format!("PRODID:{}", escape(&self.product_id)));
+        write_line(&mut ics, "CALSCALE:GREGORIAN");
+        write_line(&mut ics, "METHOD:PUBLISH");
+        write_line(&mut ics, &format!("X-WR-CALNAME:{}", escape(&self.name)));
+
+        for event in self.events.iter() {
+            event.write_ics(&mut ics);
+        }
+
+        write_line(&mut ics, "END:VCALENDAR");
+        ics
+    }
+}
+
+impl Event {
+    fn write_ics(&self, ics: &mut String) {
+        write_line(ics, "BEGIN:VEVENT");
+        write_line(ics, &format!("UID:{}", escape(&self.uid)));
+        write_line(ics, &format!("DTSTAMP:{}", self.timestamp.format("%Y%m%dT%H%M%SZ")));
+        write_line(ics, &format!("DTSTART;VALUE=DATE:{}", self.start.format("%Y%m%d")));
+        write_line(ics, &format!("DTEND;VALUE=DATE:{}", self.end.format("%Y%m%d")));
+        write_line(ics, &format!("SUMMARY:{}", escape(&self.summary)));
+        if let Some(description) = &self.description {
+            write_line(ics, &format!("DESCRIPTION:{}", escape(description)));
+        }
+        if let Some(url) = &self.url {
+            write_line(ics, &format!("URL:{url}"));
+        }
+        if !self.categories.is_empty() {
+            let categories: Vec<String> = self.categories.iter().map(|it| escape(it)).collect();
+            write_line(ics, &format!("CATEGORIES:{}", categories.join(",")));
+        }
+        write_line(ics, "TRANSP:TRANSPARENT");
+        write_line(ics, "END:VEVENT");
+    }
+}
+
+impl IntoResponse for Calendar {
+    fn into_response(self) -> Response {
+        ([(CONTENT_TYPE, ICS_MEDIA_TYPE)], self.to_ics()).into_response()
+    }
+}
+
+/// Writes a content line, folding it when longer than 75 octets (the characters are never split)
+fn write_line(ics: &mut String, line: &str) {
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > MAX_LINE_LENGTH {
+            ics.push_str("\r\n ");
+            // the leading space counts for the continuation line length
+            length = 1;
+        }
+        ics.push(c);
+        length += c.len_utf8();
+    }
+    ics.push_str("\r\n");
+}
+
+/// Escapes the iCalendar text special characters
+fn escape(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => output.push_str("\\\\"),
+            ';' => output.push_str("\\;"),
+            ',' => output.push_str("\\,"),
+            '\n' => output.push_str("\\n"),
+            '\r' => {}
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod icalendar {
+        use super::*;
+        use chrono::TimeZone;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_escape_the_text_special_characters() {
+            assert_eq!(r"a\\b\; c\, d\ne", escape("a\\b; c, d\r\ne"));
+        }
+
+        #[test]
+        fn it_should_fold_the_long_lines() {
+            let mut ics = String::new();
+            write_line(&mut ics, &format!("SUMMARY:{}", "x".repeat(100)));
+
+            let lines: Vec<&str> = ics.split("\r\n").collect();
+            assert_eq!(3, lines.len());
+            assert_eq!(75, lines[0].len());
+            assert!(lines[1].starts_with(' '));
+            assert_eq!(34, lines[1].len());
+            assert_eq!("", lines[2]);
+        }
+
+        #[test]
+        fn it_should_not_split_multi_byte_characters() {
+            let mut ics = String::new();
+            write_line(&mut ics, &format!("SUMMARY:{}", "è".repeat(50)));
+
+            for line in ics.split("\r\n") {
+                assert!(line.len() <= MAX_LINE_LENGTH);
+            }
+            assert_eq!(
+                format!("SUMMARY:{}", "è".repeat(50)),
+                ics.replace("\r\n ", "").trim_end()
+            );
+        }
+
+        #[test]
+        fn it_should_write_calendars() {
+            let timestamp = Utc.with_ymd_and_hms(2026, 10, 19, 10, 30, 0).unwrap();
+            let calendar = Calendar {
+                product_id: String::from("-//trenako//releases//EN"),
+                name: String::from("Releases: acme"),
+                events: vec![Event {
+                    uid: String::from("acme-60142@trenako"),
+                    timestamp,
+                    start: NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+                    end: NaiveDate::from_ymd_opt(2027, 1, 1).unwrap(),
+                    summary: String::from("ACME 60142"),
+                    description: Some(String::from("Locomotiva elettrica E.645, FS")),
+                    url: Some(String::from("http://localhost/api/catalog-items/acme-60142")),
+                    categories: vec![String::from("H0"), String::from("2026/Q4")],
+                }],
+            };
+
+            let expected = [
+                "BEGIN:VCALENDAR",
+                "VERSION:2.0",
+                "PRODID:-//trenako//releases//EN",
+                "CALSCALE:GREGORIAN",
+                "METHOD:PUBLISH",
+                "X-WR-CALNAME:Releases: acme",
+                "BEGIN:VEVENT",
+                "UID:acme-60142@trenako",
+                "DTSTAMP:20261019T103000Z",
+                "DTSTART;VALUE=DATE:20261001",
+                "DTEND;VALUE=DATE:20270101",
+                "SUMMARY:ACME 60142",
+                r"DESCRIPTION:Locomotiva elettrica E.645\, FS",
+                "URL:http://localhost/api/catalog-items/acme-60142",
+                "CATEGORIES:H0,2026/Q4",
+                "TRANSP:TRANSPARENT",
+                "END:VEVENT",
+                "END:VCALENDAR",
+                "",
+            ]
+            .join("\r\n");
+            assert_eq!(expected, calendar.to_ics());
+        }
+    }
+}
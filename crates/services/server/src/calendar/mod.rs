@@ -0,0 +1,5 @@
+//! the module includes the upcoming releases calendar, with its iCalendar export
+
+pub mod handlers;
+pub mod ics;
+pub mod routes;
@@ -0,0 +1,13 @@
+use crate::calendar::handlers;
+use crate::state::AppState;
+use axum::routing::get;
+use axum::Router;
+
+pub const CALENDAR_RELEASES_API: &str = "/api/calendar/releases";
+pub const CALENDAR_RELEASES_ICS: &str = "/api/calendar/releases.ics";
+
+pub fn calendar_router() -> Router<AppState> {
+    Router::new()
+        .route(CALENDAR_RELEASES_API, get(handlers::get_releases))
+        .route(CALENDAR_RELEASES_ICS, get(handlers::get_releases_ics))
+}
@@ -1,9 +1,129 @@
+use crate::catalog::brands::routes::BRANDS_ROOT_API;
+use crate::openapi::responses::{Gone, InternalServerError, NotFound};
 use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
 use axum::extract::{Path, State};
-use axum::response::IntoResponse;
+use axum::http::StatusCode;
 use catalog::brands::brand_id::BrandId;
+use catalog::brands::commands::delete_brand::{delete_brand, BrandDeletionError};
+use data::catalog::brands::repositories::BrandsRepository;
+use data::events::repositories::OutboxEventsRepository;
+use uuid::Uuid;
 
-#[tracing::instrument(name = "delete_brand", skip(_app_state))]
-pub async fn handle(Path(_brand_id): Path<BrandId>, State(_app_state): State<AppState>) -> impl IntoResponse {
-    ().into_response()
+/// Deletes the brand with the given id, the brand can be restored later
+#[utoipa::path(
+    delete,
+    path = "/api/brands/{brand_id}",
+    tag = "brands",
+    operation_id = "delete-brand",
+    params(("brand_id" = BrandId, Path, description = "the brand unique identifier")),
+    responses(
+        (status = 204, description = "the brand has been deleted"),
+        (status = 404, response = NotFound),
+        (status = 410, response = Gone),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "delete_brand", skip(app_state))]
+pub async fn handle(
+    Path(brand_id): Path<BrandId>,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode, ProblemDetail> {
+    let repo = app_state.cached(BrandsRepository);
+    let outbox = OutboxEventsRepository;
+    let database = app_state.get_database();
+
+    let result = delete_brand(&brand_id, repo, outbox, database).await;
+    result.map(|_| StatusCode::NO_CONTENT).map_err(|why| {
+        let path = format!("{}/{}", BRANDS_ROOT_API, brand_id);
+        why.to_problem_detail(Uuid::new_v4(), Some(&path))
+    })
+}
+
+impl ToProblemDetail for BrandDeletionError {
+    fn to_problem_detail(self, request_id: Uuid, _path: Option<&str>) -> ProblemDetail {
+        match self {
+            BrandDeletionError::BrandNotFound(_) => ProblemDetail::not_found(request_id, &self.to_string()),
+            BrandDeletionError::BrandAlreadyDeleted(_) => ProblemDetail::gone(request_id, &self.to_string()),
+            BrandDeletionError::BrandNotDeleted(_) => ProblemDetail::conflict(request_id, &self.to_string()),
+            BrandDeletionError::UnexpectedError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            BrandDeletionError::DatabaseError(why) => ProblemDetail::error(request_id, &why.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod brand_deletion_error_to_problem_detail {
+        use super::*;
+        use anyhow::anyhow;
+        use common::queries::errors::DatabaseError;
+        use common::trn::Trn;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_return_not_found_when_the_brand_does_not_exist() {
+            let err = BrandDeletionError::BrandNotFound(BrandId::new("ACME"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::NOT_FOUND, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/404", problem_detail.problem_type.as_str());
+            assert_eq!("The brand was not found (id: acme)", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_gone_when_the_brand_was_already_deleted() {
+            let err = BrandDeletionError::BrandAlreadyDeleted(BrandId::new("ACME"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::GONE, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/410", problem_detail.problem_type.as_str());
+            assert_eq!("The brand has been already deleted (id: acme)", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_conflict_when_the_brand_is_not_deleted() {
+            let err = BrandDeletionError::BrandNotDeleted(BrandId::new("ACME"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::CONFLICT, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/409", problem_detail.problem_type.as_str());
+            assert_eq!("The brand is not deleted (id: acme)", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_generic_errors() {
+            let err = BrandDeletionError::UnexpectedError(anyhow!("Something bad just happened"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/500", problem_detail.problem_type.as_str());
+            assert_eq!("Something bad just happened", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_database_errors() {
+            let err = BrandDeletionError::DatabaseError(DatabaseError::UnexpectedError(anyhow!(
+                "Something bad just happened"
+            )));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/500", problem_detail.problem_type.as_str());
+            assert_eq!("Something bad just happened", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+    }
 }
@@ -1,24 +1,44 @@
 use crate::catalog::brands::routes;
 use crate::hateoas::representations::CollectionModel;
+use crate::openapi::responses::{InternalServerError, Unauthorized};
+use crate::replica::consistency::ReadConsistency;
 use crate::state::AppState;
+use crate::web::admin::SoftDeleteQuery;
 use crate::web::problem::ProblemDetail;
 use crate::web::responders::ToProblemDetail;
 use axum::extract::{Query, State};
 use catalog::brands::brand::Brand;
 use catalog::brands::queries::find_all_brands::find_all_brands;
 use common::queries::pagination::PageRequest;
+use common::queries::soft_delete::SoftDeleteFilter;
 use data::catalog::brands::repositories::BrandsRepository;
 use uuid::Uuid;
 
+/// Returns the brands list
+#[utoipa::path(
+    get,
+    path = "/api/brands",
+    tag = "brands",
+    operation_id = "get-brands",
+    params(PageRequest, SoftDeleteFilter, ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    security((), ("admin_api_key" = [])),
+    responses(
+        (status = 200, description = "the brands list", body = BrandsCollection),
+        (status = 401, response = Unauthorized),
+        (status = 500, response = InternalServerError),
+    )
+)]
 #[tracing::instrument(name = "get_all_brands", skip(app_state))]
 pub async fn handle(
     Query(_page_request): Query<PageRequest>,
+    SoftDeleteQuery(filter): SoftDeleteQuery,
+    consistency: ReadConsistency,
     State(app_state): State<AppState>,
 ) -> Result<CollectionModel<Brand>, ProblemDetail> {
-    let database = app_state.get_database();
-    let repo = BrandsRepository;
+    let database = app_state.get_read_database(consistency);
+    let repo = app_state.cached(BrandsRepository);
 
-    let results = find_all_brands(repo, database).await;
+    let results = find_all_brands(filter.include_deleted, repo, database).await;
     results
         .map(|brands| CollectionModel::of(brands, Vec::new()))
         .map_err(|why| why.to_problem_detail(Uuid::new_v4(), Some(routes::BRANDS_ROOT_API)))
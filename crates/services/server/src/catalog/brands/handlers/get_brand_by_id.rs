@@ -1,24 +1,46 @@
 use crate::catalog::brands::routes;
 use crate::hateoas::representations::EntityModel;
+use crate::openapi::responses::{Gone, InternalServerError, NotFound, Unauthorized};
+use crate::replica::consistency::ReadConsistency;
 use crate::state::AppState;
+use crate::web::admin::SoftDeleteQuery;
 use crate::web::problem::ProblemDetail;
 use crate::web::responders::ToProblemDetail;
 use axum::extract::{Path, State};
 use catalog::brands::brand::Brand;
 use catalog::brands::brand_id::BrandId;
 use catalog::brands::queries::find_brand_by_id::find_brand_by_id;
+use common::queries::soft_delete::SoftDeleteFilter;
 use data::catalog::brands::repositories::BrandsRepository;
 use uuid::Uuid;
 
+/// Returns the brand with the given id
+#[utoipa::path(
+    get,
+    path = "/api/brands/{brand_id}",
+    tag = "brands",
+    operation_id = "get-brand-by-id",
+    params(("brand_id" = BrandId, Path, description = "the brand unique identifier"), SoftDeleteFilter, ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    security((), ("admin_api_key" = [])),
+    responses(
+        (status = 200, description = "the brand", body = BrandModel),
+        (status = 401, response = Unauthorized),
+        (status = 404, response = NotFound),
+        (status = 410, response = Gone),
+        (status = 500, response = InternalServerError),
+    )
+)]
 #[tracing::instrument(name = "get_brand_by_id", skip(app_state))]
 pub async fn handle(
     Path(brand_id): Path<BrandId>,
+    SoftDeleteQuery(filter): SoftDeleteQuery,
+    consistency: ReadConsistency,
     State(app_state): State<AppState>,
 ) -> Result<EntityModel<Brand>, ProblemDetail> {
-    let database = app_state.get_database();
-    let repo = BrandsRepository;
+    let database = app_state.get_read_database(consistency);
+    let repo = app_state.cached(BrandsRepository);
 
-    let result = find_brand_by_id(&brand_id, repo, database).await;
+    let result = find_brand_by_id(&brand_id, filter.include_deleted, repo, database).await;
     result
         .map(|brand| EntityModel::of(brand, vec![]))
         .map_err(|why| why.to_problem_detail(Uuid::new_v4(), Some(routes::BRANDS_ROOT_API)))
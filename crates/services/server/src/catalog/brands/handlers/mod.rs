@@ -1,11 +1,13 @@
-mod delete_brand;
-mod get_all_brands;
-mod get_brand_by_id;
-mod post_brand;
-mod put_brand;
+pub(crate) mod delete_brand;
+pub(crate) mod get_all_brands;
+pub(crate) mod get_brand_by_id;
+pub(crate) mod post_brand;
+pub(crate) mod put_brand;
+pub(crate) mod restore_brand;
 
 pub use delete_brand::handle as delete_brand;
 pub use get_all_brands::handle as get_all_brands;
 pub use get_brand_by_id::handle as get_brand_by_id;
 pub use post_brand::handle as post_brand;
 pub use put_brand::handle as put_brand;
+pub use restore_brand::handle as restore_brand;
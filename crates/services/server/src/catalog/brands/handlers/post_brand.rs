@@ -1,4 +1,5 @@
 use crate::catalog::brands::routes::BRANDS_ROOT_API;
+use crate::openapi::responses::{AlreadyExists, BadRequest, InternalServerError};
 use crate::state::AppState;
 use crate::web::problem::ProblemDetail;
 use crate::web::responders::{Created, ToProblemDetail};
@@ -7,17 +8,33 @@ use axum::Json;
 use catalog::brands::brand_request::BrandRequest;
 use catalog::brands::commands::new_brand::{create_new_brand, BrandCreationError};
 use data::catalog::brands::repositories::BrandsRepository;
+use data::events::repositories::OutboxEventsRepository;
 use uuid::Uuid;
 
+/// Creates a new brand
+#[utoipa::path(
+    post,
+    path = "/api/brands",
+    tag = "brands",
+    operation_id = "post-brand",
+    request_body = BrandRequest,
+    responses(
+        (status = 201, description = "the brand has been created", headers(("location" = String, description = "the new brand url"))),
+        (status = 400, response = BadRequest),
+        (status = 409, response = AlreadyExists),
+        (status = 500, response = InternalServerError),
+    )
+)]
 #[tracing::instrument(name = "create_new_brand", skip(app_state))]
 pub async fn handle(
     State(app_state): State<AppState>,
     Json(request): Json<BrandRequest>,
 ) -> Result<Created, ProblemDetail> {
-    let repo = BrandsRepository;
+    let repo = app_state.cached(BrandsRepository);
+    let outbox = OutboxEventsRepository;
     let database = app_state.get_database();
 
-    let result = create_new_brand(request, repo, database).await;
+    let result = create_new_brand(request, repo, outbox, database).await;
     result
         .map(|created| {
             let location = format!("{}/{}", BRANDS_ROOT_API, created.brand_id);
@@ -0,0 +1,46 @@
+use crate::catalog::brands::routes::BRANDS_ROOT_API;
+use crate::openapi::responses::{Conflict, InternalServerError, NotFound, Unauthorized};
+use crate::state::AppState;
+use crate::web::admin::AdminAccess;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use catalog::brands::brand_id::BrandId;
+use catalog::brands::commands::delete_brand::restore_brand;
+use data::catalog::brands::repositories::BrandsRepository;
+use data::events::repositories::OutboxEventsRepository;
+use uuid::Uuid;
+
+/// Restores the deleted brand with the given id
+#[utoipa::path(
+    post,
+    path = "/api/brands/{brand_id}/restore",
+    tag = "brands",
+    operation_id = "restore-brand",
+    params(("brand_id" = BrandId, Path, description = "the brand unique identifier")),
+    security(("admin_api_key" = [])),
+    responses(
+        (status = 204, description = "the brand has been restored"),
+        (status = 401, response = Unauthorized),
+        (status = 404, response = NotFound),
+        (status = 409, response = Conflict),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "restore_brand", skip(app_state))]
+pub async fn handle(
+    Path(brand_id): Path<BrandId>,
+    _admin: AdminAccess,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode, ProblemDetail> {
+    let repo = app_state.cached(BrandsRepository);
+    let outbox = OutboxEventsRepository;
+    let database = app_state.get_database();
+
+    let result = restore_brand(&brand_id, repo, outbox, database).await;
+    result.map(|_| StatusCode::NO_CONTENT).map_err(|why| {
+        let path = format!("{}/{}", BRANDS_ROOT_API, brand_id);
+        why.to_problem_detail(Uuid::new_v4(), Some(&path))
+    })
+}
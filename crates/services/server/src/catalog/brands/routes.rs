@@ -1,10 +1,11 @@
 use crate::catalog::brands::handlers;
 use crate::state::AppState;
-use axum::routing::{delete, get};
+use axum::routing::{delete, get, post};
 use axum::Router;
 
 pub const BRANDS_ROOT_API: &str = "/api/brands";
 pub const BRAND_ROOT_API: &str = "/api/brands/:brand_id";
+pub const BRAND_RESTORE_API: &str = "/api/brands/:brand_id/restore";
 
 pub fn brands_router() -> Router<AppState> {
     Router::new()
@@ -18,4 +19,5 @@ pub fn brands_router() -> Router<AppState> {
                 .get(handlers::get_brand_by_id)
                 .put(handlers::put_brand),
         )
+        .route(BRAND_RESTORE_API, post(handlers::restore_brand))
 }
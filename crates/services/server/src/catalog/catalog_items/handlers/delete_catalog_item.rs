@@ -1,12 +1,147 @@
+use crate::catalog::catalog_items::routes::CATALOG_ITEMS_ROOT_API;
+use crate::openapi::responses::{Gone, InternalServerError, NotFound};
 use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
 use axum::extract::{Path, State};
-use axum::response::IntoResponse;
+use axum::http::StatusCode;
 use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::catalog_items::commands::delete_catalog_item::{delete_catalog_item, CatalogItemDeletionError};
+use data::catalog::catalog_item::repositories::CatalogItemsRepository;
+use data::events::repositories::OutboxEventsRepository;
+use uuid::Uuid;
 
-#[tracing::instrument(name = "delete_catalog_item", skip(_app_state))]
+/// Deletes the catalog item with the given id, the catalog item can be restored later
+#[utoipa::path(
+    delete,
+    path = "/api/catalog-items/{catalogItemId}",
+    tag = "catalog-items",
+    operation_id = "delete-catalog-item",
+    params(("catalogItemId" = CatalogItemId, Path, description = "the catalog item unique identifier")),
+    responses(
+        (status = 204, description = "the catalog item has been deleted"),
+        (status = 404, response = NotFound),
+        (status = 410, response = Gone),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "delete_catalog_item", skip(app_state))]
 pub async fn handle(
-    Path(_catalog_item_id): Path<CatalogItemId>,
-    State(_app_state): State<AppState>,
-) -> impl IntoResponse {
-    ().into_response()
+    Path(catalog_item_id): Path<CatalogItemId>,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode, ProblemDetail> {
+    let repo = CatalogItemsRepository;
+    let outbox = OutboxEventsRepository;
+    let database = app_state.get_database();
+
+    let result = delete_catalog_item(&catalog_item_id, repo, outbox, database).await;
+    result.map(|_| StatusCode::NO_CONTENT).map_err(|why| {
+        let path = format!("{}/{}", CATALOG_ITEMS_ROOT_API, catalog_item_id);
+        why.to_problem_detail(Uuid::new_v4(), Some(&path))
+    })
+}
+
+impl ToProblemDetail for CatalogItemDeletionError {
+    fn to_problem_detail(self, request_id: Uuid, _path: Option<&str>) -> ProblemDetail {
+        match self {
+            CatalogItemDeletionError::CatalogItemNotFound(_) => ProblemDetail::not_found(request_id, &self.to_string()),
+            CatalogItemDeletionError::CatalogItemAlreadyDeleted(_) => {
+                ProblemDetail::gone(request_id, &self.to_string())
+            }
+            CatalogItemDeletionError::CatalogItemNotDeleted(_) => {
+                ProblemDetail::conflict(request_id, &self.to_string())
+            }
+            CatalogItemDeletionError::UnexpectedError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            CatalogItemDeletionError::DatabaseError(why) => ProblemDetail::error(request_id, &why.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod catalog_item_deletion_error_to_problem_detail {
+        use super::*;
+        use anyhow::anyhow;
+        use common::queries::errors::DatabaseError;
+        use common::trn::Trn;
+        use pretty_assertions::assert_eq;
+        use std::str::FromStr;
+
+        #[test]
+        fn it_should_return_not_found_when_the_catalog_item_does_not_exist() {
+            let err = CatalogItemDeletionError::CatalogItemNotFound(catalog_item_id());
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::NOT_FOUND, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/404", problem_detail.problem_type.as_str());
+            assert_eq!(
+                "The catalog item was not found (id: acme-123456)",
+                problem_detail.detail
+            );
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_gone_when_the_catalog_item_was_already_deleted() {
+            let err = CatalogItemDeletionError::CatalogItemAlreadyDeleted(catalog_item_id());
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::GONE, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/410", problem_detail.problem_type.as_str());
+            assert_eq!(
+                "The catalog item has been already deleted (id: acme-123456)",
+                problem_detail.detail
+            );
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_conflict_when_the_catalog_item_is_not_deleted() {
+            let err = CatalogItemDeletionError::CatalogItemNotDeleted(catalog_item_id());
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::CONFLICT, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/409", problem_detail.problem_type.as_str());
+            assert_eq!(
+                "The catalog item is not deleted (id: acme-123456)",
+                problem_detail.detail
+            );
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_generic_errors() {
+            let err = CatalogItemDeletionError::UnexpectedError(anyhow!("Something bad just happened"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/500", problem_detail.problem_type.as_str());
+            assert_eq!("Something bad just happened", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_database_errors() {
+            let err = CatalogItemDeletionError::DatabaseError(DatabaseError::UnexpectedError(anyhow!(
+                "Something bad just happened"
+            )));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/500", problem_detail.problem_type.as_str());
+            assert_eq!("Something bad just happened", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        fn catalog_item_id() -> CatalogItemId {
+            CatalogItemId::from_str("acme-123456").unwrap()
+        }
+    }
 }
@@ -0,0 +1,90 @@
+use crate::catalog::catalog_items::routes::CATALOG_ITEMS_ROOT_API;
+use crate::openapi::responses::{InternalServerError, NotFound};
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::images::commands::delete_image::{delete_image, ImageDeletionError};
+use catalog::images::image_id::ImageId;
+use data::images::repositories::ImagesRepository;
+use uuid::Uuid;
+
+/// Deletes the image from the catalog item gallery
+#[utoipa::path(
+    delete,
+    path = "/api/catalog-items/{catalogItemId}/images/{imageId}",
+    tag = "catalog-items",
+    operation_id = "delete-catalog-item-image",
+    params(
+        ("catalogItemId" = CatalogItemId, Path, description = "the catalog item unique identifier"),
+        ("imageId" = ImageId, Path, description = "the image unique identifier")
+    ),
+    responses(
+        (status = 204, description = "the image has been deleted"),
+        (status = 404, response = NotFound),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "delete_catalog_item_image", skip(app_state))]
+pub async fn handle(
+    Path((catalog_item_id, image_id)): Path<(CatalogItemId, ImageId)>,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode, ProblemDetail> {
+    let repo = ImagesRepository;
+    let database = app_state.get_database();
+
+    let result = delete_image(&catalog_item_id, &image_id, repo, app_state.images.storage(), database).await;
+    result.map(|_| StatusCode::NO_CONTENT).map_err(|why| {
+        let path = format!("{CATALOG_ITEMS_ROOT_API}/{catalog_item_id}/images/{image_id}");
+        why.to_problem_detail(Uuid::new_v4(), Some(&path))
+    })
+}
+
+impl ToProblemDetail for ImageDeletionError {
+    fn to_problem_detail(self, request_id: Uuid, _path: Option<&str>) -> ProblemDetail {
+        match self {
+            ImageDeletionError::ImageNotFound(_) => ProblemDetail::not_found(request_id, &self.to_string()),
+            ImageDeletionError::StorageError(why) => ProblemDetail::error(request_id, &format!("{why:#}")),
+            ImageDeletionError::UnexpectedError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            ImageDeletionError::DatabaseError(why) => ProblemDetail::error(request_id, &why.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod image_deletion_error_to_problem_detail {
+        use super::*;
+        use anyhow::anyhow;
+        use common::trn::Trn;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_return_not_found_when_the_image_does_not_exist() {
+            let image_id = ImageId::new();
+            let err = ImageDeletionError::ImageNotFound(image_id);
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::NOT_FOUND, problem_detail.status);
+            assert_eq!(
+                format!("The image was not found (id: {image_id})"),
+                problem_detail.detail
+            );
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_storage_errors() {
+            let err = ImageDeletionError::StorageError(anyhow!("the storage is down"));
+
+            let problem_detail = err.to_problem_detail(Uuid::new_v4(), None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("the storage is down", problem_detail.detail);
+        }
+    }
+}
@@ -1,14 +1,122 @@
+use crate::catalog::catalog_items::routes::CATALOG_ITEMS_ROOT_API;
+use crate::openapi::responses::{Gone, InternalServerError, NotFound};
 use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
 use axum::extract::{Path, State};
-use axum::response::IntoResponse;
+use axum::http::StatusCode;
 use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::catalog_items::commands::delete_rolling_stock::{delete_rolling_stock, RollingStockDeletionError};
 use catalog::catalog_items::rolling_stock_id::RollingStockId;
+use data::catalog::catalog_item::repositories::RollingStocksRepository;
+use data::events::repositories::OutboxEventsRepository;
+use uuid::Uuid;
 
-#[tracing::instrument(name = "delete_rolling_stock", skip(_app_state))]
+/// Deletes the rolling stock with the given id, the rolling stock can be restored later
+#[utoipa::path(
+    delete,
+    path = "/api/catalog-items/{catalogItemId}/rolling-stocks/{rollingStockId}",
+    tag = "catalog-items",
+    operation_id = "delete-rolling-stock",
+    params(("catalogItemId" = CatalogItemId, Path, description = "the catalog item unique identifier"), ("rollingStockId" = RollingStockId, Path, description = "the rolling stock unique identifier")),
+    responses(
+        (status = 204, description = "the rolling stock has been deleted"),
+        (status = 404, response = NotFound),
+        (status = 410, response = Gone),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "delete_rolling_stock", skip(app_state))]
 pub async fn handle(
-    Path(_catalog_item_id): Path<CatalogItemId>,
-    Path(_rolling_stock_id): Path<RollingStockId>,
-    State(_app_state): State<AppState>,
-) -> impl IntoResponse {
-    ().into_response()
+    Path((catalog_item_id, rolling_stock_id)): Path<(CatalogItemId, RollingStockId)>,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode, ProblemDetail> {
+    let repo = RollingStocksRepository;
+    let outbox = OutboxEventsRepository;
+    let database = app_state.get_database();
+
+    let result = delete_rolling_stock(&catalog_item_id, &rolling_stock_id, repo, outbox, database).await;
+    result.map(|_| StatusCode::NO_CONTENT).map_err(|why| {
+        let path = format!(
+            "{}/{}/rolling-stocks/{}",
+            CATALOG_ITEMS_ROOT_API, catalog_item_id, rolling_stock_id
+        );
+        why.to_problem_detail(Uuid::new_v4(), Some(&path))
+    })
+}
+
+impl ToProblemDetail for RollingStockDeletionError {
+    fn to_problem_detail(self, request_id: Uuid, _path: Option<&str>) -> ProblemDetail {
+        match self {
+            RollingStockDeletionError::RollingStockNotFound(_) => {
+                ProblemDetail::not_found(request_id, &self.to_string())
+            }
+            RollingStockDeletionError::RollingStockAlreadyDeleted(_) => {
+                ProblemDetail::gone(request_id, &self.to_string())
+            }
+            RollingStockDeletionError::RollingStockNotDeleted(_) => {
+                ProblemDetail::conflict(request_id, &self.to_string())
+            }
+            RollingStockDeletionError::UnexpectedError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            RollingStockDeletionError::DatabaseError(why) => ProblemDetail::error(request_id, &why.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod rolling_stock_deletion_error_to_problem_detail {
+        use super::*;
+        use anyhow::anyhow;
+        use common::trn::Trn;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_return_not_found_when_the_rolling_stock_does_not_exist() {
+            let rolling_stock_id = RollingStockId::new();
+            let err = RollingStockDeletionError::RollingStockNotFound(rolling_stock_id);
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::NOT_FOUND, problem_detail.status);
+            assert_eq!(
+                format!("The rolling stock was not found (id: {})", rolling_stock_id),
+                problem_detail.detail
+            );
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_gone_when_the_rolling_stock_was_already_deleted() {
+            let err = RollingStockDeletionError::RollingStockAlreadyDeleted(RollingStockId::new());
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::GONE, problem_detail.status);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_conflict_when_the_rolling_stock_is_not_deleted() {
+            let err = RollingStockDeletionError::RollingStockNotDeleted(RollingStockId::new());
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::CONFLICT, problem_detail.status);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_generic_errors() {
+            let err = RollingStockDeletionError::UnexpectedError(anyhow!("Something bad just happened"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("Something bad just happened", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+    }
 }
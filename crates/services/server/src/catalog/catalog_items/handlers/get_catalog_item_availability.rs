@@ -0,0 +1,71 @@
+use crate::catalog::catalog_items::routes::CATALOG_ITEMS_ROOT_API;
+use crate::hateoas::links::{Link, LinkRelation};
+use crate::hateoas::representations::EntityModel;
+use crate::openapi::responses::{InternalServerError, NotFound};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::web::urls::base_url;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use catalog::catalog_items::availability::Availability;
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::catalog_items::queries::find_availability::find_availability;
+use chrono::Utc;
+use data::catalog::catalog_item::repositories::CatalogItemsRepository;
+use uuid::Uuid;
+
+/// Returns the catalog item availability status, together with the history of its changes
+#[utoipa::path(
+    get,
+    path = "/api/catalog-items/{catalogItemId}/availability",
+    tag = "catalog-items",
+    operation_id = "get-catalog-item-availability",
+    params(("catalogItemId" = CatalogItemId, Path, description = "the catalog item unique identifier"), ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    responses(
+        (status = 200, description = "the catalog item availability", body = AvailabilityModel),
+        (status = 404, response = NotFound),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_catalog_item_availability", skip(app_state, headers))]
+pub async fn handle(
+    Path(catalog_item_id): Path<CatalogItemId>,
+    consistency: ReadConsistency,
+    headers: HeaderMap,
+    State(app_state): State<AppState>,
+) -> Result<EntityModel<Availability>, ProblemDetail> {
+    let request_id = Uuid::new_v4();
+    let path = availability_path(&catalog_item_id);
+
+    let database = app_state.get_read_database(consistency);
+    let repo = CatalogItemsRepository;
+
+    let availability = find_availability(&catalog_item_id, Utc::now().date_naive(), repo, database)
+        .await
+        .map_err(|why| why.to_problem_detail(request_id, Some(&path)))?
+        .ok_or_else(|| {
+            ProblemDetail::not_found(
+                request_id,
+                &format!("The catalog item was not found (id: {catalog_item_id})"),
+            )
+        })?;
+
+    Ok(to_model(
+        availability,
+        &base_url(app_state.public_url.as_deref(), &headers),
+    ))
+}
+
+/// Returns the path for the catalog item availability
+pub(crate) fn availability_path(catalog_item_id: &CatalogItemId) -> String {
+    format!("{CATALOG_ITEMS_ROOT_API}/{catalog_item_id}/availability")
+}
+
+/// Wraps the availability, adding the link to itself
+pub(crate) fn to_model(availability: Availability, base_url: &str) -> EntityModel<Availability> {
+    let href = format!("{base_url}{}", availability_path(&availability.catalog_item_id));
+    let links = Link::of(&href, LinkRelation::SelfLink).into_iter().collect();
+    EntityModel::of(availability, links)
+}
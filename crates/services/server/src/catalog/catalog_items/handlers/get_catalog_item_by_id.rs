@@ -1,28 +1,70 @@
+use crate::catalog::catalog_items::handlers::get_catalog_item_images::image_links;
 use crate::catalog::catalog_items::routes;
+use crate::hateoas::links::{Link, LinkRelation};
 use crate::hateoas::representations::EntityModel;
+use crate::openapi::responses::{Gone, InternalServerError, NotFound, Unauthorized};
+use crate::replica::consistency::ReadConsistency;
 use crate::state::AppState;
+use crate::web::admin::SoftDeleteQuery;
 use crate::web::problem::ProblemDetail;
 use crate::web::responders::ToProblemDetail;
+use crate::web::urls::base_url;
 use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use catalog::catalog_items::catalog_item::CatalogItem;
 use catalog::catalog_items::catalog_item_id::CatalogItemId;
 use catalog::catalog_items::queries::find_catalog_item_by_id::find_catalog_item_by_id;
+use catalog::images::queries::find_catalog_item_images::find_catalog_item_images;
+use common::queries::errors::QueryError;
+use common::queries::soft_delete::SoftDeleteFilter;
 use data::catalog::catalog_item::repositories::CatalogItemsRepository;
+use data::images::repositories::ImagesRepository;
 use uuid::Uuid;
 
-#[tracing::instrument(name = "get_catalog_item_by_id", skip(app_state))]
+/// Returns the catalog item with the given id, the links include the catalog item images
+#[utoipa::path(
+    get,
+    path = "/api/catalog-items/{catalogItemId}",
+    tag = "catalog-items",
+    operation_id = "get-catalog-item-by-id",
+    params(("catalogItemId" = CatalogItemId, Path, description = "the catalog item unique identifier"), SoftDeleteFilter, ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    security((), ("admin_api_key" = [])),
+    responses(
+        (status = 200, description = "the catalog item", body = CatalogItemModel),
+        (status = 401, response = Unauthorized),
+        (status = 404, response = NotFound),
+        (status = 410, response = Gone),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_catalog_item_by_id", skip(app_state, headers))]
 pub async fn handle(
     Path(catalog_item_id): Path<CatalogItemId>,
+    SoftDeleteQuery(filter): SoftDeleteQuery,
+    consistency: ReadConsistency,
+    headers: HeaderMap,
     State(app_state): State<AppState>,
 ) -> Result<EntityModel<CatalogItem>, ProblemDetail> {
-    let database = app_state.get_database();
     let repo = CatalogItemsRepository;
+    let path = format!("{}/{}", routes::CATALOG_ITEMS_ROOT_API, catalog_item_id);
 
-    let result = find_catalog_item_by_id(&catalog_item_id, repo, database).await;
-    result
-        .map(|catalog_item| EntityModel::of(catalog_item, Vec::new()))
-        .map_err(|why| {
-            let path = format!("{}/{}", routes::CATALOG_ITEMS_ROOT_API, catalog_item_id);
-            why.to_problem_detail(Uuid::new_v4(), Some(&path))
-        })
+    let database = app_state.get_read_database(consistency);
+    let catalog_item = find_catalog_item_by_id(&catalog_item_id, filter.include_deleted, repo, database)
+        .await
+        .map_err(|why| why.to_problem_detail(Uuid::new_v4(), Some(&path)))?;
+
+    // the images of a deleted catalog item are not available
+    let database = app_state.get_read_database(consistency);
+    let images = match find_catalog_item_images(&catalog_item_id, ImagesRepository, database).await {
+        Ok(images) => images,
+        Err(QueryError::EmptyResultSet) => Vec::new(),
+        Err(why) => return Err(why.to_problem_detail(Uuid::new_v4(), Some(&path))),
+    };
+
+    let base_url = base_url(app_state.public_url.as_deref(), &headers);
+    let links = Link::of(&format!("{base_url}{path}"), LinkRelation::SelfLink)
+        .into_iter()
+        .chain(image_links(&base_url, &images, LinkRelation::Image))
+        .collect();
+    Ok(EntityModel::of(catalog_item, links))
 }
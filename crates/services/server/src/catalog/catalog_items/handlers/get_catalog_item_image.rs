@@ -0,0 +1,70 @@
+use crate::catalog::catalog_items::routes::CATALOG_ITEMS_ROOT_API;
+use crate::openapi::responses::{InternalServerError, NotFound};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use axum::extract::{Path, State};
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE};
+use axum::response::{IntoResponse, Response};
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::images::image_id::ImageId;
+use catalog::images::queries::find_image_by_id::find_image_by_id;
+use data::images::repositories::ImagesRepository;
+use uuid::Uuid;
+
+/// the image content never changes for a given image id
+const IMMUTABLE: &str = "public, max-age=31536000, immutable";
+
+/// Returns the image content
+#[utoipa::path(
+    get,
+    path = "/api/catalog-items/{catalogItemId}/images/{imageId}",
+    tag = "catalog-items",
+    operation_id = "get-catalog-item-image",
+    params(
+        ("catalogItemId" = CatalogItemId, Path, description = "the catalog item unique identifier"),
+        ("imageId" = ImageId, Path, description = "the image unique identifier"),
+        ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")
+    ),
+    responses(
+        (status = 200, description = "the image content", content_type = "image/*", body = Vec<u8>),
+        (status = 404, response = NotFound),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_catalog_item_image", skip(app_state))]
+pub async fn handle(
+    Path((catalog_item_id, image_id)): Path<(CatalogItemId, ImageId)>,
+    consistency: ReadConsistency,
+    State(app_state): State<AppState>,
+) -> Result<Response, ProblemDetail> {
+    let database = app_state.get_read_database(consistency);
+    let repo = ImagesRepository;
+    let request_id = Uuid::new_v4();
+    let path = format!("{CATALOG_ITEMS_ROOT_API}/{catalog_item_id}/images/{image_id}");
+
+    let image = find_image_by_id(&catalog_item_id, &image_id, repo, database)
+        .await
+        .map_err(|why| why.to_problem_detail(request_id, Some(&path)))?;
+
+    let content = app_state
+        .images
+        .storage()
+        .get(&image.storage_key())
+        .await
+        .map_err(|why| {
+            tracing::error!("unable to read the image content: {why:#}");
+            ProblemDetail::error(request_id, "Unable to read the image content")
+        })?
+        .ok_or_else(|| ProblemDetail::not_found(request_id, &path))?;
+
+    Ok((
+        [
+            (CONTENT_TYPE, image.content_type),
+            (CACHE_CONTROL, IMMUTABLE.to_owned()),
+        ],
+        content,
+    )
+        .into_response())
+}
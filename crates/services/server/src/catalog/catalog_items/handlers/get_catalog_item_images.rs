@@ -0,0 +1,129 @@
+use crate::catalog::catalog_items::routes::CATALOG_ITEMS_ROOT_API;
+use crate::hateoas::links::{Link, LinkRelation};
+use crate::hateoas::representations::CollectionModel;
+use crate::openapi::responses::{InternalServerError, NotFound};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::web::urls::base_url;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::images::catalog_item_image::CatalogItemImage;
+use catalog::images::queries::find_catalog_item_images::find_catalog_item_images;
+use data::images::repositories::ImagesRepository;
+use uuid::Uuid;
+
+/// Returns the catalog item images, sorted by their position in the gallery
+#[utoipa::path(
+    get,
+    path = "/api/catalog-items/{catalogItemId}/images",
+    tag = "catalog-items",
+    operation_id = "get-catalog-item-images",
+    params(("catalogItemId" = CatalogItemId, Path, description = "the catalog item unique identifier"), ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    responses(
+        (status = 200, description = "the catalog item images", body = CatalogItemImagesCollection),
+        (status = 404, response = NotFound),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_catalog_item_images", skip(app_state, headers))]
+pub async fn handle(
+    Path(catalog_item_id): Path<CatalogItemId>,
+    headers: HeaderMap,
+    consistency: ReadConsistency,
+    State(app_state): State<AppState>,
+) -> Result<CollectionModel<CatalogItemImage>, ProblemDetail> {
+    let database = app_state.get_read_database(consistency);
+    let repo = ImagesRepository;
+    let path = format!("{CATALOG_ITEMS_ROOT_API}/{catalog_item_id}/images");
+
+    let result = find_catalog_item_images(&catalog_item_id, repo, database).await;
+    result
+        .map(|images| {
+            let base_url = base_url(app_state.public_url.as_deref(), &headers);
+            let links = Link::of(&format!("{base_url}{path}"), LinkRelation::SelfLink)
+                .into_iter()
+                .chain(image_links(&base_url, &images, LinkRelation::Item))
+                .collect();
+            CollectionModel::of(images, links)
+        })
+        .map_err(|why| why.to_problem_detail(Uuid::new_v4(), Some(&path)))
+}
+
+/// Returns the links to the images content, the primary image has the `preview` relation
+pub(crate) fn image_links(base_url: &str, images: &[CatalogItemImage], rel: LinkRelation) -> Vec<Link> {
+    images
+        .iter()
+        .filter_map(|image| {
+            let rel = if image.primary { LinkRelation::Preview } else { rel };
+            Link::of(&image_url(base_url, image), rel).ok()
+        })
+        .collect()
+}
+
+/// Returns the url for the image content
+pub(crate) fn image_url(base_url: &str, image: &CatalogItemImage) -> String {
+    format!(
+        "{base_url}{CATALOG_ITEMS_ROOT_API}/{}/images/{}",
+        image.catalog_item_id, image.image_id
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod catalog_item_image_links {
+        use super::*;
+        use catalog::images::image_id::ImageId;
+        use chrono::Utc;
+        use pretty_assertions::assert_eq;
+        use std::str::FromStr;
+
+        #[test]
+        fn it_should_create_the_image_links() {
+            let first = new_image(true);
+            let second = new_image(false);
+
+            let links = image_links(
+                "http://localhost:5000",
+                &[first.clone(), second.clone()],
+                LinkRelation::Image,
+            );
+
+            let expected = vec![
+                Link::of(
+                    &format!(
+                        "http://localhost:5000/api/catalog-items/acme-60142/images/{}",
+                        first.image_id
+                    ),
+                    LinkRelation::Preview,
+                )
+                .unwrap(),
+                Link::of(
+                    &format!(
+                        "http://localhost:5000/api/catalog-items/acme-60142/images/{}",
+                        second.image_id
+                    ),
+                    LinkRelation::Image,
+                )
+                .unwrap(),
+            ];
+            assert_eq!(expected, links);
+        }
+
+        fn new_image(primary: bool) -> CatalogItemImage {
+            CatalogItemImage {
+                image_id: ImageId::new(),
+                catalog_item_id: CatalogItemId::from_str("acme-60142").unwrap(),
+                content_type: String::from("image/png"),
+                size_bytes: 42,
+                position: 1,
+                primary,
+                created_at: Utc::now(),
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use crate::catalog::catalog_items::routes::CATALOG_ITEMS_ROOT_API;
+use crate::hateoas::links::{Link, LinkRelation};
+use crate::hateoas::representations::CollectionModel;
+use crate::openapi::responses::{BadRequest, InternalServerError};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::web::urls::base_url;
+use axum::extract::{OriginalUri, Query, State};
+use axum::http::HeaderMap;
+use catalog::brands::brand_id::BrandId;
+use catalog::catalog_items::availability_status::AvailabilityStatus;
+use catalog::catalog_items::catalog_item::CatalogItem;
+use catalog::catalog_items::epoch::Epoch;
+use catalog::catalog_items::queries::find_latest_catalog_items::{
+    find_latest_catalog_items, LatestCatalogItemsCriteria,
+};
+use catalog::railways::queries::find_railway_lineage::find_railway_lineage;
+use catalog::railways::railway_id::RailwayId;
+use catalog::scales::scale_id::ScaleId;
+use common::queries::errors::QueryError;
+use data::catalog::catalog_item::repositories::CatalogItemsRepository;
+use data::catalog::railways::repositories::RailwaysRepository;
+use serde::Deserialize;
+use std::str::FromStr;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+/// The catalog items query string
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CatalogItemsQuery {
+    /// only the catalog items for this brand
+    pub brand: Option<String>,
+    /// only the catalog items in this scale
+    pub scale: Option<String>,
+    /// only the catalog items with this availability status
+    pub status: Option<String>,
+    /// only the catalog items whose epoch overlaps this epoch (ie, `IV` or `IV/V`)
+    pub epoch: Option<String>,
+    /// only the catalog items with rolling stocks for this railway
+    pub railway: Option<String>,
+    /// include the catalog items for the railway predecessors and successors (ie, `FS` for `Trenitalia`)
+    pub include_related: Option<bool>,
+    /// only the catalog items valid in this year (the epochs are resolved with the railway country calendar)
+    pub year: Option<i32>,
+    /// the max number of catalog items
+    #[param(minimum = 1, maximum = 200, default = 50)]
+    pub limit: Option<i64>,
+}
+
+/// Returns the most recently created catalog items, the newest first
+#[utoipa::path(
+    get,
+    path = "/api/catalog-items",
+    tag = "catalog-items",
+    operation_id = "get-catalog-items",
+    params(CatalogItemsQuery, ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    responses(
+        (status = 200, description = "the catalog items list", body = CatalogItemsCollection),
+        (status = 400, response = BadRequest),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_catalog_items", skip(app_state, headers))]
+pub async fn handle(
+    Query(query): Query<CatalogItemsQuery>,
+    OriginalUri(uri): OriginalUri,
+    headers: HeaderMap,
+    consistency: ReadConsistency,
+    State(app_state): State<AppState>,
+) -> Result<CollectionModel<CatalogItem>, ProblemDetail> {
+    let request_id = Uuid::new_v4();
+    let mut criteria = to_criteria(&query).map_err(|detail| ProblemDetail::bad_request(request_id, &detail))?;
+
+    if let (Some(railway_id), Some(true)) = (&criteria.railway_id, query.include_related) {
+        let database = app_state.get_read_database(consistency);
+        let related_railway_ids = match find_railway_lineage(railway_id, RailwaysRepository, database).await {
+            Ok(lineage) => lineage
+                .railway_ids()
+                .into_iter()
+                .filter(|it| it != railway_id)
+                .collect(),
+            Err(QueryError::EmptyResultSet) => Vec::new(),
+            Err(why) => return Err(why.to_problem_detail(request_id, Some(CATALOG_ITEMS_ROOT_API))),
+        };
+        criteria = criteria.with_related_railways(related_railway_ids);
+    }
+
+    let database = app_state.get_read_database(consistency);
+    let repo = CatalogItemsRepository;
+
+    let catalog_items = find_latest_catalog_items(&criteria, repo, database)
+        .await
+        .map_err(|why| why.to_problem_detail(request_id, Some(CATALOG_ITEMS_ROOT_API)))?;
+
+    let base_url = base_url(app_state.public_url.as_deref(), &headers);
+    let links = Link::of(&format!("{base_url}{uri}"), LinkRelation::SelfLink)
+        .into_iter()
+        .collect();
+    Ok(CollectionModel::of(catalog_items, links))
+}
+
+fn to_criteria(query: &CatalogItemsQuery) -> Result<LatestCatalogItemsCriteria, String> {
+    fn parse<T: FromStr>(value: Option<&str>, name: &str) -> Result<Option<T>, String> {
+        value
+            .map(|value| T::from_str(value).map_err(|_| format!("invalid {name}: {value}")))
+            .transpose()
+    }
+
+    let criteria = LatestCatalogItemsCriteria::new(
+        parse::<BrandId>(query.brand.as_deref(), "brand")?,
+        parse::<ScaleId>(query.scale.as_deref(), "scale")?,
+        parse::<AvailabilityStatus>(query.status.as_deref(), "status")?,
+        query.limit,
+    )
+    .with_epoch(parse::<Epoch>(query.epoch.as_deref(), "epoch")?)
+    .with_railway(parse::<RailwayId>(query.railway.as_deref(), "railway")?)
+    .valid_in(query.year);
+    Ok(criteria)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod catalog_items_query {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_convert_the_query_to_criteria() {
+            let query = CatalogItemsQuery {
+                brand: Some(String::from("ACME")),
+                epoch: Some(String::from("IV/V")),
+                railway: Some(String::from("FS")),
+                year: Some(1965),
+                ..CatalogItemsQuery::default()
+            };
+
+            let criteria = to_criteria(&query).expect("the criteria are valid");
+
+            assert_eq!(Some(BrandId::new("ACME")), criteria.brand_id);
+            assert_eq!(None, criteria.scale_id);
+            assert_eq!(Some(Epoch::Multiple(vec![Epoch::IV, Epoch::V])), criteria.epoch);
+            assert_eq!(Some(RailwayId::new("FS")), criteria.railway_id);
+            assert_eq!(Some(1965), criteria.year);
+        }
+
+        #[test]
+        fn it_should_reject_invalid_values() {
+            let query = CatalogItemsQuery {
+                status: Some(String::from("not-a-status")),
+                ..CatalogItemsQuery::default()
+            };
+
+            let result = to_criteria(&query);
+
+            assert_eq!(Err(String::from("invalid status: not-a-status")), result);
+        }
+    }
+}
@@ -1,17 +1,37 @@
-mod delete_catalog_item;
-mod delete_rolling_stock;
-mod get_catalog_item_by_id;
-mod get_rolling_stock_by_id;
-mod post_catalog_item;
-mod post_rolling_stock;
-mod put_catalog_item;
-mod put_rolling_stock;
+pub(crate) mod delete_catalog_item;
+pub(crate) mod delete_catalog_item_image;
+pub(crate) mod delete_rolling_stock;
+pub(crate) mod get_catalog_item_availability;
+pub(crate) mod get_catalog_item_by_id;
+pub(crate) mod get_catalog_item_image;
+pub(crate) mod get_catalog_item_images;
+pub(crate) mod get_catalog_items;
+pub(crate) mod get_rolling_stock_by_id;
+pub(crate) mod post_catalog_item;
+pub(crate) mod post_catalog_item_availability;
+pub(crate) mod post_catalog_item_image;
+pub(crate) mod post_rolling_stock;
+pub(crate) mod put_catalog_item;
+pub(crate) mod put_catalog_item_image;
+pub(crate) mod put_rolling_stock;
+pub(crate) mod restore_catalog_item;
+pub(crate) mod restore_rolling_stock;
 
 pub use delete_catalog_item::handle as delete_catalog_item;
+pub use delete_catalog_item_image::handle as delete_catalog_item_image;
 pub use delete_rolling_stock::handle as delete_rolling_stock;
+pub use get_catalog_item_availability::handle as get_catalog_item_availability;
 pub use get_catalog_item_by_id::handle as get_catalog_item_by_id;
+pub use get_catalog_item_image::handle as get_catalog_item_image;
+pub use get_catalog_item_images::handle as get_catalog_item_images;
+pub use get_catalog_items::handle as get_catalog_items;
 pub use get_rolling_stock_by_id::handle as get_rolling_stock_by_id;
 pub use post_catalog_item::handle as post_catalog_item;
+pub use post_catalog_item_availability::handle as post_catalog_item_availability;
+pub use post_catalog_item_image::handle as post_catalog_item_image;
 pub use post_rolling_stock::handle as post_rolling_stock;
 pub use put_catalog_item::handle as put_catalog_item;
+pub use put_catalog_item_image::handle as put_catalog_item_image;
 pub use put_rolling_stock::handle as put_rolling_stock;
+pub use restore_catalog_item::handle as restore_catalog_item;
+pub use restore_rolling_stock::handle as restore_rolling_stock;
@@ -1,4 +1,5 @@
 use crate::catalog::catalog_items::routes::CATALOG_ITEMS_ROOT_API;
+use crate::openapi::responses::{AlreadyExists, BadRequest, InternalServerError, UnprocessableEntity};
 use crate::state::AppState;
 use crate::web::problem::ProblemDetail;
 use crate::web::responders::{Created, ToProblemDetail};
@@ -7,22 +8,51 @@ use axum::Json;
 use catalog::catalog_items::catalog_item_request::CatalogItemRequest;
 use catalog::catalog_items::commands::new_catalog_item::{create_new_catalog_item, CatalogItemCreationError};
 use data::catalog::catalog_item::repositories::{CatalogItemsRepository, RollingStocksRepository};
+use data::catalog::railways::repositories::RailwaysRepository;
+use data::events::repositories::OutboxEventsRepository;
 use uuid::Uuid;
 
+/// Creates a new catalog item
+#[utoipa::path(
+    post,
+    path = "/api/catalog-items",
+    tag = "catalog-items",
+    operation_id = "post-catalog-item",
+    request_body = CatalogItemRequest,
+    responses(
+        (status = 201, description = "the catalog item has been created", headers(("location" = String, description = "the new catalog item url"), ("x-trenako-warnings" = String, description = "the request warnings, percent-encoded, one header for each warning (ie, road numbers with an invalid UIC self-check digit or belonging to a different railway)"))),
+        (status = 400, response = BadRequest),
+        (status = 409, response = AlreadyExists),
+        (status = 422, response = UnprocessableEntity),
+        (status = 500, response = InternalServerError),
+    )
+)]
 #[tracing::instrument(name = "create_new_catalog_item", skip(app_state))]
 pub async fn handle(
     State(app_state): State<AppState>,
     Json(request): Json<CatalogItemRequest>,
 ) -> Result<Created, ProblemDetail> {
-    let repo = CatalogItemsRepository;
-    let rr_repo = RollingStocksRepository;
+    let repo = app_state.cached(CatalogItemsRepository);
+    let rr_repo = app_state.cached(RollingStocksRepository);
+    let railways_repo = app_state.cached(RailwaysRepository);
+    let outbox = OutboxEventsRepository;
     let database = app_state.get_database();
 
-    let result = create_new_catalog_item(request, repo, rr_repo, database).await;
+    let result = create_new_catalog_item(request, repo, rr_repo, railways_repo, outbox, database).await;
+    match &result {
+        Ok(created) => {
+            app_state.metrics.catalog_item_created();
+            if !created.warnings.is_empty() {
+                tracing::warn!(warnings = ?created.warnings, "the catalog item request has warnings");
+            }
+        }
+        Err(why) => app_state.metrics.catalog_item_rejected(why),
+    }
+
     result
         .map(|created| {
             let location = format!("{}/{}", CATALOG_ITEMS_ROOT_API, created.catalog_item_id);
-            Created::with_location(&location)
+            Created::with_location(&location).with_warnings(&created.warnings)
         })
         .map_err(|why| why.to_problem_detail(Uuid::new_v4(), None))
 }
@@ -36,6 +66,9 @@ impl ToProblemDetail for CatalogItemCreationError {
             CatalogItemCreationError::CatalogItemAlreadyExists(_) => {
                 ProblemDetail::resource_already_exists(request_id, &self.to_string())
             }
+            CatalogItemCreationError::PrototypeNotFound(_) => {
+                ProblemDetail::unprocessable_entity(request_id, &self.to_string())
+            }
             CatalogItemCreationError::RailwayNotFound(_) => {
                 ProblemDetail::unprocessable_entity(request_id, &self.to_string())
             }
@@ -60,6 +93,7 @@ mod test {
         use catalog::brands::brand_id::BrandId;
         use catalog::catalog_items::catalog_item_id::CatalogItemId;
         use catalog::catalog_items::item_number::ItemNumber;
+        use catalog::prototypes::prototype_id::PrototypeId;
         use catalog::railways::railway_id::RailwayId;
         use catalog::scales::scale_id::ScaleId;
         use common::queries::errors::DatabaseError;
@@ -116,6 +150,19 @@ mod test {
             assert_eq!(Trn::instance(&id), problem_detail.instance);
         }
 
+        #[test]
+        fn it_should_return_unprocessable_entity_when_the_prototype_was_not_found() {
+            let error = CatalogItemCreationError::PrototypeNotFound(PrototypeId::new("fs-e-656"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = error.to_problem_detail(id, None);
+            assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, problem_detail.status);
+            assert_eq!(
+                "Unable to create the catalog item due to prototype not found (id: fs-e-656)",
+                problem_detail.detail
+            );
+        }
+
         #[test]
         fn it_should_return_unprocessable_entity_when_the_scale_was_not_found() {
             let error = CatalogItemCreationError::ScaleNotFound(ScaleId::new("h0"));
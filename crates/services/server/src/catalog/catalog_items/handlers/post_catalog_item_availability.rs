@@ -0,0 +1,144 @@
+use crate::catalog::catalog_items::handlers::get_catalog_item_availability::{availability_path, to_model};
+use crate::hateoas::representations::EntityModel;
+use crate::openapi::responses::{BadRequest, Conflict, InternalServerError, NotFound};
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::web::urls::base_url;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::Json;
+use catalog::catalog_items::availability::{Availability, AvailabilityRequest};
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::catalog_items::commands::change_availability::{change_availability_status, AvailabilityChangeError};
+use catalog::catalog_items::queries::find_availability::find_availability;
+use chrono::Utc;
+use data::catalog::catalog_item::repositories::CatalogItemsRepository;
+use data::events::repositories::OutboxEventsRepository;
+use uuid::Uuid;
+
+/// Changes the catalog item availability status, the change is appended to the availability history
+#[utoipa::path(
+    post,
+    path = "/api/catalog-items/{catalogItemId}/availability",
+    tag = "catalog-items",
+    operation_id = "post-catalog-item-availability",
+    params(("catalogItemId" = CatalogItemId, Path, description = "the catalog item unique identifier")),
+    request_body = AvailabilityRequest,
+    responses(
+        (status = 200, description = "the updated catalog item availability", body = AvailabilityModel),
+        (status = 400, response = BadRequest),
+        (status = 404, response = NotFound),
+        (status = 409, response = Conflict),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "post_catalog_item_availability", skip(app_state, headers))]
+pub async fn handle(
+    Path(catalog_item_id): Path<CatalogItemId>,
+    headers: HeaderMap,
+    State(app_state): State<AppState>,
+    Json(request): Json<AvailabilityRequest>,
+) -> Result<EntityModel<Availability>, ProblemDetail> {
+    let request_id = Uuid::new_v4();
+    let path = availability_path(&catalog_item_id);
+
+    let outbox = OutboxEventsRepository;
+    let database = app_state.get_database();
+
+    change_availability_status(&catalog_item_id, request, CatalogItemsRepository, outbox, database)
+        .await
+        .map_err(|why| why.to_problem_detail(request_id, Some(&path)))?;
+
+    let database = app_state.get_database();
+    let availability = find_availability(
+        &catalog_item_id,
+        Utc::now().date_naive(),
+        CatalogItemsRepository,
+        database,
+    )
+    .await
+    .map_err(|why| why.to_problem_detail(request_id, Some(&path)))?
+    .ok_or_else(|| {
+        ProblemDetail::not_found(
+            request_id,
+            &format!("The catalog item was not found (id: {catalog_item_id})"),
+        )
+    })?;
+
+    Ok(to_model(
+        availability,
+        &base_url(app_state.public_url.as_deref(), &headers),
+    ))
+}
+
+impl ToProblemDetail for AvailabilityChangeError {
+    fn to_problem_detail(self, request_id: Uuid, _path: Option<&str>) -> ProblemDetail {
+        match self {
+            AvailabilityChangeError::InvalidRequest(_) => ProblemDetail::bad_request(request_id, ""),
+            AvailabilityChangeError::CatalogItemNotFound(_) => ProblemDetail::not_found(request_id, &self.to_string()),
+            AvailabilityChangeError::InvalidTransition(_, _) => ProblemDetail::conflict(request_id, &self.to_string()),
+            AvailabilityChangeError::UnexpectedError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            AvailabilityChangeError::DatabaseError(why) => ProblemDetail::error(request_id, &why.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod availability_change_error_to_problem_detail {
+        use super::*;
+        use anyhow::anyhow;
+        use axum::http::StatusCode;
+        use catalog::catalog_items::availability_status::AvailabilityStatus;
+        use common::trn::Trn;
+        use pretty_assertions::assert_eq;
+        use std::str::FromStr;
+        use validator::ValidationErrors;
+
+        #[test]
+        fn it_should_return_bad_request_for_invalid_request() {
+            let err = AvailabilityChangeError::InvalidRequest(ValidationErrors::new());
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::BAD_REQUEST, problem_detail.status);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_not_found_when_the_catalog_item_does_not_exist() {
+            let err = AvailabilityChangeError::CatalogItemNotFound(CatalogItemId::from_str("acme-60142").unwrap());
+
+            let problem_detail = err.to_problem_detail(Uuid::new_v4(), None);
+            assert_eq!(StatusCode::NOT_FOUND, problem_detail.status);
+            assert_eq!("The catalog item was not found (id: acme-60142)", problem_detail.detail);
+        }
+
+        #[test]
+        fn it_should_return_conflict_for_invalid_transitions() {
+            let err = AvailabilityChangeError::InvalidTransition(
+                AvailabilityStatus::Available,
+                AvailabilityStatus::Announced,
+            );
+
+            let problem_detail = err.to_problem_detail(Uuid::new_v4(), None);
+            assert_eq!(StatusCode::CONFLICT, problem_detail.status);
+            assert_eq!(
+                "The availability status cannot change from AVAILABLE to ANNOUNCED",
+                problem_detail.detail
+            );
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_generic_errors() {
+            let err = AvailabilityChangeError::UnexpectedError(anyhow!("Something bad just happened"));
+
+            let problem_detail = err.to_problem_detail(Uuid::new_v4(), None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("Something bad just happened", problem_detail.detail);
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::catalog::catalog_items::routes::CATALOG_ITEMS_ROOT_API;
+use crate::openapi::responses::{BadRequest, InternalServerError, NotFound, PayloadTooLarge, UnsupportedMediaType};
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::{Created, ToProblemDetail};
+use axum::extract::multipart::Field;
+use axum::extract::{Multipart, Path, State};
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::images::commands::upload_image::{upload_image, ImageUpload, ImageUploadError};
+use data::images::repositories::ImagesRepository;
+use uuid::Uuid;
+
+/// the multipart field with the image content
+const IMAGE_FIELD: &str = "image";
+/// the multipart field with the primary flag
+const PRIMARY_FIELD: &str = "primary";
+
+/// The multipart form to upload a new image
+#[allow(dead_code)]
+#[derive(utoipa::ToSchema)]
+pub struct ImageUploadForm {
+    /// the image content (GIF, JPEG, PNG or WebP)
+    #[schema(value_type = String, format = Binary)]
+    image: Vec<u8>,
+    /// whether the new image is the catalog item primary image (default `false`)
+    primary: Option<bool>,
+}
+
+/// Uploads a new image for the catalog item, the image is added at the end of the gallery
+#[utoipa::path(
+    post,
+    path = "/api/catalog-items/{catalogItemId}/images",
+    tag = "catalog-items",
+    operation_id = "post-catalog-item-image",
+    params(("catalogItemId" = CatalogItemId, Path, description = "the catalog item unique identifier")),
+    request_body(content = ImageUploadForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "the image has been uploaded", headers(("location" = String, description = "the new image url"))),
+        (status = 400, response = BadRequest),
+        (status = 404, response = NotFound),
+        (status = 413, response = PayloadTooLarge),
+        (status = 415, response = UnsupportedMediaType),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "post_catalog_item_image", skip(app_state, multipart))]
+pub async fn handle(
+    Path(catalog_item_id): Path<CatalogItemId>,
+    State(app_state): State<AppState>,
+    multipart: Multipart,
+) -> Result<Created, ProblemDetail> {
+    let request_id = Uuid::new_v4();
+    let path = format!("{CATALOG_ITEMS_ROOT_API}/{catalog_item_id}/images");
+    let images = &app_state.images;
+
+    let upload = read_upload(multipart, images.limits().max_size_bytes)
+        .await
+        .map_err(|why| why.to_problem_detail(request_id, Some(&path)))?;
+
+    let repo = ImagesRepository;
+    let database = app_state.get_database();
+
+    let result = upload_image(
+        &catalog_item_id,
+        upload,
+        images.limits(),
+        repo,
+        images.storage(),
+        database,
+    )
+    .await;
+    result
+        .map(|image| Created::with_location(&format!("{path}/{}", image.image_id)))
+        .map_err(|why| why.to_problem_detail(request_id, Some(&path)))
+}
+
+/// Reads the upload from the multipart form, the image content is read in chunks and the upload
+/// is rejected as soon as the image goes over the size limit
+async fn read_upload(mut multipart: Multipart, max_size_bytes: usize) -> Result<ImageUpload, MultipartUploadError> {
+    let mut image = None;
+    let mut primary = false;
+
+    while let Some(field) = multipart.next_field().await.map_err(MultipartUploadError::from)? {
+        match field.name() {
+            Some(IMAGE_FIELD) => {
+                let content_type = field.content_type().map(String::from);
+                let content = read_field(field, max_size_bytes)
+                    .await?
+                    .ok_or(MultipartUploadError::Upload(ImageUploadError::ImageTooLarge(
+                        max_size_bytes,
+                    )))?;
+                image = Some((content, content_type));
+            }
+            Some(PRIMARY_FIELD) => {
+                let value = read_field(field, 16).await?.unwrap_or_default();
+                primary = match String::from_utf8_lossy(&value).trim() {
+                    "true" => true,
+                    "false" | "" => false,
+                    _ => return Err(MultipartUploadError::InvalidForm("invalid primary flag")),
+                };
+            }
+            _ => {}
+        }
+    }
+
+    let (content, content_type) = image.ok_or(MultipartUploadError::InvalidForm("the image field is missing"))?;
+    Ok(ImageUpload {
+        content,
+        content_type,
+        primary,
+    })
+}
+
+/// Reads the field content, it returns `None` when the content is over the size limit
+async fn read_field(mut field: Field<'_>, max_size_bytes: usize) -> Result<Option<Vec<u8>>, MultipartUploadError> {
+    let mut content = Vec::new();
+    while let Some(chunk) = field.chunk().await.map_err(MultipartUploadError::from)? {
+        if content.len() + chunk.len() > max_size_bytes {
+            return Ok(None);
+        }
+        content.extend_from_slice(&chunk);
+    }
+    Ok(Some(content))
+}
+
+#[derive(Debug)]
+enum MultipartUploadError {
+    InvalidForm(&'static str),
+    Malformed(String),
+    Upload(ImageUploadError),
+}
+
+impl From<axum::extract::multipart::MultipartError> for MultipartUploadError {
+    fn from(why: axum::extract::multipart::MultipartError) -> Self {
+        MultipartUploadError::Malformed(why.body_text())
+    }
+}
+
+impl ToProblemDetail for MultipartUploadError {
+    fn to_problem_detail(self, request_id: Uuid, path: Option<&str>) -> ProblemDetail {
+        match self {
+            MultipartUploadError::InvalidForm(why) => ProblemDetail::bad_request(request_id, why),
+            MultipartUploadError::Malformed(why) => ProblemDetail::bad_request(request_id, &why),
+            MultipartUploadError::Upload(why) => why.to_problem_detail(request_id, path),
+        }
+    }
+}
+
+impl ToProblemDetail for ImageUploadError {
+    fn to_problem_detail(self, request_id: Uuid, _path: Option<&str>) -> ProblemDetail {
+        match self {
+            ImageUploadError::EmptyImage => ProblemDetail::bad_request(request_id, &self.to_string()),
+            ImageUploadError::ImageTooLarge(_) => ProblemDetail::payload_too_large(request_id, &self.to_string()),
+            ImageUploadError::UnsupportedMediaType(_) => {
+                ProblemDetail::unsupported_media_type(request_id, &self.to_string())
+            }
+            ImageUploadError::CatalogItemNotFound(_) => ProblemDetail::not_found(request_id, &self.to_string()),
+            ImageUploadError::TooManyImages(_) => ProblemDetail::conflict(request_id, &self.to_string()),
+            ImageUploadError::StorageError(why) => ProblemDetail::error(request_id, &format!("{why:#}")),
+            ImageUploadError::UnexpectedError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            ImageUploadError::DatabaseError(why) => ProblemDetail::error(request_id, &why.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod image_upload_error_to_problem_detail {
+        use super::*;
+        use anyhow::anyhow;
+        use axum::http::StatusCode;
+        use common::trn::Trn;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+        use std::str::FromStr;
+
+        #[rstest]
+        #[case(ImageUploadError::EmptyImage, StatusCode::BAD_REQUEST)]
+        #[case(ImageUploadError::ImageTooLarge(1024), StatusCode::PAYLOAD_TOO_LARGE)]
+        #[case(
+            ImageUploadError::UnsupportedMediaType(String::from("image/svg+xml")),
+            StatusCode::UNSUPPORTED_MEDIA_TYPE
+        )]
+        #[case(ImageUploadError::CatalogItemNotFound(CatalogItemId::from_str("acme-60142").unwrap()), StatusCode::NOT_FOUND)]
+        #[case(ImageUploadError::TooManyImages(20), StatusCode::CONFLICT)]
+        #[case(ImageUploadError::StorageError(anyhow!("the storage is down")), StatusCode::INTERNAL_SERVER_ERROR)]
+        #[case(ImageUploadError::UnexpectedError(anyhow!("Something bad just happened")), StatusCode::INTERNAL_SERVER_ERROR)]
+        fn it_should_convert_the_upload_errors(#[case] err: ImageUploadError, #[case] expected: StatusCode) {
+            let detail = err.to_string();
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+
+            assert_eq!(expected, problem_detail.status);
+            if expected != StatusCode::INTERNAL_SERVER_ERROR {
+                assert_eq!(detail, problem_detail.detail);
+            }
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_bad_request_for_invalid_forms() {
+            let err = MultipartUploadError::InvalidForm("the image field is missing");
+
+            let problem_detail = err.to_problem_detail(Uuid::new_v4(), None);
+
+            assert_eq!(StatusCode::BAD_REQUEST, problem_detail.status);
+            assert_eq!("the image field is missing", problem_detail.detail);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::catalog::catalog_items::handlers::get_catalog_item_images::image_url;
+use crate::catalog::catalog_items::routes::CATALOG_ITEMS_ROOT_API;
+use crate::hateoas::links::{Link, LinkRelation};
+use crate::hateoas::representations::EntityModel;
+use crate::openapi::responses::{BadRequest, InternalServerError, NotFound};
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::web::urls::base_url;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::Json;
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::images::catalog_item_image::CatalogItemImage;
+use catalog::images::commands::update_image::{update_image, ImageUpdateError};
+use catalog::images::image_id::ImageId;
+use catalog::images::image_request::ImageRequest;
+use data::images::repositories::ImagesRepository;
+use uuid::Uuid;
+
+/// Changes the image position in the gallery, or its primary flag
+#[utoipa::path(
+    put,
+    path = "/api/catalog-items/{catalogItemId}/images/{imageId}",
+    tag = "catalog-items",
+    operation_id = "put-catalog-item-image",
+    params(
+        ("catalogItemId" = CatalogItemId, Path, description = "the catalog item unique identifier"),
+        ("imageId" = ImageId, Path, description = "the image unique identifier")
+    ),
+    request_body = ImageRequest,
+    responses(
+        (status = 200, description = "the updated image", body = CatalogItemImageModel),
+        (status = 400, response = BadRequest),
+        (status = 404, response = NotFound),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "put_catalog_item_image", skip(app_state, headers))]
+pub async fn handle(
+    Path((catalog_item_id, image_id)): Path<(CatalogItemId, ImageId)>,
+    headers: HeaderMap,
+    State(app_state): State<AppState>,
+    Json(request): Json<ImageRequest>,
+) -> Result<EntityModel<CatalogItemImage>, ProblemDetail> {
+    let repo = ImagesRepository;
+    let database = app_state.get_database();
+
+    let result = update_image(&catalog_item_id, &image_id, request, repo, database).await;
+    result
+        .map(|image| {
+            let links = Link::of(
+                &image_url(&base_url(app_state.public_url.as_deref(), &headers), &image),
+                LinkRelation::SelfLink,
+            )
+            .into_iter()
+            .collect();
+            EntityModel::of(image, links)
+        })
+        .map_err(|why| {
+            let path = format!("{CATALOG_ITEMS_ROOT_API}/{catalog_item_id}/images/{image_id}");
+            why.to_problem_detail(Uuid::new_v4(), Some(&path))
+        })
+}
+
+impl ToProblemDetail for ImageUpdateError {
+    fn to_problem_detail(self, request_id: Uuid, _path: Option<&str>) -> ProblemDetail {
+        match self {
+            ImageUpdateError::InvalidRequest(_) => ProblemDetail::bad_request(request_id, ""),
+            ImageUpdateError::ImageNotFound(_) => ProblemDetail::not_found(request_id, &self.to_string()),
+            ImageUpdateError::UnexpectedError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            ImageUpdateError::DatabaseError(why) => ProblemDetail::error(request_id, &why.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod image_update_error_to_problem_detail {
+        use super::*;
+        use anyhow::anyhow;
+        use axum::http::StatusCode;
+        use common::trn::Trn;
+        use pretty_assertions::assert_eq;
+        use validator::ValidationErrors;
+
+        #[test]
+        fn it_should_return_bad_request_for_invalid_request() {
+            let err = ImageUpdateError::InvalidRequest(ValidationErrors::new());
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::BAD_REQUEST, problem_detail.status);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_not_found_when_the_image_does_not_exist() {
+            let image_id = ImageId::new();
+            let err = ImageUpdateError::ImageNotFound(image_id);
+
+            let problem_detail = err.to_problem_detail(Uuid::new_v4(), None);
+            assert_eq!(StatusCode::NOT_FOUND, problem_detail.status);
+            assert_eq!(
+                format!("The image was not found (id: {image_id})"),
+                problem_detail.detail
+            );
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_generic_errors() {
+            let err = ImageUpdateError::UnexpectedError(anyhow!("Something bad just happened"));
+
+            let problem_detail = err.to_problem_detail(Uuid::new_v4(), None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("Something bad just happened", problem_detail.detail);
+        }
+    }
+}
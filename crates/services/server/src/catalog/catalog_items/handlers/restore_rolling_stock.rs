@@ -0,0 +1,50 @@
+use crate::catalog::catalog_items::routes::CATALOG_ITEMS_ROOT_API;
+use crate::openapi::responses::{Conflict, InternalServerError, NotFound, Unauthorized};
+use crate::state::AppState;
+use crate::web::admin::AdminAccess;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::catalog_items::commands::delete_rolling_stock::restore_rolling_stock;
+use catalog::catalog_items::rolling_stock_id::RollingStockId;
+use data::catalog::catalog_item::repositories::RollingStocksRepository;
+use data::events::repositories::OutboxEventsRepository;
+use uuid::Uuid;
+
+/// Restores the deleted rolling stock with the given id
+#[utoipa::path(
+    post,
+    path = "/api/catalog-items/{catalogItemId}/rolling-stocks/{rollingStockId}/restore",
+    tag = "catalog-items",
+    operation_id = "restore-rolling-stock",
+    params(("catalogItemId" = CatalogItemId, Path, description = "the catalog item unique identifier"), ("rollingStockId" = RollingStockId, Path, description = "the rolling stock unique identifier")),
+    security(("admin_api_key" = [])),
+    responses(
+        (status = 204, description = "the rolling stock has been restored"),
+        (status = 401, response = Unauthorized),
+        (status = 404, response = NotFound),
+        (status = 409, response = Conflict),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "restore_rolling_stock", skip(app_state))]
+pub async fn handle(
+    Path((catalog_item_id, rolling_stock_id)): Path<(CatalogItemId, RollingStockId)>,
+    _admin: AdminAccess,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode, ProblemDetail> {
+    let repo = RollingStocksRepository;
+    let outbox = OutboxEventsRepository;
+    let database = app_state.get_database();
+
+    let result = restore_rolling_stock(&catalog_item_id, &rolling_stock_id, repo, outbox, database).await;
+    result.map(|_| StatusCode::NO_CONTENT).map_err(|why| {
+        let path = format!(
+            "{}/{}/rolling-stocks/{}",
+            CATALOG_ITEMS_ROOT_API, catalog_item_id, rolling_stock_id
+        );
+        why.to_problem_detail(Uuid::new_v4(), Some(&path))
+    })
+}
@@ -1,4 +1,5 @@
 //! the module includes everything related to catalog item web handlers
 
-mod handlers;
+pub(crate) mod handlers;
+pub mod review;
 pub mod routes;
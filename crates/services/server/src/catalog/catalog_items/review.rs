@@ -0,0 +1,60 @@
+//! the overdue catalog items review background task
+
+use catalog::catalog_items::commands::change_availability::request_overdue_reviews;
+use chrono::Utc;
+use common::unit_of_work::postgres::PgDatabase;
+use configuration::AvailabilityReviewSettings;
+use data::catalog::catalog_item::repositories::CatalogItemsRepository;
+use sqlx::PgPool;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// It periodically flags for editorial review the announced catalog items whose delivery date has passed
+pub struct OverdueReviewJob {
+    pg_pool: Arc<PgPool>,
+    settings: AvailabilityReviewSettings,
+}
+
+impl OverdueReviewJob {
+    /// Creates a new overdue catalog items review job
+    pub fn new(pg_pool: Arc<PgPool>, settings: &AvailabilityReviewSettings) -> Self {
+        OverdueReviewJob {
+            pg_pool,
+            settings: settings.clone(),
+        }
+    }
+
+    /// Starts the job as a background task
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move { self.run().await })
+    }
+
+    async fn run(&self) {
+        tracing::info!("Starting the overdue catalog items review job...");
+
+        let mut interval = tokio::time::interval(self.settings.poll_interval());
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            self.request_reviews().await;
+        }
+    }
+
+    async fn request_reviews(&self) {
+        let database = PgDatabase::new(&self.pg_pool);
+        match request_overdue_reviews(Utc::now(), CatalogItemsRepository, database).await {
+            Ok(catalog_item_ids) if !catalog_item_ids.is_empty() => {
+                let catalog_item_ids: Vec<String> = catalog_item_ids.iter().map(ToString::to_string).collect();
+                tracing::info!(
+                    flagged = catalog_item_ids.len(),
+                    catalog_items = catalog_item_ids.join(", "),
+                    "overdue catalog items flagged for review"
+                );
+            }
+            Ok(_) => {}
+            Err(why) => tracing::error!("unable to flag the overdue catalog items: {why:#}"),
+        }
+    }
+}
@@ -1,22 +1,49 @@
 use crate::catalog::catalog_items::handlers;
 use crate::state::AppState;
+use axum::extract::DefaultBodyLimit;
 use axum::routing::{get, post};
 use axum::Router;
 
 pub const CATALOG_ITEMS_ROOT_API: &str = "/api/catalog-items";
 pub const CATALOG_ITEM_ROOT_API: &str = "/api/catalog-items/:catalogItemId";
+pub const CATALOG_ITEM_RESTORE_API: &str = "/api/catalog-items/:catalogItemId/restore";
+pub const CATALOG_ITEM_AVAILABILITY_API: &str = "/api/catalog-items/:catalogItemId/availability";
+pub const CATALOG_ITEM_IMAGES_API: &str = "/api/catalog-items/:catalogItemId/images";
+pub const CATALOG_ITEM_IMAGE_API: &str = "/api/catalog-items/:catalogItemId/images/:imageId";
 pub const ROLLING_STOCKS_ROOT_API: &str = "/api/catalog-items/:catalogItemId/rolling-stocks";
 pub const ROLLING_STOCK_ROOT_API: &str = "/api/catalog-items/:catalogItemId/rolling-stocks/:rollingStockId";
+pub const ROLLING_STOCK_RESTORE_API: &str = "/api/catalog-items/:catalogItemId/rolling-stocks/:rollingStockId/restore";
 
 pub fn catalog_items_router() -> Router<AppState> {
     Router::new()
-        .route(CATALOG_ITEMS_ROOT_API, post(handlers::post_catalog_item))
+        .route(
+            CATALOG_ITEMS_ROOT_API,
+            get(handlers::get_catalog_items).post(handlers::post_catalog_item),
+        )
         .route(
             CATALOG_ITEM_ROOT_API,
             get(handlers::get_catalog_item_by_id)
                 .delete(handlers::delete_catalog_item)
                 .put(handlers::put_catalog_item),
         )
+        .route(CATALOG_ITEM_RESTORE_API, post(handlers::restore_catalog_item))
+        .route(
+            CATALOG_ITEM_AVAILABILITY_API,
+            get(handlers::get_catalog_item_availability).post(handlers::post_catalog_item_availability),
+        )
+        .route(
+            CATALOG_ITEM_IMAGES_API,
+            // the upload size limit is enforced while the image is read
+            get(handlers::get_catalog_item_images)
+                .post(handlers::post_catalog_item_image)
+                .layer(DefaultBodyLimit::disable()),
+        )
+        .route(
+            CATALOG_ITEM_IMAGE_API,
+            get(handlers::get_catalog_item_image)
+                .delete(handlers::delete_catalog_item_image)
+                .put(handlers::put_catalog_item_image),
+        )
         .route(ROLLING_STOCKS_ROOT_API, post(handlers::post_rolling_stock))
         .route(
             ROLLING_STOCK_ROOT_API,
@@ -24,4 +51,5 @@ pub fn catalog_items_router() -> Router<AppState> {
                 .delete(handlers::delete_rolling_stock)
                 .put(handlers::put_rolling_stock),
         )
+        .route(ROLLING_STOCK_RESTORE_API, post(handlers::restore_rolling_stock))
 }
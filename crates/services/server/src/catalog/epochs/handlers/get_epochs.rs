@@ -0,0 +1,87 @@
+use crate::openapi::responses::BadRequest;
+use crate::web::problem::ProblemDetail;
+use axum::extract::Query;
+use axum::Json;
+use catalog::catalog_items::epoch_calendar::EpochCalendar;
+use isocountry::CountryCode;
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+/// The epochs query string
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EpochsQuery {
+    /// the country (ISO 3166-1 alpha-2 code), the generic European calendar is returned when missing
+    pub country: Option<String>,
+}
+
+/// Returns the epoch calendar for a country, the countries without their own NEM 806 table get
+/// the generic European calendar
+#[utoipa::path(
+    get,
+    path = "/api/epochs",
+    tag = "epochs",
+    operation_id = "get-epochs",
+    params(EpochsQuery),
+    responses(
+        (status = 200, description = "the epoch calendar", body = EpochCalendar),
+        (status = 400, response = BadRequest),
+    )
+)]
+#[tracing::instrument(name = "get_epochs")]
+pub async fn handle(Query(query): Query<EpochsQuery>) -> Result<Json<EpochCalendar>, ProblemDetail> {
+    let calendar = match query.country.as_deref() {
+        Some(country) => {
+            let country = CountryCode::for_alpha2_caseless(country)
+                .map_err(|_| ProblemDetail::bad_request(Uuid::new_v4(), &format!("invalid country: {country}")))?;
+            EpochCalendar::for_country(country)
+        }
+        None => EpochCalendar::european(),
+    };
+
+    Ok(Json(calendar.clone()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod get_epochs {
+        use super::*;
+        use axum::http::StatusCode;
+        use axum::response::IntoResponse;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_return_the_country_epoch_calendar() {
+            let query = EpochsQuery {
+                country: Some(String::from("it")),
+            };
+
+            let Json(calendar) = handle(Query(query)).await.expect("the country is valid");
+
+            assert_eq!(Some(CountryCode::ITA), calendar.country);
+        }
+
+        #[tokio::test]
+        async fn it_should_return_the_european_epoch_calendar_without_a_country() {
+            let Json(calendar) = handle(Query(EpochsQuery::default()))
+                .await
+                .expect("the country is optional");
+
+            assert_eq!(EpochCalendar::european(), &calendar);
+        }
+
+        #[tokio::test]
+        async fn it_should_reject_invalid_countries() {
+            let query = EpochsQuery {
+                country: Some(String::from("XX")),
+            };
+
+            let response = handle(Query(query)).await.into_response();
+
+            assert_eq!(StatusCode::BAD_REQUEST, response.status());
+        }
+    }
+}
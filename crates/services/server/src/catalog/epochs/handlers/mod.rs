@@ -0,0 +1,3 @@
+pub(crate) mod get_epochs;
+
+pub use get_epochs::handle as get_epochs;
@@ -0,0 +1,10 @@
+use crate::catalog::epochs::handlers;
+use crate::state::AppState;
+use axum::routing::get;
+use axum::Router;
+
+pub const EPOCHS_ROOT_API: &str = "/api/epochs";
+
+pub fn epochs_router() -> Router<AppState> {
+    Router::new().route(EPOCHS_ROOT_API, get(handlers::get_epochs))
+}
@@ -2,6 +2,8 @@
 
 use crate::catalog::brands::routes::brands_router;
 use crate::catalog::catalog_items::routes::catalog_items_router;
+use crate::catalog::epochs::routes::epochs_router;
+use crate::catalog::prototypes::routes::prototypes_router;
 use crate::catalog::railways::routes::railways_router;
 use crate::catalog::scales::routes::scales_router;
 use crate::state::AppState;
@@ -9,12 +11,16 @@ use axum::Router;
 
 pub mod brands;
 pub mod catalog_items;
+pub mod epochs;
+pub mod prototypes;
 pub mod railways;
 pub mod scales;
 
 pub fn catalog_router() -> Router<AppState> {
     brands_router()
         .merge(catalog_items_router())
+        .merge(epochs_router())
+        .merge(prototypes_router())
         .merge(railways_router())
         .merge(scales_router())
 }
@@ -0,0 +1,60 @@
+use crate::catalog::prototypes::routes;
+use crate::hateoas::representations::CollectionModel;
+use crate::openapi::responses::{BadRequest, InternalServerError};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use axum::extract::{Query, State};
+use catalog::prototypes::prototype::Prototype;
+use catalog::prototypes::queries::find_all_prototypes::find_all_prototypes;
+use catalog::railways::railway_id::RailwayId;
+use data::catalog::prototypes::repositories::PrototypesRepository;
+use serde::Deserialize;
+use std::str::FromStr;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+/// The prototypes query string
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PrototypesQuery {
+    /// only the prototypes for this railway
+    pub railway: Option<String>,
+}
+
+/// Returns the prototypes list
+#[utoipa::path(
+    get,
+    path = "/api/prototypes",
+    tag = "prototypes",
+    operation_id = "get-prototypes",
+    params(PrototypesQuery, ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    responses(
+        (status = 200, description = "the prototypes list", body = PrototypesCollection),
+        (status = 400, response = BadRequest),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_all_prototypes", skip(app_state))]
+pub async fn handle(
+    Query(query): Query<PrototypesQuery>,
+    consistency: ReadConsistency,
+    State(app_state): State<AppState>,
+) -> Result<CollectionModel<Prototype>, ProblemDetail> {
+    let request_id = Uuid::new_v4();
+    let railway_id = query
+        .railway
+        .as_deref()
+        .map(|railway| RailwayId::from_str(railway).map_err(|_| format!("invalid railway: {railway}")))
+        .transpose()
+        .map_err(|detail| ProblemDetail::bad_request(request_id, &detail))?;
+
+    let database = app_state.get_read_database(consistency);
+    let repo = PrototypesRepository;
+
+    let results = find_all_prototypes(railway_id.as_ref(), repo, database).await;
+    results
+        .map(|prototypes| CollectionModel::of(prototypes, Vec::new()))
+        .map_err(|why| why.to_problem_detail(request_id, Some(routes::PROTOTYPE_ROOT_API)))
+}
@@ -0,0 +1,53 @@
+use crate::catalog::prototypes::routes;
+use crate::hateoas::links::{Link, LinkRelation};
+use crate::hateoas::representations::EntityModel;
+use crate::openapi::responses::{Gone, InternalServerError, NotFound};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::web::urls::base_url;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use catalog::prototypes::prototype::Prototype;
+use catalog::prototypes::prototype_id::PrototypeId;
+use catalog::prototypes::queries::find_prototype_by_id::find_prototype_by_id;
+use data::catalog::prototypes::repositories::PrototypesRepository;
+use uuid::Uuid;
+
+/// Returns the prototype with the given id
+#[utoipa::path(
+    get,
+    path = "/api/prototypes/{prototypeId}",
+    tag = "prototypes",
+    operation_id = "get-prototype-by-id",
+    params(("prototypeId" = PrototypeId, Path, description = "the prototype unique identifier"), ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    responses(
+        (status = 200, description = "the prototype", body = PrototypeModel),
+        (status = 404, response = NotFound),
+        (status = 410, response = Gone),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_prototype_by_id", skip(app_state, headers))]
+pub async fn handle(
+    Path(prototype_id): Path<PrototypeId>,
+    headers: HeaderMap,
+    consistency: ReadConsistency,
+    State(app_state): State<AppState>,
+) -> Result<EntityModel<Prototype>, ProblemDetail> {
+    let database = app_state.get_read_database(consistency);
+    let repo = PrototypesRepository;
+
+    let path = format!("{}/{}", routes::PROTOTYPE_ROOT_API, prototype_id);
+    let result = find_prototype_by_id(&prototype_id, repo, database).await;
+    result
+        .map(|prototype| {
+            let base_url = base_url(app_state.public_url.as_deref(), &headers);
+            let links = Link::of(&format!("{base_url}{path}"), LinkRelation::SelfLink)
+                .into_iter()
+                .collect();
+            EntityModel::of(prototype, links)
+        })
+        .map_err(|why| why.to_problem_detail(Uuid::new_v4(), Some(&path)))
+}
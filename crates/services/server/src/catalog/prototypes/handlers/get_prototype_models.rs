@@ -0,0 +1,52 @@
+use crate::catalog::prototypes::routes;
+use crate::hateoas::links::{Link, LinkRelation};
+use crate::hateoas::representations::CollectionModel;
+use crate::openapi::responses::{InternalServerError, NotFound};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::web::urls::base_url;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use catalog::catalog_items::catalog_item::CatalogItem;
+use catalog::prototypes::prototype_id::PrototypeId;
+use catalog::prototypes::queries::find_prototype_models::find_prototype_models;
+use data::catalog::catalog_item::repositories::CatalogItemsRepository;
+use data::catalog::prototypes::repositories::PrototypesRepository;
+use uuid::Uuid;
+
+/// Returns all the catalog items modelling the prototype, across brands and scales
+#[utoipa::path(
+    get,
+    path = "/api/prototypes/{prototypeId}/models",
+    tag = "prototypes",
+    operation_id = "get-prototype-models",
+    params(("prototypeId" = PrototypeId, Path, description = "the prototype unique identifier"), ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    responses(
+        (status = 200, description = "the catalog items modelling the prototype", body = CatalogItemsCollection),
+        (status = 404, response = NotFound),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_prototype_models", skip(app_state, headers))]
+pub async fn handle(
+    Path(prototype_id): Path<PrototypeId>,
+    headers: HeaderMap,
+    consistency: ReadConsistency,
+    State(app_state): State<AppState>,
+) -> Result<CollectionModel<CatalogItem>, ProblemDetail> {
+    let database = app_state.get_read_database(consistency);
+
+    let path = format!("{}/{}/models", routes::PROTOTYPE_ROOT_API, prototype_id);
+    let result = find_prototype_models(&prototype_id, PrototypesRepository, CatalogItemsRepository, database).await;
+    result
+        .map(|catalog_items| {
+            let base_url = base_url(app_state.public_url.as_deref(), &headers);
+            let links = Link::of(&format!("{base_url}{path}"), LinkRelation::SelfLink)
+                .into_iter()
+                .collect();
+            CollectionModel::of(catalog_items, links)
+        })
+        .map_err(|why| why.to_problem_detail(Uuid::new_v4(), Some(&path)))
+}
@@ -0,0 +1,9 @@
+pub(crate) mod get_all_prototypes;
+pub(crate) mod get_prototype_by_id;
+pub(crate) mod get_prototype_models;
+pub(crate) mod post_prototype;
+
+pub use get_all_prototypes::handle as get_all_prototypes;
+pub use get_prototype_by_id::handle as get_prototype_by_id;
+pub use get_prototype_models::handle as get_prototype_models;
+pub use post_prototype::handle as post_prototype;
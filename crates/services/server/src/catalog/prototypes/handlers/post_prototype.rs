@@ -0,0 +1,120 @@
+use crate::catalog::prototypes::routes::PROTOTYPE_ROOT_API;
+use crate::openapi::responses::{AlreadyExists, BadRequest, InternalServerError, UnprocessableEntity};
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::{Created, ToProblemDetail};
+use axum::extract::State;
+use axum::Json;
+use catalog::prototypes::commands::new_prototype::{create_new_prototype, PrototypeCreationError};
+use catalog::prototypes::prototype_request::PrototypeRequest;
+use data::catalog::prototypes::repositories::PrototypesRepository;
+use data::events::repositories::OutboxEventsRepository;
+use uuid::Uuid;
+
+/// Creates a new prototype
+#[utoipa::path(
+    post,
+    path = "/api/prototypes",
+    tag = "prototypes",
+    operation_id = "post-prototype",
+    request_body = PrototypeRequest,
+    responses(
+        (status = 201, description = "the prototype has been created", headers(("location" = String, description = "the new prototype url"))),
+        (status = 400, response = BadRequest),
+        (status = 409, response = AlreadyExists),
+        (status = 422, response = UnprocessableEntity),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "create_prototype", skip(app_state))]
+pub async fn handle(
+    State(app_state): State<AppState>,
+    Json(request): Json<PrototypeRequest>,
+) -> Result<Created, ProblemDetail> {
+    let repo = PrototypesRepository;
+    let outbox = OutboxEventsRepository;
+    let database = app_state.get_database();
+
+    let result = create_new_prototype(request, repo, outbox, database).await;
+    result
+        .map(|created| {
+            let location = format!("{}/{}", PROTOTYPE_ROOT_API, created.prototype_id);
+            Created::with_location(&location)
+        })
+        .map_err(|why| why.to_problem_detail(Uuid::new_v4(), None))
+}
+
+impl ToProblemDetail for PrototypeCreationError {
+    fn to_problem_detail(self, request_id: Uuid, _path: Option<&str>) -> ProblemDetail {
+        match self {
+            PrototypeCreationError::PrototypeAlreadyExists(_) => {
+                ProblemDetail::resource_already_exists(request_id, &self.to_string())
+            }
+            PrototypeCreationError::RailwayNotFound(_) => {
+                ProblemDetail::unprocessable_entity(request_id, &self.to_string())
+            }
+            PrototypeCreationError::DatabaseError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            PrototypeCreationError::UnexpectedError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            PrototypeCreationError::InvalidRequest(_) => ProblemDetail::bad_request(request_id, ""),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod prototype_creation_error_to_problem_detail {
+        use super::*;
+        use anyhow::anyhow;
+        use axum::http::StatusCode;
+        use catalog::prototypes::prototype_id::PrototypeId;
+        use catalog::railways::railway_id::RailwayId;
+        use common::trn::Trn;
+        use pretty_assertions::assert_eq;
+        use validator::ValidationErrors;
+
+        #[test]
+        fn it_should_return_conflict_when_the_prototype_already_exists() {
+            let error = PrototypeCreationError::PrototypeAlreadyExists(PrototypeId::new("fs-e-626"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = error.to_problem_detail(id, None);
+            assert_eq!(StatusCode::CONFLICT, problem_detail.status);
+            assert_eq!("The prototype already exists (id: fs-e-626)", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_unprocessable_entity_when_the_railway_was_not_found() {
+            let error = PrototypeCreationError::RailwayNotFound(RailwayId::new("fs"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = error.to_problem_detail(id, None);
+            assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, problem_detail.status);
+            assert_eq!(
+                "Unable to create the prototype due to railway not found (id: fs)",
+                problem_detail.detail
+            );
+        }
+
+        #[test]
+        fn it_should_return_bad_request_for_invalid_request() {
+            let error = PrototypeCreationError::InvalidRequest(ValidationErrors::new());
+
+            let id = Uuid::new_v4();
+            let problem_detail = error.to_problem_detail(id, None);
+            assert_eq!(StatusCode::BAD_REQUEST, problem_detail.status);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_generic_errors() {
+            let error = PrototypeCreationError::UnexpectedError(anyhow!("Something bad just happened"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = error.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("Something bad just happened", problem_detail.detail);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+//! the module includes everything related to prototype web handlers
+
+pub(crate) mod handlers;
+pub mod routes;
@@ -0,0 +1,18 @@
+use crate::catalog::prototypes::handlers;
+use crate::state::AppState;
+use axum::routing::get;
+use axum::Router;
+
+pub const PROTOTYPE_ROOT_API: &str = "/api/prototypes";
+pub const PROTOTYPE_API: &str = "/api/prototypes/:prototypeId";
+pub const PROTOTYPE_MODELS_API: &str = "/api/prototypes/:prototypeId/models";
+
+pub fn prototypes_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            PROTOTYPE_ROOT_API,
+            get(handlers::get_all_prototypes).post(handlers::post_prototype),
+        )
+        .route(PROTOTYPE_API, get(handlers::get_prototype_by_id))
+        .route(PROTOTYPE_MODELS_API, get(handlers::get_prototype_models))
+}
@@ -1,9 +1,129 @@
+use crate::catalog::railways::routes::RAILWAY_ROOT_API;
+use crate::openapi::responses::{Gone, InternalServerError, NotFound};
 use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
 use axum::extract::{Path, State};
-use axum::response::IntoResponse;
-use catalog::scales::scale_id::ScaleId;
+use axum::http::StatusCode;
+use catalog::railways::commands::delete_railway::{delete_railway, RailwayDeletionError};
+use catalog::railways::railway_id::RailwayId;
+use data::catalog::railways::repositories::RailwaysRepository;
+use data::events::repositories::OutboxEventsRepository;
+use uuid::Uuid;
 
-#[tracing::instrument(name = "delete_railway", skip(_app_state))]
-pub async fn handle(Path(_scale_id): Path<ScaleId>, State(_app_state): State<AppState>) -> impl IntoResponse {
-    ().into_response()
+/// Deletes the railway with the given id, the railway can be restored later
+#[utoipa::path(
+    delete,
+    path = "/api/railways/{railwayId}",
+    tag = "railways",
+    operation_id = "delete-railway",
+    params(("railwayId" = RailwayId, Path, description = "the railway unique identifier")),
+    responses(
+        (status = 204, description = "the railway has been deleted"),
+        (status = 404, response = NotFound),
+        (status = 410, response = Gone),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "delete_railway", skip(app_state))]
+pub async fn handle(
+    Path(railway_id): Path<RailwayId>,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode, ProblemDetail> {
+    let repo = app_state.cached(RailwaysRepository);
+    let outbox = OutboxEventsRepository;
+    let database = app_state.get_database();
+
+    let result = delete_railway(&railway_id, repo, outbox, database).await;
+    result.map(|_| StatusCode::NO_CONTENT).map_err(|why| {
+        let path = format!("{}/{}", RAILWAY_ROOT_API, railway_id);
+        why.to_problem_detail(Uuid::new_v4(), Some(&path))
+    })
+}
+
+impl ToProblemDetail for RailwayDeletionError {
+    fn to_problem_detail(self, request_id: Uuid, _path: Option<&str>) -> ProblemDetail {
+        match self {
+            RailwayDeletionError::RailwayNotFound(_) => ProblemDetail::not_found(request_id, &self.to_string()),
+            RailwayDeletionError::RailwayAlreadyDeleted(_) => ProblemDetail::gone(request_id, &self.to_string()),
+            RailwayDeletionError::RailwayNotDeleted(_) => ProblemDetail::conflict(request_id, &self.to_string()),
+            RailwayDeletionError::UnexpectedError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            RailwayDeletionError::DatabaseError(why) => ProblemDetail::error(request_id, &why.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod railway_deletion_error_to_problem_detail {
+        use super::*;
+        use anyhow::anyhow;
+        use common::queries::errors::DatabaseError;
+        use common::trn::Trn;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_return_not_found_when_the_railway_does_not_exist() {
+            let err = RailwayDeletionError::RailwayNotFound(RailwayId::new("FS"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::NOT_FOUND, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/404", problem_detail.problem_type.as_str());
+            assert_eq!("The railway was not found (id: fs)", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_gone_when_the_railway_was_already_deleted() {
+            let err = RailwayDeletionError::RailwayAlreadyDeleted(RailwayId::new("FS"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::GONE, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/410", problem_detail.problem_type.as_str());
+            assert_eq!("The railway has been already deleted (id: fs)", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_conflict_when_the_railway_is_not_deleted() {
+            let err = RailwayDeletionError::RailwayNotDeleted(RailwayId::new("FS"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::CONFLICT, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/409", problem_detail.problem_type.as_str());
+            assert_eq!("The railway is not deleted (id: fs)", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_generic_errors() {
+            let err = RailwayDeletionError::UnexpectedError(anyhow!("Something bad just happened"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/500", problem_detail.problem_type.as_str());
+            assert_eq!("Something bad just happened", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_database_errors() {
+            let err = RailwayDeletionError::DatabaseError(DatabaseError::UnexpectedError(anyhow!(
+                "Something bad just happened"
+            )));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/500", problem_detail.problem_type.as_str());
+            assert_eq!("Something bad just happened", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+    }
 }
@@ -1,24 +1,44 @@
 use crate::catalog::railways::routes;
 use crate::hateoas::representations::CollectionModel;
+use crate::openapi::responses::{InternalServerError, Unauthorized};
+use crate::replica::consistency::ReadConsistency;
 use crate::state::AppState;
+use crate::web::admin::SoftDeleteQuery;
 use crate::web::problem::ProblemDetail;
 use crate::web::responders::ToProblemDetail;
 use axum::extract::{Query, State};
 use catalog::railways::queries::find_all_railways::find_all_railways;
 use catalog::railways::railway::Railway;
 use common::queries::pagination::PageRequest;
+use common::queries::soft_delete::SoftDeleteFilter;
 use data::catalog::railways::repositories::RailwaysRepository;
 use uuid::Uuid;
 
+/// Returns the railways list
+#[utoipa::path(
+    get,
+    path = "/api/railways",
+    tag = "railways",
+    operation_id = "get-railways",
+    params(PageRequest, SoftDeleteFilter, ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    security((), ("admin_api_key" = [])),
+    responses(
+        (status = 200, description = "the railways list", body = RailwaysCollection),
+        (status = 401, response = Unauthorized),
+        (status = 500, response = InternalServerError),
+    )
+)]
 #[tracing::instrument(name = "get_all_railways", skip(app_state))]
 pub async fn handle(
     Query(_page_request): Query<PageRequest>,
+    SoftDeleteQuery(filter): SoftDeleteQuery,
+    consistency: ReadConsistency,
     State(app_state): State<AppState>,
 ) -> Result<CollectionModel<Railway>, ProblemDetail> {
-    let database = app_state.get_database();
-    let repo = RailwaysRepository;
+    let database = app_state.get_read_database(consistency);
+    let repo = app_state.cached(RailwaysRepository);
 
-    let results = find_all_railways(repo, database).await;
+    let results = find_all_railways(filter.include_deleted, repo, database).await;
     results
         .map(|railways| CollectionModel::of(railways, Vec::new()))
         .map_err(|why| why.to_problem_detail(Uuid::new_v4(), Some(routes::RAILWAY_ROOT_API)))
@@ -1,24 +1,46 @@
 use crate::catalog::railways::routes;
 use crate::hateoas::representations::EntityModel;
+use crate::openapi::responses::{Gone, InternalServerError, NotFound, Unauthorized};
+use crate::replica::consistency::ReadConsistency;
 use crate::state::AppState;
+use crate::web::admin::SoftDeleteQuery;
 use crate::web::problem::ProblemDetail;
 use crate::web::responders::ToProblemDetail;
 use axum::extract::{Path, State};
 use catalog::railways::queries::find_railway_by_id::find_railway_by_id;
 use catalog::railways::railway::Railway;
 use catalog::railways::railway_id::RailwayId;
+use common::queries::soft_delete::SoftDeleteFilter;
 use data::catalog::railways::repositories::RailwaysRepository;
 use uuid::Uuid;
 
+/// Returns the railway with the given id
+#[utoipa::path(
+    get,
+    path = "/api/railways/{railwayId}",
+    tag = "railways",
+    operation_id = "get-railway-by-id",
+    params(("railwayId" = RailwayId, Path, description = "the railway unique identifier"), SoftDeleteFilter, ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    security((), ("admin_api_key" = [])),
+    responses(
+        (status = 200, description = "the railway", body = RailwayModel),
+        (status = 401, response = Unauthorized),
+        (status = 404, response = NotFound),
+        (status = 410, response = Gone),
+        (status = 500, response = InternalServerError),
+    )
+)]
 #[tracing::instrument(name = "get_railway_by_id", skip(app_state))]
 pub async fn handle(
     Path(railway_id): Path<RailwayId>,
+    SoftDeleteQuery(filter): SoftDeleteQuery,
+    consistency: ReadConsistency,
     State(app_state): State<AppState>,
 ) -> Result<EntityModel<Railway>, ProblemDetail> {
-    let database = app_state.get_database();
-    let repo = RailwaysRepository;
+    let database = app_state.get_read_database(consistency);
+    let repo = app_state.cached(RailwaysRepository);
 
-    let result = find_railway_by_id(&railway_id, repo, database).await;
+    let result = find_railway_by_id(&railway_id, filter.include_deleted, repo, database).await;
     result
         .map(|railway| EntityModel::of(railway, Vec::new()))
         .map_err(|why| {
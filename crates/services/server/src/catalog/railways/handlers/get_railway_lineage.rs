@@ -0,0 +1,57 @@
+use crate::catalog::railways::routes::RAILWAY_ROOT_API;
+use crate::hateoas::links::{Link, LinkRelation};
+use crate::hateoas::representations::EntityModel;
+use crate::openapi::responses::{InternalServerError, NotFound};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::web::urls::base_url;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use catalog::railways::queries::find_railway_lineage::find_railway_lineage;
+use catalog::railways::railway_id::RailwayId;
+use catalog::railways::railway_relationship::RailwayLineage;
+use data::catalog::railways::repositories::RailwaysRepository;
+use uuid::Uuid;
+
+/// Returns the railway corporate history, with its predecessors and successors
+#[utoipa::path(
+    get,
+    path = "/api/railways/{railwayId}/lineage",
+    tag = "railways",
+    operation_id = "get-railway-lineage",
+    params(("railwayId" = RailwayId, Path, description = "the railway unique identifier"), ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    responses(
+        (status = 200, description = "the railway lineage", body = RailwayLineageModel),
+        (status = 404, response = NotFound),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_railway_lineage", skip(app_state, headers))]
+pub async fn handle(
+    Path(railway_id): Path<RailwayId>,
+    headers: HeaderMap,
+    consistency: ReadConsistency,
+    State(app_state): State<AppState>,
+) -> Result<EntityModel<RailwayLineage>, ProblemDetail> {
+    let database = app_state.get_read_database(consistency);
+    let repo = RailwaysRepository;
+
+    let path = lineage_path(&railway_id);
+    let result = find_railway_lineage(&railway_id, repo, database).await;
+    result
+        .map(|lineage| {
+            let base_url = base_url(app_state.public_url.as_deref(), &headers);
+            let links = Link::of(&format!("{base_url}{path}"), LinkRelation::SelfLink)
+                .into_iter()
+                .collect();
+            EntityModel::of(lineage, links)
+        })
+        .map_err(|why| why.to_problem_detail(Uuid::new_v4(), Some(&path)))
+}
+
+/// Returns the path for the railway lineage
+pub(crate) fn lineage_path(railway_id: &RailwayId) -> String {
+    format!("{RAILWAY_ROOT_API}/{railway_id}/lineage")
+}
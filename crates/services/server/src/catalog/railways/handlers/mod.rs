@@ -1,11 +1,17 @@
-mod delete_railway;
-mod get_all_railways;
-mod get_railway_by_id;
-mod post_railway;
-mod put_railway;
+pub(crate) mod delete_railway;
+pub(crate) mod get_all_railways;
+pub(crate) mod get_railway_by_id;
+pub(crate) mod get_railway_lineage;
+pub(crate) mod post_railway;
+pub(crate) mod post_railway_relationship;
+pub(crate) mod put_railway;
+pub(crate) mod restore_railway;
 
 pub use delete_railway::handle as delete_railway;
 pub use get_all_railways::handle as get_all_railways;
 pub use get_railway_by_id::handle as get_railway_by_id;
+pub use get_railway_lineage::handle as get_railway_lineage;
 pub use post_railway::handle as post_railway;
+pub use post_railway_relationship::handle as post_railway_relationship;
 pub use put_railway::handle as put_railway;
+pub use restore_railway::handle as restore_railway;
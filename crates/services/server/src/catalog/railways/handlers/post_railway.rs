@@ -1,4 +1,5 @@
 use crate::catalog::railways::routes::RAILWAY_ROOT_API;
+use crate::openapi::responses::{AlreadyExists, BadRequest, InternalServerError};
 use crate::state::AppState;
 use crate::web::problem::ProblemDetail;
 use crate::web::responders::{Created, ToProblemDetail};
@@ -7,17 +8,33 @@ use axum::Json;
 use catalog::railways::commands::new_railways::{create_new_railway, RailwayCreationError};
 use catalog::railways::railway_request::RailwayRequest;
 use data::catalog::railways::repositories::RailwaysRepository;
+use data::events::repositories::OutboxEventsRepository;
 use uuid::Uuid;
 
+/// Creates a new railway
+#[utoipa::path(
+    post,
+    path = "/api/railways",
+    tag = "railways",
+    operation_id = "post-railway",
+    request_body = RailwayRequest,
+    responses(
+        (status = 201, description = "the railway has been created", headers(("location" = String, description = "the new railway url"))),
+        (status = 400, response = BadRequest),
+        (status = 409, response = AlreadyExists),
+        (status = 500, response = InternalServerError),
+    )
+)]
 #[tracing::instrument(name = "create_railway", skip(app_state))]
 pub async fn handle(
     State(app_state): State<AppState>,
     Json(request): Json<RailwayRequest>,
 ) -> Result<Created, ProblemDetail> {
-    let repo = RailwaysRepository;
+    let repo = app_state.cached(RailwaysRepository);
+    let outbox = OutboxEventsRepository;
     let database = app_state.get_database();
 
-    let result = create_new_railway(request, repo, database).await;
+    let result = create_new_railway(request, repo, outbox, database).await;
     result
         .map(|created| {
             let location = format!("{}/{}", RAILWAY_ROOT_API, created.railway_id);
@@ -0,0 +1,133 @@
+use crate::catalog::railways::handlers::get_railway_lineage::lineage_path;
+use crate::openapi::responses::{AlreadyExists, BadRequest, InternalServerError, NotFound, UnprocessableEntity};
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::{Created, ToProblemDetail};
+use axum::extract::{Path, State};
+use axum::Json;
+use catalog::railways::commands::new_railway_relationship::{
+    create_new_railway_relationship, RailwayRelationshipCreationError,
+};
+use catalog::railways::railway_id::RailwayId;
+use catalog::railways::railway_relationship::RailwayRelationshipRequest;
+use data::catalog::railways::repositories::RailwaysRepository;
+use data::events::repositories::OutboxEventsRepository;
+use uuid::Uuid;
+
+/// Links the railway to its predecessor or successor (ie, `FS` renamed to `Trenitalia`)
+#[utoipa::path(
+    post,
+    path = "/api/railways/{railwayId}/relationships",
+    tag = "railways",
+    operation_id = "post-railway-relationship",
+    params(("railwayId" = RailwayId, Path, description = "the railway unique identifier")),
+    request_body = RailwayRelationshipRequest,
+    responses(
+        (status = 201, description = "the railway relationship has been created", headers(("location" = String, description = "the railway lineage url"))),
+        (status = 400, response = BadRequest),
+        (status = 404, response = NotFound),
+        (status = 409, response = AlreadyExists),
+        (status = 422, response = UnprocessableEntity),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "create_railway_relationship", skip(app_state))]
+pub async fn handle(
+    Path(railway_id): Path<RailwayId>,
+    State(app_state): State<AppState>,
+    Json(request): Json<RailwayRelationshipRequest>,
+) -> Result<Created, ProblemDetail> {
+    let repo = RailwaysRepository;
+    let outbox = OutboxEventsRepository;
+    let database = app_state.get_database();
+
+    let result = create_new_railway_relationship(&railway_id, request, repo, outbox, database).await;
+    result
+        .map(|updated| Created::with_location(&lineage_path(&updated.railway_id)))
+        .map_err(|why| why.to_problem_detail(Uuid::new_v4(), None))
+}
+
+impl ToProblemDetail for RailwayRelationshipCreationError {
+    fn to_problem_detail(self, request_id: Uuid, _path: Option<&str>) -> ProblemDetail {
+        match self {
+            RailwayRelationshipCreationError::RelationshipAlreadyExists(_, _) => {
+                ProblemDetail::resource_already_exists(request_id, &self.to_string())
+            }
+            RailwayRelationshipCreationError::RailwayNotFound(_) => {
+                ProblemDetail::not_found(request_id, &self.to_string())
+            }
+            RailwayRelationshipCreationError::CircularRelationship(_, _) => {
+                ProblemDetail::unprocessable_entity(request_id, &self.to_string())
+            }
+            RailwayRelationshipCreationError::DatabaseError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            RailwayRelationshipCreationError::UnexpectedError(why) => {
+                ProblemDetail::error(request_id, &why.to_string())
+            }
+            RailwayRelationshipCreationError::InvalidRequest(_) => ProblemDetail::bad_request(request_id, ""),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod railway_relationship_creation_error_to_problem_detail {
+        use super::*;
+        use anyhow::anyhow;
+        use axum::http::StatusCode;
+        use common::trn::Trn;
+        use pretty_assertions::assert_eq;
+        use validator::ValidationErrors;
+
+        #[test]
+        fn it_should_return_conflict_when_the_relationship_already_exists() {
+            let error =
+                RailwayRelationshipCreationError::RelationshipAlreadyExists(RailwayId::new("FS"), RailwayId::new("DB"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = error.to_problem_detail(id, None);
+            assert_eq!(StatusCode::CONFLICT, problem_detail.status);
+            assert_eq!(
+                "The railway relationship already exists (from: fs, to: db)",
+                problem_detail.detail
+            );
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_not_found_when_the_railway_does_not_exist() {
+            let error = RailwayRelationshipCreationError::RailwayNotFound(RailwayId::new("FS"));
+
+            let problem_detail = error.to_problem_detail(Uuid::new_v4(), None);
+            assert_eq!(StatusCode::NOT_FOUND, problem_detail.status);
+            assert_eq!("The railway was not found (id: fs)", problem_detail.detail);
+        }
+
+        #[test]
+        fn it_should_return_unprocessable_entity_for_circular_relationships() {
+            let error =
+                RailwayRelationshipCreationError::CircularRelationship(RailwayId::new("FS"), RailwayId::new("FS"));
+
+            let problem_detail = error.to_problem_detail(Uuid::new_v4(), None);
+            assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, problem_detail.status);
+        }
+
+        #[test]
+        fn it_should_return_bad_request_for_invalid_request() {
+            let error = RailwayRelationshipCreationError::InvalidRequest(ValidationErrors::new());
+
+            let problem_detail = error.to_problem_detail(Uuid::new_v4(), None);
+            assert_eq!(StatusCode::BAD_REQUEST, problem_detail.status);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_generic_errors() {
+            let error = RailwayRelationshipCreationError::UnexpectedError(anyhow!("Something bad just happened"));
+
+            let problem_detail = error.to_problem_detail(Uuid::new_v4(), None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("Something bad just happened", problem_detail.detail);
+        }
+    }
+}
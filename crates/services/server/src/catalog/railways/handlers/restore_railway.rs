@@ -0,0 +1,46 @@
+use crate::catalog::railways::routes::RAILWAY_ROOT_API;
+use crate::openapi::responses::{Conflict, InternalServerError, NotFound, Unauthorized};
+use crate::state::AppState;
+use crate::web::admin::AdminAccess;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use catalog::railways::commands::delete_railway::restore_railway;
+use catalog::railways::railway_id::RailwayId;
+use data::catalog::railways::repositories::RailwaysRepository;
+use data::events::repositories::OutboxEventsRepository;
+use uuid::Uuid;
+
+/// Restores the deleted railway with the given id
+#[utoipa::path(
+    post,
+    path = "/api/railways/{railwayId}/restore",
+    tag = "railways",
+    operation_id = "restore-railway",
+    params(("railwayId" = RailwayId, Path, description = "the railway unique identifier")),
+    security(("admin_api_key" = [])),
+    responses(
+        (status = 204, description = "the railway has been restored"),
+        (status = 401, response = Unauthorized),
+        (status = 404, response = NotFound),
+        (status = 409, response = Conflict),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "restore_railway", skip(app_state))]
+pub async fn handle(
+    Path(railway_id): Path<RailwayId>,
+    _admin: AdminAccess,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode, ProblemDetail> {
+    let repo = app_state.cached(RailwaysRepository);
+    let outbox = OutboxEventsRepository;
+    let database = app_state.get_database();
+
+    let result = restore_railway(&railway_id, repo, outbox, database).await;
+    result.map(|_| StatusCode::NO_CONTENT).map_err(|why| {
+        let path = format!("{}/{}", RAILWAY_ROOT_API, railway_id);
+        why.to_problem_detail(Uuid::new_v4(), Some(&path))
+    })
+}
@@ -1,10 +1,13 @@
 use crate::catalog::railways::handlers;
 use crate::state::AppState;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::Router;
 
 pub const RAILWAY_ROOT_API: &str = "/api/railways";
 pub const RAILWAY_API: &str = "/api/railways/:railwayId";
+pub const RAILWAY_RESTORE_API: &str = "/api/railways/:railwayId/restore";
+pub const RAILWAY_RELATIONSHIPS_API: &str = "/api/railways/:railwayId/relationships";
+pub const RAILWAY_LINEAGE_API: &str = "/api/railways/:railwayId/lineage";
 
 pub fn railways_router() -> Router<AppState> {
     Router::new()
@@ -18,4 +21,7 @@ pub fn railways_router() -> Router<AppState> {
                 .put(handlers::put_railway)
                 .delete(handlers::delete_railway),
         )
+        .route(RAILWAY_RESTORE_API, post(handlers::restore_railway))
+        .route(RAILWAY_RELATIONSHIPS_API, post(handlers::post_railway_relationship))
+        .route(RAILWAY_LINEAGE_API, get(handlers::get_railway_lineage))
 }
@@ -1,9 +1,129 @@
+use crate::catalog::scales::routes::SCALE_ROOT_API;
+use crate::openapi::responses::{Gone, InternalServerError, NotFound};
 use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
 use axum::extract::{Path, State};
-use axum::response::IntoResponse;
+use axum::http::StatusCode;
+use catalog::scales::commands::delete_scale::{delete_scale, ScaleDeletionError};
 use catalog::scales::scale_id::ScaleId;
+use data::catalog::scales::repositories::ScalesRepository;
+use data::events::repositories::OutboxEventsRepository;
+use uuid::Uuid;
 
-#[tracing::instrument(name = "delete_scale", skip(_app_state))]
-pub async fn handle(Path(_scale_id): Path<ScaleId>, State(_app_state): State<AppState>) -> impl IntoResponse {
-    ().into_response()
+/// Deletes the modelling scale with the given id, the modelling scale can be restored later
+#[utoipa::path(
+    delete,
+    path = "/api/scales/{scale_id}",
+    tag = "scales",
+    operation_id = "delete-scale",
+    params(("scale_id" = ScaleId, Path, description = "the modelling scale unique identifier")),
+    responses(
+        (status = 204, description = "the modelling scale has been deleted"),
+        (status = 404, response = NotFound),
+        (status = 410, response = Gone),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "delete_scale", skip(app_state))]
+pub async fn handle(
+    Path(scale_id): Path<ScaleId>,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode, ProblemDetail> {
+    let repo = app_state.cached(ScalesRepository);
+    let outbox = OutboxEventsRepository;
+    let database = app_state.get_database();
+
+    let result = delete_scale(&scale_id, repo, outbox, database).await;
+    result.map(|_| StatusCode::NO_CONTENT).map_err(|why| {
+        let path = format!("{}/{}", SCALE_ROOT_API, scale_id);
+        why.to_problem_detail(Uuid::new_v4(), Some(&path))
+    })
+}
+
+impl ToProblemDetail for ScaleDeletionError {
+    fn to_problem_detail(self, request_id: Uuid, _path: Option<&str>) -> ProblemDetail {
+        match self {
+            ScaleDeletionError::ScaleNotFound(_) => ProblemDetail::not_found(request_id, &self.to_string()),
+            ScaleDeletionError::ScaleAlreadyDeleted(_) => ProblemDetail::gone(request_id, &self.to_string()),
+            ScaleDeletionError::ScaleNotDeleted(_) => ProblemDetail::conflict(request_id, &self.to_string()),
+            ScaleDeletionError::UnexpectedError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            ScaleDeletionError::DatabaseError(why) => ProblemDetail::error(request_id, &why.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod scale_deletion_error_to_problem_detail {
+        use super::*;
+        use anyhow::anyhow;
+        use common::queries::errors::DatabaseError;
+        use common::trn::Trn;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_return_not_found_when_the_scale_does_not_exist() {
+            let err = ScaleDeletionError::ScaleNotFound(ScaleId::new("H0"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::NOT_FOUND, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/404", problem_detail.problem_type.as_str());
+            assert_eq!("The scale was not found (id: h0)", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_gone_when_the_scale_was_already_deleted() {
+            let err = ScaleDeletionError::ScaleAlreadyDeleted(ScaleId::new("H0"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::GONE, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/410", problem_detail.problem_type.as_str());
+            assert_eq!("The scale has been already deleted (id: h0)", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_conflict_when_the_scale_is_not_deleted() {
+            let err = ScaleDeletionError::ScaleNotDeleted(ScaleId::new("H0"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::CONFLICT, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/409", problem_detail.problem_type.as_str());
+            assert_eq!("The scale is not deleted (id: h0)", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_generic_errors() {
+            let err = ScaleDeletionError::UnexpectedError(anyhow!("Something bad just happened"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/500", problem_detail.problem_type.as_str());
+            assert_eq!("Something bad just happened", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_database_errors() {
+            let err = ScaleDeletionError::DatabaseError(DatabaseError::UnexpectedError(anyhow!(
+                "Something bad just happened"
+            )));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/500", problem_detail.problem_type.as_str());
+            assert_eq!("Something bad just happened", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+    }
 }
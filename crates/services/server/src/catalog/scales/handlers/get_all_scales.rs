@@ -1,24 +1,44 @@
 use crate::catalog::scales::routes;
 use crate::hateoas::representations::CollectionModel;
+use crate::openapi::responses::{InternalServerError, Unauthorized};
+use crate::replica::consistency::ReadConsistency;
 use crate::state::AppState;
+use crate::web::admin::SoftDeleteQuery;
 use crate::web::problem::ProblemDetail;
 use crate::web::responders::ToProblemDetail;
 use axum::extract::{Query, State};
 use catalog::scales::queries::find_all_scales::find_all_scales;
 use catalog::scales::scale::Scale;
 use common::queries::pagination::PageRequest;
+use common::queries::soft_delete::SoftDeleteFilter;
 use data::catalog::scales::repositories::ScalesRepository;
 use uuid::Uuid;
 
+/// Returns the modelling scales list
+#[utoipa::path(
+    get,
+    path = "/api/scales",
+    tag = "scales",
+    operation_id = "get-scales",
+    params(PageRequest, SoftDeleteFilter, ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    security((), ("admin_api_key" = [])),
+    responses(
+        (status = 200, description = "the modelling scales list", body = ScalesCollection),
+        (status = 401, response = Unauthorized),
+        (status = 500, response = InternalServerError),
+    )
+)]
 #[tracing::instrument(name = "get_all_scales", skip(app_state))]
 pub async fn handle(
     Query(_page_request): Query<PageRequest>,
+    SoftDeleteQuery(filter): SoftDeleteQuery,
+    consistency: ReadConsistency,
     State(app_state): State<AppState>,
 ) -> Result<CollectionModel<Scale>, ProblemDetail> {
-    let database = app_state.get_database();
-    let repo = ScalesRepository;
+    let database = app_state.get_read_database(consistency);
+    let repo = app_state.cached(ScalesRepository);
 
-    let results = find_all_scales(repo, database).await;
+    let results = find_all_scales(filter.include_deleted, repo, database).await;
     results
         .map(|scales| CollectionModel::of(scales, Vec::new()))
         .map_err(|why| why.to_problem_detail(Uuid::new_v4(), Some(routes::SCALE_ROOT_API)))
@@ -1,24 +1,46 @@
 use crate::catalog::scales::routes;
 use crate::hateoas::representations::EntityModel;
+use crate::openapi::responses::{Gone, InternalServerError, NotFound, Unauthorized};
+use crate::replica::consistency::ReadConsistency;
 use crate::state::AppState;
+use crate::web::admin::SoftDeleteQuery;
 use crate::web::problem::ProblemDetail;
 use crate::web::responders::ToProblemDetail;
 use axum::extract::{Path, State};
 use catalog::scales::queries::find_scale_by_id::find_scale_by_id;
 use catalog::scales::scale::Scale;
 use catalog::scales::scale_id::ScaleId;
+use common::queries::soft_delete::SoftDeleteFilter;
 use data::catalog::scales::repositories::ScalesRepository;
 use uuid::Uuid;
 
+/// Returns the modelling scale with the given id
+#[utoipa::path(
+    get,
+    path = "/api/scales/{scale_id}",
+    tag = "scales",
+    operation_id = "get-scale-by-id",
+    params(("scale_id" = ScaleId, Path, description = "the modelling scale unique identifier"), SoftDeleteFilter, ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    security((), ("admin_api_key" = [])),
+    responses(
+        (status = 200, description = "the modelling scale", body = ScaleModel),
+        (status = 401, response = Unauthorized),
+        (status = 404, response = NotFound),
+        (status = 410, response = Gone),
+        (status = 500, response = InternalServerError),
+    )
+)]
 #[tracing::instrument(name = "get_scale_by_id", skip(app_state))]
 pub async fn handle(
     Path(scale_id): Path<ScaleId>,
+    SoftDeleteQuery(filter): SoftDeleteQuery,
+    consistency: ReadConsistency,
     State(app_state): State<AppState>,
 ) -> Result<EntityModel<Scale>, ProblemDetail> {
-    let database = app_state.get_database();
-    let repo = ScalesRepository;
+    let database = app_state.get_read_database(consistency);
+    let repo = app_state.cached(ScalesRepository);
 
-    let result = find_scale_by_id(&scale_id, repo, database).await;
+    let result = find_scale_by_id(&scale_id, filter.include_deleted, repo, database).await;
     result.map(|scale| EntityModel::of(scale, Vec::new())).map_err(|why| {
         let path = format!("{}/{}", routes::SCALE_ROOT_API, scale_id);
         why.to_problem_detail(Uuid::new_v4(), Some(&path))
@@ -1,11 +1,13 @@
-mod delete_scale;
-mod get_all_scales;
-mod get_scale_by_id;
-mod post_scale;
-mod put_scale;
+pub(crate) mod delete_scale;
+pub(crate) mod get_all_scales;
+pub(crate) mod get_scale_by_id;
+pub(crate) mod post_scale;
+pub(crate) mod put_scale;
+pub(crate) mod restore_scale;
 
 pub use delete_scale::handle as delete_scale;
 pub use get_all_scales::handle as get_all_scales;
 pub use get_scale_by_id::handle as get_scale_by_id;
 pub use post_scale::handle as post_scale;
 pub use put_scale::handle as put_scale;
+pub use restore_scale::handle as restore_scale;
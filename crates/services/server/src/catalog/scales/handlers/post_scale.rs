@@ -1,4 +1,5 @@
 use crate::catalog::scales::routes::SCALE_ROOT_API;
+use crate::openapi::responses::{AlreadyExists, BadRequest, InternalServerError};
 use crate::state::AppState;
 use crate::web::problem::ProblemDetail;
 use crate::web::responders::{Created, ToProblemDetail};
@@ -7,17 +8,33 @@ use axum::Json;
 use catalog::scales::commands::new_scales::{create_new_scale, ScaleCreationError};
 use catalog::scales::scale_request::ScaleRequest;
 use data::catalog::scales::repositories::ScalesRepository;
+use data::events::repositories::OutboxEventsRepository;
 use uuid::Uuid;
 
+/// Creates a new modelling scale
+#[utoipa::path(
+    post,
+    path = "/api/scales",
+    tag = "scales",
+    operation_id = "post-scale",
+    request_body = ScaleRequest,
+    responses(
+        (status = 201, description = "the modelling scale has been created", headers(("location" = String, description = "the new modelling scale url"))),
+        (status = 400, response = BadRequest),
+        (status = 409, response = AlreadyExists),
+        (status = 500, response = InternalServerError),
+    )
+)]
 #[tracing::instrument(name = "create_new_scale", skip(app_state))]
 pub async fn handle(
     State(app_state): State<AppState>,
     Json(request): Json<ScaleRequest>,
 ) -> Result<Created, ProblemDetail> {
-    let repo = ScalesRepository;
+    let repo = app_state.cached(ScalesRepository);
+    let outbox = OutboxEventsRepository;
     let database = app_state.get_database();
 
-    let result = create_new_scale(request, repo, database).await;
+    let result = create_new_scale(request, repo, outbox, database).await;
     result
         .map(|created| {
             let location = format!("{}/{}", SCALE_ROOT_API, created.scale_id);
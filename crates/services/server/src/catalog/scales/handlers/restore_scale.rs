@@ -0,0 +1,46 @@
+use crate::catalog::scales::routes::SCALE_ROOT_API;
+use crate::openapi::responses::{Conflict, InternalServerError, NotFound, Unauthorized};
+use crate::state::AppState;
+use crate::web::admin::AdminAccess;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use catalog::scales::commands::delete_scale::restore_scale;
+use catalog::scales::scale_id::ScaleId;
+use data::catalog::scales::repositories::ScalesRepository;
+use data::events::repositories::OutboxEventsRepository;
+use uuid::Uuid;
+
+/// Restores the deleted modelling scale with the given id
+#[utoipa::path(
+    post,
+    path = "/api/scales/{scale_id}/restore",
+    tag = "scales",
+    operation_id = "restore-scale",
+    params(("scale_id" = ScaleId, Path, description = "the modelling scale unique identifier")),
+    security(("admin_api_key" = [])),
+    responses(
+        (status = 204, description = "the modelling scale has been restored"),
+        (status = 401, response = Unauthorized),
+        (status = 404, response = NotFound),
+        (status = 409, response = Conflict),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "restore_scale", skip(app_state))]
+pub async fn handle(
+    Path(scale_id): Path<ScaleId>,
+    _admin: AdminAccess,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode, ProblemDetail> {
+    let repo = app_state.cached(ScalesRepository);
+    let outbox = OutboxEventsRepository;
+    let database = app_state.get_database();
+
+    let result = restore_scale(&scale_id, repo, outbox, database).await;
+    result.map(|_| StatusCode::NO_CONTENT).map_err(|why| {
+        let path = format!("{}/{}", SCALE_ROOT_API, scale_id);
+        why.to_problem_detail(Uuid::new_v4(), Some(&path))
+    })
+}
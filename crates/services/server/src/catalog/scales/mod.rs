@@ -1,4 +1,4 @@
 //! the module includes everything related to scale web handlers
 
-mod handlers;
+pub(crate) mod handlers;
 pub mod routes;
@@ -1,10 +1,11 @@
 use crate::catalog::scales::handlers;
 use crate::state::AppState;
-use axum::routing::{delete, get};
+use axum::routing::{delete, get, post};
 use axum::Router;
 
 pub const SCALE_ROOT_API: &str = "/api/scales";
 pub const SCALE_API: &str = "/api/scales/:scale_id";
+pub const SCALE_RESTORE_API: &str = "/api/scales/:scale_id/restore";
 
 pub fn scales_router() -> Router<AppState> {
     Router::new()
@@ -15,4 +16,5 @@ pub fn scales_router() -> Router<AppState> {
                 .get(handlers::get_scale_by_id)
                 .put(handlers::put_scale),
         )
+        .route(SCALE_RESTORE_API, post(handlers::restore_scale))
 }
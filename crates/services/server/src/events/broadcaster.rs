@@ -0,0 +1,222 @@
+//! the in-process broadcast of the catalog changes to the event stream clients
+
+use async_trait::async_trait;
+use catalog::events::domain_event::EventEnvelope;
+use catalog::events::event_sink::EventSink;
+use configuration::EventStreamSettings;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// A domain event, numbered in the order it was broadcast to the event stream clients
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamEvent {
+    /// the event sequence number, it is used as the server-sent event id
+    pub sequence: u64,
+    /// the domain event
+    pub envelope: EventEnvelope,
+}
+
+/// A new subscription to the broadcast events
+#[derive(Debug)]
+pub struct Subscription {
+    /// the recent events the client missed, from the replay buffer
+    pub replay: Vec<Arc<StreamEvent>>,
+    /// the receiver for the events broadcast after the subscription
+    pub receiver: broadcast::Receiver<Arc<StreamEvent>>,
+}
+
+/// It broadcasts the domain events to the event stream clients.
+///
+/// The most recent events are kept in a bounded replay buffer, the clients which reconnect with
+/// the `Last-Event-ID` header receive the events they missed while they were disconnected (as long
+/// as they are still in the buffer). The sequence numbers restart with the server, a `Last-Event-ID`
+/// ahead of the last broadcast event means the whole buffer is replayed.
+#[derive(Debug, Clone)]
+pub struct EventBroadcaster {
+    buffer: Arc<Mutex<ReplayBuffer>>,
+    sender: broadcast::Sender<Arc<StreamEvent>>,
+    keep_alive_interval: Duration,
+}
+
+#[derive(Debug)]
+struct ReplayBuffer {
+    capacity: usize,
+    last_sequence: u64,
+    events: VecDeque<Arc<StreamEvent>>,
+}
+
+impl EventBroadcaster {
+    /// Creates a new event broadcaster
+    pub fn new(settings: &EventStreamSettings) -> Self {
+        let capacity = settings.replay_buffer_size.max(1);
+        let (sender, _) = broadcast::channel(capacity);
+        let buffer = ReplayBuffer {
+            capacity,
+            last_sequence: 0,
+            events: VecDeque::with_capacity(capacity),
+        };
+        EventBroadcaster {
+            buffer: Arc::new(Mutex::new(buffer)),
+            sender,
+            keep_alive_interval: settings.keep_alive_interval(),
+        }
+    }
+
+    /// Broadcasts the event to all the subscribers, returning its sequence number.
+    ///
+    /// The events already in the replay buffer are not broadcast twice.
+    pub fn broadcast(&self, envelope: EventEnvelope) -> Option<u64> {
+        let mut buffer = self.buffer.lock().expect("the replay buffer lock is poisoned");
+        if buffer.events.iter().any(|it| it.envelope.event_id == envelope.event_id) {
+            return None;
+        }
+
+        buffer.last_sequence += 1;
+        let event = Arc::new(StreamEvent {
+            sequence: buffer.last_sequence,
+            envelope,
+        });
+
+        if buffer.events.len() == buffer.capacity {
+            buffer.events.pop_front();
+        }
+        buffer.events.push_back(event.clone());
+
+        // sending fails only when there are no subscribers
+        let _ = self.sender.send(event);
+        Some(buffer.last_sequence)
+    }
+
+    /// Subscribes to the broadcast events, the events after `last_event_id` are replayed from the buffer
+    pub fn subscribe(&self, last_event_id: Option<u64>) -> Subscription {
+        let buffer = self.buffer.lock().expect("the replay buffer lock is poisoned");
+        let receiver = self.sender.subscribe();
+
+        let replay = match last_event_id {
+            None => Vec::new(),
+            Some(last_event_id) if last_event_id > buffer.last_sequence => buffer.events.iter().cloned().collect(),
+            Some(last_event_id) => buffer
+                .events
+                .iter()
+                .filter(|it| it.sequence > last_event_id)
+                .cloned()
+                .collect(),
+        };
+
+        Subscription { replay, receiver }
+    }
+
+    /// The interval between two keep-alive messages
+    pub fn keep_alive_interval(&self) -> Duration {
+        self.keep_alive_interval
+    }
+}
+
+#[async_trait]
+impl EventSink for EventBroadcaster {
+    fn name(&self) -> &str {
+        "event-stream"
+    }
+
+    async fn publish(&self, envelope: &EventEnvelope) -> Result<(), anyhow::Error> {
+        self.broadcast(envelope.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod event_broadcaster {
+        use super::*;
+        use catalog::brands::brand_id::BrandId;
+        use catalog::brands::brand_response::BrandCreated;
+        use catalog::events::domain_event::DomainEvent;
+        use chrono::Utc;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_broadcast_events_to_the_subscribers() {
+            let broadcaster = new_broadcaster(10);
+            let mut subscription = broadcaster.subscribe(None);
+
+            let envelope = new_envelope("ACME");
+            let sequence = broadcaster.broadcast(envelope.clone());
+
+            assert_eq!(Some(1), sequence);
+            assert!(subscription.replay.is_empty());
+
+            let received = subscription.receiver.recv().await.unwrap();
+            assert_eq!(1, received.sequence);
+            assert_eq!(envelope, received.envelope);
+        }
+
+        #[test]
+        fn it_should_not_broadcast_the_same_event_twice() {
+            let broadcaster = new_broadcaster(10);
+
+            let envelope = new_envelope("ACME");
+
+            assert_eq!(Some(1), broadcaster.broadcast(envelope.clone()));
+            assert_eq!(None, broadcaster.broadcast(envelope));
+            assert_eq!(Some(2), broadcaster.broadcast(new_envelope("Roco")));
+        }
+
+        #[test]
+        fn it_should_replay_the_events_after_the_last_event_id() {
+            let broadcaster = new_broadcaster(10);
+            for name in ["ACME", "Roco", "Rivarossi"] {
+                broadcaster.broadcast(new_envelope(name));
+            }
+
+            let subscription = broadcaster.subscribe(Some(1));
+
+            assert_eq!(vec![2, 3], sequences(&subscription));
+        }
+
+        #[test]
+        fn it_should_keep_only_the_most_recent_events_in_the_replay_buffer() {
+            let broadcaster = new_broadcaster(2);
+            for name in ["ACME", "Roco", "Rivarossi"] {
+                broadcaster.broadcast(new_envelope(name));
+            }
+
+            let subscription = broadcaster.subscribe(Some(0));
+
+            assert_eq!(vec![2, 3], sequences(&subscription));
+        }
+
+        #[test]
+        fn it_should_replay_the_whole_buffer_when_the_last_event_id_is_unknown() {
+            let broadcaster = new_broadcaster(10);
+            for name in ["ACME", "Roco"] {
+                broadcaster.broadcast(new_envelope(name));
+            }
+
+            let subscription = broadcaster.subscribe(Some(42));
+
+            assert_eq!(vec![1, 2], sequences(&subscription));
+        }
+
+        fn new_broadcaster(replay_buffer_size: usize) -> EventBroadcaster {
+            EventBroadcaster::new(&EventStreamSettings {
+                replay_buffer_size,
+                ..EventStreamSettings::default()
+            })
+        }
+
+        fn new_envelope(name: &str) -> EventEnvelope {
+            EventEnvelope::new(DomainEvent::BrandCreated(BrandCreated {
+                brand_id: BrandId::new(name),
+                created_at: Utc::now(),
+            }))
+        }
+
+        fn sequences(subscription: &Subscription) -> Vec<u64> {
+            subscription.replay.iter().map(|it| it.sequence).collect()
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use crate::events::broadcaster::{StreamEvent, Subscription};
+use crate::openapi::responses::BadRequest;
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use catalog::events::domain_event::{AggregateType, ChangeKind, DomainEvent};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{self, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+
+/// The event stream query string, the entity types are a comma separated list (ie `brand,scale`)
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventStreamQuery {
+    /// only the changes for these entity types, a comma separated list (ie `brand,scale`)
+    pub entity_types: Option<String>,
+}
+
+/// A catalog change, as pushed to the event stream clients
+#[derive(Debug, Serialize)]
+struct CatalogChange<'a> {
+    event_id: Uuid,
+    entity_type: AggregateType,
+    entity_id: String,
+    change: ChangeKind,
+    occurred_at: DateTime<Utc>,
+    #[serde(flatten)]
+    event: &'a DomainEvent,
+}
+
+/// The stream of catalog changes, as server-sent events
+#[utoipa::path(
+    get,
+    path = "/api/events",
+    tag = "events",
+    operation_id = "get-events",
+    params(EventStreamQuery, ("last-event-id" = Option<String>, Header, description = "the last event received, to replay the missed events")),
+    responses(
+        (status = 200, description = "the stream of catalog changes", content_type = "text/event-stream", body = String),
+        (status = 400, response = BadRequest),
+    )
+)]
+#[tracing::instrument(name = "get_events", skip(app_state, headers))]
+pub async fn handle(
+    Query(query): Query<EventStreamQuery>,
+    headers: HeaderMap,
+    State(app_state): State<AppState>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, ProblemDetail> {
+    let entity_types = parse_entity_types(query.entity_types.as_deref())
+        .map_err(|why| ProblemDetail::bad_request(Uuid::new_v4(), &why))?;
+
+    let last_event_id = headers
+        .get(LAST_EVENT_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok());
+
+    let broadcaster = &app_state.event_broadcaster;
+    let subscription = broadcaster.subscribe(last_event_id);
+
+    let stream = catalog_changes(subscription, entity_types).map(|event| to_sse_event(&event));
+    let keep_alive = KeepAlive::new().interval(broadcaster.keep_alive_interval());
+    Ok(Sse::new(stream).keep_alive(keep_alive))
+}
+
+fn parse_entity_types(entity_types: Option<&str>) -> Result<Vec<AggregateType>, String> {
+    entity_types
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|it| !it.is_empty())
+        .map(|it| AggregateType::from_str(it).map_err(|_| format!("invalid entity type: {it}")))
+        .collect()
+}
+
+/// The stream of replayed events followed by the live ones, only for the given entity types (all of
+/// them when empty).
+///
+/// The stream ends when the client falls behind the broadcast channel, the client is then expected
+/// to reconnect with the `Last-Event-ID` header to receive the events it missed.
+fn catalog_changes(
+    subscription: Subscription,
+    entity_types: Vec<AggregateType>,
+) -> impl Stream<Item = Arc<StreamEvent>> {
+    let Subscription { replay, receiver } = subscription;
+
+    let live = stream::unfold(receiver, |mut receiver| async move {
+        match receiver.recv().await {
+            Ok(event) => Some((event, receiver)),
+            Err(RecvError::Lagged(skipped)) => {
+                tracing::warn!(skipped, "the event stream client is lagging behind, closing the stream");
+                None
+            }
+            Err(RecvError::Closed) => None,
+        }
+    });
+
+    stream::iter(replay).chain(live).filter(move |event| {
+        let aggregate_type = event.envelope.event.aggregate_type();
+        let included = entity_types.is_empty() || entity_types.contains(&aggregate_type);
+        async move { included }
+    })
+}
+
+fn to_sse_event(stream_event: &StreamEvent) -> Result<Event, axum::Error> {
+    let envelope = &stream_event.envelope;
+    let change = CatalogChange {
+        event_id: envelope.event_id,
+        entity_type: envelope.event.aggregate_type(),
+        entity_id: envelope.event.aggregate_id(),
+        change: envelope.event.change_kind(),
+        occurred_at: envelope.occurred_at,
+        event: &envelope.event,
+    };
+
+    Event::default()
+        .id(stream_event.sequence.to_string())
+        .event(envelope.event.event_type())
+        .json_data(change)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod catalog_changes_stream {
+        use super::*;
+        use crate::events::broadcaster::EventBroadcaster;
+        use catalog::brands::brand_id::BrandId;
+        use catalog::brands::brand_response::BrandCreated;
+        use catalog::events::domain_event::EventEnvelope;
+        use catalog::scales::scale_id::ScaleId;
+        use catalog::scales::scale_response::ScaleDeleted;
+        use configuration::EventStreamSettings;
+        use pretty_assertions::assert_eq;
+        use serde_json::json;
+
+        #[test]
+        fn it_should_parse_the_entity_types() {
+            assert_eq!(Ok(Vec::new()), parse_entity_types(None));
+            assert_eq!(Ok(Vec::new()), parse_entity_types(Some("")));
+            assert_eq!(
+                Ok(vec![AggregateType::Brand, AggregateType::CatalogItem]),
+                parse_entity_types(Some("brand, catalog_item"))
+            );
+            assert_eq!(
+                Err(String::from("invalid entity type: shop")),
+                parse_entity_types(Some("brand,shop"))
+            );
+        }
+
+        #[tokio::test]
+        async fn it_should_stream_the_replayed_events_before_the_live_ones() {
+            let broadcaster = EventBroadcaster::new(&EventStreamSettings::default());
+            broadcaster.broadcast(brand_created("ACME"));
+            broadcaster.broadcast(brand_created("Roco"));
+
+            let subscription = broadcaster.subscribe(Some(1));
+            broadcaster.broadcast(brand_created("Rivarossi"));
+            drop(broadcaster);
+
+            let sequences: Vec<u64> = catalog_changes(subscription, Vec::new())
+                .map(|it| it.sequence)
+                .collect()
+                .await;
+
+            assert_eq!(vec![2, 3], sequences);
+        }
+
+        #[tokio::test]
+        async fn it_should_filter_the_events_by_entity_type() {
+            let broadcaster = EventBroadcaster::new(&EventStreamSettings::default());
+            let subscription = broadcaster.subscribe(None);
+            broadcaster.broadcast(brand_created("ACME"));
+            broadcaster.broadcast(scale_deleted("H0"));
+            drop(broadcaster);
+
+            let event_types: Vec<&str> = catalog_changes(subscription, vec![AggregateType::Scale])
+                .map(|it| it.envelope.event.event_type())
+                .collect()
+                .await;
+
+            assert_eq!(vec!["ScaleDeleted"], event_types);
+        }
+
+        #[test]
+        fn it_should_serialize_the_catalog_changes() {
+            let envelope = brand_created("ACME");
+            let change = CatalogChange {
+                event_id: envelope.event_id,
+                entity_type: envelope.event.aggregate_type(),
+                entity_id: envelope.event.aggregate_id(),
+                change: envelope.event.change_kind(),
+                occurred_at: envelope.occurred_at,
+                event: &envelope.event,
+            };
+
+            let value = serde_json::to_value(&change).unwrap();
+
+            let expected = json!({
+                "event_id": envelope.event_id,
+                "entity_type": "brand",
+                "entity_id": "acme",
+                "change": "created",
+                "occurred_at": envelope.occurred_at,
+                "event_type": "BrandCreated",
+                "payload": {
+                    "brand_id": "acme",
+                    "created_at": envelope.occurred_at
+                }
+            });
+            assert_eq!(expected, value);
+        }
+
+        fn brand_created(name: &str) -> EventEnvelope {
+            EventEnvelope::new(DomainEvent::BrandCreated(BrandCreated {
+                brand_id: BrandId::new(name),
+                created_at: Utc::now(),
+            }))
+        }
+
+        fn scale_deleted(name: &str) -> EventEnvelope {
+            EventEnvelope::new(DomainEvent::ScaleDeleted(ScaleDeleted {
+                scale_id: ScaleId::new(name),
+                deleted_at: Utc::now(),
+            }))
+        }
+    }
+}
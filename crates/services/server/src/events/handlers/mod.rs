@@ -0,0 +1,3 @@
+pub(crate) mod get_events;
+
+pub use get_events::handle as get_events;
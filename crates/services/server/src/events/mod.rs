@@ -0,0 +1,5 @@
+//! the module includes the stream of catalog changes, pushed to the clients as server-sent events
+
+pub mod broadcaster;
+pub mod handlers;
+pub mod routes;
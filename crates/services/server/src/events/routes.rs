@@ -0,0 +1,10 @@
+use crate::events::handlers;
+use crate::state::AppState;
+use axum::routing::get;
+use axum::Router;
+
+pub const EVENTS_API: &str = "/api/events";
+
+pub fn events_router() -> Router<AppState> {
+    Router::new().route(EVENTS_API, get(handlers::get_events))
+}
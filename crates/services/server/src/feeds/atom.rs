@@ -0,0 +1,208 @@
+//! a minimal Atom 1.0 (RFC 4287) feed writer
+
+use crate::hateoas::links::Link;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::fmt::Write;
+
+/// The Atom feeds media type
+pub const ATOM_MEDIA_TYPE: &str = "application/atom+xml; charset=utf-8";
+
+/// It represents an Atom feed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Feed {
+    /// the permanent feed identifier
+    pub id: String,
+    /// the feed title
+    pub title: String,
+    /// the feed subtitle
+    pub subtitle: Option<String>,
+    /// the feed language
+    pub language: String,
+    /// the feed author
+    pub author: String,
+    /// the link to the feed itself
+    pub self_link: String,
+    /// the last time the feed was modified
+    pub updated: DateTime<Utc>,
+    /// the feed entries
+    pub entries: Vec<Entry>,
+}
+
+/// It represents an Atom feed entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// the permanent entry identifier
+    pub id: String,
+    /// the entry title
+    pub title: String,
+    /// the entry summary
+    pub summary: Option<String>,
+    /// the links to the resource described by the entry
+    pub links: Vec<Link>,
+    /// the entry categories
+    pub categories: Vec<String>,
+    /// the entry creation timestamp
+    pub published: DateTime<Utc>,
+    /// the last time the entry was modified
+    pub updated: DateTime<Utc>,
+}
+
+impl Feed {
+    /// Writes the feed as an Atom XML document
+    pub fn to_xml(&self) -> String {
+        let mut xml = String::new();
+        xml.push_str(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+        xml.push('\n');
+        let _ = writeln!(
+            xml,
+            r#"<feed xmlns="http://www.w3.org/2005/Atom" xml:lang="{}">"#,
+            escape(&self.language)
+        );
+        write_element(&mut xml, 1, "id", &self.id);
+        write_element(&mut xml, 1, "title", &self.title);
+        if let Some(subtitle) = &self.subtitle {
+            write_element(&mut xml, 1, "subtitle", subtitle);
+        }
+        write_element(&mut xml, 1, "updated", &timestamp(&self.updated));
+        let _ = writeln!(
+            xml,
+            r#"  <link rel="self" type="application/atom+xml" href="{}"/>"#,
+            escape(&self.self_link)
+        );
+        xml.push_str("  <author>\n");
+        write_element(&mut xml, 2, "name", &self.author);
+        xml.push_str("  </author>\n");
+
+        for entry in self.entries.iter() {
+            entry.write_xml(&mut xml);
+        }
+
+        xml.push_str("</feed>\n");
+        xml
+    }
+}
+
+impl Entry {
+    fn write_xml(&self, xml: &mut String) {
+        xml.push_str("  <entry>\n");
+        write_element(xml, 2, "id", &self.id);
+        write_element(xml, 2, "title", &self.title);
+        for link in self.links.iter() {
+            let _ = writeln!(
+                xml,
+                r#"    <link rel="{}" type="application/json" href="{}"/>"#,
+                link.rel.as_str(),
+                escape(link.href.as_str())
+            );
+        }
+        for category in self.categories.iter() {
+            let _ = writeln!(xml, r#"    <category term="{}"/>"#, escape(category));
+        }
+        write_element(xml, 2, "published", &timestamp(&self.published));
+        write_element(xml, 2, "updated", &timestamp(&self.updated));
+        if let Some(summary) = &self.summary {
+            write_element(xml, 2, "summary", summary);
+        }
+        xml.push_str("  </entry>\n");
+    }
+}
+
+impl IntoResponse for Feed {
+    fn into_response(self) -> Response {
+        ([(CONTENT_TYPE, ATOM_MEDIA_TYPE)], self.to_xml()).into_response()
+    }
+}
+
+fn write_element(xml: &mut String, indent: usize, name: &str, value: &str) {
+    let _ = writeln!(xml, "{}<{name}>{}</{name}>", "  ".repeat(indent), escape(value));
+}
+
+fn timestamp(value: &DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Escapes the XML special characters
+fn escape(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&apos;"),
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod atom_feeds {
+        use super::*;
+        use crate::hateoas::links::LinkRelation;
+        use chrono::TimeZone;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_escape_the_xml_special_characters() {
+            assert_eq!(
+                "Fish &amp; Chips &lt;b&gt; &quot;it&apos;s&quot;",
+                escape(r#"Fish & Chips <b> "it's""#)
+            );
+        }
+
+        #[test]
+        fn it_should_write_atom_feeds() {
+            let published = Utc.with_ymd_and_hms(2026, 10, 18, 10, 30, 0).unwrap();
+            let feed = Feed {
+                id: String::from("http://localhost/feeds/catalog-items.atom"),
+                title: String::from("Catalog items"),
+                subtitle: Some(String::from("brand: acme")),
+                language: String::from("en"),
+                author: String::from("trenako"),
+                self_link: String::from("http://localhost/feeds/catalog-items.atom?brand=acme&scale=h0"),
+                updated: published,
+                entries: vec![Entry {
+                    id: String::from("http://localhost/api/catalog-items/acme-60142"),
+                    title: String::from("ACME 60142"),
+                    summary: Some(String::from("Locomotiva <E.645>")),
+                    links: vec![
+                        Link::of("http://localhost/api/catalog-items/acme-60142", LinkRelation::SelfLink).unwrap(),
+                    ],
+                    categories: vec![String::from("ANNOUNCED")],
+                    published,
+                    updated: published,
+                }],
+            };
+
+            let expected = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom" xml:lang="en">
+  <id>http://localhost/feeds/catalog-items.atom</id>
+  <title>Catalog items</title>
+  <subtitle>brand: acme</subtitle>
+  <updated>2026-10-18T10:30:00Z</updated>
+  <link rel="self" type="application/atom+xml" href="http://localhost/feeds/catalog-items.atom?brand=acme&amp;scale=h0"/>
+  <author>
+    <name>trenako</name>
+  </author>
+  <entry>
+    <id>http://localhost/api/catalog-items/acme-60142</id>
+    <title>ACME 60142</title>
+    <link rel="self" type="application/json" href="http://localhost/api/catalog-items/acme-60142"/>
+    <category term="ANNOUNCED"/>
+    <published>2026-10-18T10:30:00Z</published>
+    <updated>2026-10-18T10:30:00Z</updated>
+    <summary>Locomotiva &lt;E.645&gt;</summary>
+  </entry>
+</feed>
+"#;
+            assert_eq!(expected, feed.to_xml());
+        }
+    }
+}
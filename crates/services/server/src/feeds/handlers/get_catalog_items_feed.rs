@@ -0,0 +1,297 @@
+use crate::catalog::catalog_items::routes::CATALOG_ITEMS_ROOT_API;
+use crate::feeds::atom::{Entry, Feed};
+use crate::feeds::routes::CATALOG_ITEMS_FEED;
+use crate::hateoas::links::LinkBuilder;
+use crate::openapi::responses::{BadRequest, InternalServerError};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::web::urls::base_url;
+use axum::extract::{OriginalUri, Query, State};
+use axum::http::header::ACCEPT_LANGUAGE;
+use axum::http::HeaderMap;
+use catalog::brands::brand_id::BrandId;
+use catalog::catalog_items::availability_status::AvailabilityStatus;
+use catalog::catalog_items::catalog_item::CatalogItem;
+use catalog::catalog_items::queries::find_latest_catalog_items::{
+    find_latest_catalog_items, LatestCatalogItemsCriteria,
+};
+use catalog::scales::scale_id::ScaleId;
+use chrono::Utc;
+use common::localized_text::Language;
+use data::catalog::catalog_item::repositories::CatalogItemsRepository;
+use serde::Deserialize;
+use std::str::FromStr;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+/// The catalog items feed query string
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CatalogItemsFeedQuery {
+    /// only the catalog items for this brand
+    pub brand: Option<String>,
+    /// only the catalog items in this scale
+    pub scale: Option<String>,
+    /// only the catalog items with this availability status
+    pub status: Option<String>,
+    /// the language for the descriptions (the `Accept-Language` header is used when missing)
+    pub lang: Option<String>,
+    /// the max number of entries
+    #[param(minimum = 1, maximum = 200, default = 50)]
+    pub limit: Option<i64>,
+}
+
+/// The Atom feed for the most recently created catalog items
+#[utoipa::path(
+    get,
+    path = "/feeds/catalog-items.atom",
+    tag = "feeds",
+    operation_id = "get-catalog-items-feed",
+    params(CatalogItemsFeedQuery, ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    responses(
+        (status = 200, description = "the catalog items feed", content_type = "application/atom+xml", body = String),
+        (status = 400, response = BadRequest),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_catalog_items_feed", skip(app_state, headers))]
+pub async fn handle(
+    Query(query): Query<CatalogItemsFeedQuery>,
+    OriginalUri(uri): OriginalUri,
+    headers: HeaderMap,
+    consistency: ReadConsistency,
+    State(app_state): State<AppState>,
+) -> Result<Feed, ProblemDetail> {
+    let request_id = Uuid::new_v4();
+    let bad_request = |detail: String| ProblemDetail::bad_request(request_id, &detail);
+
+    let availability_status = query
+        .status
+        .as_deref()
+        .map(|status| AvailabilityStatus::from_str(status).map_err(|_| format!("invalid status: {status}")))
+        .transpose()
+        .map_err(bad_request)?;
+    let language = requested_language(query.lang.as_deref(), &headers).map_err(bad_request)?;
+
+    let criteria = LatestCatalogItemsCriteria::new(
+        query.brand.as_deref().map(BrandId::new),
+        query.scale.as_deref().map(ScaleId::new),
+        availability_status,
+        query.limit,
+    );
+
+    let database = app_state.get_read_database(consistency);
+    let repo = CatalogItemsRepository;
+
+    let catalog_items = find_latest_catalog_items(&criteria, repo, database)
+        .await
+        .map_err(|why| why.to_problem_detail(request_id, Some(CATALOG_ITEMS_FEED)))?;
+
+    let base_url = base_url(app_state.public_url.as_deref(), &headers);
+    let self_link = format!("{base_url}{uri}");
+    Ok(to_feed(&criteria, language, &base_url, &self_link, catalog_items))
+}
+
+/// The feed language, from the `lang` query string parameter or the `Accept-Language` header
+fn requested_language(lang: Option<&str>, headers: &HeaderMap) -> Result<Language, String> {
+    if let Some(lang) = lang {
+        return Language::from_str(lang).map_err(|_| format!("invalid language: {lang}"));
+    }
+
+    let language = headers
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| {
+            value
+                .split(',')
+                .filter_map(|it| it.split(';').next())
+                .filter_map(|it| it.trim().split('-').next())
+                .find_map(|it| Language::from_str(&it.to_lowercase()).ok())
+        });
+    Ok(language.unwrap_or(Language::English))
+}
+
+fn to_feed(
+    criteria: &LatestCatalogItemsCriteria,
+    language: Language,
+    base_url: &str,
+    self_link: &str,
+    catalog_items: Vec<CatalogItem>,
+) -> Feed {
+    let link_builder = LinkBuilder::new(base_url);
+
+    let entries: Vec<Entry> = catalog_items
+        .into_iter()
+        .map(|catalog_item| {
+            let metadata = catalog_item.metadata();
+            let self_link = link_builder
+                .link_to(CATALOG_ITEMS_ROOT_API)
+                .slash(catalog_item.id().to_string())
+                .ok();
+            let id = self_link
+                .as_ref()
+                .map(|it| it.href.to_string())
+                .unwrap_or_else(|| format!("{base_url}{CATALOG_ITEMS_ROOT_API}/{}", catalog_item.id()));
+
+            let mut categories = vec![catalog_item.scale().display().to_string()];
+            if let Some(status) = catalog_item.availability_status() {
+                categories.push(status.to_string());
+            }
+
+            Entry {
+                id,
+                title: format!("{} {}", catalog_item.brand().display(), catalog_item.item_number()),
+                summary: catalog_item.description.label_or_english(language).cloned(),
+                links: self_link.into_iter().collect(),
+                categories,
+                published: *metadata.created(),
+                updated: *metadata.last_modified().unwrap_or(metadata.created()),
+            }
+        })
+        .collect();
+
+    let updated = entries.iter().map(|it| it.updated).max().unwrap_or_else(Utc::now);
+
+    Feed {
+        id: self_link.to_string(),
+        title: String::from("Catalog items"),
+        subtitle: subtitle(criteria),
+        language: language.to_string(),
+        author: String::from("trenako"),
+        self_link: self_link.to_string(),
+        updated,
+        entries,
+    }
+}
+
+fn subtitle(criteria: &LatestCatalogItemsCriteria) -> Option<String> {
+    let mut filters = Vec::new();
+    if let Some(brand_id) = &criteria.brand_id {
+        filters.push(format!("brand: {brand_id}"));
+    }
+    if let Some(scale_id) = &criteria.scale_id {
+        filters.push(format!("scale: {scale_id}"));
+    }
+    if let Some(status) = &criteria.availability_status {
+        filters.push(format!("status: {status}"));
+    }
+
+    if filters.is_empty() {
+        None
+    } else {
+        Some(filters.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod catalog_items_feed {
+        use super::*;
+        use axum::http::HeaderValue;
+        use catalog::catalog_items::catalog_item::{CatalogItemBrand, CatalogItemScale};
+        use catalog::catalog_items::catalog_item_id::CatalogItemId;
+        use catalog::catalog_items::category::Category;
+        use catalog::catalog_items::epoch::Epoch;
+        use catalog::catalog_items::item_number::ItemNumber;
+        use catalog::catalog_items::power_method::PowerMethod;
+        use chrono::TimeZone;
+        use common::metadata::Metadata;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_use_the_requested_language() {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                ACCEPT_LANGUAGE,
+                HeaderValue::from_static("es-ES, it-IT;q=0.8, en;q=0.5"),
+            );
+
+            assert_eq!(Ok(Language::German), requested_language(Some("de"), &headers));
+            assert_eq!(Ok(Language::Italian), requested_language(None, &headers));
+            assert_eq!(Ok(Language::English), requested_language(None, &HeaderMap::new()));
+            assert_eq!(
+                Err(String::from("invalid language: xx")),
+                requested_language(Some("xx"), &headers)
+            );
+        }
+
+        #[test]
+        fn it_should_create_the_catalog_items_feed() {
+            let catalog_item = new_catalog_item();
+            let criteria = LatestCatalogItemsCriteria::new(
+                Some(BrandId::new("ACME")),
+                None,
+                Some(AvailabilityStatus::Announced),
+                None,
+            );
+            let self_link = "http://localhost:5000/feeds/catalog-items.atom?brand=acme&status=ANNOUNCED";
+
+            let feed = to_feed(
+                &criteria,
+                Language::Italian,
+                "http://localhost:5000",
+                self_link,
+                vec![catalog_item.clone()],
+            );
+
+            assert_eq!(self_link, feed.id);
+            assert_eq!("it", feed.language);
+            assert_eq!(Some(String::from("brand: acme, status: ANNOUNCED")), feed.subtitle);
+            assert_eq!(1, feed.entries.len());
+
+            let entry = &feed.entries[0];
+            let item_link = format!("http://localhost:5000/api/catalog-items/{}", catalog_item.id());
+            assert_eq!(item_link, entry.id);
+            assert_eq!("ACME 60142", entry.title);
+            assert_eq!(1, entry.links.len());
+            assert_eq!(item_link, entry.links[0].href.to_string());
+            assert_eq!(Some(String::from("Locomotiva elettrica E.645")), entry.summary);
+            assert_eq!(
+                vec![String::from("H0 (1:87)"), String::from("ANNOUNCED")],
+                entry.categories
+            );
+            assert_eq!(*catalog_item.metadata().created(), entry.published);
+            assert_eq!(*catalog_item.metadata().created(), entry.updated);
+            assert_eq!(entry.updated, feed.updated);
+        }
+
+        #[test]
+        fn it_should_create_empty_feeds() {
+            let feed = to_feed(
+                &LatestCatalogItemsCriteria::default(),
+                Language::English,
+                "http://localhost:5000",
+                "http://localhost:5000/feeds/catalog-items.atom",
+                Vec::new(),
+            );
+
+            assert_eq!(None, feed.subtitle);
+            assert!(feed.entries.is_empty());
+        }
+
+        fn new_catalog_item() -> CatalogItem {
+            let brand = CatalogItemBrand::new(BrandId::new("ACME"), "ACME");
+            let item_number = ItemNumber::new("60142");
+            CatalogItem::new(
+                CatalogItemId::of(&BrandId::new("ACME"), &item_number),
+                brand,
+                item_number,
+                Category::Locomotives,
+                CatalogItemScale::new(ScaleId::new("H0"), "H0 (1:87)"),
+                Some("Locomotiva elettrica E.645"),
+                None,
+                Vec::new(),
+                PowerMethod::DC,
+                Epoch::IV,
+                None,
+                Some(AvailabilityStatus::Announced),
+                1,
+                Metadata::created_at(Utc.with_ymd_and_hms(2026, 10, 18, 10, 30, 0).unwrap()),
+            )
+        }
+    }
+}
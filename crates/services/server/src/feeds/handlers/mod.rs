@@ -0,0 +1,3 @@
+pub(crate) mod get_catalog_items_feed;
+
+pub use get_catalog_items_feed::handle as get_catalog_items_feed;
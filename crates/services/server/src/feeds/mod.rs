@@ -0,0 +1,5 @@
+//! the module includes the Atom feeds for the catalog
+
+pub mod atom;
+pub mod handlers;
+pub mod routes;
@@ -0,0 +1,10 @@
+use crate::feeds::handlers;
+use crate::state::AppState;
+use axum::routing::get;
+use axum::Router;
+
+pub const CATALOG_ITEMS_FEED: &str = "/feeds/catalog-items.atom";
+
+pub fn feeds_router() -> Router<AppState> {
+    Router::new().route(CATALOG_ITEMS_FEED, get(handlers::get_catalog_items_feed))
+}
@@ -0,0 +1,18 @@
+use crate::graphql::routes::GRAPHQL_API;
+use async_graphql::http::GraphiQLSource;
+use axum::response::Html;
+
+/// The GraphiQL ide, to explore the GraphQL schema
+#[utoipa::path(
+    get,
+    path = "/graphql",
+    tag = "graphql",
+    operation_id = "get-graphiql",
+    responses(
+        (status = 200, description = "the GraphiQL page", content_type = "text/html", body = String),
+    )
+)]
+#[tracing::instrument(name = "get_graphiql")]
+pub async fn handle() -> Html<String> {
+    Html(GraphiQLSource::build().endpoint(GRAPHQL_API).finish())
+}
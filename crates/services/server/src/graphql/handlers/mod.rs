@@ -0,0 +1,5 @@
+pub(crate) mod get_graphiql;
+pub(crate) mod post_graphql;
+
+pub use get_graphiql::handle as get_graphiql;
+pub use post_graphql::handle as post_graphql;
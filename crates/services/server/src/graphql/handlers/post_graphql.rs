@@ -0,0 +1,87 @@
+use crate::graphql::loaders::Loaders;
+use crate::graphql::schema::CatalogSchema;
+use crate::replica::consistency::{ReadConsistency, ReadOnly};
+use crate::state::AppState;
+use crate::web::admin::AdminAccess;
+use async_graphql::parser::types::OperationType;
+use axum::extract::State;
+use axum::response::{IntoResponse, Response};
+use axum::{Extension, Json};
+
+/// Executes a GraphQL query (or mutation) over the catalog
+#[utoipa::path(
+    post,
+    path = "/graphql",
+    tag = "graphql",
+    operation_id = "post-graphql",
+    security((), ("admin_api_key" = [])),
+    params(("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to run the queries against the primary (read-your-writes)")),
+    request_body(content = Object, description = "the GraphQL request, with the query and its variables"),
+    responses(
+        (status = 200, description = "the GraphQL response, the errors are included in the `errors` field", body = Object),
+    )
+)]
+#[tracing::instrument(name = "post_graphql", skip(schema, app_state, request))]
+pub async fn handle(
+    Extension(schema): Extension<CatalogSchema>,
+    consistency: ReadConsistency,
+    admin: Option<AdminAccess>,
+    State(app_state): State<AppState>,
+    Json(request): Json<async_graphql::Request>,
+) -> Response {
+    // the queries following a mutation are reading its changes
+    let is_mutation = is_mutation(&request);
+    let consistency = if is_mutation {
+        ReadConsistency::Strong
+    } else {
+        consistency
+    };
+
+    let loaders = Loaders::new(app_state.read_pool(consistency), &app_state.reference_data);
+    let request = request
+        .data(loaders.brands)
+        .data(loaders.railways)
+        .data(loaders.scales)
+        .data(loaders.rolling_stocks)
+        .data(consistency)
+        .data(admin)
+        .data(app_state);
+
+    let response = Json(schema.execute(request).await);
+    if is_mutation {
+        response.into_response()
+    } else {
+        (Extension(ReadOnly), response).into_response()
+    }
+}
+
+fn is_mutation(request: &async_graphql::Request) -> bool {
+    async_graphql::parser::parse_query(&request.query)
+        .map(|document| {
+            document
+                .operations
+                .iter()
+                .any(|(_, operation)| operation.node.ty == OperationType::Mutation)
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("{ brands { brandId } }", false)]
+    #[case("query Brands { brands { brandId } }", false)]
+    #[case("mutation { createBrand(request: {}) }", true)]
+    #[case(
+        "query Brands { brands { brandId } } mutation Create { createBrand(request: {}) }",
+        true
+    )]
+    #[case("not a graphql query", false)]
+    fn it_should_find_the_mutations(#[case] query: &str, #[case] expected: bool) {
+        let request = async_graphql::Request::new(query);
+        assert_eq!(expected, is_mutation(&request));
+    }
+}
@@ -0,0 +1,154 @@
+//! the data loaders, to resolve the nested values in batches
+
+use async_graphql::dataloader::{DataLoader, Loader};
+use catalog::brands::brand::Brand;
+use catalog::brands::brand_id::BrandId;
+use catalog::brands::queries::find_brands_by_ids::find_brands_by_ids;
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::catalog_items::queries::find_rolling_stocks_by_catalog_item_ids::find_rolling_stocks_by_catalog_item_ids;
+use catalog::catalog_items::rolling_stock::RollingStock;
+use catalog::railways::queries::find_railways_by_ids::find_railways_by_ids;
+use catalog::railways::railway::Railway;
+use catalog::railways::railway_id::RailwayId;
+use catalog::scales::queries::find_scales_by_ids::find_scales_by_ids;
+use catalog::scales::scale::Scale;
+use catalog::scales::scale_id::ScaleId;
+use common::queries::errors::QueryError;
+use common::unit_of_work::postgres::PgDatabase;
+use data::cache::reference_data::ReferenceDataCache;
+use data::cache::repositories::CachedRepository;
+use data::catalog::brands::repositories::BrandsRepository;
+use data::catalog::catalog_item::repositories::CatalogItemsRepository;
+use data::catalog::railways::repositories::RailwaysRepository;
+use data::catalog::scales::repositories::ScalesRepository;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The loaders errors, they need to be cloned to be shared by all the resolvers in the same batch
+pub type LoaderError = Arc<QueryError>;
+
+/// It loads the brands by id
+pub struct BrandLoader {
+    pg_pool: Arc<PgPool>,
+    reference_data: ReferenceDataCache,
+}
+
+impl Loader<BrandId> for BrandLoader {
+    type Value = Brand;
+    type Error = LoaderError;
+
+    async fn load(&self, keys: &[BrandId]) -> Result<HashMap<BrandId, Self::Value>, Self::Error> {
+        let database = PgDatabase::new(&self.pg_pool);
+        let brands = find_brands_by_ids(
+            keys,
+            CachedRepository::new(BrandsRepository, &self.reference_data),
+            database,
+        )
+        .await?;
+        Ok(brands.into_iter().map(|it| (it.brand_id.clone(), it)).collect())
+    }
+}
+
+/// It loads the railways by id
+pub struct RailwayLoader {
+    pg_pool: Arc<PgPool>,
+    reference_data: ReferenceDataCache,
+}
+
+impl Loader<RailwayId> for RailwayLoader {
+    type Value = Railway;
+    type Error = LoaderError;
+
+    async fn load(&self, keys: &[RailwayId]) -> Result<HashMap<RailwayId, Self::Value>, Self::Error> {
+        let database = PgDatabase::new(&self.pg_pool);
+        let railways = find_railways_by_ids(
+            keys,
+            CachedRepository::new(RailwaysRepository, &self.reference_data),
+            database,
+        )
+        .await?;
+        Ok(railways.into_iter().map(|it| (it.railway_id.clone(), it)).collect())
+    }
+}
+
+/// It loads the modelling scales by id
+pub struct ScaleLoader {
+    pg_pool: Arc<PgPool>,
+    reference_data: ReferenceDataCache,
+}
+
+impl Loader<ScaleId> for ScaleLoader {
+    type Value = Scale;
+    type Error = LoaderError;
+
+    async fn load(&self, keys: &[ScaleId]) -> Result<HashMap<ScaleId, Self::Value>, Self::Error> {
+        let database = PgDatabase::new(&self.pg_pool);
+        let scales = find_scales_by_ids(
+            keys,
+            CachedRepository::new(ScalesRepository, &self.reference_data),
+            database,
+        )
+        .await?;
+        Ok(scales.into_iter().map(|it| (it.scale_id.clone(), it)).collect())
+    }
+}
+
+/// It loads the rolling stocks by catalog item id
+pub struct RollingStocksLoader {
+    pg_pool: Arc<PgPool>,
+}
+
+impl Loader<CatalogItemId> for RollingStocksLoader {
+    type Value = Vec<RollingStock>;
+    type Error = LoaderError;
+
+    async fn load(&self, keys: &[CatalogItemId]) -> Result<HashMap<CatalogItemId, Self::Value>, Self::Error> {
+        let database = PgDatabase::new(&self.pg_pool);
+        let rolling_stocks = find_rolling_stocks_by_catalog_item_ids(keys, CatalogItemsRepository, database).await?;
+        Ok(rolling_stocks)
+    }
+}
+
+/// The data loaders for a single GraphQL request
+pub struct Loaders {
+    pub brands: DataLoader<BrandLoader>,
+    pub railways: DataLoader<RailwayLoader>,
+    pub scales: DataLoader<ScaleLoader>,
+    pub rolling_stocks: DataLoader<RollingStocksLoader>,
+}
+
+impl Loaders {
+    /// Creates the data loaders, they cache the loaded values for the request lifetime only
+    pub fn new(pg_pool: &Arc<PgPool>, reference_data: &ReferenceDataCache) -> Self {
+        Loaders {
+            brands: DataLoader::new(
+                BrandLoader {
+                    pg_pool: pg_pool.clone(),
+                    reference_data: reference_data.clone(),
+                },
+                tokio::spawn,
+            ),
+            railways: DataLoader::new(
+                RailwayLoader {
+                    pg_pool: pg_pool.clone(),
+                    reference_data: reference_data.clone(),
+                },
+                tokio::spawn,
+            ),
+            scales: DataLoader::new(
+                ScaleLoader {
+                    pg_pool: pg_pool.clone(),
+                    reference_data: reference_data.clone(),
+                },
+                tokio::spawn,
+            ),
+            rolling_stocks: DataLoader::new(
+                RollingStocksLoader {
+                    pg_pool: pg_pool.clone(),
+                },
+                tokio::spawn,
+            ),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! the module includes the GraphQL endpoint, over the same catalog queries and commands of the REST api
+
+pub mod handlers;
+pub mod loaders;
+pub mod routes;
+pub mod schema;
+pub mod types;
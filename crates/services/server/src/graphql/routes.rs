@@ -0,0 +1,13 @@
+use crate::graphql::handlers;
+use crate::graphql::schema::build_schema;
+use crate::state::AppState;
+use axum::routing::get;
+use axum::{Extension, Router};
+
+pub const GRAPHQL_API: &str = "/graphql";
+
+pub fn graphql_router() -> Router<AppState> {
+    Router::new()
+        .route(GRAPHQL_API, get(handlers::get_graphiql).post(handlers::post_graphql))
+        .layer(Extension(build_schema()))
+}
@@ -0,0 +1,452 @@
+//! the GraphQL schema, with the queries and mutations over the catalog
+
+use crate::graphql::types::{BrandObject, CatalogItemObject, RailwayObject, ScaleObject};
+use crate::metrics::database::MeteredDatabase;
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::admin::{admin_required, AdminAccess};
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use async_graphql::{Context, EmptySubscription, ErrorExtensions, Json, Object, Result, Schema};
+use catalog::brands::brand_id::BrandId;
+use catalog::brands::brand_request::BrandRequest;
+use catalog::brands::commands::new_brand::create_new_brand;
+use catalog::brands::queries::find_all_brands::find_all_brands;
+use catalog::brands::queries::find_brand_by_id::find_brand_by_id;
+use catalog::catalog_items::availability_status::AvailabilityStatus;
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::catalog_items::catalog_item_request::CatalogItemRequest;
+use catalog::catalog_items::commands::new_catalog_item::create_new_catalog_item;
+use catalog::catalog_items::epoch::Epoch;
+use catalog::catalog_items::queries::find_catalog_item_by_id::find_catalog_item_by_id;
+use catalog::catalog_items::queries::find_latest_catalog_items::{
+    find_latest_catalog_items, LatestCatalogItemsCriteria,
+};
+use catalog::railways::commands::new_railways::create_new_railway;
+use catalog::railways::queries::find_all_railways::find_all_railways;
+use catalog::railways::queries::find_railway_by_id::find_railway_by_id;
+use catalog::railways::railway_id::RailwayId;
+use catalog::railways::railway_request::RailwayRequest;
+use catalog::scales::commands::new_scales::create_new_scale;
+use catalog::scales::queries::find_all_scales::find_all_scales;
+use catalog::scales::queries::find_scale_by_id::find_scale_by_id;
+use catalog::scales::scale_id::ScaleId;
+use catalog::scales::scale_request::ScaleRequest;
+use common::queries::errors::QueryError;
+use common::queries::pagination::PageRequest;
+use data::cache::repositories::CachedRepository;
+use data::catalog::brands::repositories::BrandsRepository;
+use data::catalog::catalog_item::repositories::{CatalogItemsRepository, RollingStocksRepository};
+use data::catalog::railways::repositories::RailwaysRepository;
+use data::catalog::scales::repositories::ScalesRepository;
+use data::events::repositories::OutboxEventsRepository;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// The catalog GraphQL schema
+pub type CatalogSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
+
+/// Builds the catalog GraphQL schema
+pub fn build_schema() -> CatalogSchema {
+    Schema::build(QueryRoot, MutationRoot, EmptySubscription).finish()
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// the model railways manufacturers
+    async fn brands(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = false)] include_deleted: bool,
+        offset: Option<usize>,
+        limit: Option<usize>,
+    ) -> Result<Vec<BrandObject>> {
+        require_admin_for_deleted(ctx, include_deleted)?;
+        let brands = find_all_brands(include_deleted, cached(ctx, BrandsRepository)?, read_database(ctx)?)
+            .await
+            .map_err(to_graphql_error)?;
+        Ok(paginate(brands, PageRequest { offset, limit })
+            .into_iter()
+            .map(BrandObject)
+            .collect())
+    }
+
+    /// the model railways manufacturer with the given id
+    async fn brand(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+        #[graphql(default = false)] include_deleted: bool,
+    ) -> Result<Option<BrandObject>> {
+        require_admin_for_deleted(ctx, include_deleted)?;
+        let brand_id = BrandId::new(&id);
+        let brand = find_brand_by_id(
+            &brand_id,
+            include_deleted,
+            cached(ctx, BrandsRepository)?,
+            read_database(ctx)?,
+        )
+        .await;
+        not_found_as_none(brand).map(|it| it.map(BrandObject))
+    }
+
+    /// the railway companies
+    async fn railways(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = false)] include_deleted: bool,
+        offset: Option<usize>,
+        limit: Option<usize>,
+    ) -> Result<Vec<RailwayObject>> {
+        require_admin_for_deleted(ctx, include_deleted)?;
+        let railways = find_all_railways(include_deleted, cached(ctx, RailwaysRepository)?, read_database(ctx)?)
+            .await
+            .map_err(to_graphql_error)?;
+        Ok(paginate(railways, PageRequest { offset, limit })
+            .into_iter()
+            .map(RailwayObject)
+            .collect())
+    }
+
+    /// the railway company with the given id
+    async fn railway(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+        #[graphql(default = false)] include_deleted: bool,
+    ) -> Result<Option<RailwayObject>> {
+        require_admin_for_deleted(ctx, include_deleted)?;
+        let railway_id = RailwayId::new(&id);
+        let railway = find_railway_by_id(
+            &railway_id,
+            include_deleted,
+            cached(ctx, RailwaysRepository)?,
+            read_database(ctx)?,
+        )
+        .await;
+        not_found_as_none(railway).map(|it| it.map(RailwayObject))
+    }
+
+    /// the modelling scales
+    async fn scales(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = false)] include_deleted: bool,
+        offset: Option<usize>,
+        limit: Option<usize>,
+    ) -> Result<Vec<ScaleObject>> {
+        require_admin_for_deleted(ctx, include_deleted)?;
+        let scales = find_all_scales(include_deleted, cached(ctx, ScalesRepository)?, read_database(ctx)?)
+            .await
+            .map_err(to_graphql_error)?;
+        Ok(paginate(scales, PageRequest { offset, limit })
+            .into_iter()
+            .map(ScaleObject)
+            .collect())
+    }
+
+    /// the modelling scale with the given id
+    async fn scale(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+        #[graphql(default = false)] include_deleted: bool,
+    ) -> Result<Option<ScaleObject>> {
+        require_admin_for_deleted(ctx, include_deleted)?;
+        let scale_id = ScaleId::new(&id);
+        let scale = find_scale_by_id(
+            &scale_id,
+            include_deleted,
+            cached(ctx, ScalesRepository)?,
+            read_database(ctx)?,
+        )
+        .await;
+        not_found_as_none(scale).map(|it| it.map(ScaleObject))
+    }
+
+    /// the catalog item with the given id
+    async fn catalog_item(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+        #[graphql(default = false)] include_deleted: bool,
+    ) -> Result<Option<CatalogItemObject>> {
+        require_admin_for_deleted(ctx, include_deleted)?;
+        let catalog_item_id = CatalogItemId::from_str(&id).map_err(|_| bad_request(&format!("invalid id: {id}")))?;
+        let catalog_item = find_catalog_item_by_id(
+            &catalog_item_id,
+            include_deleted,
+            CatalogItemsRepository,
+            read_database(ctx)?,
+        )
+        .await;
+        not_found_as_none(catalog_item).map(|it| it.map(CatalogItemObject))
+    }
+
+    /// the most recently created catalog items, the newest first
+    async fn catalog_items(
+        &self,
+        ctx: &Context<'_>,
+        brand: Option<String>,
+        scale: Option<String>,
+        status: Option<String>,
+        epoch: Option<String>,
+        railway: Option<String>,
+        year: Option<i32>,
+        limit: Option<i64>,
+    ) -> Result<Vec<CatalogItemObject>> {
+        let availability_status = status
+            .as_deref()
+            .map(|status| {
+                AvailabilityStatus::from_str(status).map_err(|_| bad_request(&format!("invalid status: {status}")))
+            })
+            .transpose()?;
+        let epoch = epoch
+            .as_deref()
+            .map(|epoch| Epoch::from_str(epoch).map_err(|_| bad_request(&format!("invalid epoch: {epoch}"))))
+            .transpose()?;
+
+        let criteria = LatestCatalogItemsCriteria::new(
+            brand.as_deref().map(BrandId::new),
+            scale.as_deref().map(ScaleId::new),
+            availability_status,
+            limit,
+        )
+        .with_epoch(epoch)
+        .with_railway(railway.as_deref().map(RailwayId::new))
+        .valid_in(year);
+
+        let catalog_items = find_latest_catalog_items(&criteria, CatalogItemsRepository, read_database(ctx)?)
+            .await
+            .map_err(to_graphql_error)?;
+        Ok(catalog_items.into_iter().map(CatalogItemObject).collect())
+    }
+}
+
+pub struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    /// creates a new brand, it returns the new brand id
+    async fn create_brand(&self, ctx: &Context<'_>, request: Json<BrandRequest>) -> Result<String> {
+        let app_state = ctx.data::<AppState>()?;
+        let created = create_new_brand(
+            request.0,
+            app_state.cached(BrandsRepository),
+            OutboxEventsRepository,
+            app_state.get_database(),
+        )
+        .await
+        .map_err(to_graphql_error)?;
+        Ok(created.brand_id.to_string())
+    }
+
+    /// creates a new railway, it returns the new railway id
+    async fn create_railway(&self, ctx: &Context<'_>, request: Json<RailwayRequest>) -> Result<String> {
+        let app_state = ctx.data::<AppState>()?;
+        let created = create_new_railway(
+            request.0,
+            app_state.cached(RailwaysRepository),
+            OutboxEventsRepository,
+            app_state.get_database(),
+        )
+        .await
+        .map_err(to_graphql_error)?;
+        Ok(created.railway_id.to_string())
+    }
+
+    /// creates a new modelling scale, it returns the new scale id
+    async fn create_scale(&self, ctx: &Context<'_>, request: Json<ScaleRequest>) -> Result<String> {
+        let app_state = ctx.data::<AppState>()?;
+        let created = create_new_scale(
+            request.0,
+            app_state.cached(ScalesRepository),
+            OutboxEventsRepository,
+            app_state.get_database(),
+        )
+        .await
+        .map_err(to_graphql_error)?;
+        Ok(created.scale_id.to_string())
+    }
+
+    /// creates a new catalog item, it returns the new catalog item id
+    async fn create_catalog_item(&self, ctx: &Context<'_>, request: Json<CatalogItemRequest>) -> Result<String> {
+        let app_state = ctx.data::<AppState>()?;
+        let created = create_new_catalog_item(
+            request.0,
+            app_state.cached(CatalogItemsRepository),
+            app_state.cached(RollingStocksRepository),
+            app_state.cached(RailwaysRepository),
+            OutboxEventsRepository,
+            app_state.get_database(),
+        )
+        .await
+        .inspect(|_| app_state.metrics.catalog_item_created())
+        .inspect_err(|why| app_state.metrics.catalog_item_rejected(why))
+        .map_err(to_graphql_error)?;
+        Ok(created.catalog_item_id.to_string())
+    }
+}
+
+/// The database for the queries, using the read consistency requested by the client
+fn read_database<'ctx>(ctx: &Context<'ctx>) -> Result<MeteredDatabase<'ctx>> {
+    let app_state = ctx.data::<AppState>()?;
+    let consistency = ctx.data_opt::<ReadConsistency>().copied().unwrap_or_default();
+    Ok(app_state.get_read_database(consistency))
+}
+
+/// The deleted resources are visible only to the admins
+fn require_admin_for_deleted(ctx: &Context<'_>, include_deleted: bool) -> Result<()> {
+    let admin = ctx.data_opt::<Option<AdminAccess>>().copied().flatten();
+    if include_deleted && admin.is_none() {
+        Err(problem_detail_to_error(admin_required(
+            "admin credentials are required to include the deleted resources",
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Wraps the repository with the reference data cache
+fn cached<R>(ctx: &Context<'_>, repo: R) -> Result<CachedRepository<R>> {
+    Ok(ctx.data::<AppState>()?.cached(repo))
+}
+
+/// Applies the page request to the query results
+pub fn paginate<T>(items: Vec<T>, page_request: PageRequest) -> Vec<T> {
+    let default = PageRequest::default();
+    let offset = page_request.offset.or(default.offset).unwrap_or_default();
+    let limit = page_request.limit.or(default.limit).unwrap_or(usize::MAX);
+    items.into_iter().skip(offset).take(limit).collect()
+}
+
+/// The resources not found (or deleted) are returned as `null`, like GraphQL clients expect
+fn not_found_as_none<T>(result: std::result::Result<T, QueryError>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(QueryError::EmptyResultSet) | Err(QueryError::ResourceDeleted) => Ok(None),
+        Err(why) => Err(to_graphql_error(why)),
+    }
+}
+
+/// Converts an error to a GraphQL error, using the same status and title of the REST problem details
+pub fn to_graphql_error<E: ToProblemDetail>(error: E) -> async_graphql::Error {
+    problem_detail_to_error(error.to_problem_detail(Uuid::new_v4(), None))
+}
+
+fn bad_request(detail: &str) -> async_graphql::Error {
+    problem_detail_to_error(ProblemDetail::bad_request(Uuid::new_v4(), detail))
+}
+
+fn problem_detail_to_error(problem_detail: ProblemDetail) -> async_graphql::Error {
+    let message = if problem_detail.detail.is_empty() {
+        problem_detail.title.clone()
+    } else {
+        problem_detail.detail.clone()
+    };
+    async_graphql::Error::new(message).extend_with(|_, extensions| {
+        extensions.set("status", problem_detail.status);
+        extensions.set("title", problem_detail.title.as_str());
+        extensions.set("instance", problem_detail.instance.to_string());
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod pagination {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_use_the_default_page_request_when_missing() {
+            let items: Vec<usize> = (0..30).collect();
+            let page = paginate(
+                items,
+                PageRequest {
+                    offset: None,
+                    limit: None,
+                },
+            );
+            assert_eq!((0..25).collect::<Vec<usize>>(), page);
+        }
+
+        #[test]
+        fn it_should_skip_and_take_the_requested_items() {
+            let items: Vec<usize> = (0..30).collect();
+            let page = paginate(
+                items,
+                PageRequest {
+                    offset: Some(10),
+                    limit: Some(5),
+                },
+            );
+            assert_eq!(vec![10, 11, 12, 13, 14], page);
+        }
+
+        #[test]
+        fn it_should_return_an_empty_page_after_the_last_item() {
+            let items: Vec<usize> = (0..3).collect();
+            let page = paginate(
+                items,
+                PageRequest {
+                    offset: Some(5),
+                    limit: None,
+                },
+            );
+            assert!(page.is_empty());
+        }
+    }
+
+    mod errors {
+        use super::*;
+        use anyhow::anyhow;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_return_none_for_missing_resources() {
+            let result = not_found_as_none::<u8>(Err(QueryError::EmptyResultSet));
+            assert_eq!(None, result.unwrap());
+
+            let result = not_found_as_none::<u8>(Err(QueryError::ResourceDeleted));
+            assert_eq!(None, result.unwrap());
+        }
+
+        #[test]
+        fn it_should_convert_query_errors_to_graphql_errors() {
+            let result = not_found_as_none::<u8>(Err(QueryError::UnexpectedError(anyhow!("Something bad"))));
+
+            let error = result.unwrap_err();
+            assert_eq!("Something bad", error.message);
+
+            let extensions = serde_json::to_value(error.extensions).unwrap();
+            assert_eq!(500, extensions["status"]);
+            assert_eq!("Error: Internal Server Error", extensions["title"]);
+        }
+
+        #[test]
+        fn it_should_use_the_title_when_the_problem_detail_is_missing() {
+            let error = problem_detail_to_error(ProblemDetail::bad_request(Uuid::new_v4(), ""));
+            assert_eq!("Bad request", error.message);
+        }
+    }
+
+    mod schema {
+        use super::*;
+
+        #[test]
+        fn it_should_include_the_catalog_types() {
+            let sdl = build_schema().sdl();
+            for type_name in ["Brand", "CatalogItem", "Railway", "RollingStock", "Scale"] {
+                assert!(
+                    sdl.contains(&format!("type {type_name} {{")),
+                    "missing type {type_name}"
+                );
+            }
+            assert!(sdl.contains("createCatalogItem(request: JSON!): String!"));
+        }
+    }
+}
@@ -0,0 +1,374 @@
+//! the GraphQL object types, wrapping the catalog view models
+
+use crate::graphql::loaders::{BrandLoader, RailwayLoader, RollingStocksLoader, ScaleLoader};
+use async_graphql::dataloader::DataLoader;
+use async_graphql::{Context, Json, Object, Result, SimpleObject};
+use catalog::brands::brand::Brand;
+use catalog::catalog_items::catalog_item::CatalogItem;
+use catalog::catalog_items::rolling_stock::RollingStock;
+use catalog::railways::railway::Railway;
+use catalog::scales::scale::Scale;
+use chrono::{DateTime, Utc};
+use common::localized_text::LocalizedText;
+use common::metadata::Metadata;
+
+/// A text with its translations
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(name = "LocalizedText")]
+pub struct LocalizedTextObject {
+    /// the German text
+    pub de: Option<String>,
+    /// the English text
+    pub en: Option<String>,
+    /// the French text
+    pub fr: Option<String>,
+    /// the Italian text
+    pub it: Option<String>,
+}
+
+impl From<&LocalizedText> for LocalizedTextObject {
+    fn from(value: &LocalizedText) -> Self {
+        LocalizedTextObject {
+            de: value.german().cloned(),
+            en: value.english().cloned(),
+            fr: value.french().cloned(),
+            it: value.italian().cloned(),
+        }
+    }
+}
+
+/// The resource metadata
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(name = "Metadata")]
+pub struct MetadataObject {
+    /// the resource version
+    pub version: u8,
+    /// the creation timestamp
+    pub created: DateTime<Utc>,
+    /// the last modification timestamp
+    pub last_modified: Option<DateTime<Utc>>,
+    /// the (soft) deletion timestamp
+    pub deleted: Option<DateTime<Utc>>,
+}
+
+impl From<&Metadata> for MetadataObject {
+    fn from(value: &Metadata) -> Self {
+        MetadataObject {
+            version: value.version(),
+            created: *value.created(),
+            last_modified: value.last_modified().cloned(),
+            deleted: value.deleted().cloned(),
+        }
+    }
+}
+
+/// A model railways manufacturer
+pub struct BrandObject(pub Brand);
+
+#[Object(name = "Brand")]
+impl BrandObject {
+    async fn id(&self) -> &str {
+        &self.0.brand_id
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    async fn registered_company_name(&self) -> Option<&str> {
+        self.0.registered_company_name.as_deref()
+    }
+
+    async fn organization_entity_type(&self) -> Option<String> {
+        self.0.organization_entity_type.map(|it| it.to_string())
+    }
+
+    async fn group_name(&self) -> Option<&str> {
+        self.0.group_name.as_deref()
+    }
+
+    async fn description(&self) -> LocalizedTextObject {
+        LocalizedTextObject::from(&self.0.description)
+    }
+
+    async fn address(&self) -> Option<Json<serde_json::Value>> {
+        to_json(&self.0.address)
+    }
+
+    async fn contact_info(&self) -> Option<Json<serde_json::Value>> {
+        to_json(&self.0.contact_info)
+    }
+
+    async fn kind(&self) -> String {
+        self.0.kind.to_string()
+    }
+
+    async fn status(&self) -> Option<String> {
+        self.0.status.map(|it| it.to_string())
+    }
+
+    async fn socials(&self) -> Option<Json<serde_json::Value>> {
+        to_json(&self.0.socials)
+    }
+
+    async fn metadata(&self) -> MetadataObject {
+        MetadataObject::from(&self.0.metadata)
+    }
+}
+
+/// A railway company
+pub struct RailwayObject(pub Railway);
+
+#[Object(name = "Railway")]
+impl RailwayObject {
+    async fn id(&self) -> &str {
+        &self.0.railway_id
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    async fn abbreviation(&self) -> Option<&str> {
+        self.0.abbreviation.as_deref()
+    }
+
+    async fn registered_company_name(&self) -> Option<&str> {
+        self.0.registered_company_name.as_deref()
+    }
+
+    async fn organization_entity_type(&self) -> Option<String> {
+        self.0.organization_entity_type.map(|it| it.to_string())
+    }
+
+    async fn description(&self) -> LocalizedTextObject {
+        LocalizedTextObject::from(&self.0.description)
+    }
+
+    async fn country(&self) -> String {
+        self.0.country.alpha2().to_string()
+    }
+
+    async fn uic_country_code(&self) -> Option<u8> {
+        self.0.uic_country_code
+    }
+
+    async fn vehicle_keeper_markings(&self) -> Option<Json<serde_json::Value>> {
+        to_json(&self.0.vehicle_keeper_markings)
+    }
+
+    async fn period_of_activity(&self) -> Option<Json<serde_json::Value>> {
+        to_json(&self.0.period_of_activity)
+    }
+
+    async fn gauge(&self) -> Option<Json<serde_json::Value>> {
+        to_json(&self.0.gauge)
+    }
+
+    async fn headquarters(&self) -> &Vec<String> {
+        &self.0.headquarters
+    }
+
+    async fn total_length(&self) -> Option<Json<serde_json::Value>> {
+        to_json(&self.0.total_length)
+    }
+
+    async fn contact_info(&self) -> Option<Json<serde_json::Value>> {
+        to_json(&self.0.contact_info)
+    }
+
+    async fn socials(&self) -> Option<Json<serde_json::Value>> {
+        to_json(&self.0.socials)
+    }
+
+    async fn metadata(&self) -> MetadataObject {
+        MetadataObject::from(&self.0.metadata)
+    }
+}
+
+/// A modelling scale
+pub struct ScaleObject(pub Scale);
+
+#[Object(name = "Scale")]
+impl ScaleObject {
+    async fn id(&self) -> &str {
+        &self.0.scale_id
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    async fn ratio(&self) -> String {
+        self.0.ratio.to_string()
+    }
+
+    async fn gauge(&self) -> Option<Json<serde_json::Value>> {
+        to_json(&self.0.gauge)
+    }
+
+    async fn description(&self) -> LocalizedTextObject {
+        LocalizedTextObject::from(&self.0.description)
+    }
+
+    async fn standards(&self) -> Vec<String> {
+        let mut standards: Vec<String> = self.0.standards.iter().map(|it| it.to_string()).collect();
+        standards.sort();
+        standards
+    }
+
+    async fn metadata(&self) -> MetadataObject {
+        MetadataObject::from(&self.0.metadata)
+    }
+}
+
+/// A catalog item, it can contain one or more rolling stocks
+pub struct CatalogItemObject(pub CatalogItem);
+
+#[Object(name = "CatalogItem")]
+impl CatalogItemObject {
+    async fn id(&self) -> String {
+        self.0.catalog_item_id.to_string()
+    }
+
+    /// the brand, resolved in batches
+    async fn brand(&self, ctx: &Context<'_>) -> Result<Option<BrandObject>> {
+        let loader = ctx.data::<DataLoader<BrandLoader>>()?;
+        let brand = loader.load_one(self.0.brand.brand_id().clone()).await?;
+        Ok(brand.map(BrandObject))
+    }
+
+    async fn item_number(&self) -> &str {
+        self.0.item_number.value()
+    }
+
+    /// the scale, resolved in batches
+    async fn scale(&self, ctx: &Context<'_>) -> Result<Option<ScaleObject>> {
+        let loader = ctx.data::<DataLoader<ScaleLoader>>()?;
+        let scale = loader.load_one(self.0.scale.scale_id().clone()).await?;
+        Ok(scale.map(ScaleObject))
+    }
+
+    async fn category(&self) -> String {
+        self.0.category.to_string()
+    }
+
+    async fn power_method(&self) -> String {
+        self.0.power_method.to_string()
+    }
+
+    async fn epoch(&self) -> String {
+        self.0.epoch.to_string()
+    }
+
+    async fn description(&self) -> LocalizedTextObject {
+        LocalizedTextObject::from(&self.0.description)
+    }
+
+    async fn details(&self) -> LocalizedTextObject {
+        LocalizedTextObject::from(&self.0.details)
+    }
+
+    async fn delivery_date(&self) -> Option<String> {
+        self.0.delivery_date.as_ref().map(|it| it.to_string())
+    }
+
+    async fn availability_status(&self) -> Option<String> {
+        self.0.availability_status.map(|it| it.to_string())
+    }
+
+    async fn count(&self) -> u8 {
+        self.0.count
+    }
+
+    /// the rolling stocks, resolved in batches unless they were already loaded with the catalog item
+    async fn rolling_stocks(&self, ctx: &Context<'_>) -> Result<Vec<RollingStockObject>> {
+        if !self.0.rolling_stocks.is_empty() {
+            return Ok(self.0.rolling_stocks.iter().cloned().map(RollingStockObject).collect());
+        }
+
+        let loader = ctx.data::<DataLoader<RollingStocksLoader>>()?;
+        let rolling_stocks = loader.load_one(self.0.catalog_item_id.clone()).await?;
+        Ok(rolling_stocks
+            .unwrap_or_default()
+            .into_iter()
+            .map(RollingStockObject)
+            .collect())
+    }
+
+    async fn metadata(&self) -> MetadataObject {
+        MetadataObject::from(&self.0.metadata)
+    }
+}
+
+/// A rolling stock
+pub struct RollingStockObject(pub RollingStock);
+
+#[Object(name = "RollingStock")]
+impl RollingStockObject {
+    async fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    async fn category(&self) -> String {
+        self.0.category().to_string()
+    }
+
+    /// the railway, resolved in batches
+    async fn railway(&self, ctx: &Context<'_>) -> Result<Option<RailwayObject>> {
+        let loader = ctx.data::<DataLoader<RailwayLoader>>()?;
+        let railway = loader.load_one(self.0.railway().id().clone()).await?;
+        Ok(railway.map(RailwayObject))
+    }
+
+    async fn railway_label(&self) -> &str {
+        self.0.railway().display_text()
+    }
+
+    async fn type_name(&self) -> &str {
+        match &self.0 {
+            RollingStock::ElectricMultipleUnit { type_name, .. } => type_name,
+            RollingStock::FreightCar { type_name, .. } => type_name,
+            RollingStock::Locomotive { class_name, .. } => class_name,
+            RollingStock::PassengerCar { type_name, .. } => type_name,
+            RollingStock::Railcar { type_name, .. } => type_name,
+        }
+    }
+
+    async fn livery(&self) -> Option<&str> {
+        self.0.livery()
+    }
+
+    async fn road_number(&self) -> Option<&str> {
+        self.0.road_number()
+    }
+
+    async fn length_over_buffers(&self) -> Option<Json<serde_json::Value>> {
+        to_json(&self.0.length_over_buffer())
+    }
+
+    async fn technical_specifications(&self) -> Option<Json<serde_json::Value>> {
+        to_json(&self.0.technical_specifications())
+    }
+
+    async fn dcc_interface(&self) -> Option<String> {
+        self.0.dcc_interface().map(|it| it.to_string())
+    }
+
+    async fn control(&self) -> Option<String> {
+        self.0.control().map(|it| it.to_string())
+    }
+
+    /// the full rolling stock representation, as returned by the REST api
+    async fn details(&self) -> Option<Json<serde_json::Value>> {
+        to_json(&self.0)
+    }
+}
+
+/// Converts a nested value to a JSON scalar, `None` when the value is missing
+fn to_json<T: serde::Serialize>(value: &T) -> Option<Json<serde_json::Value>> {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::Null) | Err(_) => None,
+        Ok(value) => Some(Json(value)),
+    }
+}
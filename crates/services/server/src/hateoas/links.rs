@@ -1,9 +1,10 @@
 use serde::Serialize;
 use thiserror::Error;
 use url::{ParseError, Url};
+use utoipa::ToSchema;
 
 /// It represents a resource Link.
-#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, ToSchema)]
 #[cfg_attr(test, derive(serde::Deserialize))]
 pub struct Link {
     pub href: Url,
@@ -34,13 +35,16 @@ pub enum LinkError {
 }
 
 /// The enumeration of link relation types
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, ToSchema)]
 #[cfg_attr(test, derive(serde::Deserialize))]
 #[serde(rename_all = "lowercase")]
 pub enum LinkRelation {
     /// An IRI that refers to the furthest preceding resource in a series of resources.
     First,
 
+    /// The target IRI points to an image which is part of the link's context.
+    Image,
+
     /// The target IRI points to a resource that is a member of the collection represented
     /// by the context IRI.
     Item,
@@ -56,11 +60,30 @@ pub enum LinkRelation {
     /// series is the link target.
     Previous,
 
+    /// Refers to a resource that provides a preview for the link's context.
+    Preview,
+
     /// Conveys an identifier for the link's context.
     #[serde(rename = "self")]
     SelfLink,
 }
 
+impl LinkRelation {
+    /// Returns the link relation name (ie `self`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LinkRelation::First => "first",
+            LinkRelation::Image => "image",
+            LinkRelation::Item => "item",
+            LinkRelation::Last => "last",
+            LinkRelation::Next => "next",
+            LinkRelation::Previous => "previous",
+            LinkRelation::Preview => "preview",
+            LinkRelation::SelfLink => "self",
+        }
+    }
+}
+
 /// A Links builder
 #[derive(Debug)]
 pub struct LinkBuilder {
@@ -126,6 +149,13 @@ mod test {
 
             assert_eq!(LinkError::InvalidHref(ParseError::RelativeUrlWithoutBase), error);
         }
+
+        #[test]
+        fn it_should_return_the_link_relation_names() {
+            assert_eq!("self", LinkRelation::SelfLink.as_str());
+            assert_eq!("next", LinkRelation::Next.as_str());
+            assert_eq!("preview", LinkRelation::Preview.as_str());
+        }
     }
 
     mod links_builder {
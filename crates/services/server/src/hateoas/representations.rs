@@ -1,7 +1,18 @@
 use crate::hateoas::links::{Link, LinkRelation};
 use axum::response::{IntoResponse, Response};
 use axum::Json;
+use catalog::brands::brand::Brand;
+use catalog::catalog_items::availability::Availability;
+use catalog::catalog_items::catalog_item::CatalogItem;
+use catalog::images::catalog_item_image::CatalogItemImage;
+use catalog::prototypes::prototype::Prototype;
+use catalog::railways::railway::Railway;
+use catalog::railways::railway_relationship::RailwayLineage;
+use catalog::scales::scale::Scale;
+use catalog::webhooks::webhook::Webhook;
+use catalog::webhooks::webhook_delivery::WebhookDelivery;
 use serde::Serialize;
+use utoipa::ToSchema;
 
 /// A trait for representation models to collect links.
 pub trait RepresentationModel {
@@ -26,8 +37,19 @@ pub trait RepresentationModel {
 }
 
 /// A simple EntityModel wrapping a domain object and adding links to it.
-#[derive(Debug, PartialEq, Clone, Serialize)]
+#[derive(Debug, PartialEq, Clone, Serialize, ToSchema)]
 #[cfg_attr(test, derive(serde::Deserialize))]
+#[aliases(
+    AvailabilityModel = EntityModel<Availability>,
+    BrandModel = EntityModel<Brand>,
+    CatalogItemImageModel = EntityModel<CatalogItemImage>,
+    CatalogItemModel = EntityModel<CatalogItem>,
+    PrototypeModel = EntityModel<Prototype>,
+    RailwayLineageModel = EntityModel<RailwayLineage>,
+    RailwayModel = EntityModel<Railway>,
+    ScaleModel = EntityModel<Scale>,
+    WebhookModel = EntityModel<Webhook>
+)]
 pub struct EntityModel<T>
 where
     T: Serialize + PartialEq + Clone,
@@ -75,8 +97,19 @@ where
     }
 }
 
-#[derive(Debug, PartialEq, Clone, Serialize)]
+/// A collection of domain objects, with its links.
+#[derive(Debug, PartialEq, Clone, Serialize, ToSchema)]
 #[cfg_attr(test, derive(serde::Deserialize))]
+#[aliases(
+    BrandsCollection = CollectionModel<Brand>,
+    CatalogItemImagesCollection = CollectionModel<CatalogItemImage>,
+    CatalogItemsCollection = CollectionModel<CatalogItem>,
+    PrototypesCollection = CollectionModel<Prototype>,
+    RailwaysCollection = CollectionModel<Railway>,
+    ScalesCollection = CollectionModel<Scale>,
+    WebhookDeliveriesCollection = CollectionModel<WebhookDelivery>,
+    WebhooksCollection = CollectionModel<Webhook>
+)]
 pub struct CollectionModel<T>
 where
     T: Serialize + PartialEq + Clone,
@@ -0,0 +1,17 @@
+use crate::health::readiness::Liveness;
+use axum::Json;
+
+/// Checks the server process is up and running, without checking its dependencies
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "management",
+    operation_id = "get-liveness",
+    responses(
+        (status = 200, description = "the server is alive", body = Liveness),
+    )
+)]
+#[tracing::instrument(name = "get_liveness")]
+pub async fn handle() -> Json<Liveness> {
+    Json(Liveness::up())
+}
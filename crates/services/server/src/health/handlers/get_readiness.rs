@@ -0,0 +1,39 @@
+use crate::health::readiness::{DatabaseCheck, HealthStatus, MigrationsCheck, PoolCheck, Readiness, ReadinessChecks};
+use crate::state::AppState;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use data::migrations::migrations_status;
+
+/// Checks the server is ready to serve requests: the database is reachable and all the migrations are applied
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "management",
+    operation_id = "get-readiness",
+    responses(
+        (status = 200, description = "the server is ready", body = Readiness),
+        (status = 503, description = "the server is not ready", body = Readiness),
+    )
+)]
+#[tracing::instrument(name = "get_readiness", skip(app_state))]
+pub async fn handle(State(app_state): State<AppState>) -> (StatusCode, Json<Readiness>) {
+    let pg_pool = &app_state.pg_pool;
+
+    // the pool usage is read before the other checks, they need a connection too
+    let pool = PoolCheck::of(pg_pool);
+    let database = DatabaseCheck::from_result(sqlx::query("SELECT 1").execute(&**pg_pool).await.map(|_| ()));
+    let migrations = MigrationsCheck::from_result(migrations_status(pg_pool).await);
+
+    let readiness = Readiness::new(ReadinessChecks {
+        database,
+        pool,
+        migrations,
+    });
+    let status_code = match readiness.status {
+        HealthStatus::Up => StatusCode::OK,
+        HealthStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+    };
+
+    (status_code, Json(readiness))
+}
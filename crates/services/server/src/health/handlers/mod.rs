@@ -0,0 +1,5 @@
+pub(crate) mod get_liveness;
+pub(crate) mod get_readiness;
+
+pub use get_liveness::handle as get_liveness;
+pub use get_readiness::handle as get_readiness;
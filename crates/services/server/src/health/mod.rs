@@ -0,0 +1,5 @@
+//! the module includes the liveness and readiness probes
+
+pub mod handlers;
+pub mod readiness;
+pub mod routes;
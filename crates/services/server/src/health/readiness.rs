@@ -0,0 +1,266 @@
+use data::migrations::MigrationsStatus;
+use serde::Serialize;
+use sqlx::PgPool;
+use utoipa::ToSchema;
+
+/// the application version
+pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// It represents the status of a probe, or a single check
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HealthStatus {
+    /// the check is passing
+    Up,
+    /// the check is failing
+    Down,
+}
+
+impl HealthStatus {
+    fn of(is_up: bool) -> Self {
+        if is_up {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        }
+    }
+}
+
+/// The liveness probe response, the process is up and serving requests
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct Liveness {
+    /// the liveness status
+    pub status: HealthStatus,
+    /// the application version
+    pub version: String,
+}
+
+impl Liveness {
+    /// Creates a new liveness response
+    pub fn up() -> Self {
+        Liveness {
+            status: HealthStatus::Up,
+            version: APP_VERSION.to_owned(),
+        }
+    }
+}
+
+/// The readiness probe response, with the details for each dependency.
+///
+/// The application is ready only when the database is reachable and all the migrations
+/// have been applied. The connection pool saturation is reported, but it never makes the
+/// application not ready: a busy instance is still able to serve requests.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct Readiness {
+    /// the readiness status
+    pub status: HealthStatus,
+    /// the application version
+    pub version: String,
+    /// the checks details
+    pub checks: ReadinessChecks,
+}
+
+/// The readiness checks
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct ReadinessChecks {
+    /// the database connectivity
+    pub database: DatabaseCheck,
+    /// the database connection pool usage
+    pub pool: PoolCheck,
+    /// the database migrations
+    pub migrations: MigrationsCheck,
+}
+
+/// The database connectivity check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct DatabaseCheck {
+    /// the check status
+    pub status: HealthStatus,
+    /// the failure reason, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// The database connection pool check
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct PoolCheck {
+    /// the number of connections in the pool
+    pub size: u32,
+    /// the number of idle connections
+    pub idle: u32,
+    /// the max number of connections
+    pub max_connections: u32,
+    /// the ratio between the connections in use and the max number of connections
+    pub saturation: f64,
+}
+
+/// The database migrations check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct MigrationsCheck {
+    /// the check status
+    pub status: HealthStatus,
+    /// the number of migrations applied
+    pub applied: usize,
+    /// the versions of the migrations still to apply
+    pub pending: Vec<i64>,
+    /// the failure reason, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl PoolCheck {
+    /// Reads the connection pool usage
+    pub fn of(pg_pool: &PgPool) -> Self {
+        let size = pg_pool.size();
+        let idle = pg_pool.num_idle() as u32;
+        let max_connections = pg_pool.options().get_max_connections();
+        PoolCheck::new(size, idle, max_connections)
+    }
+
+    fn new(size: u32, idle: u32, max_connections: u32) -> Self {
+        let in_use = size.saturating_sub(idle);
+        let saturation = if max_connections == 0 {
+            0.0
+        } else {
+            f64::from(in_use) / f64::from(max_connections)
+        };
+        PoolCheck {
+            size,
+            idle,
+            max_connections,
+            saturation,
+        }
+    }
+}
+
+impl DatabaseCheck {
+    /// Creates a new database check from the connectivity check result
+    pub fn from_result<E: ToString>(result: Result<(), E>) -> Self {
+        match result {
+            Ok(_) => DatabaseCheck {
+                status: HealthStatus::Up,
+                error: None,
+            },
+            Err(why) => DatabaseCheck {
+                status: HealthStatus::Down,
+                error: Some(why.to_string()),
+            },
+        }
+    }
+}
+
+impl MigrationsCheck {
+    /// Creates a new migrations check from the migrations status
+    pub fn from_result<E: ToString>(result: Result<MigrationsStatus, E>) -> Self {
+        match result {
+            Ok(status) => MigrationsCheck {
+                status: HealthStatus::of(status.is_up_to_date()),
+                applied: status.applied.len(),
+                pending: status.pending,
+                error: None,
+            },
+            Err(why) => MigrationsCheck {
+                status: HealthStatus::Down,
+                applied: 0,
+                pending: Vec::new(),
+                error: Some(why.to_string()),
+            },
+        }
+    }
+}
+
+impl Readiness {
+    /// Creates a new readiness response, it is up only when all the required checks are passing
+    pub fn new(checks: ReadinessChecks) -> Self {
+        let is_ready = checks.database.status == HealthStatus::Up && checks.migrations.status == HealthStatus::Up;
+        Readiness {
+            status: HealthStatus::of(is_ready),
+            version: APP_VERSION.to_owned(),
+            checks,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod readiness {
+        use super::*;
+        use anyhow::anyhow;
+        use pretty_assertions::assert_eq;
+
+        fn migrations_status(pending: Vec<i64>) -> MigrationsStatus {
+            MigrationsStatus {
+                applied: vec![1, 2],
+                pending,
+            }
+        }
+
+        #[test]
+        fn it_should_be_ready_when_the_database_is_up_and_migrated() {
+            let readiness = Readiness::new(ReadinessChecks {
+                database: DatabaseCheck::from_result(Ok::<(), anyhow::Error>(())),
+                pool: PoolCheck::new(10, 0, 10),
+                migrations: MigrationsCheck::from_result(Ok::<_, anyhow::Error>(migrations_status(vec![]))),
+            });
+
+            assert_eq!(HealthStatus::Up, readiness.status);
+            assert_eq!(1.0, readiness.checks.pool.saturation);
+            assert_eq!(APP_VERSION, readiness.version);
+        }
+
+        #[test]
+        fn it_should_not_be_ready_with_pending_migrations() {
+            let readiness = Readiness::new(ReadinessChecks {
+                database: DatabaseCheck::from_result(Ok::<(), anyhow::Error>(())),
+                pool: PoolCheck::new(2, 1, 10),
+                migrations: MigrationsCheck::from_result(Ok::<_, anyhow::Error>(migrations_status(vec![3]))),
+            });
+
+            assert_eq!(HealthStatus::Down, readiness.status);
+            assert_eq!(HealthStatus::Down, readiness.checks.migrations.status);
+            assert_eq!(vec![3], readiness.checks.migrations.pending);
+            assert_eq!(2, readiness.checks.migrations.applied);
+        }
+
+        #[test]
+        fn it_should_not_be_ready_when_the_database_is_down() {
+            let readiness = Readiness::new(ReadinessChecks {
+                database: DatabaseCheck::from_result(Err(anyhow!("connection refused"))),
+                pool: PoolCheck::new(0, 0, 10),
+                migrations: MigrationsCheck::from_result(Err::<MigrationsStatus, _>(anyhow!("connection refused"))),
+            });
+
+            assert_eq!(HealthStatus::Down, readiness.status);
+            assert_eq!(
+                Some(String::from("connection refused")),
+                readiness.checks.database.error
+            );
+            assert_eq!(0.0, readiness.checks.pool.saturation);
+        }
+
+        #[test]
+        fn it_should_serialize_the_readiness_response() {
+            let readiness = Readiness::new(ReadinessChecks {
+                database: DatabaseCheck::from_result(Ok::<(), anyhow::Error>(())),
+                pool: PoolCheck::new(4, 3, 10),
+                migrations: MigrationsCheck::from_result(Ok::<_, anyhow::Error>(migrations_status(vec![]))),
+            });
+
+            let json = serde_json::to_value(&readiness).unwrap();
+            assert_eq!(
+                serde_json::json!({
+                    "status": "UP",
+                    "version": APP_VERSION,
+                    "checks": {
+                        "database": { "status": "UP" },
+                        "pool": { "size": 4, "idle": 3, "max_connections": 10, "saturation": 0.1 },
+                        "migrations": { "status": "UP", "applied": 2, "pending": [] }
+                    }
+                }),
+                json
+            );
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::health::handlers;
+use crate::state::AppState;
+use axum::routing::get;
+use axum::Router;
+
+pub const LIVENESS_API: &str = "/health/live";
+pub const READINESS_API: &str = "/health/ready";
+
+pub fn health_router() -> Router<AppState> {
+    Router::new()
+        .route(LIVENESS_API, get(handlers::get_liveness))
+        .route(READINESS_API, get(handlers::get_readiness))
+}
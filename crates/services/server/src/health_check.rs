@@ -5,6 +5,17 @@ use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 
+/// Checks the server is up and running, with a working database connection
+#[utoipa::path(
+    get,
+    path = "/health-check",
+    tag = "management",
+    operation_id = "health-check",
+    responses(
+        (status = 200, description = "the server is healthy"),
+        (status = 503, description = "the database is not reachable"),
+    )
+)]
 #[tracing::instrument(name = "health_check", skip(app_state))]
 pub async fn handler(State(app_state): State<AppState>) -> impl IntoResponse {
     let db_pool = app_state.pg_pool;
@@ -1,10 +1,24 @@
 pub mod app;
+pub mod calendar;
 pub mod catalog;
+pub mod events;
+pub mod feeds;
+pub mod graphql;
 pub mod hateoas;
+pub mod health;
 pub mod health_check;
+pub mod metrics;
 pub mod middlewares;
+pub mod migrations;
+pub mod openapi;
+pub mod outbox;
+pub mod replica;
+pub mod shutdown;
 pub mod state;
+pub mod storage;
 #[cfg(test)]
 pub mod testing;
+pub mod tls;
 pub mod tracing;
 pub mod web;
+pub mod webhooks;
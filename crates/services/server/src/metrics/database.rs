@@ -0,0 +1,32 @@
+use crate::metrics::recorder::Metrics;
+use anyhow::Context;
+use async_trait::async_trait;
+use common::unit_of_work::postgres::PgUnitOfWork;
+use common::unit_of_work::Database;
+use sqlx::PgPool;
+use std::time::Instant;
+
+/// A postgres database connection wrapper, it records the time spent to acquire a pooled connection
+/// (and begin the transaction) for every new unit of work
+#[derive(Clone)]
+pub struct MeteredDatabase<'db> {
+    pg_pool: &'db PgPool,
+    metrics: &'db Metrics,
+}
+
+impl<'db> MeteredDatabase<'db> {
+    /// Creates a new metered postgres database pooled connection
+    pub fn new(pg_pool: &'db PgPool, metrics: &'db Metrics) -> Self {
+        MeteredDatabase { pg_pool, metrics }
+    }
+}
+
+#[async_trait]
+impl<'db> Database<'db, PgUnitOfWork<'db>> for MeteredDatabase<'db> {
+    async fn begin(self) -> Result<PgUnitOfWork<'db>, anyhow::Error> {
+        let started = Instant::now();
+        let transaction = self.pg_pool.begin().await.context("Could not begin the transaction");
+        self.metrics.observe_acquire(started.elapsed());
+        Ok(PgUnitOfWork::new(transaction?))
+    }
+}
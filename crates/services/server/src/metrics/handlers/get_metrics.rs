@@ -0,0 +1,27 @@
+use crate::state::AppState;
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+
+/// the Prometheus text format content type
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Returns the server metrics
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "management",
+    operation_id = "get-metrics",
+    responses(
+        (status = 200, description = "the server metrics, in the Prometheus text format", content_type = "text/plain", body = String),
+    )
+)]
+#[tracing::instrument(name = "get_metrics", skip(app_state))]
+pub async fn handle(State(app_state): State<AppState>) -> impl IntoResponse {
+    app_state.metrics.observe_pool(&app_state.pg_pool);
+    app_state.metrics.observe_cache(&app_state.reference_data);
+    (
+        [(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+        app_state.metrics.render(),
+    )
+}
@@ -0,0 +1,3 @@
+pub(crate) mod get_metrics;
+
+pub use get_metrics::handle as get_metrics;
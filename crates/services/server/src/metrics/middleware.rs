@@ -0,0 +1,56 @@
+use crate::metrics::recorder::Metrics;
+use axum::extract::{MatchedPath, Request, State};
+use axum::middleware::Next;
+use axum::response::Response;
+use std::time::Instant;
+
+/// the route label for the requests not matching any route
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// A middleware to record the requests count and latency, labelled by the matched route
+/// (ie `/api/brands/:brand_id`) to keep the metrics cardinality bounded
+pub async fn track_requests(State(metrics): State<Metrics>, request: Request, next: Next) -> Response {
+    let started = Instant::now();
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched_path| matched_path.as_str().to_owned())
+        .unwrap_or_else(|| String::from(UNMATCHED_ROUTE));
+
+    let response = next.run(request).await;
+
+    metrics.observe_request(&method, &route, response.status().as_u16(), started.elapsed());
+    response
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::StatusCode;
+    use axum::middleware;
+    use axum::routing::get;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn it_should_label_the_requests_with_the_matched_route() {
+        let metrics = Metrics::new();
+        let router = Router::new()
+            .route("/api/brands/:brand_id", get(|| async { StatusCode::NO_CONTENT }))
+            .layer(middleware::from_fn_with_state(metrics.clone(), track_requests));
+
+        let request = Request::builder().uri("/api/brands/acme").body(Body::empty()).unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::NO_CONTENT, response.status());
+
+        let request = Request::builder().uri("/not-found").body(Body::empty()).unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(StatusCode::NOT_FOUND, response.status());
+
+        let output = metrics.render();
+        assert!(output.contains(r#"http_requests_total{method="GET",route="/api/brands/:brand_id",status="2xx"} 1"#));
+        assert!(output.contains(r#"http_requests_total{method="GET",route="unmatched",status="4xx"} 1"#));
+    }
+}
@@ -0,0 +1,7 @@
+//! the module includes the server metrics, exposed in the Prometheus text format
+
+pub mod database;
+pub mod handlers;
+pub mod middleware;
+pub mod recorder;
+pub mod routes;
@@ -0,0 +1,271 @@
+use catalog::catalog_items::commands::new_catalog_item::CatalogItemCreationError;
+use data::cache::reference_data::ReferenceDataCache;
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry,
+    TextEncoder,
+};
+use sqlx::PgPool;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// It records the server metrics, one registry for each instance
+#[derive(Clone)]
+pub struct Metrics {
+    inner: Arc<MetricsInner>,
+}
+
+struct MetricsInner {
+    registry: Registry,
+    http_requests_total: IntCounterVec,
+    http_request_duration_seconds: HistogramVec,
+    db_pool_size: IntGauge,
+    db_pool_idle_connections: IntGauge,
+    db_pool_acquire_seconds: Histogram,
+    catalog_items_created_total: IntCounter,
+    catalog_items_rejected_total: IntCounterVec,
+    cache_hits_total: IntCounterVec,
+    cache_misses_total: IntCounterVec,
+    cache_entries: IntGaugeVec,
+}
+
+impl Metrics {
+    /// Creates a new metrics recorder, with all the collectors registered
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_requests_total = IntCounterVec::new(
+            Opts::new("http_requests_total", "the number of HTTP requests"),
+            &["method", "route", "status"],
+        )
+        .expect("invalid http_requests_total metric");
+        let http_request_duration_seconds = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "the HTTP requests latency"),
+            &["method", "route"],
+        )
+        .expect("invalid http_request_duration_seconds metric");
+        let db_pool_size = IntGauge::new("db_pool_size", "the number of connections in the database pool")
+            .expect("invalid db_pool_size metric");
+        let db_pool_idle_connections = IntGauge::new(
+            "db_pool_idle_connections",
+            "the number of idle connections in the database pool",
+        )
+        .expect("invalid db_pool_idle_connections metric");
+        let db_pool_acquire_seconds = Histogram::with_opts(HistogramOpts::new(
+            "db_pool_acquire_seconds",
+            "the time spent waiting for a pooled connection to begin a transaction",
+        ))
+        .expect("invalid db_pool_acquire_seconds metric");
+        let catalog_items_created_total =
+            IntCounter::new("catalog_items_created_total", "the number of catalog items created")
+                .expect("invalid catalog_items_created_total metric");
+        let catalog_items_rejected_total = IntCounterVec::new(
+            Opts::new("catalog_items_rejected_total", "the number of catalog items rejected"),
+            &["reason"],
+        )
+        .expect("invalid catalog_items_rejected_total metric");
+        let cache_hits_total = IntCounterVec::new(
+            Opts::new(
+                "reference_data_cache_hits_total",
+                "the number of lookups served by the cache",
+            ),
+            &["cache"],
+        )
+        .expect("invalid reference_data_cache_hits_total metric");
+        let cache_misses_total = IntCounterVec::new(
+            Opts::new(
+                "reference_data_cache_misses_total",
+                "the number of lookups which missed the cache",
+            ),
+            &["cache"],
+        )
+        .expect("invalid reference_data_cache_misses_total metric");
+        let cache_entries = IntGaugeVec::new(
+            Opts::new("reference_data_cache_entries", "the number of cached values"),
+            &["cache"],
+        )
+        .expect("invalid reference_data_cache_entries metric");
+
+        registry
+            .register(Box::new(http_requests_total.clone()))
+            .and_then(|_| registry.register(Box::new(http_request_duration_seconds.clone())))
+            .and_then(|_| registry.register(Box::new(db_pool_size.clone())))
+            .and_then(|_| registry.register(Box::new(db_pool_idle_connections.clone())))
+            .and_then(|_| registry.register(Box::new(db_pool_acquire_seconds.clone())))
+            .and_then(|_| registry.register(Box::new(catalog_items_created_total.clone())))
+            .and_then(|_| registry.register(Box::new(catalog_items_rejected_total.clone())))
+            .and_then(|_| registry.register(Box::new(cache_hits_total.clone())))
+            .and_then(|_| registry.register(Box::new(cache_misses_total.clone())))
+            .and_then(|_| registry.register(Box::new(cache_entries.clone())))
+            .expect("unable to register the server metrics");
+
+        Metrics {
+            inner: Arc::new(MetricsInner {
+                registry,
+                http_requests_total,
+                http_request_duration_seconds,
+                db_pool_size,
+                db_pool_idle_connections,
+                db_pool_acquire_seconds,
+                catalog_items_created_total,
+                catalog_items_rejected_total,
+                cache_hits_total,
+                cache_misses_total,
+                cache_entries,
+            }),
+        }
+    }
+
+    /// Records a completed HTTP request, the status code is reported by its class (ie `2xx`)
+    pub fn observe_request(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let status_class = format!("{}xx", status / 100);
+        self.inner
+            .http_requests_total
+            .with_label_values(&[method, route, &status_class])
+            .inc();
+        self.inner
+            .http_request_duration_seconds
+            .with_label_values(&[method, route])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Records the time spent waiting for a pooled connection
+    pub fn observe_acquire(&self, elapsed: Duration) {
+        self.inner.db_pool_acquire_seconds.observe(elapsed.as_secs_f64());
+    }
+
+    /// Records the current size of the database connection pool
+    pub fn observe_pool(&self, pg_pool: &PgPool) {
+        self.inner.db_pool_size.set(pg_pool.size() as i64);
+        self.inner.db_pool_idle_connections.set(pg_pool.num_idle() as i64);
+    }
+
+    /// Records the reference data cache statistics, by entity type
+    pub fn observe_cache(&self, cache: &ReferenceDataCache) {
+        for (name, stats) in cache.stats() {
+            let hits = self.inner.cache_hits_total.with_label_values(&[name]);
+            hits.inc_by(stats.hits.saturating_sub(hits.get()));
+            let misses = self.inner.cache_misses_total.with_label_values(&[name]);
+            misses.inc_by(stats.misses.saturating_sub(misses.get()));
+            self.inner
+                .cache_entries
+                .with_label_values(&[name])
+                .set(stats.entries as i64);
+        }
+    }
+
+    /// Records a new catalog item
+    pub fn catalog_item_created(&self) {
+        self.inner.catalog_items_created_total.inc();
+    }
+
+    /// Records a rejected catalog item, labelled by the error kind
+    pub fn catalog_item_rejected(&self, error: &CatalogItemCreationError) {
+        let reason = match error {
+            CatalogItemCreationError::UnexpectedError(_) => "unexpected_error",
+            CatalogItemCreationError::InvalidRequest(_) => "invalid_request",
+            CatalogItemCreationError::DatabaseError(_) => "database_error",
+            CatalogItemCreationError::CatalogItemAlreadyExists(_) => "already_exists",
+            CatalogItemCreationError::BrandNotFound(_) => "brand_not_found",
+            CatalogItemCreationError::PrototypeNotFound(_) => "prototype_not_found",
+            CatalogItemCreationError::RailwayNotFound(_) => "railway_not_found",
+            CatalogItemCreationError::ScaleNotFound(_) => "scale_not_found",
+        };
+        self.inner
+            .catalog_items_rejected_total
+            .with_label_values(&[reason])
+            .inc();
+    }
+
+    /// Returns the metrics in the Prometheus text format
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.inner.registry.gather(), &mut buffer)
+            .expect("unable to encode the server metrics");
+        String::from_utf8(buffer).expect("the server metrics are not valid utf-8")
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Metrics").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod metrics {
+        use super::*;
+        use anyhow::anyhow;
+        use catalog::brands::brand_id::BrandId;
+        use catalog::scales::scale_id::ScaleId;
+
+        #[test]
+        fn it_should_count_the_requests_by_route_and_status_class() {
+            let metrics = Metrics::new();
+            metrics.observe_request("GET", "/api/brands/:brand_id", 200, Duration::from_millis(10));
+            metrics.observe_request("GET", "/api/brands/:brand_id", 404, Duration::from_millis(5));
+            metrics.observe_request("GET", "/api/brands/:brand_id", 404, Duration::from_millis(5));
+
+            let output = metrics.render();
+            assert!(
+                output.contains(r#"http_requests_total{method="GET",route="/api/brands/:brand_id",status="2xx"} 1"#)
+            );
+            assert!(
+                output.contains(r#"http_requests_total{method="GET",route="/api/brands/:brand_id",status="4xx"} 2"#)
+            );
+            assert!(
+                output.contains(r#"http_request_duration_seconds_count{method="GET",route="/api/brands/:brand_id"} 3"#)
+            );
+        }
+
+        #[test]
+        fn it_should_count_the_catalog_items_created_and_rejected() {
+            let metrics = Metrics::new();
+            metrics.catalog_item_created();
+            metrics.catalog_item_rejected(&CatalogItemCreationError::BrandNotFound(BrandId::new("ACME")));
+            metrics.catalog_item_rejected(&CatalogItemCreationError::UnexpectedError(anyhow!("boom")));
+
+            let output = metrics.render();
+            assert!(output.contains("catalog_items_created_total 1"));
+            assert!(output.contains(r#"catalog_items_rejected_total{reason="brand_not_found"} 1"#));
+            assert!(output.contains(r#"catalog_items_rejected_total{reason="unexpected_error"} 1"#));
+        }
+
+        #[test]
+        fn it_should_record_the_connection_acquire_time() {
+            let metrics = Metrics::new();
+            metrics.observe_acquire(Duration::from_millis(3));
+
+            let output = metrics.render();
+            assert!(output.contains("db_pool_acquire_seconds_count 1"));
+        }
+
+        #[test]
+        fn it_should_record_the_reference_data_cache_statistics() {
+            let metrics = Metrics::new();
+            let cache = ReferenceDataCache::new(Duration::from_secs(60), 10);
+            cache.scales.existing.insert(ScaleId::new("H0"), ());
+            let _ = cache.scales.existing.get(&ScaleId::new("H0"));
+            let _ = cache.scales.existing.get(&ScaleId::new("N"));
+
+            metrics.observe_cache(&cache);
+            metrics.observe_cache(&cache);
+
+            let output = metrics.render();
+            assert!(output.contains(r#"reference_data_cache_hits_total{cache="scales"} 1"#));
+            assert!(output.contains(r#"reference_data_cache_misses_total{cache="scales"} 1"#));
+            assert!(output.contains(r#"reference_data_cache_entries{cache="scales"} 1"#));
+            assert!(output.contains(r#"reference_data_cache_hits_total{cache="brands"} 0"#));
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::metrics::handlers;
+use crate::state::AppState;
+use axum::routing::get;
+use axum::Router;
+
+pub fn metrics_router(path: &str) -> Router<AppState> {
+    Router::new().route(path, get(handlers::get_metrics))
+}
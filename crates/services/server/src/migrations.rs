@@ -0,0 +1,45 @@
+//! the module includes the database migrations, applied (or checked) when the server starts
+
+use configuration::RunMigrations;
+use data::migrations::{migrations_status, MIGRATOR};
+use sqlx::migrate::MigrateError;
+use sqlx::PgPool;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum MigrationsError {
+    #[error("The database schema is behind, the pending migrations are {0:?}")]
+    SchemaBehind(Vec<i64>),
+
+    #[error(transparent)]
+    MigrationFailed(#[from] MigrateError),
+
+    #[error(transparent)]
+    UnexpectedError(#[from] anyhow::Error),
+}
+
+/// Prepares the database schema, according to the run migrations setting.
+///
+/// The migrator is holding a postgres advisory lock while it applies the migrations: when more
+/// replicas are starting at the same time, only one of them is applying the pending migrations
+/// and the others are waiting for the lock to find nothing left to apply.
+pub async fn run_migrations(pg_pool: &PgPool, run_migrations: RunMigrations) -> Result<(), MigrationsError> {
+    match run_migrations {
+        RunMigrations::Never => Ok(()),
+        RunMigrations::CheckOnly => {
+            let status = migrations_status(pg_pool).await?;
+            if status.is_up_to_date() {
+                tracing::info!(applied = status.applied.len(), "The database schema is up to date");
+                Ok(())
+            } else {
+                Err(MigrationsError::SchemaBehind(status.pending))
+            }
+        }
+        RunMigrations::OnStartup => {
+            tracing::info!("Applying the database migrations...");
+            MIGRATOR.run(pg_pool).await?;
+            tracing::info!("The database migrations have been applied");
+            Ok(())
+        }
+    }
+}
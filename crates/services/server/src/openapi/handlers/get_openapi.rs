@@ -0,0 +1,18 @@
+use crate::openapi::ApiDoc;
+use axum::Json;
+use utoipa::OpenApi;
+
+/// Returns the OpenAPI document for this web api
+#[utoipa::path(
+    get,
+    path = "/api/openapi.json",
+    tag = "management",
+    operation_id = "get-openapi",
+    responses(
+        (status = 200, description = "the OpenAPI document", body = Object),
+    )
+)]
+#[tracing::instrument(name = "get_openapi")]
+pub async fn handle() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
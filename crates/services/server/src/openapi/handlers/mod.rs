@@ -0,0 +1,3 @@
+pub(crate) mod get_openapi;
+
+pub use get_openapi::handle as get_openapi;
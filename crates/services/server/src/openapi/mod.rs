@@ -0,0 +1,401 @@
+//! the module includes the OpenAPI document, generated from the web handlers and the domain types
+
+pub mod handlers;
+pub mod responses;
+pub mod routes;
+
+use crate::calendar::handlers as calendar;
+use crate::catalog::brands::handlers as brands;
+use crate::catalog::catalog_items::handlers as catalog_items;
+use crate::catalog::catalog_items::handlers::post_catalog_item_image::ImageUploadForm;
+use crate::catalog::epochs::handlers as epochs;
+use crate::catalog::prototypes::handlers as prototypes;
+use crate::catalog::railways::handlers as railways;
+use crate::catalog::scales::handlers as scales;
+use crate::events::handlers as events;
+use crate::feeds::handlers as feeds;
+use crate::graphql::handlers as graphql;
+use crate::hateoas::links::{Link, LinkRelation};
+use crate::hateoas::representations::{
+    AvailabilityModel, BrandModel, BrandsCollection, CatalogItemImageModel, CatalogItemImagesCollection,
+    CatalogItemModel, CatalogItemsCollection, PrototypeModel, PrototypesCollection, RailwayLineageModel, RailwayModel,
+    RailwaysCollection, ScaleModel, ScalesCollection, WebhookDeliveriesCollection, WebhookModel, WebhooksCollection,
+};
+use crate::health::handlers as health;
+use crate::health::readiness::{
+    DatabaseCheck, HealthStatus, Liveness, MigrationsCheck, PoolCheck, Readiness, ReadinessChecks,
+};
+use crate::health_check;
+use crate::metrics::handlers as metrics;
+use crate::openapi::responses::{
+    AlreadyExists, BadRequest, Conflict, Gone, InternalServerError, NotFound, PayloadTooLarge, Unauthorized,
+    UnprocessableEntity, UnsupportedMediaType,
+};
+use crate::replica::consistency::ReadConsistency;
+use crate::web::problem::ProblemDetail;
+use crate::webhooks::handlers as webhooks;
+use catalog::brands::brand::Brand;
+use catalog::brands::brand_id::BrandId;
+use catalog::brands::brand_kind::BrandKind;
+use catalog::brands::brand_request::BrandRequest;
+use catalog::brands::brand_status::BrandStatus;
+use catalog::catalog_items::availability::{Availability, AvailabilityChange, AvailabilityRequest};
+use catalog::catalog_items::availability_status::AvailabilityStatus;
+use catalog::catalog_items::catalog_item::{CatalogItem, CatalogItemBrand, CatalogItemScale};
+use catalog::catalog_items::catalog_item_id::CatalogItemId;
+use catalog::catalog_items::catalog_item_request::CatalogItemRequest;
+use catalog::catalog_items::category::{
+    Category, ElectricMultipleUnitType, FreightCarType, LocomotiveType, PassengerCarType, RailcarType,
+    RollingStockCategory,
+};
+use catalog::catalog_items::control::{Control, DccInterface};
+use catalog::catalog_items::delivery_date::DeliveryDate;
+use catalog::catalog_items::epoch::Epoch;
+use catalog::catalog_items::epoch_calendar::{EpochCalendar, EpochPeriod};
+use catalog::catalog_items::item_number::ItemNumber;
+use catalog::catalog_items::length_over_buffers::LengthOverBuffers;
+use catalog::catalog_items::power_method::PowerMethod;
+use catalog::catalog_items::release_calendar::{Granularity, ReleaseBucket, ReleaseCalendar};
+use catalog::catalog_items::rolling_stock::{RollingStock, RollingStockRailway};
+use catalog::catalog_items::rolling_stock_id::RollingStockId;
+use catalog::catalog_items::rolling_stock_request::RollingStockRequest;
+use catalog::catalog_items::service_level::ServiceLevel;
+use catalog::catalog_items::technical_specifications::{
+    BodyShellType, ChassisType, Coupling, CouplingSocket, FeatureFlag, Radius, TechnicalSpecifications,
+};
+use catalog::catalog_items::uic_vehicle_number::{UicVehicleKind, UicVehicleNumber};
+use catalog::common::TrackGauge;
+use catalog::images::catalog_item_image::CatalogItemImage;
+use catalog::images::image_id::ImageId;
+use catalog::images::image_request::ImageRequest;
+use catalog::prototypes::power_type::PowerType;
+use catalog::prototypes::prototype::{Prototype, PrototypeRailway};
+use catalog::prototypes::prototype_id::PrototypeId;
+use catalog::prototypes::prototype_request::PrototypeRequest;
+use catalog::prototypes::years_built::YearsBuilt;
+use catalog::railways::period_of_activity::{PeriodOfActivity, RailwayStatus};
+use catalog::railways::railway::Railway;
+use catalog::railways::railway_gauge::RailwayGauge;
+use catalog::railways::railway_id::RailwayId;
+use catalog::railways::railway_length::RailwayLength;
+use catalog::railways::railway_relationship::{
+    RailwayLineage, RailwayRelationship, RailwayRelationshipRequest, RelationshipKind,
+};
+use catalog::railways::railway_request::RailwayRequest;
+use catalog::railways::vehicle_keeper_marking::VehicleKeeperMarking;
+use catalog::scales::ratio::Ratio;
+use catalog::scales::scale::Scale;
+use catalog::scales::scale_gauge::Gauge;
+use catalog::scales::scale_id::ScaleId;
+use catalog::scales::scale_request::ScaleRequest;
+use catalog::scales::standard::Standard;
+use catalog::webhooks::webhook::Webhook;
+use catalog::webhooks::webhook_delivery::{DeliveryStatus, WebhookDelivery};
+use catalog::webhooks::webhook_id::WebhookId;
+use catalog::webhooks::webhook_request::WebhookRequest;
+use common::address::Address;
+use common::contacts::{ContactInformation, MailAddress, PhoneNumber, WebsiteUrl};
+use common::localized_text::LocalizedText;
+use common::metadata::Metadata;
+use common::organizations::OrganizationEntityType;
+use common::slug::Slug;
+use common::socials::{Handler, Socials};
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+/// The OpenAPI document for the web api
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Trenako",
+        description = "A web api for model railways collectors",
+        contact(email = "mail@trenako.com"),
+        license(name = "Apache 2.0", url = "https://www.apache.org/licenses/LICENSE-2.0.html"),
+        version = "1.0.0"
+    ),
+    servers((url = "http://localhost:5000", description = "local")),
+    tags(
+        (name = "brands", description = "modelling railway brands"),
+        (name = "calendar", description = "the upcoming releases calendar"),
+        (name = "catalog-items", description = "model railway catalog items"),
+        (name = "epochs", description = "the model railway epochs"),
+        (name = "events", description = "the stream of catalog changes"),
+        (name = "feeds", description = "the catalog feeds"),
+        (name = "graphql", description = "the GraphQL api over the catalog"),
+        (name = "management", description = "the server management"),
+        (name = "prototypes", description = "the real world vehicles modelled by the rolling stocks"),
+        (name = "railways", description = "railway companies"),
+        (name = "scales", description = "modelling railway scales"),
+        (name = "webhooks", description = "the catalog events webhooks")
+    ),
+    paths(
+        brands::get_all_brands::handle,
+        brands::post_brand::handle,
+        brands::get_brand_by_id::handle,
+        brands::delete_brand::handle,
+        brands::restore_brand::handle,
+        calendar::get_releases::handle,
+        calendar::get_releases_ics::handle,
+        catalog_items::get_catalog_items::handle,
+        catalog_items::post_catalog_item::handle,
+        catalog_items::get_catalog_item_by_id::handle,
+        catalog_items::delete_catalog_item::handle,
+        catalog_items::restore_catalog_item::handle,
+        catalog_items::get_catalog_item_availability::handle,
+        catalog_items::post_catalog_item_availability::handle,
+        catalog_items::get_catalog_item_images::handle,
+        catalog_items::post_catalog_item_image::handle,
+        catalog_items::get_catalog_item_image::handle,
+        catalog_items::put_catalog_item_image::handle,
+        catalog_items::delete_catalog_item_image::handle,
+        catalog_items::delete_rolling_stock::handle,
+        catalog_items::restore_rolling_stock::handle,
+        epochs::get_epochs::handle,
+        prototypes::get_all_prototypes::handle,
+        prototypes::post_prototype::handle,
+        prototypes::get_prototype_by_id::handle,
+        prototypes::get_prototype_models::handle,
+        railways::get_all_railways::handle,
+        railways::post_railway::handle,
+        railways::get_railway_by_id::handle,
+        railways::delete_railway::handle,
+        railways::restore_railway::handle,
+        railways::post_railway_relationship::handle,
+        railways::get_railway_lineage::handle,
+        scales::get_all_scales::handle,
+        scales::post_scale::handle,
+        scales::get_scale_by_id::handle,
+        scales::delete_scale::handle,
+        scales::restore_scale::handle,
+        webhooks::get_all_webhooks::handle,
+        webhooks::post_webhook::handle,
+        webhooks::get_webhook_by_id::handle,
+        webhooks::delete_webhook::handle,
+        webhooks::get_webhook_deliveries::handle,
+        events::get_events::handle,
+        feeds::get_catalog_items_feed::handle,
+        graphql::get_graphiql::handle,
+        graphql::post_graphql::handle,
+        health_check::handler,
+        health::get_liveness::handle,
+        health::get_readiness::handle,
+        metrics::get_metrics::handle,
+        handlers::get_openapi::handle,
+    ),
+    components(
+        schemas(
+            Address,
+            Availability,
+            AvailabilityChange,
+            AvailabilityModel,
+            AvailabilityRequest,
+            AvailabilityStatus,
+            BodyShellType,
+            Brand,
+            BrandId,
+            BrandKind,
+            BrandModel,
+            BrandRequest,
+            BrandStatus,
+            BrandsCollection,
+            CatalogItem,
+            CatalogItemBrand,
+            CatalogItemId,
+            CatalogItemImage,
+            CatalogItemImageModel,
+            CatalogItemImagesCollection,
+            CatalogItemModel,
+            CatalogItemRequest,
+            CatalogItemScale,
+            CatalogItemsCollection,
+            Category,
+            ChassisType,
+            ContactInformation,
+            Control,
+            Coupling,
+            CouplingSocket,
+            DatabaseCheck,
+            DccInterface,
+            DeliveryDate,
+            DeliveryStatus,
+            ElectricMultipleUnitType,
+            Epoch,
+            EpochCalendar,
+            EpochPeriod,
+            FeatureFlag,
+            FreightCarType,
+            Gauge,
+            Granularity,
+            Handler,
+            HealthStatus,
+            ImageId,
+            ImageRequest,
+            ImageUploadForm,
+            ItemNumber,
+            LengthOverBuffers,
+            Link,
+            LinkRelation,
+            Liveness,
+            LocalizedText,
+            LocomotiveType,
+            MailAddress,
+            Metadata,
+            MigrationsCheck,
+            OrganizationEntityType,
+            PassengerCarType,
+            PeriodOfActivity,
+            PhoneNumber,
+            PoolCheck,
+            PowerMethod,
+            PowerType,
+            ProblemDetail,
+            Prototype,
+            PrototypeId,
+            PrototypeModel,
+            PrototypeRailway,
+            PrototypeRequest,
+            PrototypesCollection,
+            Radius,
+            RailcarType,
+            Railway,
+            RailwayGauge,
+            RailwayId,
+            RailwayLength,
+            RailwayLineage,
+            RailwayLineageModel,
+            RailwayModel,
+            RailwayRelationship,
+            RailwayRelationshipRequest,
+            RailwayRequest,
+            RailwayStatus,
+            RailwaysCollection,
+            Ratio,
+            ReadConsistency,
+            Readiness,
+            ReadinessChecks,
+            RelationshipKind,
+            ReleaseBucket,
+            ReleaseCalendar,
+            RollingStock,
+            RollingStockCategory,
+            RollingStockId,
+            RollingStockRailway,
+            RollingStockRequest,
+            Scale,
+            ScaleId,
+            ScaleModel,
+            ScaleRequest,
+            ScalesCollection,
+            ServiceLevel,
+            Slug,
+            Socials,
+            Standard,
+            TechnicalSpecifications,
+            TrackGauge,
+            UicVehicleKind,
+            UicVehicleNumber,
+            VehicleKeeperMarking,
+            Webhook,
+            WebhookDeliveriesCollection,
+            WebhookDelivery,
+            WebhookId,
+            WebhookModel,
+            WebhookRequest,
+            WebhooksCollection,
+            WebsiteUrl,
+            YearsBuilt,
+        ),
+        responses(
+            AlreadyExists,
+            BadRequest,
+            Conflict,
+            Gone,
+            InternalServerError,
+            NotFound,
+            PayloadTooLarge,
+            Unauthorized,
+            UnprocessableEntity,
+            UnsupportedMediaType
+        )
+    ),
+    modifiers(&AdminSecurity)
+)]
+pub struct ApiDoc;
+
+/// The security scheme for the admin operations (the API key as bearer token)
+struct AdminSecurity;
+
+impl Modify for AdminSecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "admin_api_key",
+                SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    /// Set this environment variable to regenerate the checked-in OpenAPI document
+    const UPDATE_OPENAPI: &str = "UPDATE_OPENAPI";
+
+    fn openapi_document_path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../../openapi/openapi.json")
+    }
+
+    #[test]
+    fn it_should_match_the_checked_in_openapi_document() {
+        let generated = ApiDoc::openapi()
+            .to_pretty_json()
+            .expect("the OpenAPI document is not valid json")
+            + "\n";
+        let path = openapi_document_path();
+
+        if std::env::var_os(UPDATE_OPENAPI).is_some() {
+            std::fs::write(&path, &generated).expect("unable to write the OpenAPI document");
+        }
+
+        let checked_in = std::fs::read_to_string(&path).unwrap_or_default();
+        assert_eq!(
+            checked_in, generated,
+            "openapi/openapi.json is out of date, run `{UPDATE_OPENAPI}=1 cargo test -p server openapi` to regenerate it"
+        );
+    }
+
+    #[test]
+    fn it_should_only_reference_declared_components() {
+        let document = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let mut references = Vec::new();
+        collect_references(&document, &mut references);
+
+        let missing: Vec<&String> = references
+            .iter()
+            .filter(|reference| {
+                let pointer = reference.trim_start_matches('#');
+                document.pointer(pointer).is_none()
+            })
+            .collect();
+        assert!(missing.is_empty(), "unresolved references: {missing:?}");
+    }
+
+    fn collect_references(value: &serde_json::Value, references: &mut Vec<String>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map {
+                    match (key.as_str(), value) {
+                        ("$ref", serde_json::Value::String(reference)) => references.push(reference.clone()),
+                        _ => collect_references(value, references),
+                    }
+                }
+            }
+            serde_json::Value::Array(values) => values.iter().for_each(|v| collect_references(v, references)),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! the problem detail responses shared by the api operations
+
+use crate::web::problem::ProblemDetail;
+use utoipa::ToResponse;
+
+/// the request was not well formed
+#[derive(ToResponse)]
+#[response(content_type = "application/problem+json")]
+pub struct BadRequest(pub ProblemDetail);
+
+/// the request is missing valid admin credentials
+#[derive(ToResponse)]
+#[response(content_type = "application/problem+json")]
+pub struct Unauthorized(pub ProblemDetail);
+
+/// the resource was not found
+#[derive(ToResponse)]
+#[response(content_type = "application/problem+json")]
+pub struct NotFound(pub ProblemDetail);
+
+/// the resource has been deleted
+#[derive(ToResponse)]
+#[response(content_type = "application/problem+json")]
+pub struct Gone(pub ProblemDetail);
+
+/// the request conflicts with the resource state
+#[derive(ToResponse)]
+#[response(content_type = "application/problem+json")]
+pub struct Conflict(pub ProblemDetail);
+
+/// the resource already exists
+#[derive(ToResponse)]
+#[response(content_type = "application/problem+json")]
+pub struct AlreadyExists(pub ProblemDetail);
+
+/// the request references resources that do not exist
+#[derive(ToResponse)]
+#[response(content_type = "application/problem+json")]
+pub struct UnprocessableEntity(pub ProblemDetail);
+
+/// the request payload is over the size limit
+#[derive(ToResponse)]
+#[response(content_type = "application/problem+json")]
+pub struct PayloadTooLarge(pub ProblemDetail);
+
+/// the request payload media type is not supported
+#[derive(ToResponse)]
+#[response(content_type = "application/problem+json")]
+pub struct UnsupportedMediaType(pub ProblemDetail);
+
+/// an error has occurred
+#[derive(ToResponse)]
+#[response(content_type = "application/problem+json")]
+pub struct InternalServerError(pub ProblemDetail);
@@ -0,0 +1,10 @@
+use crate::openapi::handlers;
+use crate::state::AppState;
+use axum::routing::get;
+use axum::Router;
+
+pub const OPENAPI_API: &str = "/api/openapi.json";
+
+pub fn openapi_router() -> Router<AppState> {
+    Router::new().route(OPENAPI_API, get(handlers::get_openapi))
+}
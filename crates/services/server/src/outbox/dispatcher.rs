@@ -0,0 +1,95 @@
+//! the outbox dispatcher background task
+
+use catalog::events::dispatch_events::dispatch_pending_events;
+use catalog::events::event_sink::EventSink;
+use catalog::events::retry_policy::RetryPolicy;
+use common::unit_of_work::postgres::PgDatabase;
+use configuration::OutboxSettings;
+use data::events::repositories::OutboxEventsRepository;
+use sqlx::PgPool;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// It periodically publishes the pending events from the outbox to the event sink.
+///
+/// The pending events are claimed before they are dispatched (`FOR UPDATE SKIP LOCKED`), therefore more
+/// server instances can run their dispatchers against the same database: each event is published by one of them.
+pub struct OutboxDispatcher {
+    pg_pool: Arc<PgPool>,
+    sink: Arc<dyn EventSink>,
+    retry_policy: RetryPolicy,
+    settings: OutboxSettings,
+}
+
+impl OutboxDispatcher {
+    /// Creates a new outbox dispatcher
+    pub fn new(pg_pool: Arc<PgPool>, sink: Arc<dyn EventSink>, settings: &OutboxSettings) -> Self {
+        let retry_policy = RetryPolicy::new(
+            settings.max_attempts,
+            chrono::Duration::milliseconds(settings.initial_backoff_ms as i64),
+            chrono::Duration::milliseconds(settings.max_backoff_ms as i64),
+        );
+        OutboxDispatcher {
+            pg_pool,
+            sink,
+            retry_policy,
+            settings: settings.clone(),
+        }
+    }
+
+    /// Starts the dispatcher as a background task
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move { self.run().await })
+    }
+
+    async fn run(&self) {
+        tracing::info!(sink = self.sink.name(), "Starting the outbox dispatcher...");
+
+        let mut interval = tokio::time::interval(self.settings.poll_interval());
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            // keep going without waiting while the outbox has a backlog of pending events
+            while self.dispatch_batch().await >= self.settings.batch_size as usize {}
+        }
+    }
+
+    async fn dispatch_batch(&self) -> usize {
+        let database = PgDatabase::new(&self.pg_pool);
+        let result = dispatch_pending_events(
+            self.settings.batch_size,
+            &self.retry_policy,
+            chrono::Duration::milliseconds(self.settings.claim_timeout_ms as i64),
+            self.sink.as_ref(),
+            OutboxEventsRepository,
+            database,
+        )
+        .await;
+
+        match result {
+            Ok(outcome) => {
+                if outcome.dispatched > 0 || outcome.failed > 0 {
+                    tracing::debug!(
+                        dispatched = outcome.dispatched,
+                        failed = outcome.failed,
+                        "outbox events dispatched"
+                    );
+                }
+                if outcome.dead_lettered > 0 {
+                    tracing::warn!(
+                        dead_lettered = outcome.dead_lettered,
+                        "outbox events dead lettered, they ran out of attempts"
+                    );
+                }
+                outcome.dispatched + outcome.failed + outcome.dead_lettered
+            }
+            Err(why) => {
+                tracing::error!("unable to dispatch the outbox events: {why:#}");
+                0
+            }
+        }
+    }
+}
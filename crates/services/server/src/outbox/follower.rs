@@ -0,0 +1,96 @@
+//! the outbox follower background task, which feeds the local event sinks on every server instance
+
+use catalog::events::event_sink::EventSink;
+use catalog::events::follow_events::{feed_start_position, follow_events};
+use catalog::events::repositories::FeedPosition;
+use common::unit_of_work::postgres::PgDatabase;
+use configuration::EventStreamSettings;
+use data::events::repositories::OutboxEventsRepository;
+use sqlx::PgPool;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// It periodically reads the new events from the outbox, and publishes them to the local sinks.
+///
+/// Unlike the outbox dispatcher, every server instance runs its own follower with its own position in the
+/// outbox: the sinks which are local to the instance (ie the event stream clients connected to it) receive
+/// every event, no matter which instance stored or dispatched it. The follower starts after the last event
+/// stored before the server was started.
+pub struct OutboxFollower {
+    pg_pool: Arc<PgPool>,
+    sink: Arc<dyn EventSink>,
+    settings: EventStreamSettings,
+}
+
+impl OutboxFollower {
+    /// Creates a new outbox follower
+    pub fn new(pg_pool: Arc<PgPool>, sink: Arc<dyn EventSink>, settings: &EventStreamSettings) -> Self {
+        OutboxFollower {
+            pg_pool,
+            sink,
+            settings: settings.clone(),
+        }
+    }
+
+    /// Starts the follower as a background task
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move { self.run().await })
+    }
+
+    async fn run(&self) {
+        tracing::info!(sink = self.sink.name(), "Starting the outbox follower...");
+
+        let mut interval = tokio::time::interval(self.settings.poll_interval());
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut position = loop {
+            interval.tick().await;
+            let database = PgDatabase::new(&self.pg_pool);
+            match feed_start_position(OutboxEventsRepository, database).await {
+                Ok(position) => break position,
+                Err(why) => tracing::error!("unable to read the outbox feed position: {why:#}"),
+            }
+        };
+
+        loop {
+            interval.tick().await;
+
+            // keep going without waiting while there are more new events
+            while let Some(followed) = self.follow_batch(&mut position).await {
+                if followed < self.settings.batch_size as usize {
+                    break;
+                }
+            }
+        }
+    }
+
+    async fn follow_batch(&self, position: &mut Option<FeedPosition>) -> Option<usize> {
+        let database = PgDatabase::new(&self.pg_pool);
+        let result = follow_events(
+            *position,
+            self.settings.batch_size,
+            self.sink.as_ref(),
+            OutboxEventsRepository,
+            database,
+        )
+        .await;
+
+        match result {
+            Ok(outcome) => {
+                if outcome.failed > 0 {
+                    tracing::warn!(
+                        failed = outcome.failed,
+                        "unable to publish the outbox events to the local sinks"
+                    );
+                }
+                *position = outcome.position;
+                Some(outcome.len())
+            }
+            Err(why) => {
+                tracing::error!("unable to follow the outbox events: {why:#}");
+                None
+            }
+        }
+    }
+}
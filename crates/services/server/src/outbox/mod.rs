@@ -0,0 +1,7 @@
+//! the background tasks for the domain events stored in the outbox: the dispatcher, which publishes each
+//! event once to the external sinks, and the follower, which publishes every event to the local sinks
+//! on each server instance
+
+pub mod dispatcher;
+pub mod follower;
+pub mod sinks;
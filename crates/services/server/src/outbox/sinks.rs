@@ -0,0 +1,171 @@
+//! the event sinks available to the outbox dispatcher and follower
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use catalog::events::domain_event::EventEnvelope;
+use catalog::events::event_sink::EventSink;
+use std::sync::Arc;
+
+/// An event sink which writes the domain events to the application log
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LoggingSink;
+
+#[async_trait]
+impl EventSink for LoggingSink {
+    fn name(&self) -> &str {
+        "logging"
+    }
+
+    async fn publish(&self, envelope: &EventEnvelope) -> Result<(), anyhow::Error> {
+        tracing::info!(
+            event_id = %envelope.event_id,
+            event_type = envelope.event.event_type(),
+            aggregate_type = %envelope.event.aggregate_type(),
+            aggregate_id = envelope.event.aggregate_id(),
+            occurred_at = %envelope.occurred_at,
+            "domain event published"
+        );
+        Ok(())
+    }
+}
+
+/// An event sink which publishes each event to all the registered sinks.
+///
+/// Publishing fails when any of the sinks fails, in that case the event is dispatched again later
+/// to all the sinks (including the ones which already received it).
+#[derive(Clone, Default)]
+pub struct EventSinks {
+    sinks: Vec<Arc<dyn EventSink>>,
+}
+
+impl EventSinks {
+    /// Creates a new empty list of sinks
+    pub fn new() -> Self {
+        EventSinks::default()
+    }
+
+    /// Registers a new sink
+    pub fn with_sink<S: EventSink + 'static>(mut self, sink: S) -> Self {
+        self.sinks.push(Arc::new(sink));
+        self
+    }
+
+    /// Returns the number of registered sinks
+    pub fn len(&self) -> usize {
+        self.sinks.len()
+    }
+
+    /// Returns `true` when no sinks are registered
+    pub fn is_empty(&self) -> bool {
+        self.sinks.is_empty()
+    }
+}
+
+#[async_trait]
+impl EventSink for EventSinks {
+    fn name(&self) -> &str {
+        "sinks"
+    }
+
+    async fn publish(&self, envelope: &EventEnvelope) -> Result<(), anyhow::Error> {
+        let mut failed = Vec::new();
+        for sink in self.sinks.iter() {
+            if let Err(why) = sink.publish(envelope).await {
+                tracing::warn!(event_id = %envelope.event_id, sink = sink.name(), "unable to publish the event: {why:#}");
+                failed.push(sink.name().to_owned());
+            }
+        }
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!("unable to publish the event to: {}", failed.join(", ")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod event_sinks {
+        use super::*;
+        use catalog::brands::brand_id::BrandId;
+        use catalog::brands::brand_response::BrandCreated;
+        use catalog::events::domain_event::DomainEvent;
+        use chrono::Utc;
+        use pretty_assertions::assert_eq;
+        use std::sync::Mutex;
+
+        #[tokio::test]
+        async fn it_should_publish_the_events_to_all_the_sinks() {
+            let first = RecordingSink::new("first", false);
+            let second = RecordingSink::new("second", false);
+            let sinks = EventSinks::new().with_sink(first.clone()).with_sink(second.clone());
+
+            let envelope = new_envelope();
+            let result = sinks.publish(&envelope).await;
+
+            assert!(result.is_ok());
+            assert_eq!(vec![envelope.clone()], first.events());
+            assert_eq!(vec![envelope], second.events());
+        }
+
+        #[tokio::test]
+        async fn it_should_fail_when_any_of_the_sinks_fails() {
+            let first = RecordingSink::new("first", true);
+            let second = RecordingSink::new("second", false);
+            let sinks = EventSinks::new().with_sink(first).with_sink(second.clone());
+
+            let envelope = new_envelope();
+            let result = sinks.publish(&envelope).await;
+
+            let error = result.expect_err("result is not an error");
+            assert_eq!("unable to publish the event to: first", error.to_string());
+            assert_eq!(vec![envelope], second.events());
+        }
+
+        fn new_envelope() -> EventEnvelope {
+            EventEnvelope::new(DomainEvent::BrandCreated(BrandCreated {
+                brand_id: BrandId::new("ACME"),
+                created_at: Utc::now(),
+            }))
+        }
+
+        #[derive(Clone)]
+        struct RecordingSink {
+            name: &'static str,
+            failing: bool,
+            events: Arc<Mutex<Vec<EventEnvelope>>>,
+        }
+
+        impl RecordingSink {
+            fn new(name: &'static str, failing: bool) -> Self {
+                RecordingSink {
+                    name,
+                    failing,
+                    events: Arc::new(Mutex::new(Vec::new())),
+                }
+            }
+
+            fn events(&self) -> Vec<EventEnvelope> {
+                self.events.lock().unwrap().clone()
+            }
+        }
+
+        #[async_trait]
+        impl EventSink for RecordingSink {
+            fn name(&self) -> &str {
+                self.name
+            }
+
+            async fn publish(&self, envelope: &EventEnvelope) -> Result<(), anyhow::Error> {
+                if self.failing {
+                    return Err(anyhow!("boom"));
+                }
+                self.events.lock().unwrap().push(envelope.clone());
+                Ok(())
+            }
+        }
+    }
+}
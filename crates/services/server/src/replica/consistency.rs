@@ -0,0 +1,201 @@
+use async_trait::async_trait;
+use axum::extract::{FromRequestParts, Request, State};
+use axum::http::header::{COOKIE, SET_COOKIE};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, HeaderValue};
+use axum::middleware::Next;
+use axum::response::Response;
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::time::Duration;
+use utoipa::ToSchema;
+
+/// The request header to choose the read consistency
+pub const READ_CONSISTENCY_HEADER: &str = "x-read-consistency";
+
+/// The cookie set after a write, the queries from the same client are using the primary until it expires
+pub const READ_PRIMARY_COOKIE: &str = "trenako-read-primary";
+
+/// The consistency required by a read request.
+///
+/// The replica is lagging behind the primary, a client that just wrote something and needs to read it back
+/// can ask for a `strong` consistency: its queries are going to use the primary. Without the header, the
+/// queries are using the primary for a short time after the client wrote something (the read primary cookie).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ReadConsistency {
+    /// the query can use the read replica, and the results could be slightly out of date
+    #[default]
+    Eventual,
+    /// the query is using the primary (read-your-writes)
+    Strong,
+}
+
+impl ReadConsistency {
+    /// Parses the header value, anything but `strong` is an eventual consistency
+    pub fn from_header_value(value: &str) -> Self {
+        if value.trim().eq_ignore_ascii_case("strong") {
+            ReadConsistency::Strong
+        } else {
+            ReadConsistency::Eventual
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ReadConsistency
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let consistency = parts
+            .headers
+            .get(READ_CONSISTENCY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(ReadConsistency::from_header_value);
+        let consistency = match consistency {
+            Some(consistency) => consistency,
+            None if has_read_primary_cookie(&parts.headers) => ReadConsistency::Strong,
+            None => ReadConsistency::Eventual,
+        };
+        Ok(consistency)
+    }
+}
+
+/// The response extension for the requests which are not writing anything, even though their method is
+/// not a safe one (ie the GraphQL queries)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ReadOnly;
+
+/// A middleware to pin the queries from a client to the primary after a successful write: the response
+/// sets the read primary cookie, which expires after `pin_after_write`
+pub async fn pin_reads_after_writes(State(pin_after_write): State<Duration>, request: Request, next: Next) -> Response {
+    let is_write = !request.method().is_safe();
+    let mut response = next.run(request).await;
+
+    let is_read_only = response.extensions().get::<ReadOnly>().is_some();
+    if is_write && !is_read_only && response.status().is_success() {
+        let max_age = pin_after_write.as_millis().div_ceil(1000).max(1);
+        let cookie = format!("{READ_PRIMARY_COOKIE}=1; Max-Age={max_age}; Path=/; HttpOnly; SameSite=Lax");
+        if let Ok(cookie) = HeaderValue::from_str(&cookie) {
+            response.headers_mut().append(SET_COOKIE, cookie);
+        }
+    }
+    response
+}
+
+fn has_read_primary_cookie(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .any(|cookie| cookie.trim().split_once('=').map(|(name, _)| name) == Some(READ_PRIMARY_COOKIE))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use axum::http::Request;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Some("strong"), ReadConsistency::Strong)]
+    #[case(Some("STRONG"), ReadConsistency::Strong)]
+    #[case(Some("eventual"), ReadConsistency::Eventual)]
+    #[case(Some("whatever"), ReadConsistency::Eventual)]
+    #[case(None, ReadConsistency::Eventual)]
+    #[tokio::test]
+    async fn it_should_extract_the_read_consistency(#[case] header: Option<&str>, #[case] expected: ReadConsistency) {
+        let mut request = Request::get("/api/brands");
+        if let Some(header) = header {
+            request = request.header(READ_CONSISTENCY_HEADER, header);
+        }
+        let (mut parts, _) = request.body(()).unwrap().into_parts();
+
+        let consistency = ReadConsistency::from_request_parts(&mut parts, &()).await.unwrap();
+        assert_eq!(expected, consistency);
+    }
+
+    #[rstest]
+    #[case(None, Some("theme=dark; trenako-read-primary=1"), ReadConsistency::Strong)]
+    #[case(Some("eventual"), Some("trenako-read-primary=1"), ReadConsistency::Eventual)]
+    #[case(None, Some("trenako-read-primary-other=1"), ReadConsistency::Eventual)]
+    #[case(None, None, ReadConsistency::Eventual)]
+    #[tokio::test]
+    async fn it_should_read_from_the_primary_after_a_write(
+        #[case] header: Option<&str>,
+        #[case] cookie: Option<&str>,
+        #[case] expected: ReadConsistency,
+    ) {
+        let mut request = Request::get("/api/brands");
+        if let Some(header) = header {
+            request = request.header(READ_CONSISTENCY_HEADER, header);
+        }
+        if let Some(cookie) = cookie {
+            request = request.header(COOKIE, cookie);
+        }
+        let (mut parts, _) = request.body(()).unwrap().into_parts();
+
+        let consistency = ReadConsistency::from_request_parts(&mut parts, &()).await.unwrap();
+        assert_eq!(expected, consistency);
+    }
+
+    mod pin_reads_after_writes_middleware {
+        use super::*;
+        use axum::body::Body;
+        use axum::http::StatusCode;
+        use axum::middleware;
+        use axum::routing::post;
+        use axum::{Extension, Router};
+        use tower::ServiceExt;
+
+        #[rstest]
+        #[case(
+            "/write",
+            StatusCode::CREATED,
+            Some("trenako-read-primary=1; Max-Age=5; Path=/; HttpOnly; SameSite=Lax")
+        )]
+        #[case("/failed", StatusCode::BAD_REQUEST, None)]
+        #[case("/query", StatusCode::OK, None)]
+        #[tokio::test]
+        async fn it_should_set_the_read_primary_cookie_after_the_writes(
+            #[case] path: &str,
+            #[case] expected_status: StatusCode,
+            #[case] expected: Option<&str>,
+        ) {
+            let router = Router::new()
+                .route("/write", post(|| async { StatusCode::CREATED }))
+                .route("/failed", post(|| async { StatusCode::BAD_REQUEST }))
+                .route("/query", post(|| async { (Extension(ReadOnly), StatusCode::OK) }))
+                .layer(middleware::from_fn_with_state(
+                    Duration::from_millis(4_500),
+                    pin_reads_after_writes,
+                ));
+
+            let request = Request::post(path).body(Body::empty()).unwrap();
+            let response = router.oneshot(request).await.unwrap();
+
+            assert_eq!(expected_status, response.status());
+            let cookie = response.headers().get(SET_COOKIE).map(|it| it.to_str().unwrap());
+            assert_eq!(expected, cookie);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_set_the_read_primary_cookie_for_the_safe_methods() {
+            let router = Router::new()
+                .route("/read", axum::routing::get(|| async { StatusCode::OK }))
+                .layer(middleware::from_fn_with_state(
+                    Duration::from_secs(5),
+                    pin_reads_after_writes,
+                ));
+
+            let request = Request::get("/read").body(Body::empty()).unwrap();
+            let response = router.oneshot(request).await.unwrap();
+
+            assert!(response.headers().get(SET_COOKIE).is_none());
+        }
+    }
+}
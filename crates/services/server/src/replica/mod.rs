@@ -0,0 +1,40 @@
+//! The read replica routing: the queries are using the replica, when configured and healthy, while the
+//! commands are always using the primary.
+pub mod consistency;
+pub mod monitor;
+
+use sqlx::PgPool;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A read replica connection pool, together with its last known health status
+#[derive(Debug, Clone)]
+pub struct ReplicaPool {
+    pg_pool: Arc<PgPool>,
+    healthy: Arc<AtomicBool>,
+}
+
+impl ReplicaPool {
+    /// Creates a new replica pool, the replica is considered unhealthy until the first successful check
+    pub fn new(pg_pool: PgPool) -> Self {
+        ReplicaPool {
+            pg_pool: Arc::new(pg_pool),
+            healthy: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Returns the replica connection pool
+    pub fn pg_pool(&self) -> &Arc<PgPool> {
+        &self.pg_pool
+    }
+
+    /// Returns `true` when the last health check was successful
+    pub fn is_healthy(&self) -> bool {
+        self.healthy.load(Ordering::Relaxed)
+    }
+
+    /// Records the outcome for the last health check, returning the previous status
+    pub fn set_healthy(&self, healthy: bool) -> bool {
+        self.healthy.swap(healthy, Ordering::Relaxed)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::replica::ReplicaPool;
+use configuration::ReplicaSettings;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// It periodically checks the read replica, the queries fall back to the primary while the replica
+/// is not healthy.
+pub struct ReplicaMonitor {
+    replica: ReplicaPool,
+    interval: Duration,
+}
+
+impl ReplicaMonitor {
+    /// Creates a new replica monitor
+    pub fn new(replica: ReplicaPool, settings: &ReplicaSettings) -> Self {
+        ReplicaMonitor {
+            replica,
+            interval: settings.health_check_interval(),
+        }
+    }
+
+    /// Starts the monitor as a background task
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move { self.run().await })
+    }
+
+    async fn run(&self) {
+        tracing::info!("Starting the read replica monitor...");
+
+        let mut interval = tokio::time::interval(self.interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            self.check().await;
+        }
+    }
+
+    /// Checks the replica, recording the outcome
+    pub async fn check(&self) -> bool {
+        let result = sqlx::query("SELECT 1").execute(self.replica.pg_pool().as_ref()).await;
+        let healthy = result.is_ok();
+        let was_healthy = self.replica.set_healthy(healthy);
+
+        match result {
+            Err(why) if was_healthy => {
+                tracing::warn!("the read replica is not available, the queries are using the primary: {why}")
+            }
+            Ok(_) if !was_healthy => tracing::info!("the read replica is available"),
+            _ => {}
+        }
+        healthy
+    }
+}
@@ -0,0 +1,119 @@
+//! the module includes the graceful shutdown of the web server
+
+use std::future::Future;
+use std::time::Duration;
+use tokio::signal;
+use tokio::sync::watch;
+
+/// Waits for a shutdown signal, either SIGINT (Ctrl+C) or SIGTERM
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c().await.expect("Failed to install the Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("Failed to install the SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
+
+/// It coordinates the graceful shutdown: once the signal is received the server stops accepting
+/// new connections, and it has at most the drain timeout to complete the in-flight requests
+#[derive(Debug)]
+pub struct GracefulShutdown {
+    drain_timeout: Duration,
+    sender: watch::Sender<bool>,
+    receiver: watch::Receiver<bool>,
+}
+
+impl GracefulShutdown {
+    /// Creates a new graceful shutdown, with the given drain timeout
+    pub fn new(drain_timeout: Duration) -> Self {
+        let (sender, receiver) = watch::channel(false);
+        GracefulShutdown {
+            drain_timeout,
+            sender,
+            receiver,
+        }
+    }
+
+    /// Returns a future completing when the signal is received, to be passed to the server
+    pub fn on_signal<S>(&self, signal: S) -> impl Future<Output = ()> + Send + 'static
+    where
+        S: Future<Output = ()> + Send + 'static,
+    {
+        let sender = self.sender.clone();
+        async move {
+            signal.await;
+            tracing::info!("Shutdown signal received, draining the open connections...");
+            let _ = sender.send(true);
+        }
+    }
+
+    /// Returns a future completing when the signal is received, for the other listeners to stop as well
+    pub fn signalled(&self) -> impl Future<Output = ()> + Send + 'static {
+        let mut receiver = self.receiver.clone();
+        async move {
+            if receiver.wait_for(|received| *received).await.is_err() {
+                std::future::pending::<()>().await;
+            }
+        }
+    }
+
+    /// Returns a future completing when the drain timeout has expired, after the signal was received
+    pub fn drain_expired(&self) -> impl Future<Output = ()> + Send + 'static {
+        let signalled = self.signalled();
+        let drain_timeout = self.drain_timeout;
+        async move {
+            signalled.await;
+            tokio::time::sleep(drain_timeout).await;
+        }
+    }
+
+    /// Returns the max time to drain the open connections
+    pub fn drain_timeout(&self) -> Duration {
+        self.drain_timeout
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod graceful_shutdown {
+        use super::*;
+        use std::time::Instant;
+
+        #[tokio::test]
+        async fn it_should_expire_the_drain_timeout_only_after_the_signal() {
+            let shutdown = GracefulShutdown::new(Duration::from_millis(50));
+            let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+
+            let on_signal = tokio::spawn(shutdown.on_signal(async move {
+                let _ = rx.await;
+            }));
+            let drain_expired = tokio::spawn(shutdown.drain_expired());
+
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            assert!(!drain_expired.is_finished());
+
+            let started = Instant::now();
+            tx.send(()).unwrap();
+            on_signal.await.unwrap();
+            drain_expired.await.unwrap();
+
+            assert!(started.elapsed() >= Duration::from_millis(50));
+        }
+    }
+}
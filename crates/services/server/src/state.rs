@@ -1,20 +1,140 @@
-use common::unit_of_work::postgres::PgDatabase;
-use configuration::Settings;
+use crate::events::broadcaster::EventBroadcaster;
+use crate::metrics::database::MeteredDatabase;
+use crate::metrics::recorder::Metrics;
+use crate::replica::consistency::ReadConsistency;
+use crate::replica::ReplicaPool;
+use crate::storage::ImageStore;
+use configuration::{CacheSettings, CalendarSettings, Settings};
+use data::cache::reference_data::ReferenceDataCache;
+use data::cache::repositories::CachedRepository;
+use secrecy::SecretString;
 use sqlx::PgPool;
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct AppState {
     pub pg_pool: Arc<PgPool>,
+    pub event_broadcaster: EventBroadcaster,
+    pub metrics: Metrics,
+    pub replica: Option<ReplicaPool>,
+    pub reference_data: ReferenceDataCache,
+    pub images: ImageStore,
+    pub admin_api_key: Option<SecretString>,
+    /// the public base url for the absolute urls in the responses
+    pub public_url: Option<String>,
+    /// the release calendar settings
+    pub calendar: CalendarSettings,
 }
 
 impl AppState {
     pub fn from_settings(settings: &Settings) -> Self {
         let pg_pool = Arc::new(settings.database.get_connection_pool());
-        AppState { pg_pool }
+        let event_broadcaster = EventBroadcaster::new(&settings.event_stream);
+        AppState {
+            pg_pool,
+            event_broadcaster,
+            metrics: Metrics::new(),
+            replica: settings.database.get_replica_connection_pool().map(ReplicaPool::new),
+            reference_data: reference_data_cache(&settings.cache),
+            images: ImageStore::from_settings(&settings.images),
+            admin_api_key: settings.admin.api_key.clone(),
+            public_url: settings.server.public_url.clone(),
+            calendar: settings.calendar.clone(),
+        }
     }
 
-    pub fn get_database(&self) -> PgDatabase {
-        PgDatabase::new(&self.pg_pool)
+    /// Wraps the repository with the reference data cache
+    pub fn cached<R>(&self, repo: R) -> CachedRepository<R> {
+        CachedRepository::new(repo, &self.reference_data)
+    }
+
+    pub fn get_database(&self) -> MeteredDatabase<'_> {
+        MeteredDatabase::new(&self.pg_pool, &self.metrics)
+    }
+
+    /// Returns the database for the queries: the read replica, unless the request needs a strong
+    /// consistency or the replica is not healthy
+    pub fn get_read_database(&self, consistency: ReadConsistency) -> MeteredDatabase<'_> {
+        MeteredDatabase::new(self.read_pool(consistency), &self.metrics)
+    }
+
+    /// Returns the connection pool for the queries
+    pub fn read_pool(&self, consistency: ReadConsistency) -> &Arc<PgPool> {
+        match &self.replica {
+            Some(replica) if consistency == ReadConsistency::Eventual && replica.is_healthy() => replica.pg_pool(),
+            _ => &self.pg_pool,
+        }
+    }
+}
+
+fn reference_data_cache(settings: &CacheSettings) -> ReferenceDataCache {
+    if settings.enabled {
+        ReferenceDataCache::new(settings.ttl(), settings.max_entries)
+    } else {
+        ReferenceDataCache::disabled()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use configuration::{
+        AdminSettings, AvailabilityReviewSettings, CacheSettings, CalendarSettings, DatabaseSettings,
+        EventStreamSettings, ImagesSettings, LoggingFormat, LoggingLevel, LoggingSettings, ManagementSettings,
+        OutboxSettings, ReplicaSettings, ServerSettings, WebhooksSettings,
+    };
+    use sqlx::ConnectOptions;
+
+    fn app_state(replica: Option<ReplicaSettings>) -> AppState {
+        let mut database = DatabaseSettings::new("postgres", "pa$$word", "primary-host", 5432, "database-name");
+        database.replica = replica;
+        AppState::from_settings(&Settings {
+            database,
+            server: ServerSettings::default(),
+            logging: LoggingSettings {
+                level: LoggingLevel::Debug,
+                format: LoggingFormat::Compact,
+            },
+            outbox: OutboxSettings::default(),
+            webhooks: WebhooksSettings::default(),
+            event_stream: EventStreamSettings::default(),
+            management: ManagementSettings::default(),
+            cache: CacheSettings::default(),
+            images: ImagesSettings::default(),
+            availability_review: AvailabilityReviewSettings::default(),
+            admin: AdminSettings::default(),
+            calendar: CalendarSettings::default(),
+        })
+    }
+
+    fn host(pg_pool: &PgPool) -> String {
+        pg_pool
+            .connect_options()
+            .to_url_lossy()
+            .host_str()
+            .unwrap_or_default()
+            .to_owned()
+    }
+
+    #[tokio::test]
+    async fn it_should_use_the_primary_when_there_is_no_replica() {
+        let app_state = app_state(None);
+
+        assert_eq!("primary-host", host(app_state.read_pool(ReadConsistency::Eventual)));
+    }
+
+    #[tokio::test]
+    async fn it_should_use_the_healthy_replica_for_eventually_consistent_reads() {
+        let app_state = app_state(Some(ReplicaSettings::new("replica-host", 5433)));
+        let replica = app_state.replica.as_ref().unwrap();
+
+        assert_eq!("primary-host", host(app_state.read_pool(ReadConsistency::Eventual)));
+
+        replica.set_healthy(true);
+        assert_eq!("replica-host", host(app_state.read_pool(ReadConsistency::Eventual)));
+        assert_eq!("primary-host", host(app_state.read_pool(ReadConsistency::Strong)));
+
+        replica.set_healthy(false);
+        assert_eq!("primary-host", host(app_state.read_pool(ReadConsistency::Eventual)));
     }
 }
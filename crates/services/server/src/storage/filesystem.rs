@@ -0,0 +1,116 @@
+//! the local filesystem storage for the images
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use catalog::images::image_storage::ImageStorage;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+use uuid::Uuid;
+
+/// It stores the images as files under a root directory, the storage keys are relative paths
+#[derive(Debug, Clone)]
+pub struct FilesystemImageStorage {
+    root: PathBuf,
+}
+
+impl FilesystemImageStorage {
+    /// Creates a new filesystem storage, the root directory is created on the first upload
+    pub fn new(root: &Path) -> Self {
+        FilesystemImageStorage { root: root.to_owned() }
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf, anyhow::Error> {
+        let key = Path::new(key);
+        if key.components().all(|it| matches!(it, Component::Normal(_))) {
+            Ok(self.root.join(key))
+        } else {
+            Err(anyhow!("invalid storage key: {}", key.display()))
+        }
+    }
+}
+
+#[async_trait]
+impl ImageStorage for FilesystemImageStorage {
+    async fn put(&self, key: &str, content: &[u8], _content_type: &str) -> Result<(), anyhow::Error> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Unable to create the directory {}", parent.display()))?;
+        }
+
+        // the content is written to a temporary file first, the readers never see a partial image
+        let temp_path = path.with_extension(format!("{}.tmp", Uuid::new_v4()));
+        tokio::fs::write(&temp_path, content)
+            .await
+            .with_context(|| format!("Unable to write the image {}", temp_path.display()))?;
+        if let Err(why) = tokio::fs::rename(&temp_path, &path).await {
+            let _ = tokio::fs::remove_file(&temp_path).await;
+            return Err(anyhow!(why).context(format!("Unable to write the image {}", path.display())));
+        }
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, anyhow::Error> {
+        let path = self.path(key)?;
+        match tokio::fs::read(&path).await {
+            Ok(content) => Ok(Some(content)),
+            Err(why) if why.kind() == ErrorKind::NotFound => Ok(None),
+            Err(why) => Err(anyhow!(why).context(format!("Unable to read the image {}", path.display()))),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), anyhow::Error> {
+        let path = self.path(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(_) => Ok(()),
+            Err(why) if why.kind() == ErrorKind::NotFound => Ok(()),
+            Err(why) => Err(anyhow!(why).context(format!("Unable to delete the image {}", path.display()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod filesystem_image_storage {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[tokio::test]
+        async fn it_should_store_the_images() {
+            let root = std::env::temp_dir().join(format!("trenako-images-{}", Uuid::new_v4()));
+            let storage = FilesystemImageStorage::new(&root);
+            let key = "catalog-items/acme-60142/images/image-1";
+
+            assert_eq!(None, storage.get(key).await.unwrap());
+
+            storage.put(key, b"content", "image/png").await.unwrap();
+            assert_eq!(Some(b"content".to_vec()), storage.get(key).await.unwrap());
+
+            storage.put(key, b"new content", "image/png").await.unwrap();
+            assert_eq!(Some(b"new content".to_vec()), storage.get(key).await.unwrap());
+
+            storage.delete(key).await.unwrap();
+            assert_eq!(None, storage.get(key).await.unwrap());
+            assert!(storage.delete(key).await.is_ok());
+
+            let _ = std::fs::remove_dir_all(root);
+        }
+
+        #[test]
+        fn it_should_reject_the_keys_outside_the_root_directory() {
+            let storage = FilesystemImageStorage::new(Path::new("/var/images"));
+
+            assert_eq!(
+                PathBuf::from("/var/images/catalog-items/acme/image"),
+                storage.path("catalog-items/acme/image").unwrap()
+            );
+            assert!(storage.path("../etc/passwd").is_err());
+            assert!(storage.path("catalog-items/../../etc/passwd").is_err());
+            assert!(storage.path("/etc/passwd").is_err());
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! the module includes the storages for the catalog item images content
+
+pub mod filesystem;
+pub mod s3;
+pub mod sigv4;
+
+use crate::storage::filesystem::FilesystemImageStorage;
+use crate::storage::s3::S3ImageStorage;
+use catalog::images::commands::upload_image::ImageLimits;
+use catalog::images::image_storage::ImageStorage;
+use configuration::{ImageStorageSettings, ImagesSettings};
+use std::fmt;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+/// The images storage, together with the limits for the uploads
+#[derive(Clone)]
+pub struct ImageStore {
+    storage: Arc<dyn ImageStorage>,
+    limits: ImageLimits,
+}
+
+impl ImageStore {
+    /// Creates a new image store from the settings
+    pub fn from_settings(settings: &ImagesSettings) -> Self {
+        let storage: Arc<dyn ImageStorage> = match &settings.storage {
+            ImageStorageSettings::Filesystem { root } => Arc::new(FilesystemImageStorage::new(root)),
+            ImageStorageSettings::S3(s3) => Arc::new(S3ImageStorage::new(s3)),
+        };
+        ImageStore::new(storage, settings)
+    }
+
+    /// Creates a new image store for the given storage
+    pub fn new(storage: Arc<dyn ImageStorage>, settings: &ImagesSettings) -> Self {
+        ImageStore {
+            storage,
+            limits: ImageLimits {
+                max_size_bytes: settings.max_size_bytes,
+                max_images: settings.max_images,
+            },
+        }
+    }
+
+    /// Returns the storage for the images content
+    pub fn storage(&self) -> &dyn ImageStorage {
+        self.storage.as_ref()
+    }
+
+    /// Returns the limits for the uploads
+    pub fn limits(&self) -> &ImageLimits {
+        &self.limits
+    }
+}
+
+impl fmt::Debug for ImageStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImageStore").field("limits", &self.limits).finish()
+    }
+}
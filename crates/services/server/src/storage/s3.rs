@@ -0,0 +1,190 @@
+//! the S3 compatible object storage for the images
+
+use crate::storage::sigv4;
+use crate::storage::sigv4::Credentials;
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use catalog::images::image_storage::ImageStorage;
+use chrono::Utc;
+use configuration::S3Settings;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use reqwest::{Method, Response, StatusCode};
+use secrecy::ExposeSecret;
+use std::time::Duration;
+use url::Url;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+const AMZ_DATE: &str = "x-amz-date";
+const AMZ_CONTENT_SHA256: &str = "x-amz-content-sha256";
+
+/// It stores the images as objects in a S3 compatible bucket (AWS S3, MinIO...)
+#[derive(Debug, Clone)]
+pub struct S3ImageStorage {
+    client: reqwest::Client,
+    settings: S3Settings,
+}
+
+impl S3ImageStorage {
+    /// Creates a new S3 storage
+    pub fn new(settings: &S3Settings) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("Failed to build the S3 storage HTTP client");
+        S3ImageStorage {
+            client,
+            settings: settings.clone(),
+        }
+    }
+
+    /// Creates the bucket, it does nothing when the bucket already exists
+    pub async fn create_bucket(&self) -> Result<(), anyhow::Error> {
+        let url = self.url(None)?;
+        let response = self.send(Method::PUT, url, Vec::new(), None).await?;
+        match response.status() {
+            status if status.is_success() => Ok(()),
+            StatusCode::CONFLICT => Ok(()),
+            status => Err(anyhow!(
+                "Unable to create the bucket {} ({status})",
+                self.settings.bucket
+            )),
+        }
+    }
+
+    fn url(&self, key: Option<&str>) -> Result<Url, anyhow::Error> {
+        let mut url = Url::parse(&self.settings.endpoint).context("Invalid S3 endpoint")?;
+        let key = key.unwrap_or_default();
+        if self.settings.path_style {
+            url.set_path(&format!("{}/{key}", self.settings.bucket));
+        } else {
+            let host = url
+                .host_str()
+                .ok_or_else(|| anyhow!("Invalid S3 endpoint: missing host"))?;
+            let host = format!("{}.{host}", self.settings.bucket);
+            url.set_host(Some(&host)).context("Invalid S3 bucket name")?;
+            url.set_path(key);
+        }
+        Ok(url)
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        url: Url,
+        body: Vec<u8>,
+        content_type: Option<&str>,
+    ) -> Result<Response, anyhow::Error> {
+        let now = Utc::now();
+        let amz_date = sigv4::amz_date(&now);
+        let payload_hash = sigv4::payload_hash(&body);
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_owned(),
+            _ => return Err(anyhow!("Invalid S3 url: missing host")),
+        };
+
+        let credentials = Credentials {
+            access_key_id: &self.settings.access_key_id,
+            secret_access_key: self.settings.secret_access_key.expose_secret(),
+            region: &self.settings.region,
+        };
+        let headers = [
+            ("host", host.as_str()),
+            (AMZ_CONTENT_SHA256, payload_hash.as_str()),
+            (AMZ_DATE, amz_date.as_str()),
+        ];
+        let authorization = sigv4::authorization(&credentials, method.as_str(), &url, &headers, &payload_hash, &now);
+
+        let mut request = self
+            .client
+            .request(method, url)
+            .header(AUTHORIZATION, authorization)
+            .header(AMZ_CONTENT_SHA256, &payload_hash)
+            .header(AMZ_DATE, &amz_date);
+        if let Some(content_type) = content_type {
+            request = request.header(CONTENT_TYPE, content_type);
+        }
+
+        request
+            .body(body)
+            .send()
+            .await
+            .context("Unable to reach the S3 storage")
+    }
+}
+
+#[async_trait]
+impl ImageStorage for S3ImageStorage {
+    async fn put(&self, key: &str, content: &[u8], content_type: &str) -> Result<(), anyhow::Error> {
+        let url = self.url(Some(key))?;
+        let response = self
+            .send(Method::PUT, url, content.to_vec(), Some(content_type))
+            .await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(anyhow!("Unable to store the image {key} ({})", response.status()))
+        }
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, anyhow::Error> {
+        let url = self.url(Some(key))?;
+        let response = self.send(Method::GET, url, Vec::new(), None).await?;
+        match response.status() {
+            status if status.is_success() => {
+                let content = response.bytes().await.context("Unable to read the image content")?;
+                Ok(Some(content.to_vec()))
+            }
+            StatusCode::NOT_FOUND => Ok(None),
+            status => Err(anyhow!("Unable to read the image {key} ({status})")),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), anyhow::Error> {
+        let url = self.url(Some(key))?;
+        let response = self.send(Method::DELETE, url, Vec::new(), None).await?;
+        match response.status() {
+            status if status.is_success() => Ok(()),
+            StatusCode::NOT_FOUND => Ok(()),
+            status => Err(anyhow!("Unable to delete the image {key} ({status})")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod s3_image_storage {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use secrecy::SecretString;
+
+        #[test]
+        fn it_should_build_the_object_urls() {
+            let storage = S3ImageStorage::new(&settings("https://s3.eu-west-1.amazonaws.com", false));
+            assert_eq!(
+                "https://images.s3.eu-west-1.amazonaws.com/catalog-items/acme-60142/images/1",
+                storage.url(Some("catalog-items/acme-60142/images/1")).unwrap().as_str()
+            );
+
+            let storage = S3ImageStorage::new(&settings("http://localhost:9000", true));
+            assert_eq!(
+                "http://localhost:9000/images/catalog-items/acme-60142/images/1",
+                storage.url(Some("catalog-items/acme-60142/images/1")).unwrap().as_str()
+            );
+            assert_eq!("http://localhost:9000/images/", storage.url(None).unwrap().as_str());
+        }
+
+        fn settings(endpoint: &str, path_style: bool) -> S3Settings {
+            S3Settings {
+                endpoint: endpoint.to_owned(),
+                region: String::from("us-east-1"),
+                bucket: String::from("images"),
+                access_key_id: String::from("access-key"),
+                secret_access_key: SecretString::from(String::from("secret-key")),
+                path_style,
+            }
+        }
+    }
+}
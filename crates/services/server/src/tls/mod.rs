@@ -0,0 +1,19 @@
+//! the module includes the TLS termination (rustls), with the certificate hot-reload and the HTTP to HTTPS redirect
+
+pub mod redirect;
+pub mod reloader;
+
+use axum_server::tls_rustls::RustlsConfig;
+use configuration::TlsSettings;
+use std::io;
+
+/// Loads the rustls configuration from the PEM encoded certificate chain and private key
+pub async fn load_rustls_config(settings: &TlsSettings) -> io::Result<RustlsConfig> {
+    install_crypto_provider();
+    RustlsConfig::from_pem_file(&settings.cert_path, &settings.key_path).await
+}
+
+/// Installs `ring` as the process-wide rustls crypto provider, unless one is already installed
+fn install_crypto_provider() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+}
@@ -0,0 +1,80 @@
+use axum::extract::Host;
+use axum::http::Uri;
+use axum::response::Redirect;
+use axum::Router;
+
+/// the default port for HTTPS
+const DEFAULT_HTTPS_PORT: u16 = 443;
+
+/// Returns the router for the plain HTTP listener, it redirects every request to HTTPS
+pub fn redirect_router(https_port: u16) -> Router {
+    Router::new().fallback(move |Host(host): Host, uri: Uri| async move {
+        Redirect::permanent(&https_uri(&host, https_port, &uri))
+    })
+}
+
+/// Returns the HTTPS uri for the request, the port from the host header is replaced by the HTTPS port
+fn https_uri(host: &str, https_port: u16, uri: &Uri) -> String {
+    let host_name = match host.rsplit_once(':') {
+        // an IPv6 address without a port (ie `[::1]`) has colons too
+        Some((host_name, port)) if !port.ends_with(']') => host_name,
+        _ => host,
+    };
+    let path_and_query = uri.path_and_query().map(|it| it.as_str()).unwrap_or("/");
+
+    if https_port == DEFAULT_HTTPS_PORT {
+        format!("https://{host_name}{path_and_query}")
+    } else {
+        format!("https://{host_name}:{https_port}{path_and_query}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod https_uris {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        #[rstest]
+        #[case("trenako.com", 443, "/api/brands?page=1", "https://trenako.com/api/brands?page=1")]
+        #[case("trenako.com:80", 443, "/api/brands", "https://trenako.com/api/brands")]
+        #[case("localhost:8080", 5443, "/", "https://localhost:5443/")]
+        #[case("[::1]:8080", 5443, "/health/live", "https://[::1]:5443/health/live")]
+        #[case("[::1]", 443, "/health/live", "https://[::1]/health/live")]
+        fn it_should_build_the_https_uri(
+            #[case] host: &str,
+            #[case] https_port: u16,
+            #[case] uri: &str,
+            #[case] expected: &str,
+        ) {
+            let uri: Uri = uri.parse().unwrap();
+            assert_eq!(expected, https_uri(host, https_port, &uri));
+        }
+    }
+
+    mod redirect_router {
+        use super::*;
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use pretty_assertions::assert_eq;
+        use tower::ServiceExt;
+
+        #[tokio::test]
+        async fn it_should_redirect_to_https() {
+            let request = Request::post("/api/brands?page=2")
+                .header("host", "trenako.com:8080")
+                .body(Body::empty())
+                .unwrap();
+            let response = redirect_router(8443).oneshot(request).await.unwrap();
+
+            assert_eq!(StatusCode::PERMANENT_REDIRECT, response.status());
+            assert_eq!(
+                "https://trenako.com:8443/api/brands?page=2",
+                response.headers()["location"]
+            );
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use axum_server::tls_rustls::RustlsConfig;
+use configuration::TlsSettings;
+use std::io;
+use std::path::Path;
+use std::time::SystemTime;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// It periodically checks the certificate files, reloading the rustls configuration when they change.
+///
+/// The new connections are using the reloaded certificate, while the open connections are not affected.
+/// When the new files are not valid (ie the certificate was written, but not the key yet) the current
+/// configuration is kept, and the reload is attempted again on the next check.
+pub struct CertificateReloader {
+    rustls_config: RustlsConfig,
+    settings: TlsSettings,
+}
+
+impl CertificateReloader {
+    /// Creates a new certificate reloader
+    pub fn new(rustls_config: RustlsConfig, settings: &TlsSettings) -> Self {
+        CertificateReloader {
+            rustls_config,
+            settings: settings.clone(),
+        }
+    }
+
+    /// Starts the reloader as a background task
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move { self.run().await })
+    }
+
+    async fn run(&self) {
+        tracing::info!(cert_path = ?self.settings.cert_path, "Starting the certificate reloader...");
+
+        let mut interval = tokio::time::interval(self.settings.reload_interval());
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut loaded = self.last_modified().await.ok();
+        loop {
+            interval.tick().await;
+
+            let current = match self.last_modified().await {
+                Ok(current) => current,
+                Err(why) => {
+                    tracing::warn!("unable to check the certificate files: {why}");
+                    continue;
+                }
+            };
+
+            if Some(current) != loaded {
+                match self.reload().await {
+                    Ok(_) => {
+                        tracing::info!("certificate reloaded");
+                        loaded = Some(current);
+                    }
+                    Err(why) => tracing::error!("unable to reload the certificate: {why}"),
+                }
+            }
+        }
+    }
+
+    async fn reload(&self) -> io::Result<()> {
+        self.rustls_config
+            .reload_from_pem_file(&self.settings.cert_path, &self.settings.key_path)
+            .await
+    }
+
+    async fn last_modified(&self) -> io::Result<(SystemTime, SystemTime)> {
+        let cert = modified(&self.settings.cert_path).await?;
+        let key = modified(&self.settings.key_path).await?;
+        Ok((cert, key))
+    }
+}
+
+async fn modified(path: &Path) -> io::Result<SystemTime> {
+    tokio::fs::metadata(path).await?.modified()
+}
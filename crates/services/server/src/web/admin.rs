@@ -0,0 +1,103 @@
+//! the admin access to the restricted operations (restore and the deleted resources)
+
+use crate::state::AppState;
+use crate::web::problem::ProblemDetail;
+use async_trait::async_trait;
+use axum::extract::{FromRequestParts, Query};
+use axum::http::request::Parts;
+use axum::http::{header, HeaderValue};
+use axum::response::{IntoResponse, Response};
+use common::queries::soft_delete::SoftDeleteFilter;
+use secrecy::{ExposeSecret, SecretString};
+use uuid::Uuid;
+
+/// The proof the request is coming from an admin.
+///
+/// The request must include the admin API key as bearer token (`Authorization: Bearer <api key>`),
+/// when the server has no admin API key configured every request is rejected.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AdminAccess;
+
+impl AdminAccess {
+    /// Checks the `Authorization` header value against the admin API key
+    pub fn verify(authorization: Option<&str>, api_key: Option<&SecretString>) -> Option<AdminAccess> {
+        let api_key = api_key
+            .map(|it| it.expose_secret().as_bytes())
+            .filter(|it| !it.is_empty())?;
+        let token = authorization?.strip_prefix("Bearer ")?.trim();
+        constant_time_eq(token.as_bytes(), api_key).then_some(AdminAccess)
+    }
+}
+
+/// Returns the problem detail for the requests without valid admin credentials
+pub fn admin_required(detail: &str) -> ProblemDetail {
+    ProblemDetail::unauthorized(Uuid::new_v4(), detail)
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for AdminAccess {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let authorization = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok());
+        AdminAccess::verify(authorization, state.admin_api_key.as_ref()).ok_or_else(|| {
+            (
+                [(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"))],
+                admin_required("admin credentials are required"),
+            )
+                .into_response()
+        })
+    }
+}
+
+/// The soft delete filter from the query string, the deleted resources are visible only to the admins
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SoftDeleteQuery(pub SoftDeleteFilter);
+
+#[async_trait]
+impl FromRequestParts<AppState> for SoftDeleteQuery {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let Query(filter) = Query::<SoftDeleteFilter>::from_request_parts(parts, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        if filter.include_deleted {
+            AdminAccess::from_request_parts(parts, state).await?;
+        }
+        Ok(SoftDeleteQuery(filter))
+    }
+}
+
+/// Compares the two values without short-circuiting on the first difference
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Some("Bearer my-api-key"), Some("my-api-key"), Some(AdminAccess))]
+    #[case(Some("Bearer other-api-key"), Some("my-api-key"), None)]
+    #[case(Some("Bearer my-api-ke"), Some("my-api-key"), None)]
+    #[case(Some("Basic my-api-key"), Some("my-api-key"), None)]
+    #[case(Some("my-api-key"), Some("my-api-key"), None)]
+    #[case(None, Some("my-api-key"), None)]
+    #[case(Some("Bearer my-api-key"), None, None)]
+    #[case(Some("Bearer "), Some(""), None)]
+    fn it_should_verify_the_admin_api_key(
+        #[case] authorization: Option<&str>,
+        #[case] api_key: Option<&str>,
+        #[case] expected: Option<AdminAccess>,
+    ) {
+        let api_key = api_key.map(|it| SecretString::from(it.to_owned()));
+        assert_eq!(expected, AdminAccess::verify(authorization, api_key.as_ref()));
+    }
+}
@@ -1,5 +1,7 @@
 //! the module includes everything related to common web functions
 
+pub mod admin;
 pub mod problem;
 pub mod queries;
 pub mod responders;
+pub mod urls;
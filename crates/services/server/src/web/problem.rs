@@ -5,6 +5,7 @@ use common::trn::Trn;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 use url::Url;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// A problem detail
@@ -14,7 +15,7 @@ use uuid::Uuid;
 /// From RFC-7807
 /// "problem detail" is a way to carry machine-readable details of errors in a HTTP response to avoid
 /// the need to define new error response formats for HTTP APIs.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct ProblemDetail {
     /// A URI reference (RFC-3986) that identifies the problem type. This specification
     /// encourages that, when dereferenced, it provide human-readable documentation for the
@@ -33,6 +34,7 @@ pub struct ProblemDetail {
     pub status: u16,
     /// A TRN reference that identifies the specific occurrence of the problem.
     /// It may or may not yield further information if dereferenced.
+    #[schema(value_type = String)]
     pub instance: Trn,
 }
 
@@ -88,6 +90,18 @@ impl ProblemDetail {
         }
     }
 
+    /// Creates a new problem detail for a request which conflicts with the current state of the resource
+    pub fn conflict(id: Uuid, detail: &str) -> ProblemDetail {
+        let type_url = Url::parse("https://httpstatuses.com/409").unwrap();
+        ProblemDetail {
+            problem_type: type_url,
+            title: String::from("Conflict"),
+            detail: detail.to_owned(),
+            status: StatusCode::CONFLICT.as_u16(),
+            instance: Trn::instance(&id),
+        }
+    }
+
     /// Creates a new problem detail for a resource not found
     pub fn not_found(id: Uuid, detail: &str) -> ProblemDetail {
         let type_url = Url::parse("https://httpstatuses.com/404").unwrap();
@@ -99,6 +113,54 @@ impl ProblemDetail {
             instance: Trn::instance(&id),
         }
     }
+
+    /// Creates a new problem detail for request payloads over the size limit
+    pub fn payload_too_large(id: Uuid, detail: &str) -> ProblemDetail {
+        let type_url = Url::parse("https://httpstatuses.com/413").unwrap();
+        ProblemDetail {
+            problem_type: type_url,
+            title: String::from("Payload too large"),
+            detail: detail.to_owned(),
+            status: StatusCode::PAYLOAD_TOO_LARGE.as_u16(),
+            instance: Trn::instance(&id),
+        }
+    }
+
+    /// Creates a new problem detail for request payloads with an unsupported media type
+    pub fn unsupported_media_type(id: Uuid, detail: &str) -> ProblemDetail {
+        let type_url = Url::parse("https://httpstatuses.com/415").unwrap();
+        ProblemDetail {
+            problem_type: type_url,
+            title: String::from("Unsupported media type"),
+            detail: detail.to_owned(),
+            status: StatusCode::UNSUPPORTED_MEDIA_TYPE.as_u16(),
+            instance: Trn::instance(&id),
+        }
+    }
+
+    /// Creates a new problem detail for requests without valid credentials
+    pub fn unauthorized(id: Uuid, detail: &str) -> ProblemDetail {
+        let type_url = Url::parse("https://httpstatuses.com/401").unwrap();
+        ProblemDetail {
+            problem_type: type_url,
+            title: String::from("Unauthorized"),
+            detail: detail.to_owned(),
+            status: StatusCode::UNAUTHORIZED.as_u16(),
+            instance: Trn::instance(&id),
+        }
+    }
+
+    /// Creates a new problem detail for a resource which has been deleted
+    pub fn gone(id: Uuid, detail: &str) -> ProblemDetail {
+        let type_url = Url::parse("https://httpstatuses.com/410").unwrap();
+        ProblemDetail {
+            problem_type: type_url,
+            title: String::from("The resource has been deleted"),
+            detail: detail.to_owned(),
+            status: StatusCode::GONE.as_u16(),
+            instance: Trn::instance(&id),
+        }
+    }
 }
 
 impl Display for ProblemDetail {
@@ -171,6 +233,38 @@ mod tests {
             assert_eq!(instance_id, problem_detail.instance);
         }
 
+        #[test]
+        fn it_should_create_a_new_problem_detail_for_conflicts() {
+            let id = Uuid::new_v4();
+            let instance_id = Trn::instance(&id);
+            let detail = "my first conflict";
+            let type_url = Url::parse("https://httpstatuses.com/409").unwrap();
+
+            let problem_detail = ProblemDetail::conflict(id, detail);
+
+            assert_eq!(type_url, problem_detail.problem_type);
+            assert_eq!("Conflict", problem_detail.title);
+            assert_eq!(detail, problem_detail.detail);
+            assert_eq!(409, problem_detail.status);
+            assert_eq!(instance_id, problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_create_a_new_problem_detail_resource_gone() {
+            let id = Uuid::new_v4();
+            let instance_id = Trn::instance(&id);
+            let detail = "/api/brands/acme";
+            let type_url = Url::parse("https://httpstatuses.com/410").unwrap();
+
+            let problem_detail = ProblemDetail::gone(id, detail);
+
+            assert_eq!(type_url, problem_detail.problem_type);
+            assert_eq!("The resource has been deleted", problem_detail.title);
+            assert_eq!(detail, problem_detail.detail);
+            assert_eq!(410, problem_detail.status);
+            assert_eq!(instance_id, problem_detail.instance);
+        }
+
         #[test]
         fn it_should_create_a_new_problem_detail_for_unprocessable_entities() {
             let id = Uuid::new_v4();
@@ -202,5 +296,53 @@ mod tests {
             assert_eq!(400, problem_detail.status);
             assert_eq!(instance_id, problem_detail.instance);
         }
+
+        #[test]
+        fn it_should_create_a_new_problem_detail_for_payloads_too_large() {
+            let id = Uuid::new_v4();
+            let instance_id = Trn::instance(&id);
+            let detail = "my first large payload";
+            let type_url = Url::parse("https://httpstatuses.com/413").unwrap();
+
+            let problem_detail = ProblemDetail::payload_too_large(id, detail);
+
+            assert_eq!(type_url, problem_detail.problem_type);
+            assert_eq!("Payload too large", problem_detail.title);
+            assert_eq!(detail, problem_detail.detail);
+            assert_eq!(413, problem_detail.status);
+            assert_eq!(instance_id, problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_create_a_new_problem_detail_for_unauthorized_requests() {
+            let id = Uuid::new_v4();
+            let instance_id = Trn::instance(&id);
+            let detail = "admin credentials are required";
+            let type_url = Url::parse("https://httpstatuses.com/401").unwrap();
+
+            let problem_detail = ProblemDetail::unauthorized(id, detail);
+
+            assert_eq!(type_url, problem_detail.problem_type);
+            assert_eq!("Unauthorized", problem_detail.title);
+            assert_eq!(detail, problem_detail.detail);
+            assert_eq!(401, problem_detail.status);
+            assert_eq!(instance_id, problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_create_a_new_problem_detail_for_unsupported_media_types() {
+            let id = Uuid::new_v4();
+            let instance_id = Trn::instance(&id);
+            let detail = "my first unsupported media type";
+            let type_url = Url::parse("https://httpstatuses.com/415").unwrap();
+
+            let problem_detail = ProblemDetail::unsupported_media_type(id, detail);
+
+            assert_eq!(type_url, problem_detail.problem_type);
+            assert_eq!("Unsupported media type", problem_detail.title);
+            assert_eq!(detail, problem_detail.detail);
+            assert_eq!(415, problem_detail.status);
+            assert_eq!(instance_id, problem_detail.instance);
+        }
     }
 }
@@ -9,6 +9,7 @@ impl ToProblemDetail for QueryError {
             QueryError::ConversionError(_) => ProblemDetail::error(request_id, "Something went wrong"),
             QueryError::DatabaseError(_) => ProblemDetail::error(request_id, "Something went wrong"),
             QueryError::EmptyResultSet => ProblemDetail::not_found(request_id, path.unwrap_or("")),
+            QueryError::ResourceDeleted => ProblemDetail::gone(request_id, path.unwrap_or("")),
             QueryError::UnexpectedError(why) => ProblemDetail::error(request_id, &why.to_string()),
         }
     }
@@ -65,6 +66,24 @@ mod test {
             );
         }
 
+        #[tokio::test]
+        async fn it_should_produce_a_problem_detail_for_deleted_resources() {
+            let error = QueryError::ResourceDeleted;
+            let problem_detail = error.to_problem_detail(request_id(), Some("/my-path"));
+
+            assert_eq!(
+                problem_detail.problem_type,
+                Url::parse("https://httpstatuses.com/410").unwrap()
+            );
+            assert_eq!(problem_detail.title, "The resource has been deleted");
+            assert_eq!(problem_detail.detail, "/my-path");
+            assert_eq!(problem_detail.status, 410);
+            assert_eq!(
+                problem_detail.instance,
+                Trn::from_str("trn:instance:1a29fa04-8704-48d4-ab8b-31594eeaf504").unwrap()
+            );
+        }
+
         #[tokio::test]
         async fn it_should_produce_a_problem_detail_for_conversion_errors() {
             let error = QueryError::ConversionError(ConversionErrors::new());
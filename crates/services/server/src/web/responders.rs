@@ -1,8 +1,15 @@
 use crate::web::problem::ProblemDetail;
 use axum::http::{header, HeaderValue, StatusCode};
 use axum::response::{IntoResponse, Response};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 use uuid::Uuid;
 
+/// The response header with the request warnings, one header value for each warning
+pub const WARNINGS_HEADER: &str = "x-trenako-warnings";
+
+/// The characters to percent-encode in the warnings: the controls, the non-ASCII ones and the `%` itself
+const WARNINGS_ENCODE_SET: &AsciiSet = &CONTROLS.add(b'%');
+
 pub trait ToProblemDetail {
     /// Convert this value to a problem detail with the given `request_id`
     fn to_problem_detail(self, request_id: Uuid, path: Option<&str>) -> ProblemDetail;
@@ -10,18 +17,40 @@ pub trait ToProblemDetail {
 
 pub struct Created {
     location: HeaderValue,
+    warnings: Vec<HeaderValue>,
 }
 
 impl Created {
     pub fn with_location(location_url: &str) -> Self {
         let location: HeaderValue = location_url.parse().unwrap();
-        Created { location }
+        Created {
+            location,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Adds the warnings to the response, as `x-trenako-warnings` headers (one for each warning).
+    ///
+    /// The warnings are percent-encoded, in order to always produce valid header values.
+    pub fn with_warnings(mut self, warnings: &[String]) -> Self {
+        self.warnings = warnings
+            .iter()
+            .map(|warning| {
+                let encoded = utf8_percent_encode(warning, WARNINGS_ENCODE_SET).to_string();
+                HeaderValue::try_from(encoded).expect("the percent-encoded warnings are valid header values")
+            })
+            .collect();
+        self
     }
 }
 
 impl IntoResponse for Created {
     fn into_response(self) -> Response {
-        (StatusCode::CREATED, [(header::LOCATION, self.location)], ()).into_response()
+        let mut response = (StatusCode::CREATED, [(header::LOCATION, self.location)], ()).into_response();
+        for warning in self.warnings {
+            response.headers_mut().append(WARNINGS_HEADER, warning);
+        }
+        response
     }
 }
 
@@ -41,7 +70,44 @@ mod test {
             assert_eq!(
                 HeaderValue::from_str("http://localhost").unwrap(),
                 response.headers().get(header::LOCATION).unwrap()
-            )
+            );
+            assert!(response.headers().get(WARNINGS_HEADER).is_none());
+        }
+
+        #[test]
+        fn it_should_create_created_responses_with_warnings() {
+            let warnings = vec![String::from("first \"warning\""), String::from("second warning")];
+            let created = Created::with_location("http://localhost").with_warnings(&warnings);
+
+            let response = created.into_response();
+            let values: Vec<&HeaderValue> = response.headers().get_all(WARNINGS_HEADER).iter().collect();
+            assert_eq!(
+                vec![
+                    &HeaderValue::from_static("first \"warning\""),
+                    &HeaderValue::from_static("second warning")
+                ],
+                values
+            );
+            assert!(response.headers().get(header::WARNING).is_none());
+        }
+
+        #[test]
+        fn it_should_percent_encode_the_warnings() {
+            let warnings = vec![
+                String::from("the road number 'Ae 6/6 11401 «Zürich»' is not valid"),
+                String::from("first line\nsecond line (100%)"),
+            ];
+            let created = Created::with_location("http://localhost").with_warnings(&warnings);
+
+            let response = created.into_response();
+            let values: Vec<&HeaderValue> = response.headers().get_all(WARNINGS_HEADER).iter().collect();
+            assert_eq!(
+                vec![
+                    &HeaderValue::from_static("the road number 'Ae 6/6 11401 %C2%ABZ%C3%BCrich%C2%BB' is not valid"),
+                    &HeaderValue::from_static("first line%0Asecond line (100%25)")
+                ],
+                values
+            );
         }
     }
 }
@@ -0,0 +1,61 @@
+//! the helpers to build absolute urls for the responses
+
+use axum::http::header::HOST;
+use axum::http::HeaderMap;
+
+const X_FORWARDED_PROTO: &str = "x-forwarded-proto";
+
+/// Returns the base url (scheme and host) for the absolute urls in the responses: the configured
+/// public url, when missing it is built from the current request headers (taking into account
+/// the `X-Forwarded-Proto` header set by the reverse proxies)
+pub fn base_url(public_url: Option<&str>, headers: &HeaderMap) -> String {
+    if let Some(public_url) = public_url {
+        return public_url.trim_end_matches('/').to_owned();
+    }
+
+    let scheme = headers
+        .get(X_FORWARDED_PROTO)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("http");
+    let host = headers
+        .get(HOST)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("localhost");
+    format!("{scheme}://{host}")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod urls {
+        use super::*;
+        use axum::http::HeaderValue;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_return_the_base_url() {
+            let mut headers = HeaderMap::new();
+            assert_eq!("http://localhost", base_url(None, &headers));
+
+            headers.insert(HOST, HeaderValue::from_static("trenako.com"));
+            assert_eq!("http://trenako.com", base_url(None, &headers));
+
+            headers.insert(X_FORWARDED_PROTO, HeaderValue::from_static("https"));
+            assert_eq!("https://trenako.com", base_url(None, &headers));
+        }
+
+        #[test]
+        fn it_should_prefer_the_public_url_to_the_request_headers() {
+            let mut headers = HeaderMap::new();
+            headers.insert(HOST, HeaderValue::from_static("attacker.example"));
+            headers.insert(X_FORWARDED_PROTO, HeaderValue::from_static("http"));
+
+            assert_eq!("https://trenako.com", base_url(Some("https://trenako.com"), &headers));
+            assert_eq!(
+                "https://trenako.com/catalog",
+                base_url(Some("https://trenako.com/catalog/"), &headers)
+            );
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::openapi::responses::{InternalServerError, NotFound, Unauthorized};
+use crate::state::AppState;
+use crate::web::admin::AdminAccess;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::webhooks::routes::WEBHOOKS_ROOT_API;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use catalog::webhooks::commands::delete_webhook::{delete_webhook, WebhookDeletionError};
+use catalog::webhooks::webhook_id::WebhookId;
+use data::webhooks::repositories::WebhooksRepository;
+use uuid::Uuid;
+
+/// Deletes the webhook with the given id
+#[utoipa::path(
+    delete,
+    path = "/api/webhooks/{webhook_id}",
+    tag = "webhooks",
+    operation_id = "delete-webhook",
+    params(("webhook_id" = WebhookId, Path, description = "the webhook unique identifier")),
+    security(("admin_api_key" = [])),
+    responses(
+        (status = 204, description = "the webhook has been deleted"),
+        (status = 401, response = Unauthorized),
+        (status = 404, response = NotFound),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "delete_webhook", skip(app_state))]
+pub async fn handle(
+    Path(webhook_id): Path<WebhookId>,
+    _admin: AdminAccess,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode, ProblemDetail> {
+    let repo = WebhooksRepository;
+    let database = app_state.get_database();
+
+    let result = delete_webhook(&webhook_id, repo, database).await;
+    result.map(|_| StatusCode::NO_CONTENT).map_err(|why| {
+        let path = format!("{}/{}", WEBHOOKS_ROOT_API, webhook_id);
+        why.to_problem_detail(Uuid::new_v4(), Some(&path))
+    })
+}
+
+impl ToProblemDetail for WebhookDeletionError {
+    fn to_problem_detail(self, request_id: Uuid, _path: Option<&str>) -> ProblemDetail {
+        match self {
+            WebhookDeletionError::WebhookNotFound(_) => ProblemDetail::not_found(request_id, &self.to_string()),
+            WebhookDeletionError::UnexpectedError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            WebhookDeletionError::DatabaseError(why) => ProblemDetail::error(request_id, &why.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod webhook_deletion_error_to_problem_detail {
+        use super::*;
+        use anyhow::anyhow;
+        use common::queries::errors::DatabaseError;
+        use common::trn::Trn;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_return_not_found_when_the_webhook_does_not_exist() {
+            let webhook_id = WebhookId::new();
+            let err = WebhookDeletionError::WebhookNotFound(webhook_id);
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::NOT_FOUND, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/404", problem_detail.problem_type.as_str());
+            assert_eq!(
+                format!("The webhook was not found (id: {webhook_id})"),
+                problem_detail.detail
+            );
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_generic_errors() {
+            let err = WebhookDeletionError::UnexpectedError(anyhow!("Something bad just happened"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("Something bad just happened", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_database_errors() {
+            let err = WebhookDeletionError::DatabaseError(DatabaseError::UnexpectedError(anyhow!(
+                "Something bad just happened"
+            )));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("Something bad just happened", problem_detail.detail);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+    }
+}
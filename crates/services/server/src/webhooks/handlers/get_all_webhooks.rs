@@ -0,0 +1,42 @@
+use crate::hateoas::representations::CollectionModel;
+use crate::openapi::responses::{InternalServerError, Unauthorized};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::admin::AdminAccess;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::webhooks::routes;
+use axum::extract::State;
+use catalog::webhooks::queries::find_all_webhooks::find_all_webhooks;
+use catalog::webhooks::webhook::Webhook;
+use data::webhooks::repositories::WebhooksRepository;
+use uuid::Uuid;
+
+/// Returns the webhooks list
+#[utoipa::path(
+    get,
+    path = "/api/webhooks",
+    tag = "webhooks",
+    operation_id = "get-webhooks",
+    params(("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    security(("admin_api_key" = [])),
+    responses(
+        (status = 200, description = "the webhooks list", body = WebhooksCollection),
+        (status = 401, response = Unauthorized),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_all_webhooks", skip(app_state))]
+pub async fn handle(
+    _admin: AdminAccess,
+    consistency: ReadConsistency,
+    State(app_state): State<AppState>,
+) -> Result<CollectionModel<Webhook>, ProblemDetail> {
+    let database = app_state.get_read_database(consistency);
+    let repo = WebhooksRepository;
+
+    let results = find_all_webhooks(repo, database).await;
+    results
+        .map(|webhooks| CollectionModel::of(webhooks, Vec::new()))
+        .map_err(|why| why.to_problem_detail(Uuid::new_v4(), Some(routes::WEBHOOKS_ROOT_API)))
+}
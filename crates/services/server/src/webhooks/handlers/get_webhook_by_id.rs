@@ -0,0 +1,45 @@
+use crate::hateoas::representations::EntityModel;
+use crate::openapi::responses::{InternalServerError, NotFound, Unauthorized};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::admin::AdminAccess;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::webhooks::routes;
+use axum::extract::{Path, State};
+use catalog::webhooks::queries::find_webhook_by_id::find_webhook_by_id;
+use catalog::webhooks::webhook::Webhook;
+use catalog::webhooks::webhook_id::WebhookId;
+use data::webhooks::repositories::WebhooksRepository;
+use uuid::Uuid;
+
+/// Returns the webhook with the given id
+#[utoipa::path(
+    get,
+    path = "/api/webhooks/{webhook_id}",
+    tag = "webhooks",
+    operation_id = "get-webhook-by-id",
+    params(("webhook_id" = WebhookId, Path, description = "the webhook unique identifier"), ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    security(("admin_api_key" = [])),
+    responses(
+        (status = 200, description = "the webhook", body = WebhookModel),
+        (status = 401, response = Unauthorized),
+        (status = 404, response = NotFound),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_webhook_by_id", skip(app_state))]
+pub async fn handle(
+    Path(webhook_id): Path<WebhookId>,
+    _admin: AdminAccess,
+    consistency: ReadConsistency,
+    State(app_state): State<AppState>,
+) -> Result<EntityModel<Webhook>, ProblemDetail> {
+    let database = app_state.get_read_database(consistency);
+    let repo = WebhooksRepository;
+
+    let result = find_webhook_by_id(&webhook_id, repo, database).await;
+    result
+        .map(|webhook| EntityModel::of(webhook, vec![]))
+        .map_err(|why| why.to_problem_detail(Uuid::new_v4(), Some(routes::WEBHOOKS_ROOT_API)))
+}
@@ -0,0 +1,45 @@
+use crate::hateoas::representations::CollectionModel;
+use crate::openapi::responses::{InternalServerError, NotFound, Unauthorized};
+use crate::replica::consistency::ReadConsistency;
+use crate::state::AppState;
+use crate::web::admin::AdminAccess;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::ToProblemDetail;
+use crate::webhooks::routes;
+use axum::extract::{Path, State};
+use catalog::webhooks::queries::find_webhook_deliveries::find_webhook_deliveries;
+use catalog::webhooks::webhook_delivery::WebhookDelivery;
+use catalog::webhooks::webhook_id::WebhookId;
+use data::webhooks::repositories::WebhooksRepository;
+use uuid::Uuid;
+
+/// Returns the delivery attempts for the webhook with the given id
+#[utoipa::path(
+    get,
+    path = "/api/webhooks/{webhook_id}/deliveries",
+    tag = "webhooks",
+    operation_id = "get-webhook-deliveries",
+    params(("webhook_id" = WebhookId, Path, description = "the webhook unique identifier"), ("x-read-consistency" = Option<ReadConsistency>, Header, description = "`strong` to read from the primary (read-your-writes)")),
+    security(("admin_api_key" = [])),
+    responses(
+        (status = 200, description = "the webhook deliveries", body = WebhookDeliveriesCollection),
+        (status = 401, response = Unauthorized),
+        (status = 404, response = NotFound),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "get_webhook_deliveries", skip(app_state))]
+pub async fn handle(
+    Path(webhook_id): Path<WebhookId>,
+    _admin: AdminAccess,
+    consistency: ReadConsistency,
+    State(app_state): State<AppState>,
+) -> Result<CollectionModel<WebhookDelivery>, ProblemDetail> {
+    let database = app_state.get_read_database(consistency);
+    let repo = WebhooksRepository;
+
+    let results = find_webhook_deliveries(&webhook_id, repo, database).await;
+    results
+        .map(|deliveries| CollectionModel::of(deliveries, Vec::new()))
+        .map_err(|why| why.to_problem_detail(Uuid::new_v4(), Some(routes::WEBHOOKS_ROOT_API)))
+}
@@ -0,0 +1,11 @@
+pub(crate) mod delete_webhook;
+pub(crate) mod get_all_webhooks;
+pub(crate) mod get_webhook_by_id;
+pub(crate) mod get_webhook_deliveries;
+pub(crate) mod post_webhook;
+
+pub use delete_webhook::handle as delete_webhook;
+pub use get_all_webhooks::handle as get_all_webhooks;
+pub use get_webhook_by_id::handle as get_webhook_by_id;
+pub use get_webhook_deliveries::handle as get_webhook_deliveries;
+pub use post_webhook::handle as post_webhook;
@@ -0,0 +1,111 @@
+use crate::openapi::responses::{BadRequest, InternalServerError, Unauthorized};
+use crate::state::AppState;
+use crate::web::admin::AdminAccess;
+use crate::web::problem::ProblemDetail;
+use crate::web::responders::{Created, ToProblemDetail};
+use crate::webhooks::routes::WEBHOOKS_ROOT_API;
+use axum::extract::State;
+use axum::Json;
+use catalog::webhooks::commands::new_webhook::{create_new_webhook, WebhookCreationError};
+use catalog::webhooks::webhook_request::WebhookRequest;
+use data::webhooks::repositories::WebhooksRepository;
+use uuid::Uuid;
+
+/// Subscribes a new webhook to the catalog events
+#[utoipa::path(
+    post,
+    path = "/api/webhooks",
+    tag = "webhooks",
+    operation_id = "post-webhook",
+    request_body = WebhookRequest,
+    security(("admin_api_key" = [])),
+    responses(
+        (status = 201, description = "the webhook has been created", headers(("location" = String, description = "the new webhook url"))),
+        (status = 400, response = BadRequest),
+        (status = 401, response = Unauthorized),
+        (status = 500, response = InternalServerError),
+    )
+)]
+#[tracing::instrument(name = "create_new_webhook", skip(app_state))]
+pub async fn handle(
+    _admin: AdminAccess,
+    State(app_state): State<AppState>,
+    Json(request): Json<WebhookRequest>,
+) -> Result<Created, ProblemDetail> {
+    let repo = WebhooksRepository;
+    let database = app_state.get_database();
+
+    let result = create_new_webhook(request, repo, database).await;
+    result
+        .map(|created| {
+            let location = format!("{}/{}", WEBHOOKS_ROOT_API, created.webhook_id);
+            Created::with_location(&location)
+        })
+        .map_err(|why| why.to_problem_detail(Uuid::new_v4(), None))
+}
+
+impl ToProblemDetail for WebhookCreationError {
+    fn to_problem_detail(self, request_id: Uuid, _path: Option<&str>) -> ProblemDetail {
+        match self {
+            WebhookCreationError::InvalidRequest(_) => ProblemDetail::bad_request(request_id, ""),
+            WebhookCreationError::UnexpectedError(why) => ProblemDetail::error(request_id, &why.to_string()),
+            WebhookCreationError::DatabaseError(why) => ProblemDetail::error(request_id, &why.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod webhook_creation_error_to_problem_detail {
+        use super::*;
+        use anyhow::anyhow;
+        use axum::http::StatusCode;
+        use common::queries::errors::DatabaseError;
+        use common::trn::Trn;
+        use pretty_assertions::assert_eq;
+        use validator::ValidationErrors;
+
+        #[test]
+        fn it_should_return_bad_request_for_invalid_request() {
+            let err = WebhookCreationError::InvalidRequest(ValidationErrors::new());
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::BAD_REQUEST, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/400", problem_detail.problem_type.as_str());
+            assert_eq!("", problem_detail.detail);
+            assert_eq!("Bad request", problem_detail.title);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_generic_errors() {
+            let err = WebhookCreationError::UnexpectedError(anyhow!("Something bad just happened"));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/500", problem_detail.problem_type.as_str());
+            assert_eq!("Something bad just happened", problem_detail.detail);
+            assert_eq!("Error: Internal Server Error", problem_detail.title);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+
+        #[test]
+        fn it_should_return_an_internal_server_error_for_database_errors() {
+            let err = WebhookCreationError::DatabaseError(DatabaseError::UnexpectedError(anyhow!(
+                "Something bad just happened"
+            )));
+
+            let id = Uuid::new_v4();
+            let problem_detail = err.to_problem_detail(id, None);
+            assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, problem_detail.status);
+            assert_eq!("https://httpstatuses.com/500", problem_detail.problem_type.as_str());
+            assert_eq!("Something bad just happened", problem_detail.detail);
+            assert_eq!("Error: Internal Server Error", problem_detail.title);
+            assert_eq!(Trn::instance(&id), problem_detail.instance);
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! the HTTP implementation for the webhook sender
+
+use crate::webhooks::signature;
+use async_trait::async_trait;
+use catalog::webhooks::target_url::{is_public_address, validate_target_url};
+use catalog::webhooks::webhook_delivery::{DeliveryAttempt, PendingDelivery};
+use catalog::webhooks::webhook_sender::WebhookSender;
+use chrono::Utc;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::header::{CONTENT_TYPE, USER_AGENT};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// It delivers the domain events as signed JSON `POST` requests.
+///
+/// The requests are sent only to public `https` urls: the host names are resolved by the sender, and
+/// the connections use only the resolved addresses which passed the check.
+#[derive(Debug, Clone)]
+pub struct HttpWebhookSender {
+    client: reqwest::Client,
+    allow_private_targets: bool,
+}
+
+impl HttpWebhookSender {
+    /// Creates a new webhook sender, with the given timeout for each request. The target urls are
+    /// not checked when `allow_private_targets` is set (only for local development and tests)
+    pub fn new(timeout: Duration, allow_private_targets: bool) -> Self {
+        let mut builder = reqwest::Client::builder()
+            .timeout(timeout)
+            .redirect(reqwest::redirect::Policy::none());
+        if !allow_private_targets {
+            builder = builder.dns_resolver(Arc::new(PublicAddressResolver));
+        }
+        let client = builder.build().expect("Failed to build the webhooks HTTP client");
+        HttpWebhookSender {
+            client,
+            allow_private_targets,
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(&self, delivery: &PendingDelivery) -> DeliveryAttempt {
+        if !self.allow_private_targets {
+            if let Err(why) = validate_target_url(&delivery.target_url) {
+                return DeliveryAttempt::Unreachable {
+                    error: format!("the target url is not allowed ({})", why.code),
+                };
+            }
+        }
+
+        let signature = signature::sign(&delivery.secret, Utc::now().timestamp(), &delivery.payload);
+
+        let result = self
+            .client
+            .post(&delivery.target_url)
+            .header(CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .header(USER_AGENT, "trenako-webhooks")
+            .header(signature::SIGNATURE_HEADER, signature)
+            .header(signature::EVENT_HEADER, &delivery.event_type)
+            .header(signature::DELIVERY_HEADER, delivery.delivery_id.to_string())
+            .body(delivery.payload.clone())
+            .send()
+            .await;
+
+        match result {
+            Ok(response) if response.status().is_success() => DeliveryAttempt::Delivered {
+                response_status: response.status().as_u16(),
+            },
+            Ok(response) => DeliveryAttempt::Rejected {
+                response_status: response.status().as_u16(),
+            },
+            Err(why) => DeliveryAttempt::Unreachable {
+                error: format!("{:#}", anyhow::Error::new(why)),
+            },
+        }
+    }
+}
+
+/// It resolves the webhook host names, rejecting the names with addresses which are not public
+/// (ie a DNS record pointing to the internal network)
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_owned();
+        Box::pin(async move {
+            let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+            if let Some(address) = addresses.iter().find(|it| !is_public_address(&it.ip())) {
+                return Err(format!("the host {host} resolves to a non public address ({})", address.ip()).into());
+            }
+            if addresses.is_empty() {
+                return Err(format!("the host {host} has no addresses").into());
+            }
+            Ok(Box::new(addresses.into_iter()) as Addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod http_webhook_sender {
+        use super::*;
+        use axum::extract::State;
+        use axum::http::{HeaderMap, StatusCode};
+        use axum::routing::post;
+        use axum::Router;
+        use catalog::webhooks::webhook_id::WebhookId;
+        use pretty_assertions::assert_eq;
+        use std::sync::{Arc, Mutex};
+        use tokio::net::TcpListener;
+        use uuid::Uuid;
+
+        type Received = Arc<Mutex<Vec<(HeaderMap, String)>>>;
+
+        #[tokio::test]
+        async fn it_should_post_signed_requests_to_the_webhook() {
+            let (target_url, received) = spawn_receiver(StatusCode::NO_CONTENT).await;
+            let delivery = new_delivery(&target_url);
+
+            let sender = local_sender();
+            let outcome = sender.send(&delivery).await;
+
+            assert_eq!(DeliveryAttempt::Delivered { response_status: 204 }, outcome);
+
+            let received = received.lock().unwrap();
+            assert_eq!(1, received.len());
+            let (headers, body) = &received[0];
+            assert_eq!(&delivery.payload, body);
+            assert_eq!("application/json", headers[CONTENT_TYPE.as_str()]);
+            assert_eq!("BrandCreated", headers[signature::EVENT_HEADER]);
+            assert_eq!(
+                delivery.delivery_id.to_string(),
+                headers[signature::DELIVERY_HEADER].to_str().unwrap()
+            );
+
+            let signature = headers[signature::SIGNATURE_HEADER].to_str().unwrap();
+            assert!(signature::verify(&delivery.secret, signature, body));
+        }
+
+        #[tokio::test]
+        async fn it_should_report_the_error_status_codes() {
+            let (target_url, _) = spawn_receiver(StatusCode::SERVICE_UNAVAILABLE).await;
+            let delivery = new_delivery(&target_url);
+
+            let sender = local_sender();
+            let outcome = sender.send(&delivery).await;
+
+            assert_eq!(DeliveryAttempt::Rejected { response_status: 503 }, outcome);
+        }
+
+        #[tokio::test]
+        async fn it_should_report_unreachable_webhooks() {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let port = listener.local_addr().unwrap().port();
+            drop(listener);
+            let delivery = new_delivery(&format!("http://127.0.0.1:{port}/hooks"));
+
+            let sender = local_sender();
+            let outcome = sender.send(&delivery).await;
+
+            assert!(matches!(outcome, DeliveryAttempt::Unreachable { .. }));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_send_requests_to_the_internal_network() {
+            let (target_url, received) = spawn_receiver(StatusCode::NO_CONTENT).await;
+            let sender = HttpWebhookSender::new(Duration::from_secs(5), false);
+
+            let outcome = sender.send(&new_delivery(&target_url)).await;
+            assert_eq!(
+                DeliveryAttempt::Unreachable {
+                    error: String::from("the target url is not allowed (https_required)")
+                },
+                outcome
+            );
+
+            let target_url = target_url.replace("http://", "https://");
+            let outcome = sender.send(&new_delivery(&target_url)).await;
+            assert_eq!(
+                DeliveryAttempt::Unreachable {
+                    error: String::from("the target url is not allowed (private_address)")
+                },
+                outcome
+            );
+
+            assert!(received.lock().unwrap().is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_reject_the_host_names_resolved_to_private_addresses() {
+            let name: Name = "localhost".parse().unwrap();
+
+            let result = PublicAddressResolver.resolve(name).await;
+
+            let error = result.err().expect("the resolution should fail").to_string();
+            assert!(error.starts_with("the host localhost resolves to a non public address"));
+        }
+
+        /// A sender for the local test receivers, the target urls are not checked
+        fn local_sender() -> HttpWebhookSender {
+            HttpWebhookSender::new(Duration::from_secs(5), true)
+        }
+
+        async fn spawn_receiver(status: StatusCode) -> (String, Received) {
+            let received: Received = Arc::new(Mutex::new(Vec::new()));
+            let app = Router::new()
+                .route(
+                    "/hooks",
+                    post(
+                        move |State(received): State<Received>, headers: HeaderMap, body: String| async move {
+                            received.lock().unwrap().push((headers, body));
+                            status
+                        },
+                    ),
+                )
+                .with_state(received.clone());
+
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let port = listener.local_addr().unwrap().port();
+            tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+            (format!("http://127.0.0.1:{port}/hooks"), received)
+        }
+
+        fn new_delivery(target_url: &str) -> PendingDelivery {
+            PendingDelivery {
+                delivery_id: Uuid::new_v4(),
+                webhook_id: WebhookId::new(),
+                event_id: Uuid::new_v4(),
+                event_type: String::from("BrandCreated"),
+                payload: String::from(r#"{"event_type":"BrandCreated"}"#),
+                attempts: 0,
+                target_url: String::from(target_url),
+                secret: String::from("a-very-long-secret"),
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+//! the module includes everything related to the webhook subscriptions: the web handlers, and the
+//! background tasks delivering the domain events to the subscribers
+
+pub mod handlers;
+pub mod http_sender;
+pub mod routes;
+pub mod signature;
+pub mod sink;
+pub mod worker;
@@ -0,0 +1,21 @@
+use crate::state::AppState;
+use crate::webhooks::handlers;
+use axum::routing::get;
+use axum::Router;
+
+pub const WEBHOOKS_ROOT_API: &str = "/api/webhooks";
+pub const WEBHOOK_ROOT_API: &str = "/api/webhooks/:webhook_id";
+pub const WEBHOOK_DELIVERIES_API: &str = "/api/webhooks/:webhook_id/deliveries";
+
+pub fn webhooks_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            WEBHOOKS_ROOT_API,
+            get(handlers::get_all_webhooks).post(handlers::post_webhook),
+        )
+        .route(
+            WEBHOOK_ROOT_API,
+            get(handlers::get_webhook_by_id).delete(handlers::delete_webhook),
+        )
+        .route(WEBHOOK_DELIVERIES_API, get(handlers::get_webhook_deliveries))
+}
@@ -0,0 +1,77 @@
+//! the webhook requests signature
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// The header with the request signature
+pub const SIGNATURE_HEADER: &str = "x-trenako-signature";
+/// The header with the domain event type
+pub const EVENT_HEADER: &str = "x-trenako-event";
+/// The header with the delivery id, it stays the same for all the attempts of a delivery
+pub const DELIVERY_HEADER: &str = "x-trenako-delivery";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs the request body with the webhook secret.
+///
+/// The signature has the form `t=<timestamp>,v1=<signature>`, where the signature is the hex encoded
+/// HMAC-SHA256 for the `<timestamp>.<body>` string. Receivers should reject requests with an old timestamp.
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let signature = new_mac(secret, timestamp, body).finalize().into_bytes();
+    format!("t={timestamp},v1={}", hex::encode(signature))
+}
+
+/// Verifies the signature header value for the request body
+pub fn verify(secret: &str, signature: &str, body: &str) -> bool {
+    let mut timestamp = None;
+    let mut value = None;
+    for part in signature.split(',') {
+        match part.split_once('=') {
+            Some(("t", t)) => timestamp = t.parse::<i64>().ok(),
+            Some(("v1", v)) => value = hex::decode(v).ok(),
+            _ => {}
+        }
+    }
+
+    match (timestamp, value) {
+        (Some(timestamp), Some(value)) => new_mac(secret, timestamp, body).verify_slice(&value).is_ok(),
+        _ => false,
+    }
+}
+
+fn new_mac(secret: &str, timestamp: i64, body: &str) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(format!("{timestamp}.{body}").as_bytes());
+    mac
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod webhook_signatures {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn it_should_sign_the_request_body() {
+            let signature = sign("my-secret", 1760774400, r#"{"event_type":"BrandCreated"}"#);
+            assert_eq!(
+                "t=1760774400,v1=e4c3b6e34a5bea0d2e1a1c7bef09a318122b1307a9375eb21a5326c2efce6a08",
+                signature
+            );
+        }
+
+        #[test]
+        fn it_should_verify_the_signatures() {
+            let body = r#"{"event_type":"BrandCreated"}"#;
+            let signature = sign("my-secret", 1760774400, body);
+
+            assert!(verify("my-secret", &signature, body));
+            assert!(!verify("another-secret", &signature, body));
+            assert!(!verify("my-secret", &signature, r#"{"event_type":"BrandDeleted"}"#));
+            assert!(!verify("my-secret", "t=1760774400", body));
+            assert!(!verify("my-secret", "not a signature", body));
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! the event sink which schedules the webhook deliveries
+
+use async_trait::async_trait;
+use catalog::events::domain_event::EventEnvelope;
+use catalog::events::event_sink::EventSink;
+use catalog::webhooks::commands::schedule_deliveries::schedule_webhook_deliveries;
+use common::unit_of_work::postgres::PgDatabase;
+use data::webhooks::repositories::WebhooksRepository;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+/// An event sink which schedules a delivery for each webhook subscribed to the event type,
+/// the deliveries are then sent by the webhook delivery worker
+#[derive(Debug, Clone)]
+pub struct WebhooksSink {
+    pg_pool: Arc<PgPool>,
+}
+
+impl WebhooksSink {
+    /// Creates a new webhooks sink
+    pub fn new(pg_pool: Arc<PgPool>) -> Self {
+        WebhooksSink { pg_pool }
+    }
+}
+
+#[async_trait]
+impl EventSink for WebhooksSink {
+    fn name(&self) -> &str {
+        "webhooks"
+    }
+
+    async fn publish(&self, envelope: &EventEnvelope) -> Result<(), anyhow::Error> {
+        let database = PgDatabase::new(&self.pg_pool);
+        let scheduled = schedule_webhook_deliveries(envelope, WebhooksRepository, database).await?;
+        if scheduled.scheduled > 0 {
+            tracing::debug!(event_id = %envelope.event_id, scheduled = scheduled.scheduled, "webhook deliveries scheduled");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+//! the webhook deliveries background task
+
+use crate::webhooks::http_sender::HttpWebhookSender;
+use catalog::events::retry_policy::RetryPolicy;
+use catalog::webhooks::commands::deliver_webhooks::deliver_pending_webhooks;
+use common::unit_of_work::postgres::PgDatabase;
+use configuration::WebhooksSettings;
+use data::webhooks::repositories::WebhooksRepository;
+use sqlx::PgPool;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// It periodically sends the webhook deliveries due for their next attempt
+pub struct WebhookDeliveryWorker {
+    pg_pool: Arc<PgPool>,
+    sender: HttpWebhookSender,
+    retry_policy: RetryPolicy,
+    settings: WebhooksSettings,
+}
+
+impl WebhookDeliveryWorker {
+    /// Creates a new webhook delivery worker
+    pub fn new(pg_pool: Arc<PgPool>, settings: &WebhooksSettings) -> Self {
+        let retry_policy = RetryPolicy::new(
+            settings.max_attempts,
+            chrono::Duration::milliseconds(settings.initial_backoff_ms as i64),
+            chrono::Duration::milliseconds(settings.max_backoff_ms as i64),
+        );
+        WebhookDeliveryWorker {
+            pg_pool,
+            sender: HttpWebhookSender::new(settings.request_timeout(), settings.allow_private_targets),
+            retry_policy,
+            settings: settings.clone(),
+        }
+    }
+
+    /// Starts the worker as a background task
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move { self.run().await })
+    }
+
+    async fn run(&self) {
+        tracing::info!("Starting the webhook delivery worker...");
+
+        let mut interval = tokio::time::interval(self.settings.poll_interval());
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            // keep going without waiting while there is a backlog of due deliveries
+            while self.deliver_batch().await >= self.settings.batch_size as usize {}
+        }
+    }
+
+    async fn deliver_batch(&self) -> usize {
+        let database = PgDatabase::new(&self.pg_pool);
+        let result = deliver_pending_webhooks(
+            self.settings.batch_size,
+            &self.retry_policy,
+            chrono::Duration::milliseconds(self.settings.claim_timeout_ms as i64),
+            &self.sender,
+            WebhooksRepository,
+            database,
+        )
+        .await;
+
+        match result {
+            Ok(outcome) => {
+                let attempted = outcome.delivered + outcome.retried + outcome.failed;
+                if attempted > 0 {
+                    tracing::debug!(
+                        delivered = outcome.delivered,
+                        retried = outcome.retried,
+                        failed = outcome.failed,
+                        "webhook deliveries attempted"
+                    );
+                }
+                attempted
+            }
+            Err(why) => {
+                tracing::error!("unable to send the webhook deliveries: {why:#}");
+                0
+            }
+        }
+    }
+}
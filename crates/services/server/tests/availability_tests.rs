@@ -0,0 +1,80 @@
+pub mod common;
+
+use crate::common::seeding::{seed_brands, seed_catalog_items, seed_railways, seed_scales};
+use crate::common::{create_docker_test, spawn_app, IMAGE_NAME};
+use reqwest::StatusCode;
+use serde_json::json;
+
+const CATALOG_ITEM_AVAILABILITY: &str = "/api/catalog-items/acme-60011/availability";
+
+#[tokio::test]
+async fn it_should_change_the_catalog_item_availability() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let pg_pool = sut.pg_pool();
+        seed_brands(&pg_pool).await;
+        seed_railways(&pg_pool).await;
+        seed_scales(&pg_pool).await;
+        seed_catalog_items(&pg_pool).await;
+
+        let endpoint = sut.endpoint(CATALOG_ITEM_AVAILABILITY);
+        let response = client
+            .post(&endpoint)
+            .json(&json!({ "status": "DISCONTINUED", "note": "no longer produced" }))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+        let body = response
+            .json::<serde_json::Value>()
+            .await
+            .expect("Failed to fetch the response body");
+        assert_eq!("DISCONTINUED", body["status"]);
+
+        let history = body["history"].as_array().expect("the availability history");
+        let last = history.last().expect("the last availability change");
+        assert_eq!("DISCONTINUED", last["status"]);
+        assert_eq!("no longer produced", last["note"]);
+
+        let response = client
+            .post(&endpoint)
+            .json(&json!({ "status": "ANNOUNCED" }))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::CONFLICT, response.status());
+
+        let response = client.get(&endpoint).send().await.expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn it_should_return_not_found_for_the_availability_of_missing_catalog_items() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let endpoint = sut.endpoint("/api/catalog-items/acme-99999/availability");
+        let response = client.get(endpoint).send().await.expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::NOT_FOUND, response.status());
+    })
+    .await;
+}
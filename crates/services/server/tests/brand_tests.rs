@@ -1,7 +1,7 @@
 pub mod common;
 
 use crate::common::seeding::seed_brands;
-use crate::common::{create_docker_test, spawn_app, IMAGE_NAME};
+use crate::common::{create_docker_test, spawn_app, ADMIN_API_KEY, IMAGE_NAME};
 use ::common::contacts::{MailAddress, PhoneNumber};
 use ::common::organizations::OrganizationEntityType;
 use ::common::socials::Handler;
@@ -326,6 +326,88 @@ async fn it_should_return_200_with_empty_result_set_when_no_brand_is_found() {
     .await;
 }
 
+#[tokio::test]
+async fn it_should_soft_delete_and_restore_brands() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let pg_pool = sut.pg_pool();
+        seed_brands(&pg_pool).await;
+
+        let endpoint = sut.endpoint(API_BRANDS);
+        let brand_endpoint = format!("{}/acme", endpoint);
+
+        let response = client
+            .delete(&brand_endpoint)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(204, response.status().as_u16());
+
+        let response = client
+            .get(&brand_endpoint)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(410, response.status().as_u16());
+
+        let response = client.get(&endpoint).send().await.expect("Failed to execute request.");
+        let body = response
+            .json::<Brands>()
+            .await
+            .expect("Failed to fetch the response body");
+        assert_eq!(1, body.items.len());
+
+        let response = client
+            .get(format!("{}?include_deleted=true", endpoint))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(401, response.status().as_u16());
+
+        let response = client
+            .get(format!("{}?include_deleted=true", endpoint))
+            .bearer_auth(ADMIN_API_KEY)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        let body = response
+            .json::<Brands>()
+            .await
+            .expect("Failed to fetch the response body");
+        assert_eq!(2, body.items.len());
+
+        let response = client
+            .post(format!("{}/restore", brand_endpoint))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(401, response.status().as_u16());
+
+        let response = client
+            .post(format!("{}/restore", brand_endpoint))
+            .bearer_auth(ADMIN_API_KEY)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(204, response.status().as_u16());
+
+        let response = client
+            .get(&brand_endpoint)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(200, response.status().as_u16());
+    })
+    .await;
+}
+
 #[derive(Debug)]
 struct Saved {
     brand_id: String,
@@ -0,0 +1,108 @@
+pub mod common;
+
+use crate::common::seeding::{seed_brands, seed_catalog_items, seed_railways, seed_scales};
+use crate::common::{create_docker_test, spawn_app, IMAGE_NAME};
+use reqwest::StatusCode;
+use sqlx::PgPool;
+
+const CALENDAR_RELEASES: &str = "/api/calendar/releases";
+
+#[tokio::test]
+async fn it_should_return_the_releases_calendar() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let pg_pool = sut.pg_pool();
+        seed_brands(&pg_pool).await;
+        seed_railways(&pg_pool).await;
+        seed_scales(&pg_pool).await;
+        seed_catalog_items(&pg_pool).await;
+        announce(&pg_pool, "acme-60011", "2026/Q4").await;
+
+        let endpoint = sut.endpoint(&format!("{CALENDAR_RELEASES}?from=2026-09&to=2026-12&brand=acme"));
+        let response = client.get(endpoint).send().await.expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+        let body = response
+            .json::<serde_json::Value>()
+            .await
+            .expect("Failed to fetch the response body");
+
+        let buckets: Vec<(String, usize)> = body["buckets"]
+            .as_array()
+            .expect("the calendar buckets")
+            .iter()
+            .map(|bucket| {
+                (
+                    bucket["period"].as_str().unwrap_or_default().to_string(),
+                    bucket["catalog_items"].as_array().map(Vec::len).unwrap_or_default(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (String::from("2026/09"), 0),
+                (String::from("2026/10"), 1),
+                (String::from("2026/11"), 1),
+                (String::from("2026/12"), 1),
+            ],
+            buckets
+        );
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn it_should_export_the_releases_calendar() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let pg_pool = sut.pg_pool();
+        seed_brands(&pg_pool).await;
+        seed_railways(&pg_pool).await;
+        seed_scales(&pg_pool).await;
+        seed_catalog_items(&pg_pool).await;
+        announce(&pg_pool, "acme-60011", "2026/11").await;
+
+        let endpoint = sut.endpoint(&format!("{CALENDAR_RELEASES}.ics?from=2026&to=2027&brand=acme"));
+        let response = client.get(endpoint).send().await.expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("text/calendar; charset=utf-8", response.headers()["content-type"]);
+
+        let body = response.text().await.expect("Failed to fetch the response body");
+        assert!(body.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(body.contains("UID:acme-60011@trenako\r\n"));
+        assert!(body.contains("DTSTART;VALUE=DATE:20261101\r\n"));
+        assert!(body.contains("DTEND;VALUE=DATE:20261201\r\n"));
+
+        let endpoint = sut.endpoint(&format!("{CALENDAR_RELEASES}.ics?from=2027&to=2026"));
+        let response = client.get(endpoint).send().await.expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    })
+    .await;
+}
+
+async fn announce(pg_pool: &PgPool, catalog_item_id: &str, delivery_date: &str) {
+    sqlx::query(
+        "UPDATE catalog_items SET availability_status = 'ANNOUNCED', delivery_date = $2 WHERE catalog_item_id = $1",
+    )
+    .bind(catalog_item_id)
+    .bind(delivery_date)
+    .execute(pg_pool)
+    .await
+    .expect("Failed to announce the catalog item");
+}
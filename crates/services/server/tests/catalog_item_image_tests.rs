@@ -0,0 +1,166 @@
+use crate::common::seeding::{seed_brands, seed_catalog_items, seed_railways, seed_scales};
+use crate::common::{create_docker_test, spawn_app, ServiceUnderTest, IMAGE_NAME};
+use reqwest::multipart::{Form, Part};
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+pub mod common;
+
+const API_CATALOG_ITEM_IMAGES: &str = "/api/catalog-items/acme-60011/images";
+const PNG: &[u8] = b"\x89PNG\x0D\x0A\x1A\x0A\x00\x00\x00\x0DIHDR";
+
+#[tokio::test]
+async fn it_should_upload_the_catalog_item_images() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        seed(&sut).await;
+
+        let first = upload(&client, &sut, "image/png", false).await;
+        assert_eq!(StatusCode::CREATED, first.status());
+        let first_location = location(&first);
+
+        let second = upload(&client, &sut, "image/png", false).await;
+        assert_eq!(StatusCode::CREATED, second.status());
+        let second_location = location(&second);
+
+        let response = client
+            .get(sut.endpoint(&first_location))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("image/png", response.headers()["content-type"]);
+        assert_eq!(PNG, response.bytes().await.unwrap().as_ref());
+
+        let response = client
+            .put(sut.endpoint(&second_location))
+            .json(&json!({ "position": 1, "primary": true }))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(StatusCode::OK, response.status());
+
+        let images = get_json(&client, &sut, API_CATALOG_ITEM_IMAGES).await;
+        let items = images["items"].as_array().unwrap();
+        assert_eq!(2, items.len());
+        assert!(second_location.ends_with(items[0]["image_id"].as_str().unwrap()));
+        assert_eq!(json!(true), items[0]["primary"]);
+        assert_eq!(json!(false), items[1]["primary"]);
+
+        let catalog_item = get_json(&client, &sut, "/api/catalog-items/acme-60011").await;
+        let links = catalog_item["_links"].as_array().unwrap();
+        assert!(links
+            .iter()
+            .any(|it| it["rel"] == "preview" && it["href"].as_str().unwrap().ends_with(&second_location)));
+        assert!(links
+            .iter()
+            .any(|it| it["rel"] == "image" && it["href"].as_str().unwrap().ends_with(&first_location)));
+
+        let response = client
+            .delete(sut.endpoint(&second_location))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(StatusCode::NO_CONTENT, response.status());
+
+        let images = get_json(&client, &sut, API_CATALOG_ITEM_IMAGES).await;
+        let items = images["items"].as_array().unwrap();
+        assert_eq!(1, items.len());
+        assert_eq!(json!(1), items[0]["position"]);
+        assert_eq!(json!(true), items[0]["primary"]);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn it_should_reject_the_images_with_an_unsupported_media_type() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        seed(&sut).await;
+
+        let response = upload(&client, &sut, "image/jpeg", false).await;
+
+        assert_eq!(StatusCode::UNSUPPORTED_MEDIA_TYPE, response.status());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn it_should_return_404_not_found_uploading_images_for_missing_catalog_items() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let form = Form::new().part("image", Part::bytes(PNG.to_vec()).file_name("image.png"));
+        let response = client
+            .post(sut.endpoint("/api/catalog-items/not-found/images"))
+            .multipart(form)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::NOT_FOUND, response.status());
+    })
+    .await;
+}
+
+async fn seed(sut: &ServiceUnderTest) {
+    sut.run_database_migrations().await;
+
+    let pg_pool = sut.pg_pool();
+    seed_brands(&pg_pool).await;
+    seed_railways(&pg_pool).await;
+    seed_scales(&pg_pool).await;
+    seed_catalog_items(&pg_pool).await;
+}
+
+async fn upload(
+    client: &reqwest::Client,
+    sut: &ServiceUnderTest,
+    content_type: &str,
+    primary: bool,
+) -> reqwest::Response {
+    let part = Part::bytes(PNG.to_vec())
+        .file_name("image.png")
+        .mime_str(content_type)
+        .unwrap();
+    let form = Form::new().part("image", part).text("primary", primary.to_string());
+    client
+        .post(sut.endpoint(API_CATALOG_ITEM_IMAGES))
+        .multipart(form)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+async fn get_json(client: &reqwest::Client, sut: &ServiceUnderTest, path: &str) -> Value {
+    let response = client
+        .get(sut.endpoint(path))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(StatusCode::OK, response.status());
+    response
+        .json::<Value>()
+        .await
+        .expect("Failed to fetch the response body")
+}
+
+fn location(response: &reqwest::Response) -> String {
+    response.headers()["location"].to_str().unwrap().to_owned()
+}
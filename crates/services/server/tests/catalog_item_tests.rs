@@ -1,4 +1,4 @@
-use crate::common::seeding::{seed_brands, seed_catalog_items, seed_railways, seed_scales};
+use crate::common::seeding::{seed_brands, seed_catalog_items, seed_railway, seed_railways, seed_scales};
 use crate::common::{create_docker_test, spawn_app, IMAGE_NAME};
 use catalog::brands::brand_id::BrandId;
 use catalog::catalog_items::availability_status::AvailabilityStatus;
@@ -17,7 +17,11 @@ use catalog::catalog_items::rolling_stock_id::RollingStockId;
 use catalog::catalog_items::service_level::ServiceLevel;
 use catalog::catalog_items::technical_specifications::{BodyShellType, ChassisType, CouplingSocket, FeatureFlag};
 use catalog::railways::railway_id::RailwayId;
+use catalog::railways::railway_request::RailwayRequest;
+use catalog::railways::vehicle_keeper_marking::VehicleKeeperMarking;
 use catalog::scales::scale_id::ScaleId;
+use chrono::NaiveDate;
+use isocountry::CountryCode;
 use reqwest::StatusCode;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
@@ -103,6 +107,7 @@ async fn it_should_find_catalog_items_by_id() {
                 livery,
                 length_over_buffer: _,
                 technical_specifications: _,
+                prototype_id,
                 class_name,
                 road_number,
                 series,
@@ -120,6 +125,7 @@ async fn it_should_find_catalog_items_by_id() {
                 assert_eq!(road_number, String::from("E402 015"));
                 assert_eq!(depot, Some(String::from("Milano Centrale")));
                 assert_eq!(series, None);
+                assert_eq!(prototype_id, None);
                 assert_eq!(locomotive_type, LocomotiveType::ElectricLocomotive);
                 assert!(!is_dummy);
             }
@@ -129,6 +135,52 @@ async fn it_should_find_catalog_items_by_id() {
     .await
 }
 
+#[tokio::test]
+async fn it_should_filter_the_catalog_items_by_epoch() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let pg_pool = sut.pg_pool();
+        seed_brands(&pg_pool).await;
+        seed_railways(&pg_pool).await;
+        seed_scales(&pg_pool).await;
+        seed_catalog_items(&pg_pool).await;
+
+        let endpoint = sut.endpoint(API_CATALOG_ITEMS);
+        for (query, expected) in [
+            ("railway=fs&year=1995", 1),
+            ("railway=fs&year=1965", 0),
+            ("year=2000", 1),
+            ("epoch=IV/V", 1),
+            ("epoch=IV", 0),
+        ] {
+            let response = client
+                .get(format!("{endpoint}?{query}"))
+                .send()
+                .await
+                .expect("Failed to execute request.");
+            assert_eq!(StatusCode::OK, response.status());
+
+            let body = response
+                .json::<serde_json::Value>()
+                .await
+                .expect("Failed to fetch the response body");
+            assert_eq!(
+                Some(expected),
+                body["items"].as_array().map(|items| items.len()),
+                "{query}"
+            );
+        }
+    })
+    .await
+}
+
 #[tokio::test]
 async fn it_should_return_409_when_the_catalog_item_already_exists() {
     let test = create_docker_test();
@@ -348,6 +400,146 @@ async fn it_should_return_422_when_the_railway_is_not_found() {
     .await
 }
 
+#[tokio::test]
+async fn it_should_return_warnings_for_invalid_uic_road_numbers() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        sut.run_database_migrations().await;
+
+        let pg_pool = sut.pg_pool();
+        seed_brands(&pg_pool).await;
+        seed_railways(&pg_pool).await;
+        seed_scales(&pg_pool).await;
+
+        let request = json!({
+            "brand" : "ACME",
+            "item_number" : "45510",
+            "category" : "FREIGHT_CARS",
+            "scale" : "H0",
+            "power_method" : "DC",
+            "epoch": "IV",
+            "description" : {
+                "it" : "Carro a sponde alte Eaos"
+            },
+            "details" : {},
+            "count" : 1,
+            "rolling_stocks": [{
+                "category" : "FREIGHT_CAR",
+                "type_name" : "Eaos",
+                "freight_car_type" : "GONDOLA",
+                "road_number" : "31 80 665 1 234-5",
+                "railway" : "FS"
+              }]
+        });
+
+        let client = reqwest::Client::new();
+        let endpoint = sut.endpoint(API_CATALOG_ITEMS);
+        let response = client
+            .post(endpoint)
+            .json(&request)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::CREATED, response.status());
+        assert_eq!(
+            "rolling_stocks[0]: the road number '31 80 665 1 234-5' has an invalid UIC self-check digit (expected: 0)",
+            response.headers()["x-trenako-warnings"].to_str().unwrap()
+        );
+    })
+    .await
+}
+
+#[tokio::test]
+async fn it_should_match_the_vehicle_keeper_markings_at_the_reference_date() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        sut.run_database_migrations().await;
+
+        let pg_pool = sut.pg_pool();
+        seed_brands(&pg_pool).await;
+        seed_railways(&pg_pool).await;
+        seed_scales(&pg_pool).await;
+        seed_railway(
+            &pg_pool,
+            RailwayRequest {
+                name: String::from("SBB"),
+                country: Some(CountryCode::CHE),
+                uic_country_code: Some(85),
+                vehicle_keeper_markings: vec![VehicleKeeperMarking::new(
+                    "SBB",
+                    None,
+                    NaiveDate::from_ymd_opt(2004, 12, 31),
+                )],
+                ..RailwayRequest::default()
+            },
+        )
+        .await;
+
+        let request = |item_number: &str, epoch: &str| {
+            json!({
+                "brand" : "ACME",
+                "item_number" : item_number,
+                "category" : "LOCOMOTIVES",
+                "scale" : "H0",
+                "power_method" : "AC",
+                "epoch": epoch,
+                "delivery_date": "2024",
+                "description" : {
+                    "it" : "Locomotiva elettrica Re 460"
+                },
+                "details" : {},
+                "count" : 1,
+                "rolling_stocks": [{
+                    "category" : "LOCOMOTIVE",
+                    "class_name" : "Re 460",
+                    "road_number" : "91 85 4460 001-1 CH-SBB",
+                    "locomotive_type" : "ELECTRIC_LOCOMOTIVE",
+                    "railway" : "DB",
+                    "is_dummy" : false
+                  }]
+            })
+        };
+
+        let client = reqwest::Client::new();
+        let endpoint = sut.endpoint(API_CATALOG_ITEMS);
+
+        // the vehicle keeper marking was in use during the epoch IV
+        let response = client
+            .post(&endpoint)
+            .json(&request("46000", "IV"))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::CREATED, response.status());
+        assert_eq!(
+            "rolling_stocks[0]: the road number '91 85 4460 001-1 CH-SBB' belongs to the railway 'sbb' (expected: db)",
+            response.headers()["x-trenako-warnings"].to_str().unwrap()
+        );
+
+        // the vehicle keeper marking was expired at the delivery date, for the current epoch
+        let response = client
+            .post(&endpoint)
+            .json(&request("46001", "VI"))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::CREATED, response.status());
+        assert!(response.headers().get("x-trenako-warnings").is_none());
+    })
+    .await
+}
+
 #[tokio::test]
 async fn it_should_create_a_new_locomotive() {
     let test = create_docker_test();
@@ -1,5 +1,6 @@
 use crate::common::IMAGE_NAME;
 use configuration::DatabaseSettings;
+use data::migrations::MIGRATOR;
 use dockertest::waitfor::{MessageSource, MessageWait};
 use dockertest::{Image, TestBodySpecification};
 use sqlx::PgPool;
@@ -42,7 +43,7 @@ impl Database {
     }
 
     pub async fn run_database_migrations(&self) {
-        sqlx::migrate!("../../../migrations")
+        MIGRATOR
             .run(&self.pg_pool())
             .await
             .expect("Failed to migrate the database");
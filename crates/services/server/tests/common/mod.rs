@@ -1,13 +1,20 @@
 use crate::common::database::Database;
-use configuration::{LoggingFormat, LoggingLevel, LoggingSettings, ServerSettings, Settings};
+use configuration::{
+    AdminSettings, AvailabilityReviewSettings, CacheSettings, CalendarSettings, EventStreamSettings,
+    ImageStorageSettings, ImagesSettings, LoggingFormat, LoggingLevel, LoggingSettings, ManagementSettings,
+    OutboxSettings, ServerSettings, Settings, WebhooksSettings,
+};
 use dockertest::{DockerTest, Source};
+use secrecy::SecretString;
 use server::app;
 use sqlx::PgPool;
-use std::future::IntoFuture;
 use tokio::net::TcpListener;
 
 pub const IMAGE_NAME: &str = "postgres";
 
+/// the API key for the admin operations
+pub const ADMIN_API_KEY: &str = "test-admin-api-key";
+
 pub mod database;
 pub mod seeding;
 
@@ -38,12 +45,37 @@ pub async fn spawn_app(postgres_port: u32) -> ServiceUnderTest {
         server: ServerSettings {
             host: String::from("127.0.0.1"),
             port: 0,
+            ..ServerSettings::default()
         },
         database: database_settings,
         logging: LoggingSettings {
             level: LoggingLevel::Error,
             format: LoggingFormat::Compact,
         },
+        outbox: OutboxSettings {
+            poll_interval_ms: 100,
+            ..OutboxSettings::default()
+        },
+        webhooks: WebhooksSettings {
+            poll_interval_ms: 100,
+            initial_backoff_ms: 100,
+            allow_private_targets: true,
+            ..WebhooksSettings::default()
+        },
+        event_stream: EventStreamSettings::default(),
+        management: ManagementSettings::default(),
+        cache: CacheSettings::default(),
+        images: ImagesSettings {
+            storage: ImageStorageSettings::Filesystem {
+                root: std::env::temp_dir().join(format!("trenako-images-{}", uuid::Uuid::new_v4())),
+            },
+            ..ImagesSettings::default()
+        },
+        availability_review: AvailabilityReviewSettings::default(),
+        admin: AdminSettings {
+            api_key: Some(SecretString::from(ADMIN_API_KEY.to_owned())),
+        },
+        calendar: CalendarSettings::default(),
     };
 
     let listener = TcpListener::bind("127.0.0.1:0")
@@ -51,8 +83,7 @@ pub async fn spawn_app(postgres_port: u32) -> ServiceUnderTest {
         .expect("Failed to bind random port");
     let port = listener.local_addr().unwrap().port();
 
-    let http_server = axum::serve(listener, app::build_app(&settings)).into_future();
-    let _handle = tokio::spawn(http_server);
+    let _handle = tokio::spawn(async move { app::run(listener, &settings).await });
 
     ServiceUnderTest {
         base_endpoint_url: format!("http://127.0.0.1:{port}"),
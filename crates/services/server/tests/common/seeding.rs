@@ -2,6 +2,7 @@ use ::data::catalog::brands::repositories::BrandsRepository;
 use ::data::catalog::catalog_item::repositories::{CatalogItemsRepository, RollingStocksRepository};
 use ::data::catalog::railways::repositories::RailwaysRepository;
 use ::data::catalog::scales::repositories::ScalesRepository;
+use ::data::webhooks::repositories::WebhooksRepository;
 use catalog::brands::brand_request::BrandRequest;
 use catalog::brands::commands::new_brand::NewBrandCommand;
 use catalog::brands::commands::repositories::NewBrandRepository;
@@ -14,6 +15,12 @@ use catalog::railways::railway_request::RailwayRequest;
 use catalog::scales::commands::new_scales::NewScaleCommand;
 use catalog::scales::commands::repositories::NewScaleRepository;
 use catalog::scales::scale_request::ScaleRequest;
+use catalog::webhooks::commands::new_webhook::NewWebhookCommand;
+use catalog::webhooks::commands::repositories::NewWebhookRepository;
+use catalog::webhooks::webhook_id::WebhookId;
+use catalog::webhooks::webhook_request::WebhookRequest;
+use chrono::Utc;
+use common::metadata::Metadata;
 use common::unit_of_work::postgres::PgDatabase;
 use common::unit_of_work::{Database, UnitOfWork};
 use serde_derive::Deserialize;
@@ -39,6 +46,22 @@ pub async fn seed_brands(pg_pool: &PgPool) {
     unit_of_work.commit().await.unwrap();
 }
 
+/// Inserts a new webhook, without the request validation: the local receivers are not valid target urls
+pub async fn seed_webhook(pg_pool: &PgPool, request: WebhookRequest) -> WebhookId {
+    let db = PgDatabase::new(pg_pool);
+    let mut unit_of_work = db.begin().await.unwrap();
+
+    let command = NewWebhookCommand {
+        webhook_id: WebhookId::new(),
+        payload: request,
+        metadata: Metadata::created_at(Utc::now()),
+    };
+    WebhooksRepository.insert(&command, &mut unit_of_work).await.unwrap();
+
+    unit_of_work.commit().await.unwrap();
+    command.webhook_id
+}
+
 pub async fn seed_catalog_items(pg_pool: &PgPool) {
     let db = PgDatabase::new(pg_pool);
     let mut unit_of_work = db.begin().await.unwrap();
@@ -82,6 +105,17 @@ pub async fn seed_railways(pg_pool: &PgPool) {
     unit_of_work.commit().await.unwrap();
 }
 
+/// Inserts a new railway
+pub async fn seed_railway(pg_pool: &PgPool, request: RailwayRequest) {
+    let db = PgDatabase::new(pg_pool);
+    let mut unit_of_work = db.begin().await.unwrap();
+
+    let command = NewRailwayCommand::try_from(request).expect("invalid railway request");
+    RailwaysRepository.insert(&command, &mut unit_of_work).await.unwrap();
+
+    unit_of_work.commit().await.unwrap();
+}
+
 pub async fn seed_scales(pg_pool: &PgPool) {
     let db = PgDatabase::new(pg_pool);
     let mut unit_of_work = db.begin().await.unwrap();
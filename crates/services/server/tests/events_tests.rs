@@ -0,0 +1,89 @@
+pub mod common;
+
+use crate::common::{create_docker_test, spawn_app, IMAGE_NAME};
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+const API_BRANDS: &str = "/api/brands";
+const API_EVENTS: &str = "/api/events";
+const API_SCALES: &str = "/api/scales";
+
+#[tokio::test]
+async fn it_should_stream_the_catalog_changes() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let mut response = client
+            .get(sut.endpoint(&format!("{API_EVENTS}?entity_types=brand")))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("text/event-stream", response.headers()["content-type"]);
+
+        let request = json!({
+            "name": "H0",
+            "ratio": 87.0,
+            "gauge": {
+                "millimeters": 16.5,
+                "inches": 0.65,
+                "track_gauge": "STANDARD"
+            },
+            "description": {},
+            "standards": ["NEM"]
+        });
+        let created = client
+            .post(sut.endpoint(API_SCALES))
+            .json(&request)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(StatusCode::CREATED, created.status());
+
+        let request = json!({
+            "name": "ACME",
+            "description": {},
+            "address": null,
+            "contact_info": null,
+            "socials": null,
+            "kind": "INDUSTRIAL",
+            "status": "ACTIVE"
+        });
+        let created = client
+            .post(sut.endpoint(API_BRANDS))
+            .json(&request)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(StatusCode::CREATED, created.status());
+
+        let mut received = String::new();
+        while !received.contains("\n\n") {
+            let chunk = tokio::time::timeout(Duration::from_secs(10), response.chunk())
+                .await
+                .expect("no event received")
+                .unwrap()
+                .expect("the event stream is closed");
+            received.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+
+        let fields: Vec<(&str, &str)> = received.lines().filter_map(|line| line.split_once(": ")).collect();
+        assert!(fields.contains(&("event", "BrandCreated")));
+        assert!(fields.iter().any(|(name, _)| *name == "id"));
+
+        let data = fields.iter().find(|(name, _)| *name == "data").unwrap().1;
+        let change: Value = serde_json::from_str(data).unwrap();
+        assert_eq!("brand", change["entity_type"]);
+        assert_eq!("acme", change["entity_id"]);
+        assert_eq!("created", change["change"]);
+    })
+    .await;
+}
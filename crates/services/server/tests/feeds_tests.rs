@@ -0,0 +1,77 @@
+pub mod common;
+
+use crate::common::seeding::{seed_brands, seed_catalog_items, seed_railways, seed_scales};
+use crate::common::{create_docker_test, spawn_app, IMAGE_NAME};
+use reqwest::StatusCode;
+
+const CATALOG_ITEMS_FEED: &str = "/feeds/catalog-items.atom";
+
+#[tokio::test]
+async fn it_should_return_the_catalog_items_feed() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let pg_pool = sut.pg_pool();
+        seed_brands(&pg_pool).await;
+        seed_railways(&pg_pool).await;
+        seed_scales(&pg_pool).await;
+        seed_catalog_items(&pg_pool).await;
+
+        let endpoint = sut.endpoint(&format!("{CATALOG_ITEMS_FEED}?brand=ACME&scale=H0&lang=it"));
+        let response = client.get(endpoint).send().await.expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(
+            "application/atom+xml; charset=utf-8",
+            response.headers()["content-type"]
+        );
+
+        let body = response.text().await.expect("Failed to fetch the response body");
+        assert!(body.contains(r#"<feed xmlns="http://www.w3.org/2005/Atom" xml:lang="it">"#));
+        assert!(body.contains("<title>ACME 60011</title>"));
+        assert!(body.contains(&format!(
+            r#"<link rel="self" type="application/json" href="{}"/>"#,
+            sut.endpoint("/api/catalog-items/acme-60011")
+        )));
+        assert!(body.contains("<summary>Locomotiva elettrica E 402A 015"));
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn it_should_filter_the_catalog_items_feed_by_availability_status() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let pg_pool = sut.pg_pool();
+        seed_brands(&pg_pool).await;
+        seed_railways(&pg_pool).await;
+        seed_scales(&pg_pool).await;
+        seed_catalog_items(&pg_pool).await;
+
+        let endpoint = sut.endpoint(&format!("{CATALOG_ITEMS_FEED}?status=ANNOUNCED"));
+        let response = client.get(endpoint).send().await.expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+        let body = response.text().await.expect("Failed to fetch the response body");
+        assert!(!body.contains("<entry>"));
+
+        let endpoint = sut.endpoint(&format!("{CATALOG_ITEMS_FEED}?status=UNKNOWN"));
+        let response = client.get(endpoint).send().await.expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    })
+    .await;
+}
@@ -0,0 +1,141 @@
+pub mod common;
+
+use crate::common::seeding::{seed_brands, seed_catalog_items, seed_railways, seed_scales};
+use crate::common::{create_docker_test, spawn_app, IMAGE_NAME};
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+const GRAPHQL_API: &str = "/graphql";
+
+#[tokio::test]
+async fn it_should_resolve_the_nested_catalog_item_values() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let pg_pool = sut.pg_pool();
+        seed_brands(&pg_pool).await;
+        seed_railways(&pg_pool).await;
+        seed_scales(&pg_pool).await;
+        seed_catalog_items(&pg_pool).await;
+
+        let query = r#"{
+            catalogItems(brand: "ACME") {
+                id
+                itemNumber
+                brand { id name }
+                scale { id name }
+                rollingStocks { category railway { id name } }
+            }
+        }"#;
+
+        let response = client
+            .post(sut.endpoint(GRAPHQL_API))
+            .json(&json!({ "query": query }))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+
+        let body: Value = response.json().await.expect("Failed to fetch the response body");
+        assert_eq!(None, body.get("errors"));
+
+        let catalog_item = &body["data"]["catalogItems"][0];
+        assert_eq!("acme-60011", catalog_item["id"]);
+        assert_eq!("60011", catalog_item["itemNumber"]);
+        assert_eq!("ACME", catalog_item["brand"]["name"]);
+        assert_eq!("H0", catalog_item["scale"]["name"]);
+        assert_eq!("fs", catalog_item["rollingStocks"][0]["railway"]["id"]);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn it_should_paginate_the_brands() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        seed_brands(&sut.pg_pool()).await;
+
+        let response = client
+            .post(sut.endpoint(GRAPHQL_API))
+            .json(&json!({ "query": "{ brands(offset: 1, limit: 1) { name } }" }))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+
+        let body: Value = response.json().await.expect("Failed to fetch the response body");
+        assert_eq!(json!({ "brands": [{ "name": "PIKO" }] }), body["data"]);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn it_should_create_new_scales() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let request = json!({
+            "name": "H0m",
+            "ratio": 87.0,
+            "gauge": {
+                "millimeters": 12.0,
+                "inches": 0.47,
+                "track_gauge": "NARROW"
+            },
+            "description": {
+                "en": "H0 narrow gauge"
+            },
+            "standards": ["NEM"]
+        });
+
+        let response = client
+            .post(sut.endpoint(GRAPHQL_API))
+            .json(&json!({
+                "query": "mutation ($request: JSON!) { createScale(request: $request) }",
+                "variables": { "request": request }
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+
+        let body: Value = response.json().await.expect("Failed to fetch the response body");
+        assert_eq!(json!({ "createScale": "h0m" }), body["data"]);
+
+        let response = client
+            .post(sut.endpoint(GRAPHQL_API))
+            .json(&json!({
+                "query": "mutation ($request: JSON!) { createScale(request: $request) }",
+                "variables": { "request": request }
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        let body: Value = response.json().await.expect("Failed to fetch the response body");
+        assert_eq!(409, body["errors"][0]["extensions"]["status"]);
+    })
+    .await;
+}
@@ -1,6 +1,7 @@
 pub mod common;
 
 use crate::common::{create_docker_test, spawn_app, IMAGE_NAME};
+use reqwest::StatusCode;
 
 #[tokio::test]
 async fn health_check_works() {
@@ -21,3 +22,55 @@ async fn health_check_works() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn it_should_report_the_server_is_alive() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+
+        let endpoint = sut.endpoint("/health/live");
+        let response = client.get(endpoint).send().await.expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+        let body: serde_json::Value = response.json().await.expect("Failed to fetch the response body");
+        assert_eq!("UP", body["status"]);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn it_should_not_be_ready_until_the_migrations_are_applied() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+
+        let endpoint = sut.endpoint("/health/ready");
+        let response = client.get(&endpoint).send().await.expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::SERVICE_UNAVAILABLE, response.status());
+        let body: serde_json::Value = response.json().await.expect("Failed to fetch the response body");
+        assert_eq!("DOWN", body["status"]);
+        assert_eq!("UP", body["checks"]["database"]["status"]);
+        assert_eq!("DOWN", body["checks"]["migrations"]["status"]);
+
+        sut.run_database_migrations().await;
+
+        let response = client.get(&endpoint).send().await.expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+        let body: serde_json::Value = response.json().await.expect("Failed to fetch the response body");
+        assert_eq!("UP", body["status"]);
+        assert_eq!(env!("CARGO_PKG_VERSION"), body["version"]);
+        assert_eq!(serde_json::json!([]), body["checks"]["migrations"]["pending"]);
+    })
+    .await;
+}
@@ -0,0 +1,56 @@
+use catalog::images::image_storage::ImageStorage;
+use configuration::S3Settings;
+use dockertest::waitfor::{MessageSource, MessageWait};
+use dockertest::{DockerTest, Image, Source, TestBodySpecification};
+use secrecy::SecretString;
+use server::storage::s3::S3ImageStorage;
+
+const MINIO_IMAGE_NAME: &str = "minio/minio";
+const MINIO_ROOT_USER: &str = "minioadmin";
+const MINIO_ROOT_PASSWORD: &str = "minioadmin";
+
+fn create_minio_container() -> TestBodySpecification {
+    let image = Image::with_repository(MINIO_IMAGE_NAME).tag("RELEASE.2024-10-13T13-34-11Z");
+    let mut composition = TestBodySpecification::with_image(image)
+        .replace_cmd(vec![String::from("server"), String::from("/data")])
+        .set_wait_for(Box::new(MessageWait {
+            message: String::from("API:"),
+            source: MessageSource::Stdout,
+            timeout: 10,
+        }));
+
+    composition.modify_port_map(9000, 0);
+    composition.modify_env("MINIO_ROOT_USER", MINIO_ROOT_USER);
+    composition.modify_env("MINIO_ROOT_PASSWORD", MINIO_ROOT_PASSWORD);
+    composition
+}
+
+#[tokio::test]
+async fn it_should_store_the_images_in_a_s3_compatible_storage() {
+    let mut test = DockerTest::new().with_default_source(Source::DockerHub);
+    test.provide_container(create_minio_container());
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(MINIO_IMAGE_NAME).host_port(9000).unwrap();
+
+        let storage = S3ImageStorage::new(&S3Settings {
+            endpoint: format!("http://127.0.0.1:{port}"),
+            region: String::from("us-east-1"),
+            bucket: String::from("trenako-images"),
+            access_key_id: String::from(MINIO_ROOT_USER),
+            secret_access_key: SecretString::from(String::from(MINIO_ROOT_PASSWORD)),
+            path_style: true,
+        });
+        storage.create_bucket().await.expect("unable to create the bucket");
+
+        let key = "catalog-items/acme-60011/images/9d7e8a2c-52f6-4a4e-9f2e-0a6b1f1f6f3b";
+        assert_eq!(None, storage.get(key).await.unwrap());
+
+        storage.put(key, b"image content", "image/png").await.unwrap();
+        assert_eq!(Some(b"image content".to_vec()), storage.get(key).await.unwrap());
+
+        storage.delete(key).await.unwrap();
+        assert_eq!(None, storage.get(key).await.unwrap());
+    })
+    .await;
+}
@@ -0,0 +1,31 @@
+pub mod common;
+
+use crate::common::{create_docker_test, spawn_app, IMAGE_NAME};
+use reqwest::StatusCode;
+
+#[tokio::test]
+async fn it_should_expose_the_server_metrics() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let endpoint = sut.endpoint("/api/brands");
+        let response = client.get(endpoint).send().await.expect("Failed to execute request.");
+        assert_eq!(StatusCode::OK, response.status());
+
+        let endpoint = sut.endpoint("/metrics");
+        let response = client.get(endpoint).send().await.expect("Failed to execute request.");
+        assert_eq!(StatusCode::OK, response.status());
+
+        let body = response.text().await.expect("Failed to fetch the response body");
+        assert!(body.contains(r#"http_requests_total{method="GET",route="/api/brands",status="2xx"} 1"#));
+        assert!(body.contains("db_pool_size"));
+        assert!(body.contains("db_pool_acquire_seconds_count 1"));
+    })
+    .await;
+}
@@ -0,0 +1,51 @@
+pub mod common;
+
+use crate::common::{create_docker_test, spawn_app, IMAGE_NAME};
+use configuration::RunMigrations;
+use data::migrations::migrations_status;
+use server::migrations::{run_migrations, MigrationsError};
+
+#[tokio::test]
+async fn it_should_refuse_a_database_schema_behind_when_check_only() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let pg_pool = sut.pg_pool();
+
+        let result = run_migrations(&pg_pool, RunMigrations::CheckOnly).await;
+        assert!(matches!(result, Err(MigrationsError::SchemaBehind(pending)) if !pending.is_empty()));
+
+        sut.run_database_migrations().await;
+
+        let result = run_migrations(&pg_pool, RunMigrations::CheckOnly).await;
+        assert!(result.is_ok());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn it_should_apply_the_migrations_on_startup_from_concurrent_replicas() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let first_replica = sut.pg_pool();
+        let second_replica = sut.pg_pool();
+
+        let (first, second) = tokio::join!(
+            run_migrations(&first_replica, RunMigrations::OnStartup),
+            run_migrations(&second_replica, RunMigrations::OnStartup)
+        );
+        assert!(first.is_ok());
+        assert!(second.is_ok());
+
+        let status = migrations_status(&first_replica).await.unwrap();
+        assert!(status.is_up_to_date());
+    })
+    .await;
+}
@@ -0,0 +1,27 @@
+pub mod common;
+
+use crate::common::{create_docker_test, spawn_app, IMAGE_NAME};
+use reqwest::StatusCode;
+
+#[tokio::test]
+async fn it_should_serve_the_openapi_document() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+
+        let endpoint = sut.endpoint("/api/openapi.json");
+        let response = client.get(endpoint).send().await.expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+
+        let body: serde_json::Value = response.json().await.expect("Failed to fetch the response body");
+        assert_eq!("Trenako", body["info"]["title"]);
+        assert!(body["paths"]["/api/brands"]["get"].is_object());
+        assert!(body["components"]["schemas"]["CatalogItem"].is_object());
+    })
+    .await;
+}
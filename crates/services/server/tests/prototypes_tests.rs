@@ -0,0 +1,151 @@
+pub mod common;
+
+use crate::common::seeding::{seed_brands, seed_railways, seed_scales};
+use crate::common::{create_docker_test, spawn_app, IMAGE_NAME};
+use reqwest::StatusCode;
+use serde_json::json;
+
+const PROTOTYPES: &str = "/api/prototypes";
+const CATALOG_ITEMS: &str = "/api/catalog-items";
+
+#[tokio::test]
+async fn it_should_create_new_prototypes() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let pg_pool = sut.pg_pool();
+        seed_railways(&pg_pool).await;
+
+        let request = json!({
+            "railway": "FS",
+            "class_name": "E.626",
+            "builder": "TIBB",
+            "years_built": { "from": 1927, "until": 1939 },
+            "power_type": "ELECTRIC",
+            "length_over_buffers": 14.95
+        });
+
+        let response = client
+            .post(sut.endpoint(PROTOTYPES))
+            .json(&request)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::CREATED, response.status());
+        let location = response.headers().get("location").expect("the location header");
+        assert_eq!("/api/prototypes/fs-e-626", location.to_str().unwrap());
+
+        let response = client
+            .post(sut.endpoint(PROTOTYPES))
+            .json(&request)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::CONFLICT, response.status());
+
+        let response = client
+            .get(sut.endpoint("/api/prototypes/fs-e-626"))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+        let body = response
+            .json::<serde_json::Value>()
+            .await
+            .expect("Failed to fetch the response body");
+        assert_eq!("E.626", body["class_name"]);
+        assert_eq!("fs", body["railway"]["railway_id"]);
+        assert_eq!("ELECTRIC", body["power_type"]);
+        assert_eq!(14.95, body["length_over_buffers"]);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn it_should_return_the_catalog_items_modelling_the_prototype() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let pg_pool = sut.pg_pool();
+        seed_brands(&pg_pool).await;
+        seed_railways(&pg_pool).await;
+        seed_scales(&pg_pool).await;
+
+        let response = client
+            .post(sut.endpoint(PROTOTYPES))
+            .json(&json!({ "railway": "FS", "class_name": "E.626" }))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::CREATED, response.status());
+
+        let response = client
+            .post(sut.endpoint(CATALOG_ITEMS))
+            .json(&json!({
+                "brand": "ACME",
+                "item_number": "60626",
+                "scale": "H0",
+                "category": "LOCOMOTIVES",
+                "power_method": "DC",
+                "epoch": "III",
+                "description": { "it": "Locomotiva elettrica E.626" },
+                "details": {},
+                "count": 1,
+                "rolling_stocks": [{
+                    "category": "LOCOMOTIVE",
+                    "railway": "FS",
+                    "prototype": "fs-e-626",
+                    "class_name": "E.626",
+                    "road_number": "E.626 001",
+                    "locomotive_type": "ELECTRIC_LOCOMOTIVE",
+                    "is_dummy": false
+                }]
+            }))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::CREATED, response.status());
+
+        let response = client
+            .get(sut.endpoint("/api/prototypes/fs-e-626/models"))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+        let body = response
+            .json::<serde_json::Value>()
+            .await
+            .expect("Failed to fetch the response body");
+        let items = body["items"].as_array().expect("the catalog items");
+        assert_eq!(1, items.len());
+        assert_eq!("acme-60626", items[0]["catalog_item_id"]);
+        assert_eq!("fs-e-626", items[0]["rolling_stocks"][0]["prototype_id"]);
+
+        let response = client
+            .get(sut.endpoint("/api/prototypes/fs-e-646/models"))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::NOT_FOUND, response.status());
+    })
+    .await;
+}
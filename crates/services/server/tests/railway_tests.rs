@@ -11,6 +11,7 @@ use catalog::common::TrackGauge;
 use catalog::railways::period_of_activity::RailwayStatus;
 use catalog::railways::railway::Railway;
 use catalog::railways::railway_id::RailwayId;
+use catalog::railways::railway_relationship::{RailwayLineage, RelationshipKind};
 use chrono::NaiveDate;
 use isocountry::CountryCode;
 use reqwest::StatusCode;
@@ -411,6 +412,100 @@ struct Saved {
     socials_youtube: Option<String>,
 }
 
+#[tokio::test]
+async fn it_should_link_railways_and_return_their_lineage() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let pg_pool = sut.pg_pool();
+        seed_railways(&pg_pool).await;
+
+        let request = json!({
+            "name" : "Trenitalia",
+            "description" : {},
+            "country" : "IT",
+            "period_of_activity" : {
+                "status" : "ACTIVE",
+                "operating_since" : "2000-06-01"
+            }
+        });
+        let response = client
+            .post(sut.endpoint(API_RAILWAYS))
+            .json(&request)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(StatusCode::CREATED, response.status());
+
+        let request = json!({
+            "related_railway" : "fs",
+            "kind" : "SPLIT_FROM",
+            "date" : "2000-06-01"
+        });
+        let response = client
+            .post(sut.endpoint(&format!("{API_RAILWAYS}/trenitalia/relationships")))
+            .json(&request)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(StatusCode::CREATED, response.status());
+
+        let response = client
+            .post(sut.endpoint(&format!("{API_RAILWAYS}/trenitalia/relationships")))
+            .json(&request)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(StatusCode::CONFLICT, response.status());
+
+        let response = client
+            .get(sut.endpoint(&format!("{API_RAILWAYS}/fs/lineage")))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(StatusCode::OK, response.status());
+
+        let lineage = response
+            .json::<RailwayLineage>()
+            .await
+            .expect("Failed to fetch the response body");
+        assert_eq!(RailwayId::new("fs"), lineage.railway_id);
+        assert!(lineage.predecessors.is_empty());
+        assert_eq!(1, lineage.successors.len());
+        assert_eq!(RailwayId::new("trenitalia"), lineage.successors[0].railway_id);
+        assert_eq!(RelationshipKind::SplitFrom, lineage.successors[0].kind);
+        assert_eq!(NaiveDate::from_ymd_opt(2000, 6, 1), lineage.successors[0].date);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn it_should_return_404_for_the_lineage_when_the_railway_is_not_found() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let response = client
+            .get(sut.endpoint(&format!("{API_RAILWAYS}/not-found/lineage")))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(StatusCode::NOT_FOUND, response.status());
+    })
+    .await;
+}
+
 #[derive(Debug, Deserialize)]
 struct Railways {
     items: Vec<Railway>,
@@ -0,0 +1,110 @@
+use configuration::{
+    AdminSettings, AvailabilityReviewSettings, CacheSettings, CalendarSettings, DatabaseSettings, EventStreamSettings,
+    ImagesSettings, LoggingFormat, LoggingLevel, LoggingSettings, ManagementSettings, OutboxSettings, ServerSettings,
+    Settings, TlsSettings, WebhooksSettings,
+};
+use rcgen::CertifiedKey;
+use reqwest::StatusCode;
+use server::app;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use uuid::Uuid;
+
+/// It writes a new self-signed certificate for `localhost`, returning the PEM encoded certificate
+fn write_self_signed_certificate(cert_path: &Path, key_path: &Path) -> String {
+    let CertifiedKey { cert, key_pair } = rcgen::generate_simple_self_signed(vec![String::from("localhost")])
+        .expect("Failed to generate the certificate");
+    std::fs::write(cert_path, cert.pem()).expect("Failed to write the certificate");
+    std::fs::write(key_path, key_pair.serialize_pem()).expect("Failed to write the private key");
+    cert.pem()
+}
+
+fn client_trusting(cert_pem: &str) -> reqwest::Client {
+    let certificate = reqwest::Certificate::from_pem(cert_pem.as_bytes()).unwrap();
+    reqwest::Client::builder()
+        .add_root_certificate(certificate)
+        .tls_built_in_root_certs(false)
+        .build()
+        .unwrap()
+}
+
+/// It spawns the server with TLS enabled, the database is never reached by the probes under test
+async fn spawn_https_app(cert_path: PathBuf, key_path: PathBuf) -> u16 {
+    let settings = Settings {
+        server: ServerSettings {
+            host: String::from("127.0.0.1"),
+            port: 0,
+            tls: Some(TlsSettings {
+                reload_interval_ms: 50,
+                ..TlsSettings::new(cert_path, key_path)
+            }),
+            ..ServerSettings::default()
+        },
+        database: DatabaseSettings::new("postgres", "postgres", "127.0.0.1", 5432, "postgres"),
+        logging: LoggingSettings {
+            level: LoggingLevel::Error,
+            format: LoggingFormat::Compact,
+        },
+        outbox: OutboxSettings {
+            enabled: false,
+            ..OutboxSettings::default()
+        },
+        webhooks: WebhooksSettings {
+            enabled: false,
+            ..WebhooksSettings::default()
+        },
+        event_stream: EventStreamSettings::default(),
+        management: ManagementSettings::default(),
+        cache: CacheSettings::default(),
+        images: ImagesSettings::default(),
+        availability_review: AvailabilityReviewSettings {
+            enabled: false,
+            ..AvailabilityReviewSettings::default()
+        },
+        admin: AdminSettings::default(),
+        calendar: CalendarSettings::default(),
+    };
+
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind random port");
+    let port = listener.local_addr().unwrap().port();
+
+    let _handle = tokio::spawn(async move { app::run(listener, &settings).await });
+    port
+}
+
+#[tokio::test]
+async fn it_should_serve_https_and_reload_the_certificate() {
+    let certs_dir = std::env::temp_dir().join(format!("trenako-tls-{}", Uuid::new_v4()));
+    std::fs::create_dir_all(&certs_dir).unwrap();
+    let cert_path = certs_dir.join("cert.pem");
+    let key_path = certs_dir.join("key.pem");
+
+    let first_cert = write_self_signed_certificate(&cert_path, &key_path);
+    let port = spawn_https_app(cert_path.clone(), key_path.clone()).await;
+    let endpoint = format!("https://localhost:{port}/health/live");
+
+    let response = client_trusting(&first_cert)
+        .get(&endpoint)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(StatusCode::OK, response.status());
+
+    let second_cert = write_self_signed_certificate(&cert_path, &key_path);
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let response = client_trusting(&second_cert)
+        .get(&endpoint)
+        .send()
+        .await
+        .expect("Failed to execute request with the reloaded certificate.");
+    assert_eq!(StatusCode::OK, response.status());
+
+    let result = client_trusting(&first_cert).get(&endpoint).send().await;
+    assert!(result.is_err(), "the old certificate is still served");
+
+    std::fs::remove_dir_all(&certs_dir).unwrap();
+}
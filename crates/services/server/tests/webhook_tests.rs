@@ -0,0 +1,181 @@
+pub mod common;
+
+use crate::common::seeding::seed_webhook;
+use crate::common::{create_docker_test, spawn_app, ADMIN_API_KEY, IMAGE_NAME};
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::routing::post;
+use axum::Router;
+use catalog::webhooks::webhook_request::WebhookRequest;
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use server::webhooks::signature;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+const API_BRANDS: &str = "/api/brands";
+const API_WEBHOOKS: &str = "/api/webhooks";
+const SECRET: &str = "my-very-long-webhook-secret";
+
+type Received = Arc<Mutex<Vec<(HeaderMap, String)>>>;
+
+#[tokio::test]
+async fn it_should_deliver_signed_events_to_the_webhooks() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let (target_url, received) = spawn_receiver().await;
+
+        let request = WebhookRequest {
+            target_url,
+            event_types: vec![String::from("BrandCreated")],
+            secret: String::from(SECRET),
+        };
+        let webhook_id = seed_webhook(&sut.pg_pool(), request).await;
+        let webhook_location = format!("{API_WEBHOOKS}/{webhook_id}");
+
+        let request = json!({
+            "name": "ACME",
+            "description": {},
+            "address": null,
+            "contact_info": null,
+            "socials": null,
+            "kind": "INDUSTRIAL",
+            "status": "ACTIVE"
+        });
+        let response = client
+            .post(sut.endpoint(API_BRANDS))
+            .json(&request)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::CREATED, response.status());
+
+        let mut attempts = 0;
+        while received.lock().unwrap().is_empty() && attempts < 100 {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            attempts += 1;
+        }
+
+        let (headers, body) = received
+            .lock()
+            .unwrap()
+            .first()
+            .cloned()
+            .expect("no webhook delivery received");
+        assert_eq!("BrandCreated", headers[signature::EVENT_HEADER]);
+        let signature_header = headers[signature::SIGNATURE_HEADER].to_str().unwrap();
+        assert!(signature::verify(SECRET, signature_header, &body));
+
+        let event: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!("BrandCreated", event["event_type"]);
+        assert_eq!("acme", event["payload"]["brand_id"]);
+
+        let response = client
+            .get(sut.endpoint(&format!("{webhook_location}/deliveries")))
+            .bearer_auth(ADMIN_API_KEY)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::OK, response.status());
+        let deliveries: Value = response.json().await.unwrap();
+        assert_eq!(1, deliveries["items"].as_array().unwrap().len());
+        assert_eq!("DELIVERED", deliveries["items"][0]["status"]);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn it_should_return_400_for_invalid_webhook_requests() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        let request = json!({
+            "target_url": "not a url",
+            "event_types": ["NotAnEvent"],
+            "secret": "short"
+        });
+        let response = client
+            .post(sut.endpoint(API_WEBHOOKS))
+            .bearer_auth(ADMIN_API_KEY)
+            .json(&request)
+            .send()
+            .await
+            .expect("Failed to execute request.");
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn it_should_reject_webhooks_to_the_internal_network() {
+    let test = create_docker_test();
+
+    test.run_async(|ops| async move {
+        let (_, port) = ops.handle(IMAGE_NAME).host_port(5432).unwrap();
+
+        let sut = spawn_app(*port).await;
+        let client = reqwest::Client::new();
+        sut.run_database_migrations().await;
+
+        for target_url in [
+            "http://www.example.com/hooks",
+            "https://127.0.0.1/hooks",
+            "https://localhost:8080/hooks",
+            "https://169.254.169.254/latest/meta-data",
+        ] {
+            let request = json!({
+                "target_url": target_url,
+                "event_types": ["BrandCreated"],
+                "secret": SECRET
+            });
+            let response = client
+                .post(sut.endpoint(API_WEBHOOKS))
+                .bearer_auth(ADMIN_API_KEY)
+                .json(&request)
+                .send()
+                .await
+                .expect("Failed to execute request.");
+
+            assert_eq!(StatusCode::BAD_REQUEST, response.status(), "{target_url}");
+        }
+    })
+    .await;
+}
+
+async fn spawn_receiver() -> (String, Received) {
+    let received: Received = Arc::new(Mutex::new(Vec::new()));
+    let app = Router::new()
+        .route(
+            "/hooks",
+            post(
+                |State(received): State<Received>, headers: HeaderMap, body: String| async move {
+                    received.lock().unwrap().push((headers, body));
+                    StatusCode::OK
+                },
+            ),
+        )
+        .with_state(received.clone());
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    (format!("http://127.0.0.1:{port}/hooks"), received)
+}
@@ -16,6 +16,8 @@ use catalog::catalog_items::service_level::ServiceLevel;
 use catalog::catalog_items::technical_specifications::{
     BodyShellType, ChassisType, Coupling, CouplingSocket, FeatureFlag, Radius, TechnicalSpecifications,
 };
+use catalog::catalog_items::uic_coach_designation::decode_passenger_car_type;
+use catalog::catalog_items::uic_wagon_code::suggest_freight_car_type;
 use common::length::Length;
 use common::localized_text::LocalizedText;
 use common::measure_units::MeasureUnit;
@@ -111,6 +113,7 @@ impl TryInto<RollingStockRequest> for CsvRecord {
                 livery: self.livery,
                 length_over_buffers: length,
                 technical_specifications: tech_specs,
+                prototype: None,
                 class_name: self.type_name,
                 road_number: self
                     .road_number
@@ -122,31 +125,43 @@ impl TryInto<RollingStockRequest> for CsvRecord {
                 control: self.control,
                 is_dummy: self.is_dummy,
             },
-            RollingStockCategory::PassengerCar => RollingStockRequest::PassengerCarRequest {
-                railway: self.railway,
-                livery: self.livery,
-                length_over_buffers: length,
-                technical_specifications: tech_specs,
-                type_name: self.type_name,
-                road_number: self.road_number,
-                series: self.series.map(String::from),
-                passenger_car_type: PassengerCarType::from_str(&self.subcategory).ok(),
-                service_level: self.service_level,
-            },
+            RollingStockCategory::PassengerCar => {
+                let designation = decode_passenger_car_type(&self.type_name);
+                RollingStockRequest::PassengerCarRequest {
+                    railway: self.railway,
+                    livery: self.livery,
+                    length_over_buffers: length,
+                    technical_specifications: tech_specs,
+                    prototype: None,
+                    type_name: self.type_name,
+                    road_number: self.road_number,
+                    series: self.series,
+                    passenger_car_type: PassengerCarType::from_str(&self.subcategory)
+                        .ok()
+                        .or(designation.as_ref().map(|it| it.passenger_car_type)),
+                    service_level: self
+                        .service_level
+                        .or(designation.as_ref().and_then(|it| it.service_level)),
+                }
+            }
             RollingStockCategory::FreightCar => RollingStockRequest::FreightCarRequest {
                 railway: self.railway,
                 livery: self.livery,
                 length_over_buffers: length,
                 technical_specifications: tech_specs,
+                prototype: None,
+                freight_car_type: FreightCarType::from_str(&self.subcategory)
+                    .ok()
+                    .or_else(|| suggest_freight_car_type(&self.type_name)),
                 type_name: self.type_name,
                 road_number: self.road_number,
-                freight_car_type: FreightCarType::from_str(&self.subcategory).ok(),
             },
             RollingStockCategory::Railcar => RollingStockRequest::RailcarRequest {
                 railway: self.railway,
                 livery: self.livery,
                 length_over_buffers: length,
                 technical_specifications: tech_specs,
+                prototype: None,
                 type_name: self.type_name,
                 road_number: self.road_number,
                 series: self.series,
@@ -162,6 +177,7 @@ impl TryInto<RollingStockRequest> for CsvRecord {
                 livery: self.livery,
                 length_over_buffers: length,
                 technical_specifications: tech_specs,
+                prototype: None,
                 type_name: self.type_name,
                 road_number: self.road_number,
                 series: self.series,
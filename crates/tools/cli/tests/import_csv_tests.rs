@@ -1,5 +1,5 @@
 use catalog::catalog_items::availability_status::AvailabilityStatus;
-use catalog::catalog_items::category::{Category, LocomotiveType};
+use catalog::catalog_items::category::{Category, FreightCarType, LocomotiveType, PassengerCarType};
 use catalog::catalog_items::control::{Control, DccInterface};
 use catalog::catalog_items::delivery_date::DeliveryDate;
 use catalog::catalog_items::epoch::Epoch;
@@ -35,10 +35,7 @@ fn it_should_parse_a_single_locomotive_from_csv_files() {
     assert_eq!(ItemNumber::new("HR2934"), rivarossi_hr2934.item_number);
     assert_eq!(Category::Locomotives, rivarossi_hr2934.category);
     assert_eq!(PowerMethod::DC, rivarossi_hr2934.power_method);
-    assert_eq!(
-        Epoch::Multiple(Box::new(Epoch::IV), Box::new(Epoch::V)),
-        rivarossi_hr2934.epoch
-    );
+    assert_eq!(Epoch::Multiple(vec![Epoch::IV, Epoch::V]), rivarossi_hr2934.epoch);
     assert_eq!("H0", rivarossi_hr2934.scale);
     assert_eq!(
         rivarossi_hr2934_description.as_ref(),
@@ -60,6 +57,7 @@ fn it_should_parse_a_single_locomotive_from_csv_files() {
             livery,
             length_over_buffers,
             technical_specifications,
+            prototype: _,
             class_name,
             road_number,
             series,
@@ -163,6 +161,7 @@ fn it_should_parse_multiple_passenger_cars_from_csv_files() {
             livery,
             length_over_buffers,
             technical_specifications,
+            prototype: _,
             type_name,
             road_number,
             series,
@@ -223,6 +222,7 @@ fn it_should_parse_multiple_passenger_cars_from_csv_files() {
             livery,
             length_over_buffers,
             technical_specifications,
+            prototype: _,
             type_name,
             road_number,
             series,
@@ -283,6 +283,7 @@ fn it_should_parse_multiple_passenger_cars_from_csv_files() {
             livery,
             length_over_buffers,
             technical_specifications,
+            prototype: _,
             type_name,
             road_number,
             series,
@@ -343,6 +344,7 @@ fn it_should_parse_multiple_passenger_cars_from_csv_files() {
             livery,
             length_over_buffers,
             technical_specifications,
+            prototype: _,
             type_name,
             road_number,
             series,
@@ -409,6 +411,7 @@ fn it_should_parse_a_single_freight_car_from_csv_files() {
             livery,
             length_over_buffers,
             technical_specifications,
+            prototype: _,
             type_name,
             road_number,
             freight_car_type,
@@ -417,7 +420,7 @@ fn it_should_parse_a_single_freight_car_from_csv_files() {
             assert_eq!(&Some(String::from("verde")), livery);
             assert_eq!(&String::from("Sgnss"), type_name);
             assert_eq!(&None, road_number);
-            assert_eq!(&None, freight_car_type);
+            assert_eq!(&Some(FreightCarType::ContainerCars), freight_car_type);
 
             assert_eq!(&Some(expected_length), length_over_buffers);
 
@@ -461,3 +464,30 @@ fn it_should_parse_a_single_freight_car_from_csv_files() {
         _ => panic!("rivarossi_hr6613.rolling_stocks[0] is not a FreightCarRequest"),
     }
 }
+
+#[test]
+fn it_should_fill_the_passenger_car_defaults_from_the_uic_designation() {
+    let csv_file = format!("{}/passenger_cars_single.csv", CSV_FILE_ROOT);
+
+    let catalog_items = read_catalog_items(&csv_file).expect("failed to parse the test csv file");
+
+    assert_eq!(1, catalog_items.len());
+
+    let acme_50710 = &catalog_items[0];
+    assert_eq!(ItemNumber::new("50710"), acme_50710.item_number);
+    assert_eq!(1, acme_50710.rolling_stocks.len());
+
+    match &acme_50710.rolling_stocks[0] {
+        PassengerCarRequest {
+            type_name,
+            passenger_car_type,
+            service_level,
+            ..
+        } => {
+            assert_eq!(&String::from("Bpmz"), type_name);
+            assert_eq!(&Some(PassengerCarType::OpenCoach), passenger_car_type);
+            assert_eq!(&Some(ServiceLevel::SecondClass), service_level);
+        }
+        _ => panic!("acme_50710.rolling_stocks[0] is not a PassengerCarRequest"),
+    }
+}
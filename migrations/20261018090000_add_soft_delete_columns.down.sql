@@ -0,0 +1,5 @@
+ALTER TABLE public.rolling_stocks DROP COLUMN IF EXISTS deleted_at;
+ALTER TABLE public.catalog_items DROP COLUMN IF EXISTS deleted_at;
+ALTER TABLE public.scales DROP COLUMN IF EXISTS deleted_at;
+ALTER TABLE public.railways DROP COLUMN IF EXISTS deleted_at;
+ALTER TABLE public.brands DROP COLUMN IF EXISTS deleted_at;
@@ -0,0 +1,5 @@
+ALTER TABLE public.brands ADD COLUMN deleted_at timestamptz;
+ALTER TABLE public.railways ADD COLUMN deleted_at timestamptz;
+ALTER TABLE public.scales ADD COLUMN deleted_at timestamptz;
+ALTER TABLE public.catalog_items ADD COLUMN deleted_at timestamptz;
+ALTER TABLE public.rolling_stocks ADD COLUMN deleted_at timestamptz;
@@ -0,0 +1,16 @@
+CREATE TABLE public.outbox_events
+(
+    event_id       uuid                     NOT NULL,
+    aggregate_type varchar(25)              NOT NULL,
+    aggregate_id   varchar(50)              NOT NULL,
+    event_type     varchar(50)              NOT NULL,
+    payload        jsonb                    NOT NULL,
+    occurred_at    timestamp with time zone NOT NULL,
+    created_at     timestamp with time zone NOT NULL DEFAULT now(),
+    dispatched_at  timestamp with time zone,
+    attempts       integer                  NOT NULL DEFAULT 0,
+    last_error     text,
+    CONSTRAINT "PK_outbox_events" PRIMARY KEY (event_id)
+);
+
+CREATE INDEX "IDX_outbox_events_pending" ON public.outbox_events (created_at) WHERE dispatched_at IS NULL;
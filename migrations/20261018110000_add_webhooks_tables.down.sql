@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS public.webhook_deliveries;
+
+DROP TABLE IF EXISTS public.webhooks;
+
+DROP TYPE IF EXISTS webhook_delivery_status;
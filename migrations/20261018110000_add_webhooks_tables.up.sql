@@ -0,0 +1,42 @@
+CREATE TYPE webhook_delivery_status AS ENUM (
+    'PENDING',
+    'DELIVERED',
+    'FAILED'
+    );
+
+CREATE TABLE public.webhooks
+(
+    webhook_id       uuid                     NOT NULL,
+    target_url       varchar(500)             NOT NULL,
+    event_types      varchar(50)[]            NOT NULL,
+    secret           varchar(100)             NOT NULL,
+    created_at       timestamp with time zone NOT NULL,
+    last_modified_at timestamp with time zone,
+    version          integer                  NOT NULL DEFAULT 1,
+    CONSTRAINT "PK_webhooks" PRIMARY KEY (webhook_id)
+);
+
+CREATE TABLE public.webhook_deliveries
+(
+    delivery_id          uuid                     NOT NULL,
+    webhook_id           uuid                     NOT NULL,
+    event_id             uuid                     NOT NULL,
+    event_type           varchar(50)              NOT NULL,
+    payload              text                     NOT NULL,
+    status               webhook_delivery_status  NOT NULL,
+    attempts             integer                  NOT NULL DEFAULT 0,
+    last_response_status integer,
+    last_error           text,
+    last_attempt_at      timestamp with time zone,
+    next_attempt_at      timestamp with time zone,
+    delivered_at         timestamp with time zone,
+    created_at           timestamp with time zone NOT NULL,
+    CONSTRAINT "PK_webhook_deliveries" PRIMARY KEY (delivery_id),
+    CONSTRAINT "FK_webhook_deliveries_webhooks" FOREIGN KEY (webhook_id)
+        REFERENCES public.webhooks (webhook_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE CASCADE,
+    CONSTRAINT "UQ_webhook_deliveries_event" UNIQUE (webhook_id, event_id)
+);
+
+CREATE INDEX "IDX_webhook_deliveries_due" ON public.webhook_deliveries (next_attempt_at) WHERE status = 'PENDING';
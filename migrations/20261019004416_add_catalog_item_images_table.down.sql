@@ -0,0 +1 @@
+DROP TABLE IF EXISTS public.catalog_item_images;
@@ -0,0 +1,19 @@
+CREATE TABLE public.catalog_item_images
+(
+    image_id        uuid                     NOT NULL,
+    catalog_item_id varchar(76)              NOT NULL,
+    content_type    varchar(50)              NOT NULL,
+    size_bytes      bigint                   NOT NULL,
+    position        integer                  NOT NULL,
+    is_primary      boolean                  NOT NULL DEFAULT false,
+    created_at      timestamp with time zone NOT NULL,
+    CONSTRAINT "PK_catalog_item_images" PRIMARY KEY (image_id),
+    CONSTRAINT "FK_catalog_item_images_catalog_items" FOREIGN KEY (catalog_item_id)
+        REFERENCES public.catalog_items (catalog_item_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE CASCADE
+);
+
+CREATE INDEX "IDX_catalog_item_images_position" ON public.catalog_item_images (catalog_item_id, position);
+
+CREATE UNIQUE INDEX "UQ_catalog_item_images_primary" ON public.catalog_item_images (catalog_item_id) WHERE is_primary;
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS "IDX_catalog_items_epoch_range";
+
+ALTER TABLE public.catalog_items DROP COLUMN epoch_end;
+ALTER TABLE public.catalog_items DROP COLUMN epoch_start;
@@ -0,0 +1,32 @@
+ALTER TABLE public.catalog_items ADD COLUMN epoch_start smallint;
+ALTER TABLE public.catalog_items ADD COLUMN epoch_end smallint;
+
+-- the slots on the epochs timeline, they must match the catalog Epoch ranges
+UPDATE public.catalog_items AS c
+SET epoch_start = r.epoch_start,
+    epoch_end   = r.epoch_end
+FROM (
+    SELECT catalog_item_id,
+           MIN(CASE value
+                   WHEN 'I' THEN 0 WHEN 'II' THEN 1 WHEN 'IIa' THEN 1 WHEN 'IIb' THEN 2
+                   WHEN 'III' THEN 4 WHEN 'IIIa' THEN 4 WHEN 'IIIb' THEN 5 WHEN 'IIIc' THEN 6
+                   WHEN 'IV' THEN 8 WHEN 'IVa' THEN 8 WHEN 'IVb' THEN 9
+                   WHEN 'V' THEN 11 WHEN 'Va' THEN 11 WHEN 'Vb' THEN 12 WHEN 'Vm' THEN 13
+                   WHEN 'VI' THEN 15
+               END) AS epoch_start,
+           MAX(CASE value
+                   WHEN 'I' THEN 0 WHEN 'II' THEN 3 WHEN 'IIa' THEN 1 WHEN 'IIb' THEN 2
+                   WHEN 'III' THEN 7 WHEN 'IIIa' THEN 4 WHEN 'IIIb' THEN 5 WHEN 'IIIc' THEN 6
+                   WHEN 'IV' THEN 10 WHEN 'IVa' THEN 8 WHEN 'IVb' THEN 9
+                   WHEN 'V' THEN 14 WHEN 'Va' THEN 11 WHEN 'Vb' THEN 12 WHEN 'Vm' THEN 13
+                   WHEN 'VI' THEN 15
+               END) AS epoch_end
+    FROM public.catalog_items, unnest(string_to_array(epoch, '/')) AS value
+    GROUP BY catalog_item_id
+) AS r
+WHERE c.catalog_item_id = r.catalog_item_id;
+
+ALTER TABLE public.catalog_items ALTER COLUMN epoch_start SET NOT NULL;
+ALTER TABLE public.catalog_items ALTER COLUMN epoch_end SET NOT NULL;
+
+CREATE INDEX "IDX_catalog_items_epoch_range" ON public.catalog_items (epoch_start, epoch_end);
@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS public."IDX_catalog_items_announced";
+
+ALTER TABLE public.catalog_items
+    DROP COLUMN IF EXISTS review_requested_at;
+
+DROP TABLE IF EXISTS public.catalog_item_availability_history;
@@ -0,0 +1,29 @@
+CREATE TABLE public.catalog_item_availability_history
+(
+    history_id      bigserial                NOT NULL,
+    catalog_item_id varchar(76)              NOT NULL,
+    status          availability_status      NOT NULL,
+    previous_status availability_status      NULL,
+    note            varchar(250)             NULL,
+    changed_at      timestamp with time zone NOT NULL,
+    CONSTRAINT "PK_catalog_item_availability_history" PRIMARY KEY (history_id),
+    CONSTRAINT "FK_catalog_item_availability_history_catalog_items" FOREIGN KEY (catalog_item_id)
+        REFERENCES public.catalog_items (catalog_item_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE CASCADE
+);
+
+CREATE INDEX "IDX_catalog_item_availability_history_changed_at"
+    ON public.catalog_item_availability_history (catalog_item_id, changed_at);
+
+INSERT INTO public.catalog_item_availability_history (catalog_item_id, status, changed_at)
+SELECT catalog_item_id, availability_status, created_at
+FROM public.catalog_items
+WHERE availability_status IS NOT NULL;
+
+ALTER TABLE public.catalog_items
+    ADD COLUMN review_requested_at timestamp with time zone NULL;
+
+CREATE INDEX "IDX_catalog_items_announced"
+    ON public.catalog_items (delivery_date)
+    WHERE availability_status = 'ANNOUNCED' AND deleted_at IS NULL;
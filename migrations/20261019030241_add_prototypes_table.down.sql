@@ -0,0 +1,9 @@
+DROP INDEX IF EXISTS public."IDX_rolling_stocks_prototype_id";
+
+ALTER TABLE public.rolling_stocks
+    DROP CONSTRAINT IF EXISTS "FK_rolling_stocks_prototypes",
+    DROP COLUMN IF EXISTS prototype_id;
+
+DROP TABLE IF EXISTS public.prototypes;
+
+DROP TYPE IF EXISTS power_type;
@@ -0,0 +1,44 @@
+CREATE TYPE power_type AS ENUM (
+    'BATTERY',
+    'DIESEL',
+    'ELECTRIC',
+    'HYBRID',
+    'STEAM',
+    'UNPOWERED'
+    );
+
+CREATE TABLE public.prototypes
+(
+    prototype_id     varchar(160)             NOT NULL,
+    railway_id       varchar(50)              NOT NULL,
+    class_name       varchar(50)              NOT NULL,
+    series           varchar(50),
+    builder          varchar(100),
+    built_from       integer,
+    built_until      integer,
+    power_type       power_type,
+    length_meters    numeric(9, 3),
+    created_at       timestamp with time zone NOT NULL,
+    last_modified_at timestamp with time zone,
+    deleted_at       timestamp with time zone,
+    version          integer                  NOT NULL DEFAULT 1,
+    CONSTRAINT "PK_prototypes" PRIMARY KEY (prototype_id),
+    CONSTRAINT "FK_prototypes_railways" FOREIGN KEY (railway_id)
+        REFERENCES public.railways (railway_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE NO ACTION
+);
+
+CREATE INDEX "IDX_prototypes_railway_id"
+    ON public.prototypes (railway_id);
+
+ALTER TABLE public.rolling_stocks
+    ADD COLUMN prototype_id varchar(160) NULL,
+    ADD CONSTRAINT "FK_rolling_stocks_prototypes" FOREIGN KEY (prototype_id)
+        REFERENCES public.prototypes (prototype_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE NO ACTION;
+
+CREATE INDEX "IDX_rolling_stocks_prototype_id"
+    ON public.rolling_stocks (prototype_id)
+    WHERE prototype_id IS NOT NULL;
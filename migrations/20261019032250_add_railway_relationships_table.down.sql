@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS public."IDX_railway_relationships_related_railway_id";
+
+DROP TABLE IF EXISTS public.railway_relationships;
+
+DROP TYPE IF EXISTS railway_relationship_kind;
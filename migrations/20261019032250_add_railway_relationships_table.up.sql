@@ -0,0 +1,27 @@
+CREATE TYPE railway_relationship_kind AS ENUM (
+    'RENAMED_TO',
+    'MERGED_INTO',
+    'SPLIT_FROM'
+    );
+
+CREATE TABLE public.railway_relationships
+(
+    railway_id         varchar(50)               NOT NULL,
+    related_railway_id varchar(50)               NOT NULL,
+    kind               railway_relationship_kind NOT NULL,
+    relationship_date  date,
+    created_at         timestamp with time zone  NOT NULL,
+    CONSTRAINT "PK_railway_relationships" PRIMARY KEY (railway_id, related_railway_id),
+    CONSTRAINT "FK_railway_relationships_railways" FOREIGN KEY (railway_id)
+        REFERENCES public.railways (railway_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE NO ACTION,
+    CONSTRAINT "FK_railway_relationships_related_railways" FOREIGN KEY (related_railway_id)
+        REFERENCES public.railways (railway_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE NO ACTION,
+    CONSTRAINT "CK_railway_relationships_self" CHECK (railway_id <> related_railway_id)
+);
+
+CREATE INDEX "IDX_railway_relationships_related_railway_id"
+    ON public.railway_relationships (related_railway_id);
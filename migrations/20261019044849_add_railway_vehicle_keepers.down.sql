@@ -0,0 +1,4 @@
+ALTER TABLE public.railways
+    DROP CONSTRAINT IF EXISTS "CK_railways_uic_country_code",
+    DROP COLUMN IF EXISTS vehicle_keeper_markings,
+    DROP COLUMN IF EXISTS uic_country_code;
@@ -0,0 +1,4 @@
+ALTER TABLE public.railways
+    ADD COLUMN uic_country_code        smallint,
+    ADD COLUMN vehicle_keeper_markings jsonb NOT NULL DEFAULT '[]'::jsonb,
+    ADD CONSTRAINT "CK_railways_uic_country_code" CHECK (uic_country_code BETWEEN 10 AND 99);
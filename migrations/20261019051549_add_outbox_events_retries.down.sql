@@ -0,0 +1,8 @@
+DROP INDEX IF EXISTS public."IDX_outbox_events_pending";
+
+ALTER TABLE public.outbox_events
+    DROP COLUMN IF EXISTS last_attempt_at,
+    DROP COLUMN IF EXISTS next_attempt_at,
+    DROP COLUMN IF EXISTS dead_lettered_at;
+
+CREATE INDEX "IDX_outbox_events_pending" ON public.outbox_events (created_at) WHERE dispatched_at IS NULL;
@@ -0,0 +1,9 @@
+ALTER TABLE public.outbox_events
+    ADD COLUMN last_attempt_at  timestamp with time zone,
+    ADD COLUMN next_attempt_at  timestamp with time zone NOT NULL DEFAULT now(),
+    ADD COLUMN dead_lettered_at timestamp with time zone;
+
+DROP INDEX IF EXISTS public."IDX_outbox_events_pending";
+
+CREATE INDEX "IDX_outbox_events_pending" ON public.outbox_events (next_attempt_at)
+    WHERE dispatched_at IS NULL AND dead_lettered_at IS NULL;
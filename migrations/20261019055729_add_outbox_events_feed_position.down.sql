@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS public."IDX_outbox_events_feed_position";
+
+ALTER TABLE public.outbox_events
+    DROP COLUMN IF EXISTS sequence,
+    DROP COLUMN IF EXISTS transaction_id;
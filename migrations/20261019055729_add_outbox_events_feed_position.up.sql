@@ -0,0 +1,5 @@
+ALTER TABLE public.outbox_events
+    ADD COLUMN transaction_id xid8   NOT NULL DEFAULT pg_current_xact_id(),
+    ADD COLUMN sequence       bigserial;
+
+CREATE INDEX "IDX_outbox_events_feed_position" ON public.outbox_events (transaction_id, sequence);